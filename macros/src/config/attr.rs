@@ -12,6 +12,29 @@ pub enum FieldStatus {
     Hidden,
 }
 
+/// Field naming rule from #[config(rename_all = "xxx")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    /// `my_field` -> `my-field`
+    KebabCase,
+}
+
+impl RenameRule {
+    /// Name as written in `#[serde(rename_all = "...")]`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::KebabCase => "kebab-case",
+        }
+    }
+
+    /// Apply the rule to a snake_case field name.
+    pub fn apply(self, field: &str) -> String {
+        match self {
+            Self::KebabCase => field.replace('_', "-"),
+        }
+    }
+}
+
 /// Get section name from #[config(section = "xxx")]
 pub fn get_section(attrs: &[Attribute]) -> Option<String> {
     get_string_attr(attrs, "section")
@@ -32,6 +55,66 @@ pub fn get_inline_doc(attrs: &[Attribute]) -> Option<String> {
     get_string_attr(attrs, "inline_doc")
 }
 
+/// Get environment variable name from #[config(env = "xxx")]
+pub fn get_env_var(attrs: &[Attribute]) -> Option<String> {
+    get_string_attr(attrs, "env")
+}
+
+/// Get old field name from #[config(alias = "xxx")]
+pub fn get_alias(attrs: &[Attribute]) -> Option<String> {
+    get_string_attr(attrs, "alias")
+}
+
+/// Get field naming rule from #[config(rename_all = "xxx")]
+///
+/// Returns the rejected value as error for unsupported rules.
+pub fn get_rename_all(attrs: &[Attribute]) -> Result<Option<RenameRule>, String> {
+    match get_string_attr(attrs, "rename_all").as_deref() {
+        None => Ok(None),
+        Some("kebab-case") => Ok(Some(RenameRule::KebabCase)),
+        Some(other) => Err(other.to_string()),
+    }
+}
+
+/// Get validator function name from #[config(validate = "xxx")]
+pub fn get_validator(attrs: &[Attribute]) -> Option<String> {
+    get_string_attr(attrs, "validate")
+}
+
+/// Check if field has a matching #[serde(alias = "xxx")]
+pub fn has_serde_alias(attrs: &[Attribute], alias: &str) -> bool {
+    has_serde_value(attrs, "alias", alias)
+}
+
+/// Check if struct has a matching #[serde(rename_all = "xxx")]
+pub fn has_serde_rename_all(attrs: &[Attribute], rule: RenameRule) -> bool {
+    has_serde_value(attrs, "rename_all", rule.as_str())
+}
+
+/// Check for #[serde(key = "value")]
+fn has_serde_value(attrs: &[Attribute], key: &str, expected: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|a| a.path().is_ident("serde"))
+        .any(|attr| {
+            let mut found = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.input.peek(syn::Token![=]) {
+                    let value = meta.value()?;
+                    if meta.path.is_ident(key)
+                        && let Ok(lit) = value.parse::<syn::LitStr>()
+                    {
+                        found |= lit.value() == expected;
+                    } else {
+                        let _: Option<syn::Lit> = value.parse().ok();
+                    }
+                }
+                Ok(())
+            });
+            found
+        })
+}
+
 /// Get string value from #[config(key = "value")]
 fn get_string_attr(attrs: &[Attribute], key: &str) -> Option<String> {
     for attr in attrs {
@@ -3,8 +3,8 @@
 use syn::Type;
 
 use crate::config::attr::{
-    extract_doc_comment, get_custom_name, get_default_value, get_inline_doc, has_attr,
-    parse_field_status,
+    RenameRule, extract_doc_comment, get_alias, get_custom_name, get_default_value, get_env_var,
+    get_inline_doc, get_validator, has_attr, has_serde_alias, parse_field_status,
 };
 
 // Re-export FieldStatus for convenience
@@ -18,6 +18,12 @@ pub struct FieldInfo {
     pub inline_doc: Option<String>,
     pub status: FieldStatus,
     pub default: Option<String>,
+    pub env: Option<String>,
+    pub alias: Option<String>,
+    /// Whether `alias` is mirrored by `#[serde(alias = "...")]`.
+    pub serde_alias: bool,
+    /// Validator from `#[config(validate = "...")]`, an associated fn of the struct.
+    pub validate: Option<syn::Ident>,
     pub skip: bool,
     pub sub: bool,
     pub ty: Type,
@@ -25,17 +31,26 @@ pub struct FieldInfo {
 
 impl FieldInfo {
     /// Parse field info from a syn::Field.
-    pub fn from_field(field: &syn::Field) -> Option<Self> {
+    ///
+    /// `rename_all` applies to the TOML name unless `#[config(name = "...")]` is set.
+    pub fn from_field(field: &syn::Field, rename_all: Option<RenameRule>) -> Option<Self> {
         let ident = field.ident.as_ref()?;
         let attrs = &field.attrs;
 
         Some(Self {
             name: ident.clone(),
-            toml_name: get_custom_name(attrs).unwrap_or_else(|| ident.to_string()),
+            toml_name: get_custom_name(attrs).unwrap_or_else(|| match rename_all {
+                Some(rule) => rule.apply(&ident.to_string()),
+                None => ident.to_string(),
+            }),
             doc: extract_doc_comment(attrs),
             inline_doc: get_inline_doc(attrs),
             status: parse_field_status(attrs),
             default: get_default_value(attrs),
+            env: get_env_var(attrs),
+            alias: get_alias(attrs),
+            serde_alias: get_alias(attrs).is_some_and(|a| has_serde_alias(attrs, &a)),
+            validate: get_validator(attrs).map(|f| syn::Ident::new(&f, ident.span())),
             skip: has_attr(attrs, "skip"),
             sub: has_attr(attrs, "sub"),
             ty: field.ty.clone(),
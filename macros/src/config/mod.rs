@@ -1,18 +1,23 @@
-//! Config derive macro - generates FIELDS and template().
+//! Config derive macro - generates FIELDS, template(), schema() and from_env().
 //!
-//! Combines field path generation and TOML template generation.
+//! Combines field path generation, TOML template generation, JSON Schema
+//! generation and environment variable overrides.
 
 mod attr;
 mod field;
+mod schema;
 mod template;
 mod types;
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{Data, DeriveInput, Fields};
 
-use attr::{extract_doc_comment, get_section, parse_field_status};
+use attr::{
+    extract_doc_comment, get_rename_all, get_section, has_serde_rename_all, parse_field_status,
+};
 use field::{FieldInfo, FieldStatus};
+use schema::generate_schema_code;
 use template::generate_template_code;
 use types::infer_section;
 
@@ -38,8 +43,42 @@ pub fn derive(input: &DeriveInput) -> TokenStream {
         _ => return quote! { compile_error!("Config only works on structs"); },
     };
 
+    // Field naming rule, mirrored by serde like aliases below
+    let rename_all = match get_rename_all(&input.attrs) {
+        Ok(rule) => rule,
+        Err(value) => {
+            let msg =
+                format!("unsupported #[config(rename_all = \"{value}\")], expected \"kebab-case\"");
+            return quote_spanned! { name.span() => compile_error!(#msg); };
+        }
+    };
+    if let Some(rule) = rename_all
+        && !has_serde_rename_all(&input.attrs, rule)
+    {
+        let rule = rule.as_str();
+        let msg = format!(
+            "#[config(rename_all = \"{rule}\")] requires #[serde(rename_all = \"{rule}\")] on the same struct"
+        );
+        return quote_spanned! { name.span() => compile_error!(#msg); };
+    }
+
     // Collect field info
-    let field_infos: Vec<FieldInfo> = fields.iter().filter_map(FieldInfo::from_field).collect();
+    let field_infos: Vec<FieldInfo> = fields
+        .iter()
+        .filter_map(|f| FieldInfo::from_field(f, rename_all))
+        .collect();
+
+    // Derive macros cannot add attributes, so aliases must be mirrored for serde
+    for f in &field_infos {
+        if let Some(alias) = &f.alias
+            && !f.serde_alias
+        {
+            let msg = format!(
+                "#[config(alias = \"{alias}\")] requires #[serde(alias = \"{alias}\")] on the same field"
+            );
+            return quote_spanned! { f.name.span() => compile_error!(#msg); };
+        }
+    }
 
     // Generate FIELDS struct (skip fields with #[config(skip)])
     let fields_for_path: Vec<_> = field_infos.iter().filter(|f| !f.skip).collect();
@@ -67,6 +106,11 @@ pub fn derive(input: &DeriveInput) -> TokenStream {
 
     let template_code = generate_template_code(&template_fields);
 
+    // Generate schema code (skip internal fields, hidden fields are still valid config)
+    let schema_fields: Vec<_> = field_infos.iter().filter(|f| !f.skip).collect();
+
+    let schema_code = generate_schema_code(&schema_fields);
+
     let has_section_status = matches!(
         section_status,
         FieldStatus::NotImplemented | FieldStatus::Deprecated | FieldStatus::Experimental
@@ -116,6 +160,58 @@ pub fn derive(input: &DeriveInput) -> TokenStream {
         })
         .collect();
 
+    // Generate deprecated alias entries and checks for #[config(alias = "old")]
+    let alias_paths: Vec<(String, String)> = field_infos
+        .iter()
+        .filter(|f| !f.skip)
+        .filter_map(|f| {
+            let alias = f.alias.as_ref()?;
+            let (old_path, new_path) = if section.is_empty() {
+                (alias.clone(), f.toml_name.clone())
+            } else {
+                (
+                    format!("{}.{}", section, alias),
+                    format!("{}.{}", section, f.toml_name),
+                )
+            };
+            Some((old_path, new_path))
+        })
+        .collect();
+
+    let alias_entries = alias_paths.iter().map(|(old_path, new_path)| {
+        quote! { (#old_path, #new_path), }
+    });
+
+    let alias_checks = alias_paths.iter().map(|(old_path, new_path)| {
+        quote! {
+            if diag.is_present(#old_path) {
+                crate::config::types::status::check_alias(#old_path, #new_path, diag);
+            }
+        }
+    });
+
+    // Generate custom validator calls for #[config(validate = "fn_name")].
+    // Only fields changed from their default are checked, so defaults never fail.
+    let validator_calls: Vec<_> = field_infos
+        .iter()
+        .filter(|f| !f.skip && !f.sub)
+        .filter_map(|f| {
+            let validator = f.validate.as_ref()?;
+            let field_name = &f.name;
+            Some(quote! {
+                if self.#field_name != default.#field_name {
+                    Self::#validator(&self.#field_name, diag);
+                }
+            })
+        })
+        .collect();
+
+    let validator_default = if validator_calls.is_empty() {
+        quote! {}
+    } else {
+        quote! { let default = Self::default(); }
+    };
+
     // Generate recursive calls for nested Config types
     let nested_calls: Vec<_> = field_infos
         .iter()
@@ -128,6 +224,36 @@ pub fn derive(input: &DeriveInput) -> TokenStream {
         })
         .collect();
 
+    // Generate environment variable overrides for #[config(env = "VAR")] fields
+    let env_overrides: Vec<_> = field_infos
+        .iter()
+        .filter(|f| !f.skip && !f.sub)
+        .filter_map(|f| {
+            let var = f.env.as_ref()?;
+            let field_name = &f.name;
+            let full_path = if section.is_empty() {
+                f.toml_name.clone()
+            } else {
+                format!("{}.{}", section, f.toml_name)
+            };
+            Some(quote! {
+                crate::config::types::env::override_field(&mut self.#field_name, #var, #full_path)?;
+            })
+        })
+        .collect();
+
+    // Generate recursive env override calls for nested Config types
+    let nested_env_calls: Vec<_> = field_infos
+        .iter()
+        .filter(|f| !f.skip && f.sub)
+        .map(|f| {
+            let field_name = &f.name;
+            quote! {
+                self.#field_name.from_env()?;
+            }
+        })
+        .collect();
+
     // Generate section-level status check
     let section_status_check = if has_section_status {
         let status_token = match section_status {
@@ -173,6 +299,11 @@ pub fn derive(input: &DeriveInput) -> TokenStream {
             /// Section documentation.
             pub const TEMPLATE_DOC: &'static str = #section_doc;
 
+            /// Renamed fields as `(old_path, new_path)`, from `#[config(alias = "...")]`.
+            pub const DEPRECATED_ALIASES: &'static [(&'static str, &'static str)] = &[
+                #(#alias_entries)*
+            ];
+
             /// Generate TOML template for this config section.
             pub fn template() -> String {
                 let default = Self::default();
@@ -202,13 +333,34 @@ pub fn derive(input: &DeriveInput) -> TokenStream {
                 out
             }
 
-            /// Validate field status (experimental, deprecated, not_implemented).
+            /// Generate JSON Schema object for this config section.
+            #[allow(unused_variables)]
+            pub fn schema() -> serde_json::Value {
+                let default = Self::default();
+                let mut properties = serde_json::Map::new();
+                #schema_code
+                crate::config::types::schema::object(Self::TEMPLATE_DOC, properties)
+            }
+
+            /// Validate field status (experimental, deprecated, not_implemented, renamed)
+            /// and run `#[config(validate = "...")]` field validators.
             #[allow(unused_variables)]
             pub fn validate_field_status(&self, diag: &mut crate::config::ConfigDiagnostics) {
                 #section_status_check
                 #(#status_checks)*
+                #(#alias_checks)*
+                #validator_default
+                #(#validator_calls)*
                 #(#nested_calls)*
             }
+
+            /// Override fields from environment variables (`#[config(env = "...")]`).
+            #[allow(clippy::wrong_self_convention)]
+            pub fn from_env(&mut self) -> ::core::result::Result<(), crate::config::ConfigError> {
+                #(#env_overrides)*
+                #(#nested_env_calls)*
+                Ok(())
+            }
         }
     }
 }
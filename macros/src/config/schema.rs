@@ -0,0 +1,64 @@
+//! JSON Schema generation code for Config derive macro.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::config::field::{FieldInfo, FieldStatus};
+use crate::config::types::type_to_string;
+
+/// Generate schema property insertion code for fields
+pub fn generate_schema_code(fields: &[&FieldInfo]) -> TokenStream {
+    let field_codes: Vec<TokenStream> = fields
+        .iter()
+        .map(|f| generate_field_schema_code(f))
+        .collect();
+
+    quote! {
+        #(#field_codes)*
+    }
+}
+
+/// Generate schema code for a single field
+fn generate_field_schema_code(info: &FieldInfo) -> TokenStream {
+    let field_name = &info.name;
+    let toml_name = &info.toml_name;
+    let field_ty = &info.ty;
+
+    // Nested Config types provide their own schema
+    if info.sub {
+        return quote! {
+            properties.insert(#toml_name.to_string(), <#field_ty>::schema());
+        };
+    }
+
+    let ty_str = type_to_string(&info.ty);
+
+    // Prefer full doc comment, fall back to inline doc
+    let description = match info.doc.as_ref().or(info.inline_doc.as_ref()) {
+        Some(doc) => quote! { Some(#doc) },
+        None => quote! { None },
+    };
+
+    let status = match info.status {
+        FieldStatus::Experimental => {
+            quote! { Some(crate::config::types::FieldStatus::Experimental) }
+        }
+        FieldStatus::NotImplemented => {
+            quote! { Some(crate::config::types::FieldStatus::NotImplemented) }
+        }
+        FieldStatus::Deprecated => quote! { Some(crate::config::types::FieldStatus::Deprecated) },
+        FieldStatus::Normal | FieldStatus::Hidden => quote! { None },
+    };
+
+    quote! {
+        properties.insert(
+            #toml_name.to_string(),
+            crate::config::types::schema::field::<#field_ty>(
+                #ty_str,
+                #description,
+                serde_json::to_value(&default.#field_name).ok(),
+                #status,
+            ),
+        );
+    }
+}
@@ -26,13 +26,20 @@ fn generate_field_template_code(info: &FieldInfo) -> TokenStream {
     // Check if inline_doc is specified
     let has_inline = info.inline_doc.is_some();
 
-    // Doc comment code (always output if present)
-    let doc_code = if let Some(ref doc) = info.doc {
-        let doc_lines: Vec<_> = doc.lines().map(|l| format!("# {}\n", l.trim())).collect();
+    // Doc comment code (always output if present), followed by env override note
+    let mut doc_lines: Vec<_> = info
+        .doc
+        .iter()
+        .flat_map(|doc| doc.lines().map(|l| format!("# {}\n", l.trim())))
+        .collect();
+    if let Some(ref var) = info.env {
+        doc_lines.push(format!("# (env: {}) overrides this value when set\n", var));
+    }
+    let doc_code = if doc_lines.is_empty() {
+        quote! {}
+    } else {
         let doc_str = doc_lines.join("");
         quote! { out.push_str(#doc_str); }
-    } else {
-        quote! {}
     };
 
     // Status handling
@@ -2,7 +2,7 @@
 //!
 //! # Config derive macro
 //!
-//! Generates both field path accessors and TOML template.
+//! Generates field path accessors, TOML template and JSON Schema.
 //!
 //! ```ignore
 //! #[derive(Config)]
@@ -29,18 +29,28 @@
 //! // - SiteInfoConfig::FIELDS.title -> FieldPath("site.info.title")
 //! // - SiteInfoConfig::template() -> TOML string with comments
 //! // - SiteInfoConfig::template_with_header() -> with [section] header
+//! // - SiteInfoConfig::schema() -> JSON Schema object for editor support
+//! // - SiteInfoConfig::from_env() -> apply `#[config(env = "...")]` overrides
 //! ```
 //!
 //! # Attributes
 //!
 //! Struct-level:
 //! - `#[config(section = "path")]` - TOML section path
+//! - `#[config(rename_all = "kebab-case")]` - Use kebab-case TOML names for all fields
+//!   (requires a matching `#[serde(rename_all = "kebab-case")]`; `name` still wins)
 //!
 //! Field-level:
 //! - `#[config(skip)]` - Skip from FIELDS (internal use)
 //! - `#[config(hidden)]` - Hide from template output
 //! - `#[config(name = "x")]` - Custom TOML field name
 //! - `#[config(default = "x")]` - Default value in template
+//! - `#[config(alias = "old")]` - Renamed field, warns when the old name is used
+//!   (requires a matching `#[serde(alias = "old")]`)
+//! - `#[config(env = "VAR")]` - Override from environment variable via `from_env()`
+//! - `#[config(validate = "fn_name")]` - Call `Self::fn_name(&field, diag)` from
+//!   `validate_field_status()` when the field differs from its default
+//!   (signature `fn(&FieldType, &mut ConfigDiagnostics)`)
 //! - `#[config(status = experimental)]` - Mark as experimental
 //! - `#[config(status = not_implemented)]` - Mark as not implemented
 //! - `#[config(status = deprecated)]` - Mark as deprecated
@@ -56,7 +66,7 @@ mod config;
 use proc_macro::TokenStream;
 use syn::{DeriveInput, parse_macro_input};
 
-/// Derive macro that generates FIELDS and template()
+/// Derive macro that generates FIELDS, template(), schema() and from_env()
 #[proc_macro_derive(Config, attributes(config))]
 pub fn derive_config(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -67,6 +67,10 @@ impl CompilerActor {
                 self.on_asset_change(paths).await;
                 bg
             }
+            CompilerMsg::CssChange(paths) => {
+                self.on_css_change(paths).await;
+                bg
+            }
             CompilerMsg::OutputChange(paths) => {
                 self.on_output_change(paths).await;
                 bg
@@ -103,13 +107,29 @@ impl CompilerActor {
         hash_before: u64,
         watched_post_paths: Option<Vec<PathBuf>>,
     ) {
-        if self.state.with_pages(|pages| pages.pages_hash()) != hash_before {
+        // Pages linking to the old URL don't depend on the moved page,
+        // so only a full rebuild refreshes them (and the address space).
+        let permalink_moved = std::mem::take(&mut self.permalink_moved);
+
+        if !permalink_moved && self.state.with_pages(|pages| pages.pages_hash()) != hash_before {
             self.recompile_virtual_users().await;
         }
         if let Some(paths) = watched_post_paths {
             self.run_watched_post_hooks(&paths);
         }
+        let _ = self
+            .vdom_tx
+            .send(VdomMsg::Progress {
+                percent: 100,
+                message: "done".to_string(),
+            })
+            .await;
         let _ = self.vdom_tx.send(VdomMsg::BatchEnd { config }).await;
+
+        if permalink_moved {
+            crate::debug!("compile"; "permalink changed, rebuilding site");
+            self.on_full_rebuild().await;
+        }
     }
 }
 
@@ -4,10 +4,11 @@ use std::time::Instant;
 
 use rustc_hash::FxHashSet;
 
+use super::progress::BuildProgress;
 use super::tasks::spawn_batch;
 use super::utils::{
-    cleanup_removed_assets, format_asset_reason, is_reloadable_output_asset, log_asset_errors,
-    process_assets,
+    cleanup_removed_assets, format_asset_reason, is_css_file, is_reloadable_output_asset,
+    log_asset_errors, process_assets,
 };
 use super::{ACTIVE_RECOMPILE_COOLDOWN, BackgroundTask, CompilerActor};
 use crate::actor::messages::VdomMsg;
@@ -35,15 +36,18 @@ impl CompilerActor {
         let changed_set: FxHashSet<PathBuf> = changed_paths.iter().cloned().collect();
 
         let direct: Vec<_> = queue.direct_files().cloned().collect();
+        let affected: Vec<_> = queue.affected_files().cloned().collect();
+        let progress = BuildProgress::new(self.vdom_tx.clone(), direct.len() + affected.len());
+
         for path in &direct {
             if self.should_skip_noop_change(path, &changed_set, hook_outputs_changed) {
                 crate::debug!("compile"; "skip no-op save: {}", path.display());
-                continue;
+            } else {
+                self.compile_one(path).await;
             }
-            self.compile_one(path).await;
+            progress.page_done();
         }
 
-        let affected: Vec<_> = queue.affected_files().cloned().collect();
         crate::debug!("compile"; "{} direct, {} affected", direct.len(), affected.len());
 
         if affected.is_empty() {
@@ -61,6 +65,7 @@ impl CompilerActor {
                 pages_hash,
                 watched_post_paths,
                 self.page_epoch.ticket(),
+                progress,
             ))
         }
     }
@@ -233,6 +238,46 @@ impl CompilerActor {
         }
     }
 
+    /// Handle stylesheet-only changes: copy, then swap `<link>` hrefs in place.
+    ///
+    /// Avoids recompiling pages; falls back to a reload if processing fails.
+    pub(super) async fn on_css_change(&mut self, paths: Vec<PathBuf>) {
+        use crate::asset::{compute_asset_href, version};
+
+        let config = self.config.current();
+        let count = paths.len();
+        let errors = tokio::task::spawn_blocking({
+            let paths = paths.clone();
+            let config = Arc::clone(&config);
+            move || process_assets(&paths, &config)
+        })
+        .await
+        .unwrap_or_default();
+
+        log_asset_errors(&errors);
+        if !errors.is_empty() {
+            let reason = format_asset_reason(count, errors.len());
+            let _ = self.vdom_tx.send(VdomMsg::Reload { reason }).await;
+            return;
+        }
+
+        let hrefs: Vec<String> = paths
+            .iter()
+            .filter(|path| version::update_version(path))
+            .filter_map(|path| compute_asset_href(path, &config).ok())
+            .collect();
+        self.send_css_reload(hrefs).await;
+    }
+
+    /// Send in-place stylesheet reloads to connected clients.
+    async fn send_css_reload(&mut self, hrefs: Vec<String>) {
+        if hrefs.is_empty() {
+            return;
+        }
+        crate::log!("css"; "{} stylesheets updated, swapping in place", hrefs.len());
+        let _ = self.vdom_tx.send(VdomMsg::CssReload { hrefs }).await;
+    }
+
     pub(super) async fn on_output_change(&mut self, paths: Vec<PathBuf>) {
         use crate::asset::version;
 
@@ -259,6 +304,22 @@ impl CompilerActor {
             return;
         }
 
+        // Generated stylesheets (CSS processor output) are swapped in place
+        if output_assets
+            .iter()
+            .all(|path| path.exists() && is_css_file(path))
+        {
+            let config = self.config.current();
+            let paths = config.paths();
+            let hrefs = output_assets
+                .iter()
+                .filter(|path| version::update_version(path))
+                .filter_map(|path| paths.url_for_path(path))
+                .collect();
+            self.send_css_reload(hrefs).await;
+            return;
+        }
+
         let mut any_changed = false;
         let mut removed_count = 0usize;
         for path in &output_assets {
@@ -331,8 +392,9 @@ impl CompilerActor {
         use crate::asset::version;
         use crate::compiler::dependency::clear_graph;
         use crate::compiler::scheduler::SCHEDULER;
-        use crate::core::{BuildMode, set_healthy};
+        use crate::core::set_healthy;
         use crate::reload::active::ACTIVE_PAGE;
+        use crate::reload::compile::watch_mode;
 
         crate::debug!("compile"; "full rebuild triggered");
         set_healthy(false);
@@ -347,7 +409,7 @@ impl CompilerActor {
         let config = self.config.current();
         let state = Arc::clone(&self.state);
         let result = tokio::task::spawn_blocking(move || {
-            crate::cli::build::build_site(BuildMode::DEVELOPMENT, &config, &state, true)
+            crate::cli::build::build_site(watch_mode(&config), &config, &state, true)
         })
         .await;
 
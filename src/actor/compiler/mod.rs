@@ -7,6 +7,7 @@
 mod dispatch;
 mod handlers;
 mod pipeline;
+mod progress;
 mod tasks;
 mod utils;
 
@@ -46,6 +47,8 @@ pub struct CompilerActor {
     pub(super) last_active_recompile: Option<Instant>,
     pub(super) page_epoch: PageStateEpoch,
     pub(super) typst_host: Option<CachedTypstHost>,
+    /// A page moved to a new permalink; links elsewhere need a full rebuild
+    pub(super) permalink_moved: bool,
 }
 
 impl CompilerActor {
@@ -63,6 +66,7 @@ impl CompilerActor {
             last_active_recompile: None,
             page_epoch: PageStateEpoch::new(),
             typst_host: None,
+            permalink_moved: false,
         }
     }
 
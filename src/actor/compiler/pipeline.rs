@@ -2,13 +2,14 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::actor::messages::VdomMsg;
-use crate::compiler::dependency::{collect_virtual_dependents, flush_current_thread_deps};
+use crate::address::PermalinkUpdate;
+use crate::compiler::dependency::collect_virtual_dependents;
 use crate::compiler::scheduler::SCHEDULER;
 use crate::config::SiteConfig;
-use crate::reload::compile::{CompileOutcome, compile_page};
+use crate::reload::compile::CompileOutcome;
 
 use super::CompilerActor;
-use super::tasks::compile_batch;
+use super::tasks::{compile_batch, compile_page_timed};
 
 impl CompilerActor {
     /// Compile a single file (blocking).
@@ -20,10 +21,8 @@ impl CompilerActor {
         SCHEDULER.invalidate(&path);
 
         let result = tokio::task::spawn_blocking(move || {
-            let outcome = compile_page(&path, &compile_config, &typst_host, &state);
-            // spawn_blocking threads are not rayon workers.
-            flush_current_thread_deps();
-            outcome
+            // Deps are flushed on the compiling thread (not a rayon worker).
+            compile_page_timed(&path, &compile_config, &typst_host, &state, None)
         })
         .await;
 
@@ -74,14 +73,19 @@ impl CompilerActor {
                 vdom,
                 permalink_change,
                 warnings,
-            } => VdomMsg::Process {
-                config,
-                path,
-                url_path,
-                vdom,
-                permalink_change,
-                warnings,
-            },
+            } => {
+                if matches!(permalink_change, Some(PermalinkUpdate::Changed { .. })) {
+                    self.permalink_moved = true;
+                }
+                VdomMsg::Process {
+                    config,
+                    path,
+                    url_path,
+                    vdom,
+                    permalink_change,
+                    warnings,
+                }
+            }
             CompileOutcome::Reload { reason } => VdomMsg::Reload { reason },
             CompileOutcome::Skipped => VdomMsg::Skip,
             CompileOutcome::Error {
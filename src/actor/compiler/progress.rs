@@ -0,0 +1,90 @@
+//! Rebuild progress reporting.
+//!
+//! Counts compiled pages across the direct and background phases of one
+//! compile request and forwards the percentage to the browser. Completion
+//! (100%) is only sent by `finish_batch`, after virtual package users have
+//! been recompiled as well.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+use tokio::sync::mpsc;
+
+use crate::actor::messages::VdomMsg;
+
+/// Highest percentage reported while pages are still compiling
+const MAX_PENDING_PERCENT: u8 = 99;
+
+/// Shared page counter for one compile request
+pub(super) struct BuildProgress {
+    tx: mpsc::Sender<VdomMsg>,
+    total: usize,
+    done: AtomicUsize,
+    last_percent: AtomicU8,
+}
+
+impl BuildProgress {
+    pub(super) fn new(tx: mpsc::Sender<VdomMsg>, total: usize) -> Arc<Self> {
+        Arc::new(Self {
+            tx,
+            total,
+            done: AtomicUsize::new(0),
+            last_percent: AtomicU8::new(0),
+        })
+    }
+
+    /// Record one compiled page, sending an update when the percentage grows.
+    ///
+    /// Called from rayon workers, so the send never blocks: a full channel
+    /// just drops the update, the next page sends a newer one.
+    pub(super) fn page_done(&self) {
+        let done = self.done.fetch_add(1, Ordering::Relaxed) + 1;
+        let percent = pending_percent(done, self.total);
+        if self.last_percent.fetch_max(percent, Ordering::Relaxed) < percent {
+            let _ = self.tx.try_send(VdomMsg::Progress {
+                percent,
+                message: format!("compiled {done}/{} pages", self.total),
+            });
+        }
+    }
+}
+
+fn pending_percent(done: usize, total: usize) -> u8 {
+    if total == 0 {
+        return 0;
+    }
+    let percent = done.min(total) * 100 / total;
+    (percent as u8).min(MAX_PENDING_PERCENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pending_percent() {
+        assert_eq!(pending_percent(0, 0), 0);
+        assert_eq!(pending_percent(1, 4), 25);
+        assert_eq!(pending_percent(1, 3), 33);
+        assert_eq!(pending_percent(4, 4), MAX_PENDING_PERCENT);
+    }
+
+    #[test]
+    fn test_sends_only_increases() {
+        let (tx, mut rx) = mpsc::channel(8);
+        let progress = BuildProgress::new(tx, 200);
+
+        progress.page_done(); // 0% -> not sent
+        progress.page_done(); // 1%
+        progress.page_done(); // still 1%
+
+        match rx.try_recv().unwrap() {
+            VdomMsg::Progress { percent, message } => {
+                assert_eq!(percent, 1);
+                assert_eq!(message, "compiled 2/200 pages");
+            }
+            other => panic!("expected progress, got {other:?}"),
+        }
+        assert!(rx.try_recv().is_err());
+    }
+}
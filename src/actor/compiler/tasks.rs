@@ -1,16 +1,20 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::address::SiteIndex;
-use crate::compiler::dependency::flush_thread_local_deps;
+use crate::compiler::dependency::{flush_current_thread_deps, flush_thread_local_deps};
 use crate::compiler::page::{PageStateTicket, TypstHost};
 use crate::compiler::scheduler::SCHEDULER;
+use crate::compiler::timeout::run_with_timeout;
 use crate::config::SiteConfig;
 use crate::reload::compile::{CompileOutcome, compile_page, compile_page_with_ticket};
 
+use super::progress::BuildProgress;
 use super::{BackgroundTask, BatchResult};
 
 /// Spawn background compilation task.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn spawn_batch(
     paths: Vec<PathBuf>,
     config: Arc<SiteConfig>,
@@ -19,10 +23,18 @@ pub(super) fn spawn_batch(
     pages_hash: u64,
     watched_post_paths: Option<Vec<PathBuf>>,
     ticket: PageStateTicket,
+    progress: Arc<BuildProgress>,
 ) -> BackgroundTask {
     tokio::spawn(async move {
-        let outcomes =
-            compile_batch_with_ticket(paths, Arc::clone(&config), typst_host, state, ticket).await;
+        let outcomes = compile_batch_with_ticket(
+            paths,
+            Arc::clone(&config),
+            typst_host,
+            state,
+            ticket,
+            Some(progress),
+        )
+        .await;
         BatchResult {
             config,
             outcomes,
@@ -39,7 +51,7 @@ pub(super) async fn compile_batch(
     typst_host: Arc<TypstHost>,
     state: Arc<SiteIndex>,
 ) -> Vec<CompileOutcome> {
-    compile_batch_inner(paths, config, typst_host, state, None).await
+    compile_batch_inner(paths, config, typst_host, state, None, None).await
 }
 
 pub(super) async fn compile_batch_with_ticket(
@@ -48,8 +60,9 @@ pub(super) async fn compile_batch_with_ticket(
     typst_host: Arc<TypstHost>,
     state: Arc<SiteIndex>,
     ticket: PageStateTicket,
+    progress: Option<Arc<BuildProgress>>,
 ) -> Vec<CompileOutcome> {
-    compile_batch_inner(paths, config, typst_host, state, Some(ticket)).await
+    compile_batch_inner(paths, config, typst_host, state, Some(ticket), progress).await
 }
 
 async fn compile_batch_inner(
@@ -58,6 +71,7 @@ async fn compile_batch_inner(
     typst_host: Arc<TypstHost>,
     state: Arc<SiteIndex>,
     ticket: Option<PageStateTicket>,
+    progress: Option<Arc<BuildProgress>>,
 ) -> Vec<CompileOutcome> {
     use rayon::prelude::*;
     for path in &paths {
@@ -67,11 +81,13 @@ async fn compile_batch_inner(
     tokio::task::spawn_blocking(move || {
         let results: Vec<_> = paths
             .par_iter()
-            .map(|path| match &ticket {
-                Some(ticket) => {
-                    compile_page_with_ticket(path, &config, &typst_host, &state, ticket)
+            .map(|path| {
+                let outcome =
+                    compile_page_timed(path, &config, &typst_host, &state, ticket.as_ref());
+                if let Some(progress) = &progress {
+                    progress.page_done();
                 }
-                None => compile_page(path, &config, &typst_host, &state),
+                outcome
             })
             .collect();
         flush_thread_local_deps();
@@ -81,6 +97,55 @@ async fn compile_batch_inner(
     .unwrap_or_default()
 }
 
+/// Compile a single page, giving up after `build.compile_timeout_secs`.
+///
+/// A timed-out page is reported as a compile error so the queue keeps moving;
+/// the compilation itself runs to completion on its own thread.
+pub(super) fn compile_page_timed(
+    path: &Path,
+    config: &Arc<SiteConfig>,
+    typst_host: &Arc<TypstHost>,
+    state: &Arc<SiteIndex>,
+    ticket: Option<&PageStateTicket>,
+) -> CompileOutcome {
+    let owned_path = path.to_path_buf();
+    let config_ref = Arc::clone(config);
+    let typst_host = Arc::clone(typst_host);
+    let state = Arc::clone(state);
+    let ticket = ticket.cloned();
+
+    with_compile_timeout(
+        path,
+        config.build.compile_timeout(),
+        move || match &ticket {
+            Some(ticket) => {
+                compile_page_with_ticket(&owned_path, &config_ref, &typst_host, &state, ticket)
+            }
+            None => compile_page(&owned_path, &config_ref, &typst_host, &state),
+        },
+    )
+}
+
+/// Run `compile` for `path`, turning a timeout into [`CompileOutcome::Error`].
+fn with_compile_timeout(
+    path: &Path,
+    timeout: Option<Duration>,
+    compile: impl FnOnce() -> CompileOutcome + Send + 'static,
+) -> CompileOutcome {
+    let result = run_with_timeout(timeout, move || {
+        let outcome = compile();
+        // The compile may run on a non-rayon thread.
+        flush_current_thread_deps();
+        outcome
+    });
+
+    result.unwrap_or_else(|timed_out| CompileOutcome::Error {
+        path: path.to_path_buf(),
+        url_path: None,
+        error: timed_out.to_string(),
+    })
+}
+
 /// Abort background task if running.
 pub(super) fn abort_task(task: &mut Option<BackgroundTask>) {
     if let Some(t) = task.take() {
@@ -117,6 +182,39 @@ mod tests {
         state.clear();
     }
 
+    #[test]
+    fn compile_timeout_reports_error_for_slow_compile() {
+        let path = PathBuf::from("content/slow.typ");
+        let outcome = with_compile_timeout(&path, Some(Duration::from_millis(20)), || {
+            std::thread::sleep(Duration::from_secs(2));
+            CompileOutcome::Skipped
+        });
+
+        match outcome {
+            CompileOutcome::Error {
+                path: error_path,
+                error,
+                ..
+            } => {
+                assert_eq!(error_path, path);
+                assert!(error.contains("timed out after 20ms"), "{error}");
+            }
+            _ => panic!("expected timeout error"),
+        }
+    }
+
+    #[test]
+    fn compile_timeout_passes_through_fast_compile() {
+        let path = PathBuf::from("content/fast.typ");
+        let outcome = with_compile_timeout(&path, Some(Duration::from_secs(5)), || {
+            CompileOutcome::Skipped
+        });
+        assert!(matches!(outcome, CompileOutcome::Skipped));
+
+        let outcome = with_compile_timeout(&path, None, || CompileOutcome::Skipped);
+        assert!(matches!(outcome, CompileOutcome::Skipped));
+    }
+
     #[tokio::test]
     async fn compile_batch_with_stale_ticket_does_not_commit_page_state() {
         let dir = TempDir::new().unwrap();
@@ -147,6 +245,7 @@ mod tests {
             typst_host,
             Arc::clone(&state),
             ticket,
+            None,
         )
         .await;
 
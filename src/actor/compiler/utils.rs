@@ -10,7 +10,7 @@ pub(super) fn process_assets(paths: &[PathBuf], config: &SiteConfig) -> Vec<(Pat
         .filter_map(|path| {
             let result = if config.build.assets.contains_source(path) {
                 process_asset(path, config, false, true)
-            } else if path.starts_with(&config.build.content) {
+            } else if config.build.is_content_path(path) {
                 process_rel_asset(path, config, false, true)
             } else {
                 process_asset(path, config, false, true)
@@ -90,6 +90,40 @@ fn remove_empty_parent(output: &Path) {
     }
 }
 
+pub(super) fn log_asset_errors(errors: &[(PathBuf, String)]) {
+    for (path, error) in errors {
+        crate::log!("error"; "asset {}: {}", path.display(), error);
+    }
+}
+
+pub(super) fn is_reloadable_output_asset(path: &Path) -> bool {
+    !matches!(
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_ascii_lowercase())
+            .as_deref(),
+        Some("html" | "htm")
+    )
+}
+
+pub(super) fn is_css_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("css"))
+}
+
+pub(super) fn format_asset_reason(total: usize, error_count: usize) -> String {
+    if error_count == 0 {
+        format!("{} assets updated", total)
+    } else {
+        format!(
+            "{} assets updated, {} errors",
+            total - error_count,
+            error_count
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,31 +176,3 @@ mod tests {
         )));
     }
 }
-
-pub(super) fn log_asset_errors(errors: &[(PathBuf, String)]) {
-    for (path, error) in errors {
-        crate::log!("error"; "asset {}: {}", path.display(), error);
-    }
-}
-
-pub(super) fn is_reloadable_output_asset(path: &Path) -> bool {
-    !matches!(
-        path.extension()
-            .and_then(|ext| ext.to_str())
-            .map(|ext| ext.to_ascii_lowercase())
-            .as_deref(),
-        Some("html" | "htm")
-    )
-}
-
-pub(super) fn format_asset_reason(total: usize, error_count: usize) -> String {
-    if error_count == 0 {
-        format!("{} assets updated", total)
-    } else {
-        format!(
-            "{} assets updated, {} errors",
-            total - error_count,
-            error_count
-        )
-    }
-}
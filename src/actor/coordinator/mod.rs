@@ -4,6 +4,9 @@
 //! - Creates communication channels
 //! - Wires up actors
 //! - Runs them concurrently
+//!
+//! Without a WebSocket port (`build --watch`) the WsActor is not started;
+//! messages meant for browsers are dropped.
 
 mod runtime;
 
@@ -29,6 +32,7 @@ pub struct Coordinator {
     config: ConfigHandle,
     state: Arc<SiteIndex>,
     ws_port: Option<u16>,
+    ws_tls: Option<Arc<rustls::ServerConfig>>,
     ws_server: Option<WsServerHandle>,
     shutdown_rx: Option<Receiver<()>>,
 }
@@ -40,6 +44,7 @@ impl Coordinator {
             config,
             state,
             ws_port: None,
+            ws_tls: None,
             ws_server: None,
             shutdown_rx: None,
         }
@@ -51,6 +56,12 @@ impl Coordinator {
         self
     }
 
+    /// Serve the WebSocket over TLS (`wss://`).
+    pub fn with_ws_tls(mut self, tls: Arc<rustls::ServerConfig>) -> Self {
+        self.ws_tls = Some(tls);
+        self
+    }
+
     /// Set shutdown signal receiver.
     pub fn with_shutdown_signal(mut self, rx: Receiver<()>) -> Self {
         self.shutdown_rx = Some(rx);
@@ -92,7 +103,13 @@ impl Coordinator {
             self.state.clone(),
         );
 
-        let ws_actor = WsActor::new(ws_rx).with_pending_errors(restored_errors);
+        let ws_actor = self.ws_port.map(|_| {
+            let ws_actor = WsActor::new(ws_rx).with_pending_errors(restored_errors);
+            match self.ws_tls.take() {
+                Some(tls) => ws_actor.with_tls(tls),
+                None => ws_actor,
+            }
+        });
         crate::debug!("vdom"; "cache: {} entries", restored_count);
 
         if !restored_warnings.is_empty() {
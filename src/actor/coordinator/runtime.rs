@@ -11,11 +11,12 @@ use crate::actor::ws::WsActor;
 use crate::reload::server::WsServerHandle;
 
 /// Run all actors concurrently.
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn run_actors(
     fs: FsActor,
     compiler: CompilerActor,
     vdom: VdomActor,
-    ws: WsActor,
+    ws: Option<WsActor>,
     ws_server: Option<WsServerHandle>,
     compiler_tx: mpsc::Sender<CompilerMsg>,
     vdom_tx: mpsc::Sender<VdomMsg>,
@@ -25,7 +26,7 @@ pub(super) async fn run_actors(
     let mut vdom_handle = tokio::spawn(async move { vdom.run().await });
     let mut fs_handle = tokio::spawn(async move { fs.run().await });
     let mut compiler_handle = tokio::spawn(async move { compiler.run().await });
-    let mut ws_handle = tokio::spawn(async move { ws.run().await });
+    let mut ws_handle = ws.map(|ws| tokio::spawn(async move { ws.run().await }));
 
     let mut result = Ok(());
     let mut finished_actor = None;
@@ -45,7 +46,7 @@ pub(super) async fn run_actors(
                     finished_actor = Some("compiler");
                     result = actor_join_result("compiler", join);
                 }
-                join = &mut ws_handle => {
+                join = join_optional(&mut ws_handle) => {
                     finished_actor = Some("ws");
                     result = actor_join_result("ws", join);
                 }
@@ -68,7 +69,7 @@ pub(super) async fn run_actors(
                     finished_actor = Some("compiler");
                     result = actor_join_result("compiler", join);
                 }
-                join = &mut ws_handle => {
+                join = join_optional(&mut ws_handle) => {
                     finished_actor = Some("ws");
                     result = actor_join_result("ws", join);
                 }
@@ -95,7 +96,9 @@ pub(super) async fn run_actors(
             wait_for_actor("compiler", compiler_handle, timeout).await,
         );
     }
-    if finished_actor != Some("ws") {
+    if finished_actor != Some("ws")
+        && let Some(ws_handle) = ws_handle
+    {
         record_shutdown_result(&mut result, wait_for_actor("ws", ws_handle, timeout).await);
     }
     if finished_actor != Some("vdom") {
@@ -120,6 +123,16 @@ async fn wait_for_shutdown(rx: Receiver<()>) {
     }
 }
 
+/// Join an actor that may not have been started; never resolves if absent
+async fn join_optional(
+    handle: &mut Option<tokio::task::JoinHandle<()>>,
+) -> std::result::Result<(), JoinError> {
+    match handle {
+        Some(handle) => handle.await,
+        None => std::future::pending().await,
+    }
+}
+
 fn actor_join_result(actor: &str, result: std::result::Result<(), JoinError>) -> Result<()> {
     result.map_err(|err| anyhow!("{actor} actor task failed: {err}"))
 }
@@ -9,7 +9,7 @@ use crate::utils::path::normalize_path;
 
 /// Classifies raw debounced events into final DebouncedEvents.
 ///
-/// Pipeline: correct_by_existence → recover_from_dir_events → promote_untracked → filter_actionable
+/// Pipeline: drop_ignored → correct_by_existence → recover_from_dir_events → promote_untracked → filter_actionable
 pub(super) struct EventClassifier;
 
 impl EventClassifier {
@@ -21,6 +21,7 @@ impl EventClassifier {
     ) -> Option<DebouncedEvents> {
         let mut changes = raw;
 
+        Self::drop_ignored(&mut changes, config);
         Self::correct_by_existence(&mut changes);
         Self::recover_from_dir_events(&mut changes, state);
         Self::promote_untracked(&mut changes, config, state);
@@ -32,6 +33,12 @@ impl EventClassifier {
         Some(DebouncedEvents(changes.into_iter().collect()))
     }
 
+    /// Drop events for paths matching `serve.watch_ignore`.
+    fn drop_ignored(changes: &mut FxHashMap<PathBuf, ChangeKind>, config: &SiteConfig) {
+        let root = config.get_root();
+        changes.retain(|path, _| !config.serve.is_watch_ignored(path, root));
+    }
+
     /// Reconcile event kinds with actual filesystem state.
     ///
     /// The watcher may report stale events (e.g., Created for a file that's already
@@ -158,6 +165,28 @@ impl EventClassifier {
         }
     }
 
+    /// Check whether a changed file is a stylesheet that can be hot-swapped.
+    ///
+    /// Matches `.css` assets and `site.header.styles` entries. CSS processor
+    /// input is excluded, since its output is regenerated by the hook.
+    pub(super) fn is_stylesheet(path: &Path, config: &SiteConfig) -> bool {
+        use crate::reload::classify::{FileCategory, categorize_path};
+
+        let is_css = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("css"));
+        if !is_css || crate::hooks::css::is_css_input(path, config) {
+            return false;
+        }
+
+        config
+            .site
+            .header
+            .lists_style(&normalize_path(path), config.get_root())
+            || matches!(categorize_path(path, config), FileCategory::Asset)
+    }
+
     /// Filter to actionable events only.
     ///
     /// - Created/Modified: must be a file (not a directory)
@@ -215,6 +244,24 @@ mod tests {
         (temp, config)
     }
 
+    #[test]
+    fn classify_drops_ignored_paths() {
+        let (_tmp, config) = make_config();
+        let ignored = config.build.content.join("node_modules").join("pkg.js");
+        let kept = config.build.content.join("post.typ");
+        std::fs::create_dir_all(ignored.parent().unwrap()).unwrap();
+        std::fs::write(&ignored, "").unwrap();
+        std::fs::write(&kept, "").unwrap();
+
+        let mut changes = FxHashMap::default();
+        changes.insert(ignored.clone(), ChangeKind::Modified);
+        changes.insert(kept.clone(), ChangeKind::Modified);
+        EventClassifier::drop_ignored(&mut changes, &config);
+
+        assert!(!changes.contains_key(&ignored));
+        assert!(changes.contains_key(&kept));
+    }
+
     #[test]
     fn filter_actionable_keeps_removed_output() {
         let (_tmp, config) = make_config();
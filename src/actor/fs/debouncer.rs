@@ -6,12 +6,13 @@ use rustc_hash::FxHashMap;
 use super::types::ChangeKind;
 use crate::utils::path::normalize_path;
 
-pub(super) const DEBOUNCE_MS: u64 = 300;
 pub(super) const REBUILD_COOLDOWN_MS: u64 = 800;
 
 /// Pure debouncer: only handles timing and event deduplication.
 /// No business logic, no global state access.
 pub(super) struct Debouncer {
+    /// Quiet time after the last event (`serve.watch_debounce_ms`)
+    debounce: Duration,
     /// Path → ChangeKind (dedup is free via HashMap key uniqueness)
     pub(super) changes: FxHashMap<std::path::PathBuf, ChangeKind>,
     pub(super) last_event: Option<std::time::Instant>,
@@ -19,8 +20,9 @@ pub(super) struct Debouncer {
 }
 
 impl Debouncer {
-    pub(super) fn new() -> Self {
+    pub(super) fn new(debounce: Duration) -> Self {
         Self {
+            debounce,
             changes: FxHashMap::default(),
             last_event: None,
             last_compile: None,
@@ -117,7 +119,7 @@ impl Debouncer {
             return false;
         };
 
-        if last_event.elapsed() < Duration::from_millis(DEBOUNCE_MS) {
+        if last_event.elapsed() < self.debounce {
             return false;
         }
 
@@ -136,8 +138,7 @@ impl Debouncer {
             return Duration::from_secs(86400);
         };
 
-        let debounce_remaining =
-            Duration::from_millis(DEBOUNCE_MS).saturating_sub(last_event.elapsed());
+        let debounce_remaining = self.debounce.saturating_sub(last_event.elapsed());
 
         let cooldown_remaining = self
             .last_compile
@@ -167,6 +168,12 @@ mod tests {
 
     use super::*;
 
+    const DEBOUNCE_MS: u64 = 300;
+
+    fn new_debouncer() -> Debouncer {
+        Debouncer::new(Duration::from_millis(DEBOUNCE_MS))
+    }
+
     fn make_event(paths: Vec<&str>, kind: notify::EventKind) -> notify::Event {
         notify::Event {
             kind,
@@ -199,7 +206,7 @@ mod tests {
 
     #[test]
     fn stores_non_temp_events_by_path() {
-        let mut debouncer = Debouncer::new();
+        let mut debouncer = new_debouncer();
         assert!(!debouncer.is_ready());
 
         add_event(&mut debouncer, "/tmp/a.typ", create_kind());
@@ -214,7 +221,7 @@ mod tests {
 
     #[test]
     fn ignores_temp_files_without_refreshing_debounce_window() {
-        let mut debouncer = Debouncer::new();
+        let mut debouncer = new_debouncer();
 
         add_event(&mut debouncer, "/tmp/real.typ", modify_kind());
         assert!(debouncer.last_event.is_some());
@@ -229,7 +236,7 @@ mod tests {
 
     #[test]
     fn first_create_or_modify_event_wins_for_same_path() {
-        let mut debouncer = Debouncer::new();
+        let mut debouncer = new_debouncer();
 
         add_event(&mut debouncer, "/tmp/a.typ", create_kind());
         add_event(&mut debouncer, "/tmp/a.typ", modify_kind());
@@ -240,7 +247,7 @@ mod tests {
 
     #[test]
     fn deduplicates_same_notify_event_paths() {
-        let mut debouncer = Debouncer::new();
+        let mut debouncer = new_debouncer();
 
         debouncer.add_event(&make_event(vec!["/tmp/a.typ", "/tmp/a.typ"], modify_kind()));
 
@@ -249,14 +256,14 @@ mod tests {
 
     #[test]
     fn sleep_duration_without_events_is_idle() {
-        let debouncer = Debouncer::new();
+        let debouncer = new_debouncer();
 
         assert!(debouncer.sleep_duration() >= Duration::from_secs(3600));
     }
 
     #[test]
     fn sleep_duration_after_event_tracks_debounce_window() {
-        let mut debouncer = Debouncer::new();
+        let mut debouncer = new_debouncer();
         debouncer.last_event = Some(std::time::Instant::now());
 
         let dur = debouncer.sleep_duration();
@@ -265,9 +272,23 @@ mod tests {
         assert!(dur <= Duration::from_millis(DEBOUNCE_MS + 10));
     }
 
+    #[test]
+    fn sleep_duration_uses_configured_debounce() {
+        let mut debouncer = Debouncer::new(Duration::from_millis(50));
+        debouncer.last_event = Some(std::time::Instant::now());
+
+        assert!(debouncer.sleep_duration() <= Duration::from_millis(50));
+
+        debouncer.last_event = Some(std::time::Instant::now() - Duration::from_millis(60));
+        debouncer
+            .changes
+            .insert(PathBuf::from("/tmp/a.typ"), ChangeKind::Modified);
+        assert!(debouncer.is_ready());
+    }
+
     #[test]
     fn sleep_duration_respects_rebuild_cooldown() {
-        let mut debouncer = Debouncer::new();
+        let mut debouncer = new_debouncer();
         debouncer.last_event = Some(std::time::Instant::now());
         debouncer.last_compile = Some(std::time::Instant::now());
 
@@ -279,14 +300,14 @@ mod tests {
 
     #[test]
     fn event_state_transitions_preserve_effective_change() {
-        let mut restored = Debouncer::new();
+        let mut restored = new_debouncer();
         add_event(&mut restored, "/tmp/a.typ", remove_kind());
         assert_change_kind(&restored, "/tmp/a.typ", ChangeKind::Removed);
         add_event(&mut restored, "/tmp/a.typ", create_kind());
         assert_eq!(restored.changes.len(), 1);
         assert_change_kind(&restored, "/tmp/a.typ", ChangeKind::Created);
 
-        let mut discarded = Debouncer::new();
+        let mut discarded = new_debouncer();
         add_event(&mut discarded, "/tmp/a.typ", create_kind());
         assert_change_kind(&discarded, "/tmp/a.typ", ChangeKind::Created);
         add_event(&mut discarded, "/tmp/a.typ", remove_kind());
@@ -295,7 +316,7 @@ mod tests {
             "created+removed should discard"
         );
 
-        let mut upgraded = Debouncer::new();
+        let mut upgraded = new_debouncer();
         add_event(&mut upgraded, "/tmp/a.typ", modify_kind());
         add_event(&mut upgraded, "/tmp/a.typ", remove_kind());
         assert_eq!(upgraded.changes.len(), 1);
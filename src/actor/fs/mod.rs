@@ -82,7 +82,7 @@ impl FsActor {
             watcher,
             watch_roots,
             compiler_tx,
-            debouncer: Debouncer::new(),
+            debouncer: Debouncer::new(current_config.serve.watch_debounce()),
             config,
             state,
         })
@@ -118,11 +118,17 @@ impl FsActor {
         loop {
             tokio::select! {
                 biased;
-                Some(event) = async_rx.recv() => debouncer.add_event(&event),
+                Some(event) = async_rx.recv() => {
+                    watch_roots.note_event(&event);
+                    debouncer.add_event(&event);
+                }
                 _ = tokio::time::sleep(debouncer.sleep_duration()) => {
-                    // Ensure watcher roots remain attached.
+                    // Ensure watcher roots remain attached, including new directories.
+                    // Files created before a new directory was watched are rescanned.
                     let current_config = config.current();
-                    watch_roots.sync_config(&mut watcher, &current_config);
+                    if let Some(recovered) = watch_roots.sync_config(&mut watcher, &current_config) {
+                        debouncer.add_event(&recovered);
+                    }
                     // Classify recovered events and route messages.
                     if process_changes(&mut debouncer, &compiler_tx, &current_config, &state).await.is_err() {
                         break;
@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use rustc_hash::FxHashSet;
 
+use super::classifier::EventClassifier;
 use super::types::DebouncedEvents;
 use crate::actor::messages::CompilerMsg;
 use crate::address::SiteIndex;
@@ -77,8 +78,16 @@ pub(super) fn events_to_messages(
         && !result.asset_changed.is_empty()
         && crate::hooks::has_watched_hooks(config, &changed_refs);
 
-    if !result.asset_changed.is_empty() {
-        messages.push(CompilerMsg::AssetChange(result.asset_changed));
+    // Existing stylesheets are swapped in place; removals need a recompile
+    let (css_changed, asset_changed): (Vec<_>, Vec<_>) = result
+        .asset_changed
+        .into_iter()
+        .partition(|p| p.exists() && EventClassifier::is_stylesheet(p, config));
+    if !css_changed.is_empty() {
+        messages.push(CompilerMsg::CssChange(css_changed));
+    }
+    if !asset_changed.is_empty() {
+        messages.push(CompilerMsg::AssetChange(asset_changed));
     }
 
     // Output changes are tracked separately:
@@ -191,6 +200,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn existing_stylesheet_change_enqueues_css_change() {
+        let (_tmp, mut config) = make_config();
+        let root = config.get_root().to_path_buf();
+        config.build.assets.normalize(&root);
+        let css = root.join("assets/styles/main.css");
+        let image = root.join("assets/logo.png");
+        std::fs::create_dir_all(css.parent().unwrap()).unwrap();
+        std::fs::write(&css, "body{}").unwrap();
+        std::fs::write(&image, "png").unwrap();
+
+        let events = DebouncedEvents(vec![
+            (css.clone(), ChangeKind::Modified),
+            (image.clone(), ChangeKind::Modified),
+        ]);
+        let state = SiteIndex::new();
+        let messages = events_to_messages(events, &config, &state);
+
+        let css_paths = messages.iter().find_map(|msg| match msg {
+            CompilerMsg::CssChange(paths) => Some(paths.clone()),
+            _ => None,
+        });
+        let asset_paths = messages.iter().find_map(|msg| match msg {
+            CompilerMsg::AssetChange(paths) => Some(paths.clone()),
+            _ => None,
+        });
+        assert_eq!(css_paths, Some(vec![css]));
+        assert_eq!(asset_paths, Some(vec![image]));
+    }
+
+    #[test]
+    fn css_processor_input_is_not_a_stylesheet() {
+        let (_tmp, mut config) = make_config();
+        let root = config.get_root().to_path_buf();
+        config.build.assets.normalize(&root);
+        let input = root.join("assets/styles/tailwind.css");
+        config.build.hooks.css.enable = true;
+        config.build.hooks.css.path = Some(input.clone());
+
+        assert!(!EventClassifier::is_stylesheet(&input, &config));
+        assert!(EventClassifier::is_stylesheet(
+            &root.join("assets/styles/main.css"),
+            &config
+        ));
+    }
+
     #[test]
     fn created_asset_enqueues_asset_change() {
         let (_tmp, mut config) = make_config();
@@ -248,6 +303,7 @@ mod tests {
             watch: WatchMode::Bool(true),
             build_args: vec![],
             quiet: true,
+            env: Default::default(),
         });
 
         let asset = config.get_root().join("assets/styles/tailwind.css");
@@ -1,6 +1,7 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use notify::event::CreateKind;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use rustc_hash::FxHashSet;
 
 use super::is_transient_not_found;
@@ -12,9 +13,13 @@ use crate::config::SiteConfig;
 /// - Attach existing roots at startup
 /// - Re-attach roots that were removed and recreated
 /// - Track root changes after config reload
+/// - Attach directories created while serving, rescanning their files
 pub(super) struct WatchRoots {
     desired: Vec<PathBuf>,
+    /// Every watched path: roots and directories created under them.
     attached: FxHashSet<PathBuf>,
+    /// Directories created since the last tick, not yet attached.
+    created: Vec<PathBuf>,
 }
 
 impl WatchRoots {
@@ -22,6 +27,7 @@ impl WatchRoots {
         Self {
             desired: paths,
             attached: FxHashSet::default(),
+            created: Vec::new(),
         }
     }
 
@@ -71,7 +77,31 @@ impl WatchRoots {
         Ok(())
     }
 
-    pub(super) fn maintain(&mut self, watcher: &mut RecommendedWatcher) {
+    /// Record directories reported as created, to be attached on the next tick.
+    pub(super) fn note_event(&mut self, event: &notify::Event) {
+        let EventKind::Create(kind) = event.kind else {
+            return;
+        };
+
+        for path in &event.paths {
+            // Some backends (kqueue, Windows) don't tell files and folders apart
+            let is_dir = match kind {
+                CreateKind::Folder => true,
+                CreateKind::Any | CreateKind::Other => path.is_dir(),
+                CreateKind::File => false,
+            };
+            if is_dir && !self.created.contains(path) {
+                self.created.push(path.clone());
+            }
+        }
+    }
+
+    /// Re-attach missing roots and attach newly created directories.
+    ///
+    /// Files written to a new directory before its watch took effect produced
+    /// no event, so each attached directory is scanned; the returned `Create`
+    /// event carries the files found.
+    pub(super) fn maintain(&mut self, watcher: &mut RecommendedWatcher) -> Option<notify::Event> {
         // Drop stale handles for roots that no longer exist.
         self.attached.retain(|path| path.exists());
 
@@ -85,15 +115,48 @@ impl WatchRoots {
                 crate::debug!("watch"; "re-attached watch: {}", path.display());
             }
         }
+
+        let mut created = std::mem::take(&mut self.created);
+        // Parents first: `mkdir -p a/b` only needs `a`
+        created.sort();
+
+        let mut attached_now: Vec<PathBuf> = Vec::new();
+        let mut recovered = Vec::new();
+        for dir in created {
+            if self.attached.contains(&dir)
+                || attached_now.iter().any(|parent| dir.starts_with(parent))
+                || !dir.is_dir()
+                || !self.desired.iter().any(|root| dir.starts_with(root))
+            {
+                continue;
+            }
+
+            if watcher.watch(&dir, RecursiveMode::Recursive).is_ok() {
+                crate::debug!("watch"; "attached new directory: {}", dir.display());
+                collect_files(&dir, &mut recovered);
+                self.attached.insert(dir.clone());
+                attached_now.push(dir);
+            }
+        }
+
+        (!recovered.is_empty()).then(|| notify::Event {
+            kind: EventKind::Create(CreateKind::File),
+            paths: recovered,
+            attrs: Default::default(),
+        })
     }
 
-    pub(super) fn sync_config(&mut self, watcher: &mut RecommendedWatcher, config: &SiteConfig) {
+    pub(super) fn sync_config(
+        &mut self,
+        watcher: &mut RecommendedWatcher,
+        config: &SiteConfig,
+    ) -> Option<notify::Event> {
         let desired = collect_watch_paths(config);
-        let desired_set: FxHashSet<PathBuf> = desired.iter().cloned().collect();
+        // Directories attached under a root stay until the root goes away
         let stale: Vec<PathBuf> = self
             .attached
             .iter()
-            .filter(|path| !desired_set.contains(*path))
+            .filter(|path| !desired.iter().any(|root| path.starts_with(root)))
             .cloned()
             .collect();
 
@@ -104,14 +167,40 @@ impl WatchRoots {
             self.attached.remove(&path);
         }
 
+        // Output changes come from our own builds; ignored directories are never compiled
+        let root = config.get_root();
+        let output_dir = config.paths().output_dir();
+        self.created.retain(|dir| {
+            !dir.starts_with(&output_dir) && !config.serve.is_watch_ignored(dir, root)
+        });
+
         self.desired = desired;
-        self.maintain(watcher);
+        self.maintain(watcher)
+    }
+}
+
+/// Collect files below `dir`, without following symlinked directories
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if entry.file_type().is_ok_and(|t| t.is_dir()) {
+            collect_files(&path, files);
+        } else {
+            files.push(path);
+        }
     }
 }
 
 fn collect_watch_paths(config: &SiteConfig) -> Vec<PathBuf> {
     let root = config.get_root();
-    let mut paths = vec![root.join(&config.build.content)];
+    let mut paths: Vec<PathBuf> = config
+        .build
+        .content_dirs()
+        .map(|dir| root.join(dir))
+        .collect();
     for dep in &config.build.deps {
         paths.push(root.join(dep));
     }
@@ -134,6 +223,10 @@ fn collect_watch_paths(config: &SiteConfig) -> Vec<PathBuf> {
     if config.config_path.exists() {
         paths.push(config.config_path.clone());
     }
+    let local_config = config.local_config_path();
+    if local_config.exists() {
+        paths.push(local_config);
+    }
 
     let output_dir = config.paths().output_dir();
     let _ = std::fs::create_dir_all(&output_dir);
@@ -143,6 +236,9 @@ fn collect_watch_paths(config: &SiteConfig) -> Vec<PathBuf> {
 
     dedupe_output_children(&mut paths, &output_dir);
 
+    // Recursive watches can't exclude subtrees, but whole ignored roots can be skipped
+    paths.retain(|path| !config.serve.is_watch_ignored(path, root));
+
     paths
 }
 
@@ -156,8 +252,90 @@ fn dedupe_output_children(paths: &mut Vec<PathBuf>, output_root: &std::path::Pat
 
 #[cfg(test)]
 mod tests {
-    use super::dedupe_output_children;
+    use super::super::debouncer::Debouncer;
+    use super::super::types::ChangeKind;
+    use super::{WatchRoots, dedupe_output_children};
+    use crate::utils::path::normalize_path;
+    use notify::event::CreateKind;
+    use notify::{Event, EventKind, RecommendedWatcher};
+    use std::fs;
     use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn watcher() -> RecommendedWatcher {
+        notify::recommended_watcher(|_| {}).unwrap()
+    }
+
+    fn folder_created(path: PathBuf) -> Event {
+        Event::new(EventKind::Create(CreateKind::Folder)).add_path(path)
+    }
+
+    #[test]
+    fn attaches_created_directory_and_recovers_files() {
+        let dir = TempDir::new().unwrap();
+        let content = dir.path().join("content");
+        fs::create_dir(&content).unwrap();
+
+        let mut watcher = watcher();
+        let mut roots = WatchRoots::new(vec![content.clone()]);
+        roots.attach_existing(&mut watcher).unwrap();
+        assert!(roots.maintain(&mut watcher).is_none());
+
+        // Files written before the new directory is watched
+        let section = content.join("new-section");
+        fs::create_dir_all(section.join("nested")).unwrap();
+        fs::write(section.join("post.typ"), "= Post").unwrap();
+        fs::write(section.join("nested/deep.typ"), "= Deep").unwrap();
+
+        roots.note_event(&folder_created(section.clone()));
+        roots.note_event(&folder_created(section.join("nested")));
+        let event = roots.maintain(&mut watcher).expect("rescan event");
+        assert!(roots.attached.contains(&section));
+        // Covered by the recursive watch on its parent
+        assert!(!roots.attached.contains(&section.join("nested")));
+
+        let mut paths = event.paths.clone();
+        paths.sort();
+        assert_eq!(
+            paths,
+            [section.join("nested/deep.typ"), section.join("post.typ")]
+        );
+
+        // The recovered files reach the compiler as created pages
+        let mut debouncer =
+            Debouncer::new(crate::config::section::ServeConfig::default().watch_debounce());
+        debouncer.add_event(&event);
+        assert_eq!(
+            debouncer.changes[&normalize_path(&section.join("post.typ"))],
+            ChangeKind::Created
+        );
+
+        // Already attached: no duplicate watch, no second rescan
+        roots.note_event(&folder_created(section.clone()));
+        assert!(roots.maintain(&mut watcher).is_none());
+    }
+
+    #[test]
+    fn ignores_created_files_and_directories_outside_roots() {
+        let dir = TempDir::new().unwrap();
+        let content = dir.path().join("content");
+        let outside = dir.path().join("outside");
+        fs::create_dir_all(&content).unwrap();
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("a.typ"), "").unwrap();
+        fs::write(content.join("b.typ"), "").unwrap();
+
+        let mut watcher = watcher();
+        let mut roots = WatchRoots::new(vec![content.clone()]);
+        roots.attach_existing(&mut watcher).unwrap();
+
+        roots.note_event(&folder_created(outside.clone()));
+        roots.note_event(
+            &Event::new(EventKind::Create(CreateKind::File)).add_path(content.join("b.typ")),
+        );
+        assert!(roots.maintain(&mut watcher).is_none());
+        assert!(!roots.attached.contains(&outside));
+    }
 
     #[test]
     fn keeps_output_root_and_drops_descendants() {
@@ -38,6 +38,8 @@ pub enum CompilerMsg {
     ContentRemoved(Vec<PathBuf>),
     /// Process asset changes (copy files, trigger reload)
     AssetChange(Vec<PathBuf>),
+    /// Stylesheet-only asset changes (copy files, swap `<link>` in place)
+    CssChange(Vec<PathBuf>),
     /// Output file changes (usually hook-generated artifacts)
     OutputChange(Vec<PathBuf>),
     /// Retry scan after initial failure (triggered by file change when !is_healthy)
@@ -70,6 +72,10 @@ pub enum VdomMsg {
     },
     /// Trigger reload
     Reload { reason: String },
+    /// Reload stylesheets in place (hrefs without version query)
+    CssReload { hrefs: Vec<String> },
+    /// Rebuild progress (100 = batch finished)
+    Progress { percent: u8, message: String },
     /// Compilation error (display via VdomActor's WatchStatus for proper overwrite)
     Error {
         path: PathBuf,
@@ -113,6 +119,10 @@ pub enum WsMsg {
         /// If set, browser updates URL before reload
         url_change: Option<UrlChange>,
     },
+    /// Swap a stylesheet `<link>` without reloading the page
+    CssReload { href: String },
+    /// Rebuild progress (100 = batch finished)
+    Progress { percent: u8, message: String },
     /// Compilation error (display overlay, no reload)
     Error { path: String, error: String },
     /// Clear error for a specific file path
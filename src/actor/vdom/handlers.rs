@@ -113,9 +113,11 @@ impl VdomActor {
 
         // Compute diff (now using new_url as key, which has the renamed cache entry)
         let key = CacheKey::new(url_path.as_str());
-        let result =
-            tokio::task::spawn_blocking(move || compute_diff_shared(&BUILD_CACHE, key, new_vdom))
-                .await;
+        let granularity = config.serve.diff.granularity;
+        let result = tokio::task::spawn_blocking(move || {
+            compute_diff_shared(&BUILD_CACHE, key, new_vdom, granularity)
+        })
+        .await;
 
         let outcome = match result {
             Ok(outcome) => outcome,
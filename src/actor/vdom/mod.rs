@@ -31,8 +31,8 @@ use batch::BatchLogger;
 use super::messages::{VdomMsg, WsMsg};
 use crate::address::SiteIndex;
 use crate::cache::{
-    PersistedDiagnostics, persist_cache, persist_diagnostics, restore_cache,
-    restore_dependency_graph, restore_diagnostics,
+    DIAGNOSTICS_MAX_AGE_SECS, PersistedDiagnostics, persist_cache, persist_diagnostics,
+    restore_cache, restore_dependency_graph, restore_diagnostics,
 };
 use crate::compiler::page::BUILD_CACHE;
 
@@ -83,8 +83,17 @@ impl VdomActor {
             crate::debug!("vdom"; "dependency graph restore failed: {}", e);
         }
 
-        // Restore diagnostics from disk
-        let error_state = restore_diagnostics(&root).unwrap_or_default();
+        // Restore diagnostics from disk, dropping those from another tola version
+        let mut error_state =
+            restore_diagnostics(&root, DIAGNOSTICS_MAX_AGE_SECS).unwrap_or_default();
+        if !error_state.is_empty() && !error_state.is_current_version() {
+            crate::debug!(
+                "vdom";
+                "discarding diagnostics from tola {}",
+                error_state.tola_version
+            );
+            error_state = PersistedDiagnostics::new();
+        }
 
         let restored_errors: Vec<_> = error_state
             .errors()
@@ -136,6 +145,16 @@ impl VdomActor {
 
                 VdomMsg::Reload { reason } => self.forward_reload(reason).await,
 
+                VdomMsg::CssReload { hrefs } => {
+                    for href in hrefs {
+                        let _ = self.ws_tx.send(WsMsg::CssReload { href }).await;
+                    }
+                }
+
+                VdomMsg::Progress { percent, message } => {
+                    let _ = self.ws_tx.send(WsMsg::Progress { percent, message }).await;
+                }
+
                 VdomMsg::Error {
                     path,
                     url_path,
@@ -188,7 +207,12 @@ impl VdomActor {
 
     fn persist_state(&self) {
         let source_paths = self.state.read(|_, address| address.source_paths());
-        match persist_cache(&BUILD_CACHE, &source_paths, &self.root) {
+        let compression = crate::config::config_handle()
+            .current()
+            .build
+            .cache
+            .compression();
+        match persist_cache(&BUILD_CACHE, &source_paths, &self.root, compression) {
             Ok(n) => crate::debug!("vdom"; "persisted {} cache entries", n),
             Err(e) => crate::debug!("vdom"; "cache persist failed: {}", e),
         }
@@ -247,7 +271,7 @@ mod persistence_tests {
         tx.send(VdomMsg::Shutdown).await.unwrap();
         actor_handle.await.unwrap();
 
-        let state = restore_diagnostics(&root).unwrap();
+        let state = restore_diagnostics(&root, u64::MAX).unwrap();
         assert_eq!(state.error_count(), 1, "Should have 1 persisted error");
         let error = state.first_error().unwrap();
         assert_eq!(error.error, "test error");
@@ -384,7 +408,7 @@ mod persistence_tests {
         let cleared = ws_rx.try_recv().expect("clear_error should be sent");
         assert!(matches!(cleared, WsMsg::ClearError { .. }));
 
-        let state = restore_diagnostics(&root).unwrap();
+        let state = restore_diagnostics(&root, u64::MAX).unwrap();
         assert_eq!(
             state.error_count(),
             0,
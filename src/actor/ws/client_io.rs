@@ -10,12 +10,13 @@ use crate::core::UrlPath;
 use crate::reload::active::ACTIVE_PAGE;
 use crate::reload::message::HotReloadMessage;
 
+use super::stream::ClientStream;
 use super::{RegisteredClient, WsActor};
 
 impl WsActor {
     fn initial_client_messages(&self) -> Vec<HotReloadMessage> {
         let mut messages = vec![
-            HotReloadMessage::connected(),
+            HotReloadMessage::connected(self.build_id()),
             HotReloadMessage::clear_all_errors(),
         ];
         let mut errors: Vec<_> = self.pending_errors.lock().errors().cloned().collect();
@@ -30,6 +31,14 @@ impl WsActor {
 
     /// Add a new client connection
     pub(super) fn add_client(&self, stream: TcpStream) {
+        let stream = match ClientStream::new(stream, self.tls.as_ref()) {
+            Ok(stream) => stream,
+            Err(e) => {
+                crate::log!("ws"; "tls setup failed: {}", e);
+                return;
+            }
+        };
+
         // Keep blocking mode during handshake, switch to non-blocking after
         match tungstenite::accept(stream) {
             Ok(mut ws) => {
@@ -83,6 +92,18 @@ impl WsActor {
                 // Non-blocking read
                 match client.ws.read() {
                     Ok(Message::Text(text)) => {
+                        // Answer pings (sent by the client after reconnecting)
+                        if let Some(ts) = Self::parse_ping_message(&text) {
+                            let pong = HotReloadMessage::Pong { ts };
+                            if client
+                                .ws
+                                .send(Message::Text(pong.to_json().into()))
+                                .is_err()
+                            {
+                                disconnected.push(i);
+                            }
+                            continue;
+                        }
                         // Parse and update client's route
                         if let Some(new_route) = Self::parse_page_message(&text) {
                             // Remove old route from ACTIVE_PAGE if different
@@ -131,7 +152,7 @@ impl WsActor {
     /// Client sends `{type: "page", path: "/..."}` in onopen callback.
     /// We try to read it immediately to avoid race condition where
     /// hot reload message is sent before client's route is set.
-    fn try_read_initial_route(ws: &mut WebSocket<TcpStream>) -> Option<UrlPath> {
+    fn try_read_initial_route(ws: &mut WebSocket<ClientStream>) -> Option<UrlPath> {
         // Try multiple times with short delays to catch the initial message
         for _ in 0..5 {
             match ws.read() {
@@ -153,6 +174,14 @@ impl WsActor {
         None
     }
 
+    /// Parse a client ping and return its timestamp
+    fn parse_ping_message(text: &str) -> Option<u64> {
+        match HotReloadMessage::from_json(text)? {
+            HotReloadMessage::Ping { ts } => Some(ts),
+            _ => None,
+        }
+    }
+
     /// Parse page message and return the route if valid
     fn parse_page_message(text: &str) -> Option<UrlPath> {
         use percent_encoding::percent_decode_str;
@@ -193,6 +222,29 @@ mod tests {
         assert_eq!(messages.len(), 2);
     }
 
+    #[test]
+    fn initial_client_messages_include_build_id() {
+        let (_tx, rx) = mpsc::channel(1);
+        let actor = WsActor::new(rx);
+
+        match &actor.initial_client_messages()[0] {
+            HotReloadMessage::Connected { build, .. } => assert_eq!(build, &actor.build_id()),
+            other => panic!("expected connected message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_ping_message() {
+        assert_eq!(
+            WsActor::parse_ping_message(r#"{"type":"ping","ts":42}"#),
+            Some(42)
+        );
+        assert_eq!(
+            WsActor::parse_ping_message(r#"{"type":"page","path":"/"}"#),
+            None
+        );
+    }
+
     #[test]
     fn initial_client_messages_replay_all_pending_errors() {
         let (_tx, rx) = mpsc::channel(1);
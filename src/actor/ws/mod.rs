@@ -4,7 +4,8 @@
 //! - Managing WebSocket client connections
 //! - Broadcasting messages to all connected clients
 //! - Targeted push to clients viewing specific routes
-//! - Receiving client messages (e.g., current page URL)
+//! - Receiving client messages (e.g., current page URL, pings)
+//! - Tracking a build id so reconnecting clients can detect missed updates
 //!
 //! # Architecture
 //!
@@ -16,10 +17,11 @@
 
 mod client_io;
 mod delivery;
+mod stream;
 
-use std::net::TcpStream;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use parking_lot::Mutex;
 use tokio::sync::mpsc;
@@ -31,10 +33,11 @@ use crate::cache::{PersistedDiagnostics, PersistedError};
 use crate::core::UrlPath;
 use crate::reload::active::ACTIVE_PAGE;
 use crate::reload::message::HotReloadMessage;
+use stream::ClientStream;
 
 /// A registered WebSocket client with its current route
 struct RegisteredClient {
-    ws: WebSocket<TcpStream>,
+    ws: WebSocket<ClientStream>,
     /// Current route this client is viewing (for targeted push)
     route: Option<UrlPath>,
 }
@@ -49,6 +52,12 @@ pub struct WsActor {
     pending_errors: Arc<Mutex<PersistedDiagnostics>>,
     /// Stop signal for the reader thread.
     stop_reader: Arc<AtomicBool>,
+    /// Serve `wss://` when set (`serve.tls`)
+    tls: Option<Arc<rustls::ServerConfig>>,
+    /// Server start time (ms), so a restarted server has a new build id
+    started: u64,
+    /// Number of updates pushed to clients (patches, reloads, CSS)
+    generation: u64,
 }
 
 impl WsActor {
@@ -59,9 +68,26 @@ impl WsActor {
             clients: Arc::new(Mutex::new(Vec::new())),
             pending_errors: Arc::new(Mutex::new(PersistedDiagnostics::new())),
             stop_reader: Arc::new(AtomicBool::new(false)),
+            tls: None,
+            started: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+            generation: 0,
         }
     }
 
+    /// Identifier of the current build, sent in the `connected` message
+    fn build_id(&self) -> String {
+        format!("{:x}-{}", self.started, self.generation)
+    }
+
+    /// Accept clients over TLS
+    pub fn with_tls(mut self, tls: Arc<rustls::ServerConfig>) -> Self {
+        self.tls = Some(tls);
+        self
+    }
+
     /// Set one initial pending error (for snapshot recovery)
     pub fn with_pending_error(self, path: String, error: String) -> Self {
         self.with_pending_errors(vec![PersistedError::new(path, String::new(), error)])
@@ -94,6 +120,7 @@ impl WsActor {
                     patches,
                     url_change,
                 } => {
+                    self.generation += 1;
                     // Build HotReloadMessage with optional url_change
                     let hr_msg = if let Some(change) = url_change {
                         crate::debug!("ws"; "sending patch with url_change: {} -> {}", change.old, change.new);
@@ -117,6 +144,7 @@ impl WsActor {
                     url_path,
                     url_change,
                 } => {
+                    self.generation += 1;
                     crate::debug!("ws"; "sending reload: {}", reason);
                     let hr_msg = if let Some(change) = url_change {
                         crate::debug!("ws"; "reload with url_change: {} -> {}", change.old, change.new);
@@ -138,6 +166,18 @@ impl WsActor {
                     }
                 }
 
+                WsMsg::CssReload { href } => {
+                    self.generation += 1;
+                    crate::debug!("ws"; "sending css reload: {}", href);
+                    let hr_msg = HotReloadMessage::css_reload(href);
+                    self.broadcast(Message::Text(hr_msg.to_json().into()));
+                }
+
+                WsMsg::Progress { percent, message } => {
+                    let hr_msg = HotReloadMessage::progress(percent, message);
+                    self.broadcast(Message::Text(hr_msg.to_json().into()));
+                }
+
                 WsMsg::Error { path, error } => {
                     // Cache error for new clients (snapshot recovery)
                     self.pending_errors.lock().push_error(PersistedError::new(
@@ -203,4 +243,14 @@ mod tests {
 
         assert!(ACTIVE_PAGE.get_all().is_empty());
     }
+
+    #[test]
+    fn build_id_changes_with_generation() {
+        let (_tx, rx) = mpsc::channel(1);
+        let mut actor = WsActor::new(rx);
+
+        let before = actor.build_id();
+        actor.generation += 1;
+        assert_ne!(actor.build_id(), before);
+    }
 }
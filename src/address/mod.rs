@@ -21,6 +21,7 @@
 //! # Module Structure
 //!
 //! - [`conflict`]: URL conflict detection (multiple sources -> same URL)
+//! - [`redirect`]: Alias redirect graph (loops, long chains)
 //! - [`resource`]: Resource types (Page, Asset, AssetKind)
 //! - [`resolve`]: Link resolution types and utilities
 //! - [`space`]: AddressSpace core implementation
@@ -44,6 +45,7 @@
 #![allow(dead_code)]
 
 pub mod conflict;
+pub mod redirect;
 mod resolve;
 mod resource;
 mod space;
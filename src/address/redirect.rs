@@ -0,0 +1,181 @@
+//! Redirect graph built from page aliases.
+//!
+//! Every alias is an edge `alias -> permalink`. When a redirect target is
+//! itself an alias, browsers follow a chain; a chain that returns to an
+//! earlier URL loops forever.
+
+use std::path::PathBuf;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::core::UrlPath;
+
+/// A redirect page written for an alias
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redirect {
+    /// Permalink of the page declaring the alias.
+    pub target: UrlPath,
+    /// Source file declaring the alias.
+    pub source: PathBuf,
+}
+
+/// Problems found in the redirect graph
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RedirectAnalysis {
+    /// Loops, starting at their smallest URL and repeating it at the end.
+    pub cycles: Vec<Vec<UrlPath>>,
+    /// Chains with more than `max_chain` hops, from first alias to final URL.
+    pub long_chains: Vec<Vec<UrlPath>>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    /// On the current DFS path.
+    Gray,
+    /// Fully explored.
+    Black,
+}
+
+/// Find redirect loops and chains longer than `max_chain` hops
+pub fn analyze(redirects: &FxHashMap<UrlPath, Redirect>, max_chain: usize) -> RedirectAnalysis {
+    let mut starts: Vec<&UrlPath> = redirects.keys().collect();
+    starts.sort();
+
+    // DFS with color marking; each node has at most one outgoing edge
+    let mut color: FxHashMap<&UrlPath, Color> = FxHashMap::default();
+    let mut cycles = Vec::new();
+    for &start in &starts {
+        if color.contains_key(start) {
+            continue;
+        }
+
+        let mut path: Vec<&UrlPath> = Vec::new();
+        let mut node = start;
+        loop {
+            match color.get(node) {
+                Some(Color::Gray) => {
+                    let begin = path.iter().position(|n| *n == node).unwrap_or(0);
+                    cycles.push(normalize_cycle(&path[begin..]));
+                    break;
+                }
+                Some(Color::Black) => break,
+                None => {}
+            }
+            color.insert(node, Color::Gray);
+            path.push(node);
+            match redirects.get(node) {
+                Some(redirect) => node = &redirect.target,
+                None => break,
+            }
+        }
+        for node in path {
+            color.insert(node, Color::Black);
+        }
+    }
+
+    let in_cycle: FxHashSet<&UrlPath> = cycles.iter().flatten().collect();
+    let targets: FxHashSet<&UrlPath> = redirects.values().map(|r| &r.target).collect();
+
+    // Measure chains from their heads (aliases nothing redirects to)
+    let long_chains = starts
+        .into_iter()
+        .filter(|start| !targets.contains(start))
+        .filter_map(|start| chain_from(start, redirects, &in_cycle))
+        .filter(|chain| chain.len() - 1 > max_chain)
+        .collect();
+
+    RedirectAnalysis {
+        cycles,
+        long_chains,
+    }
+}
+
+/// Follow redirects from `start`; `None` if the chain runs into a loop
+fn chain_from(
+    start: &UrlPath,
+    redirects: &FxHashMap<UrlPath, Redirect>,
+    in_cycle: &FxHashSet<&UrlPath>,
+) -> Option<Vec<UrlPath>> {
+    let mut chain = vec![start.clone()];
+    let mut node = start;
+    while let Some(redirect) = redirects.get(node) {
+        if in_cycle.contains(&redirect.target) {
+            return None;
+        }
+        node = &redirect.target;
+        chain.push(node.clone());
+    }
+    Some(chain)
+}
+
+/// Rotate a cycle to start at its smallest URL and close it
+fn normalize_cycle(nodes: &[&UrlPath]) -> Vec<UrlPath> {
+    let min = nodes
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, url)| **url)
+        .map_or(0, |(i, _)| i);
+    let mut cycle: Vec<UrlPath> = nodes[min..]
+        .iter()
+        .chain(&nodes[..min])
+        .map(|url| (*url).clone())
+        .collect();
+    cycle.push(cycle[0].clone());
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&str, &str)]) -> FxHashMap<UrlPath, Redirect> {
+        edges
+            .iter()
+            .map(|(alias, target)| {
+                (
+                    UrlPath::from_page(alias),
+                    Redirect {
+                        target: UrlPath::from_page(target),
+                        source: PathBuf::from("content/page.typ"),
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn urls(chain: &[UrlPath]) -> Vec<&str> {
+        chain.iter().map(UrlPath::as_str).collect()
+    }
+
+    #[test]
+    fn test_detects_cycle() {
+        let redirects = graph(&[("/b/", "/a/"), ("/a/", "/b/"), ("/old/", "/a/")]);
+        let analysis = analyze(&redirects, 3);
+
+        assert_eq!(analysis.cycles.len(), 1);
+        assert_eq!(urls(&analysis.cycles[0]), vec!["/a/", "/b/", "/a/"]);
+        // The chain feeding into the loop is not reported separately
+        assert!(analysis.long_chains.is_empty());
+    }
+
+    #[test]
+    fn test_self_loop() {
+        let analysis = analyze(&graph(&[("/a/", "/a/")]), 3);
+        assert_eq!(urls(&analysis.cycles[0]), vec!["/a/", "/a/"]);
+    }
+
+    #[test]
+    fn test_long_chain() {
+        let redirects = graph(&[("/a/", "/b/"), ("/b/", "/c/"), ("/c/", "/d/")]);
+
+        assert!(analyze(&redirects, 3).long_chains.is_empty());
+
+        let analysis = analyze(&redirects, 2);
+        assert!(analysis.cycles.is_empty());
+        assert_eq!(analysis.long_chains.len(), 1);
+        assert_eq!(
+            urls(&analysis.long_chains[0]),
+            vec!["/a/", "/b/", "/c/", "/d/"]
+        );
+    }
+}
@@ -3,6 +3,7 @@
 //! This is the single source of truth for all addressable resources in the site.
 
 use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use crate::asset::AssetRoute;
@@ -12,6 +13,7 @@ use crate::page::PageRoute;
 use crate::utils::path::route::split_path_fragment;
 use crate::utils::path::slug::slugify_path;
 
+use super::redirect::Redirect;
 use super::resolve::{resolve_physical_path, resolve_relative_url};
 use super::{ResolveContext, ResolveResult, Resource};
 
@@ -49,6 +51,10 @@ pub struct AddressSpace {
     pub(super) by_source: FxHashMap<PathBuf, UrlPath>,
     /// Page URL -> heading IDs (for fragment validation)
     headings: FxHashMap<UrlPath, FxHashSet<String>>,
+    /// Natural (path-derived) URL -> custom permalink, for pages that override it
+    natural_urls: FxHashMap<UrlPath, UrlPath>,
+    /// Alias URL -> redirect target (from page `aliases` metadata)
+    redirects: FxHashMap<UrlPath, Redirect>,
     /// Assets directory prefix (e.g., "assets")
     assets_prefix: String,
     /// Slug configuration for URL normalization
@@ -66,6 +72,8 @@ impl AddressSpace {
         self.by_url.clear();
         self.by_source.clear();
         self.headings.clear();
+        self.natural_urls.clear();
+        self.redirects.clear();
         self.assets_prefix.clear();
         self.slug_config = None;
     }
@@ -105,6 +113,26 @@ impl AddressSpace {
         self.by_source.insert(source, permalink);
     }
 
+    /// Record the natural URL of a page whose permalink is overridden in metadata.
+    ///
+    /// Links to the natural URL resolve with a warning pointing at the permalink,
+    /// since nothing is written there.
+    pub fn register_natural_url(&mut self, natural: UrlPath, permalink: UrlPath) {
+        if natural != permalink {
+            self.natural_urls.insert(natural, permalink);
+        }
+    }
+
+    /// Register a page alias, written as a redirect to `target`.
+    pub fn register_redirect(&mut self, alias: UrlPath, target: UrlPath, source: PathBuf) {
+        self.redirects.insert(alias, Redirect { target, source });
+    }
+
+    /// Alias URL -> redirect mappings.
+    pub fn redirects(&self) -> &FxHashMap<UrlPath, Redirect> {
+        &self.redirects
+    }
+
     /// Register an asset in the address space.
     pub fn register_asset(&mut self, route: AssetRoute) {
         let url = route.url.clone();
@@ -336,6 +364,37 @@ impl AddressSpace {
             .collect()
     }
 
+    /// Export the URL map as JSON for external tools.
+    ///
+    /// Produces `{"urls": {permalink: source}, "aliases": {alias: canonical}}`.
+    /// Keys are site-relative URLs; sources are made relative to `root`.
+    pub fn to_json(&self, root: &Path) -> serde_json::Value {
+        let relative = |source: &Path| {
+            source
+                .strip_prefix(root)
+                .unwrap_or(source)
+                .to_string_lossy()
+                .replace('\\', "/")
+        };
+
+        let urls: BTreeMap<&str, String> = self
+            .pages()
+            .filter_map(|resource| match resource {
+                Resource::Page { route, .. } => {
+                    Some((route.permalink.as_str(), relative(&route.source)))
+                }
+                Resource::Asset { .. } => None,
+            })
+            .collect();
+        let aliases: BTreeMap<&str, &str> = self
+            .redirects
+            .iter()
+            .map(|(alias, redirect)| (alias.as_str(), redirect.target.as_str()))
+            .collect();
+
+        serde_json::json!({ "urls": urls, "aliases": aliases })
+    }
+
     /// Get heading IDs for a page.
     pub fn headings_for(&self, permalink: &UrlPath) -> Option<&FxHashSet<String>> {
         self.headings.get(permalink)
@@ -496,6 +555,20 @@ impl AddressSpace {
             return ResolveResult::Found(resource.clone());
         }
 
+        // Natural URL of a page with a custom permalink
+        if let Some(permalink) = self.natural_urls.get(&normalized)
+            && self.by_url.contains_key(permalink)
+        {
+            return ResolveResult::Warning {
+                resolved: Some(permalink.to_string()),
+                message: format!(
+                    "'{}' is the default URL of a page with custom permalink '{}'.\n\
+                     Link to the permalink instead.",
+                    path, permalink
+                ),
+            };
+        }
+
         ResolveResult::NotFound {
             target: if fragment.is_empty() {
                 path.to_string()
@@ -787,6 +860,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_to_json() {
+        let mut space = AddressSpace::new();
+        space.register_page(
+            test_route(
+                "/site/content/hello.typ",
+                "/hello/",
+                "public/hello/index.html",
+            ),
+            None,
+        );
+        space.register_redirect(
+            UrlPath::from_page("/old-hello/"),
+            UrlPath::from_page("/hello/"),
+            PathBuf::from("/site/content/hello.typ"),
+        );
+
+        let json = space.to_json(Path::new("/site"));
+        assert_eq!(json["urls"]["/hello/"], "content/hello.typ");
+        assert_eq!(json["aliases"]["/old-hello/"], "/hello/");
+        assert_eq!(json["urls"].as_object().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_register_page() {
         let mut space = AddressSpace::new();
@@ -802,6 +898,45 @@ mod tests {
         assert_eq!(space.page_count(), 1);
     }
 
+    #[test]
+    fn test_resolve_natural_url_of_custom_permalink() {
+        let mut space = AddressSpace::new();
+        let route = test_route(
+            "content/posts/about.typ",
+            "/about/",
+            "public/about/index.html",
+        );
+        space.register_page(route, None);
+        space.register_natural_url(
+            UrlPath::from_page("/posts/about/"),
+            UrlPath::from_page("/about/"),
+        );
+
+        let current = UrlPath::from_page("/");
+        let ctx = ResolveContext {
+            current_permalink: &current,
+            source_path: Path::new("content/index.typ"),
+            origin: crate::core::LinkOrigin::Href,
+        };
+
+        assert!(matches!(
+            space.resolve("/about/", &ctx),
+            ResolveResult::Found(_)
+        ));
+        match space.resolve("/posts/about/", &ctx) {
+            ResolveResult::Warning { resolved, .. } => {
+                assert_eq!(resolved.as_deref(), Some("/about/"));
+            }
+            other => panic!("expected warning, got {other:?}"),
+        }
+
+        space.clear();
+        assert!(matches!(
+            space.resolve("/posts/about/", &ctx),
+            ResolveResult::NotFound { .. }
+        ));
+    }
+
     /// Create a test AssetRoute.
     fn test_asset_route(source: &str, url: &str, output: &str) -> AssetRoute {
         use crate::asset::AssetKind;
@@ -0,0 +1,167 @@
+//! Content-hash filenames for `site.header` styles and scripts.
+//!
+//! Header stylesheets and scripts are written as `name-<hash12>.ext`, so
+//! browsers can cache them forever. `tola serve` keeps plain filenames with
+//! `?v=` versions, which in-place stylesheet reloads match on.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::SystemTime;
+
+use dashmap::DashMap;
+
+use crate::config::SiteConfig;
+use crate::image::svg::filename_hash;
+use crate::utils::path::normalize_path;
+
+/// Length of the hex hash in hashed filenames
+const HASH_LEN: usize = 12;
+
+/// Source path -> (mtime, hashed filename), recomputed when the source changes
+static HASHED_NAMES: LazyLock<DashMap<PathBuf, (SystemTime, String)>> = LazyLock::new(DashMap::new);
+
+/// Whether header assets get content-hash filenames (every command but `serve`)
+pub fn uses_hashed_names(config: &SiteConfig) -> bool {
+    !config.cli.is_some_and(|cli| cli.is_serve())
+}
+
+/// Check if a source file is listed in `site.header.styles` or `site.header.scripts`
+pub fn is_header_asset(path: &Path, config: &SiteConfig) -> bool {
+    config
+        .site
+        .header
+        .lists_asset(&normalize_path(path), config.get_root())
+}
+
+/// Output path for a header asset, `None` if it keeps its plain name
+pub fn hashed_output(source: &Path, output: &Path, config: &SiteConfig) -> Option<PathBuf> {
+    if !uses_hashed_names(config) || !is_header_asset(source, config) {
+        return None;
+    }
+    Some(output.with_file_name(hashed_file_name(source)?))
+}
+
+/// Replace the last segment of `href` with the hashed filename of `source`
+pub fn hashed_href(href: &str, source: &Path) -> Option<String> {
+    let name = hashed_file_name(source)?;
+    let (dir, _) = href.rsplit_once('/')?;
+    Some(format!("{dir}/{name}"))
+}
+
+/// Hashed filename `name-<hash12>.ext` from the content of `source`
+pub fn hashed_file_name(source: &Path) -> Option<String> {
+    let source = normalize_path(source);
+    let mtime = fs::metadata(&source).and_then(|m| m.modified()).ok()?;
+    if let Some(entry) = HASHED_NAMES.get(&source)
+        && entry.0 == mtime
+    {
+        return Some(entry.1.clone());
+    }
+
+    let content = fs::read(&source).ok()?;
+    let stem = source.file_stem()?.to_str()?;
+    let name = match source.extension().and_then(|e| e.to_str()) {
+        Some(ext) => format!("{stem}-{}.{ext}", filename_hash(&content)),
+        None => format!("{stem}-{}", filename_hash(&content)),
+    };
+    HASHED_NAMES.insert(source, (mtime, name.clone()));
+    Some(name)
+}
+
+/// Remove the plain and older hashed versions of `current` next to it
+pub fn cleanup_old_versions(current: &Path, plain: &Path) -> std::io::Result<()> {
+    let Some(dir) = current.parent() else {
+        return Ok(());
+    };
+    let Some(stem) = plain.file_stem().and_then(|s| s.to_str()) else {
+        return Ok(());
+    };
+    let ext = plain.extension().and_then(|e| e.to_str());
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path == current {
+            continue;
+        }
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if is_version_of(name, stem, ext) {
+            fs::remove_file(&path)?;
+            crate::debug!("assets"; "removed old version {}", path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Check if `name` is `{stem}.{ext}` or `{stem}-<hash12>.{ext}`
+fn is_version_of(name: &str, stem: &str, ext: Option<&str>) -> bool {
+    let base = match ext {
+        Some(ext) => name.strip_suffix(ext).and_then(|n| n.strip_suffix('.')),
+        None => Some(name),
+    };
+    let Some(rest) = base.and_then(|b| b.strip_prefix(stem)) else {
+        return false;
+    };
+
+    rest.is_empty()
+        || rest.strip_prefix('-').is_some_and(|hash| {
+            hash.len() == HASH_LEN && hash.bytes().all(|b| b.is_ascii_hexdigit())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_hashed_file_name() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("style.css");
+        fs::write(&file, "body { color: red; }").unwrap();
+
+        let name = hashed_file_name(&file).unwrap();
+        let hash = filename_hash(b"body { color: red; }");
+        assert_eq!(name, format!("style-{hash}.css"));
+
+        let href = hashed_href("/assets/style.css", &file).unwrap();
+        assert_eq!(href, format!("/assets/style-{hash}.css"));
+    }
+
+    #[test]
+    fn test_is_version_of() {
+        assert!(is_version_of("app.css", "app", Some("css")));
+        assert!(is_version_of("app-0123456789ab.css", "app", Some("css")));
+        assert!(!is_version_of("app-dark.css", "app", Some("css")));
+        assert!(!is_version_of(
+            "app-dark-0123456789ab.css",
+            "app",
+            Some("css")
+        ));
+        assert!(!is_version_of("app-0123456789ab.js", "app", Some("css")));
+        assert!(!is_version_of("application.css", "app", Some("css")));
+    }
+
+    #[test]
+    fn test_cleanup_old_versions() {
+        let dir = TempDir::new().unwrap();
+        let current = dir.path().join("app-aaaaaaaaaaaa.css");
+        for name in [
+            "app.css",
+            "app-aaaaaaaaaaaa.css",
+            "app-bbbbbbbbbbbb.css",
+            "app-dark.css",
+        ] {
+            fs::write(dir.path().join(name), "").unwrap();
+        }
+
+        cleanup_old_versions(&current, &dir.path().join("app.css")).unwrap();
+
+        assert!(current.exists());
+        assert!(dir.path().join("app-dark.css").exists());
+        assert!(!dir.path().join("app.css").exists());
+        assert!(!dir.path().join("app-bbbbbbbbbbbb.css").exists());
+    }
+}
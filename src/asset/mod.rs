@@ -1,6 +1,7 @@
 //! Asset processing and path mapping.
 
 mod generated;
+pub mod hashed;
 mod kind;
 mod meta;
 pub mod minify;
@@ -9,8 +9,10 @@ use crate::config::SiteConfig;
 use crate::core::ContentKind;
 use crate::freshness::is_newer_than;
 use crate::hooks::css;
+use crate::image::{metadata, responsive};
 use crate::log;
 
+use super::hashed;
 use super::meta::{relative_path, route_from_source};
 
 /// Process an asset file from the assets directory
@@ -24,9 +26,14 @@ pub fn process_asset(
     log_file: bool,
 ) -> Result<()> {
     let route = route_from_source(asset_path.to_path_buf(), config)?;
+    process_responsive_variants(asset_path, &route.output, config)?;
+
+    // Header styles/scripts are written under content-hash names
+    let hashed = hashed::hashed_output(asset_path, &route.output, config);
+    let output = hashed.as_deref().unwrap_or(&route.output);
 
     // Skip if up-to-date (use mtime comparison for assets, not hash markers)
-    if !clean && route.output.exists() && !is_newer_than(asset_path, &route.output) {
+    if !clean && output.exists() && !is_newer_than(asset_path, output) {
         return Ok(());
     }
 
@@ -34,7 +41,7 @@ pub fn process_asset(
         log!("assets"; "{}", relative_path(asset_path, config));
     }
 
-    if let Some(parent) = route.output.parent() {
+    if let Some(parent) = output.parent() {
         fs::create_dir_all(parent)?;
     }
 
@@ -58,9 +65,13 @@ pub fn process_asset(
         let source = fs::read_to_string(&route.source)?;
         let minified =
             super::minify::minify_by_ext(&route.source, &source).unwrap_or_else(|| source.clone());
-        fs::write(&route.output, minified)?;
+        fs::write(output, minified)?;
     } else {
-        fs::copy(&route.source, &route.output)?;
+        copy_file(&route.source, output, config)?;
+    }
+
+    if hashed.is_some() {
+        hashed::cleanup_old_versions(output, &route.output)?;
     }
     Ok(())
 }
@@ -74,7 +85,10 @@ pub fn process_rel_asset(
     clean: bool,
     log_file: bool,
 ) -> Result<()> {
-    let content = &config.build.content;
+    let content = config
+        .build
+        .content_root(path)
+        .unwrap_or(&config.build.content);
     let output = config.paths().output_dir();
 
     let rel_path = path
@@ -83,6 +97,7 @@ pub fn process_rel_asset(
         .ok_or_else(|| anyhow!("Invalid path"))?;
 
     let output_path = output.join(rel_path);
+    process_responsive_variants(path, &output_path, config)?;
 
     // Relative assets don't depend on templates/config, use mtime comparison
     if !clean && output_path.exists() && !is_newer_than(path, &output_path) {
@@ -97,11 +112,11 @@ pub fn process_rel_asset(
         fs::create_dir_all(parent)?;
     }
 
-    fs::copy(path, output_path)?;
+    copy_file(path, &output_path, config)?;
     Ok(())
 }
 
-/// Process all non-content files in the content directory
+/// Process all non-content files in the content directories
 ///
 /// Copies all files that are not pages (.typ, .md) to the output directory,
 /// preserving the directory structure.
@@ -120,15 +135,22 @@ pub fn process_rel_asset(
 ///
 /// Returns the number of files copied
 pub fn process_content_assets(config: &SiteConfig, clean: bool) -> Result<usize> {
-    let content_dir = &config.build.content;
     let output_dir = config.paths().output_dir();
 
-    if !content_dir.exists() {
-        return Ok(0);
-    }
-
     let mut count = 0;
-    copy_content_assets_recursive(content_dir, content_dir, &output_dir, clean, &mut count)?;
+    for content_dir in config.build.content_dirs() {
+        if !content_dir.exists() {
+            continue;
+        }
+        copy_content_assets_recursive(
+            content_dir,
+            content_dir,
+            &output_dir,
+            config,
+            clean,
+            &mut count,
+        )?;
+    }
     Ok(count)
 }
 
@@ -137,6 +159,7 @@ fn copy_content_assets_recursive(
     dir: &Path,
     content_root: &Path,
     output_root: &Path,
+    config: &SiteConfig,
     clean: bool,
     count: &mut usize,
 ) -> Result<()> {
@@ -149,7 +172,14 @@ fn copy_content_assets_recursive(
 
         if src_path.is_dir() {
             // Recursively process subdirectories
-            copy_content_assets_recursive(&src_path, content_root, output_root, clean, count)?;
+            copy_content_assets_recursive(
+                &src_path,
+                content_root,
+                output_root,
+                config,
+                clean,
+                count,
+            )?;
         } else {
             // Skip content files (.typ, .md) - they are pages, not assets
             if ContentKind::from_path(&src_path).is_some() {
@@ -159,6 +189,7 @@ fn copy_content_assets_recursive(
             // Compute output path: content/a/b/file.png -> output/a/b/file.png
             let rel_path = src_path.strip_prefix(content_root).unwrap_or(&src_path);
             let dest_path = output_root.join(rel_path);
+            process_responsive_variants(&src_path, &dest_path, config)?;
 
             // Skip if destination is fresh
             if !clean && dest_path.exists() && !is_newer_than(&src_path, &dest_path) {
@@ -169,7 +200,7 @@ fn copy_content_assets_recursive(
             if let Some(parent) = dest_path.parent() {
                 fs::create_dir_all(parent)?;
             }
-            fs::copy(&src_path, &dest_path)?;
+            copy_file(&src_path, &dest_path, config)?;
             *count += 1;
         }
     }
@@ -177,6 +208,39 @@ fn copy_content_assets_recursive(
     Ok(())
 }
 
+/// Copy a file to the output, stripping image metadata when
+/// `build.assets.images.strip_exif` is enabled
+fn copy_file(source: &Path, output: &Path, config: &SiteConfig) -> Result<()> {
+    if config.build.assets.images.strip_exif
+        && let Some(stripped) = metadata::strip_file(source)?
+    {
+        fs::write(output, stripped)?;
+    } else {
+        fs::copy(source, output)?;
+    }
+    Ok(())
+}
+
+/// Generate responsive image variants next to an image's output
+///
+/// Variants use content-hash names, so existing files are never stale and
+/// this runs regardless of the original's freshness.
+fn process_responsive_variants(source: &Path, output: &Path, config: &SiteConfig) -> Result<()> {
+    let images = &config.build.assets.images;
+    if !images.is_selected(source, config.get_root()) {
+        return Ok(());
+    }
+    let (Some(plan), Some(output_dir)) = (responsive::plan(source, images), output.parent()) else {
+        return Ok(());
+    };
+
+    let written = responsive::generate(source, output_dir, &plan)?;
+    if written > 0 {
+        crate::debug!("assets"; "{} responsive variants for {}", written, relative_path(source, config));
+    }
+    Ok(())
+}
+
 /// Process flatten assets (files that go to output root)
 ///
 /// Returns the number of files processed
@@ -185,8 +249,11 @@ pub fn process_flatten_assets(config: &SiteConfig, clean: bool, log_file: bool)
     let mut count = 0;
 
     for route in assets {
+        let hashed = hashed::hashed_output(&route.source, &route.output, config);
+        let output = hashed.as_deref().unwrap_or(&route.output);
+
         // Skip if up-to-date (use mtime comparison for assets)
-        if !clean && route.output.exists() && !is_newer_than(&route.source, &route.output) {
+        if !clean && output.exists() && !is_newer_than(&route.source, output) {
             continue;
         }
 
@@ -194,11 +261,14 @@ pub fn process_flatten_assets(config: &SiteConfig, clean: bool, log_file: bool)
             log!("assets"; "{}", relative_path(&route.source, config));
         }
 
-        if let Some(parent) = route.output.parent() {
+        if let Some(parent) = output.parent() {
             fs::create_dir_all(parent)?;
         }
 
-        fs::copy(&route.source, &route.output)?;
+        copy_file(&route.source, output, config)?;
+        if hashed.is_some() {
+            hashed::cleanup_old_versions(output, &route.output)?;
+        }
         count += 1;
     }
 
@@ -351,4 +421,103 @@ mod tests {
         let count = process_content_assets(&config, false).unwrap();
         assert_eq!(count, 1);
     }
+
+    #[test]
+    fn test_process_content_assets_strip_exif() {
+        let dir = TempDir::new().unwrap();
+        let content_dir = dir.path().join("content");
+        fs::create_dir_all(&content_dir).unwrap();
+
+        // Minimal JPEG: SOI, APP1 (EXIF), SOS, EOI
+        let exif = b"Exif\0\0GPS-SECRET";
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        jpeg.extend_from_slice(&((exif.len() + 2) as u16).to_be_bytes());
+        jpeg.extend_from_slice(exif);
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0xFF, 0xD9]);
+        fs::write(content_dir.join("photo.jpg"), &jpeg).unwrap();
+
+        let output_dir = dir.path().join("public");
+        let mut config = SiteConfig::default();
+        config.build.content = content_dir;
+        config.build.output = output_dir.clone();
+
+        process_content_assets(&config, true).unwrap();
+        assert_eq!(fs::read(output_dir.join("photo.jpg")).unwrap(), jpeg);
+
+        config.build.assets.images.strip_exif = true;
+        process_content_assets(&config, true).unwrap();
+        assert_eq!(
+            fs::read(output_dir.join("photo.jpg")).unwrap(),
+            [0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x02, 0xFF, 0xD9]
+        );
+    }
+
+    #[test]
+    fn test_process_content_assets_responsive_variants() {
+        use image::{Rgba, RgbaImage};
+
+        let dir = TempDir::new().unwrap();
+        let content_dir = dir.path().join("content");
+        fs::create_dir_all(&content_dir).unwrap();
+        RgbaImage::from_pixel(100, 50, Rgba([0, 0, 0, 255]))
+            .save(content_dir.join("photo.png"))
+            .unwrap();
+
+        let output_dir = dir.path().join("public");
+        let mut config = SiteConfig::default();
+        config.set_root(dir.path());
+        config.build.content = content_dir;
+        config.build.output = output_dir.clone();
+        config.build.assets.images.enable = true;
+        config.build.assets.images.srcset_widths = vec![40];
+
+        process_content_assets(&config, true).unwrap();
+
+        let mut names: Vec<_> = fs::read_dir(&output_dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        // Original, 40w png, 40w + 100w webp
+        assert_eq!(names.len(), 4);
+        assert!(names.contains(&"photo.png".to_string()));
+        assert!(
+            names
+                .iter()
+                .any(|n| n.starts_with("photo-40w.") && n.ends_with(".png"))
+        );
+        assert!(
+            names
+                .iter()
+                .any(|n| n.starts_with("photo-100w.") && n.ends_with(".webp"))
+        );
+    }
+
+    #[test]
+    fn test_process_asset_header_style_hashed() {
+        use crate::config::section::build::assets::NestedEntry;
+
+        let dir = TempDir::new().unwrap();
+        let assets_dir = dir.path().join("assets");
+        fs::create_dir_all(&assets_dir).unwrap();
+        let style = assets_dir.join("site.css");
+        fs::write(&style, "body{}").unwrap();
+
+        let output_dir = dir.path().join("public");
+        let mut config = SiteConfig::default();
+        config.set_root(dir.path());
+        config.build.output = output_dir.clone();
+        config.build.assets.nested = vec![NestedEntry::Simple(assets_dir)];
+        config.site.header.styles = vec![style.clone()];
+
+        // Plain copy left over from an earlier build
+        fs::create_dir_all(output_dir.join("assets")).unwrap();
+        fs::write(output_dir.join("assets/site.css"), "body{}").unwrap();
+
+        process_asset(&style, &config, true, false).unwrap();
+
+        let hash = crate::image::svg::filename_hash(b"body{}");
+        assert!(output_dir.join(format!("assets/site-{hash}.css")).exists());
+        assert!(!output_dir.join("assets/site.css").exists());
+    }
 }
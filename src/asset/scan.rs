@@ -153,15 +153,14 @@ pub fn scan_flatten_assets(config: &SiteConfig) -> Vec<AssetRoute> {
 ///
 /// This function only reads the filesystem and returns data
 pub fn scan_content_assets(config: &SiteConfig) -> Vec<AssetRoute> {
-    let content_dir = &config.build.content;
     let output_root = config.paths().output_dir();
 
-    if !content_dir.exists() {
-        return vec![];
-    }
-
     let mut results = Vec::new();
-    scan_content_recursive(&mut results, content_dir, content_dir, &output_root);
+    for content_dir in config.build.content_dirs() {
+        if content_dir.exists() {
+            scan_content_recursive(&mut results, content_dir, content_dir, &output_root);
+        }
+    }
     results
 }
 
@@ -0,0 +1,61 @@
+//! URL map persistence.
+//!
+//! Each build stores the address space's URL map so `tola query --urls`
+//! can answer without rescanning the site.
+
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::CACHE_DIR;
+
+/// URL map cache file name
+const URL_MAP_FILE: &str = "urls.json";
+
+/// Persist the URL map of the last build to disk
+pub fn persist_url_map(map: &Value, root: &Path) -> std::io::Result<()> {
+    let cache_dir = root.join(CACHE_DIR);
+    fs::create_dir_all(&cache_dir)?;
+
+    let json = serde_json::to_string(map)?;
+    fs::write(cache_dir.join(URL_MAP_FILE), json)?;
+    crate::debug!("persist"; "saved url map");
+    Ok(())
+}
+
+/// Restore the URL map of the last build, `None` if no build has stored one
+pub fn restore_url_map(root: &Path) -> std::io::Result<Option<Value>> {
+    let path = root.join(CACHE_DIR).join(URL_MAP_FILE);
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let json = fs::read_to_string(&path)?;
+    let map = serde_json::from_str(&json)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+    crate::debug!("persist"; "restored url map");
+    Ok(Some(map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        assert!(restore_url_map(temp.path()).unwrap().is_none());
+
+        let map = serde_json::json!({
+            "urls": { "/hello/": "content/hello.typ" },
+            "aliases": { "/old/": "/hello/" },
+        });
+        persist_url_map(&map, temp.path()).unwrap();
+
+        assert_eq!(restore_url_map(temp.path()).unwrap(), Some(map));
+    }
+}
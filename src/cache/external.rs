@@ -0,0 +1,162 @@
+//! External link check results persistence.
+//!
+//! `tola validate` stores the outcome of each external URL check so repeated
+//! runs within the configured TTL skip the network round-trip.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::CACHE_DIR;
+
+/// External link cache file name
+const EXTERNAL_LINKS_FILE: &str = "external-links.json";
+
+/// Outcome of a single external URL check
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExternalLinkStatus {
+    /// Unix timestamp (seconds) of the check.
+    pub checked_at: u64,
+    /// Failure reason, `None` if the URL responded successfully.
+    pub error: Option<String>,
+}
+
+impl ExternalLinkStatus {
+    /// Record a check performed now.
+    pub fn now(error: Option<String>) -> Self {
+        Self {
+            checked_at: unix_now(),
+            error,
+        }
+    }
+
+    /// Whether this result is still within `ttl` seconds.
+    pub fn is_fresh(&self, ttl: u64) -> bool {
+        ttl > 0 && unix_now().saturating_sub(self.checked_at) < ttl
+    }
+}
+
+/// Cached external link results keyed by URL
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ExternalLinkCache {
+    links: BTreeMap<String, ExternalLinkStatus>,
+}
+
+impl ExternalLinkCache {
+    /// Get a result if it is still within `ttl` seconds.
+    pub fn get_fresh(&self, url: &str, ttl: u64) -> Option<&ExternalLinkStatus> {
+        self.links.get(url).filter(|status| status.is_fresh(ttl))
+    }
+
+    /// Insert or replace a result.
+    pub fn insert(&mut self, url: String, status: ExternalLinkStatus) {
+        self.links.insert(url, status);
+    }
+
+    /// Drop entries older than `ttl` seconds.
+    pub fn prune(&mut self, ttl: u64) {
+        self.links.retain(|_, status| status.is_fresh(ttl));
+    }
+
+    /// Number of cached URLs.
+    pub fn len(&self) -> usize {
+        self.links.len()
+    }
+}
+
+/// Persist external link results to disk
+pub fn persist_external_links(cache: &ExternalLinkCache, root: &Path) -> std::io::Result<()> {
+    let cache_dir = root.join(CACHE_DIR);
+    fs::create_dir_all(&cache_dir)?;
+
+    let json = serde_json::to_string_pretty(cache)?;
+    fs::write(cache_dir.join(EXTERNAL_LINKS_FILE), json)?;
+    crate::debug!("persist"; "saved {} external link results", cache.len());
+    Ok(())
+}
+
+/// Restore external link results from disk
+pub fn restore_external_links(root: &Path) -> std::io::Result<ExternalLinkCache> {
+    let path = root.join(CACHE_DIR).join(EXTERNAL_LINKS_FILE);
+
+    if !path.exists() {
+        return Ok(ExternalLinkCache::default());
+    }
+
+    let json = fs::read_to_string(&path)?;
+    let cache: ExternalLinkCache = serde_json::from_str(&json)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+
+    crate::debug!("persist"; "restored {} external link results", cache.len());
+    Ok(cache)
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_roundtrip() {
+        let temp = TempDir::new().unwrap();
+        let mut cache = ExternalLinkCache::default();
+        cache.insert(
+            "https://example.com".to_string(),
+            ExternalLinkStatus::now(None),
+        );
+        cache.insert(
+            "https://example.com/missing".to_string(),
+            ExternalLinkStatus::now(Some("404 Not Found".to_string())),
+        );
+
+        persist_external_links(&cache, temp.path()).unwrap();
+        let restored = restore_external_links(temp.path()).unwrap();
+
+        assert_eq!(restored.len(), 2);
+        let missing = restored
+            .get_fresh("https://example.com/missing", 60)
+            .unwrap();
+        assert_eq!(missing.error.as_deref(), Some("404 Not Found"));
+    }
+
+    #[test]
+    fn test_ttl() {
+        let mut cache = ExternalLinkCache::default();
+        cache.insert(
+            "https://old.example".to_string(),
+            ExternalLinkStatus {
+                checked_at: unix_now() - 120,
+                error: None,
+            },
+        );
+        cache.insert(
+            "https://new.example".to_string(),
+            ExternalLinkStatus::now(None),
+        );
+
+        assert!(cache.get_fresh("https://old.example", 60).is_none());
+        assert!(cache.get_fresh("https://new.example", 60).is_some());
+        // TTL of zero disables the cache
+        assert!(cache.get_fresh("https://new.example", 0).is_none());
+
+        cache.prune(60);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_restore_missing_file() {
+        let temp = TempDir::new().unwrap();
+        let cache = restore_external_links(temp.path()).unwrap();
+        assert_eq!(cache.len(), 0);
+    }
+}
@@ -2,6 +2,7 @@
 
 use std::fs;
 use std::path::Path;
+use std::time::{Duration, SystemTime};
 
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,9 @@ use super::CACHE_DIR;
 /// Diagnostics state file name
 const DIAGNOSTICS_FILE: &str = "diagnostics.json";
 
+/// Age after which `tola serve` ignores persisted diagnostics (one week)
+pub const DIAGNOSTICS_MAX_AGE_SECS: u64 = 7 * 24 * 60 * 60;
+
 /// A single persisted compile error
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PersistedError {
@@ -54,10 +58,33 @@ impl PersistedWarning {
 }
 
 /// Collection of persisted compile diagnostics (errors + warnings)
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+///
+/// `build_time` and `tola_version` are stamped by [`persist_diagnostics`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PersistedDiagnostics {
     errors: Vec<PersistedError>,
     warnings: Vec<PersistedWarning>,
+    /// When the diagnostics were written (epoch for files without it).
+    #[serde(default = "unix_epoch")]
+    pub build_time: SystemTime,
+    /// Version of tola that wrote the diagnostics.
+    #[serde(default)]
+    pub tola_version: String,
+}
+
+impl Default for PersistedDiagnostics {
+    fn default() -> Self {
+        Self {
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            build_time: unix_epoch(),
+            tola_version: String::new(),
+        }
+    }
+}
+
+fn unix_epoch() -> SystemTime {
+    SystemTime::UNIX_EPOCH
 }
 
 impl PersistedDiagnostics {
@@ -65,6 +92,18 @@ impl PersistedDiagnostics {
         Self::default()
     }
 
+    /// Whether the diagnostics were written by the running tola version.
+    pub fn is_current_version(&self) -> bool {
+        self.tola_version == env!("CARGO_PKG_VERSION")
+    }
+
+    /// Whether the diagnostics were written more than `max_age_secs` ago.
+    pub fn is_older_than(&self, max_age_secs: u64) -> bool {
+        SystemTime::now()
+            .duration_since(self.build_time)
+            .is_ok_and(|age| age > Duration::from_secs(max_age_secs))
+    }
+
     // === Error methods ===
 
     /// Add an error, replacing existing error for same path.
@@ -149,24 +188,21 @@ impl PersistedDiagnostics {
     }
 }
 
-/// Check if file content is the same as new content
-fn file_content_matches(path: &Path, content: &str) -> bool {
-    path.exists() && fs::read_to_string(path).is_ok_and(|existing| existing == content)
-}
-
 /// Persist compile diagnostics to disk
+///
+/// Stamps the current time and tola version.
 pub fn persist_diagnostics(state: &PersistedDiagnostics, root: &Path) -> std::io::Result<()> {
     let cache_dir = root.join(CACHE_DIR);
     let path = cache_dir.join(DIAGNOSTICS_FILE);
 
     fs::create_dir_all(&cache_dir)?;
 
-    let json = serde_json::to_string_pretty(state)?;
-
-    if file_content_matches(&path, &json) {
-        crate::debug!("persist"; "diagnostics unchanged, skipping write");
-        return Ok(());
-    }
+    let stamped = PersistedDiagnostics {
+        build_time: SystemTime::now(),
+        tola_version: env!("CARGO_PKG_VERSION").to_string(),
+        ..state.clone()
+    };
+    let json = serde_json::to_string_pretty(&stamped)?;
 
     fs::write(&path, &json)?;
     crate::debug!("persist"; "saved {} errors, {} warnings", state.error_count(), state.warning_count());
@@ -174,7 +210,12 @@ pub fn persist_diagnostics(state: &PersistedDiagnostics, root: &Path) -> std::io
 }
 
 /// Restore compile diagnostics from disk
-pub fn restore_diagnostics(root: &Path) -> std::io::Result<PersistedDiagnostics> {
+///
+/// Diagnostics written more than `max_age_secs` ago are dropped.
+pub fn restore_diagnostics(
+    root: &Path,
+    max_age_secs: u64,
+) -> std::io::Result<PersistedDiagnostics> {
     let path = root.join(CACHE_DIR).join(DIAGNOSTICS_FILE);
 
     if !path.exists() {
@@ -185,6 +226,50 @@ pub fn restore_diagnostics(root: &Path) -> std::io::Result<PersistedDiagnostics>
     let state: PersistedDiagnostics = serde_json::from_str(&json)
         .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
 
+    if state.is_older_than(max_age_secs) {
+        crate::debug!("persist"; "diagnostics older than {}s, ignoring", max_age_secs);
+        return Ok(PersistedDiagnostics::new());
+    }
+
     crate::debug!("persist"; "restored {} errors, {} warnings", state.error_count(), state.warning_count());
     Ok(state)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_persist_stamps_time_and_version() {
+        let dir = TempDir::new().unwrap();
+        let mut state = PersistedDiagnostics::new();
+        state.push_error(PersistedError::new("a.typ", "/a/", "boom"));
+        assert!(!state.is_current_version());
+
+        persist_diagnostics(&state, dir.path()).unwrap();
+        let restored = restore_diagnostics(dir.path(), DIAGNOSTICS_MAX_AGE_SECS).unwrap();
+        assert!(restored.is_current_version());
+        assert!(!restored.is_older_than(60));
+        assert_eq!(restored.error_count(), 1);
+    }
+
+    #[test]
+    fn test_restore_drops_stale_diagnostics() {
+        let dir = TempDir::new().unwrap();
+        let cache_dir = dir.path().join(CACHE_DIR);
+        fs::create_dir_all(&cache_dir).unwrap();
+        // Written before timestamps existed: treated as built at the epoch
+        fs::write(
+            cache_dir.join(DIAGNOSTICS_FILE),
+            r#"{"errors": [{"path": "a.typ", "url_path": "/a/", "error": "boom"}], "warnings": []}"#,
+        )
+        .unwrap();
+
+        let restored = restore_diagnostics(dir.path(), DIAGNOSTICS_MAX_AGE_SECS).unwrap();
+        assert!(restored.is_empty());
+        let restored = restore_diagnostics(dir.path(), u64::MAX).unwrap();
+        assert_eq!(restored.error_count(), 1);
+        assert!(!restored.is_current_version());
+    }
+}
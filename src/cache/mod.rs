@@ -1,5 +1,7 @@
 //! Cache persistence for VDOM and compile diagnostics.
 
+mod address;
+mod external;
 mod failure;
 mod index;
 mod modified;
@@ -10,14 +12,23 @@ pub(crate) const CACHE_DIR: &str = ".tola/cache";
 
 // VDOM cache
 pub use vdom::{
-    clear_cache_dir, has_cache, persist_cache, restore_cache, restore_dependency_graph,
+    clear_cache_dir, has_cache, load_dependency_entries, persist_cache, restore_cache,
+    restore_dependency_graph,
 };
 
+// External link check results
+pub use external::{
+    ExternalLinkCache, ExternalLinkStatus, persist_external_links, restore_external_links,
+};
+
+// URL map of the last build
+pub use address::{persist_url_map, restore_url_map};
+
 // Diagnostics state (errors + warnings)
 pub use failure::{
-    PersistedDiagnostics, PersistedError, PersistedWarning, persist_diagnostics,
-    restore_diagnostics,
+    DIAGNOSTICS_MAX_AGE_SECS, PersistedDiagnostics, PersistedError, PersistedWarning,
+    persist_diagnostics, restore_diagnostics,
 };
 
 // Modified file detection
-pub use modified::{RemovedFile, get_modified_files};
+pub use modified::{RemovedFile, get_dependents, get_modified_files, get_unchanged_files};
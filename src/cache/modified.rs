@@ -7,6 +7,8 @@ use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::CACHE_DIR;
 use super::index::{CacheFileInfo, CacheIndex, INDEX_FILE};
+use super::vdom::collect_dependency_entries;
+use crate::compiler::dependency::DependencyGraph;
 use crate::core::{ContentKind, UrlPath};
 
 /// Result of modified files detection
@@ -18,6 +20,8 @@ pub struct ModifiedFilesResult {
     pub removed: Vec<RemovedFile>,
     /// Files that were modified since they were cached
     pub modified: Vec<PathBuf>,
+    /// Non-content dependencies (templates, utils) whose hash changed
+    pub changed_deps: Vec<PathBuf>,
     /// Source paths mapping (url -> source path) for reuse
     pub source_paths: FxHashMap<UrlPath, PathBuf>,
     /// Cached URL mapping by source path (source -> url)
@@ -56,7 +60,12 @@ impl RemovedFile {
 /// - `created`: exists now, not in cache index
 /// - `removed`: existed in cache index, now missing
 /// - `modified`: existed in cache index and hash changed
-pub fn get_modified_files(root: &Path, content_dir: &Path) -> ModifiedFilesResult {
+///
+/// `changed_deps` additionally lists recorded dependencies that changed.
+pub fn get_modified_files<'a>(
+    root: &Path,
+    content_dirs: impl IntoIterator<Item = &'a Path>,
+) -> ModifiedFilesResult {
     // Ensure hash comparisons reflect current on-disk content.
     crate::freshness::clear_cache();
 
@@ -65,8 +74,12 @@ pub fn get_modified_files(root: &Path, content_dir: &Path) -> ModifiedFilesResul
     };
 
     let mut result = ModifiedFilesResult::default();
-    let current_content = collect_content_files(content_dir);
+    let current_content: FxHashSet<PathBuf> = content_dirs
+        .into_iter()
+        .flat_map(collect_content_files)
+        .collect();
     let mut modified_set = FxHashSet::default();
+    let mut changed_deps = FxHashSet::default();
 
     for (url, info) in &index.entries {
         let Some(source_path) = resolve_source_path(root, info) else {
@@ -87,6 +100,7 @@ pub fn get_modified_files(root: &Path, content_dir: &Path) -> ModifiedFilesResul
             continue;
         }
 
+        changed_deps.extend(changed_dependencies(root, info));
         if is_file_modified(root, info) {
             modified_set.insert(source_path);
         }
@@ -97,9 +111,14 @@ pub fn get_modified_files(root: &Path, content_dir: &Path) -> ModifiedFilesResul
         .filter(|path| !result.cached_urls_by_source.contains_key(path))
         .collect();
     result.modified = modified_set.into_iter().collect();
+    result.changed_deps = changed_deps
+        .into_iter()
+        .filter(|path| !result.cached_urls_by_source.contains_key(path))
+        .collect();
 
     result.created.sort();
     result.modified.sort();
+    result.changed_deps.sort();
     result
         .removed
         .sort_by(|a, b| a.source_path.cmp(&b.source_path));
@@ -117,6 +136,44 @@ pub fn get_modified_files(root: &Path, content_dir: &Path) -> ModifiedFilesResul
     result
 }
 
+/// Source files whose cached build output is still valid.
+///
+/// A source qualifies when its content hash and every recorded dependency
+/// hash match the cache index, and its VDOM snapshot is still on disk.
+pub fn get_unchanged_files(root: &Path) -> FxHashSet<PathBuf> {
+    let Some(index) = load_index(root) else {
+        return FxHashSet::default();
+    };
+
+    let cache_dir = root.join(CACHE_DIR);
+    index
+        .entries
+        .values()
+        .filter(|info| cache_dir.join(format!("{}.vdom", info.filename)).is_file())
+        .filter(|info| !is_file_modified(root, info))
+        .filter_map(|info| resolve_source_path(root, info))
+        .collect()
+}
+
+/// Content files that transitively depend on `path`, per the cache index.
+///
+/// Used on startup so a template edited while the server was offline
+/// recompiles every page importing it, including indirect importers.
+pub fn get_dependents(path: &Path, root: &Path) -> FxHashSet<PathBuf> {
+    let Some(index) = load_index(root) else {
+        return FxHashSet::default();
+    };
+
+    let mut graph = DependencyGraph::new();
+    for (source, deps) in collect_dependency_entries(&index, root) {
+        graph.record(&source, &deps);
+    }
+
+    let dependents = graph.dependents_of(&crate::utils::path::normalize_path(path));
+    crate::debug!("modified"; "{} has {} dependents", path.display(), dependents.len());
+    dependents
+}
+
 /// Load all source paths from cache index
 pub fn get_source_paths(root: &Path) -> FxHashMap<UrlPath, PathBuf> {
     let Some(index) = load_index(root) else {
@@ -142,7 +199,7 @@ fn load_index(root: &Path) -> Option<CacheIndex> {
     serde_json::from_str(&json).ok()
 }
 
-/// Collect current content files from the configured content directory.
+/// Collect current content files from one content directory.
 fn collect_content_files(content_dir: &Path) -> FxHashSet<PathBuf> {
     if !content_dir.exists() {
         return FxHashSet::default();
@@ -170,6 +227,17 @@ fn resolve_source_path(root: &Path, info: &CacheFileInfo) -> Option<PathBuf> {
     ))
 }
 
+/// Recorded dependencies of a cached file whose content hash changed
+fn changed_dependencies<'a>(
+    root: &'a Path,
+    info: &'a CacheFileInfo,
+) -> impl Iterator<Item = PathBuf> + 'a {
+    info.dependencies
+        .iter()
+        .filter(move |(dep_rel, cached_hash)| compute_hash(&root.join(dep_rel)) != **cached_hash)
+        .map(move |(dep_rel, _)| crate::utils::path::normalize_path(&root.join(dep_rel)))
+}
+
 /// Check if a cached file has been modified (source or deps changed)
 fn is_file_modified(root: &Path, info: &CacheFileInfo) -> bool {
     // Check source hash
@@ -256,7 +324,7 @@ mod tests {
         fs::write(&modified, "= new").unwrap();
         fs::write(&created, "= created").unwrap();
 
-        let result = get_modified_files(root, &content);
+        let result = get_modified_files(root, [content.as_path()]);
 
         assert_eq!(result.created.len(), 1);
         assert!(
@@ -279,4 +347,85 @@ mod tests {
         );
         assert_eq!(result.removed[0].url_path, UrlPath::from_page("/removed/"));
     }
+
+    #[test]
+    fn test_unchanged_files_need_matching_hash_and_snapshot() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let content = root.join("content");
+        fs::create_dir_all(&content).unwrap();
+
+        let unchanged = content.join("unchanged.typ");
+        let modified = content.join("modified.typ");
+        let no_snapshot = content.join("no-snapshot.typ");
+        fs::write(&unchanged, "= unchanged").unwrap();
+        fs::write(&modified, "= old").unwrap();
+        fs::write(&no_snapshot, "= no snapshot").unwrap();
+
+        let mut index = CacheIndex::new();
+        for name in ["unchanged", "modified", "no-snapshot"] {
+            index.entries.insert(
+                format!("/{name}/"),
+                make_entry(root, &format!("content/{name}.typ"), name),
+            );
+        }
+        write_index(root, &index);
+        for name in ["unchanged", "modified"] {
+            fs::write(root.join(CACHE_DIR).join(format!("{name}.vdom")), b"").unwrap();
+        }
+
+        fs::write(&modified, "= new").unwrap();
+        crate::freshness::clear_cache();
+
+        let result = get_unchanged_files(root);
+        assert_eq!(result.len(), 1);
+        assert!(result.contains(&crate::utils::path::normalize_path(&unchanged)));
+    }
+
+    #[test]
+    fn test_template_change_marks_dependents() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let content = root.join("content");
+        let templates = root.join("templates");
+        fs::create_dir_all(&content).unwrap();
+        fs::create_dir_all(&templates).unwrap();
+
+        let template = templates.join("tola.typ");
+        fs::write(&template, "#let page(body) = body").unwrap();
+        for name in ["shared", "post", "about", "plain"] {
+            fs::write(content.join(format!("{name}.typ")), format!("= {name}")).unwrap();
+        }
+
+        let dep = |rel: &str| (rel.to_string(), compute_hash(&root.join(rel)));
+        let mut index = CacheIndex::new();
+        for (name, deps) in [
+            ("shared", vec![dep("templates/tola.typ")]),
+            ("about", vec![dep("templates/tola.typ")]),
+            // Imports the template only through `shared.typ`
+            ("post", vec![dep("content/shared.typ")]),
+            ("plain", vec![]),
+        ] {
+            let mut entry = make_entry(root, &format!("content/{name}.typ"), name);
+            entry.dependencies = deps.into_iter().collect();
+            index.entries.insert(format!("/{name}/"), entry);
+        }
+        write_index(root, &index);
+
+        fs::write(&template, "#let page(body) = [#body]").unwrap();
+        crate::freshness::clear_cache();
+
+        let result = get_modified_files(root, [content.as_path()]);
+        let template = crate::utils::path::normalize_path(&template);
+        assert_eq!(result.changed_deps, vec![template.clone()]);
+
+        let dependents = get_dependents(&template, root);
+        let page =
+            |name: &str| crate::utils::path::normalize_path(&content.join(format!("{name}.typ")));
+        assert_eq!(dependents.len(), 3);
+        for name in ["shared", "about", "post"] {
+            assert!(dependents.contains(&page(name)), "{name} should be marked");
+        }
+        assert!(!dependents.contains(&page("plain")));
+    }
 }
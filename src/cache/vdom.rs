@@ -1,5 +1,7 @@
 //! VDOM cache persistence.
 
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -14,6 +16,9 @@ use tola_vdom::snapshot::{from_bytes, to_bytes};
 use super::CACHE_DIR;
 use super::index::{CacheFileInfo, CacheIndex, INDEX_FILE};
 
+/// zstd frame magic number (little-endian `0xFD2FB528`)
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
 /// Entry ready to be persisted to disk
 struct PersistEntry {
     url: String,
@@ -23,10 +28,13 @@ struct PersistEntry {
 }
 
 /// Persist the VDOM cache to disk
+///
+/// With `compression = Some(level)`, each entry is zstd-compressed.
 pub fn persist_cache(
     cache: &SharedCache,
     source_paths: &FxHashMap<UrlPath, PathBuf>,
     root: &Path,
+    compression: Option<i32>,
 ) -> std::io::Result<usize> {
     let cache_dir = root.join(CACHE_DIR);
     fs::create_dir_all(&cache_dir)?;
@@ -39,7 +47,7 @@ pub fn persist_cache(
     let mut saved = 0;
 
     for entry in entries {
-        if write_entry(&cache_dir, &entry).is_ok() {
+        if write_entry(&cache_dir, &entry, compression).is_ok() {
             index.entries.insert(entry.url, entry.info);
             saved += 1;
         }
@@ -53,6 +61,8 @@ pub fn persist_cache(
 }
 
 /// Restore the VDOM cache from disk
+///
+/// Compressed and uncompressed entries are both accepted.
 pub fn restore_cache(cache: &SharedCache, root: &Path) -> std::io::Result<usize> {
     let Some(index) = load_cache_index(root)? else {
         return Ok(0);
@@ -97,6 +107,31 @@ pub fn restore_dependency_graph(root: &Path) -> std::io::Result<usize> {
     Ok(count)
 }
 
+/// Dependency edges stored by the last build, without touching the global graph
+///
+/// Maps each cached page source to its dependencies, both relative to `root`
+/// (files outside the project keep their absolute path). Pages without
+/// dependencies are included with an empty set. `None` if no cache exists.
+pub fn load_dependency_entries(
+    root: &Path,
+) -> std::io::Result<Option<BTreeMap<String, BTreeSet<String>>>> {
+    let Some(index) = load_cache_index(root)? else {
+        return Ok(None);
+    };
+
+    // A source can back several URLs (e.g. paginated pages)
+    let mut entries: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for info in index.entries.into_values() {
+        if !info.source_path.is_empty() {
+            entries
+                .entry(info.source_path)
+                .or_default()
+                .extend(info.dependencies.into_keys());
+        }
+    }
+    Ok(Some(entries))
+}
+
 /// Check if valid VDOM cache exists
 pub fn has_cache(root: &Path) -> bool {
     let Some(index) = load_cache_index(root).ok().flatten() else {
@@ -238,7 +273,10 @@ fn collect_entries_to_restore(
 }
 
 /// Extract dependency entries from index
-fn collect_dependency_entries(index: &CacheIndex, root: &Path) -> Vec<(PathBuf, Vec<PathBuf>)> {
+pub(super) fn collect_dependency_entries(
+    index: &CacheIndex,
+    root: &Path,
+) -> Vec<(PathBuf, Vec<PathBuf>)> {
     index
         .entries
         .values()
@@ -252,9 +290,14 @@ fn collect_dependency_entries(index: &CacheIndex, root: &Path) -> Vec<(PathBuf,
 }
 
 /// Write a single cache entry to disk
-fn write_entry(cache_dir: &Path, entry: &PersistEntry) -> std::io::Result<()> {
+fn write_entry(
+    cache_dir: &Path,
+    entry: &PersistEntry,
+    compression: Option<i32>,
+) -> std::io::Result<()> {
     let path = cache_dir.join(format!("{}.vdom", &entry.filename));
-    fs::write(&path, &entry.bytes).map_err(|e| {
+    let bytes = encode_entry(&entry.bytes, compression)?;
+    fs::write(&path, bytes).map_err(|e| {
         crate::debug!("persist"; "failed to write {}: {}", path.display(), e);
         e
     })
@@ -275,6 +318,10 @@ fn read_entry(cache_dir: &Path, filename: &str) -> Result<StructuralDocument, St
         crate::debug!("persist"; "failed to read {}: {}", path.display(), e);
         e.to_string()
     })?;
+    let bytes = decode_entry(bytes).map_err(|e| {
+        crate::debug!("persist"; "failed to decompress {}: {}", path.display(), e);
+        e.to_string()
+    })?;
 
     from_bytes(&bytes).map_err(|e| {
         crate::debug!("persist"; "failed to decode snapshot {}: {}", path.display(), e);
@@ -282,6 +329,23 @@ fn read_entry(cache_dir: &Path, filename: &str) -> Result<StructuralDocument, St
     })
 }
 
+/// Compress snapshot bytes when a compression level is set
+fn encode_entry(bytes: &[u8], compression: Option<i32>) -> std::io::Result<Cow<'_, [u8]>> {
+    match compression {
+        Some(level) => zstd::encode_all(bytes, level).map(Cow::Owned),
+        None => Ok(Cow::Borrowed(bytes)),
+    }
+}
+
+/// Decompress snapshot bytes if they start with a zstd frame
+fn decode_entry(bytes: Vec<u8>) -> std::io::Result<Vec<u8>> {
+    if bytes.starts_with(&ZSTD_MAGIC) {
+        zstd::decode_all(bytes.as_slice())
+    } else {
+        Ok(bytes)
+    }
+}
+
 /// Load cache index from disk
 fn load_cache_index(root: &Path) -> std::io::Result<Option<CacheIndex>> {
     let path = root.join(CACHE_DIR).join(INDEX_FILE);
@@ -368,4 +432,49 @@ mod tests {
 
         assert!(has_cache(dir.path()));
     }
+
+    /// Snapshot-like blob: repeated element records with tags, classes and text
+    fn sample_snapshot(pages: usize) -> Vec<u8> {
+        let mut blob = Vec::new();
+        for i in 0..pages {
+            for (tag, class) in [("section", "content"), ("p", "body"), ("a", "link")] {
+                blob.extend_from_slice(&(tag.len() as u32).to_le_bytes());
+                blob.extend_from_slice(tag.as_bytes());
+                blob.extend_from_slice(b"class\0");
+                blob.extend_from_slice(class.as_bytes());
+                blob.extend_from_slice(&(i as u64).to_le_bytes());
+                blob.extend_from_slice(
+                    format!("Paragraph {i} of the page with some ordinary prose text.").as_bytes(),
+                );
+            }
+        }
+        blob
+    }
+
+    #[test]
+    fn entry_roundtrip_compressed_and_plain() {
+        let bytes = sample_snapshot(10);
+
+        let compressed = encode_entry(&bytes, Some(3)).unwrap();
+        assert!(compressed.starts_with(&ZSTD_MAGIC));
+        assert_eq!(decode_entry(compressed.into_owned()).unwrap(), bytes);
+
+        // Caches written without compression still load
+        let plain = encode_entry(&bytes, None).unwrap();
+        assert_eq!(&*plain, bytes.as_slice());
+        assert_eq!(decode_entry(plain.into_owned()).unwrap(), bytes);
+    }
+
+    #[test]
+    fn compression_shrinks_cache_entries() {
+        let bytes = sample_snapshot(100);
+        let compressed = encode_entry(&bytes, Some(3)).unwrap();
+
+        let ratio = compressed.len() as f64 / bytes.len() as f64;
+        assert!(
+            ratio < 0.4,
+            "compressed to {:.0}% of original",
+            ratio * 100.0
+        );
+    }
 }
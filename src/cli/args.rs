@@ -11,6 +11,10 @@ pub struct Cli {
     #[arg(long, global = true, default_value = "auto")]
     pub color: ColorChoice,
 
+    /// Print machine-readable JSON instead of colored text (one object per line)
+    #[arg(long, global = true)]
+    pub json: bool,
+
     /// Path to local Typst packages.
     #[arg(long, global = true, value_hint = clap::ValueHint::DirPath)]
     pub package_path: Option<PathBuf>,
@@ -49,6 +53,14 @@ pub enum Commands {
         /// Print generated config to stdout without creating files
         #[arg(long)]
         dry: bool,
+
+        /// Starter template: blog, docs or minimal (`list` prints them all)
+        #[arg(long, value_name = "NAME", default_value = "blog")]
+        template: String,
+
+        /// Only create `tola.toml` and `content/`, allowing a non-empty directory
+        #[arg(long, conflicts_with = "template")]
+        bare: bool,
     },
 
     /// Build the site for production
@@ -56,6 +68,10 @@ pub enum Commands {
     Build {
         #[command(flatten)]
         build_args: BuildArgs,
+
+        /// Keep watching and rebuild changed files, without starting a server
+        #[arg(short, long)]
+        watch: bool,
     },
 
     /// Start development server with hot reload
@@ -75,6 +91,10 @@ pub enum Commands {
         /// Enable file watching for auto-rebuild
         #[arg(short, long, action = clap::ArgAction::Set, num_args = 0..=1, default_missing_value = "true", require_equals = false)]
         watch: Option<bool>,
+
+        /// Open the site in the default browser after startup
+        #[arg(long, action = clap::ArgAction::Set, num_args = 0..=1, default_missing_value = "true", require_equals = false)]
+        open: Option<bool>,
     },
 
     /// Deploy the site to configured target
@@ -83,6 +103,16 @@ pub enum Commands {
         /// Force deploy even if there are uncommitted changes
         #[arg(short, long, action = clap::ArgAction::Set, num_args = 0..=1, default_missing_value = "true", require_equals = false)]
         force: Option<bool>,
+
+        /// Show files that would change without deploying
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Package the built site into a zip or tar.gz archive
+    Export {
+        #[command(flatten)]
+        args: ExportArgs,
     },
 
     /// Query metadata from content files
@@ -99,11 +129,51 @@ pub enum Commands {
         args: ValidateArgs,
     },
 
+    /// Remove the output directory and build cache
+    Clean {
+        /// Only clear the cache, keep the output directory
+        #[arg(long)]
+        cache_only: bool,
+    },
+
+    /// Create a new content page with a metadata stub
+    #[command(visible_alias = "n")]
+    New {
+        #[command(flatten)]
+        args: NewArgs,
+    },
+
     /// Check and fix common issues (missing templates, outdated files)
     #[command(visible_alias = "F")]
-    Fix,
+    Fix {
+        #[command(flatten)]
+        args: FixArgs,
+    },
+
+    /// Summarize pages, assets, output size and cache state
+    Stats,
+
+    /// Print JSON Schema for tola.toml (for editor completion and validation)
+    Schema,
+
+    /// Print a shell completion script to stdout
+    #[command(after_long_help = COMPLETIONS_HELP)]
+    Completions {
+        /// Target shell
+        shell: clap_complete::Shell,
+    },
 }
 
+const COMPLETIONS_HELP: &str = "\
+Installation:
+  bash        tola completions bash > ~/.local/share/bash-completion/completions/tola
+  zsh         tola completions zsh > ~/.zfunc/_tola   (with `fpath+=~/.zfunc` before compinit)
+  fish        tola completions fish > ~/.config/fish/completions/tola.fish
+  elvish      tola completions elvish >> ~/.config/elvish/rc.elv
+  powershell  tola completions powershell >> $PROFILE
+
+Or load for the current session only, e.g. `source <(tola completions bash)`.";
+
 /// Validate command arguments
 #[derive(clap::Args, Debug, Clone)]
 pub struct ValidateArgs {
@@ -123,6 +193,110 @@ pub struct ValidateArgs {
     /// Treat validation failures as warnings instead of errors
     #[arg(long, short = 'w')]
     pub warn_only: bool,
+
+    /// Report format
+    #[arg(long, value_enum, default_value_t = ValidateReporter::Human)]
+    pub reporter: ValidateReporter,
+
+    /// Write the JUnit report to this file instead of stdout
+    #[arg(long, value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
+    pub junit_output: Option<PathBuf>,
+
+    /// Also write a self-contained HTML report to this file
+    #[arg(long, value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
+    pub html_report: Option<PathBuf>,
+}
+
+/// Validate report format
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidateReporter {
+    /// Colored report on stderr
+    Human,
+    /// JUnit XML for CI test reporting, alongside the human report
+    Junit,
+}
+
+/// Export command arguments
+#[derive(clap::Args, Debug, Clone)]
+pub struct ExportArgs {
+    /// Archive format (default: from the `--output` extension, else zip)
+    #[arg(long, value_enum)]
+    pub format: Option<ExportFormat>,
+
+    /// Archive path (default: `site.zip` or `site.tar.gz`)
+    #[arg(long, value_name = "FILE", value_hint = clap::ValueHint::FilePath)]
+    pub output: Option<PathBuf>,
+
+    /// Omit output files matching a gitignore-style glob (e.g. `.tola/`, `*.map`)
+    #[arg(long, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+}
+
+/// Export archive format
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Deflate-compressed zip
+    Zip,
+    /// Gzip-compressed tarball
+    #[value(name = "tar.gz")]
+    TarGz,
+}
+
+impl ExportFormat {
+    /// File extension, without the leading dot.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Zip => "zip",
+            Self::TarGz => "tar.gz",
+        }
+    }
+
+    /// Format implied by an archive file name.
+    pub fn from_path(path: &std::path::Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".zip") {
+            Some(Self::Zip)
+        } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+            Some(Self::TarGz)
+        } else {
+            None
+        }
+    }
+}
+
+/// New command arguments
+#[derive(clap::Args, Debug, Clone)]
+pub struct NewArgs {
+    /// Page path relative to the content directory (e.g., `posts/hello-world`)
+    #[arg(value_hint = clap::ValueHint::FilePath)]
+    pub name: PathBuf,
+
+    /// Mark the page as a draft
+    #[arg(short, long)]
+    pub draft: bool,
+
+    /// Publish date (YYYY-MM-DD)
+    #[arg(long)]
+    pub date: Option<String>,
+}
+
+/// Fix command arguments
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct FixArgs {
+    /// Apply every update without prompting
+    #[arg(short = 'y', long)]
+    pub accept: bool,
+
+    /// Never prompt (CI): accept updates, only report link fixes, exit 1 if files changed
+    #[arg(long)]
+    pub non_interactive: bool,
+}
+
+impl FixArgs {
+    /// Whether updates are applied without asking.
+    pub const fn accepts_all(&self) -> bool {
+        self.accept || self.non_interactive
+    }
 }
 
 /// Shared build arguments for Build and Serve commands
@@ -167,6 +341,21 @@ pub struct BuildArgs {
     /// Skip draft pages during build (default: false, drafts are included)
     #[arg(short = 'E', long)]
     pub skip_drafts: bool,
+
+    /// Only compile content files matching a glob (relative to content dir).
+    ///
+    /// Other pages still take part in link validation; their existing output is kept.
+    /// Ignored by `serve`, which compiles on demand.
+    ///
+    /// Example: `tola build --only "posts/**"`
+    #[arg(long, value_name = "GLOB")]
+    pub only: Option<String>,
+
+    /// Write a JSON build manifest (pages, assets, timings, warnings) to this path.
+    ///
+    /// Ignored by `serve`.
+    #[arg(long, value_name = "PATH", value_hint = clap::ValueHint::FilePath)]
+    pub manifest: Option<PathBuf>,
 }
 
 /// Query command arguments
@@ -205,6 +394,65 @@ pub struct QueryArgs {
     /// Write output to file instead of stdout
     #[arg(short, long, value_hint = clap::ValueHint::FilePath)]
     pub output: Option<PathBuf>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = QueryFormat::Json)]
+    pub format: QueryFormat,
+
+    /// Separator for joining array values in CSV output
+    #[arg(long, default_value = "|")]
+    pub separator: String,
+
+    /// Start an interactive session (`field = value` queries, `:help` for commands)
+    #[arg(long, conflicts_with = "output")]
+    pub repl: bool,
+
+    /// Print the site's URL map (`urls` and `aliases`) instead of page metadata.
+    /// Reads the map stored by the last build, scanning the site if none exists.
+    #[arg(long, conflicts_with_all = ["repl", "fields", "paths"])]
+    pub urls: bool,
+
+    /// Print the dependency graph stored by the last build as Graphviz DOT.
+    /// Edges point from a template or file to the pages that use it.
+    #[arg(long, conflicts_with_all = ["repl", "fields", "paths", "urls"])]
+    pub dep_graph: bool,
+
+    /// Leave pages without dependencies out of `--dep-graph`
+    #[arg(long, requires = "dep_graph")]
+    pub skip_isolated: bool,
+
+    /// Add `word_count`, `char_count` and `reading_time_minutes`, counted from
+    /// the page text (`site.info.reading_speed` words per minute)
+    #[arg(long, conflicts_with_all = ["urls", "dep_graph"])]
+    pub stats: bool,
+
+    /// Sort pages (`reading_time`: longest first, implies `--stats`)
+    #[arg(long, value_enum, conflicts_with_all = ["urls", "dep_graph"])]
+    pub sort: Option<QuerySort>,
+}
+
+impl QueryArgs {
+    /// Whether page text statistics are needed (`--stats` or `--sort reading_time`)
+    pub fn wants_stats(&self) -> bool {
+        self.stats || self.sort == Some(QuerySort::ReadingTime)
+    }
+}
+
+/// Query output format
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryFormat {
+    /// JSON array, one object per page
+    Json,
+    /// CSV with a header row; nested fields flattened with dot notation
+    Csv,
+}
+
+/// Query sort order
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuerySort {
+    /// Reading time, longest first
+    #[value(name = "reading_time")]
+    ReadingTime,
 }
 
 #[allow(unused)]
@@ -215,20 +463,38 @@ impl Cli {
     pub const fn is_build(&self) -> bool {
         matches!(self.command, Commands::Build { .. })
     }
+    pub const fn is_build_watch(&self) -> bool {
+        matches!(self.command, Commands::Build { watch: true, .. })
+    }
     pub const fn is_serve(&self) -> bool {
         matches!(self.command, Commands::Serve { .. })
     }
     pub const fn is_deploy(&self) -> bool {
         matches!(self.command, Commands::Deploy { .. })
     }
+    pub const fn is_export(&self) -> bool {
+        matches!(self.command, Commands::Export { .. })
+    }
     pub const fn is_query(&self) -> bool {
         matches!(self.command, Commands::Query { .. })
     }
     pub const fn is_validate(&self) -> bool {
         matches!(self.command, Commands::Validate { .. })
     }
+    pub const fn is_clean(&self) -> bool {
+        matches!(self.command, Commands::Clean { .. })
+    }
+    pub const fn is_new(&self) -> bool {
+        matches!(self.command, Commands::New { .. })
+    }
     pub const fn is_fix(&self) -> bool {
-        matches!(self.command, Commands::Fix)
+        matches!(self.command, Commands::Fix { .. })
+    }
+    pub const fn is_schema(&self) -> bool {
+        matches!(self.command, Commands::Schema)
+    }
+    pub const fn is_completions(&self) -> bool {
+        matches!(self.command, Commands::Completions { .. })
     }
 }
 
@@ -253,6 +519,91 @@ mod tests {
         assert_eq!(cli.package_cache_path, Some(PathBuf::from("typst-cache")));
     }
 
+    #[test]
+    fn parses_query_format() {
+        let cli = Cli::try_parse_from(["tola", "query", "--format", "csv"]).unwrap();
+        let Commands::Query { args } = cli.command else {
+            panic!("expected query command");
+        };
+        assert_eq!(args.format, QueryFormat::Csv);
+        assert_eq!(args.separator, "|");
+    }
+
+    #[test]
+    fn parses_query_stats_and_sort() {
+        let cli = Cli::try_parse_from(["tola", "query", "--sort", "reading_time"]).unwrap();
+        let Commands::Query { args } = cli.command else {
+            panic!("expected query command");
+        };
+        assert_eq!(args.sort, Some(QuerySort::ReadingTime));
+        assert!(!args.stats);
+        assert!(args.wants_stats());
+
+        assert!(Cli::try_parse_from(["tola", "query", "--stats", "--urls"]).is_err());
+    }
+
+    #[test]
+    fn parses_global_json_flag() {
+        let cli = Cli::try_parse_from(["tola", "build", "--json"]).unwrap();
+        assert!(cli.json);
+        let cli = Cli::try_parse_from(["tola", "--json", "stats"]).unwrap();
+        assert!(cli.json);
+    }
+
+    #[test]
+    fn parses_build_watch() {
+        let cli = Cli::try_parse_from(["tola", "build", "--watch"]).unwrap();
+        assert!(matches!(cli.command, Commands::Build { watch: true, .. }));
+        let cli = Cli::try_parse_from(["tola", "build"]).unwrap();
+        assert!(matches!(cli.command, Commands::Build { watch: false, .. }));
+    }
+
+    #[test]
+    fn parses_export_args() {
+        let cli = Cli::try_parse_from([
+            "tola",
+            "export",
+            "--format",
+            "tar.gz",
+            "--exclude",
+            ".tola/",
+            "--exclude",
+            "*.map",
+        ])
+        .unwrap();
+        let Commands::Export { args } = cli.command else {
+            panic!("expected export command");
+        };
+        assert_eq!(args.format, Some(ExportFormat::TarGz));
+        assert_eq!(args.exclude, [".tola/", "*.map"]);
+        assert_eq!(args.output, None);
+    }
+
+    #[test]
+    fn export_format_from_path() {
+        use std::path::Path;
+        assert_eq!(
+            ExportFormat::from_path(Path::new("dist/Site.ZIP")),
+            Some(ExportFormat::Zip)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("site.tar.gz")),
+            Some(ExportFormat::TarGz)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("site.tgz")),
+            Some(ExportFormat::TarGz)
+        );
+        assert_eq!(ExportFormat::from_path(Path::new("site.tar")), None);
+    }
+
+    #[test]
+    fn parses_completions_shell() {
+        let cli = Cli::try_parse_from(["tola", "completions", "zsh"]).unwrap();
+        assert!(cli.is_completions());
+        assert!(Cli::try_parse_from(["tola", "completions", "tcsh"]).is_err());
+    }
+
     #[test]
     fn rejects_typst_prefixed_package_path() {
         let err =
@@ -0,0 +1,197 @@
+//! CDN rule files for Netlify and Cloudflare Pages.
+//!
+//! Both hosts read `_headers` and `_redirects` from the deploy root:
+//!
+//! ```text
+//! # _headers
+//! /.tola/*
+//!   Cache-Control: public, max-age=31536000, immutable
+//! /about/
+//!   Cache-Control: public, max-age=0, must-revalidate
+//!
+//! # _redirects
+//! /old-about/ /about/ 301
+//! ```
+//!
+//! Rules never overlap: both hosts merge every matching rule, which would
+//! join two `Cache-Control` values into one header.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::address::SiteIndex;
+use crate::config::SiteConfig;
+use crate::log;
+
+/// Cache-Control for files whose name changes with their content
+const IMMUTABLE: &str = "public, max-age=31536000, immutable";
+
+/// Cache-Control for HTML pages, revalidated on every request
+const REVALIDATE: &str = "public, max-age=0, must-revalidate";
+
+/// Write `_headers` and `_redirects` to the output root when enabled
+pub(super) fn write_cdn_files(config: &SiteConfig, state: &SiteIndex) -> Result<()> {
+    let cdn = &config.build.cdn;
+    let root = config.paths().output_root();
+
+    if cdn.headers_file {
+        let path = root.join("_headers");
+        let rules = collect_rules(root, "/")
+            .with_context(|| format!("Failed to scan output {}", root.display()))?
+            .1;
+        fs::write(&path, render_headers(&rules))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        log!("cdn"; "_headers");
+    }
+
+    if cdn.redirects_file {
+        let path = root.join("_redirects");
+        let redirects = state.read(|_, space| {
+            let mut redirects: Vec<(String, String)> = space
+                .redirects()
+                .iter()
+                .map(|(alias, redirect)| (alias.to_string(), redirect.target.to_string()))
+                .collect();
+            redirects.sort();
+            redirects
+        });
+        fs::write(&path, render_redirects(&redirects))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        log!("cdn"; "_redirects");
+    }
+
+    Ok(())
+}
+
+fn render_headers(rules: &[(String, &str)]) -> String {
+    rules
+        .iter()
+        .map(|(url, cache)| format!("{url}\n  Cache-Control: {cache}\n"))
+        .collect()
+}
+
+fn render_redirects(redirects: &[(String, String)]) -> String {
+    redirects
+        .iter()
+        .map(|(alias, target)| format!("{alias} {target} 301\n"))
+        .collect()
+}
+
+/// Collect cache rules under `dir` (served at `url`, ending in `/`)
+///
+/// Returns whether every file below `dir` is content-hashed. Such directories
+/// collapse into a single `url*` rule.
+fn collect_rules(dir: &Path, url: &str) -> io::Result<(bool, Vec<(String, &'static str)>)> {
+    let mut entries: Vec<_> = fs::read_dir(dir)?.filter_map(|e| e.ok()).collect();
+    entries.sort_by_key(|e| e.file_name());
+
+    let mut all_hashed = true;
+    let mut rules = Vec::new();
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if entry.file_type()?.is_dir() {
+            if name == ".git" {
+                continue;
+            }
+            let (hashed, sub_rules) = collect_rules(&entry.path(), &format!("{url}{name}/"))?;
+            all_hashed &= hashed;
+            rules.extend(sub_rules);
+        } else if is_hashed_name(&name) {
+            rules.push((format!("{url}{name}"), IMMUTABLE));
+        } else {
+            all_hashed = false;
+            if name == "index.html" {
+                rules.push((url.to_string(), REVALIDATE));
+            } else if name.ends_with(".html") {
+                rules.push((format!("{url}{name}"), REVALIDATE));
+            }
+        }
+    }
+
+    if all_hashed && !rules.is_empty() {
+        return Ok((true, vec![(format!("{url}*"), IMMUTABLE)]));
+    }
+    Ok((all_hashed, rules))
+}
+
+/// Check if a filename carries a content hash (`svg-3f2a9c1b04de.svg`, `photo-40w.<hash>.png`)
+///
+/// The last `-`/`.` separated part before the extension must be at least 8
+/// hex digits with a letter among them, so dates like `backup-20240101.zip`
+/// are not taken for hashes.
+fn is_hashed_name(name: &str) -> bool {
+    let Some((stem, _ext)) = name.rsplit_once('.') else {
+        return false;
+    };
+    let Some((_, token)) = stem.rsplit_once(['-', '.']) else {
+        return false;
+    };
+
+    token.len() >= 8
+        && token.bytes().all(|b| b.is_ascii_hexdigit())
+        && token.bytes().any(|b| b.is_ascii_alphabetic())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_is_hashed_name() {
+        assert!(is_hashed_name("svg-3f2a9c1b04de.svg"));
+        assert!(is_hashed_name("enhance-a1b2c3d4.css"));
+        assert!(is_hashed_name("photo-40w.3f2a9c1b04de.webp"));
+        assert!(!is_hashed_name("style.css"));
+        assert!(!is_hashed_name("app-dark.css"));
+        assert!(!is_hashed_name("backup-20240101.zip"));
+        assert!(!is_hashed_name("a1b2c3d4e5f6"));
+    }
+
+    #[test]
+    fn test_collect_rules() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        for file in [
+            "index.html",
+            "404.html",
+            "about/index.html",
+            ".tola/enhance-a1b2c3d4.css",
+            ".tola/svg-3f2a9c1b04de.svg",
+            "assets/site-0123456789ab.css",
+            "assets/logo.png",
+        ] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+
+        let (all_hashed, rules) = collect_rules(root, "/").unwrap();
+        assert!(!all_hashed);
+        assert_eq!(
+            rules,
+            [
+                ("/.tola/*".to_string(), IMMUTABLE),
+                ("/404.html".to_string(), REVALIDATE),
+                ("/about/".to_string(), REVALIDATE),
+                ("/assets/site-0123456789ab.css".to_string(), IMMUTABLE),
+                ("/".to_string(), REVALIDATE),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let headers = render_headers(&[("/.tola/*".to_string(), IMMUTABLE)]);
+        assert_eq!(
+            headers,
+            "/.tola/*\n  Cache-Control: public, max-age=31536000, immutable\n"
+        );
+
+        let redirects = render_redirects(&[("/old/".to_string(), "/new/".to_string())]);
+        assert_eq!(redirects, "/old/ /new/ 301\n");
+    }
+}
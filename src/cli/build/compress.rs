@@ -0,0 +1,255 @@
+//! Pre-compressed output files (`[build.compression]`).
+//!
+//! Writes `file.html.br` / `file.html.gz` next to text output files (HTML,
+//! CSS, JS, JSON and XML, including feeds, sitemaps and SVG). Runs at the
+//! very end of a build, after the feed, sitemap and search index exist.
+//!
+//! A variant is only written when it is smaller than the original.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use jwalk::WalkDir;
+use rayon::prelude::*;
+
+use crate::cli::clean::format_bytes;
+use crate::config::{CompressionConfig, SiteConfig};
+use crate::log;
+use crate::utils::mime::{self, types};
+use crate::utils::plural_count;
+
+/// Brotli quality (0-11), highest since files are compressed once per build
+const BROTLI_QUALITY: u32 = 11;
+/// Brotli window size (log2)
+const BROTLI_WINDOW: u32 = 22;
+
+/// Content types worth pre-compressing
+const COMPRESSIBLE: &[&str] = &[
+    types::HTML,
+    types::CSS,
+    types::JAVASCRIPT,
+    types::JSON,
+    types::XML,
+    types::RSS,
+    types::ATOM,
+    types::SVG,
+];
+
+/// Sizes of the files a compression pass covered
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct CompressionStats {
+    files: usize,
+    original_bytes: u64,
+    /// Bytes served with brotli (the original where `.br` was not smaller).
+    brotli_bytes: u64,
+    /// Bytes served with gzip (the original where `.gz` was not smaller).
+    gzip_bytes: u64,
+}
+
+impl CompressionStats {
+    const fn merge(self, other: Self) -> Self {
+        Self {
+            files: self.files + other.files,
+            original_bytes: self.original_bytes + other.original_bytes,
+            brotli_bytes: self.brotli_bytes + other.brotli_bytes,
+            gzip_bytes: self.gzip_bytes + other.gzip_bytes,
+        }
+    }
+}
+
+/// Compress the output directory if `[build.compression]` is enabled
+pub fn compress_output(config: &SiteConfig) -> Result<()> {
+    let compression = &config.build.compression;
+    if !compression.is_enabled() {
+        return Ok(());
+    }
+    // Watch rebuilds rewrite pages without recompressing them
+    if config.cli.is_some_and(|cli| cli.is_build_watch()) {
+        log!("compress"; "skipped with --watch, compressed files would go stale");
+        return Ok(());
+    }
+
+    let output_dir = config.paths().output_dir();
+    let files = collect_files(&output_dir, compression);
+    let stats = files
+        .par_iter()
+        .map(|path| compress_file(path, compression))
+        .try_reduce(CompressionStats::default, |a, b| Ok(a.merge(b)))?;
+
+    if stats.files > 0 {
+        let mut sizes = Vec::new();
+        if compression.brotli {
+            sizes.push(format!("brotli {}", format_bytes(stats.brotli_bytes)));
+        }
+        if compression.gzip {
+            sizes.push(format!("gzip {}", format_bytes(stats.gzip_bytes)));
+        }
+        log!(
+            "compress";
+            "{} ({}) -> {}",
+            plural_count(stats.files, "file"),
+            format_bytes(stats.original_bytes),
+            sizes.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Compressible files under `output_dir`, minus `exclude` matches
+fn collect_files(output_dir: &Path, compression: &CompressionConfig) -> Vec<PathBuf> {
+    WalkDir::new(output_dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .map(|entry| entry.path())
+        .filter(|path| COMPRESSIBLE.contains(&mime::from_path(path)))
+        .filter(|path| {
+            let relative = path.strip_prefix(output_dir).unwrap_or(path);
+            !compression.is_excluded(&relative.to_string_lossy().replace('\\', "/"))
+        })
+        .collect()
+}
+
+/// Write the enabled variants of one file
+fn compress_file(path: &Path, compression: &CompressionConfig) -> Result<CompressionStats> {
+    let data = fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let size = data.len() as u64;
+    if size < compression.min_size_bytes {
+        return Ok(CompressionStats::default());
+    }
+
+    let mut stats = CompressionStats {
+        files: 1,
+        original_bytes: size,
+        ..Default::default()
+    };
+    if compression.brotli {
+        stats.brotli_bytes =
+            write_variant(path, "br", &brotli_compress(&data)?, size)?.unwrap_or(size);
+    }
+    if compression.gzip {
+        stats.gzip_bytes = write_variant(path, "gz", &gzip_compress(&data)?, size)?.unwrap_or(size);
+    }
+    Ok(stats)
+}
+
+/// Write `path.{ext}` if `compressed` is smaller than the original
+///
+/// Returns the written size, `None` (and removes a stale variant) otherwise.
+fn write_variant(path: &Path, ext: &str, compressed: &[u8], original: u64) -> Result<Option<u64>> {
+    let mut variant = path.as_os_str().to_owned();
+    variant.push(".");
+    variant.push(ext);
+    let variant = PathBuf::from(variant);
+
+    if compressed.len() as u64 >= original {
+        let _ = fs::remove_file(&variant);
+        return Ok(None);
+    }
+    fs::write(&variant, compressed)
+        .with_context(|| format!("Failed to write {}", variant.display()))?;
+    Ok(Some(compressed.len() as u64))
+}
+
+fn brotli_compress(data: &[u8]) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    {
+        let mut writer =
+            brotli::CompressorWriter::new(&mut out, 4096, BROTLI_QUALITY, BROTLI_WINDOW);
+        writer.write_all(data)?;
+    }
+    Ok(out)
+}
+
+fn gzip_compress(data: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(data)?;
+    Ok(encoder.finish()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use tempfile::TempDir;
+
+    fn config(brotli: bool, gzip: bool) -> CompressionConfig {
+        CompressionConfig {
+            brotli,
+            gzip,
+            exclude: vec!["skip/**".to_string()],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_compress_file_writes_variants() {
+        let dir = TempDir::new().unwrap();
+        let html = "<p>compress me</p>\n".repeat(200);
+        let path = dir.path().join("index.html");
+        fs::write(&path, &html).unwrap();
+
+        let stats = compress_file(&path, &config(true, true)).unwrap();
+        assert_eq!(stats.files, 1);
+        assert_eq!(stats.original_bytes, html.len() as u64);
+        assert!(stats.brotli_bytes < stats.original_bytes);
+        assert!(stats.gzip_bytes < stats.original_bytes);
+
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(fs::File::open(dir.path().join("index.html.gz")).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, html);
+
+        let mut decoded = String::new();
+        brotli::Decompressor::new(
+            fs::File::open(dir.path().join("index.html.br")).unwrap(),
+            4096,
+        )
+        .read_to_string(&mut decoded)
+        .unwrap();
+        assert_eq!(decoded, html);
+    }
+
+    #[test]
+    fn test_compress_file_skips_small_files() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("tiny.css");
+        fs::write(&path, "a{}").unwrap();
+
+        let stats = compress_file(&path, &config(true, true)).unwrap();
+        assert_eq!(stats, CompressionStats::default());
+        assert!(!dir.path().join("tiny.css.br").exists());
+        assert!(!dir.path().join("tiny.css.gz").exists());
+    }
+
+    #[test]
+    fn test_collect_files() {
+        let dir = TempDir::new().unwrap();
+        for file in [
+            "index.html",
+            "feed.xml",
+            "logo.png",
+            "skip/a.js",
+            "app.js.gz",
+        ] {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "x").unwrap();
+        }
+
+        let mut files: Vec<_> = collect_files(dir.path(), &config(true, false))
+            .into_iter()
+            .map(|path| path.strip_prefix(dir.path()).unwrap().to_path_buf())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            [PathBuf::from("feed.xml"), PathBuf::from("index.html")]
+        );
+    }
+}
@@ -0,0 +1,227 @@
+//! Build manifest (`tola build --manifest <path>`).
+//!
+//! Lists what a build produced, for CI dashboards and deploy diffing:
+//!
+//! ```json
+//! {
+//!   "version": "0.7.0",
+//!   "commit": "3f2a...",
+//!   "build_time_ms": 1520,
+//!   "pages": [{ "source": "content/index.typ", "permalink": "/", "output_path": "index.html",
+//!               "size_bytes": 5120, "compile_time_ms": 12, "was_cached": false }],
+//!   "assets": [{ "source": "assets/logo.png", "output_path": "assets/logo.png", "size_bytes": 2048 }],
+//!   "warnings": [{ "location": "content/index.typ:3", "message": "unknown font family" }]
+//! }
+//! ```
+//!
+//! See [`crate::compiler::page::PAGE_TIMINGS`] for what `compile_time_ms` covers.
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::address::{Resource, SiteIndex};
+use crate::compiler::page::PAGE_TIMINGS;
+use crate::config::SiteConfig;
+use crate::log;
+use crate::utils::git;
+
+/// Everything a build produced
+#[derive(Debug, Serialize)]
+pub struct BuildManifest {
+    /// tola version.
+    pub version: &'static str,
+    /// `HEAD` commit of the project repository, if any.
+    pub commit: Option<String>,
+    /// Wall-clock build time.
+    pub build_time_ms: u64,
+    pub pages: Vec<PageEntry>,
+    pub assets: Vec<AssetEntry>,
+    pub warnings: Vec<WarningEntry>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PageEntry {
+    /// Source file, relative to the site root.
+    pub source: String,
+    pub permalink: String,
+    /// Output file, relative to the output directory.
+    pub output_path: String,
+    pub size_bytes: u64,
+    /// `None` when the page was not compiled in this build.
+    pub compile_time_ms: Option<u64>,
+    /// Output reused because source and dependencies were unchanged.
+    pub was_cached: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AssetEntry {
+    pub source: String,
+    pub output_path: String,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WarningEntry {
+    /// `path[:line]`, relative to the site root.
+    pub location: Option<String>,
+    pub message: String,
+}
+
+impl BuildManifest {
+    /// Collect pages and assets from the address space of a finished build
+    pub fn collect(
+        config: &SiteConfig,
+        state: &SiteIndex,
+        warnings: &typst_batch::Diagnostics,
+        build_time: Duration,
+    ) -> Self {
+        let root = config.get_root();
+        let output = &config.build.output;
+
+        let (mut pages, mut assets) = state.read(|_, space| {
+            let pages: Vec<PageEntry> = space
+                .pages()
+                .filter_map(|resource| match resource {
+                    Resource::Page { route, .. } => Some(PageEntry {
+                        source: relative(&route.source, root),
+                        permalink: route.permalink.to_string(),
+                        output_path: relative(&route.output_file, output),
+                        size_bytes: file_size(&route.output_file),
+                        compile_time_ms: PAGE_TIMINGS.get(&route.source).map(as_millis),
+                        was_cached: PAGE_TIMINGS.is_cached(&route.source),
+                    }),
+                    Resource::Asset { .. } => None,
+                })
+                .collect();
+            let assets: Vec<AssetEntry> = space
+                .assets()
+                .filter_map(|resource| match resource {
+                    Resource::Asset { route } => Some(AssetEntry {
+                        source: relative(&route.source, root),
+                        output_path: relative(&route.output, output),
+                        size_bytes: file_size(&route.output),
+                    }),
+                    Resource::Page { .. } => None,
+                })
+                .collect();
+            (pages, assets)
+        });
+        pages.sort_by(|a, b| a.output_path.cmp(&b.output_path));
+        assets.sort_by(|a, b| a.output_path.cmp(&b.output_path));
+
+        let warnings = warnings
+            .iter()
+            .map(|warning| WarningEntry {
+                location: warning.path.as_deref().map(|path| {
+                    let path = relative(Path::new(path), root);
+                    match warning.line {
+                        Some(line) => format!("{path}:{line}"),
+                        None => path,
+                    }
+                }),
+                message: warning.message.clone(),
+            })
+            .collect();
+
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            commit: project_commit(root),
+            build_time_ms: as_millis(build_time),
+            pages,
+            assets,
+            warnings,
+        }
+    }
+
+    /// Write the manifest as pretty-printed JSON
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)
+            .with_context(|| format!("Failed to write build manifest to {}", path.display()))?;
+
+        log!("manifest"; "{}", path.display());
+        Ok(())
+    }
+}
+
+/// `HEAD` commit id of the repository containing the site
+fn project_commit(root: &Path) -> Option<String> {
+    let repo = git::discover_repo(root).ok()?;
+    let repo = repo.to_thread_local();
+    let id = repo.head_id().ok()?;
+    Some(id.to_string())
+}
+
+/// Path relative to `base`, with `/` separators
+fn relative(path: &Path, base: &Path) -> String {
+    path.strip_prefix(base)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+fn file_size(path: &Path) -> u64 {
+    fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+fn as_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::UrlPath;
+    use crate::page::PageRoute;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_pages() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let mut config = SiteConfig::default();
+        config.set_root(root);
+        config.build.output = root.join("public");
+
+        let source = root.join("content/about.typ");
+        let output_file = config.build.output.join("about/index.html");
+        fs::create_dir_all(output_file.parent().unwrap()).unwrap();
+        fs::write(&output_file, "<html></html>").unwrap();
+
+        let route = PageRoute {
+            source: source.clone(),
+            is_index: false,
+            is_404: false,
+            permalink: UrlPath::from_page("/about/"),
+            output_dir: output_file.parent().unwrap().to_path_buf(),
+            output_file,
+            full_url: "/about/".to_string(),
+        };
+        let state = SiteIndex::new();
+        state.edit(|_, space| space.register_page(route, None));
+        PAGE_TIMINGS.mark_cached(&source);
+
+        let warnings = typst_batch::Diagnostics::from_vec(Vec::new());
+        let manifest =
+            BuildManifest::collect(&config, &state, &warnings, Duration::from_millis(42));
+
+        assert_eq!(manifest.build_time_ms, 42);
+        assert_eq!(manifest.pages.len(), 1);
+        let entry = &manifest.pages[0];
+        assert_eq!(entry.source, "content/about.typ");
+        assert_eq!(entry.permalink, "/about/");
+        assert_eq!(entry.output_path, "about/index.html");
+        assert_eq!(entry.size_bytes, 13);
+        assert!(entry.was_cached);
+        assert!(manifest.warnings.is_empty());
+    }
+}
@@ -6,11 +6,21 @@
 //! - **Collect** - Gather content files and assets
 //! - **Compile** - Parallel content compilation + asset processing
 //! - **Iterative** - Rebuild iterative pages with complete metadata
-//! - **Post-process** - Flatten assets, CNAME, CSS processor, enhance CSS
+//! - **Post-process** - Flatten assets, CNAME, CSS processor, enhance CSS, CDN rule files
 //! - **Post Hooks** - User-defined post-build commands
 //! - **Finalize** - Cache persistence, warnings, logging
+//!
+//! After the feed and sitemap are written, [`compress`] adds pre-compressed
+//! `.br` / `.gz` files (`[build.compression]`).
+//!
+//! With `--watch`, [`watch`] keeps rebuilding changed files afterwards.
 
+mod cdn;
+pub mod compress;
+pub mod manifest;
 mod pipeline;
+pub mod report;
+pub mod watch;
 
 use crate::{
     address::SiteIndex,
@@ -23,6 +33,14 @@ use crate::{
 };
 use anyhow::Result;
 
+/// Result of a full site build
+pub struct BuildOutput {
+    /// Iterative pages, compiled once all metadata is known.
+    pub pages: Pages,
+    /// Compiler warnings (already printed).
+    pub warnings: typst_batch::Diagnostics,
+}
+
 /// Build the entire site using two-phase compilation
 ///
 /// Pipeline: pre-hooks -> init -> collect -> compile -> iterative -> post-process -> post-hooks -> finalize
@@ -31,7 +49,7 @@ pub fn build_site(
     config: &SiteConfig,
     state: &SiteIndex,
     quiet: bool,
-) -> Result<Pages> {
+) -> Result<BuildOutput> {
     let warnings = WarningCollector::new();
 
     // Initialize (must be before pre hooks to clean output dir first)
@@ -57,6 +75,10 @@ pub fn build_site(
         progress.as_ref(),
     )?;
 
+    if let Some(p) = progress {
+        p.finish();
+    }
+
     // Log drafts skipped
     if !quiet && metadata.stats.has_skipped_drafts() {
         log!(
@@ -66,7 +88,17 @@ pub fn build_site(
         );
     }
 
+    // Log pages reused from the cache
+    if !quiet && metadata.stats.cache_hits > 0 {
+        log!(
+            "build";
+            "{} unchanged, reused from cache",
+            plural_count(metadata.stats.cache_hits, "page")
+        );
+    }
+
     // Rebuild iterative pages with complete metadata
+    let iterative_progress = pipeline::create_iterative_progress(&metadata, quiet);
     let pages = pipeline::rebuild_iterative_pages(
         mode,
         config,
@@ -75,20 +107,21 @@ pub fn build_site(
         deps_hash,
         &metadata,
         &warnings,
+        iterative_progress.as_ref(),
     )?;
 
-    if let Some(p) = progress {
+    if let Some(p) = iterative_progress {
         p.finish();
     }
 
     // Post-processing
-    pipeline::post_process(config, quiet)?;
+    pipeline::post_process(config, state, quiet)?;
 
     // Post Hooks
     hooks::run_post_hooks(config, mode, true)?;
 
     // Finalize
-    pipeline::finalize_build(config, state, &warnings, quiet)?;
+    let warnings = pipeline::finalize_build(config, state, &metadata.stats, &warnings, quiet)?;
 
-    Ok(pages)
+    Ok(BuildOutput { pages, warnings })
 }
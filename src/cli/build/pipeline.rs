@@ -12,7 +12,7 @@ use crate::{
     asset::process_asset,
     compiler::{
         collect_all_files,
-        page::{self, MetadataResult, Pages, TypstHost, WarningCollector},
+        page::{self, CompileStats, MetadataResult, Pages, TypstHost, WarningCollector},
     },
     config::{SiteConfig, section::build::DiagnosticsConfig},
     core::{BuildMode, ContentKind, is_shutdown},
@@ -20,6 +20,7 @@ use crate::{
     log,
     logger::ProgressLine,
     package::generate_lsp_stubs,
+    utils::plural_count,
 };
 
 /// Collected files for the build
@@ -38,7 +39,11 @@ pub(super) fn init_build(config: &SiteConfig) -> Result<TypstHost> {
     // Generate LSP stubs for tinymist completion
     let _ = generate_lsp_stubs(config.get_root());
 
+    if config.build.clean && config.build.only.is_some() {
+        log!("warning"; "--clean with --only drops output of pages outside the filter");
+    }
     ensure_output_dir(&config.build.output, config.build.clean)?;
+    remove_draft_previews(config)?;
 
     if config.build.clean
         && let Err(e) = crate::cache::clear_cache_dir(config.get_root())
@@ -51,6 +56,8 @@ pub(super) fn init_build(config: &SiteConfig) -> Result<TypstHost> {
 
     // Clear caches for accurate change detection
     freshness::clear_cache();
+    page::PAGE_TIMINGS.clear();
+    page::BUILD_ERRORS.clear();
 
     Ok(typst_host)
 }
@@ -65,7 +72,13 @@ pub(super) fn collect_build_files(config: &SiteConfig) -> BuildFiles {
         .collect();
 
     // Count content files by type (content assets handled separately)
-    let content_files = collect_all_files(&config.build.content);
+    // `--only` narrows what gets compiled, so progress totals follow it
+    let content_files: Vec<_> = config
+        .build
+        .content_dirs()
+        .flat_map(collect_all_files)
+        .filter(|p| config.build.is_selected(p))
+        .collect();
     let typst_count = content_files
         .iter()
         .filter(|p| ContentKind::from_path(p) == Some(ContentKind::Typst))
@@ -82,9 +95,9 @@ pub(super) fn collect_build_files(config: &SiteConfig) -> BuildFiles {
     }
 }
 
-/// Create progress display if not quiet
+/// Create progress display if not quiet (never in JSON mode)
 pub(super) fn create_progress(files: &BuildFiles, quiet: bool) -> Option<ProgressLine> {
-    if quiet {
+    if quiet || crate::logger::json::is_json() {
         return None;
     }
     Some(ProgressLine::new(&[
@@ -94,7 +107,24 @@ pub(super) fn create_progress(files: &BuildFiles, quiet: bool) -> Option<Progres
     ]))
 }
 
+/// Create the `[iterative]` progress display for the second pass
+///
+/// Kept apart from the compile progress so the two passes read differently.
+pub(super) fn create_iterative_progress(
+    metadata: &MetadataResult,
+    quiet: bool,
+) -> Option<ProgressLine> {
+    if quiet || crate::logger::json::is_json() || !metadata.has_iterative_pages() {
+        return None;
+    }
+    Some(ProgressLine::with_prefix(
+        "iterative",
+        &[("pages", metadata.iterative_paths.len())],
+    ))
+}
+
 /// Compile content and process assets in parallel
+#[allow(clippy::too_many_arguments)]
 pub(super) fn compile_and_process(
     mode: BuildMode,
     config: &SiteConfig,
@@ -158,6 +188,7 @@ fn process_assets(
 }
 
 /// Rebuild iterative pages if any exist
+#[allow(clippy::too_many_arguments)]
 pub(super) fn rebuild_iterative_pages(
     mode: BuildMode,
     config: &SiteConfig,
@@ -166,11 +197,18 @@ pub(super) fn rebuild_iterative_pages(
     deps_hash: ContentHash,
     metadata: &MetadataResult,
     warnings: &WarningCollector,
+    progress: Option<&ProgressLine>,
 ) -> Result<Pages> {
     if !metadata.has_iterative_pages() {
         return Ok(Pages { items: vec![] });
     }
 
+    let progress_fn = |completed: usize, _total: usize| {
+        if let Some(p) = progress {
+            p.set("pages", completed);
+        }
+    };
+
     match state.with_pages(|pages| {
         page::rebuild_iterative_pages(
             mode,
@@ -182,18 +220,22 @@ pub(super) fn rebuild_iterative_pages(
             Some(deps_hash),
             metadata.snapshot.clone(),
             warnings,
+            Some(&progress_fn),
         )
     }) {
         Ok(pages) => Ok(Pages { items: pages }),
         Err(e) => {
-            log!("error"; "compile failed: {:#}", e);
+            // JSON diagnostics report the compile errors themselves
+            if !crate::logger::json::is_json_diagnostics() {
+                log!("error"; "compile failed: {:#}", e);
+            }
             Err(anyhow!("Build failed"))
         }
     }
 }
 
-/// Post-processing (flatten assets, CNAME, HTML 404, content assets)
-pub(super) fn post_process(config: &SiteConfig, _quiet: bool) -> Result<()> {
+/// Post-processing (flatten assets, CNAME, HTML 404, content assets, CDN rule files)
+pub(super) fn post_process(config: &SiteConfig, state: &SiteIndex, _quiet: bool) -> Result<()> {
     let clean = config.build.clean;
 
     // Flatten assets (files copied to output root)
@@ -213,6 +255,9 @@ pub(super) fn post_process(config: &SiteConfig, _quiet: bool) -> Result<()> {
         crate::pipeline::transform::cleanup_nobg_originals();
     }
 
+    // Netlify/Cloudflare `_headers` and `_redirects`
+    super::cdn::write_cdn_files(config, state)?;
+
     Ok(())
 }
 
@@ -246,12 +291,17 @@ fn copy_html_404(config: &SiteConfig) -> Result<()> {
 }
 
 /// Finalize build (warnings, cache, logging)
+///
+/// With `--verbose`, logs compiled page counts per format.
+///
+/// Returns the printed warnings.
 pub(super) fn finalize_build(
     config: &SiteConfig,
     state: &SiteIndex,
+    stats: &CompileStats,
     warnings: &WarningCollector,
     quiet: bool,
-) -> Result<()> {
+) -> Result<typst_batch::Diagnostics> {
     // Print compiler warnings with truncation
     let drained = warnings.drain();
     if !drained.is_empty() {
@@ -260,17 +310,34 @@ pub(super) fn finalize_build(
 
     // Persist VDOM cache for serve reuse
     let source_paths = state.read(|_, address| address.source_paths());
-    if let Err(e) =
-        crate::cache::persist_cache(&page::BUILD_CACHE, &source_paths, config.get_root())
-    {
+    if let Err(e) = crate::cache::persist_cache(
+        &page::BUILD_CACHE,
+        &source_paths,
+        config.get_root(),
+        config.build.cache.compression(),
+    ) {
         crate::debug!("build"; "failed to persist vdom cache: {}", e);
     }
 
+    // Persist URL map for `tola query --urls`
+    let url_map = state.read(|_, address| address.to_json(config.get_root()));
+    if let Err(e) = crate::cache::persist_url_map(&url_map, config.get_root()) {
+        crate::debug!("build"; "failed to persist url map: {}", e);
+    }
+
     if !quiet {
+        if crate::logger::is_verbose() {
+            log!(
+                "build";
+                "compiled {} and {}",
+                plural_count(stats.typst_pages, "Typst page"),
+                plural_count(stats.markdown_pages, "Markdown page")
+            );
+        }
         log_build_result(&config.build.output)?;
     }
 
-    Ok(())
+    Ok(drained)
 }
 
 /// Print warnings with max_warnings limit
@@ -304,6 +371,26 @@ fn ensure_output_dir(output: &Path, clean: bool) -> Result<()> {
     }
 }
 
+/// Remove draft previews left in the output by `tola serve`
+///
+/// Only `serve` with `serve.drafts.enable` writes drafts, so regular builds
+/// must not ship them.
+fn remove_draft_previews(config: &SiteConfig) -> Result<()> {
+    let prefix = config.serve.drafts.prefix.trim_matches('/');
+    if config.serve.drafts.enable || prefix.is_empty() {
+        return Ok(());
+    }
+
+    let drafts_dir = config.paths().output_dir().join(prefix);
+    if drafts_dir.is_dir() {
+        fs::remove_dir_all(&drafts_dir).with_context(|| {
+            format!("Failed to remove draft previews: {}", drafts_dir.display())
+        })?;
+        crate::debug!("build"; "removed draft previews: {}", drafts_dir.display());
+    }
+    Ok(())
+}
+
 fn log_build_result(output: &Path) -> Result<()> {
     let file_count = fs::read_dir(output)?
         .filter_map(Result::ok)
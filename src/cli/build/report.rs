@@ -0,0 +1,213 @@
+//! Build summary for `tola build --json`.
+//!
+//! Printed as the last JSON line, after any `log!` records:
+//!
+//! ```json
+//! {"type":"build","exit_code":1,"pages_compiled":12,"time_ms":840,
+//!  "errors":[{"path":"content/index.typ","line":3,"column":5,
+//!             "message":"unknown variable: x","severity":"error"}],
+//!  "warnings":[]}
+//! ```
+//!
+//! [`print_error_records`] writes the same errors to stderr as JSON diagnostics
+//! (`[build.diagnostics] format = "json"`).
+
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Serialize;
+use typst_batch::{DiagnosticInfo, DiagnosticSeverity, Diagnostics};
+
+use crate::compiler::page::{BUILD_ERRORS, PAGE_TIMINGS};
+use crate::logger::json::{DiagnosticRecord, emit_diagnostic};
+
+/// Outcome of a build
+#[derive(Debug, Serialize)]
+pub struct BuildReport {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    pub exit_code: i32,
+    pub errors: Vec<DiagnosticEntry>,
+    pub warnings: Vec<DiagnosticEntry>,
+    pub pages_compiled: usize,
+    pub time_ms: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DiagnosticEntry {
+    /// Source file, relative to the site root.
+    pub path: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+    pub severity: &'static str,
+}
+
+impl BuildReport {
+    /// Summarize a finished build
+    ///
+    /// `warnings` are the printed warnings of a successful build. Errors come
+    /// from compile diagnostics; a failure without any (e.g. an asset error)
+    /// is reported as a single path-less error.
+    pub fn new(
+        root: &Path,
+        result: Result<&Diagnostics, &anyhow::Error>,
+        build_time: Duration,
+    ) -> Self {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+
+        // Left in place for `print_error_records`
+        for info in BUILD_ERRORS.snapshot().iter() {
+            let entry = DiagnosticEntry::from_info(info, root);
+            match info.severity {
+                DiagnosticSeverity::Warning => warnings.push(entry),
+                _ => errors.push(entry),
+            }
+        }
+
+        match result {
+            Ok(diagnostics) => {
+                warnings.extend(
+                    diagnostics
+                        .iter()
+                        .map(|info| DiagnosticEntry::from_info(info, root)),
+                );
+            }
+            Err(e) if errors.is_empty() => errors.push(DiagnosticEntry {
+                path: None,
+                line: None,
+                column: None,
+                message: format!("{e:#}"),
+                severity: "error",
+            }),
+            Err(_) => {}
+        }
+
+        Self {
+            kind: "build",
+            exit_code: i32::from(result.is_err()),
+            errors,
+            warnings,
+            pages_compiled: PAGE_TIMINGS.compiled_count(),
+            time_ms: u64::try_from(build_time.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+
+    /// Print the report as a JSON line on stdout
+    pub fn print(&self) {
+        crate::logger::json::emit(self);
+    }
+}
+
+/// Write the compile errors of a failed command as JSON lines on stderr
+///
+/// A failure without compile diagnostics (e.g. an asset error) is written as
+/// a single file-less record.
+pub fn print_error_records(root: &Path, error: &anyhow::Error) {
+    let errors = BUILD_ERRORS.drain();
+    for info in errors.iter() {
+        emit_diagnostic(&DiagnosticRecord {
+            level: severity_name(&info.severity),
+            file: relative_path(info, root),
+            line: info.line,
+            col: info.column,
+            message: info.message.clone(),
+            hint: (!info.hints.is_empty()).then(|| {
+                let hints: Vec<String> = info.hints.iter().map(ToString::to_string).collect();
+                hints.join("\n")
+            }),
+        });
+    }
+
+    if errors.is_empty() {
+        emit_diagnostic(&DiagnosticRecord {
+            level: "error",
+            file: None,
+            line: None,
+            col: None,
+            message: format!("{error:#}"),
+            hint: None,
+        });
+    }
+}
+
+/// Source path of a diagnostic, relative to the site root
+fn relative_path(info: &DiagnosticInfo, root: &Path) -> Option<String> {
+    info.path.as_deref().map(|path| {
+        let path = Path::new(path);
+        path.strip_prefix(root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    })
+}
+
+fn severity_name(severity: &DiagnosticSeverity) -> &'static str {
+    match severity {
+        DiagnosticSeverity::Warning => "warning",
+        _ => "error",
+    }
+}
+
+impl DiagnosticEntry {
+    fn from_info(info: &DiagnosticInfo, root: &Path) -> Self {
+        Self {
+            path: relative_path(info, root),
+            line: info.line,
+            column: info.column,
+            message: info.message.clone(),
+            severity: severity_name(&info.severity),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(severity: DiagnosticSeverity, path: &str, message: &str) -> DiagnosticInfo {
+        DiagnosticInfo {
+            severity,
+            message: message.to_string(),
+            path: Some(path.to_string()),
+            line: Some(3),
+            column: Some(5),
+            source_lines: Vec::new(),
+            hints: Vec::new(),
+            traces: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_report_success_and_failure() {
+        let root = Path::new("/site");
+        let warnings = Diagnostics::from_vec(vec![info(
+            DiagnosticSeverity::Warning,
+            "/site/content/index.typ",
+            "unknown font family",
+        )]);
+
+        let report = BuildReport::new(root, Ok(&warnings), Duration::from_millis(7));
+        assert_eq!(report.exit_code, 0);
+        assert!(report.errors.is_empty());
+        assert_eq!(report.warnings.len(), 1);
+        assert_eq!(
+            report.warnings[0].path.as_deref(),
+            Some("content/index.typ")
+        );
+        assert_eq!(report.warnings[0].severity, "warning");
+
+        let json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&report).unwrap()).unwrap();
+        assert_eq!(json["type"], "build");
+        assert_eq!(json["time_ms"], 7);
+
+        let error = anyhow::anyhow!("Build failed");
+        let report = BuildReport::new(root, Err(&error), Duration::ZERO);
+        assert_eq!(report.exit_code, 1);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].message, "Build failed");
+        assert_eq!(report.errors[0].path, None);
+    }
+}
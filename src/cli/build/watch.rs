@@ -0,0 +1,49 @@
+//! Rebuild on file changes without a dev server (`tola build --watch`).
+//!
+//! Runs the watch actors of `serve` after the initial build, minus the HTTP
+//! and WebSocket servers:
+//!
+//! ```text
+//! FsActor --> CompilerActor --> VdomActor
+//! (watch)       (typst)         (cache)
+//! ```
+//!
+//! Changed pages are written as production HTML (see [`BuildMode::WATCH`]).
+//!
+//! [`BuildMode::WATCH`]: crate::core::BuildMode::WATCH
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use crossbeam::channel;
+
+use crate::actor::Coordinator;
+use crate::address::SiteIndex;
+use crate::config::config_handle;
+use crate::core::{register_watcher, set_healthy, set_serving};
+use crate::log;
+
+/// Watch for changes and rebuild until Ctrl+C
+///
+/// `state` must hold the pages of a successful build.
+pub fn watch_site(state: Arc<SiteIndex>) -> Result<()> {
+    let (shutdown_tx, shutdown_rx) = channel::unbounded::<()>();
+    register_watcher(shutdown_tx);
+
+    // File events are only processed once the site is built and healthy
+    set_serving();
+    set_healthy(true);
+
+    log!("watch"; "watching for changes, press Ctrl+C to stop");
+
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()?;
+
+    rt.block_on(
+        Coordinator::with_config(config_handle(), state)
+            .with_shutdown_signal(shutdown_rx)
+            .run(),
+    )
+}
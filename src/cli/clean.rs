@@ -0,0 +1,168 @@
+//! Clean command - remove build output and cache.
+
+use anyhow::{Context, Result, bail};
+use jwalk::WalkDir;
+use std::fs;
+use std::path::Path;
+
+use crate::cache::{CACHE_DIR, clear_cache_dir};
+use crate::config::SiteConfig;
+use crate::log;
+
+/// Run the clean command
+pub fn run_clean(config: &SiteConfig, cache_only: bool) -> Result<()> {
+    let root = config.get_root();
+    let mut freed = 0;
+
+    if !cache_only {
+        freed += remove_output_dir(config)?;
+    }
+
+    let cache_dir = root.join(CACHE_DIR);
+    if cache_dir.exists() {
+        let size = dir_size(&cache_dir);
+        clear_cache_dir(root).with_context(|| {
+            format!("Failed to remove cache directory '{}'", cache_dir.display())
+        })?;
+        log!("clean"; "removed {} ({})", CACHE_DIR, format_bytes(size));
+        freed += size;
+    }
+
+    if freed == 0 {
+        log!("clean"; "nothing to clean");
+    } else {
+        log!("clean"; "freed {}", format_bytes(freed));
+    }
+
+    Ok(())
+}
+
+/// Remove `build.output`, refusing paths outside the project root
+///
+/// Returns the number of bytes freed.
+fn remove_output_dir(config: &SiteConfig) -> Result<u64> {
+    let output = &config.build.output;
+    let relative = config.root_relative(output);
+
+    // `root_relative` returns the path unchanged when it is not under root
+    if relative.is_absolute() || relative.as_os_str().is_empty() {
+        bail!(
+            "Refusing to remove output directory '{}': it is not inside the project root",
+            output.display()
+        );
+    }
+
+    if !output.exists() {
+        return Ok(0);
+    }
+
+    let size = dir_size(output);
+    fs::remove_dir_all(output)
+        .with_context(|| format!("Failed to remove output directory '{}'", output.display()))?;
+    log!("clean"; "removed {} ({})", relative.display(), format_bytes(size));
+
+    Ok(size)
+}
+
+/// Total size of all files under `dir`
+pub(crate) fn dir_size(dir: &Path) -> u64 {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Format a byte count for display (e.g., `1.5 MB`)
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KB", "MB", "GB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)] // Display only
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+    format!("{size:.1} {unit}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn make_config(temp: &TempDir) -> SiteConfig {
+        let mut config = SiteConfig::default();
+        config.set_root(temp.path());
+        config.build.output = temp.path().join("public");
+        config
+    }
+
+    #[test]
+    fn test_clean_removes_output_and_cache() {
+        let temp = TempDir::new().unwrap();
+        let config = make_config(&temp);
+        fs::create_dir_all(temp.path().join("public/posts")).unwrap();
+        fs::write(temp.path().join("public/posts/index.html"), "hello").unwrap();
+        fs::create_dir_all(temp.path().join(CACHE_DIR)).unwrap();
+        fs::write(temp.path().join(CACHE_DIR).join("index"), "x").unwrap();
+
+        run_clean(&config, false).unwrap();
+
+        assert!(!temp.path().join("public").exists());
+        assert!(!temp.path().join(CACHE_DIR).exists());
+    }
+
+    #[test]
+    fn test_clean_cache_only_keeps_output() {
+        let temp = TempDir::new().unwrap();
+        let config = make_config(&temp);
+        fs::create_dir_all(temp.path().join("public")).unwrap();
+        fs::create_dir_all(temp.path().join(CACHE_DIR)).unwrap();
+
+        run_clean(&config, true).unwrap();
+
+        assert!(temp.path().join("public").exists());
+        assert!(!temp.path().join(CACHE_DIR).exists());
+    }
+
+    #[test]
+    fn test_clean_refuses_output_outside_root() {
+        let temp = TempDir::new().unwrap();
+        let outside = TempDir::new().unwrap();
+        let mut config = make_config(&temp);
+        config.build.output = outside.path().to_path_buf();
+
+        let err = run_clean(&config, false).unwrap_err();
+
+        assert!(err.to_string().contains("not inside the project root"));
+        assert!(outside.path().exists());
+    }
+
+    #[test]
+    fn test_clean_refuses_project_root() {
+        let temp = TempDir::new().unwrap();
+        let mut config = make_config(&temp);
+        config.build.output = temp.path().to_path_buf();
+
+        assert!(run_clean(&config, false).is_err());
+        assert!(temp.path().exists());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MB");
+    }
+}
@@ -14,6 +14,7 @@ use crate::compiler::family::Indexed;
 use crate::compiler::page::scan;
 use crate::compiler::page::typst::{MAX_METADATA_SCAN_ITERATIONS, scan_single_with_current};
 use crate::config::SiteConfig;
+use crate::config::section::BuildSectionConfig;
 use crate::core::{BuildMode, ContentKind};
 use crate::package::build_visible_inputs;
 use crate::page::{HashStabilityTracker, PageKind, PageMeta, StabilityDecision, StoredPageMap};
@@ -67,7 +68,14 @@ impl<T> Default for ParallelCollector<T> {
 }
 
 /// Collect content files based on CLI paths
-pub fn collect_content_files(paths: &[PathBuf], content_dir: &Path) -> Result<Vec<PathBuf>> {
+///
+/// Relative paths resolve against the primary content directory; with no
+/// paths, every content directory (including `build.extra_content`) is collected.
+pub fn collect_content_files(
+    paths: &[PathBuf],
+    build: &BuildSectionConfig,
+) -> Result<Vec<PathBuf>> {
+    let content_dir = build.content.as_path();
     // Handle stdin case: read paths from stdin when `-` is passed
     let paths: Vec<PathBuf> = if paths.len() == 1 && paths[0].as_os_str() == "-" {
         read_paths_from_stdin()?
@@ -77,7 +85,7 @@ pub fn collect_content_files(paths: &[PathBuf], content_dir: &Path) -> Result<Ve
 
     if paths.is_empty() {
         // No paths specified: collect all content files
-        let all_files = collect_all_files(content_dir);
+        let all_files = build.content_dirs().flat_map(collect_all_files).collect();
         return Ok(filter_content_files(all_files));
     }
 
@@ -448,11 +456,14 @@ pub fn populate_stored_pages(
     use crate::compiler::collect_all_files;
 
     let root = crate::utils::path::normalize_path(config.get_root());
-    let content_dir = root.join(&config.build.content);
     let label = &config.build.meta.label;
 
     // Collect all content files
-    let all_files = collect_all_files(&content_dir);
+    let all_files = config
+        .build
+        .content_dirs()
+        .flat_map(|dir| collect_all_files(&root.join(dir)))
+        .collect();
     let content_files = filter_content_files(all_files);
     let (typst_files, markdown_files) = ContentKind::partition_by_kind(&content_files);
 
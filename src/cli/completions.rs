@@ -0,0 +1,50 @@
+//! Completions command - print a shell completion script.
+//!
+//! Generated from the clap definitions, so every subcommand and flag is
+//! covered. Path arguments (`--config`, `--output`, ...) complete through
+//! their `ValueHint`s in shells that support it.
+
+use std::io::{self, Write};
+
+use anyhow::Result;
+use clap::CommandFactory;
+use clap_complete::Shell;
+
+use super::Cli;
+
+/// Print the completion script for `shell` to stdout
+pub fn print_completions(shell: Shell) -> Result<()> {
+    let mut stdout = io::stdout().lock();
+    write_completions(shell, &mut stdout);
+    stdout.flush()?;
+    Ok(())
+}
+
+fn write_completions(shell: Shell, out: &mut dyn Write) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_completions_cover_subcommands_and_flags() {
+        let mut out = Vec::new();
+        write_completions(Shell::Bash, &mut out);
+        let script = String::from_utf8(out).unwrap();
+
+        for word in [
+            "build",
+            "serve",
+            "query",
+            "validate",
+            "--config",
+            "--warn-only",
+        ] {
+            assert!(script.contains(word), "missing `{word}`");
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Deploy change sets for `--dry-run`.
+//!
+//! Both sides are `path -> digest` maps: the last deployed snapshot and the
+//! current output. Digests only need to be comparable with each other.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::utils::plural_count;
+use crate::{debug, log};
+
+/// Files a deploy would add, modify or delete
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct DeployDiff {
+    pub added: Vec<String>,
+    pub modified: Vec<String>,
+    pub deleted: Vec<String>,
+}
+
+/// Per file type counts
+#[derive(Debug, Default, PartialEq, Eq)]
+struct TypeCounts {
+    added: usize,
+    modified: usize,
+    deleted: usize,
+}
+
+impl DeployDiff {
+    /// Compare the deployed snapshot with the current output
+    pub fn between(
+        previous: &BTreeMap<String, String>,
+        current: &BTreeMap<String, String>,
+    ) -> Self {
+        let mut diff = Self::default();
+        for (path, digest) in current {
+            match previous.get(path) {
+                None => diff.added.push(path.clone()),
+                Some(old) if old != digest => diff.modified.push(path.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.deleted = previous
+            .keys()
+            .filter(|path| !current.contains_key(*path))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
+    }
+
+    /// Log the change summary grouped by file type (file list in verbose mode)
+    pub fn report(&self, provider: &str) {
+        if self.is_empty() {
+            log!("deploy"; "dry run ({}): no changes", provider);
+            return;
+        }
+
+        log!(
+            "deploy";
+            "dry run ({}): {} added, {} modified, {} deleted",
+            provider,
+            plural_count(self.added.len(), "file"),
+            self.modified.len(),
+            self.deleted.len()
+        );
+        for (ext, counts) in self.by_type() {
+            log!(
+                "deploy";
+                "  {:<8} +{} ~{} -{}",
+                ext,
+                counts.added,
+                counts.modified,
+                counts.deleted
+            );
+        }
+
+        for path in &self.added {
+            debug!("deploy"; "+ {}", path);
+        }
+        for path in &self.modified {
+            debug!("deploy"; "~ {}", path);
+        }
+        for path in &self.deleted {
+            debug!("deploy"; "- {}", path);
+        }
+    }
+
+    /// Counts keyed by lowercase extension (`(none)` for files without one)
+    fn by_type(&self) -> BTreeMap<String, TypeCounts> {
+        let mut groups: BTreeMap<String, TypeCounts> = BTreeMap::new();
+        let mut count = |paths: &[String], field: fn(&mut TypeCounts) -> &mut usize| {
+            for path in paths {
+                *field(groups.entry(file_type(path)).or_default()) += 1;
+            }
+        };
+        count(&self.added, |c| &mut c.added);
+        count(&self.modified, |c| &mut c.modified);
+        count(&self.deleted, |c| &mut c.deleted);
+        groups
+    }
+}
+
+fn file_type(path: &str) -> String {
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map_or_else(|| "(none)".to_string(), str::to_ascii_lowercase)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(path, digest)| ((*path).to_string(), (*digest).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_deploy_diff_between() {
+        let previous = snapshot(&[("index.html", "1"), ("style.css", "2"), ("old.html", "3")]);
+        let current = snapshot(&[("index.html", "1"), ("style.css", "9"), ("new.JS", "4")]);
+
+        let diff = DeployDiff::between(&previous, &current);
+        assert_eq!(diff.added, vec!["new.JS"]);
+        assert_eq!(diff.modified, vec!["style.css"]);
+        assert_eq!(diff.deleted, vec!["old.html"]);
+
+        let groups = diff.by_type();
+        assert_eq!(groups.keys().collect::<Vec<_>>(), vec!["css", "html", "js"]);
+        assert_eq!(
+            groups["html"],
+            TypeCounts {
+                added: 0,
+                modified: 0,
+                deleted: 1
+            }
+        );
+
+        assert!(DeployDiff::between(&current, &current).is_empty());
+    }
+}
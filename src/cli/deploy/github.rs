@@ -0,0 +1,116 @@
+//! GitHub Pages deployment over git.
+//!
+//! `build.output` is committed as its own repository and pushed to
+//! `deploy.github.branch`. User and organization pages (`page_type = "user"`
+//! or `"org"`) are served from the root of `<name>.github.io`, so their
+//! `path_prefix` is cleared before building (see `SiteConfig::finalize`).
+
+use anyhow::Result;
+
+use super::DeployDiff;
+use crate::config::SiteConfig;
+use crate::config::section::GithubPageType;
+use crate::log;
+use crate::utils::git;
+
+/// Deploy to GitHub Pages
+pub fn deploy(config: &SiteConfig) -> Result<()> {
+    check_page_type(config);
+
+    if config.deploy.dry_run {
+        return dry_run(config);
+    }
+
+    let repo = ensure_output_repo(config)?;
+
+    git::commit_all(&repo, "deploy it")?;
+    git::push(&repo, config)?;
+    Ok(())
+}
+
+/// Warn about settings that don't fit user/org pages
+fn check_page_type(config: &SiteConfig) {
+    let github = &config.deploy.github;
+    if github.page_type == GithubPageType::Project {
+        return;
+    }
+
+    let repo = repo_name(&github.url).unwrap_or_default();
+    if !is_pages_repo(repo) {
+        log!(
+            "warning";
+            "page_type = \"{}\" expects a `<name>.github.io` repository, got `{}`",
+            github.page_type.as_str(),
+            repo
+        );
+    }
+    if github.branch == "gh-pages" {
+        log!(
+            "warning";
+            "{} pages are published from the repository's default branch, not gh-pages",
+            github.page_type.as_str()
+        );
+    }
+}
+
+/// Compare the output directory against its last deployed commit
+fn dry_run(config: &SiteConfig) -> Result<()> {
+    let output = &config.build.output;
+    let deployed = match git::open_repo(output) {
+        Ok(repo) => git::head_blobs(&repo)?,
+        Err(_) => Default::default(),
+    };
+    let current = git::worktree_blobs(output)?;
+
+    DeployDiff::between(&deployed, &current).report("github");
+    Ok(())
+}
+
+/// Ensure output directory is a git repository for deploy
+fn ensure_output_repo(config: &SiteConfig) -> Result<gix::ThreadSafeRepository> {
+    git::open_repo(&config.build.output).or_else(|_| git::create_repo(&config.build.output))
+}
+
+/// Repository name of an HTTPS or SSH remote URL
+///
+/// `https://github.com/user/user.github.io.git` -> `user.github.io`
+fn repo_name(url: &str) -> Option<&str> {
+    let url = url.trim().trim_end_matches('/');
+    let url = url.strip_suffix(".git").unwrap_or(url);
+    let name = url.rsplit(['/', ':']).next()?;
+    (!name.is_empty()).then_some(name)
+}
+
+/// Whether `repo` is a user/org pages repository (`<name>.github.io`)
+fn is_pages_repo(repo: &str) -> bool {
+    repo.to_ascii_lowercase()
+        .strip_suffix(".github.io")
+        .is_some_and(|name| !name.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_name() {
+        assert_eq!(
+            repo_name("https://github.com/user/user.github.io"),
+            Some("user.github.io")
+        );
+        assert_eq!(repo_name("https://github.com/user/blog.git/"), Some("blog"));
+        assert_eq!(
+            repo_name("git@github.com:org/org.github.io.git"),
+            Some("org.github.io")
+        );
+        assert_eq!(repo_name(""), None);
+    }
+
+    #[test]
+    fn test_is_pages_repo() {
+        assert!(is_pages_repo("user.github.io"));
+        assert!(is_pages_repo("User.GitHub.io"));
+        assert!(!is_pages_repo("blog"));
+        assert!(!is_pages_repo(".github.io"));
+    }
+}
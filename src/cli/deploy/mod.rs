@@ -0,0 +1,23 @@
+//! Site deployment module.
+//!
+//! Handles deployment to various hosting providers.
+//!
+//! With `--dry-run`, each provider reports what would change instead of
+//! deploying: no network calls, no commits.
+
+mod diff;
+mod github;
+mod netlify;
+
+use crate::config::SiteConfig;
+use anyhow::{Result, bail};
+use diff::DeployDiff;
+
+/// Deploy the built site to configured provider
+pub fn deploy_site(config: &SiteConfig) -> Result<()> {
+    match config.deploy.provider.as_str() {
+        "github" => github::deploy(config),
+        "netlify" => netlify::deploy(config),
+        _ => bail!("This platform is not supported now"),
+    }
+}
@@ -0,0 +1,543 @@
+//! Netlify deployment over the REST API.
+//!
+//! Uses Netlify's file-digest flow:
+//!
+//! 1. `POST /sites/{site_id}/deploys` with a `path -> sha1` map of `build.output`
+//! 2. `PUT /deploys/{id}/files/{path}` for each digest Netlify does not have yet
+//! 3. Poll `GET /deploys/{id}` until the deploy is `ready`
+//!
+//! Netlify publishes the deploy by itself once every required file is uploaded.
+//! Unchanged files are never re-sent.
+//!
+//! The digests of the last successful deploy are kept in
+//! `.tola/cache/netlify-deploy.json` so `--dry-run` can diff offline.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
+use jwalk::WalkDir;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::{Client, Response};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use super::DeployDiff;
+use crate::cache::CACHE_DIR;
+use crate::config::SiteConfig;
+use crate::log;
+use crate::utils::{git, plural_count};
+
+const API_BASE: &str = "https://api.netlify.com/api/v1";
+
+const MANIFEST_FILE: &str = "netlify-deploy.json";
+
+/// Parallel file uploads.
+const UPLOAD_CONCURRENCY: usize = 8;
+
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+const POLL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Characters escaped in a URL path segment.
+const SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Deploy state returned by the Netlify API
+#[derive(Debug, Deserialize)]
+struct DeployState {
+    id: String,
+    #[serde(default)]
+    state: String,
+    /// SHA1 digests of files Netlify still needs.
+    #[serde(default)]
+    required: Vec<String>,
+    #[serde(default)]
+    ssl_url: Option<String>,
+    #[serde(default)]
+    deploy_ssl_url: Option<String>,
+    #[serde(default)]
+    error_message: Option<String>,
+}
+
+/// Digests of the last successful deploy
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Manifest {
+    site_id: String,
+    files: BTreeMap<String, String>,
+}
+
+/// A file in the output directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct OutputFile {
+    path: PathBuf,
+    sha1: String,
+}
+
+/// Deploy `build.output` to Netlify
+///
+/// When `production_branch` is set and the site repository is on another
+/// branch, a draft deploy is created instead; `force` publishes anyway.
+pub fn deploy(config: &SiteConfig) -> Result<()> {
+    let netlify = &config.deploy.netlify;
+    if netlify.site_id.trim().is_empty() {
+        bail!("[deploy.netlify] site_id is required");
+    }
+    let token = read_token(netlify.token_path.as_deref())?;
+
+    let branch = git::discover_repo(config.get_root())
+        .ok()
+        .and_then(|repo| git::current_branch(&repo).ok().flatten());
+    let draft = is_draft(
+        branch.as_deref(),
+        &netlify.production_branch,
+        config.deploy.force,
+    );
+
+    let files = collect_files(&config.build.output)?;
+    if files.is_empty() {
+        bail!(
+            "Nothing to deploy: {} is empty",
+            config.build.output.display()
+        );
+    }
+    let digests: BTreeMap<String, String> = files
+        .iter()
+        .map(|(key, file)| (key.clone(), file.sha1.clone()))
+        .collect();
+
+    let root = config.get_root();
+    if config.deploy.dry_run {
+        let deployed = restore_manifest(root, &netlify.site_id).unwrap_or_default();
+        DeployDiff::between(&deployed, &digests).report("netlify");
+        return Ok(());
+    }
+
+    let client = Client::builder()
+        .user_agent(concat!("tola/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .context("Failed to create HTTP client")?;
+
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .context("Failed to create tokio runtime")?;
+
+    let api = Api {
+        client,
+        base: API_BASE.to_string(),
+        token,
+    };
+
+    log!(
+        "deploy";
+        "netlify: {} deploy of {}",
+        if draft { "draft" } else { "production" },
+        plural_count(files.len(), "file")
+    );
+
+    let state = rt.block_on(run(api, &netlify.site_id, files, draft, branch.as_deref()))?;
+
+    if !draft {
+        let manifest = Manifest {
+            site_id: netlify.site_id.clone(),
+            files: digests,
+        };
+        if let Err(e) = persist_manifest(root, &manifest) {
+            crate::debug!("deploy"; "failed to persist netlify manifest: {}", e);
+        }
+    }
+
+    let url = if draft {
+        state.deploy_ssl_url
+    } else {
+        state.ssl_url.or(state.deploy_ssl_url)
+    };
+    match url {
+        Some(url) => log!("deploy"; "netlify: deploy {} is live at {}", state.id, url),
+        None => log!("deploy"; "netlify: deploy {} is live", state.id),
+    }
+    Ok(())
+}
+
+/// Draft unless on the production branch (or forced).
+///
+/// Sites outside a git repository, or with a detached HEAD, deploy to production.
+fn is_draft(branch: Option<&str>, production_branch: &str, force: bool) -> bool {
+    !force && !production_branch.is_empty() && branch.is_some_and(|b| b != production_branch)
+}
+
+/// Read the access token file
+fn read_token(path: Option<&Path>) -> Result<String> {
+    let path = path.context("[deploy.netlify] token_path is required")?;
+    let token = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read Netlify token: {}", path.display()))?;
+    let token = token.trim();
+    if token.is_empty() {
+        bail!("Netlify token file is empty: {}", path.display());
+    }
+    Ok(token.to_owned())
+}
+
+/// Collect output files keyed by their deploy path (`/about/index.html`)
+///
+/// The `.git` directory left behind by GitHub deploys is skipped.
+fn collect_files(output: &Path) -> Result<BTreeMap<String, OutputFile>> {
+    let mut files = BTreeMap::new();
+
+    let entries = WalkDir::new(output)
+        .process_read_dir(|_, _, _, children| {
+            children.retain(|entry| !matches!(entry, Ok(e) if e.file_name() == ".git"));
+        })
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file());
+
+    for entry in entries {
+        let path = entry.path();
+        let Ok(relative) = path.strip_prefix(output) else {
+            continue;
+        };
+        let key = format!("/{}", relative.to_string_lossy().replace('\\', "/"));
+        let bytes =
+            fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        let sha1 = sha1_hex(&bytes)?;
+        files.insert(key, OutputFile { path, sha1 });
+    }
+
+    Ok(files)
+}
+
+/// Files of the last production deploy to `site_id`
+fn restore_manifest(root: &Path, site_id: &str) -> Option<BTreeMap<String, String>> {
+    let json = fs::read_to_string(root.join(CACHE_DIR).join(MANIFEST_FILE)).ok()?;
+    let manifest: Manifest = serde_json::from_str(&json).ok()?;
+    (manifest.site_id == site_id).then_some(manifest.files)
+}
+
+fn persist_manifest(root: &Path, manifest: &Manifest) -> std::io::Result<()> {
+    let cache_dir = root.join(CACHE_DIR);
+    fs::create_dir_all(&cache_dir)?;
+    fs::write(
+        cache_dir.join(MANIFEST_FILE),
+        serde_json::to_string_pretty(manifest)?,
+    )
+}
+
+/// Hex SHA1 digest, as expected by the Netlify file-digest API
+fn sha1_hex(bytes: &[u8]) -> Result<String> {
+    let mut hasher = gix::hash::hasher(gix::hash::Kind::Sha1);
+    hasher.update(bytes);
+    Ok(hasher.try_finalize()?.to_hex().to_string())
+}
+
+/// Authenticated Netlify API client
+#[derive(Clone)]
+struct Api {
+    client: Client,
+    base: String,
+    token: String,
+}
+
+impl Api {
+    async fn create_deploy(
+        &self,
+        site_id: &str,
+        files: &BTreeMap<String, OutputFile>,
+        draft: bool,
+        branch: Option<&str>,
+    ) -> Result<DeployState> {
+        let digests: BTreeMap<&str, &str> = files
+            .iter()
+            .map(|(key, file)| (key.as_str(), file.sha1.as_str()))
+            .collect();
+        let mut body = serde_json::json!({ "files": digests, "draft": draft });
+        if let Some(branch) = branch.filter(|_| draft) {
+            body["branch"] = branch.into();
+        }
+
+        let url = format!("{}/sites/{}/deploys", self.base, encode_segment(site_id));
+        let response = self
+            .client
+            .post(url)
+            .bearer_auth(&self.token)
+            .header("Content-Type", "application/json")
+            .body(body.to_string())
+            .send()
+            .await
+            .context("Failed to create Netlify deploy")?;
+        parse(response).await
+    }
+
+    async fn upload(&self, deploy_id: &str, key: &str, path: &Path) -> Result<()> {
+        let bytes = fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        let encoded: Vec<_> = key
+            .trim_start_matches('/')
+            .split('/')
+            .map(encode_segment)
+            .collect();
+        let url = format!(
+            "{}/deploys/{}/files/{}",
+            self.base,
+            encode_segment(deploy_id),
+            encoded.join("/")
+        );
+
+        let response = self
+            .client
+            .put(url)
+            .bearer_auth(&self.token)
+            .header("Content-Type", "application/octet-stream")
+            .body(bytes)
+            .send()
+            .await
+            .with_context(|| format!("Failed to upload {key}"))?;
+        ensure_success(response)
+            .await
+            .with_context(|| format!("Failed to upload {key}"))?;
+        Ok(())
+    }
+
+    async fn get_deploy(&self, deploy_id: &str) -> Result<DeployState> {
+        let url = format!("{}/deploys/{}", self.base, encode_segment(deploy_id));
+        let response = self
+            .client
+            .get(url)
+            .bearer_auth(&self.token)
+            .send()
+            .await
+            .context("Failed to query Netlify deploy")?;
+        parse(response).await
+    }
+}
+
+/// Create the deploy, upload required files and wait until it is ready
+async fn run(
+    api: Api,
+    site_id: &str,
+    files: BTreeMap<String, OutputFile>,
+    draft: bool,
+    branch: Option<&str>,
+) -> Result<DeployState> {
+    let deploy = api.create_deploy(site_id, &files, draft, branch).await?;
+
+    // One upload per required digest; identical files share a digest
+    let mut by_digest: BTreeMap<&str, (&str, &Path)> = BTreeMap::new();
+    for (key, file) in &files {
+        by_digest
+            .entry(file.sha1.as_str())
+            .or_insert((key.as_str(), file.path.as_path()));
+    }
+    let uploads: Vec<(String, PathBuf)> = deploy
+        .required
+        .iter()
+        .filter_map(|sha| by_digest.get(sha.as_str()))
+        .map(|(key, path)| ((*key).to_owned(), path.to_path_buf()))
+        .collect();
+
+    log!(
+        "deploy";
+        "netlify: uploading {} ({} unchanged)",
+        plural_count(uploads.len(), "file"),
+        files.len().saturating_sub(uploads.len())
+    );
+
+    let permits = Arc::new(Semaphore::new(UPLOAD_CONCURRENCY));
+    let mut tasks = JoinSet::new();
+    for (key, path) in uploads {
+        let api = api.clone();
+        let id = deploy.id.clone();
+        let permits = Arc::clone(&permits);
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+            api.upload(&id, &key, &path).await
+        });
+    }
+    while let Some(joined) = tasks.join_next().await {
+        joined.context("Upload task failed")??;
+    }
+
+    wait_until_ready(&api, &deploy.id).await
+}
+
+/// Poll until Netlify has processed the deploy
+async fn wait_until_ready(api: &Api, deploy_id: &str) -> Result<DeployState> {
+    let started = Instant::now();
+    loop {
+        let state = api.get_deploy(deploy_id).await?;
+        match state.state.as_str() {
+            "ready" => return Ok(state),
+            "error" => bail!(
+                "Netlify deploy {} failed: {}",
+                deploy_id,
+                state.error_message.as_deref().unwrap_or("unknown error")
+            ),
+            _ if started.elapsed() > POLL_TIMEOUT => bail!(
+                "Timed out waiting for Netlify deploy {} (state: {})",
+                deploy_id,
+                state.state
+            ),
+            _ => tokio::time::sleep(POLL_INTERVAL).await,
+        }
+    }
+}
+
+fn encode_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, SEGMENT).to_string()
+}
+
+/// Fail with the response body on non-2xx status
+async fn ensure_success(response: Response) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    bail!("Netlify API returned {}: {}", status, body.trim())
+}
+
+async fn parse(response: Response) -> Result<DeployState> {
+    let bytes = ensure_success(response).await?.bytes().await?;
+    serde_json::from_slice(&bytes).context("Unexpected Netlify API response")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::thread;
+    use tempfile::TempDir;
+    use tiny_http::{Method, Response, Server};
+
+    #[test]
+    fn test_is_draft() {
+        assert!(!is_draft(Some("main"), "main", false));
+        assert!(is_draft(Some("feature"), "main", false));
+        assert!(!is_draft(Some("feature"), "main", true));
+        assert!(!is_draft(None, "main", false));
+        assert!(!is_draft(Some("feature"), "", false));
+    }
+
+    #[test]
+    fn test_sha1_hex() {
+        assert_eq!(
+            sha1_hex(b"hello").unwrap(),
+            "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d"
+        );
+    }
+
+    #[test]
+    fn test_collect_files() {
+        let dir = TempDir::new().unwrap();
+        let out = dir.path();
+        fs::create_dir_all(out.join("posts/hello world")).unwrap();
+        fs::create_dir_all(out.join(".git")).unwrap();
+        fs::write(out.join("index.html"), "hello").unwrap();
+        fs::write(out.join("posts/hello world/index.html"), "hi").unwrap();
+        fs::write(out.join(".git/HEAD"), "ref").unwrap();
+
+        let files = collect_files(out).unwrap();
+        let keys: Vec<_> = files.keys().map(String::as_str).collect();
+        assert_eq!(keys, vec!["/index.html", "/posts/hello world/index.html"]);
+        assert_eq!(
+            files["/index.html"].sha1,
+            "aaf4c61ddcc5e8a2dabede0f3b482cd9aea9434d"
+        );
+    }
+
+    #[test]
+    fn test_manifest_roundtrip() {
+        let dir = TempDir::new().unwrap();
+        let manifest = Manifest {
+            site_id: "site-1".into(),
+            files: BTreeMap::from([("/a.html".to_string(), "abc".to_string())]),
+        };
+        persist_manifest(dir.path(), &manifest).unwrap();
+
+        assert_eq!(
+            restore_manifest(dir.path(), "site-1"),
+            Some(manifest.files.clone())
+        );
+        // A different site never diffs against this manifest
+        assert_eq!(restore_manifest(dir.path(), "site-2"), None);
+    }
+
+    #[test]
+    fn test_read_token() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("token");
+        fs::write(&path, "  secret\n").unwrap();
+        assert_eq!(read_token(Some(&path)).unwrap(), "secret");
+
+        fs::write(&path, "\n").unwrap();
+        assert!(read_token(Some(&path)).is_err());
+        assert!(read_token(None).is_err());
+    }
+
+    /// Fake Netlify API that requires `/a.html` and records uploads
+    fn spawn_server(uploads: Arc<Mutex<Vec<String>>>) -> String {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let required = sha1_hex(b"a").unwrap();
+
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let url = request.url().to_string();
+                let json = match (request.method(), url.as_str()) {
+                    (Method::Post, "/sites/site-1/deploys") => {
+                        assert!(body.contains("\"/a.html\""));
+                        format!(r#"{{"id":"d1","state":"uploading","required":["{required}"]}}"#)
+                    }
+                    (Method::Put, path) => {
+                        uploads.lock().unwrap().push(format!("{path} {body}"));
+                        "{}".to_string()
+                    }
+                    (Method::Get, "/deploys/d1") => {
+                        r#"{"id":"d1","state":"ready","ssl_url":"https://x.netlify.app"}"#
+                            .to_string()
+                    }
+                    _ => {
+                        let _ = request.respond(Response::empty(404));
+                        continue;
+                    }
+                };
+                let _ = request.respond(Response::from_string(json));
+            }
+        });
+
+        base
+    }
+
+    #[test]
+    fn test_run_uploads_required_files() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("a.html"), "a").unwrap();
+        fs::write(dir.path().join("b.html"), "b").unwrap();
+        let files = collect_files(dir.path()).unwrap();
+
+        let uploads = Arc::new(Mutex::new(Vec::new()));
+        let api = Api {
+            client: Client::new(),
+            base: spawn_server(Arc::clone(&uploads)),
+            token: "secret".into(),
+        };
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let state = rt.block_on(run(api, "site-1", files, false, None)).unwrap();
+
+        assert_eq!(state.state, "ready");
+        assert_eq!(state.ssl_url.as_deref(), Some("https://x.netlify.app"));
+        assert_eq!(*uploads.lock().unwrap(), vec!["/deploys/d1/files/a.html a"]);
+    }
+}
@@ -0,0 +1,138 @@
+//! Zip and tar.gz writers.
+
+use std::fs::File;
+use std::io::{self, Write};
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use super::Entry;
+
+/// Write `entries` as a deflate-compressed zip archive
+pub(super) fn write_zip(file: File, entries: &[Entry]) -> Result<()> {
+    let mut zip = ZipWriter::new(file);
+
+    for entry in entries {
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        #[cfg(unix)]
+        let options = {
+            use std::os::unix::fs::PermissionsExt;
+            options.unix_permissions(std::fs::metadata(&entry.path)?.permissions().mode())
+        };
+
+        if entry.is_dir {
+            zip.add_directory(entry.name.as_str(), options)?;
+        } else {
+            zip.start_file(entry.name.as_str(), options)?;
+            let mut source = File::open(&entry.path)
+                .with_context(|| format!("Failed to read {}", entry.path.display()))?;
+            io::copy(&mut source, &mut zip)?;
+        }
+    }
+
+    zip.finish()?;
+    Ok(())
+}
+
+/// Write `entries` as a gzip-compressed tar archive
+///
+/// Headers carry the full metadata (mode, mtime) of each file.
+pub(super) fn write_tar_gz(file: File, entries: &[Entry]) -> Result<()> {
+    let mut tar = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+    for entry in entries {
+        if entry.is_dir {
+            tar.append_dir(&entry.name, &entry.path)?;
+        } else {
+            tar.append_path_with_name(&entry.path, &entry.name)
+                .with_context(|| format!("Failed to read {}", entry.path.display()))?;
+        }
+    }
+
+    tar.into_inner()?.finish()?.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::Read;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn entries(dir: &Path) -> Vec<Entry> {
+        fs::create_dir_all(dir.join("bin")).unwrap();
+        fs::write(dir.join("index.html"), "<h1>hi</h1>").unwrap();
+        fs::write(dir.join("bin/run.sh"), "#!/bin/sh").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(dir.join("bin/run.sh"), fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        [("bin", true), ("bin/run.sh", false), ("index.html", false)]
+            .into_iter()
+            .map(|(name, is_dir)| Entry {
+                path: dir.join(name),
+                name: name.to_string(),
+                is_dir,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_write_zip() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("site.zip");
+        write_zip(File::create(&path).unwrap(), &entries(dir.path())).unwrap();
+
+        let mut zip = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        let names: Vec<_> = zip.file_names().map(str::to_owned).collect();
+        assert_eq!(names.len(), 3);
+        assert!(names.contains(&"bin/".to_string()));
+
+        let mut content = String::new();
+        zip.by_name("index.html")
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        assert_eq!(content, "<h1>hi</h1>");
+
+        #[cfg(unix)]
+        assert_eq!(
+            zip.by_name("bin/run.sh").unwrap().unix_mode().unwrap() & 0o777,
+            0o755
+        );
+    }
+
+    #[test]
+    fn test_write_tar_gz() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("site.tar.gz");
+        write_tar_gz(File::create(&path).unwrap(), &entries(dir.path())).unwrap();
+
+        let decoder = flate2::read::GzDecoder::new(File::open(&path).unwrap());
+        let mut archive = tar::Archive::new(decoder);
+        let mut found = Vec::new();
+        for entry in archive.entries().unwrap() {
+            let mut entry = entry.unwrap();
+            let name = entry.path().unwrap().to_string_lossy().into_owned();
+            let mode = entry.header().mode().unwrap();
+            if name == "index.html" {
+                let mut content = String::new();
+                entry.read_to_string(&mut content).unwrap();
+                assert_eq!(content, "<h1>hi</h1>");
+            }
+            found.push((name, mode & 0o777));
+        }
+
+        let names: Vec<_> = found.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["bin", "bin/run.sh", "index.html"]);
+        #[cfg(unix)]
+        assert_eq!(found[1].1, 0o755);
+    }
+}
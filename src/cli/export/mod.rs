@@ -0,0 +1,201 @@
+//! Site export module.
+//!
+//! Packages `build.output` into a zip or tar.gz archive, e.g. for CI
+//! artifacts. Entry names are relative to the output directory and keep
+//! their Unix permissions.
+
+mod archive;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+
+use crate::cli::clean::format_bytes;
+use crate::cli::{ExportArgs, ExportFormat};
+use crate::config::SiteConfig;
+use crate::log;
+use crate::utils::git::IgnoreMatcher;
+use crate::utils::plural_count;
+
+/// Repository left in the output directory by GitHub deploys, never exported
+const GIT_DIR: &str = ".git";
+
+/// A file or directory to archive
+#[derive(Debug)]
+struct Entry {
+    path: PathBuf,
+    /// Archive name, `/`-separated and relative to the output directory.
+    name: String,
+    is_dir: bool,
+}
+
+/// Whether `build.output` holds a previous build
+pub fn has_build_output(config: &SiteConfig) -> bool {
+    fs::read_dir(&config.build.output)
+        .is_ok_and(|mut entries| entries.any(|entry| entry.is_ok_and(|e| e.file_name() != GIT_DIR)))
+}
+
+/// Package `build.output` into the archive described by `args`
+pub fn export_site(config: &SiteConfig, args: &ExportArgs) -> Result<()> {
+    let format = args
+        .format
+        .or_else(|| args.output.as_deref().and_then(ExportFormat::from_path))
+        .unwrap_or(ExportFormat::Zip);
+    let path = args
+        .output
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(format!("site.{}", format.extension())));
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let file =
+        fs::File::create(&path).with_context(|| format!("Failed to create {}", path.display()))?;
+
+    // The archive may be written inside the output directory; never include it
+    let output = fs::canonicalize(&config.build.output)
+        .with_context(|| format!("Failed to read {}", config.build.output.display()))?;
+    let archive = fs::canonicalize(&path)?;
+    let matcher = IgnoreMatcher::new(args.exclude.join("\n").as_bytes());
+    let entries = collect_entries(&output, &matcher, &archive)?;
+
+    let file_count = entries.iter().filter(|e| !e.is_dir).count();
+    if file_count == 0 {
+        drop(file);
+        let _ = fs::remove_file(&path);
+        bail!(
+            "Nothing to export: {} is empty",
+            config.build.output.display()
+        );
+    }
+
+    match format {
+        ExportFormat::Zip => archive::write_zip(file, &entries),
+        ExportFormat::TarGz => archive::write_tar_gz(file, &entries),
+    }
+    .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    log!(
+        "export";
+        "wrote {} ({}, {})",
+        path.display(),
+        plural_count(file_count, "file"),
+        format_bytes(size)
+    );
+    Ok(())
+}
+
+/// Collect the entries below `output` in sorted order
+///
+/// Excluded directories are skipped with everything inside them.
+fn collect_entries(output: &Path, matcher: &IgnoreMatcher, skip: &Path) -> Result<Vec<Entry>> {
+    let mut entries = Vec::new();
+    collect_dir(output, output, matcher, skip, &mut entries)?;
+    Ok(entries)
+}
+
+fn collect_dir(
+    output: &Path,
+    dir: &Path,
+    matcher: &IgnoreMatcher,
+    skip: &Path,
+    entries: &mut Vec<Entry>,
+) -> Result<()> {
+    let mut children = fs::read_dir(dir)
+        .with_context(|| format!("Failed to read {}", dir.display()))?
+        .collect::<std::io::Result<Vec<_>>>()?;
+    children.sort_by_key(|child| child.file_name());
+
+    for child in children {
+        let path = child.path();
+        if child.file_name() == GIT_DIR || path == skip {
+            continue;
+        }
+
+        // Follow symlinks, archiving their targets
+        let is_dir = fs::metadata(&path)?.is_dir();
+        let name = path
+            .strip_prefix(output)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        if matcher.matches(&name, is_dir) {
+            continue;
+        }
+
+        entries.push(Entry {
+            path: path.clone(),
+            name,
+            is_dir,
+        });
+        if is_dir {
+            collect_dir(output, &path, matcher, skip, entries)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn site() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for file in [
+            "index.html",
+            "about/index.html",
+            "assets/app.js",
+            "assets/app.js.map",
+            ".tola/cache.bin",
+            ".git/HEAD",
+        ] {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, file).unwrap();
+        }
+        dir
+    }
+
+    fn names(entries: &[Entry]) -> Vec<&str> {
+        entries.iter().map(|e| e.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_collect_entries() {
+        let dir = site();
+        let matcher = IgnoreMatcher::new(b"");
+        let entries = collect_entries(dir.path(), &matcher, Path::new("")).unwrap();
+
+        assert_eq!(
+            names(&entries),
+            [
+                ".tola",
+                ".tola/cache.bin",
+                "about",
+                "about/index.html",
+                "assets",
+                "assets/app.js",
+                "assets/app.js.map",
+                "index.html",
+            ]
+        );
+        assert!(entries[0].is_dir);
+        assert!(!entries[1].is_dir);
+    }
+
+    #[test]
+    fn test_collect_entries_exclude() {
+        let dir = site();
+        let matcher = IgnoreMatcher::new(b".tola/\n*.map");
+        let skip = dir.path().join("index.html");
+        let entries = collect_entries(dir.path(), &matcher, &skip).unwrap();
+
+        assert_eq!(
+            names(&entries),
+            ["about", "about/index.html", "assets", "assets/app.js"]
+        );
+    }
+}
@@ -4,7 +4,12 @@ use std::path::Path;
 
 use crate::log;
 
-use super::{VERSION_PATTERN, prompt::prompt_create};
+use super::VERSION_PATTERN;
+use super::diff::FileDiff;
+use super::prompt::{HunkAnswer, UpdateAnswer, prompt_create, prompt_hunk, prompt_update};
+
+/// Diff lines printed before the full diff is offered with `[v]iew`
+const PREVIEW_LINES: usize = 40;
 
 /// File check result
 pub(super) enum CheckResult {
@@ -14,22 +19,35 @@ pub(super) enum CheckResult {
     Created,
     /// File is missing, user declined
     Skipped,
-    /// File exists but version is outdated or missing
+    /// File exists but version is outdated or missing, left unchanged
     Outdated,
+    /// Outdated file rewritten, fully or hunk by hunk
+    Updated,
+}
+
+impl CheckResult {
+    /// Whether the file on disk was written.
+    pub(super) const fn changed(&self) -> bool {
+        matches!(self, Self::Created | Self::Updated)
+    }
 }
 
 /// Check file and fix if needed
+///
+/// With `accept_all`, missing files are created and outdated ones replaced
+/// without prompting.
 pub(super) fn check_and_fix(
     path: &Path,
     name: &str,
     current_version: &str,
     github_url: &str,
+    accept_all: bool,
     generate: impl FnOnce() -> String,
 ) -> Result<CheckResult> {
     // Case: File missing -> prompt to create
     if !path.exists() {
         log!("fix"; "{} not found", name);
-        if prompt_create(name)? {
+        if accept_all || prompt_create(name)? {
             fs::write(path, generate())?;
             log!("fix"; "created {}", name);
             return Ok(CheckResult::Created);
@@ -39,17 +57,73 @@ pub(super) fn check_and_fix(
 
     // Case: File exists -> check version
     match extract_version(path)? {
-        Some(v) if v == current_version => Ok(CheckResult::Ok),
-        Some(v) => {
-            log!("fix"; "{}: v{} -> v{} available", name, v, current_version);
-            log!("fix"; "see `{}`", github_url);
-            Ok(CheckResult::Outdated)
+        Some(v) if v == current_version => return Ok(CheckResult::Ok),
+        Some(v) => log!("fix"; "{}: v{} -> v{} available", name, v, current_version),
+        None => log!("fix"; "{}: no version marker", name),
+    }
+    log!("fix"; "see `{}`", github_url);
+
+    let current = fs::read_to_string(path)?;
+    let updated = generate();
+    if accept_all {
+        fs::write(path, updated)?;
+        log!("fix"; "updated {}", name);
+        return Ok(CheckResult::Updated);
+    }
+
+    let diff = FileDiff::new(&current, &updated);
+    let lines = diff.lines();
+    print_diff(name, &lines[..lines.len().min(PREVIEW_LINES)]);
+    let truncated = lines.len() > PREVIEW_LINES;
+    if truncated {
+        eprintln!("... {} more diff lines", lines.len() - PREVIEW_LINES);
+    }
+
+    let accepted = loop {
+        match prompt_update(name, truncated)? {
+            UpdateAnswer::Accept => break vec![true; diff.hunk_count()],
+            UpdateAnswer::Decline => return Ok(CheckResult::Outdated),
+            UpdateAnswer::Patch => break select_hunks(&diff)?,
+            UpdateAnswer::View => print_diff(name, &lines),
         }
-        None => {
-            log!("fix"; "{}: no version marker", name);
-            log!("fix"; "see `{}`", github_url);
-            Ok(CheckResult::Outdated)
+    };
+    if !accepted.contains(&true) {
+        return Ok(CheckResult::Outdated);
+    }
+
+    fs::write(path, diff.apply(&accepted))?;
+    log!(
+        "fix";
+        "updated {} ({}/{} hunks)",
+        name,
+        accepted.iter().filter(|&&a| a).count(),
+        accepted.len()
+    );
+    Ok(CheckResult::Updated)
+}
+
+/// Ask about each hunk in turn
+fn select_hunks(diff: &FileDiff) -> Result<Vec<bool>> {
+    let mut accepted = vec![false; diff.hunk_count()];
+    for (index, accept) in accepted.iter_mut().enumerate() {
+        for line in diff.hunk_lines(index) {
+            eprintln!("{line}");
         }
+        match prompt_hunk()? {
+            HunkAnswer::Yes => *accept = true,
+            HunkAnswer::No => {}
+            HunkAnswer::Quit => break,
+        }
+    }
+    Ok(accepted)
+}
+
+/// Print diff lines under `--- a/name` / `+++ b/name` headers
+fn print_diff(name: &str, lines: &[String]) {
+    eprintln!("--- a/{name}");
+    eprintln!("+++ b/{name}");
+    for line in lines {
+        eprintln!("{line}");
     }
 }
 
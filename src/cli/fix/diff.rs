@@ -0,0 +1,113 @@
+//! Line diffs between an outdated file and its new version.
+
+use owo_colors::OwoColorize;
+use similar::{ChangeTag, DiffOp, TextDiff};
+
+/// Unchanged lines shown around each hunk
+const CONTEXT_LINES: usize = 3;
+
+/// `diff -u` style diff split into hunks
+pub(super) struct FileDiff<'a> {
+    diff: TextDiff<'a, 'a, 'a, str>,
+    hunks: Vec<Vec<DiffOp>>,
+}
+
+impl<'a> FileDiff<'a> {
+    pub(super) fn new(old: &'a str, new: &'a str) -> Self {
+        let diff = TextDiff::from_lines(old, new);
+        let hunks = diff.grouped_ops(CONTEXT_LINES);
+        Self { diff, hunks }
+    }
+
+    pub(super) fn hunk_count(&self) -> usize {
+        self.hunks.len()
+    }
+
+    /// Colored lines of one hunk, starting with its `@@` header.
+    pub(super) fn hunk_lines(&self, index: usize) -> Vec<String> {
+        let ops = &self.hunks[index];
+        let (Some(first), Some(last)) = (ops.first(), ops.last()) else {
+            return Vec::new();
+        };
+        let old_start = first.old_range().start;
+        let new_start = first.new_range().start;
+        let header = format!(
+            "@@ -{},{} +{},{} @@",
+            old_start + 1,
+            last.old_range().end - old_start,
+            new_start + 1,
+            last.new_range().end - new_start
+        );
+
+        let mut lines = vec![header.cyan().to_string()];
+        for op in ops {
+            for change in self.diff.iter_changes(op) {
+                let text = change.value().trim_end_matches('\n');
+                lines.push(match change.tag() {
+                    ChangeTag::Equal => format!(" {text}"),
+                    ChangeTag::Delete => format!("-{text}").red().to_string(),
+                    ChangeTag::Insert => format!("+{text}").green().to_string(),
+                });
+            }
+        }
+        lines
+    }
+
+    /// Colored lines of every hunk.
+    pub(super) fn lines(&self) -> Vec<String> {
+        (0..self.hunk_count())
+            .flat_map(|index| self.hunk_lines(index))
+            .collect()
+    }
+
+    /// Old content with only the accepted hunks applied.
+    pub(super) fn apply(&self, accepted: &[bool]) -> String {
+        let old = self.diff.old_slices();
+        let new = self.diff.new_slices();
+        let mut out = String::new();
+        let mut cursor = 0;
+
+        for (ops, &accept) in self.hunks.iter().zip(accepted) {
+            let (Some(first), Some(last)) = (ops.first(), ops.last()) else {
+                continue;
+            };
+            let old_range = first.old_range().start..last.old_range().end;
+            let new_range = first.new_range().start..last.new_range().end;
+            out.extend(old[cursor..old_range.start].iter().copied());
+            if accept {
+                out.extend(new[new_range].iter().copied());
+            } else {
+                out.extend(old[old_range.clone()].iter().copied());
+            }
+            cursor = old_range.end;
+        }
+        out.extend(old[cursor..].iter().copied());
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_selected_hunks() {
+        let old = (1..=20).map(|i| format!("line {i}\n")).collect::<String>();
+        let new = old
+            .replace("line 2\n", "line two\n")
+            .replace("line 18\n", "line eighteen\n");
+        let diff = FileDiff::new(&old, &new);
+        assert_eq!(diff.hunk_count(), 2);
+
+        assert_eq!(diff.apply(&[true, true]), new);
+        assert_eq!(diff.apply(&[false, false]), old);
+        let first_only = diff.apply(&[true, false]);
+        assert!(first_only.contains("line two\n"));
+        assert!(first_only.contains("line 18\n"));
+
+        let hunk = diff.hunk_lines(0);
+        assert!(hunk[0].contains("@@ -1,5 +1,5 @@"));
+        assert!(hunk.iter().any(|line| line.contains("-line 2")));
+        assert!(hunk.iter().any(|line| line.contains("+line two")));
+    }
+}
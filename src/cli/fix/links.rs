@@ -0,0 +1,259 @@
+//! Broken internal link repair.
+//!
+//! Finds page links that resolve to nothing, suggests the closest permalink
+//! (edit distance < 3, or the same last path segment) and, once every
+//! suggestion has been answered, rewrites only the link destination.
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::address::Resource;
+use crate::cli::validate::find_broken_page_links;
+use crate::config::SiteConfig;
+use crate::core::ContentKind;
+use crate::log;
+use crate::utils::path::route::{split_path_fragment, strip_path_prefix};
+use crate::utils::plural_count;
+
+use super::prompt::{LinkAnswer, prompt_replace_link};
+
+/// Maximum edit distance (exclusive) for a close match
+const MAX_DISTANCE: usize = 3;
+
+/// An accepted link replacement
+struct Replacement {
+    file: PathBuf,
+    old: String,
+    new: String,
+}
+
+/// Check internal links and repair the accepted ones.
+///
+/// Without `interactive`, suggestions are only reported.
+/// Returns `true` if any broken link was found.
+pub(super) fn check_and_fix_links(config: &SiteConfig, interactive: bool) -> Result<bool> {
+    let root = config.get_root();
+    let (state, broken) = find_broken_page_links(config)?;
+    if broken.is_empty() {
+        return Ok(false);
+    }
+
+    let prefix = config.paths().prefix().to_string_lossy().into_owned();
+    let permalinks: Vec<String> = state.read(|_, space| {
+        space
+            .pages()
+            .filter_map(|resource| match resource {
+                Resource::Page { route, .. } => {
+                    Some(strip_path_prefix(route.permalink.as_str(), &prefix))
+                }
+                Resource::Asset { .. } => None,
+            })
+            .collect()
+    });
+
+    log!("fix"; "found {}", plural_count(broken.len(), "broken link"));
+
+    let mut accepted = Vec::new();
+    let mut asking = interactive;
+    for link in broken {
+        let source = link.file.strip_prefix(root).unwrap_or(&link.file).display();
+        let Some(new) = suggest(&link.dest, &permalinks) else {
+            log!("fix"; "{}: `{}` not found, no close match", source, link.dest);
+            continue;
+        };
+
+        log!("fix"; "{}: `{}` -> `{}`", source, link.dest, new);
+        if !asking {
+            continue;
+        }
+        match prompt_replace_link()? {
+            LinkAnswer::Yes => accepted.push(Replacement {
+                file: link.file,
+                old: link.dest,
+                new,
+            }),
+            LinkAnswer::No => {}
+            LinkAnswer::Skip => asking = false,
+        }
+    }
+
+    if accepted.is_empty() {
+        log!("fix"; "no links changed");
+        return Ok(true);
+    }
+
+    // Summarize everything before touching any file
+    log!("fix"; "applying {}:", plural_count(accepted.len(), "link fix"));
+    for replacement in &accepted {
+        let source = replacement
+            .file
+            .strip_prefix(root)
+            .unwrap_or(&replacement.file);
+        log!("fix"; "  {}: `{}` -> `{}`", source.display(), replacement.old, replacement.new);
+    }
+
+    let mut by_file: BTreeMap<&Path, Vec<&Replacement>> = BTreeMap::new();
+    for replacement in &accepted {
+        by_file
+            .entry(&replacement.file)
+            .or_default()
+            .push(replacement);
+    }
+    for (file, replacements) in by_file {
+        apply_replacements(file, &replacements, root)?;
+    }
+
+    Ok(true)
+}
+
+/// Rewrite one file, replacing only the link destinations.
+fn apply_replacements(file: &Path, replacements: &[&Replacement], root: &Path) -> Result<()> {
+    let kind = ContentKind::from_path(file);
+    let mut content =
+        fs::read_to_string(file).with_context(|| format!("Failed to read {}", file.display()))?;
+
+    for replacement in replacements {
+        match replace_link(&content, kind, &replacement.old, &replacement.new) {
+            Some(updated) => content = updated,
+            None => {
+                let source = file.strip_prefix(root).unwrap_or(file);
+                log!("fix"; "{}: could not locate `{}`, left unchanged", source.display(), replacement.old);
+            }
+        }
+    }
+
+    fs::write(file, content).with_context(|| format!("Failed to write {}", file.display()))
+}
+
+/// Replace every occurrence of `old` used as a link destination.
+///
+/// - Typst: string literals, `"old"`
+/// - Markdown: inline links `](old)` / `](old "title")`, autolinks `<old>`
+///   and reference definitions `]: old`
+fn replace_link(content: &str, kind: Option<ContentKind>, old: &str, new: &str) -> Option<String> {
+    let patterns: &[(&str, &str)] = match kind {
+        Some(ContentKind::Markdown) => &[
+            ("](", ")"),
+            ("](", " "),
+            ("<", ">"),
+            ("]: ", "\n"),
+            ("]: ", " "),
+        ],
+        _ => &[("\"", "\"")],
+    };
+
+    let mut result = content.to_string();
+    let mut found = false;
+    for (open, close) in patterns {
+        let from = format!("{open}{old}{close}");
+        if result.contains(&from) {
+            result = result.replace(&from, &format!("{open}{new}{close}"));
+            found = true;
+        }
+    }
+    found.then_some(result)
+}
+
+/// Closest permalink to `dest`, keeping its `#fragment`.
+pub(crate) fn suggest(dest: &str, permalinks: &[String]) -> Option<String> {
+    let (path, fragment) = split_path_fragment(dest);
+    let target = path.trim_end_matches('/');
+    let name = basename(target);
+
+    permalinks
+        .iter()
+        .filter_map(|permalink| {
+            let candidate = permalink.trim_end_matches('/');
+            let distance = edit_distance(target, candidate);
+            let same_name = !name.is_empty() && basename(candidate) == name;
+            (distance < MAX_DISTANCE || same_name).then_some((distance, permalink))
+        })
+        .min()
+        .map(|(_, permalink)| match fragment {
+            "" => permalink.clone(),
+            fragment => format!("{permalink}#{fragment}"),
+        })
+}
+
+/// Last path segment, ignoring `./`, `../` and file extensions
+fn basename(path: &str) -> &str {
+    let name = path.rsplit('/').next().unwrap_or(path);
+    let name = name.rsplit_once('.').map_or(name, |(stem, _)| stem);
+    if name == "." || name == ".." {
+        ""
+    } else {
+        name
+    }
+}
+
+/// Levenshtein distance over chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitute.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("/about", "/about"), 0);
+        assert_eq!(edit_distance("/abut", "/about"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_suggest() {
+        let permalinks = vec![
+            "/".to_string(),
+            "/about/".to_string(),
+            "/posts/hello-world/".to_string(),
+        ];
+
+        assert_eq!(suggest("/abot/", &permalinks).as_deref(), Some("/about/"));
+        assert_eq!(
+            suggest("/blog/hello-world#intro", &permalinks).as_deref(),
+            Some("/posts/hello-world/#intro")
+        );
+        assert_eq!(
+            suggest("../hello-world.typ", &permalinks).as_deref(),
+            Some("/posts/hello-world/")
+        );
+        assert_eq!(suggest("/contact/", &permalinks), None);
+    }
+
+    #[test]
+    fn test_replace_link() {
+        let typst = r#"See #link("/abot/")[about] and "/abot/x"."#;
+        assert_eq!(
+            replace_link(typst, Some(ContentKind::Typst), "/abot/", "/about/").as_deref(),
+            Some(r#"See #link("/about/")[about] and "/abot/x"."#)
+        );
+
+        let markdown = "[About](/abot/ \"title\") [x](/abot/x) [y](/abot/)\n\n[ref]: /abot/\n";
+        assert_eq!(
+            replace_link(markdown, Some(ContentKind::Markdown), "/abot/", "/about/").as_deref(),
+            Some("[About](/about/ \"title\") [x](/abot/x) [y](/about/)\n\n[ref]: /about/\n")
+        );
+
+        assert_eq!(
+            replace_link("no links", Some(ContentKind::Markdown), "/abot/", "/about/"),
+            None
+        );
+    }
+}
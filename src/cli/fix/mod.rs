@@ -1,15 +1,20 @@
 //! Fix command - check and repair common issues.
 
 mod check;
+mod diff;
+mod links;
 mod prompt;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 
+use crate::cli::args::FixArgs;
 use crate::config::SiteConfig;
 use crate::embed::typst::{TOLA_TEMPLATE, TOLA_UTIL, TolaTypstVars};
 use crate::log;
+use crate::utils::plural_count;
 
 use check::{CheckResult, check_and_fix};
+pub(crate) use links::suggest;
 
 /// Version prefix in tola.typ files: `// Tola SSG ... (vX.X.X)`
 pub(super) const VERSION_PATTERN: &str = "(v";
@@ -21,12 +26,17 @@ const GITHUB_UTIL: &str =
     "https://github.com/tola-rs/tola-ssg/blob/main/src/embed/typst/utils/tola.typ";
 
 /// Run the fix command
-pub fn run_fix(config: &SiteConfig) -> Result<()> {
+///
+/// Outdated files show a diff before the update prompt. `--non-interactive`
+/// applies updates without prompting and fails if any file changed.
+pub fn run_fix(config: &SiteConfig, args: &FixArgs) -> Result<()> {
     let root = config.get_root();
     let deps = &config.build.deps;
     let current_version = env!("CARGO_PKG_VERSION");
+    let accept_all = args.accepts_all();
 
     let mut has_issues = false;
+    let mut changed = 0;
 
     // Check templates/tola.typ if "templates" is in deps
     let templates_dir = root.join("templates");
@@ -36,9 +46,11 @@ pub fn run_fix(config: &SiteConfig) -> Result<()> {
             "templates/tola.typ",
             current_version,
             GITHUB_TEMPLATE,
-            || TOLA_TEMPLATE.render(&TolaTypstVars::default()),
+            accept_all,
+            || TOLA_TEMPLATE.render(&TolaTypstVars::from_config(config)),
         )?;
         has_issues |= !matches!(result, CheckResult::Ok);
+        changed += usize::from(result.changed());
     }
 
     // Check utils/tola.typ if "utils" is in deps
@@ -49,13 +61,23 @@ pub fn run_fix(config: &SiteConfig) -> Result<()> {
             "utils/tola.typ",
             current_version,
             GITHUB_UTIL,
-            || TOLA_UTIL.render(&TolaTypstVars::default()),
+            accept_all,
+            || TOLA_UTIL.render(&TolaTypstVars::from_config(config)),
         )?;
         has_issues |= !matches!(result, CheckResult::Ok);
+        changed += usize::from(result.changed());
     }
 
+    // Check internal links and offer close matches for broken ones
+    has_issues |= links::check_and_fix_links(config, !args.non_interactive)?;
+
     if !has_issues {
-        log!("fix"; "all files up to date");
+        log!("fix"; "all files up to date, no broken links");
+    }
+
+    // CI: a changed file means the committed tree was out of date
+    if args.non_interactive && changed > 0 {
+        bail!("{} changed", plural_count(changed, "file"));
     }
 
     Ok(())
@@ -12,3 +12,76 @@ pub(super) fn prompt_create(name: &str) -> Result<bool> {
     let input = input.trim().to_lowercase();
     Ok(input == "y" || input == "yes")
 }
+
+/// Answer to a link replacement prompt
+pub(super) enum LinkAnswer {
+    Yes,
+    No,
+    /// Decline this and all remaining suggestions
+    Skip,
+}
+
+/// Prompt user to accept a suggested link replacement
+pub(super) fn prompt_replace_link() -> Result<LinkAnswer> {
+    eprint!("Replace? [y/N/s(kip)] ");
+    io::stderr().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(match input.trim().to_lowercase().as_str() {
+        "y" | "yes" => LinkAnswer::Yes,
+        "s" | "skip" => LinkAnswer::Skip,
+        _ => LinkAnswer::No,
+    })
+}
+
+/// Answer to an outdated file prompt
+pub(super) enum UpdateAnswer {
+    Accept,
+    Decline,
+    /// Choose hunk by hunk
+    Patch,
+    /// Print the whole diff, then ask again
+    View,
+}
+
+/// Prompt user to update an outdated file
+pub(super) fn prompt_update(name: &str, can_view: bool) -> Result<UpdateAnswer> {
+    let view = if can_view { ", [v]iew full diff" } else { "" };
+    eprint!("Update {name}? [a]ccept all, [d]ecline, [p]atch interactively{view} ");
+    io::stderr().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(match input.trim().to_lowercase().as_str() {
+        "a" | "accept" => UpdateAnswer::Accept,
+        "p" | "patch" => UpdateAnswer::Patch,
+        "v" | "view" if can_view => UpdateAnswer::View,
+        _ => UpdateAnswer::Decline,
+    })
+}
+
+/// Answer to a single hunk prompt
+pub(super) enum HunkAnswer {
+    Yes,
+    No,
+    /// Decline this and all remaining hunks
+    Quit,
+}
+
+/// Prompt user to apply one diff hunk
+pub(super) fn prompt_hunk() -> Result<HunkAnswer> {
+    eprint!("Apply this hunk? [y/N/q(uit)] ");
+    io::stderr().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    Ok(match input.trim().to_lowercase().as_str() {
+        "y" | "yes" => HunkAnswer::Yes,
+        "q" | "quit" => HunkAnswer::Quit,
+        _ => HunkAnswer::No,
+    })
+}
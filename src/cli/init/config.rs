@@ -1,19 +1,22 @@
 //! Configuration file generation.
 //!
-//! Creates tola.toml and ignore files for new sites.
+//! Creates tola.toml, ignore files and editor settings for new sites.
 
 use anyhow::{Context, Result};
 use std::{fs, path::Path};
 
 use crate::config::section::{
-    AssetsConfig, AssetsValidateConfig, PagesValidateConfig, ServeConfig,
+    AssetsConfig, AssetsValidateConfig, ExternalValidateConfig, OrphansValidateConfig,
+    PagesValidateConfig, ServeConfig,
     build::CssProcessorConfig,
     site::{HeaderConfig, SeoConfig, SiteInfoConfig},
 };
+use crate::config::{LOCAL_CONFIG_FILE, SiteConfig};
+use crate::embed::templates::EDITOR_FILES;
 use crate::embed::typst::{TOLA_TEMPLATE, TOLA_UTIL, TolaTypstVars};
 
 /// Default config filename
-const CONFIG_FILE: &str = "tola.toml";
+pub(super) const CONFIG_FILE: &str = "tola.toml";
 
 /// Files to write ignore patterns to
 const IGNORE_FILES: &[&str] = &[".gitignore", ".ignore"];
@@ -59,6 +62,14 @@ pub fn generate_config_template() -> String {
 
     // [validate.assets] section
     out.push_str(&AssetsValidateConfig::template_with_header());
+    out.push('\n');
+
+    // [validate.orphans] section
+    out.push_str(&OrphansValidateConfig::template_with_header());
+    out.push('\n');
+
+    // [validate.external] section
+    out.push_str(&ExternalValidateConfig::template_with_header());
 
     out
 }
@@ -79,12 +90,14 @@ pub fn write_config(root: &Path) -> Result<()> {
 /// Patterns include:
 /// - Output directory (e.g., `/dist/`)
 /// - Tola cache directory (`/.tola/`)
+/// - Local config overrides (`/tola.local.toml`)
 /// - OS-specific files (`.DS_Store`)
 pub fn write_ignore_files(root: &Path, output_dir: &Path) -> Result<()> {
     let output_pattern = Path::new("/").join(output_dir);
     let patterns = [
         output_pattern.to_string_lossy().into_owned(),
         "/.tola/".to_string(),
+        format!("/{}", LOCAL_CONFIG_FILE),
         ".DS_Store".to_string(),
     ];
 
@@ -102,12 +115,34 @@ pub fn write_ignore_files(root: &Path, output_dir: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Write `.editorconfig` and `typstfmt.toml` (2-space Typst indent, UTF-8, ...)
+pub fn write_editor_files(root: &Path) -> Result<()> {
+    for (filename, content) in EDITOR_FILES {
+        let path = root.join(filename);
+        // Only create if doesn't exist (don't overwrite user's editor settings)
+        if !path.exists() {
+            fs::write(&path, content)
+                .with_context(|| format!("Failed to write '{}'", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Config template followed by the editor files, for `tola init --dry`
+pub fn generate_dry_output() -> String {
+    let mut out = generate_config_template();
+    for (filename, content) in EDITOR_FILES {
+        out.push_str(&format!("\n# ---- {} ----\n{}", filename, content));
+    }
+    out
+}
+
 /// Write templates/tola.typ with default show rules for HTML export
-pub fn write_tola_template(root: &Path) -> Result<()> {
-    let path = root.join("templates/tola.typ");
+pub fn write_tola_template(config: &SiteConfig) -> Result<()> {
+    let path = config.get_root().join("templates/tola.typ");
     // Only create if doesn't exist
     if !path.exists() {
-        let content = TOLA_TEMPLATE.render(&TolaTypstVars::default());
+        let content = TOLA_TEMPLATE.render(&TolaTypstVars::from_config(config));
         fs::write(&path, content)
             .with_context(|| format!("Failed to write '{}'", path.display()))?;
     }
@@ -115,11 +150,11 @@ pub fn write_tola_template(root: &Path) -> Result<()> {
 }
 
 /// Write utils/tola.typ with utility functions
-pub fn write_tola_util(root: &Path) -> Result<()> {
-    let path = root.join("utils/tola.typ");
+pub fn write_tola_util(config: &SiteConfig) -> Result<()> {
+    let path = config.get_root().join("utils/tola.typ");
     // Only create if doesn't exist
     if !path.exists() {
-        let content = TOLA_UTIL.render(&TolaTypstVars::default());
+        let content = TOLA_UTIL.render(&TolaTypstVars::from_config(config));
         fs::write(&path, content)
             .with_context(|| format!("Failed to write '{}'", path.display()))?;
     }
@@ -144,6 +179,18 @@ mod tests {
         assert!(content.contains("[site.seo.feed]"));
     }
 
+    #[test]
+    fn test_config_template_has_no_unknown_fields() {
+        let template = generate_config_template();
+        let mut ignored = Vec::new();
+        let _: SiteConfig =
+            serde_ignored::deserialize(toml::Deserializer::new(&template), |path| {
+                ignored.push(path.to_string());
+            })
+            .unwrap();
+        assert!(ignored.is_empty(), "unknown fields: {ignored:?}");
+    }
+
     #[test]
     fn test_write_ignore_files() {
         let temp = TempDir::new().unwrap();
@@ -155,6 +202,29 @@ mod tests {
         let content = fs::read_to_string(&gitignore).unwrap();
         assert!(content.contains("/dist"));
         assert!(content.contains("/.tola/"));
+        assert!(content.contains("/tola.local.toml"));
+    }
+
+    #[test]
+    fn test_write_editor_files() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("typstfmt.toml"), "custom").unwrap();
+
+        write_editor_files(temp.path()).unwrap();
+
+        let editorconfig = fs::read_to_string(temp.path().join(".editorconfig")).unwrap();
+        assert!(editorconfig.contains("[*.typ]"));
+        assert!(editorconfig.contains("indent_size = 2"));
+        let typstfmt = fs::read_to_string(temp.path().join("typstfmt.toml")).unwrap();
+        assert_eq!(typstfmt, "custom");
+    }
+
+    #[test]
+    fn test_dry_output_includes_editor_files() {
+        let out = generate_dry_output();
+        assert!(out.contains("[site.info]"));
+        assert!(out.contains("# ---- .editorconfig ----"));
+        assert!(out.contains("# ---- typstfmt.toml ----"));
     }
 
     #[test]
@@ -1,19 +1,25 @@
 //! Site initialization module.
 //!
-//! Creates new site structure with default configuration.
+//! Creates new site structure from a starter template with default configuration.
 //!
 //! # Module Structure
 //!
 //! - [`validate`]: Pre-initialization validation
-//! - [`structure`]: Directory structure creation
+//! - [`structure`]: Template expansion (directories and files)
 //! - [`config`]: Configuration file generation
 
 mod config;
 mod structure;
 mod validate;
 
-use crate::{config::SiteConfig, log, package::generate_lsp_stubs};
-use anyhow::Result;
+use crate::{
+    config::SiteConfig,
+    embed::templates::{self, StarterTemplate},
+    log,
+    package::generate_lsp_stubs,
+};
+use anyhow::{Result, bail};
+use owo_colors::OwoColorize;
 use std::path::Path;
 
 pub use validate::InitMode;
@@ -21,20 +27,35 @@ pub use validate::InitMode;
 /// Create a new site with default structure
 ///
 /// # Steps
-/// 1. Validate target directory
-/// 2. Create directory structure
-/// 3. Write configuration files
-/// 4. Generate LSP stubs
+/// 1. Resolve the starter template
+/// 2. Validate target directory
+/// 3. Create directory structure and template files
+/// 4. Write configuration files (tola.toml, ignore files, `.editorconfig`,
+///    `typstfmt.toml`)
+/// 5. Generate LSP stubs
 ///
-/// If `dry_run` is true, only prints the config template to stdout
-pub fn new_site(site_config: &SiteConfig, has_name: bool, dry_run: bool) -> Result<()> {
+/// If `dry_run` is true, only prints the config template and editor files to stdout.
+/// If `bare` is true, only `tola.toml` and the content directory are created
+/// (no template files, ignore or editor files, LSP stubs), and the target directory
+/// may already hold files.
+pub fn new_site(
+    site_config: &SiteConfig,
+    has_name: bool,
+    template: &str,
+    dry_run: bool,
+    bare: bool,
+) -> Result<()> {
+    let template = resolve_template(template)?;
+
     if dry_run {
-        print!("{}", config::generate_config_template());
+        print!("{}", config::generate_dry_output());
         return Ok(());
     }
 
     let root = site_config.get_root();
-    let mode = if has_name {
+    let mode = if bare {
+        InitMode::Bare
+    } else if has_name {
         InitMode::NewDir
     } else {
         InitMode::CurrentDir
@@ -45,23 +66,77 @@ pub fn new_site(site_config: &SiteConfig, has_name: bool, dry_run: bool) -> Resu
         std::process::exit(1);
     }
 
-    structure::create_structure(root)?;
+    if bare {
+        let content_dir = root.join(site_config.root_relative(&site_config.build.content));
+        structure::create_bare_structure(root, &content_dir)?;
+        config::write_config(root)?;
+        log!("init"; "Site initialized successfully (bare)");
+        return Ok(());
+    }
+
+    structure::create_structure(root, template)?;
 
     config::write_config(root)?;
     let output_dir = site_config.root_relative(&site_config.build.output);
     config::write_ignore_files(root, &output_dir)?;
-    config::write_tola_template(root)?;
-    config::write_tola_util(root)?;
+    config::write_editor_files(root)?;
+    if template.tola_typst {
+        config::write_tola_template(site_config)?;
+        config::write_tola_util(site_config)?;
+    }
 
     generate_lsp_stubs(root)?;
 
-    log!("init"; "Site initialized successfully");
+    log!("init"; "Site initialized successfully (template: {})", template.name);
+    Ok(())
+}
+
+/// Print available starter templates (`tola init --template list`)
+pub fn print_templates() -> Result<()> {
+    let width = templates::ALL
+        .iter()
+        .map(|t| t.name.len())
+        .max()
+        .unwrap_or(0);
+    for t in templates::ALL {
+        let name = format!("{:width$}", t.name);
+        println!("{}  {}", name.bold(), t.description);
+    }
     Ok(())
 }
 
+/// Look up a starter template by name
+fn resolve_template(name: &str) -> Result<&'static StarterTemplate> {
+    match templates::find(name) {
+        Some(t) => Ok(t),
+        None => {
+            let names: Vec<_> = templates::ALL.iter().map(|t| t.name).collect();
+            bail!(
+                "unknown template '{}' (available: {}; run `tola init --template list`)",
+                name,
+                names.join(", ")
+            )
+        }
+    }
+}
+
 /// Get the output directory path relative to root
 ///
 /// Helper for external callers that need the output path
 pub fn get_output_dir(config: &SiteConfig) -> &Path {
     &config.build.output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_template() {
+        assert_eq!(resolve_template("docs").unwrap().name, "docs");
+        assert_eq!(resolve_template("minimal").unwrap().name, "minimal");
+
+        let err = resolve_template("wiki").unwrap_err().to_string();
+        assert!(err.contains("blog, docs, minimal"));
+    }
+}
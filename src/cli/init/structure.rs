@@ -1,27 +1,18 @@
 //! Site directory structure creation.
 //!
-//! Creates the standard Tola site directory layout.
+//! Expands a starter template's directories and files into the site root.
 
 use anyhow::{Context, Result};
 use std::{fs, path::Path};
 
-/// Standard site directory structure
-const SITE_DIRS: &[&str] = &[
-    "content",
-    "assets/images",
-    "assets/iconfonts",
-    "assets/fonts",
-    "assets/scripts",
-    "assets/styles",
-    "templates",
-    "utils",
-];
+use crate::embed::templates::StarterTemplate;
 
 /// Create site directory structure at the given root
 ///
-/// Creates all standard directories. The root directory
-/// is created if it doesn't exist
-pub fn create_structure(root: &Path) -> Result<()> {
+/// Creates the template's directories and writes its files. The root
+/// directory is created if it doesn't exist, and existing files are
+/// never overwritten
+pub fn create_structure(root: &Path, template: &StarterTemplate) -> Result<()> {
     // Ensure root exists
     if !root.exists() {
         fs::create_dir_all(root)
@@ -29,18 +20,42 @@ pub fn create_structure(root: &Path) -> Result<()> {
     }
 
     // Create all subdirectories
-    for dir in SITE_DIRS {
+    for dir in template.dirs {
         let path = root.join(dir);
         fs::create_dir_all(&path)
             .with_context(|| format!("Failed to create directory '{}'", path.display()))?;
     }
 
+    // Write template files
+    for (rel, content) in template.files {
+        let path = root.join(rel);
+        if path.exists() {
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory '{}'", parent.display()))?;
+        }
+        fs::write(&path, content)
+            .with_context(|| format!("Failed to write '{}'", path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Create only the root and content directories (`tola init --bare`)
+pub fn create_bare_structure(root: &Path, content_dir: &Path) -> Result<()> {
+    for path in [root, content_dir] {
+        fs::create_dir_all(path)
+            .with_context(|| format!("Failed to create directory '{}'", path.display()))?;
+    }
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::embed::templates;
     use tempfile::TempDir;
 
     #[test]
@@ -48,18 +63,64 @@ mod tests {
         let temp = TempDir::new().unwrap();
         let root = temp.path().join("my_site");
 
-        create_structure(&root).unwrap();
+        create_structure(&root, &templates::BLOG).unwrap();
 
         assert!(root.join("content").is_dir());
         assert!(root.join("assets/images").is_dir());
         assert!(root.join("templates").is_dir());
     }
 
+    #[test]
+    fn test_create_bare_structure() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path().join("my_site");
+
+        create_bare_structure(&root, &root.join("content")).unwrap();
+
+        assert!(root.join("content").is_dir());
+        assert_eq!(fs::read_dir(&root).unwrap().count(), 1);
+    }
+
     #[test]
     fn test_create_structure_existing_root() {
         let temp = TempDir::new().unwrap();
-        create_structure(temp.path()).unwrap();
+        create_structure(temp.path(), &templates::BLOG).unwrap();
+
+        assert!(temp.path().join("content").is_dir());
+    }
+
+    #[test]
+    fn test_create_structure_docs_files() {
+        let temp = TempDir::new().unwrap();
+        create_structure(temp.path(), &templates::DOCS).unwrap();
+
+        assert!(temp.path().join("templates/docs.typ").is_file());
+        assert!(
+            temp.path()
+                .join("content/guide/getting-started.typ")
+                .is_file()
+        );
+        assert!(temp.path().join("assets/styles/docs.css").is_file());
+    }
+
+    #[test]
+    fn test_create_structure_minimal() {
+        let temp = TempDir::new().unwrap();
+        create_structure(temp.path(), &templates::MINIMAL).unwrap();
 
         assert!(temp.path().join("content").is_dir());
+        assert!(!temp.path().join("templates").exists());
+    }
+
+    #[test]
+    fn test_create_structure_keeps_existing_files() {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("content")).unwrap();
+        fs::write(temp.path().join("content/index.typ"), "mine").unwrap();
+
+        create_structure(temp.path(), &templates::DOCS).unwrap();
+
+        let content = fs::read_to_string(temp.path().join("content/index.typ")).unwrap();
+        assert_eq!(content, "mine");
     }
 }
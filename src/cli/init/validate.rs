@@ -5,6 +5,8 @@
 use anyhow::{Context, Result, bail};
 use std::{fs, path::Path};
 
+use super::config::CONFIG_FILE;
+
 /// Initialization mode determines validation rules
 #[derive(Debug, Clone, Copy)]
 pub enum InitMode {
@@ -12,6 +14,8 @@ pub enum InitMode {
     CurrentDir,
     /// `tola init <name>` - create new subdirectory (must not exist)
     NewDir,
+    /// `tola init --bare` - target may hold files, but no config yet
+    Bare,
 }
 
 /// Validate target directory for initialization
@@ -19,6 +23,7 @@ pub enum InitMode {
 /// # Rules
 /// - `CurrentDir`: directory must be empty (or not exist)
 /// - `NewDir`: directory must not exist
+/// - `Bare`: directory must not already contain `tola.toml`
 pub fn validate_target(root: &Path, mode: InitMode) -> Result<()> {
     match mode {
         InitMode::CurrentDir => {
@@ -38,6 +43,12 @@ pub fn validate_target(root: &Path, mode: InitMode) -> Result<()> {
                 );
             }
         }
+        InitMode::Bare => {
+            let config = root.join(CONFIG_FILE);
+            if config.exists() {
+                bail!("'{}' already exists.", config.display());
+            }
+        }
     }
     Ok(())
 }
@@ -78,6 +89,16 @@ mod tests {
         assert!(validate_target(temp.path(), InitMode::NewDir).is_err());
     }
 
+    #[test]
+    fn test_non_empty_dir_bare_mode() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("file.txt"), "content").unwrap();
+        assert!(validate_target(temp.path(), InitMode::Bare).is_ok());
+
+        fs::write(temp.path().join(CONFIG_FILE), "").unwrap();
+        assert!(validate_target(temp.path(), InitMode::Bare).is_err());
+    }
+
     #[test]
     fn test_non_existing_dir_new_mode() {
         let temp = TempDir::new().unwrap();
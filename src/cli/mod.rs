@@ -2,12 +2,20 @@
 
 mod args;
 pub mod build;
+pub mod clean;
 pub mod common;
+pub mod completions;
 pub mod deploy;
+pub mod export;
 pub mod fix;
 pub mod init;
+pub mod new;
 pub mod query;
+pub mod schema;
 pub mod serve;
+pub mod stats;
 pub mod validate;
 
-pub use args::{BuildArgs, Cli, Commands, ValidateArgs};
+pub use args::{
+    BuildArgs, Cli, Commands, ExportArgs, ExportFormat, ValidateArgs, ValidateReporter,
+};
@@ -0,0 +1,275 @@
+//! New command - scaffold a content page.
+//!
+//! `tola new posts/hello-world` creates `content/posts/hello-world.typ` with a
+//! metadata block using the configured `build.meta.label`.
+
+use anyhow::{Context, Result, bail};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::cli::args::NewArgs;
+use crate::config::SiteConfig;
+use crate::log;
+use crate::utils::date::DateTimeUtc;
+use crate::utils::path::slug::slugify_path;
+
+/// Run the new command
+pub fn new_page(args: &NewArgs, config: &SiteConfig) -> Result<()> {
+    let path = create_page(args, config)?;
+    log!("new"; "created {}", config.root_relative(&path).display());
+    Ok(())
+}
+
+/// Write the page stub and return its absolute path
+fn create_page(args: &NewArgs, config: &SiteConfig) -> Result<PathBuf> {
+    let date = match args.date.as_deref() {
+        Some(s) => {
+            let parsed = DateTimeUtc::parse(s)
+                .with_context(|| format!("Invalid date '{s}', expected YYYY-MM-DD"))?;
+            parsed.validate()?;
+            Some(format!(
+                "{:04}-{:02}-{:02}",
+                parsed.year, parsed.month, parsed.day
+            ))
+        }
+        None => None,
+    };
+
+    let relative = page_relative_path(&args.name, config)?;
+    let path = config.build.content.join(&relative);
+    if path.exists() {
+        bail!("'{}' already exists", config.root_relative(&path).display());
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory '{}'", parent.display()))?;
+    }
+
+    let title = title_from_name(&args.name);
+    let content = render_stub(
+        &title,
+        date.as_deref(),
+        args.draft,
+        &config.build.meta.label,
+    );
+    fs::write(&path, content).with_context(|| format!("Failed to write '{}'", path.display()))?;
+
+    Ok(path)
+}
+
+/// Resolve `name` to a slugified `.typ` path relative to the content directory
+fn page_relative_path(name: &Path, config: &SiteConfig) -> Result<PathBuf> {
+    if name
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_)))
+    {
+        bail!(
+            "Page name '{}' must be a relative path inside the content directory",
+            name.display()
+        );
+    }
+
+    let stem = match name.extension() {
+        Some(ext) if ext == "typ" => name.with_extension(""),
+        _ => name.to_path_buf(),
+    };
+    let slugged = slugify_path(&stem, &config.build.slug);
+    if slugged.as_os_str().is_empty() {
+        bail!(
+            "Page name '{}' is empty after slugification",
+            name.display()
+        );
+    }
+
+    // Append rather than replace so dotted names like `v1.2-notes` survive
+    let mut file = slugged.into_os_string();
+    file.push(".typ");
+    Ok(PathBuf::from(file))
+}
+
+/// Derive a human-readable title from the last path component
+///
+/// `posts/hello-world` -> `Hello World`
+fn title_from_name(name: &Path) -> String {
+    let stem = name
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    stem.split(['-', '_', ' '])
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Render the `.typ` page stub
+fn render_stub(title: &str, date: Option<&str>, draft: bool, label: &str) -> String {
+    let mut out = String::from("#metadata((\n");
+    out.push_str(&format!("  title: \"{}\",\n", escape_typst_str(title)));
+    match date {
+        Some(date) => out.push_str(&format!("  date: \"{date}\",\n")),
+        None => out.push_str("  // date: \"YYYY-MM-DD\",\n"),
+    }
+    if draft {
+        out.push_str("  draft: true,\n");
+    } else {
+        out.push_str("  // draft: true,\n");
+    }
+    // Optional fields, uncomment as needed
+    out.push_str("  // summary: [],\n");
+    out.push_str("  // tags: (),\n");
+    out.push_str("  // author: \"\",\n");
+    out.push_str(&format!(
+        ")) <{label}>\n\n= {}\n",
+        escape_typst_markup(title)
+    ));
+    out
+}
+
+/// Escape a string for use inside a Typst string literal
+fn escape_typst_str(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escape characters with markup meaning so `s` renders as plain text in Typst markup
+fn escape_typst_markup(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(
+            c,
+            '\\' | '#' | '*' | '_' | '`' | '$' | '<' | '>' | '@' | '[' | ']' | '~' | '/'
+        ) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::section::SlugCase;
+    use tempfile::TempDir;
+
+    fn make_config(temp: &TempDir) -> SiteConfig {
+        let mut config = SiteConfig::default();
+        config.set_root(temp.path());
+        config.build.content = temp.path().join("content");
+        config
+    }
+
+    fn args(name: &str) -> NewArgs {
+        NewArgs {
+            name: PathBuf::from(name),
+            draft: false,
+            date: None,
+        }
+    }
+
+    #[test]
+    fn test_new_page_creates_nested_file() {
+        let temp = TempDir::new().unwrap();
+        let config = make_config(&temp);
+
+        let path = create_page(&args("posts/hello-world"), &config).unwrap();
+
+        assert_eq!(path, temp.path().join("content/posts/hello-world.typ"));
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("title: \"Hello World\""));
+        assert!(content.contains(")) <tola-meta>"));
+        assert!(content.contains("= Hello World"));
+        assert!(content.contains("// draft: true"));
+    }
+
+    #[test]
+    fn test_new_page_draft_and_date() {
+        let temp = TempDir::new().unwrap();
+        let config = make_config(&temp);
+        let mut args = args("note");
+        args.draft = true;
+        args.date = Some("2024-06-15".to_string());
+
+        let path = create_page(&args, &config).unwrap();
+        let content = fs::read_to_string(path).unwrap();
+
+        assert!(content.contains("  draft: true,"));
+        assert!(content.contains("  date: \"2024-06-15\","));
+    }
+
+    #[test]
+    fn test_new_page_uses_meta_label() {
+        let temp = TempDir::new().unwrap();
+        let mut config = make_config(&temp);
+        config.build.meta.label = "meta".to_string();
+
+        let path = create_page(&args("about"), &config).unwrap();
+        let content = fs::read_to_string(path).unwrap();
+
+        assert!(content.contains(")) <meta>"));
+    }
+
+    #[test]
+    fn test_new_page_applies_slug() {
+        let temp = TempDir::new().unwrap();
+        let mut config = make_config(&temp);
+
+        let path = create_page(&args("Posts/Hello World.typ"), &config).unwrap();
+        assert_eq!(path, temp.path().join("content/posts/hello-world.typ"));
+
+        config.build.slug.case = SlugCase::Preserve;
+        let path = create_page(&args("Notes/Café: Menu"), &config).unwrap();
+        assert_eq!(path, temp.path().join("content/Notes/Café-Menu.typ"));
+    }
+
+    #[test]
+    fn test_render_stub_escapes_title_markup() {
+        let stub = render_stub("C# *and* $x$ <tag> \"q\"", None, false, "tola-meta");
+
+        assert!(stub.contains(r#"  title: "C# *and* $x$ <tag> \"q\"","#));
+        assert!(stub.contains(r#"= C\# \*and\* \$x\$ \<tag\> "q""#));
+    }
+
+    #[test]
+    fn test_new_page_refuses_overwrite() {
+        let temp = TempDir::new().unwrap();
+        let config = make_config(&temp);
+
+        create_page(&args("post"), &config).unwrap();
+        let err = create_page(&args("post"), &config).unwrap_err();
+
+        assert!(err.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn test_new_page_rejects_invalid_input() {
+        let temp = TempDir::new().unwrap();
+        let config = make_config(&temp);
+
+        assert!(create_page(&args("../escape"), &config).is_err());
+
+        let mut bad_date = args("post");
+        bad_date.date = Some("2024-13-01".to_string());
+        assert!(create_page(&bad_date, &config).is_err());
+    }
+
+    #[test]
+    fn test_title_from_name() {
+        assert_eq!(
+            title_from_name(Path::new("posts/hello-world")),
+            "Hello World"
+        );
+        assert_eq!(
+            title_from_name(Path::new("my_first_post.typ")),
+            "My First Post"
+        );
+    }
+}
@@ -4,19 +4,20 @@ use anyhow::Result;
 use rayon::prelude::*;
 use serde_json::Value as JsonValue;
 
-use crate::cli::args::QueryArgs;
+use crate::cli::args::{QueryArgs, QuerySort};
 use crate::cli::common::{
     ParallelCollector, batch_scan_typst_metadata_iterative, scan_markdown_file,
 };
+use crate::compiler::CompileContext;
 use crate::config::SiteConfig;
-use crate::core::ContentKind;
 use crate::core::UrlPath;
+use crate::core::{BuildMode, ContentKind};
 use crate::log;
 use crate::page::StoredPageMap;
 use crate::utils::path::normalize_path;
 use crate::utils::path::route::strip_path_prefix_from_page_url;
 
-use super::types::{PageQueryResult, QueryMeta, QueryResult};
+use super::types::{PageQueryResult, PageStats, QueryMeta, QueryResult};
 
 pub(super) fn query_files(
     files: &[PathBuf],
@@ -29,6 +30,7 @@ pub(super) fn query_files(
     let root = normalize_path(config.get_root());
     let include_drafts = args.drafts;
     let raw_mode = args.raw;
+    let with_stats = args.wants_stats();
     let label = &config.build.meta.label;
 
     // Separate Typst and Markdown files
@@ -44,10 +46,11 @@ pub(super) fn query_files(
 
     // Process Typst results
     for (file, raw_meta) in typst_files.iter().zip(typst_results) {
-        if let Some(result) = process_query_result(file, raw_meta, raw_mode, config, store) {
+        if let Some(mut result) = process_query_result(file, raw_meta, raw_mode, config, store) {
             if result.meta.is_draft() && !include_drafts {
                 continue;
             }
+            attach_text_stats(&mut result, file, with_stats, config, host, store);
             collector.push(result);
         }
     }
@@ -56,11 +59,13 @@ pub(super) fn query_files(
     markdown_files.par_iter().for_each(|file| {
         match query_markdown_vdom(file, config, host, store) {
             Ok(raw_meta) => {
-                if let Some(result) = process_query_result(file, raw_meta, raw_mode, config, store)
+                if let Some(mut result) =
+                    process_query_result(file, raw_meta, raw_mode, config, store)
                 {
                     if result.meta.is_draft() && !include_drafts {
                         return;
                     }
+                    attach_text_stats(&mut result, file, with_stats, config, host, store);
                     collector.push(result);
                 }
             }
@@ -70,10 +75,25 @@ pub(super) fn query_files(
         }
     });
 
-    let pages = collector.drain_with_capacity(file_count);
+    let mut pages = collector.drain_with_capacity(file_count);
+    if args.sort == Some(QuerySort::ReadingTime) {
+        sort_by_reading_time(&mut pages);
+    }
     Ok(QueryResult { pages })
 }
 
+/// Longest reading time first, then most words, then path
+fn sort_by_reading_time(pages: &mut [PageQueryResult]) {
+    pages.sort_by(|a, b| {
+        let key = |page: &PageQueryResult| {
+            page.stats
+                .map(|s| (s.reading_time_minutes, s.word_count))
+                .unwrap_or_default()
+        };
+        key(b).cmp(&key(a)).then_with(|| a.path.cmp(&b.path))
+    });
+}
+
 fn process_query_result(
     file: &Path,
     raw_meta: Option<JsonValue>,
@@ -96,6 +116,8 @@ fn process_query_result(
             return None;
         }
     };
+    // Sources outside the content dir only have their custom permalink
+    let natural_permalink = natural_permalink(file, config).unwrap_or_else(|_| permalink.clone());
 
     let meta = if raw_mode {
         QueryMeta::Raw(raw_meta)
@@ -112,10 +134,46 @@ fn process_query_result(
     Some(PageQueryResult {
         path: rel_path,
         permalink,
+        natural_permalink,
         meta,
+        stats: None,
     })
 }
 
+/// Fill `reading_time_minutes` (with `build.meta.reading_time`) and `stats`
+/// (with `--stats`) from one scan of the page text.
+///
+/// Metadata scans don't render page text, so this needs a VDOM scan.
+fn attach_text_stats(
+    result: &mut PageQueryResult,
+    file: &Path,
+    with_stats: bool,
+    config: &SiteConfig,
+    host: &crate::compiler::page::TypstHost,
+    store: &StoredPageMap,
+) {
+    let reading_time = config.build.meta.reading_time;
+    if !reading_time && !with_stats {
+        return;
+    }
+
+    let ctx = CompileContext::new(BuildMode::PRODUCTION, config, host, store);
+    match crate::compiler::page::scan_text(file, &ctx) {
+        Ok(text) => {
+            let stats = PageStats::from_text(&text, config.site.info.reading_speed);
+            if reading_time {
+                result.meta.set_reading_time(stats.reading_time_minutes);
+            }
+            if with_stats {
+                result.stats = Some(stats);
+            }
+        }
+        Err(e) => {
+            crate::debug!("query"; "failed to count words for {}: {}", file.display(), e);
+        }
+    }
+}
+
 /// Resolve output permalink for query result.
 ///
 /// Priority:
@@ -143,7 +201,14 @@ fn resolve_permalink(
         return Ok(strip_path_prefix_from_page_url(mapped.as_str(), &prefix));
     }
 
-    // Keep behavior aligned with build routing (slug/path_prefix aware).
+    natural_permalink(file, config)
+}
+
+/// Path-derived permalink, ignoring any `permalink` metadata.
+///
+/// Keeps behavior aligned with build routing (slug/path_prefix aware).
+fn natural_permalink(file: &Path, config: &SiteConfig) -> Result<String> {
+    let prefix = config.paths().prefix().to_string_lossy().into_owned();
     let compiled = crate::compiler::page::CompiledPage::from_paths(file, config)?;
     Ok(strip_path_prefix_from_page_url(
         compiled.route.permalink.as_str(),
@@ -195,4 +260,96 @@ mod tests {
 
         assert!(result.is_none());
     }
+
+    #[test]
+    fn query_result_includes_natural_permalink() {
+        let dir = TempDir::new().unwrap();
+        let root = normalize_path(dir.path());
+        let content_dir = root.join("content");
+        std::fs::create_dir_all(content_dir.join("posts")).unwrap();
+
+        let source = content_dir.join("posts/about.typ");
+        std::fs::write(&source, "= About").unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(&root);
+        config.build.content = content_dir;
+
+        let store = StoredPageMap::new();
+        let result = process_query_result(
+            &source,
+            Some(json!({ "title": "About", "permalink": "/about/" })),
+            true,
+            &config,
+            &store,
+        )
+        .unwrap();
+
+        assert_eq!(result.permalink, "/about/");
+        assert_eq!(result.natural_permalink, "/posts/about/");
+    }
+
+    #[test]
+    fn query_files_counts_page_text_with_stats() {
+        let dir = TempDir::new().unwrap();
+        let root = normalize_path(dir.path());
+        let content_dir = root.join("content");
+        std::fs::create_dir_all(&content_dir).unwrap();
+
+        let short = content_dir.join("short.typ");
+        std::fs::write(
+            &short,
+            "#metadata((title: \"Short\")) <tola-meta>\n\nOne two three.\n",
+        )
+        .unwrap();
+        let long = content_dir.join("long.typ");
+        std::fs::write(
+            &long,
+            format!(
+                "#metadata((title: \"Long\")) <tola-meta>\n\n{}\n",
+                "word ".repeat(250)
+            ),
+        )
+        .unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(&root);
+        config.build.content = content_dir;
+        config.build.meta.reading_time = true;
+
+        let cli = <crate::cli::args::Cli as clap::Parser>::try_parse_from([
+            "tola",
+            "query",
+            "--sort",
+            "reading_time",
+        ])
+        .unwrap();
+        let crate::cli::args::Commands::Query { args } = cli.command else {
+            panic!("expected query command");
+        };
+        let store = StoredPageMap::new();
+        let host = crate::compiler::page::TypstHost::for_config(&config);
+        let result = query_files(&[short, long], &args, &config, &host, &store).unwrap();
+
+        let stats: Vec<_> = result
+            .pages
+            .iter()
+            .map(|page| page.stats.unwrap())
+            .collect();
+        assert_eq!(stats[0].word_count, 250);
+        assert_eq!(stats[0].reading_time_minutes, 2);
+        assert_eq!(stats[1].word_count, 3);
+        assert_eq!(stats[1].char_count, "One two three.".len());
+
+        // Metadata reading time comes from the same scan
+        for page in &result.pages {
+            let QueryMeta::Normalized(meta) = &page.meta else {
+                panic!("expected normalized metadata");
+            };
+            assert_eq!(
+                meta.reading_time_minutes,
+                page.stats.map(|s| s.reading_time_minutes)
+            );
+        }
+    }
 }
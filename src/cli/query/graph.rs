@@ -0,0 +1,121 @@
+//! Dependency graph output (`tola query --dep-graph`).
+//!
+//! Renders the dependencies stored by the last build as Graphviz DOT, with
+//! edges from a dependency to the pages using it and one cluster per
+//! directory:
+//!
+//! ```text
+//! tola query --dep-graph | dot -Tsvg > deps.svg
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+
+use anyhow::{Result, bail};
+
+use crate::cli::args::QueryArgs;
+use crate::config::SiteConfig;
+use crate::log;
+
+/// Print the cached dependency graph as DOT
+pub(super) fn output_dep_graph(config: &SiteConfig, args: &QueryArgs) -> Result<()> {
+    let Some(entries) = crate::cache::load_dependency_entries(config.get_root())? else {
+        bail!("no dependency graph cached, run `tola build` first");
+    };
+
+    let dot = render_dot(&entries, args.skip_isolated);
+    if let Some(ref output_path) = args.output {
+        fs::write(output_path, dot)?;
+        log!("query"; "wrote dependency graph to {}", output_path.display());
+    } else {
+        print!("{dot}");
+    }
+
+    Ok(())
+}
+
+/// Render `page -> dependencies` as a DOT digraph
+///
+/// With `skip_isolated`, pages without dependencies are left out.
+fn render_dot(entries: &BTreeMap<String, BTreeSet<String>>, skip_isolated: bool) -> String {
+    let mut nodes: BTreeSet<&str> = BTreeSet::new();
+    let mut edges: BTreeSet<(&str, &str)> = BTreeSet::new();
+    for (page, deps) in entries {
+        if skip_isolated && deps.is_empty() {
+            continue;
+        }
+        nodes.insert(page.as_str());
+        for dep in deps {
+            nodes.insert(dep.as_str());
+            edges.insert((dep.as_str(), page.as_str()));
+        }
+    }
+
+    // Directory -> files directly inside it
+    let mut clusters: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for &node in &nodes {
+        let dir = node.rsplit_once('/').map_or("", |(dir, _)| dir);
+        clusters.entry(dir).or_default().push(node);
+    }
+
+    let mut dot = String::from("digraph dependencies {\n");
+    dot.push_str("  rankdir=LR;\n");
+    dot.push_str("  node [shape=box, fontname=\"monospace\"];\n");
+    for (i, (dir, files)) in clusters.iter().enumerate() {
+        let indent = if dir.is_empty() {
+            "  "
+        } else {
+            dot.push_str(&format!("  subgraph cluster_{i} {{\n"));
+            dot.push_str(&format!("    label={};\n", quote(dir)));
+            "    "
+        };
+        for file in files {
+            let label = file.rsplit_once('/').map_or(*file, |(_, name)| name);
+            dot.push_str(&format!(
+                "{indent}{} [label={}];\n",
+                quote(file),
+                quote(label)
+            ));
+        }
+        if !dir.is_empty() {
+            dot.push_str("  }\n");
+        }
+    }
+    for (from, to) in &edges {
+        dot.push_str(&format!("  {} -> {};\n", quote(from), quote(to)));
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// DOT quoted string
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_dot() {
+        let mut entries: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        entries.insert(
+            "content/blog/post.typ".to_string(),
+            BTreeSet::from(["templates/base.typ".to_string()]),
+        );
+        entries.insert("content/about.typ".to_string(), BTreeSet::new());
+
+        let dot = render_dot(&entries, false);
+        assert!(dot.starts_with("digraph dependencies {\n"));
+        assert!(dot.contains("label=\"content/blog\";"));
+        assert!(dot.contains("\"content/blog/post.typ\" [label=\"post.typ\"];"));
+        assert!(dot.contains("\"templates/base.typ\" -> \"content/blog/post.typ\";"));
+        assert!(dot.contains("\"content/about.typ\""));
+        assert!(dot.ends_with("}\n"));
+
+        let dot = render_dot(&entries, true);
+        assert!(!dot.contains("about.typ"));
+        assert!(dot.contains("post.typ"));
+    }
+}
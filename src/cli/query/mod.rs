@@ -4,7 +4,9 @@
 //! Uses fast scanning for Typst files (5-20x faster) and shared VDOM pipeline for Markdown.
 
 mod collect;
+mod graph;
 mod output;
+mod repl;
 mod types;
 
 use anyhow::Result;
@@ -17,13 +19,20 @@ use crate::utils::plural_count;
 
 /// Execute query command
 pub fn run_query(args: &QueryArgs, config: &SiteConfig) -> Result<()> {
+    if args.urls {
+        return output::output_urls(&url_map(config)?, args);
+    }
+    if args.dep_graph {
+        return graph::output_dep_graph(config, args);
+    }
+
     let store = StoredPageMap::new();
     let host = crate::compiler::page::TypstHost::for_config(config);
 
     // Populate page data first so pages() returns correct data for all pages.
     crate::cli::common::populate_stored_pages(config, &host, &store)?;
 
-    let files = crate::cli::common::collect_content_files(&args.paths, &config.build.content)?;
+    let files = crate::cli::common::collect_content_files(&args.paths, &config.build)?;
 
     let file_count = files.len();
     log!("query"; "querying {}", plural_count(file_count, "file"));
@@ -36,6 +45,23 @@ pub fn run_query(args: &QueryArgs, config: &SiteConfig) -> Result<()> {
         plural_count(results.pages.len(), "page with metadata")
     );
 
+    if args.repl {
+        let pages = output::to_json(&results, args);
+        return repl::run(pages, args.pretty, config.get_root());
+    }
+
     output::output_results(&results, args)?;
     Ok(())
 }
+
+/// URL map of the last build, or of a fresh scan if no build stored one
+fn url_map(config: &SiteConfig) -> Result<serde_json::Value> {
+    let root = config.get_root();
+    if let Some(map) = crate::cache::restore_url_map(root)? {
+        return Ok(map);
+    }
+
+    crate::debug!("query"; "no stored url map, scanning site");
+    let state = crate::cli::validate::scan_site_index(config)?;
+    Ok(state.read(|_, space| space.to_json(root)))
+}
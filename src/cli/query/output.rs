@@ -4,7 +4,7 @@ use std::io::Write;
 use anyhow::Result;
 use serde_json::{Map, Value as JsonValue};
 
-use crate::cli::args::QueryArgs;
+use crate::cli::args::{QueryArgs, QueryFormat};
 use crate::log;
 
 use super::types::{PageQueryResult, QueryResult};
@@ -15,32 +15,38 @@ pub(super) fn output_results(results: &QueryResult, args: &QueryArgs) -> Result<
         return Ok(());
     }
 
-    let output = if let Some(ref fields) = args.fields {
-        let normalized_fields = normalize_fields(fields);
-        filter_fields(results, &normalized_fields, args.filter_empty)
-    } else {
-        format_results(results, args.filter_empty)
+    let output_json = to_json(results, args);
+
+    let formatted = match args.format {
+        QueryFormat::Csv => to_csv(&output_json, &args.separator),
+        QueryFormat::Json if args.pretty => serde_json::to_string_pretty(&output_json)?,
+        QueryFormat::Json => serde_json::to_string(&output_json)?,
     };
 
-    // Format output: JSON for --raw, simplified JSON for default
-    let output_json = if args.raw {
-        output
+    // Output to file or stdout
+    if let Some(ref output_path) = args.output {
+        let mut file = fs::File::create(output_path)?;
+        writeln!(file, "{}", formatted)?;
+        log!("query"; "wrote output to {}", output_path.display());
     } else {
-        use typst_batch::codegen::json_to_simple_text;
-        json_to_simple_text(&output)
-    };
+        println!("{}", formatted);
+    }
+
+    Ok(())
+}
 
+/// Print the site's URL map as JSON
+pub(super) fn output_urls(map: &JsonValue, args: &QueryArgs) -> Result<()> {
     let formatted = if args.pretty {
-        serde_json::to_string_pretty(&output_json)?
+        serde_json::to_string_pretty(map)?
     } else {
-        serde_json::to_string(&output_json)?
+        serde_json::to_string(map)?
     };
 
-    // Output to file or stdout
     if let Some(ref output_path) = args.output {
         let mut file = fs::File::create(output_path)?;
         writeln!(file, "{}", formatted)?;
-        log!("query"; "wrote output to {}", output_path.display());
+        log!("query"; "wrote url map to {}", output_path.display());
     } else {
         println!("{}", formatted);
     }
@@ -48,6 +54,24 @@ pub(super) fn output_results(results: &QueryResult, args: &QueryArgs) -> Result<
     Ok(())
 }
 
+/// Build the JSON array of pages, honoring `--fields`, `--filter-empty` and `--raw`
+pub(super) fn to_json(results: &QueryResult, args: &QueryArgs) -> JsonValue {
+    let output = if let Some(ref fields) = args.fields {
+        let normalized_fields = normalize_fields(fields);
+        filter_fields(results, &normalized_fields, args.filter_empty)
+    } else {
+        format_results(results, args.filter_empty)
+    };
+
+    // JSON for --raw, simplified JSON for default
+    if args.raw {
+        output
+    } else {
+        use typst_batch::codegen::json_to_simple_text;
+        json_to_simple_text(&output)
+    }
+}
+
 /// Format all results, optionally filtering empty fields
 fn format_results(results: &QueryResult, filter_empty: bool) -> JsonValue {
     let pages: Vec<JsonValue> = results
@@ -59,23 +83,32 @@ fn format_results(results: &QueryResult, filter_empty: bool) -> JsonValue {
     JsonValue::Array(pages)
 }
 
-/// Format a single page result with path/permalink first
-fn format_page(page: &PageQueryResult, filter_empty: bool) -> JsonValue {
-    let mut obj = Map::new();
+/// Keys always emitted first, ahead of metadata fields
+const CANONICAL_KEYS: [&str; 3] = ["path", "permalink", "natural_permalink"];
 
-    // path and permalink always first
+/// Insert path/permalink/natural_permalink
+fn insert_canonical(obj: &mut Map<String, JsonValue>, page: &PageQueryResult) {
     obj.insert("path".to_string(), JsonValue::String(page.path.clone()));
     obj.insert(
         "permalink".to_string(),
         JsonValue::String(page.permalink.clone()),
     );
+    obj.insert(
+        "natural_permalink".to_string(),
+        JsonValue::String(page.natural_permalink.clone()),
+    );
+}
+
+/// Format a single page result with path/permalink first
+fn format_page(page: &PageQueryResult, filter_empty: bool) -> JsonValue {
+    let mut obj = Map::new();
+    insert_canonical(&mut obj, page);
 
     // Add meta fields
-    let meta_value = serde_json::to_value(&page.meta).unwrap_or_default();
-    if let JsonValue::Object(meta_obj) = meta_value {
+    if let JsonValue::Object(meta_obj) = meta_value(page) {
         for (key, value) in meta_obj {
             // Keep canonical top-level keys stable.
-            if matches!(key.as_str(), "path" | "permalink") {
+            if CANONICAL_KEYS.contains(&key.as_str()) {
                 continue;
             }
             if !filter_empty || !is_empty_value(&value) {
@@ -87,6 +120,25 @@ fn format_page(page: &PageQueryResult, filter_empty: bool) -> JsonValue {
     JsonValue::Object(obj)
 }
 
+/// Serialize page metadata with computed pagination fields (and `--stats`)
+fn meta_value(page: &PageQueryResult) -> JsonValue {
+    let mut value = serde_json::to_value(&page.meta).unwrap_or_default();
+    if let JsonValue::Object(obj) = &mut value {
+        let page_number = page.meta.page_number();
+        obj.insert("is_paginated".to_string(), page_number.is_some().into());
+        obj.insert("page_number".to_string(), page_number.into());
+        if let Some(stats) = page.stats {
+            obj.insert("word_count".to_string(), stats.word_count.into());
+            obj.insert("char_count".to_string(), stats.char_count.into());
+            obj.insert(
+                "reading_time_minutes".to_string(),
+                stats.reading_time_minutes.into(),
+            );
+        }
+    }
+    value
+}
+
 /// Check if a JSON value is considered "empty" (null, "", or [])
 fn is_empty_value(value: &JsonValue) -> bool {
     match value {
@@ -97,25 +149,18 @@ fn is_empty_value(value: &JsonValue) -> bool {
     }
 }
 
-/// Filter to specific fields, with path/permalink always included first
+/// Filter to specific fields, with path/permalinks always included first
 fn filter_fields(results: &QueryResult, fields: &[String], filter_empty: bool) -> JsonValue {
     let pages: Vec<JsonValue> = results
         .pages
         .iter()
         .map(|page| {
             let mut obj = Map::new();
+            insert_canonical(&mut obj, page);
 
-            // path and permalink always first
-            obj.insert("path".to_string(), JsonValue::String(page.path.clone()));
-            obj.insert(
-                "permalink".to_string(),
-                JsonValue::String(page.permalink.clone()),
-            );
-
-            let meta_value = serde_json::to_value(&page.meta).unwrap_or_default();
-            if let JsonValue::Object(meta_obj) = meta_value {
+            if let JsonValue::Object(meta_obj) = meta_value(page) {
                 for field in fields {
-                    if matches!(field.as_str(), "path" | "permalink") {
+                    if CANONICAL_KEYS.contains(&field.as_str()) {
                         continue;
                     }
 
@@ -137,6 +182,101 @@ fn filter_fields(results: &QueryResult, fields: &[String], filter_empty: bool) -
     JsonValue::Array(pages)
 }
 
+/// Serialize pages as CSV, one row per page
+///
+/// Columns are the union of all page keys in first-seen order. Objects are
+/// flattened with dot notation (`extra.github`), arrays joined with `separator`.
+fn to_csv(output: &JsonValue, separator: &str) -> String {
+    let rows: Vec<Vec<(String, String)>> = output
+        .as_array()
+        .map(|pages| {
+            pages
+                .iter()
+                .map(|page| {
+                    let mut row = Vec::new();
+                    flatten_csv_value("", page, separator, &mut row);
+                    row
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut columns: Vec<&str> = Vec::new();
+    for (key, _) in rows.iter().flatten() {
+        if !columns.contains(&key.as_str()) {
+            columns.push(key);
+        }
+    }
+
+    let mut csv = csv_record(columns.iter().copied());
+    for row in &rows {
+        let cells = columns.iter().map(|column| {
+            row.iter()
+                .find(|(key, _)| key == column)
+                .map_or("", |(_, value)| value.as_str())
+        });
+        csv.push_str(&csv_record(cells));
+    }
+    // println!/writeln! add the final newline
+    csv.pop();
+    csv
+}
+
+/// Flatten a JSON value into `(column, cell)` pairs
+fn flatten_csv_value(
+    prefix: &str,
+    value: &JsonValue,
+    separator: &str,
+    row: &mut Vec<(String, String)>,
+) {
+    match value {
+        JsonValue::Object(obj) => {
+            for (key, value) in obj {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten_csv_value(&key, value, separator, row);
+            }
+        }
+        JsonValue::Array(items) => {
+            let joined = items
+                .iter()
+                .map(csv_scalar)
+                .collect::<Vec<_>>()
+                .join(separator);
+            row.push((prefix.to_string(), joined));
+        }
+        _ => row.push((prefix.to_string(), csv_scalar(value))),
+    }
+}
+
+/// Cell text for a single value (nested containers stay JSON)
+fn csv_scalar(value: &JsonValue) -> String {
+    match value {
+        JsonValue::Null => String::new(),
+        JsonValue::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// One CSV line, quoting cells that contain `,`, `"` or line breaks (RFC 4180)
+fn csv_record<'a>(cells: impl Iterator<Item = &'a str>) -> String {
+    let mut line = cells
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
 /// Normalize field list from CLI.
 ///
 /// Supports both comma-separated and whitespace-separated inputs, e.g.
@@ -164,7 +304,9 @@ mod tests {
             pages: vec![PageQueryResult {
                 path: "content/post.typ".to_string(),
                 permalink: "/post/".to_string(),
+                natural_permalink: "/post/".to_string(),
                 meta: QueryMeta::Raw(meta),
+                stats: None,
             }],
         }
     }
@@ -180,4 +322,59 @@ mod tests {
         assert_eq!(page.get("permalink"), Some(&json!("/post/")));
         assert_eq!(page.get("url"), Some(&JsonValue::Null));
     }
+
+    #[test]
+    fn csv_flattens_nested_fields_and_joins_arrays() {
+        let output = json!([
+            {
+                "path": "a.typ",
+                "title": "Hello, world",
+                "tags": ["rust", "typst"],
+                "extra": { "github": "alice" }
+            },
+            { "path": "b.typ", "title": "Say \"hi\"", "draft": true }
+        ]);
+
+        assert_eq!(
+            to_csv(&output, "|"),
+            "path,title,tags,extra.github,draft\n\
+             a.typ,\"Hello, world\",rust|typst,alice,\n\
+             b.typ,\"Say \"\"hi\"\"\",,,true"
+        );
+    }
+
+    #[test]
+    fn stats_fields_are_included() {
+        let mut result = query_result(json!({ "title": "Post", "reading_time_minutes": 9 }));
+        result.pages[0].stats = Some(crate::cli::query::types::PageStats {
+            word_count: 420,
+            char_count: 2500,
+            reading_time_minutes: 3,
+        });
+
+        let output = filter_fields(
+            &result,
+            &["word_count".to_string(), "reading_time_minutes".to_string()],
+            false,
+        );
+        let page = output.as_array().unwrap()[0].as_object().unwrap();
+        assert_eq!(page.get("word_count"), Some(&json!(420)));
+        assert_eq!(page.get("reading_time_minutes"), Some(&json!(3)));
+        assert!(!page.contains_key("char_count"));
+    }
+
+    #[test]
+    fn pagination_fields_are_included() {
+        let result = query_result(json!({ "title": "Archive", "page-number": 2 }));
+
+        let output = format_results(&result, false);
+        let page = output.as_array().unwrap()[0].as_object().unwrap();
+        assert_eq!(page.get("is_paginated"), Some(&json!(true)));
+        assert_eq!(page.get("page_number"), Some(&json!(2)));
+
+        let result = query_result(json!({ "title": "Post" }));
+        let output = format_results(&result, false);
+        let page = output.as_array().unwrap()[0].as_object().unwrap();
+        assert_eq!(page.get("is_paginated"), Some(&json!(false)));
+    }
 }
@@ -0,0 +1,395 @@
+//! Interactive query session (`tola query --repl`).
+//!
+//! Pages are scanned once; each line is then answered from memory:
+//!
+//! - `field = value`: print matching pages as JSON (`and` combines conditions)
+//! - `:count [query]`: count matching pages
+//! - `:fields`: list metadata fields found across pages
+//! - `:help`, `:quit`
+//!
+//! Lines are edited with rustyline: history (kept in `.tola/query_history`)
+//! and tab completion of commands and metadata field names.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use serde_json::Value as JsonValue;
+
+/// Query history file, relative to the site root
+const HISTORY_FILE: &str = ".tola/query_history";
+
+const HELP: &str = "\
+field = value        pages whose field equals value (arrays: contains value)
+a = x and b = y      all conditions must match
+:count [query]       count matching pages (all pages without a query)
+:fields              list metadata fields
+:help                show this help
+:quit                leave the session
+<Tab>                complete commands and field names";
+
+/// Commands offered by tab completion
+const COMMANDS: &[&str] = &[":count", ":fields", ":help", ":quit"];
+
+/// Result of evaluating one input line
+#[derive(Debug, PartialEq)]
+enum Reply {
+    /// Text for stdout
+    Output(String),
+    /// Nothing to print (blank line)
+    Empty,
+    Quit,
+}
+
+/// One `field = value` condition
+#[derive(Debug, PartialEq)]
+struct Condition {
+    /// Dot-separated path into the page object (`extra.series`)
+    field: String,
+    value: String,
+}
+
+impl Condition {
+    fn matches(&self, page: &JsonValue) -> bool {
+        let found = self
+            .field
+            .split('.')
+            .try_fold(page, |value, key| value.get(key));
+        found.is_some_and(|value| value_matches(value, &self.value))
+    }
+}
+
+/// Compare a JSON value with the query text; arrays match any element
+fn value_matches(value: &JsonValue, expected: &str) -> bool {
+    match value {
+        JsonValue::String(s) => s == expected,
+        JsonValue::Array(items) => items.iter().any(|item| value_matches(item, expected)),
+        JsonValue::Number(_) | JsonValue::Bool(_) => {
+            serde_json::from_str::<JsonValue>(expected).is_ok_and(|parsed| parsed == *value)
+        }
+        JsonValue::Null => expected == "null",
+        JsonValue::Object(_) => false,
+    }
+}
+
+/// Parse `field = value [and field = value ...]`
+fn parse_query(query: &str) -> Result<Vec<Condition>, String> {
+    query
+        .split(" and ")
+        .map(|part| {
+            let (field, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("expected `field = value`, got `{}`", part.trim()))?;
+            let field = field.trim();
+            if field.is_empty() {
+                return Err(format!("missing field name in `{}`", part.trim()));
+            }
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            Ok(Condition {
+                field: field.to_string(),
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// In-memory query session over scanned pages
+struct Session {
+    pages: Vec<JsonValue>,
+    pretty: bool,
+}
+
+impl Session {
+    fn eval(&self, line: &str) -> Result<Reply, String> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(Reply::Empty);
+        }
+
+        let Some(command) = line.strip_prefix(':') else {
+            let matched = self.select(line)?;
+            let json = JsonValue::Array(matched.into_iter().cloned().collect());
+            let text = if self.pretty {
+                serde_json::to_string_pretty(&json)
+            } else {
+                serde_json::to_string(&json)
+            };
+            return Ok(Reply::Output(text.map_err(|e| e.to_string())?));
+        };
+
+        let (name, rest) = command.split_once(' ').unwrap_or((command, ""));
+        match name {
+            "quit" | "q" | "exit" => Ok(Reply::Quit),
+            "help" | "h" => Ok(Reply::Output(HELP.to_string())),
+            "fields" => Ok(Reply::Output(self.fields().join("\n"))),
+            "count" if rest.trim().is_empty() => Ok(Reply::Output(self.pages.len().to_string())),
+            "count" => Ok(Reply::Output(self.select(rest)?.len().to_string())),
+            _ => Err(format!("unknown command `:{name}`, try :help")),
+        }
+    }
+
+    /// Pages matching every condition of `query`
+    fn select(&self, query: &str) -> Result<Vec<&JsonValue>, String> {
+        let conditions = parse_query(query)?;
+        Ok(self
+            .pages
+            .iter()
+            .filter(|page| conditions.iter().all(|c| c.matches(page)))
+            .collect())
+    }
+
+    /// Sorted union of top-level keys across pages
+    fn fields(&self) -> Vec<String> {
+        self.pages
+            .iter()
+            .filter_map(JsonValue::as_object)
+            .flat_map(|obj| obj.keys().cloned())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Sorted dot-separated paths to every object key (`extra.series`)
+    fn field_paths(&self) -> Vec<String> {
+        fn collect(value: &JsonValue, prefix: &str, out: &mut BTreeSet<String>) {
+            let Some(obj) = value.as_object() else { return };
+            for (key, child) in obj {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                collect(child, &path, out);
+                out.insert(path);
+            }
+        }
+
+        let mut paths = BTreeSet::new();
+        for page in &self.pages {
+            collect(page, "", &mut paths);
+        }
+        paths.into_iter().collect()
+    }
+}
+
+/// Tab completion for the word before `pos`
+///
+/// A `:` word at the start of the line completes commands, any other word
+/// completes field names. Returns the start of the word and the candidates.
+fn complete_word(fields: &[String], line: &str, pos: usize) -> (usize, Vec<String>) {
+    let start = line[..pos].rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    let word = &line[start..pos];
+
+    let candidates = if start == 0 && word.starts_with(':') {
+        COMMANDS
+            .iter()
+            .filter(|command| command.starts_with(word))
+            .map(|command| command.to_string())
+            .collect()
+    } else {
+        fields
+            .iter()
+            .filter(|field| field.starts_with(word))
+            .cloned()
+            .collect()
+    };
+    (start, candidates)
+}
+
+/// Line editor helper completing commands and field names
+struct QueryHelper {
+    fields: Vec<String>,
+}
+
+impl Completer for QueryHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(complete_word(&self.fields, line, pos))
+    }
+}
+
+impl Hinter for QueryHelper {
+    type Hint = String;
+}
+
+impl Highlighter for QueryHelper {}
+
+impl Validator for QueryHelper {}
+
+impl Helper for QueryHelper {}
+
+/// Run the interactive session on stdin until `:quit` or end of input.
+pub(super) fn run(pages: JsonValue, pretty: bool, root: &Path) -> Result<()> {
+    let pages = match pages {
+        JsonValue::Array(pages) => pages,
+        other => vec![other],
+    };
+    let session = Session { pages, pretty };
+    let history = root.join(HISTORY_FILE);
+
+    let mut editor: Editor<QueryHelper, _> = Editor::new()?;
+    editor.set_helper(Some(QueryHelper {
+        fields: session.field_paths(),
+    }));
+    // No history yet on first run
+    let _ = editor.load_history(&history);
+
+    eprintln!(
+        "{} loaded, :help for commands",
+        crate::utils::plural_count(session.pages.len(), "page")
+    );
+
+    loop {
+        let line = match editor.readline("tola> ") {
+            Ok(line) => line,
+            // Ctrl-C drops the current line, Ctrl-D leaves
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        if !line.trim().is_empty() {
+            editor.add_history_entry(line.trim())?;
+            save_history(&mut editor, &history);
+        }
+
+        match session.eval(&line) {
+            Ok(Reply::Output(text)) => println!("{text}"),
+            Ok(Reply::Empty) => {}
+            Ok(Reply::Quit) => return Ok(()),
+            Err(e) => eprintln!("error: {e}"),
+        }
+    }
+}
+
+/// Append new history entries; failures only cost history.
+fn save_history(editor: &mut Editor<QueryHelper, rustyline::history::DefaultHistory>, path: &Path) {
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .map_err(ReadlineError::from)
+        .and_then(|()| editor.append_history(path));
+    if let Err(e) = result {
+        crate::debug!("query"; "failed to write {}: {}", path.display(), e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn session() -> Session {
+        Session {
+            pages: vec![
+                json!({"path": "a.typ", "title": "A", "tags": ["rust", "web"], "draft": false}),
+                json!({"path": "b.typ", "title": "B c", "tags": ["web"], "extra": {"series": "intro"}}),
+            ],
+            pretty: false,
+        }
+    }
+
+    fn output(reply: Result<Reply, String>) -> String {
+        match reply.unwrap() {
+            Reply::Output(text) => text,
+            other => panic!("expected output, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_query() {
+        assert_eq!(
+            parse_query(r#"title = "B c" and draft=false"#).unwrap(),
+            vec![
+                Condition {
+                    field: "title".into(),
+                    value: "B c".into()
+                },
+                Condition {
+                    field: "draft".into(),
+                    value: "false".into()
+                },
+            ]
+        );
+        assert!(parse_query("title").is_err());
+        assert!(parse_query("= x").is_err());
+    }
+
+    #[test]
+    fn test_query_prints_matching_pages() {
+        let session = session();
+        let text = output(session.eval("tags = rust"));
+        let pages: JsonValue = serde_json::from_str(&text).unwrap();
+        assert_eq!(pages, json!([session.pages[0]]));
+
+        assert_eq!(output(session.eval("extra.series = intro")), {
+            serde_json::to_string(&json!([session.pages[1]])).unwrap()
+        });
+        assert_eq!(output(session.eval("tags = web and draft = false")), {
+            serde_json::to_string(&json!([session.pages[0]])).unwrap()
+        });
+    }
+
+    #[test]
+    fn test_commands() {
+        let session = session();
+        assert_eq!(output(session.eval(":count")), "2");
+        assert_eq!(output(session.eval(":count tags = web")), "2");
+        assert_eq!(output(session.eval(":count title = Z")), "0");
+        assert_eq!(
+            output(session.eval(":fields")),
+            "draft\nextra\npath\ntags\ntitle"
+        );
+        assert_eq!(session.eval("  "), Ok(Reply::Empty));
+        assert_eq!(session.eval(":quit"), Ok(Reply::Quit));
+        assert!(session.eval(":nope").is_err());
+    }
+
+    #[test]
+    fn test_field_paths() {
+        assert_eq!(
+            session().field_paths(),
+            ["draft", "extra", "extra.series", "path", "tags", "title"]
+        );
+    }
+
+    #[test]
+    fn test_complete_word() {
+        let fields = session().field_paths();
+
+        assert_eq!(
+            complete_word(&fields, "ta", 2),
+            (0, vec!["tags".to_string()])
+        );
+        assert_eq!(
+            complete_word(&fields, "tags = web and ex", 17),
+            (15, vec!["extra".to_string(), "extra.series".to_string()])
+        );
+        assert_eq!(
+            complete_word(&fields, ":c", 2),
+            (0, vec![":count".to_string()])
+        );
+        assert_eq!(
+            complete_word(&fields, ":count ti", 9),
+            (7, vec!["title".to_string()])
+        );
+        assert!(complete_word(&fields, "zz", 2).1.is_empty());
+    }
+}
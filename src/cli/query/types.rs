@@ -18,6 +18,25 @@ impl QueryMeta {
             QueryMeta::Raw(json) => json.get("draft").and_then(|v| v.as_bool()).unwrap_or(false),
         }
     }
+
+    /// Page number of a paginated page (`page-number` metadata).
+    pub(super) fn page_number(&self) -> Option<u64> {
+        match self {
+            QueryMeta::Normalized(meta) => meta.page_number.map(u64::from),
+            QueryMeta::Raw(json) => json.get("page-number").and_then(|v| v.as_u64()),
+        }
+    }
+
+    /// Attach the estimated reading time (serialized as `reading_time_minutes`).
+    pub(super) fn set_reading_time(&mut self, minutes: u32) {
+        match self {
+            QueryMeta::Normalized(meta) => meta.reading_time_minutes = Some(minutes),
+            QueryMeta::Raw(JsonValue::Object(map)) => {
+                map.insert("reading_time_minutes".to_string(), minutes.into());
+            }
+            QueryMeta::Raw(_) => {}
+        }
+    }
 }
 
 impl Serialize for QueryMeta {
@@ -32,13 +51,39 @@ impl Serialize for QueryMeta {
     }
 }
 
+/// Page text statistics (`--stats`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PageStats {
+    pub word_count: usize,
+    /// Characters with whitespace runs collapsed to one space
+    pub char_count: usize,
+    pub reading_time_minutes: u32,
+}
+
+impl PageStats {
+    /// Count `text` at `wpm` words per minute.
+    pub fn from_text(text: &str, wpm: u32) -> Self {
+        let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        Self {
+            word_count: crate::page::reading::count_words(&collapsed),
+            char_count: collapsed.chars().count(),
+            reading_time_minutes: crate::page::reading::reading_time_minutes(&collapsed, wpm),
+        }
+    }
+}
+
 /// Result for a single queried page
 #[derive(Debug, Serialize)]
 pub struct PageQueryResult {
     pub path: String,
     pub permalink: String,
+    /// Path-derived permalink, before any `permalink` metadata override.
+    pub natural_permalink: String,
     #[serde(flatten)]
     pub meta: QueryMeta,
+    /// Text statistics, only with `--stats`.
+    #[serde(skip)]
+    pub stats: Option<PageStats>,
 }
 
 /// Result for batch query
@@ -47,3 +92,22 @@ pub struct PageQueryResult {
 pub struct QueryResult {
     pub pages: Vec<PageQueryResult>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_stats_from_text() {
+        let text = format!("  Hello\n\n  world {}", "word ".repeat(199));
+        let stats = PageStats::from_text(&text, 200);
+        assert_eq!(stats.word_count, 201);
+        assert_eq!(stats.reading_time_minutes, 2);
+        assert_eq!(
+            stats.char_count,
+            "Hello world ".len() + "word ".len() * 199 - 1
+        );
+
+        assert_eq!(PageStats::from_text(" \n", 200), PageStats::default());
+    }
+}
@@ -0,0 +1,15 @@
+//! Schema command - print JSON Schema for `tola.toml`.
+//!
+//! Point your editor at the output for completion and validation, e.g. with
+//! Even Better TOML: `#:schema ./tola.schema.json` at the top of `tola.toml`.
+
+use anyhow::Result;
+
+use crate::config::SiteConfig;
+
+/// Print the JSON Schema document to stdout
+pub fn print_schema() -> Result<()> {
+    let schema = SiteConfig::schema();
+    println!("{}", serde_json::to_string_pretty(&schema)?);
+    Ok(())
+}
@@ -108,8 +108,10 @@ pub fn serve_build(
     state: Arc<SiteIndex>,
 ) -> Result<()> {
     // Collect all content files
-    let content_files: Vec<_> = compiler::collect_all_files(&config.build.content)
-        .into_iter()
+    let content_files: Vec<_> = config
+        .build
+        .content_dirs()
+        .flat_map(compiler::collect_all_files)
         .filter(|p| ContentKind::is_content_file(p))
         .collect();
 
@@ -134,14 +136,25 @@ pub fn serve_build(
     // Finalize: print warnings and persist cache
     finalize_serve_build(config, &state, &warnings)?;
 
-    // Generate feed and sitemap
-    let (rss_result, sitemap_result) = rayon::join(
-        || state.with_pages(|pages| seo::feed::build_feed(config, pages)),
-        || state.with_pages(|pages| seo::sitemap::build_sitemap(config, pages)),
+    // Generate feed, sitemap and search index
+    let repo = seo::sitemap::open_source_repo(config);
+    let ((rss_result, sitemap_result), search_result) = rayon::join(
+        || {
+            rayon::join(
+                || state.with_pages(|pages| seo::feed::build_feed(config, pages)),
+                || {
+                    state.with_pages(|pages| {
+                        seo::sitemap::build_sitemap(config, pages, repo.as_ref())
+                    })
+                },
+            )
+        },
+        || state.with_pages(|pages| seo::search::build_search_index(config, pages)),
     );
 
     rss_result?;
     sitemap_result?;
+    search_result?;
 
     debug!("build"; "done");
     Ok(())
@@ -267,6 +280,7 @@ fn finalize_serve_build(
 ) -> Result<()> {
     use crate::cache::{
         PersistedDiagnostics, PersistedError, PersistedWarning, persist_cache, persist_diagnostics,
+        persist_url_map,
     };
     let root = config.get_root();
 
@@ -323,9 +337,16 @@ fn finalize_serve_build(
         &compiler::page::BUILD_CACHE,
         &source_paths,
         config.get_root(),
+        config.build.cache.compression(),
     ) {
         crate::debug!("build"; "failed to persist vdom cache: {}", e);
     }
 
+    // Persist URL map for `tola query --urls`
+    let url_map = state.read(|_, address| address.to_json(root));
+    if let Err(e) = persist_url_map(&url_map, root) {
+        crate::debug!("build"; "failed to persist url map: {}", e);
+    }
+
     Ok(())
 }
@@ -4,10 +4,13 @@ use crate::config::SiteConfig;
 use std::fs;
 use std::path::Path;
 
-/// Check if the content directory is effectively empty
+/// Check if every content directory is effectively empty
 pub fn is_content_empty(config: &SiteConfig) -> bool {
-    let dir = &config.build.content;
+    config.build.content_dirs().all(is_dir_empty)
+}
 
+/// Check if one content directory is effectively empty
+fn is_dir_empty(dir: &Path) -> bool {
     if !dir.exists() {
         return true;
     }
@@ -52,7 +55,13 @@ pub fn maybe_inject_hotreload(
 fn inject_hotreload_script(content: &[u8], path_prefix: &Path, ws_port: u16) -> Vec<u8> {
     use crate::embed::serve::{HOTRELOAD_JS, HotreloadVars};
 
-    let script = HOTRELOAD_JS.external_tag_with_vars(path_prefix, &HotreloadVars { ws_port });
+    let script = HOTRELOAD_JS.external_tag_with_vars(
+        path_prefix,
+        &HotreloadVars {
+            ws_port,
+            ws_scheme: super::ws_scheme(),
+        },
+    );
     let script_bytes = script.as_bytes();
 
     // Byte pattern for </body> - most generators use lowercase
@@ -0,0 +1,154 @@
+//! Transport-neutral request/response pair.
+//!
+//! Request handling in `serve` (file serving, hot reload injection,
+//! on-demand compilation, proxying) only talks to [`Exchange`] and builds a
+//! [`Reply`]. The HTTP/1.1 listener (`tiny_http`) and the HTTP/2 listener
+//! (`hyper`, see `http2.rs`) each implement the trait.
+
+use std::io::{Cursor, Read};
+use std::net::SocketAddr;
+
+use anyhow::Result;
+use tiny_http::{Header, Request, Response, StatusCode};
+
+/// One request waiting for its response
+pub trait Exchange: Send + 'static {
+    /// Request method (`GET`, `HEAD`, ...)
+    fn method(&self) -> &str;
+
+    /// Request target: path and query
+    fn url(&self) -> &str;
+
+    /// All request headers in order
+    fn headers(&self) -> Vec<(String, String)>;
+
+    /// Client address, if known
+    fn remote_addr(&self) -> Option<SocketAddr>;
+
+    /// Read the request body
+    fn read_body(&mut self) -> Result<Vec<u8>>;
+
+    /// Send `reply`, consuming the request
+    fn respond(self, reply: Reply) -> Result<()>;
+
+    /// First value of header `name` (case-insensitive)
+    fn header(&self, name: &str) -> Option<String> {
+        self.headers()
+            .into_iter()
+            .find(|(field, _)| field.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value)
+    }
+
+    fn is_head(&self) -> bool {
+        self.method().eq_ignore_ascii_case("HEAD")
+    }
+}
+
+/// Response body
+pub enum Body {
+    Empty,
+    Bytes(Vec<u8>),
+    /// Streamed body with its length, if known (ranges, proxied responses)
+    Reader(Box<dyn Read + Send>, Option<u64>),
+}
+
+impl Body {
+    /// Body length, if known up front
+    pub fn length(&self) -> Option<u64> {
+        match self {
+            Self::Empty => Some(0),
+            Self::Bytes(bytes) => Some(bytes.len() as u64),
+            Self::Reader(_, len) => *len,
+        }
+    }
+
+    fn into_reader(self) -> Box<dyn Read + Send> {
+        match self {
+            Self::Empty => Box::new(std::io::empty()),
+            Self::Bytes(bytes) => Box::new(Cursor::new(bytes)),
+            Self::Reader(reader, _) => reader,
+        }
+    }
+}
+
+/// Status, headers and body of a response
+pub struct Reply {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Body,
+}
+
+impl Reply {
+    pub fn new(status: u16, body: Body) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    pub fn empty(status: u16) -> Self {
+        Self::new(status, Body::Empty)
+    }
+
+    /// Add a header (repeated names are kept)
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Whether a header named `name` is set (case-insensitive)
+    pub fn has_header(&self, name: &str) -> bool {
+        self.headers
+            .iter()
+            .any(|(field, _)| field.eq_ignore_ascii_case(name))
+    }
+}
+
+impl Exchange for Request {
+    fn method(&self) -> &str {
+        Request::method(self).as_str()
+    }
+
+    fn url(&self) -> &str {
+        Request::url(self)
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        Request::headers(self)
+            .iter()
+            .map(|h| (h.field.as_str().to_string(), h.value.to_string()))
+            .collect()
+    }
+
+    fn remote_addr(&self) -> Option<SocketAddr> {
+        Request::remote_addr(self).copied()
+    }
+
+    fn read_body(&mut self) -> Result<Vec<u8>> {
+        let mut body = Vec::new();
+        self.as_reader().read_to_end(&mut body)?;
+        Ok(body)
+    }
+
+    fn respond(self, reply: Reply) -> Result<()> {
+        let headers = reply
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Header::from_bytes(name.as_bytes(), value.as_bytes()).ok())
+            .collect();
+        let length = reply
+            .body
+            .length()
+            .and_then(|len| usize::try_from(len).ok());
+        let response = Response::new(
+            StatusCode(reply.status),
+            headers,
+            reply.body.into_reader(),
+            length,
+            None,
+        );
+        Request::respond(self, response)?;
+        Ok(())
+    }
+}
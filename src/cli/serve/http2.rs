@@ -0,0 +1,324 @@
+//! HTTP/2 listener (`[serve.http2]`).
+//!
+//! Replaces `tiny_http` when enabled. Connections are TLS-only and negotiate
+//! `h2` (or `http/1.1` for older clients) through ALPN; `hyper` speaks the
+//! protocol while requests are answered by the same handler as HTTP/1.1 on
+//! the request pool.
+//!
+//! Handlers run on blocking worker threads, so each request is collected up
+//! front and streamed response bodies are fed back into the runtime chunk by
+//! chunk.
+
+use std::convert::Infallible;
+use std::io::{self, Read};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use bytes::Bytes;
+use http_body_util::channel::Channel;
+use http_body_util::combinators::BoxBody;
+use http_body_util::{BodyExt, Full};
+use hyper::body::Incoming;
+use hyper::header::{CONTENT_LENGTH, CONTENT_TYPE, HeaderName, HeaderValue};
+use hyper::http::request::Parts;
+use hyper::service::service_fn;
+use hyper::{Response, StatusCode};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto;
+use tokio::net::TcpListener;
+use tokio::runtime::{Handle, Runtime};
+use tokio::sync::{Notify, oneshot};
+use tokio_rustls::TlsAcceptor;
+
+use super::RequestPool;
+use super::exchange::{Body, Exchange, Reply};
+use crate::debug;
+
+/// ALPN protocols offered, preferred first
+const ALPN_PROTOCOLS: [&[u8]; 2] = [b"h2", b"http/1.1"];
+
+/// Read size for streamed response bodies
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Streamed chunks buffered ahead of the client
+const CHANNEL_CAPACITY: usize = 4;
+
+type ResponseBody = BoxBody<Bytes, io::Error>;
+
+/// Bound HTTP/2 server
+pub struct Http2Server {
+    runtime: Runtime,
+    listener: TcpListener,
+    acceptor: TlsAcceptor,
+    shutdown: Arc<Notify>,
+}
+
+impl Http2Server {
+    /// Serve `listener` with the certificate of `tls`
+    pub fn new(listener: std::net::TcpListener, tls: &rustls::ServerConfig) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("tola-http2")
+            .enable_all()
+            .build()
+            .context("failed to create HTTP/2 runtime")?;
+
+        listener.set_nonblocking(true)?;
+        let listener = {
+            let _guard = runtime.enter();
+            TcpListener::from_std(listener)?
+        };
+
+        let mut tls = tls.clone();
+        tls.alpn_protocols = ALPN_PROTOCOLS.iter().map(|p| p.to_vec()).collect();
+
+        Ok(Self {
+            runtime,
+            listener,
+            acceptor: TlsAcceptor::from(Arc::new(tls)),
+            shutdown: Arc::new(Notify::new()),
+        })
+    }
+
+    /// Callback that makes [`Self::run`] stop accepting connections
+    pub fn unblocker(&self) -> impl Fn() + Send + Sync + 'static {
+        let shutdown = Arc::clone(&self.shutdown);
+        move || shutdown.notify_one()
+    }
+
+    /// Accept connections until shutdown (blocking)
+    ///
+    /// Open connections keep running on the runtime until `self` is dropped.
+    pub fn run(&self, pool: &Arc<RequestPool>) {
+        self.runtime.block_on(async {
+            loop {
+                let (stream, remote) = tokio::select! {
+                    accepted = self.listener.accept() => match accepted {
+                        Ok(accepted) => accepted,
+                        Err(e) => {
+                            debug!("serve"; "accept failed: {}", e);
+                            continue;
+                        }
+                    },
+                    _ = self.shutdown.notified() => return,
+                };
+                tokio::spawn(serve_connection(
+                    self.acceptor.clone(),
+                    stream,
+                    remote,
+                    Arc::clone(pool),
+                ));
+            }
+        });
+    }
+}
+
+async fn serve_connection(
+    acceptor: TlsAcceptor,
+    stream: tokio::net::TcpStream,
+    remote: SocketAddr,
+    pool: Arc<RequestPool>,
+) {
+    let stream = match acceptor.accept(stream).await {
+        Ok(stream) => stream,
+        Err(e) => {
+            debug!("serve"; "tls handshake with {} failed: {}", remote, e);
+            return;
+        }
+    };
+
+    let service = service_fn(move |request| dispatch(request, remote, Arc::clone(&pool)));
+    if let Err(e) = auto::Builder::new(TokioExecutor::new())
+        .serve_connection(TokioIo::new(stream), service)
+        .await
+    {
+        debug!("serve"; "connection with {} closed: {}", remote, e);
+    }
+}
+
+/// Hand one request to the pool and wait for its response
+async fn dispatch(
+    request: hyper::Request<Incoming>,
+    remote: SocketAddr,
+    pool: Arc<RequestPool>,
+) -> Result<Response<ResponseBody>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = match body.collect().await {
+        Ok(body) => body.to_bytes().to_vec(),
+        Err(e) => {
+            debug!("serve"; "failed to read request body: {}", e);
+            return Ok(status_only(StatusCode::BAD_REQUEST));
+        }
+    };
+
+    let (reply_tx, reply_rx) = oneshot::channel();
+    pool.spawn(Http2Exchange {
+        url: parts.uri.path_and_query().map_or_else(
+            || parts.uri.path().to_string(),
+            |pq| pq.as_str().to_string(),
+        ),
+        parts,
+        body,
+        remote,
+        reply_tx,
+        runtime: Handle::current(),
+    });
+
+    // A handler that fails without replying drops the sender
+    Ok(reply_rx
+        .await
+        .unwrap_or_else(|_| status_only(StatusCode::INTERNAL_SERVER_ERROR)))
+}
+
+fn status_only(status: StatusCode) -> Response<ResponseBody> {
+    let mut response = Response::new(empty_body());
+    *response.status_mut() = status;
+    response
+}
+
+fn empty_body() -> ResponseBody {
+    Full::new(Bytes::new()).map_err(|e| match e {}).boxed()
+}
+
+/// Request received over hyper, answered from a pool thread
+struct Http2Exchange {
+    url: String,
+    parts: Parts,
+    body: Vec<u8>,
+    remote: SocketAddr,
+    reply_tx: oneshot::Sender<Response<ResponseBody>>,
+    runtime: Handle,
+}
+
+impl Exchange for Http2Exchange {
+    fn method(&self) -> &str {
+        self.parts.method.as_str()
+    }
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+
+    fn headers(&self) -> Vec<(String, String)> {
+        self.parts
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+                (name.as_str().to_string(), value)
+            })
+            .collect()
+    }
+
+    fn remote_addr(&self) -> Option<SocketAddr> {
+        Some(self.remote)
+    }
+
+    fn read_body(&mut self) -> Result<Vec<u8>> {
+        Ok(std::mem::take(&mut self.body))
+    }
+
+    fn respond(self, reply: Reply) -> Result<()> {
+        let length = reply.body.length();
+        let (head, body) = split_reply(reply);
+
+        let mut reader = match body {
+            Body::Reader(reader, _) => reader,
+            body => {
+                let body = match body {
+                    Body::Bytes(bytes) => Full::new(Bytes::from(bytes))
+                        .map_err(|e| match e {})
+                        .boxed(),
+                    _ => empty_body(),
+                };
+                // The client is gone if the receiver was dropped
+                let _ = self.reply_tx.send(head.map(|()| body));
+                return Ok(());
+            }
+        };
+
+        let (mut sender, channel) = Channel::<Bytes, io::Error>::new(CHANNEL_CAPACITY);
+        let mut response = head.map(|()| channel.boxed());
+        if let Some(length) = length {
+            response
+                .headers_mut()
+                .insert(CONTENT_LENGTH, HeaderValue::from(length));
+        }
+        if self.reply_tx.send(response).is_err() {
+            return Ok(());
+        }
+
+        let mut buf = vec![0; CHUNK_SIZE];
+        loop {
+            let read = match reader.read(&mut buf) {
+                Ok(0) => return Ok(()),
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    sender.abort(io::Error::new(e.kind(), e.to_string()));
+                    return Err(e.into());
+                }
+            };
+            let chunk = Bytes::copy_from_slice(&buf[..read]);
+            if self.runtime.block_on(sender.send_data(chunk)).is_err() {
+                // Client went away mid-stream
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Status and headers of `reply` as a hyper response head, plus its body
+///
+/// `Content-Type` is replaced rather than repeated (the last one wins, as
+/// with `tiny_http`); headers hyper can't represent are dropped.
+fn split_reply(reply: Reply) -> (Response<()>, Body) {
+    let mut head = Response::new(());
+    *head.status_mut() =
+        StatusCode::from_u16(reply.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    let headers = head.headers_mut();
+    for (name, value) in &reply.headers {
+        let (Ok(name), Ok(value)) = (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) else {
+            continue;
+        };
+        if name == CONTENT_TYPE {
+            headers.insert(name, value);
+        } else {
+            headers.append(name, value);
+        }
+    }
+    (head, reply.body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_reply_headers() {
+        let reply = Reply::new(206, Body::Bytes(b"partial".to_vec()))
+            .with_header("Content-Type", "application/octet-stream")
+            .with_header("Set-Cookie", "a=1")
+            .with_header("Set-Cookie", "b=2")
+            .with_header("Content-Type", "text/html; charset=utf-8")
+            .with_header("Bad Header", "dropped");
+
+        let (head, body) = split_reply(reply);
+        assert_eq!(head.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(head.headers()[CONTENT_TYPE], "text/html; charset=utf-8");
+        assert_eq!(head.headers().get_all("set-cookie").iter().count(), 2);
+        assert_eq!(head.headers().len(), 3);
+        assert!(matches!(body, Body::Bytes(bytes) if bytes == b"partial"));
+    }
+
+    #[test]
+    fn test_split_reply_invalid_status() {
+        let (head, _) = split_reply(Reply::empty(1000));
+        assert_eq!(head.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -1,29 +1,35 @@
 //! Server lifecycle management.
 
 use crate::address::SiteIndex;
-use crate::{actor::Coordinator, config::ConfigHandle, core::register_server, log};
+use crate::{actor::Coordinator, config::ConfigHandle, core::register_server, debug, log};
 use anyhow::Result;
 use crossbeam::channel::{Receiver, Sender};
 use std::{
-    net::SocketAddr,
+    fmt::Display,
+    io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    process::{Command, Stdio},
     sync::Arc,
     thread::{self, JoinHandle},
 };
-use tiny_http::Server;
 
 /// Maximum number of port binding attempts
 const MAX_PORT_RETRIES: u16 = 10;
 
 /// Bind to the specified interface and port, with automatic port retry
-pub fn bind_with_retry(
+///
+/// `bind` creates the listener for one address; the next port is tried
+/// when it fails.
+pub fn bind_with_retry<T, E: Display>(
     interface: std::net::IpAddr,
     base_port: u16,
-) -> Result<(Server, SocketAddr)> {
+    bind: impl Fn(SocketAddr) -> Result<T, E>,
+) -> Result<(T, SocketAddr)> {
     for offset in 0..MAX_PORT_RETRIES {
         let port = base_port.saturating_add(offset);
         let addr = SocketAddr::new(interface, port);
 
-        match Server::http(addr) {
+        match bind(addr) {
             Ok(server) => {
                 if offset > 0 {
                     log!("serve"; "port {} in use, using {} instead", base_port, port);
@@ -45,12 +51,112 @@ pub fn bind_with_retry(
     unreachable!()
 }
 
+/// Open the server URL in the default browser (`serve.open`)
+///
+/// Best effort: failures such as a headless CI without a browser are only
+/// reported in verbose mode.
+pub fn open_browser(addr: SocketAddr, scheme: &str) {
+    let url = browser_url(addr, scheme);
+    thread::spawn(move || {
+        if let Err(e) = launch_browser(&url) {
+            debug!("serve"; "failed to open browser: {}", e);
+        }
+    });
+}
+
+/// URL to open; wildcard binds are reached through loopback
+fn browser_url(addr: SocketAddr, scheme: &str) -> String {
+    let addr = match addr.ip() {
+        IpAddr::V4(ip) if ip.is_unspecified() => {
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), addr.port())
+        }
+        IpAddr::V6(ip) if ip.is_unspecified() => {
+            SocketAddr::new(Ipv6Addr::LOCALHOST.into(), addr.port())
+        }
+        _ => addr,
+    };
+    format!("{scheme}://{addr}/")
+}
+
+/// Log the URLs the server is reachable at
+///
+/// A wildcard bind (`0.0.0.0`) also lists every non-loopback IPv4 address,
+/// so the site can be opened from other devices on the same network.
+pub fn log_urls(addr: SocketAddr, scheme: &str) {
+    if !addr.ip().is_unspecified() {
+        log!("serve"; "{}://{}", scheme, addr);
+        return;
+    }
+
+    log!("serve"; "{} (localhost)", browser_url(addr, scheme).trim_end_matches('/'));
+    let interfaces = match if_addrs::get_if_addrs() {
+        Ok(interfaces) => interfaces,
+        Err(e) => {
+            debug!("serve"; "failed to list network interfaces: {}", e);
+            return;
+        }
+    };
+    for url in network_urls(addr, scheme, interfaces.iter().map(|i| i.ip())) {
+        log!("serve"; "{} (network)", url);
+    }
+}
+
+/// URLs for non-loopback IPv4 addresses, deduplicated in order
+fn network_urls(
+    addr: SocketAddr,
+    scheme: &str,
+    ips: impl IntoIterator<Item = IpAddr>,
+) -> Vec<String> {
+    let mut urls: Vec<String> = Vec::new();
+    for ip in ips {
+        let IpAddr::V4(ip) = ip else { continue };
+        if ip.is_loopback() || ip.is_unspecified() || ip.is_link_local() {
+            continue;
+        }
+        let url = format!("{scheme}://{}", SocketAddr::new(ip.into(), addr.port()));
+        if !urls.contains(&url) {
+            urls.push(url);
+        }
+    }
+    urls
+}
+
+fn launch_browser(url: &str) -> io::Result<()> {
+    #[cfg(target_os = "macos")]
+    let mut cmd = Command::new("open");
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", "start", ""]);
+        cmd
+    };
+    #[cfg(not(any(target_os = "macos", windows)))]
+    let mut cmd = Command::new("xdg-open");
+
+    let status = cmd
+        .arg(url)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "browser launcher exited with {status}"
+        )))
+    }
+}
+
 /// Register server for graceful shutdown
 ///
 /// This registers the server with the global shutdown handler set up in main()
-/// When Ctrl+C is pressed, the handler will unblock the server and notify actors
-pub fn register_server_for_shutdown(server: Arc<Server>, shutdown_tx: Sender<()>) {
-    register_server(server, shutdown_tx);
+/// When Ctrl+C is pressed, the handler calls `unblock` to stop accepting
+/// requests and notifies actors
+pub fn register_server_for_shutdown(
+    unblock: impl Fn() + Send + Sync + 'static,
+    shutdown_tx: Sender<()>,
+) {
+    register_server(unblock, shutdown_tx);
 }
 
 /// Spawn the actor system for file watching and hot reload
@@ -59,6 +165,7 @@ pub fn spawn_actors(
     state: Arc<SiteIndex>,
     watch_enabled: bool,
     ws_port: Option<u16>,
+    ws_tls: Option<Arc<rustls::ServerConfig>>,
     shutdown_rx: Receiver<()>,
 ) -> Option<JoinHandle<()>> {
     if !watch_enabled {
@@ -66,7 +173,7 @@ pub fn spawn_actors(
     }
 
     Some(thread::spawn(move || {
-        run_actor_system(config, state, ws_port, shutdown_rx);
+        run_actor_system(config, state, ws_port, ws_tls, shutdown_rx);
     }))
 }
 
@@ -74,6 +181,7 @@ fn run_actor_system(
     config: ConfigHandle,
     state: Arc<SiteIndex>,
     ws_port: Option<u16>,
+    ws_tls: Option<Arc<rustls::ServerConfig>>,
     shutdown_rx: Receiver<()>,
 ) {
     let rt = tokio::runtime::Builder::new_multi_thread()
@@ -88,6 +196,9 @@ fn run_actor_system(
         if let Some(port) = ws_port {
             coordinator = coordinator.with_ws_port(port);
         }
+        if let Some(tls) = ws_tls {
+            coordinator = coordinator.with_ws_tls(tls);
+        }
         if let Err(e) = coordinator.run().await {
             log!("actor"; "error: {}", e);
         }
@@ -109,3 +220,37 @@ pub fn wait_for_shutdown(handle: Option<JoinHandle<()>>) {
     // Force exit: background threads (tokio, hook subprocesses) may not respond
     std::process::exit(0);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_browser_url() {
+        let addr: SocketAddr = "127.0.0.1:5277".parse().unwrap();
+        assert_eq!(browser_url(addr, "http"), "http://127.0.0.1:5277/");
+
+        let addr: SocketAddr = "0.0.0.0:5277".parse().unwrap();
+        assert_eq!(browser_url(addr, "http"), "http://127.0.0.1:5277/");
+
+        let addr: SocketAddr = "[::]:8080".parse().unwrap();
+        assert_eq!(browser_url(addr, "https"), "https://[::1]:8080/");
+    }
+
+    #[test]
+    fn test_network_urls() {
+        let addr: SocketAddr = "0.0.0.0:5277".parse().unwrap();
+        let ips: Vec<IpAddr> = vec![
+            Ipv4Addr::LOCALHOST.into(),
+            "192.168.1.5".parse().unwrap(),
+            "169.254.0.3".parse().unwrap(),
+            "fe80::1".parse().unwrap(),
+            "10.0.0.2".parse().unwrap(),
+            "192.168.1.5".parse().unwrap(),
+        ];
+        assert_eq!(
+            network_urls(addr, "http", ips),
+            ["http://192.168.1.5:5277", "http://10.0.0.2:5277"]
+        );
+    }
+}
@@ -4,11 +4,15 @@ mod build;
 mod classify;
 mod compile;
 mod content;
+mod exchange;
+mod http2;
 mod lifecycle;
 mod path;
+mod proxy;
 mod response;
 mod scan;
 mod startup;
+mod tls;
 
 pub use build::init_serve_build;
 pub(crate) use build::start_serve_build;
@@ -25,13 +29,14 @@ use crate::{
 use anyhow::Result;
 use classify::{ServedOutputKind, classify_served_output};
 use crossbeam::channel;
+use exchange::Exchange;
 use parking_lot::Mutex;
-use std::net::SocketAddr;
+use std::net::{SocketAddr, TcpListener};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
-use tiny_http::{Request, Server};
+use tiny_http::Server;
 
 /// Default WebSocket port for hot reload
 pub const DEFAULT_WS_PORT: u16 = 35729;
@@ -40,12 +45,36 @@ pub const DEFAULT_WS_PORT: u16 = 35729;
 /// Updated by coordinator after WebSocket server binds successfully
 static ACTUAL_WS_PORT: AtomicU16 = AtomicU16::new(DEFAULT_WS_PORT);
 
+/// Whether the server (and hot reload WebSocket) runs over TLS
+static TLS_ACTIVE: AtomicBool = AtomicBool::new(false);
+
 /// Startup scan readiness for progressive serving.
 /// Kept in serve module to avoid leaking serve-only state into core globals.
 static SCAN_READY: AtomicBool = AtomicBool::new(false);
 /// Last observed HTTP request time. Used to keep startup warmup out of the
 /// user's way while the first page load is still in flight.
 static LAST_REQUEST_MS: AtomicU64 = AtomicU64::new(0);
+/// Requests accepted but not yet answered, drained on shutdown.
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+/// Poll interval while draining in-flight requests
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Counts a request as in flight until dropped
+struct InFlightGuard;
+
+impl InFlightGuard {
+    fn new() -> Self {
+        IN_FLIGHT.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        IN_FLIGHT.fetch_sub(1, Ordering::SeqCst);
+    }
+}
 
 struct CachedTypstHost {
     config: Arc<SiteConfig>,
@@ -64,6 +93,15 @@ fn get_actual_ws_port() -> u16 {
     ACTUAL_WS_PORT.load(Ordering::Relaxed)
 }
 
+/// URL scheme of the hot reload WebSocket
+fn ws_scheme() -> &'static str {
+    if TLS_ACTIVE.load(Ordering::Relaxed) {
+        "wss"
+    } else {
+        "ws"
+    }
+}
+
 pub(crate) fn set_scan_ready(ready: bool) {
     SCAN_READY.store(ready, Ordering::SeqCst);
 }
@@ -116,12 +154,21 @@ fn compile_source_on_demand(
 
 /// Bound server ready to accept requests
 pub struct BoundServer {
-    server: Arc<Server>,
+    listener: Listener,
     addr: SocketAddr,
     ws_port: Option<u16>,
+    ws_tls: Option<Arc<rustls::ServerConfig>>,
     shutdown_rx: channel::Receiver<()>,
 }
 
+/// Transport accepting connections
+enum Listener {
+    /// HTTP/1.1 (optionally over TLS) via `tiny_http`
+    Http1(Arc<Server>),
+    /// HTTP/2 with HTTP/1.1 fallback over TLS via `hyper` (`[serve.http2]`)
+    Http2(http2::Http2Server),
+}
+
 /// Bind the HTTP server without starting the request loop
 ///
 /// This allows the caller to start background tasks (like scan) before
@@ -129,23 +176,49 @@ pub struct BoundServer {
 /// with a 503 response
 pub fn bind_server() -> Result<BoundServer> {
     let config = config_handle().current();
-    let (server, addr) = lifecycle::bind_with_retry(config.serve.interface, config.serve.port)?;
-    let server = Arc::new(server);
+    let tls = tls::TlsIdentity::load(&config)?;
+    let ws_tls = tls.as_ref().map(|tls| tls.server_config()).transpose()?;
+    let (interface, port) = (config.serve.interface, config.serve.port);
+
+    let (shutdown_tx, shutdown_rx) = channel::unbounded::<()>();
+    let (listener, addr) = if config.serve.http2.enable
+        && let Some(tls_config) = &ws_tls
+    {
+        let (listener, addr) = lifecycle::bind_with_retry(interface, port, TcpListener::bind)?;
+        let server = http2::Http2Server::new(listener, tls_config)?;
+        lifecycle::register_server_for_shutdown(server.unblocker(), shutdown_tx);
+        debug!("serve"; "http/2 enabled");
+        (Listener::Http2(server), addr)
+    } else {
+        let (server, addr) = lifecycle::bind_with_retry(interface, port, |addr| match &tls {
+            Some(tls) => Server::https(addr, tls.ssl_config()),
+            None => Server::http(addr),
+        })?;
+        let server = Arc::new(server);
+        let unblock = Arc::clone(&server);
+        lifecycle::register_server_for_shutdown(move || unblock.unblock(), shutdown_tx);
+        (Listener::Http1(server), addr)
+    };
+
+    TLS_ACTIVE.store(tls.is_some(), Ordering::Relaxed);
+    let scheme = if tls.is_some() { "https" } else { "http" };
 
     let ws_port = config.serve.watch.then_some(DEFAULT_WS_PORT);
     if ws_port.is_some() {
-        debug!("hotreload"; "ws://localhost:{}", DEFAULT_WS_PORT);
+        debug!("hotreload"; "{}://localhost:{}", ws_scheme(), DEFAULT_WS_PORT);
     }
 
-    let (shutdown_tx, shutdown_rx) = channel::unbounded::<()>();
-    lifecycle::register_server_for_shutdown(Arc::clone(&server), shutdown_tx);
-
-    log!("serve"; "http://{}", addr);
+    lifecycle::log_urls(addr, scheme);
+    proxy::check_targets(&config);
+    if config.serve.open {
+        lifecycle::open_browser(addr, scheme);
+    }
 
     Ok(BoundServer {
-        server,
+        listener,
         addr,
         ws_port,
+        ws_tls,
         shutdown_rx,
     })
 }
@@ -165,29 +238,53 @@ impl BoundServer {
             Arc::clone(&state),
             config.serve.watch,
             self.ws_port,
+            self.ws_tls,
             self.shutdown_rx,
         );
-        run_request_loop(&self.server, state);
+        let pool = Arc::new(RequestPool::new(state));
+        match &self.listener {
+            Listener::Http1(server) => run_request_loop(server, &pool),
+            Listener::Http2(server) => server.run(&pool),
+        }
+        // The HTTP/2 runtime lives in `self.listener`, so streamed
+        // responses keep flowing while requests drain.
+        drain_requests(Duration::from_secs(config.serve.shutdown_timeout_secs));
         lifecycle::wait_for_shutdown(actor_handle);
         Ok(())
     }
 }
 
-fn run_request_loop(server: &Server, state: Arc<SiteIndex>) {
-    // Use thread pool to handle requests concurrently
-    // This prevents on-demand compilation from blocking other requests
-    let pool = rayon::ThreadPoolBuilder::new()
-        .num_threads(4)
-        .build()
-        .expect("failed to create thread pool");
+/// Worker threads answering requests for either listener
+///
+/// Requests are handled off the accepting thread, so on-demand compilation
+/// doesn't block other requests.
+struct RequestPool {
+    pool: rayon::ThreadPool,
+    state: Arc<SiteIndex>,
+    typst_hosts: TypstHostCache,
+}
 
-    let config_handle = config_handle();
-    let typst_hosts: TypstHostCache = Arc::new(Mutex::new(None));
-    for request in server.incoming_requests() {
-        let state = Arc::clone(&state);
-        let typst_hosts = Arc::clone(&typst_hosts);
-        pool.spawn(move || {
-            let config = config_handle.current();
+impl RequestPool {
+    fn new(state: Arc<SiteIndex>) -> Self {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .expect("failed to create thread pool");
+        Self {
+            pool,
+            state,
+            typst_hosts: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Handle `request` on a worker thread, counting it as in flight
+    fn spawn(&self, request: impl Exchange) {
+        let state = Arc::clone(&self.state);
+        let typst_hosts = Arc::clone(&self.typst_hosts);
+        let guard = InFlightGuard::new();
+        self.pool.spawn(move || {
+            let _guard = guard;
+            let config = config_handle().current();
             if let Err(e) = handle_request(request, config, typst_hosts, state) {
                 log!("serve"; "request error: {e}");
             }
@@ -195,9 +292,44 @@ fn run_request_loop(server: &Server, state: Arc<SiteIndex>) {
     }
 }
 
+fn run_request_loop(server: &Server, pool: &RequestPool) {
+    for request in server.incoming_requests() {
+        pool.spawn(request);
+    }
+}
+
+/// Wait for in-flight requests after the server stopped accepting new ones
+///
+/// Requests still running after `timeout` are abandoned when the process exits.
+fn drain_requests(timeout: Duration) {
+    let pending = IN_FLIGHT.load(Ordering::SeqCst);
+    if pending == 0 {
+        return;
+    }
+
+    let deadline = std::time::Instant::now() + timeout;
+    while IN_FLIGHT.load(Ordering::SeqCst) > 0 && std::time::Instant::now() < deadline {
+        std::thread::sleep(DRAIN_POLL_INTERVAL);
+    }
+
+    let remaining = IN_FLIGHT.load(Ordering::SeqCst);
+    if remaining == 0 {
+        log!("serve"; "drained {}", crate::utils::plural_count(pending, "in-flight request"));
+    } else {
+        log!(
+            "serve";
+            "{} still running after {}s, exiting",
+            crate::utils::plural_count(remaining, "request"),
+            timeout.as_secs()
+        );
+    }
+}
+
 /// Handle a single HTTP request
-fn handle_request(
-    request: Request,
+///
+/// Requests arriving after shutdown get a 503 while in-flight ones drain.
+fn handle_request<E: Exchange>(
+    request: E,
     config: Arc<SiteConfig>,
     typst_hosts: TypstHostCache,
     state: Arc<SiteIndex>,
@@ -216,12 +348,20 @@ fn handle_request(
     let ws_port = config_ref.serve.watch.then_some(get_actual_ws_port());
     if let Some(port) = ws_port {
         use crate::embed::serve::{HOTRELOAD_JS, HotreloadVars};
-        let vars = HotreloadVars { ws_port: port };
+        let vars = HotreloadVars {
+            ws_port: port,
+            ws_scheme: ws_scheme(),
+        };
         if request.url() == HOTRELOAD_JS.url_path_with_vars(&config_ref.build.path_prefix, &vars) {
             return response::respond_hotreload_js(request, port);
         }
     }
 
+    // Forward `[[serve.proxy]]` prefixes to their backends before file lookup
+    if let Some(proxy) = proxy::find(config_ref, &request) {
+        return proxy::forward(request, proxy);
+    }
+
     let request_url = request.url().to_string();
 
     // Serve static output files as early as possible, even during startup scan.
@@ -313,8 +453,8 @@ fn handle_request(
     response::respond_not_found(request, config_ref, ws_port)
 }
 
-fn serve_unhealthy_request(
-    request: Request,
+fn serve_unhealthy_request<E: Exchange>(
+    request: E,
     request_url: &str,
     config: Arc<SiteConfig>,
     typst_hosts: TypstHostCache,
@@ -385,8 +525,8 @@ fn push_content_candidate(matches: &mut Vec<PathBuf>, candidate: PathBuf) {
     }
 }
 
-fn serve_file_with_recovery(
-    request: Request,
+fn serve_file_with_recovery<E: Exchange>(
+    request: E,
     request_url: &str,
     path: &Path,
     config: Arc<SiteConfig>,
@@ -407,8 +547,8 @@ fn serve_file_with_recovery(
     }
 }
 
-fn serve_file_without_recovery(
-    request: Request,
+fn serve_file_without_recovery<E: Exchange>(
+    request: E,
     path: &Path,
     config: &SiteConfig,
     ws_port: Option<u16>,
@@ -420,8 +560,8 @@ fn serve_file_without_recovery(
     }
 }
 
-fn recover_missing_output(
-    request: Request,
+fn recover_missing_output<E: Exchange>(
+    request: E,
     request_url: &str,
     config: Arc<SiteConfig>,
     typst_hosts: &TypstHostCache,
@@ -447,15 +587,20 @@ fn recover_missing_output(
 #[cfg(test)]
 mod tests {
     use super::classify::{ServedOutputKind, classify_served_output};
-    use super::guess_source_before_scan;
+    use super::{IN_FLIGHT, InFlightGuard, drain_requests, guess_source_before_scan};
     use crate::address::SiteIndex;
     use crate::config::SiteConfig;
     use crate::core::UrlPath;
     use crate::page::{PageMeta, PageRoute};
     use std::fs;
     use std::path::{Path, PathBuf};
+    use std::sync::atomic::Ordering;
+    use std::time::{Duration, Instant};
     use tempfile::TempDir;
 
+    /// Serializes tests that touch the global in-flight counter
+    static IN_FLIGHT_LOCK: parking_lot::Mutex<()> = parking_lot::Mutex::new(());
+
     fn make_test_config(root: &Path) -> SiteConfig {
         let root = crate::utils::path::normalize_path(root);
         let mut config = SiteConfig::default();
@@ -616,4 +761,53 @@ mod tests {
 
         assert!(guess_source_before_scan("/posts/", &config).is_none());
     }
+
+    #[test]
+    fn in_flight_guard_counts_until_dropped() {
+        let _lock = IN_FLIGHT_LOCK.lock();
+        let before = IN_FLIGHT.load(Ordering::SeqCst);
+
+        let first = InFlightGuard::new();
+        let second = InFlightGuard::new();
+        assert_eq!(IN_FLIGHT.load(Ordering::SeqCst), before + 2);
+
+        drop(first);
+        assert_eq!(IN_FLIGHT.load(Ordering::SeqCst), before + 1);
+        drop(second);
+        assert_eq!(IN_FLIGHT.load(Ordering::SeqCst), before);
+    }
+
+    #[test]
+    fn drain_requests_returns_once_nothing_is_in_flight() {
+        let _lock = IN_FLIGHT_LOCK.lock();
+
+        // Nothing pending: no waiting at all
+        let start = Instant::now();
+        drain_requests(Duration::from_secs(10));
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        // A request finishing mid-drain ends the wait early
+        let guard = InFlightGuard::new();
+        let worker = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            drop(guard);
+        });
+        let start = Instant::now();
+        drain_requests(Duration::from_secs(10));
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(IN_FLIGHT.load(Ordering::SeqCst), 0);
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn drain_requests_gives_up_at_the_deadline() {
+        let _lock = IN_FLIGHT_LOCK.lock();
+
+        let guard = InFlightGuard::new();
+        let start = Instant::now();
+        drain_requests(Duration::from_millis(100));
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        assert_eq!(IN_FLIGHT.load(Ordering::SeqCst), 1);
+        drop(guard);
+    }
 }
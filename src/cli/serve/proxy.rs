@@ -0,0 +1,174 @@
+//! Reverse proxy for `[[serve.proxy]]`.
+//!
+//! Requests whose path falls under a proxy `prefix` are forwarded to its
+//! `target` before any file lookup. The backend response is streamed back
+//! with its status and end-to-end headers; hop-by-hop headers are dropped in
+//! both directions and `X-Forwarded-For` is set.
+//!
+//! WebSocket upgrades are never proxied: hot reload has its own port, and
+//! neither listener tunnels a connection.
+
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use anyhow::Result;
+use reqwest::blocking::Client;
+
+use super::exchange::{Body, Exchange, Reply};
+use crate::config::SiteConfig;
+use crate::config::section::ProxyConfig;
+use crate::log;
+
+/// Headers that only apply to a single connection (RFC 9110 §7.6.1)
+const HOP_BY_HOP: &[&str] = &[
+    "connection",
+    "keep-alive",
+    "proxy-authenticate",
+    "proxy-authorization",
+    "proxy-connection",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+];
+
+/// Timeout for the startup reachability check
+const CHECK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Shared client; redirects are passed through to the browser
+static CLIENT: LazyLock<Client> = LazyLock::new(|| {
+    Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap_or_default()
+});
+
+/// Proxy rule for `request`, if any
+pub fn find<'a>(config: &'a SiteConfig, request: &impl Exchange) -> Option<&'a ProxyConfig> {
+    if is_websocket_upgrade(request) {
+        return None;
+    }
+    let path = request.url().split(['?', '#']).next().unwrap_or_default();
+    config.serve.proxy.iter().find(|proxy| proxy.matches(path))
+}
+
+/// Forward `request` to the proxy target and stream the response back
+pub fn forward<E: Exchange>(mut request: E, proxy: &ProxyConfig) -> Result<()> {
+    let url = format!("{}{}", proxy.target.trim_end_matches('/'), request.url());
+
+    let body = request.read_body()?;
+
+    let method = reqwest::Method::from_bytes(request.method().as_bytes())?;
+    let mut backend = CLIENT.request(method, &url).body(body);
+    let mut forwarded_for = None;
+    for (name, value) in request.headers() {
+        if is_hop_by_hop(&name) || name.eq_ignore_ascii_case("host") {
+            continue;
+        }
+        if name.eq_ignore_ascii_case("x-forwarded-for") {
+            forwarded_for = Some(value);
+            continue;
+        }
+        backend = backend.header(name, value);
+    }
+    if let Some(client) = request.remote_addr() {
+        let ip = client.ip().to_string();
+        let value = match forwarded_for {
+            Some(existing) => format!("{existing}, {ip}"),
+            None => ip,
+        };
+        backend = backend.header("X-Forwarded-For", value);
+    }
+
+    let response = match backend.send() {
+        Ok(response) => response,
+        Err(e) => {
+            log!("proxy"; "{} -> {}: {}", request.url(), proxy.target, e);
+            let message = format!("Bad Gateway: {} is unreachable", proxy.target);
+            let reply = Reply::new(502, Body::Bytes(message.into_bytes()))
+                .with_header("Content-Type", crate::utils::mime::types::PLAIN);
+            return request.respond(reply);
+        }
+    };
+
+    let status = response.status().as_u16();
+    let headers: Vec<(String, String)> = response
+        .headers()
+        .iter()
+        .filter(|(name, _)| !is_hop_by_hop(name.as_str()) && *name != "content-length")
+        .map(|(name, value)| {
+            let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+            (name.to_string(), value)
+        })
+        .collect();
+    let length = response.content_length();
+
+    let mut reply = Reply::new(status, Body::Reader(Box::new(response), length));
+    reply.headers = headers;
+    request.respond(reply)
+}
+
+/// Warn about proxy targets that refuse connections (runs in the background)
+pub fn check_targets(config: &SiteConfig) {
+    let targets: Vec<String> = config
+        .serve
+        .proxy
+        .iter()
+        .map(|p| p.target.clone())
+        .collect();
+    if targets.is_empty() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        for target in targets {
+            if !is_reachable(&target) {
+                log!("proxy"; "warning: {} is unreachable", target);
+            }
+        }
+    });
+}
+
+fn is_reachable(target: &str) -> bool {
+    let Ok(url) = url::Url::parse(target) else {
+        return false;
+    };
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return false;
+    };
+    (host, port)
+        .to_socket_addrs()
+        .map(|mut addrs| addrs.any(|addr| TcpStream::connect_timeout(&addr, CHECK_TIMEOUT).is_ok()))
+        .unwrap_or(false)
+}
+
+fn is_hop_by_hop(name: &str) -> bool {
+    HOP_BY_HOP.iter().any(|h| h.eq_ignore_ascii_case(name))
+}
+
+fn is_websocket_upgrade(request: &impl Exchange) -> bool {
+    request
+        .header("upgrade")
+        .is_some_and(|value| value.eq_ignore_ascii_case("websocket"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hop_by_hop() {
+        assert!(is_hop_by_hop("Connection"));
+        assert!(is_hop_by_hop("transfer-encoding"));
+        assert!(!is_hop_by_hop("content-type"));
+        assert!(!is_hop_by_hop("set-cookie"));
+    }
+
+    #[test]
+    fn test_unreachable_target() {
+        assert!(!is_reachable("not a url"));
+        // Port 1 (tcpmux) is essentially never listening locally
+        assert!(!is_reachable("http://127.0.0.1:1"));
+    }
+}
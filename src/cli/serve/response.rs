@@ -1,37 +1,42 @@
 //! HTTP response handlers.
 
+//!
+//! Handlers build a [`Reply`] and send it through an [`Exchange`], so the
+//! HTTP/1.1 and HTTP/2 listeners share them.
+
 use super::content::maybe_inject_hotreload;
+use super::exchange::{Body, Exchange, Reply};
 use crate::config::SiteConfig;
+use crate::config::section::ServeConfig;
 use anyhow::{Context, Result};
 use std::{fs, path::Path};
-use tiny_http::{Header, Method, Request, Response, StatusCode};
 
 /// Result of attempting to serve a file from disk.
 ///
 /// `Missing` indicates the file disappeared before read and may be recovered
 /// by recompiling from source.
-pub enum FileServeResult {
+pub enum FileServeResult<E> {
     Served,
-    Missing(Request),
+    Missing(E),
 }
 
 /// Respond with a static file, optionally injecting hotreload script
-pub fn respond_file(
-    request: Request,
+pub fn respond_file<E: Exchange>(
+    request: E,
     path: &Path,
     path_prefix: &Path,
     ws_port: Option<u16>,
-) -> Result<FileServeResult> {
+) -> Result<FileServeResult<E>> {
     let content_type = crate::utils::mime::from_path(path);
     let no_cache = content_type == crate::utils::mime::types::HTML;
 
-    if is_head_request(&request) {
+    if request.is_head() {
         send_head(request, 200, content_type, no_cache)?;
         return Ok(FileServeResult::Served);
     }
 
     // Check for Range header (video/audio seeking)
-    if let Some(range) = get_range_header(&request) {
+    if let Some(range) = request.header("Range") {
         respond_range(request, path, content_type, &range)?;
         return Ok(FileServeResult::Served);
     }
@@ -52,8 +57,8 @@ pub fn respond_file(
 }
 
 /// Handle Range request for media files (video/audio seeking)
-fn respond_range(
-    request: Request,
+fn respond_range<E: Exchange>(
+    request: E,
     path: &Path,
     content_type: &'static str,
     range: &str,
@@ -75,20 +80,12 @@ fn respond_range(
 
     // Build 206 Partial Content response with streaming reader
     let content_range = format!("bytes {}-{}/{}", start, end, file_size);
-    let response = Response::new(
-        StatusCode(206),
-        vec![
-            Header::from_bytes("Content-Type", content_type).unwrap(),
-            Header::from_bytes("Content-Range", content_range.as_bytes()).unwrap(),
-            Header::from_bytes("Accept-Ranges", "bytes").unwrap(),
-        ],
-        reader,
-        Some(length as usize),
-        None,
-    );
-
-    request.respond(response)?;
-    Ok(())
+    let reply = Reply::new(206, Body::Reader(Box::new(reader), Some(length)))
+        .with_header("Content-Type", content_type)
+        .with_header("Content-Range", content_range)
+        .with_header("Accept-Ranges", "bytes");
+
+    respond(request, reply, content_type)
 }
 
 /// Parse Range header value "start-end" into (start, end) bytes
@@ -120,21 +117,12 @@ fn parse_range(range: &str, file_size: u64) -> Result<(u64, u64)> {
     Ok((start, end))
 }
 
-/// Extract Range header from request
-fn get_range_header(request: &Request) -> Option<String> {
-    request
-        .headers()
-        .iter()
-        .find(|h| h.field.as_str().as_str().eq_ignore_ascii_case("range"))
-        .map(|h| h.value.to_string())
-}
-
 /// Respond with 404 page (custom or default)
 ///
 /// For HTML 404 pages, reads directly from source for hot reload support
 /// For compiled 404 pages (typst), reads from output directory
-pub fn respond_not_found(
-    request: Request,
+pub fn respond_not_found<E: Exchange>(
+    request: E,
     config: &SiteConfig,
     ws_port: Option<u16>,
 ) -> Result<()> {
@@ -159,7 +147,7 @@ pub fn respond_not_found(
         (None, false)
     };
 
-    if is_head_request(&request) {
+    if request.is_head() {
         let mime = if found { HTML } else { PLAIN };
         return send_head(request, 404, mime, false);
     }
@@ -173,15 +161,13 @@ pub fn respond_not_found(
 }
 
 /// Respond with 503 + auto-retry (build not ready yet)
-pub fn respond_loading(request: Request) -> Result<()> {
+pub fn respond_loading<E: Exchange>(request: E) -> Result<()> {
     use crate::utils::mime::types::HTML;
 
     // HEAD requests are used by polling logic; keep response lightweight.
-    if is_head_request(&request) {
-        let response =
-            Response::empty(StatusCode(503)).with_header(make_header("Content-Type", HTML));
-        request.respond(response)?;
-        return Ok(());
+    if request.is_head() {
+        let reply = Reply::empty(503).with_header("Content-Type", HTML);
+        return respond(request, reply, HTML);
     }
 
     // Keep a stable loading page and poll readiness via HEAD.
@@ -206,15 +192,13 @@ pub fn respond_loading(request: Request) -> Result<()> {
 </script>
 </body></html>"#;
 
-    let response = Response::from_string(body)
-        .with_status_code(StatusCode(503))
-        .with_header(make_header("Content-Type", HTML));
-    request.respond(response)?;
-    Ok(())
+    let reply =
+        Reply::new(503, Body::Bytes(body.as_bytes().to_vec())).with_header("Content-Type", HTML);
+    respond(request, reply, HTML)
 }
 
 /// Respond with 503 Service Unavailable (server shutting down)
-pub fn respond_unavailable(request: Request) -> Result<()> {
+pub fn respond_unavailable<E: Exchange>(request: E) -> Result<()> {
     use crate::utils::mime::types::PLAIN;
     send_body(
         request,
@@ -229,15 +213,14 @@ pub fn respond_unavailable(request: Request) -> Result<()> {
 ///
 /// Includes a polling script that auto-refreshes when content is created
 /// Note: HEAD requests return without X-Tola-Ready to prevent infinite refresh loop
-pub fn respond_welcome(request: Request) -> Result<()> {
+pub fn respond_welcome<E: Exchange>(request: E) -> Result<()> {
     use crate::embed::serve::{WELCOME_HTML, WelcomeVars};
     use crate::utils::mime::types::HTML;
 
     // HEAD request: return without X-Tola-Ready (polling checks this header)
-    if is_head_request(&request) {
-        let response =
-            Response::empty(StatusCode(200)).with_header(make_header("Content-Type", HTML));
-        return request.respond(response).map_err(Into::into);
+    if request.is_head() {
+        let reply = Reply::empty(200).with_header("Content-Type", HTML);
+        return respond(request, reply, HTML);
     }
 
     let body = WELCOME_HTML.render(&WelcomeVars {
@@ -267,30 +250,25 @@ pub fn respond_welcome(request: Request) -> Result<()> {
     send_html(request, body)
 }
 
-fn is_head_request(request: &Request) -> bool {
-    request.method() == &Method::Head
-}
-
-fn send_head(
-    request: Request,
+fn send_head<E: Exchange>(
+    request: E,
     status: u16,
     content_type: &'static str,
     no_cache: bool,
 ) -> Result<()> {
-    let response = Response::empty(StatusCode(status))
-        .with_header(make_header("Content-Type", content_type))
-        .with_header(make_header("X-Tola-Ready", "true"));
-    let response = if no_cache {
-        with_no_cache_headers(response)
+    let reply = Reply::empty(status)
+        .with_header("Content-Type", content_type)
+        .with_header("X-Tola-Ready", "true");
+    let reply = if no_cache {
+        with_no_cache_headers(reply)
     } else {
-        response
+        reply
     };
-    request.respond(response)?;
-    Ok(())
+    respond(request, reply, content_type)
 }
 
-fn send_body(
-    request: Request,
+fn send_body<E: Exchange>(
+    request: E,
     status: u16,
     content_type: &'static str,
     body: Vec<u8>,
@@ -302,30 +280,28 @@ fn send_body(
         body
     };
 
-    let response = Response::from_data(body)
-        .with_status_code(StatusCode(status))
-        .with_header(make_header("Content-Type", content_type))
-        .with_header(make_header("X-Tola-Ready", "true"));
-    let response = if no_cache {
-        with_no_cache_headers(response)
+    let reply = Reply::new(status, Body::Bytes(body))
+        .with_header("Content-Type", content_type)
+        .with_header("X-Tola-Ready", "true");
+    let reply = if no_cache {
+        with_no_cache_headers(reply)
     } else {
-        response
+        reply
     };
-    request.respond(response)?;
-    Ok(())
+    respond(request, reply, content_type)
 }
+
 /// Send HTML without X-Tola-Ready (for welcome pages)
-fn send_html(request: Request, body: String) -> Result<()> {
+fn send_html<E: Exchange>(request: E, body: String) -> Result<()> {
     use crate::utils::mime::types::HTML;
-    let response = Response::from_string(crate::utils::html::ensure_doctype(body))
-        .with_header(make_header("Content-Type", HTML));
-    request.respond(response)?;
-    Ok(())
+    let body = crate::utils::html::ensure_doctype(body).into_bytes();
+    let reply = Reply::new(200, Body::Bytes(body)).with_header("Content-Type", HTML);
+    respond(request, reply, HTML)
 }
 
 /// Respond with compilation error (500), with hotreload for auto-refresh
-pub fn respond_compile_error(
-    request: Request,
+pub fn respond_compile_error<E: Exchange>(
+    request: E,
     error: &anyhow::Error,
     path_prefix: &Path,
     ws_port: Option<u16>,
@@ -340,31 +316,110 @@ pub fn respond_compile_error(
 }
 
 /// Respond with hotreload.js from memory
-pub fn respond_hotreload_js(request: Request, ws_port: u16) -> Result<()> {
+pub fn respond_hotreload_js<E: Exchange>(request: E, ws_port: u16) -> Result<()> {
     use crate::embed::serve::{HOTRELOAD_JS, HotreloadVars};
     use crate::utils::mime::types::JAVASCRIPT;
 
-    let vars = HotreloadVars { ws_port };
+    let vars = HotreloadVars {
+        ws_port,
+        ws_scheme: super::ws_scheme(),
+    };
     let body = HOTRELOAD_JS.render(&vars);
     send_body(request, 200, JAVASCRIPT, body.into_bytes(), false)
 }
 
-fn make_header(key: &'static str, value: &'static str) -> Header {
-    Header::from_bytes(key, value).unwrap()
+/// Send `reply`, adding `[[serve.middleware]]` headers that match the request path
+/// and a `Cache-Control` header from `[[serve.headers]]` if none is set yet
+fn respond<E: Exchange>(request: E, reply: Reply, content_type: &str) -> Result<()> {
+    let config = crate::config::config_handle().current();
+    let reply = with_middleware_headers(reply, &config.serve, request.url(), content_type);
+    let reply = with_cache_control(reply, &config.serve, request.url());
+    request.respond(reply)
+}
+
+fn with_cache_control(reply: Reply, serve: &ServeConfig, url: &str) -> Reply {
+    if reply.has_header("Cache-Control") {
+        return reply;
+    }
+    reply.with_header("Cache-Control", serve.cache_control(url))
+}
+
+fn with_middleware_headers(
+    mut reply: Reply,
+    serve: &ServeConfig,
+    url: &str,
+    content_type: &str,
+) -> Reply {
+    // Names and values are checked at config load
+    reply
+        .headers
+        .extend(serve.middleware_headers(url, content_type));
+    reply
 }
 
-fn with_no_cache_headers<R: std::io::Read>(response: Response<R>) -> Response<R> {
-    response
-        .with_header(make_header(
+fn with_no_cache_headers(reply: Reply) -> Reply {
+    reply
+        .with_header(
             "Cache-Control",
             "no-store, no-cache, must-revalidate, max-age=0",
-        ))
-        .with_header(make_header("Pragma", "no-cache"))
-        .with_header(make_header("Expires", "0"))
+        )
+        .with_header("Pragma", "no-cache")
+        .with_header("Expires", "0")
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_matching_middleware_headers() {
+        let serve: ServeConfig = toml::from_str(
+            r#"
+[[middleware]]
+path_pattern = "/assets/**"
+headers = { "X-Content-Kind" = "%{content_type}" }
+"#,
+        )
+        .unwrap();
+
+        let header = |url: &str| {
+            let reply = with_middleware_headers(Reply::empty(200), &serve, url, "text/css");
+            reply
+                .headers
+                .into_iter()
+                .find(|(name, _)| name == "X-Content-Kind")
+                .map(|(_, value)| value)
+        };
+        assert_eq!(header("/assets/site.css").as_deref(), Some("text/css"));
+        assert_eq!(header("/index.html"), None);
+    }
+
+    #[test]
+    fn adds_cache_control_unless_set() {
+        let serve = ServeConfig::default();
+        let cache_control = |reply: Reply, url: &str| {
+            with_cache_control(reply, &serve, url)
+                .headers
+                .into_iter()
+                .filter(|(name, _)| name == "Cache-Control")
+                .map(|(_, value)| value)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            cache_control(Reply::empty(200), "/.tola/spa-1a2b.js"),
+            ["public, max-age=31536000, immutable"]
+        );
+        assert_eq!(
+            cache_control(Reply::empty(200), "/assets/site.css"),
+            ["no-cache"]
+        );
+        let html = with_no_cache_headers(Reply::empty(200));
+        assert_eq!(
+            cache_control(html, "/index.html"),
+            ["no-store, no-cache, must-revalidate, max-age=0"]
+        );
+    }
 
     #[test]
     fn prepends_doctype_to_html_bytes() {
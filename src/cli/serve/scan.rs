@@ -6,7 +6,7 @@
 use anyhow::Result;
 
 use crate::address::SiteIndex;
-use crate::compiler::page::{TypstHost, build_address_space, collect_content_files};
+use crate::compiler::page::{TypstHost, build_address_space, collect_site_content_files};
 use crate::config::SiteConfig;
 use crate::core::ContentKind;
 use crate::page::CompiledPage;
@@ -23,7 +23,7 @@ use crate::page::CompiledPage;
 pub fn scan_pages(config: &SiteConfig, host: &TypstHost, state: &SiteIndex) -> Result<()> {
     let next = SiteIndex::new();
 
-    let content_files = collect_content_files(&config.build.content);
+    let content_files = collect_site_content_files(config);
     let (typst_files, markdown_files) = ContentKind::partition_by_kind(&content_files);
 
     let scan_result =
@@ -16,7 +16,7 @@ use crate::compiler::dependency::{self, collect_virtual_dependents};
 use crate::compiler::page::{BUILD_CACHE, TypstHost, cache_vdom};
 use crate::compiler::scheduler::SCHEDULER;
 use crate::config::{self, SiteConfig};
-use crate::core::UrlPath;
+use crate::core::{BuildMode, UrlPath};
 use crate::page::PageState;
 use crate::reload::compile::{self, CompileOutcome};
 use crate::{debug, log, logger};
@@ -31,6 +31,7 @@ const STARTUP_POLL_INTERVAL: Duration = Duration::from_millis(50);
 pub fn serve_with_cache(config: &SiteConfig) -> Result<()> {
     use crate::core::{set_healthy, set_serving};
     let state = Arc::new(SiteIndex::new());
+    crate::package::init_build_info(BuildMode::DEVELOPMENT, None, config.get_root());
 
     if config.build.clean
         && let Err(e) = cache::clear_cache_dir(config.get_root())
@@ -146,7 +147,8 @@ fn startup_with_cache(config: &SiteConfig, state: &SiteIndex) -> bool {
     set_scan_ready(true);
 
     let root = config.get_root();
-    let mut diagnostics = cache::restore_diagnostics(root).unwrap_or_default();
+    let mut diagnostics =
+        cache::restore_diagnostics(root, cache::DIAGNOSTICS_MAX_AGE_SECS).unwrap_or_default();
     let mut files_to_compile = FxHashSet::default();
     let mut error_files = 0usize;
     let mut stale_diagnostics = Vec::new();
@@ -164,15 +166,16 @@ fn startup_with_cache(config: &SiteConfig, state: &SiteIndex) -> bool {
         diagnostics.clear_for(&path);
     }
 
-    let modified = cache::get_modified_files(root, &config.build.content);
+    let modified = cache::get_modified_files(root, config.build.content_dirs());
 
     debug!(
         "startup";
-        "offline changes: errors={}, created={}, removed={}, modified={}",
+        "offline changes: errors={}, created={}, removed={}, modified={}, deps={}",
         error_files,
         modified.created.len(),
         modified.removed.len(),
-        modified.modified.len()
+        modified.modified.len(),
+        modified.changed_deps.len()
     );
 
     cleanup_removed_files(&modified.removed, config, state, &mut diagnostics);
@@ -180,6 +183,11 @@ fn startup_with_cache(config: &SiteConfig, state: &SiteIndex) -> bool {
     for path in modified.created {
         files_to_compile.insert(path);
     }
+    // Pages importing a changed template or page, directly or transitively
+    for changed in modified.changed_deps.iter().chain(&modified.modified) {
+        let dependents = cache::get_dependents(changed, root);
+        files_to_compile.extend(dependents.into_iter().filter(|path| path.exists()));
+    }
     for path in modified.modified {
         files_to_compile.insert(path);
     }
@@ -319,6 +327,7 @@ fn relative_source_path(config: &SiteConfig, path: &Path) -> String {
         .to_string()
 }
 
+#[allow(clippy::too_many_arguments)]
 fn handle_startup_vdom_outcome(
     path: PathBuf,
     url_path: UrlPath,
@@ -384,7 +393,7 @@ fn compile_startup_batch(
 
         let outcomes = compile::compile_startup_batch(path_chunk, config, typst_host, state);
 
-        for (input_path, outcome) in path_chunk.iter().zip(outcomes.into_iter()) {
+        for (input_path, outcome) in path_chunk.iter().zip(outcomes) {
             let rel_input = input_path
                 .strip_prefix(config.get_root())
                 .unwrap_or(input_path)
@@ -0,0 +1,139 @@
+//! HTTPS for the development server (`[serve.tls]`).
+//!
+//! Uses the configured `cert`/`key` when set, otherwise a self-signed
+//! certificate for `localhost` generated on first run and cached in
+//! `.tola/cache/tls/`. The same identity secures the hot reload WebSocket.
+
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result, anyhow};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject};
+
+use crate::cache::CACHE_DIR;
+use crate::config::SiteConfig;
+use crate::log;
+
+/// Cached self-signed certificate directory (inside `CACHE_DIR`)
+const TLS_CACHE_DIR: &str = "tls";
+const CERT_FILE: &str = "cert.pem";
+const KEY_FILE: &str = "key.pem";
+
+/// Names the self-signed certificate is valid for
+const SELF_SIGNED_NAMES: &[&str] = &["localhost", "127.0.0.1", "::1"];
+
+/// PEM-encoded certificate chain and private key
+pub struct TlsIdentity {
+    cert_pem: Vec<u8>,
+    key_pem: Vec<u8>,
+}
+
+impl TlsIdentity {
+    /// Load the identity for `serve.tls`, or `None` when TLS is disabled.
+    pub fn load(config: &SiteConfig) -> Result<Option<Self>> {
+        let tls = &config.serve.tls;
+        if !tls.enable {
+            return Ok(None);
+        }
+
+        let identity = match (&tls.cert, &tls.key) {
+            (Some(cert), Some(key)) => Self::read(cert, key)?,
+            _ => Self::self_signed(config.get_root())?,
+        };
+        Ok(Some(identity))
+    }
+
+    /// Read an existing certificate/key pair.
+    fn read(cert: &Path, key: &Path) -> Result<Self> {
+        let cert_pem = fs::read(cert)
+            .with_context(|| format!("Failed to read TLS certificate: {}", cert.display()))?;
+        let key_pem =
+            fs::read(key).with_context(|| format!("Failed to read TLS key: {}", key.display()))?;
+        Ok(Self { cert_pem, key_pem })
+    }
+
+    /// Reuse the cached self-signed certificate, generating it on first run.
+    fn self_signed(root: &Path) -> Result<Self> {
+        let dir = root.join(CACHE_DIR).join(TLS_CACHE_DIR);
+        let (cert, key) = (dir.join(CERT_FILE), dir.join(KEY_FILE));
+        if cert.is_file() && key.is_file() {
+            return Self::read(&cert, &key);
+        }
+
+        let identity = Self::generate()?;
+        fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        write_file(&cert, &identity.cert_pem)?;
+        write_file(&key, &identity.key_pem)?;
+
+        log!("tls"; "generated self-signed certificate in {}", dir.display());
+        Ok(identity)
+    }
+
+    fn generate() -> Result<Self> {
+        let names = SELF_SIGNED_NAMES
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        let rcgen::CertifiedKey { cert, key_pair } = rcgen::generate_simple_self_signed(names)
+            .context("Failed to generate self-signed certificate")?;
+        Ok(Self {
+            cert_pem: cert.pem().into_bytes(),
+            key_pem: key_pair.serialize_pem().into_bytes(),
+        })
+    }
+
+    /// Configuration for the HTTPS listener.
+    pub fn ssl_config(&self) -> tiny_http::SslConfig {
+        tiny_http::SslConfig {
+            certificate: self.cert_pem.clone(),
+            private_key: self.key_pem.clone(),
+        }
+    }
+
+    /// Configuration for the `wss://` hot reload listener.
+    pub fn server_config(&self) -> Result<Arc<rustls::ServerConfig>> {
+        let certs = CertificateDer::pem_slice_iter(&self.cert_pem)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("Invalid TLS certificate: {e:?}"))?;
+        let key = PrivateKeyDer::from_pem_slice(&self.key_pem)
+            .map_err(|e| anyhow!("Invalid TLS key: {e:?}"))?;
+
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let config = rustls::ServerConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()?
+            .with_no_client_auth()
+            .with_single_cert(certs, key)
+            .context("TLS certificate does not match key")?;
+        Ok(Arc::new(config))
+    }
+}
+
+fn write_file(path: &Path, contents: &[u8]) -> Result<()> {
+    fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_self_signed_is_cached() {
+        let dir = TempDir::new().unwrap();
+
+        let first = TlsIdentity::self_signed(dir.path()).unwrap();
+        assert!(first.cert_pem.starts_with(b"-----BEGIN CERTIFICATE-----"));
+        assert!(first.server_config().is_ok());
+
+        let second = TlsIdentity::self_signed(dir.path()).unwrap();
+        assert_eq!(first.cert_pem, second.cert_pem);
+        assert_eq!(first.key_pem, second.key_pem);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let config = SiteConfig::default();
+        assert!(TlsIdentity::load(&config).unwrap().is_none());
+    }
+}
@@ -0,0 +1,383 @@
+//! Stats command - summarize site content without building.
+//!
+//! Everything is derived from files on disk: sources under `build.content`,
+//! asset directories, and whatever is already in `build.output`. Word counts
+//! come from scanning each page's text, as `tola query --stats` does. Cache
+//! figures are only reported when a persisted cache exists.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use crate::cache::{self, CACHE_DIR};
+use crate::cli::clean::{dir_size, format_bytes};
+use crate::compiler::page::TypstHost;
+use crate::compiler::{CompileContext, collect_all_files};
+use crate::config::SiteConfig;
+use crate::core::{BuildMode, ContentKind};
+use crate::page::StoredPageMap;
+use crate::page::reading::count_words;
+use crate::utils::plural_count;
+
+/// Number of largest pages listed
+const LARGEST_PAGES: usize = 10;
+
+/// Upper bounds (exclusive) of word count histogram buckets
+const WORD_BUCKETS: &[usize] = &[100, 500, 1000, 2000, 5000];
+
+/// Collected site statistics
+#[derive(Debug, Default, Serialize)]
+pub struct SiteStats {
+    pub pages: PageCounts,
+    pub assets: FileSizes,
+    /// `None` when the output directory has not been built yet
+    pub output: Option<OutputStats>,
+    /// Page count per content subdirectory (`.` for the content root)
+    pub directories: BTreeMap<String, usize>,
+    pub words: WordStats,
+    /// `None` when no persisted cache exists
+    pub cache: Option<CacheStats>,
+}
+
+/// Page counts by source format
+#[derive(Debug, Default, Serialize)]
+pub struct PageCounts {
+    pub total: usize,
+    pub typst: usize,
+    pub markdown: usize,
+}
+
+/// File count and total size
+#[derive(Debug, Default, Serialize)]
+pub struct FileSizes {
+    pub count: usize,
+    pub bytes: u64,
+}
+
+/// Output directory statistics
+#[derive(Debug, Default, Serialize)]
+pub struct OutputStats {
+    pub files: FileSizes,
+    /// Largest HTML files, descending by size
+    pub largest_pages: Vec<PageSize>,
+}
+
+/// Compiled page size
+#[derive(Debug, Serialize)]
+pub struct PageSize {
+    pub path: String,
+    pub bytes: u64,
+}
+
+/// Word count totals and histogram
+#[derive(Debug, Default, Serialize)]
+pub struct WordStats {
+    pub total: usize,
+    pub histogram: Vec<WordBucket>,
+}
+
+/// Pages whose word count falls in `label`
+#[derive(Debug, Serialize)]
+pub struct WordBucket {
+    pub label: String,
+    pub pages: usize,
+}
+
+/// Persisted cache statistics
+#[derive(Debug, Default, Serialize)]
+pub struct CacheStats {
+    /// Pages whose cached output is still valid
+    pub reusable: usize,
+    /// `reusable / pages.total`
+    pub hit_rate: f64,
+    pub bytes: u64,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+/// Run the stats command
+pub fn run_stats(config: &SiteConfig, json: bool) -> Result<()> {
+    let stats = collect_stats(config);
+    if json {
+        crate::logger::json::emit(&stats);
+    } else {
+        print_stats(&stats);
+    }
+    Ok(())
+}
+
+/// Gather statistics from source, asset, output and cache directories
+pub fn collect_stats(config: &SiteConfig) -> SiteStats {
+    let mut stats = SiteStats::default();
+    let mut word_counts = Vec::new();
+
+    // Populate page data so `pages()` listings count like they render
+    let host = TypstHost::for_config(config);
+    let store = StoredPageMap::new();
+    if let Err(e) = crate::cli::common::populate_stored_pages(config, &host, &store) {
+        crate::debug!("stats"; "failed to scan page metadata: {}", e);
+    }
+    let ctx = CompileContext::new(BuildMode::PRODUCTION, config, &host, &store);
+
+    let content_files: Vec<_> = config
+        .build
+        .content_dirs()
+        .filter(|dir| dir.exists())
+        .flat_map(collect_all_files)
+        .collect();
+
+    for path in &content_files {
+        let Some(kind) = ContentKind::from_path(path) else {
+            // Colocated files next to pages are copied as assets
+            add_file(&mut stats.assets, path);
+            continue;
+        };
+
+        stats.pages.total += 1;
+        match kind {
+            ContentKind::Typst => stats.pages.typst += 1,
+            ContentKind::Markdown => stats.pages.markdown += 1,
+        }
+
+        let dir = path
+            .parent()
+            .zip(config.build.content_root(path))
+            .and_then(|(p, content)| p.strip_prefix(content).ok())
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .filter(|p| !p.is_empty())
+            .unwrap_or_else(|| ".".to_string());
+        *stats.directories.entry(dir).or_default() += 1;
+
+        match crate::compiler::page::scan_text(path, &ctx) {
+            Ok(text) => word_counts.push(count_words(&text)),
+            Err(e) => {
+                crate::debug!("stats"; "failed to count words for {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    let assets = &config.build.assets;
+    for source in assets.nested_sources().chain(assets.flatten_sources()) {
+        if source.is_dir() {
+            collect_all_files(source)
+                .iter()
+                .for_each(|path| add_file(&mut stats.assets, path));
+        } else if source.is_file() {
+            add_file(&mut stats.assets, source);
+        }
+    }
+
+    stats.words = word_stats(&word_counts);
+    stats.output = output_stats(&config.build.output);
+    stats.cache = cache_stats(config.get_root(), stats.pages.total);
+    stats
+}
+
+fn add_file(sizes: &mut FileSizes, path: &Path) {
+    sizes.count += 1;
+    sizes.bytes += fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+}
+
+fn word_stats(counts: &[usize]) -> WordStats {
+    let mut histogram: Vec<WordBucket> = Vec::with_capacity(WORD_BUCKETS.len() + 1);
+    let mut lower = 0;
+    for &upper in WORD_BUCKETS {
+        histogram.push(WordBucket {
+            label: format!("{lower}-{}", upper - 1),
+            pages: counts.iter().filter(|&&c| c >= lower && c < upper).count(),
+        });
+        lower = upper;
+    }
+    histogram.push(WordBucket {
+        label: format!("{lower}+"),
+        pages: counts.iter().filter(|&&c| c >= lower).count(),
+    });
+
+    WordStats {
+        total: counts.iter().sum(),
+        histogram,
+    }
+}
+
+fn output_stats(output: &Path) -> Option<OutputStats> {
+    if !output.is_dir() {
+        return None;
+    }
+
+    let mut stats = OutputStats::default();
+    let mut pages = Vec::new();
+    for path in collect_all_files(output) {
+        let bytes = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        stats.files.count += 1;
+        stats.files.bytes += bytes;
+        if path.extension().is_some_and(|ext| ext == "html") {
+            let rel = path.strip_prefix(output).unwrap_or(&path);
+            pages.push(PageSize {
+                path: rel.to_string_lossy().replace('\\', "/"),
+                bytes,
+            });
+        }
+    }
+
+    pages.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.path.cmp(&b.path)));
+    pages.truncate(LARGEST_PAGES);
+    stats.largest_pages = pages;
+    Some(stats)
+}
+
+fn cache_stats(root: &Path, total_pages: usize) -> Option<CacheStats> {
+    if !cache::has_cache(root) {
+        return None;
+    }
+
+    let reusable = cache::get_unchanged_files(root).len();
+    let diagnostics = cache::restore_diagnostics(root, u64::MAX).unwrap_or_default();
+    #[allow(clippy::cast_precision_loss)] // Display only
+    let hit_rate = if total_pages == 0 {
+        0.0
+    } else {
+        reusable.min(total_pages) as f64 / total_pages as f64
+    };
+
+    Some(CacheStats {
+        reusable,
+        hit_rate,
+        bytes: dir_size(&root.join(CACHE_DIR)),
+        errors: diagnostics.error_count(),
+        warnings: diagnostics.warning_count(),
+    })
+}
+
+fn print_stats(stats: &SiteStats) {
+    let pages = &stats.pages;
+    println!("{}", "pages".bold());
+    println!(
+        "  {} ({} typst, {} markdown)",
+        plural_count(pages.total, "page"),
+        pages.typst,
+        pages.markdown
+    );
+    for (dir, count) in &stats.directories {
+        println!("  {:<30} {}", dir.cyan(), count);
+    }
+
+    println!("\n{}", "words".bold());
+    println!("  {} total", stats.words.total);
+    for bucket in &stats.words.histogram {
+        println!("  {:<12} {}", bucket.label, bucket.pages);
+    }
+
+    println!("\n{}", "assets".bold());
+    println!(
+        "  {} ({})",
+        plural_count(stats.assets.count, "file"),
+        format_bytes(stats.assets.bytes)
+    );
+
+    println!("\n{}", "output".bold());
+    match &stats.output {
+        Some(output) => {
+            println!(
+                "  {} ({})",
+                plural_count(output.files.count, "file"),
+                format_bytes(output.files.bytes)
+            );
+            for page in &output.largest_pages {
+                println!("  {:<50} {}", page.path.cyan(), format_bytes(page.bytes));
+            }
+        }
+        None => println!("  {}", "not built yet".dimmed()),
+    }
+
+    println!("\n{}", "cache".bold());
+    match &stats.cache {
+        Some(cache) => {
+            println!(
+                "  {:.0}% reusable ({} of {}), {}",
+                cache.hit_rate * 100.0,
+                cache.reusable,
+                plural_count(pages.total, "page"),
+                format_bytes(cache.bytes)
+            );
+            println!(
+                "  {}, {}",
+                plural_count(cache.errors, "error"),
+                plural_count(cache.warnings, "warning")
+            );
+        }
+        None => println!("  {}", "no cache".dimmed()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn make_config(temp: &TempDir) -> SiteConfig {
+        let mut config = SiteConfig::default();
+        config.set_root(temp.path());
+        config.build.content = temp.path().join("content");
+        config.build.output = temp.path().join("public");
+        config
+    }
+
+    #[test]
+    fn test_word_histogram() {
+        let words = word_stats(&[0, 50, 150, 6000]);
+        assert_eq!(words.total, 6200);
+        assert_eq!(words.histogram[0].label, "0-99");
+        assert_eq!(words.histogram[0].pages, 2);
+        assert_eq!(words.histogram[1].pages, 1);
+        assert_eq!(words.histogram.last().unwrap().label, "5000+");
+        assert_eq!(words.histogram.last().unwrap().pages, 1);
+    }
+
+    #[test]
+    fn test_collect_stats_without_output() {
+        let temp = TempDir::new().unwrap();
+        let config = make_config(&temp);
+        let content = temp.path().join("content");
+        fs::create_dir_all(content.join("posts")).unwrap();
+        fs::write(content.join("index.typ"), "= Home").unwrap();
+        fs::write(content.join("posts/a.md"), "Hello there").unwrap();
+        fs::write(content.join("posts/b.typ"), "= B").unwrap();
+        fs::write(content.join("posts/cover.png"), [0u8; 16]).unwrap();
+
+        let stats = collect_stats(&config);
+
+        assert_eq!(stats.pages.total, 3);
+        assert_eq!(stats.pages.typst, 2);
+        assert_eq!(stats.pages.markdown, 1);
+        assert_eq!(stats.directories.get("."), Some(&1));
+        assert_eq!(stats.directories.get("posts"), Some(&2));
+        assert_eq!(stats.assets.count, 1);
+        assert_eq!(stats.assets.bytes, 16);
+        // "Home" + "Hello there" + "B", counted from page text
+        assert_eq!(stats.words.total, 4);
+        assert!(stats.output.is_none());
+        assert!(stats.cache.is_none());
+    }
+
+    #[test]
+    fn test_largest_pages() {
+        let temp = TempDir::new().unwrap();
+        let config = make_config(&temp);
+        let output = temp.path().join("public");
+        fs::create_dir_all(output.join("posts")).unwrap();
+        fs::write(output.join("index.html"), "x".repeat(10)).unwrap();
+        fs::write(output.join("posts/index.html"), "x".repeat(30)).unwrap();
+        fs::write(output.join("style.css"), "x".repeat(50)).unwrap();
+
+        let output = collect_stats(&config).output.unwrap();
+
+        assert_eq!(output.files.count, 3);
+        assert_eq!(output.files.bytes, 90);
+        assert_eq!(output.largest_pages.len(), 2);
+        assert_eq!(output.largest_pages[0].path, "posts/index.html");
+    }
+}
@@ -0,0 +1,218 @@
+//! Accessibility audit of built pages.
+//!
+//! Reads each page's HTML from the output directory (written by the last
+//! `tola build`) and checks the rules enabled in `[validate.accessibility]`:
+//!
+//! - `images`: `<img>` needs `alt`; `alt=""` or `role="presentation"` /
+//!   `role="none"` marks a decorative image. Non-empty alt text that is just
+//!   the image file name (e.g. `alt="IMG_0042"`) is reported too
+//! - `heading_levels`: a heading may be at most one level deeper than the
+//!   previous one (`<h1>` -> `<h3>` is reported)
+//! - `lang`: `<html>` needs a non-empty `lang` attribute
+
+use std::fs;
+use std::path::Path;
+
+use crate::compiler::page::CompiledPage;
+use crate::config::AccessibilityValidateConfig;
+use crate::log;
+use crate::utils::plural_count;
+
+use super::report::{ValidationError, ValidationReport};
+
+/// Audit the output HTML of every page, adding issues to `report`
+pub(super) fn audit_pages(
+    config: &AccessibilityValidateConfig,
+    root: &Path,
+    all_pages: &[CompiledPage],
+    report: &mut ValidationReport,
+) {
+    let mut missing = 0;
+    for page in all_pages {
+        let Ok(html) = fs::read_to_string(&page.route.output_file) else {
+            missing += 1;
+            continue;
+        };
+
+        let source = page
+            .route
+            .source
+            .strip_prefix(root)
+            .unwrap_or(&page.route.source);
+        for issue in audit_html(&html, config) {
+            report.add_accessibility(source.to_string_lossy().to_string(), issue);
+        }
+    }
+
+    if missing > 0 {
+        log!(
+            "validate";
+            "accessibility: skipped {} without build output (run `tola build` first)",
+            plural_count(missing, "page")
+        );
+    }
+}
+
+/// Check one HTML document against the enabled rules
+fn audit_html(html: &str, config: &AccessibilityValidateConfig) -> Vec<ValidationError> {
+    let Ok(dom) = tl::parse(html, tl::ParserOptions::default()) else {
+        return Vec::new();
+    };
+
+    let mut issues = Vec::new();
+    let mut has_html = false;
+    let mut has_lang = false;
+    let mut last_heading: Option<u8> = None;
+
+    // `nodes()` is in document order
+    for tag in dom.nodes().iter().filter_map(|node| node.as_tag()) {
+        let name = tag.name().as_utf8_str().to_ascii_lowercase();
+        let attrs = tag.attributes();
+        let attr = |key: &str| {
+            attrs
+                .get(key)
+                .map(|v| v.map(|v| v.as_utf8_str().into_owned()))
+        };
+
+        match name.as_str() {
+            "html" => {
+                has_html = true;
+                has_lang = attr("lang")
+                    .flatten()
+                    .is_some_and(|lang| !lang.trim().is_empty());
+            }
+            "img" if config.images => {
+                let decorative = matches!(
+                    attr("role").flatten().as_deref(),
+                    Some("presentation" | "none")
+                );
+                let src = attr("src").flatten().unwrap_or_default();
+                match attr("alt") {
+                    None if !decorative => {
+                        issues.push(ValidationError::new(
+                            format!("<img src=\"{src}\">"),
+                            "missing alt text",
+                        ));
+                    }
+                    Some(Some(alt)) if is_file_name(&alt, &src) => {
+                        issues.push(ValidationError::new(
+                            format!("<img src=\"{src}\">"),
+                            format!("alt text \"{alt}\" is the file name"),
+                        ));
+                    }
+                    _ => {}
+                }
+            }
+            _ if config.heading_levels => {
+                let Some(level) = heading_level(&name) else {
+                    continue;
+                };
+                if let Some(previous) = last_heading
+                    && level > previous + 1
+                {
+                    let text = tag.inner_text(dom.parser());
+                    issues.push(ValidationError::new(
+                        format!("<h{level}> \"{}\"", text.trim()),
+                        format!("skips a level after <h{previous}>"),
+                    ));
+                }
+                last_heading = Some(level);
+            }
+            _ => {}
+        }
+    }
+
+    if config.lang && has_html && !has_lang {
+        issues.push(ValidationError::new("<html>", "missing lang attribute"));
+    }
+    issues
+}
+
+/// Whether `alt` only repeats the file name of `src`, with or without extension
+fn is_file_name(alt: &str, src: &str) -> bool {
+    let alt = alt.trim();
+    let path = src.split(['?', '#']).next().unwrap_or_default();
+    let Some(file_name) = path.rsplit('/').next().filter(|name| !name.is_empty()) else {
+        return false;
+    };
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem);
+
+    !alt.is_empty() && (alt.eq_ignore_ascii_case(file_name) || alt.eq_ignore_ascii_case(stem))
+}
+
+/// Level of an `h1`-`h6` tag name
+fn heading_level(name: &str) -> Option<u8> {
+    match name.as_bytes() {
+        [b'h', level @ b'1'..=b'6'] => Some(level - b'0'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets(html: &str) -> Vec<String> {
+        audit_html(html, &AccessibilityValidateConfig::default())
+            .into_iter()
+            .map(|issue| issue.target)
+            .collect()
+    }
+
+    #[test]
+    fn test_images() {
+        let issues = targets(
+            r#"<html lang="en"><body>
+<img src="a.png">
+<img src="b.png" alt="">
+<img src="c.png" alt="A cat">
+<img src="d.png" role="presentation">
+<img src="/images/IMG_0042.jpg?v=2" alt="img_0042">
+<img src="/images/logo.svg" alt="logo.svg">
+<img src="/images/logo.svg" alt="Tola logo">
+</body></html>"#,
+        );
+        assert_eq!(
+            issues,
+            vec![
+                r#"<img src="a.png">"#,
+                r#"<img src="/images/IMG_0042.jpg?v=2">"#,
+                r#"<img src="/images/logo.svg">"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_headings() {
+        let issues = targets(
+            r#"<html lang="en"><body>
+<h1>Title</h1><h2>Intro</h2><h4>Deep</h4><h2>Next</h2><h3>Sub</h3>
+</body></html>"#,
+        );
+        assert_eq!(issues, vec![r#"<h4> "Deep""#]);
+    }
+
+    #[test]
+    fn test_lang() {
+        assert_eq!(targets("<html><body></body></html>"), vec!["<html>"]);
+        assert_eq!(
+            targets(r#"<html lang=""><body></body></html>"#),
+            vec!["<html>"]
+        );
+        assert!(targets(r#"<html lang="zh"><body></body></html>"#).is_empty());
+    }
+
+    #[test]
+    fn test_disabled_rules() {
+        let config = AccessibilityValidateConfig {
+            images: false,
+            heading_levels: false,
+            lang: false,
+            ..Default::default()
+        };
+        let html = r#"<html><body><h1>A</h1><h3>B</h3><img src="x.png"></body></html>"#;
+        assert!(audit_html(html, &config).is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+//! External link checking over HTTP.
+//!
+//! Unique `http(s)` URLs are checked with `HEAD` (falling back to `GET` when
+//! the server answers 405), bounded by `validate.external.concurrency`.
+//! Results are cached in `.tola/cache/external-links.json` for `cache_ttl` seconds.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use reqwest::{Client, StatusCode, redirect::Policy};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::cache::{
+    ExternalLinkCache, ExternalLinkStatus, persist_external_links, restore_external_links,
+};
+use crate::config::ExternalValidateConfig;
+use crate::log;
+use crate::utils::plural_count;
+
+/// Check external URLs, returning `url -> reason` for broken ones
+pub fn check_external_links(
+    urls: &[String],
+    config: &ExternalValidateConfig,
+    root: &Path,
+) -> Result<BTreeMap<String, String>> {
+    let mut cache = restore_external_links(root).unwrap_or_else(|e| {
+        crate::debug!("validate"; "failed to restore external link cache: {}", e);
+        ExternalLinkCache::default()
+    });
+    cache.prune(config.cache_ttl);
+
+    let (mut results, pending) = partition_cached(urls, &cache, config.cache_ttl);
+
+    if !pending.is_empty() {
+        log!(
+            "validate";
+            "checking {} ({} cached)",
+            plural_count(pending.len(), "external link"),
+            results.len()
+        );
+
+        for (url, error) in fetch_all(pending, config)? {
+            cache.insert(url.clone(), ExternalLinkStatus::now(error.clone()));
+            results.insert(url, error);
+        }
+
+        if let Err(e) = persist_external_links(&cache, root) {
+            crate::debug!("validate"; "failed to persist external link cache: {}", e);
+        }
+    }
+
+    Ok(results
+        .into_iter()
+        .filter_map(|(url, error)| error.map(|reason| (url, reason)))
+        .collect())
+}
+
+/// Split URLs into fresh cached results and URLs that need a request
+fn partition_cached(
+    urls: &[String],
+    cache: &ExternalLinkCache,
+    ttl: u64,
+) -> (BTreeMap<String, Option<String>>, Vec<String>) {
+    let mut cached = BTreeMap::new();
+    let mut pending = Vec::new();
+
+    for url in urls {
+        match cache.get_fresh(url, ttl) {
+            Some(status) => {
+                cached.insert(url.clone(), status.error.clone());
+            }
+            None => pending.push(url.clone()),
+        }
+    }
+
+    (cached, pending)
+}
+
+/// Request all URLs in parallel, bounded by `concurrency`
+fn fetch_all(
+    urls: Vec<String>,
+    config: &ExternalValidateConfig,
+) -> Result<Vec<(String, Option<String>)>> {
+    let client = Client::builder()
+        .timeout(config.timeout())
+        .redirect(Policy::limited(config.max_redirects))
+        .user_agent(concat!("tola/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .context("Failed to create HTTP client")?;
+
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .context("Failed to create tokio runtime")?;
+
+    let permits = Arc::new(Semaphore::new(config.concurrency.max(1)));
+
+    Ok(rt.block_on(async move {
+        let mut tasks = JoinSet::new();
+        for url in urls {
+            let client = client.clone();
+            let permits = Arc::clone(&permits);
+            tasks.spawn(async move {
+                let _permit = permits.acquire_owned().await;
+                let error = check_url(&client, &url).await;
+                (url, error)
+            });
+        }
+
+        let mut results = Vec::with_capacity(tasks.len());
+        while let Some(joined) = tasks.join_next().await {
+            if let Ok(result) = joined {
+                results.push(result);
+            }
+        }
+        results
+    }))
+}
+
+/// Check a single URL, returning the failure reason if broken
+async fn check_url(client: &Client, url: &str) -> Option<String> {
+    // Some servers reject HEAD outright; retry those with GET
+    let response = match client.head(url).send().await {
+        Ok(r) if r.status() == StatusCode::METHOD_NOT_ALLOWED => client.get(url).send().await,
+        other => other,
+    };
+
+    match response {
+        Ok(r) if r.status().is_success() => None,
+        Ok(r) => Some(r.status().to_string()),
+        Err(e) => Some(describe_error(&e)),
+    }
+}
+
+/// Short, stable description of a request error
+fn describe_error(e: &reqwest::Error) -> String {
+    if e.is_timeout() {
+        "timed out".to_string()
+    } else if e.is_redirect() {
+        "too many redirects".to_string()
+    } else if e.is_connect() {
+        "connection failed".to_string()
+    } else {
+        e.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use tempfile::TempDir;
+    use tiny_http::{Method, Response, Server};
+
+    /// Serve `/ok`, `/get-only` (405 on HEAD) and 404 for everything else
+    fn spawn_server() -> String {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap();
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let status = match (request.method(), request.url()) {
+                    (_, "/ok") => 200,
+                    (Method::Head, "/get-only") => 405,
+                    (_, "/get-only") => 200,
+                    _ => 404,
+                };
+                let _ = request.respond(Response::empty(status));
+            }
+        });
+        format!("http://{addr}")
+    }
+
+    #[test]
+    fn test_partition_cached() {
+        let mut cache = ExternalLinkCache::default();
+        cache.insert(
+            "https://cached.example".to_string(),
+            ExternalLinkStatus::now(Some("404 Not Found".to_string())),
+        );
+        let urls = vec![
+            "https://cached.example".to_string(),
+            "https://new.example".to_string(),
+        ];
+
+        let (cached, pending) = partition_cached(&urls, &cache, 60);
+
+        assert_eq!(
+            cached.get("https://cached.example"),
+            Some(&Some("404 Not Found".to_string()))
+        );
+        assert_eq!(pending, vec!["https://new.example".to_string()]);
+    }
+
+    #[test]
+    fn test_check_external_links() {
+        let base = spawn_server();
+        let temp = TempDir::new().unwrap();
+        let config = ExternalValidateConfig {
+            enable: true,
+            timeout_ms: 2_000,
+            ..Default::default()
+        };
+        let urls = vec![
+            format!("{base}/ok"),
+            format!("{base}/get-only"),
+            format!("{base}/missing"),
+        ];
+
+        let broken = check_external_links(&urls, &config, temp.path()).unwrap();
+
+        assert_eq!(broken.len(), 1);
+        assert!(broken[&format!("{base}/missing")].contains("404"));
+
+        // Second run is served from the cache
+        let cache = restore_external_links(temp.path()).unwrap();
+        assert_eq!(cache.len(), 3);
+    }
+}
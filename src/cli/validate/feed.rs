@@ -0,0 +1,323 @@
+//! Feed check of the build output.
+//!
+//! Reads the feeds written by the last `tola build` (`site.seo.feed.path`,
+//! plus `atom_path` when both formats are enabled) and reports:
+//!
+//! - XML that is not well-formed, or a root other than `<rss>` / `<feed>`
+//! - RSS 2.0: a `<channel>` without `<title>`, `<link>` or `<description>`,
+//!   items with neither `<title>` nor `<description>`, and `<pubDate>`
+//!   values that are not RFC 2822 dates
+//! - Atom: a `<feed>` or `<entry>` without `<id>`, `<title>` or `<updated>`
+
+use std::fs;
+
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::config::SiteConfig;
+use crate::log;
+use crate::utils::date::is_rfc2822;
+
+use super::report::{ValidationError, ValidationReport};
+
+/// Check the feed files in the output directory, adding issues to `report`
+pub(super) fn validate_feed(config: &SiteConfig, report: &mut ValidationReport) {
+    let output_dir = config.paths().output_dir();
+    let feed = &config.site.seo.feed;
+
+    for format in feed.formats() {
+        let path = feed.output_path(format);
+        let rel = path.to_string_lossy().into_owned();
+        let xml = match fs::read_to_string(output_dir.join(path)) {
+            Ok(xml) => xml,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                log!(
+                    "validate";
+                    "feed: no {} in output (run `tola build` first)",
+                    path.display()
+                );
+                continue;
+            }
+            Err(e) => {
+                report.add_feed(
+                    rel.clone(),
+                    ValidationError::new(&rel, format!("unreadable: {e}")),
+                );
+                continue;
+            }
+        };
+
+        match parse_feed(&xml) {
+            Ok(root) => {
+                for error in check_feed(&root) {
+                    report.add_feed(rel.clone(), error);
+                }
+            }
+            Err(e) => report.add_feed(rel.clone(), ValidationError::new("malformed XML", e)),
+        }
+    }
+}
+
+/// Element of a parsed feed: local name, unescaped text and child elements
+#[derive(Debug, Default)]
+struct Node {
+    name: String,
+    text: String,
+    children: Vec<Node>,
+}
+
+impl Node {
+    fn from_start(e: &BytesStart) -> Self {
+        Self {
+            name: String::from_utf8_lossy(e.local_name().as_ref()).into_owned(),
+            ..Default::default()
+        }
+    }
+
+    fn child(&self, name: &str) -> Option<&Self> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Self> {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    /// Text of the element and its descendants (Atom `type="xhtml"` titles)
+    fn full_text(&self) -> String {
+        let mut text = self.text.clone();
+        for child in &self.children {
+            text.push_str(&child.full_text());
+        }
+        text
+    }
+}
+
+/// Parse a feed into its root element, failing on XML that is not well-formed
+fn parse_feed(xml: &str) -> Result<Node, String> {
+    let mut reader = Reader::from_str(xml);
+    // Open elements, innermost last
+    let mut stack: Vec<Node> = Vec::new();
+    let mut root: Option<Node> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("{e} (at byte {})", reader.error_position()))?;
+        match event {
+            Event::Eof => break,
+            Event::Start(e) => stack.push(Node::from_start(&e)),
+            Event::Empty(e) => attach(Node::from_start(&e), &mut stack, &mut root)?,
+            Event::End(_) => {
+                let node = stack.pop().ok_or("unexpected closing tag")?;
+                attach(node, &mut stack, &mut root)?;
+            }
+            Event::Text(e) => {
+                let text = std::str::from_utf8(&e).map_err(|e| e.to_string())?;
+                match stack.last_mut() {
+                    Some(node) => node.text.push_str(text),
+                    None if !text.trim().is_empty() => {
+                        return Err("text outside the root element".to_string());
+                    }
+                    None => {}
+                }
+            }
+            Event::GeneralRef(e) => {
+                if let Some(node) = stack.last_mut() {
+                    let name = std::str::from_utf8(&e).map_err(|e| e.to_string())?;
+                    let entity = format!("&{name};");
+                    let value = quick_xml::escape::unescape(&entity).map_err(|e| e.to_string())?;
+                    node.text.push_str(&value);
+                }
+            }
+            Event::CData(e) => {
+                if let Some(node) = stack.last_mut() {
+                    node.text
+                        .push_str(std::str::from_utf8(&e).map_err(|e| e.to_string())?);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !stack.is_empty() {
+        return Err("unclosed element at end of file".to_string());
+    }
+    root.ok_or_else(|| "no root element".to_string())
+}
+
+/// Add a closed element to its parent, or make it the root
+fn attach(node: Node, stack: &mut [Node], root: &mut Option<Node>) -> Result<(), String> {
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(node),
+        None if root.is_some() => return Err("multiple root elements".to_string()),
+        None => *root = Some(node),
+    }
+    Ok(())
+}
+
+/// Required-element issues of a parsed feed
+fn check_feed(root: &Node) -> Vec<ValidationError> {
+    match root.name.as_str() {
+        "rss" => check_rss(root),
+        "feed" => check_atom(root),
+        other => vec![ValidationError::new(
+            format!("<{other}>"),
+            "unexpected root element (expected <rss> or <feed>)",
+        )],
+    }
+}
+
+/// RSS 2.0: channel elements, item content and `pubDate` format
+fn check_rss(rss: &Node) -> Vec<ValidationError> {
+    let Some(channel) = rss.child("channel") else {
+        return vec![ValidationError::new("<rss>", "has no <channel>")];
+    };
+
+    let mut errors = Vec::new();
+    for name in ["title", "link", "description"] {
+        if channel.child(name).is_none() {
+            errors.push(ValidationError::new(
+                "<channel>",
+                format!("has no <{name}>"),
+            ));
+        }
+    }
+
+    for (i, item) in channel.children("item").enumerate() {
+        let target = item_target(item, "item", i);
+        if item.child("title").is_none() && item.child("description").is_none() {
+            errors.push(ValidationError::new(
+                &target,
+                "has neither <title> nor <description>",
+            ));
+        }
+        if let Some(date) = item.child("pubDate")
+            && !is_rfc2822(&date.text)
+        {
+            errors.push(ValidationError::new(
+                &target,
+                format!("invalid pubDate `{}` (expected RFC 2822)", date.text.trim()),
+            ));
+        }
+    }
+    errors
+}
+
+/// Atom: `id`, `title` and `updated` on the feed and every entry
+fn check_atom(feed: &Node) -> Vec<ValidationError> {
+    const REQUIRED: [&str; 3] = ["id", "title", "updated"];
+
+    let mut errors = Vec::new();
+    for name in REQUIRED {
+        if feed.child(name).is_none() {
+            errors.push(ValidationError::new("<feed>", format!("has no <{name}>")));
+        }
+    }
+
+    for (i, entry) in feed.children("entry").enumerate() {
+        let target = item_target(entry, "entry", i);
+        for name in REQUIRED {
+            if entry.child(name).is_none() {
+                errors.push(ValidationError::new(&target, format!("has no <{name}>")));
+            }
+        }
+    }
+    errors
+}
+
+/// Name an item by its title, falling back to its position
+fn item_target(item: &Node, kind: &str, index: usize) -> String {
+    let title = item.child("title").map(Node::full_text);
+    match title.as_deref().map(str::trim) {
+        Some(title) if !title.is_empty() => format!("{kind} \"{title}\""),
+        _ => format!("{kind} {}", index + 1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(xml: &str) -> Vec<String> {
+        check_feed(&parse_feed(xml).unwrap())
+            .into_iter()
+            .map(|e| format!("{} {}", e.target, e.reason))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_feed() {
+        let root = parse_feed(
+            r#"<?xml version="1.0"?>
+<rss version="2.0"><channel>
+  <title>Tom &amp; Jerry</title>
+  <item><description><![CDATA[<p>hi</p>]]></description></item>
+</channel></rss>"#,
+        )
+        .unwrap();
+        assert_eq!(root.name, "rss");
+        let channel = root.child("channel").unwrap();
+        assert_eq!(channel.child("title").unwrap().text, "Tom & Jerry");
+        let item = channel.children("item").next().unwrap();
+        assert_eq!(item.child("description").unwrap().text, "<p>hi</p>");
+
+        for xml in [
+            "",
+            "<rss><channel></rss>",
+            "<rss></rss><rss></rss>",
+            "<feed><title>a &bogus; b</title></feed>",
+        ] {
+            assert!(parse_feed(xml).is_err(), "{xml:?}");
+        }
+    }
+
+    #[test]
+    fn test_check_rss() {
+        let xml = r#"<rss version="2.0"><channel>
+  <title>Blog</title>
+  <link>https://example.com/</link>
+  <description>Posts</description>
+  <item><title>Good</title><pubDate>Mon, 15 Jan 2024 10:30:45 GMT</pubDate></item>
+  <item><description>Summary only</description></item>
+  <item><link>https://example.com/empty/</link></item>
+  <item><title>Bad date</title><pubDate>2024-01-15</pubDate></item>
+</channel></rss>"#;
+        assert_eq!(
+            errors(xml),
+            [
+                "item 3 has neither <title> nor <description>",
+                "item \"Bad date\" invalid pubDate `2024-01-15` (expected RFC 2822)",
+            ]
+        );
+
+        assert_eq!(
+            errors("<rss><channel><title>Blog</title></channel></rss>"),
+            ["<channel> has no <link>", "<channel> has no <description>"]
+        );
+        assert_eq!(errors("<rss/>"), ["<rss> has no <channel>"]);
+        assert_eq!(
+            errors("<urlset/>"),
+            ["<urlset> unexpected root element (expected <rss> or <feed>)"]
+        );
+    }
+
+    #[test]
+    fn test_check_atom() {
+        let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <id>https://example.com/</id>
+  <title type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml">Blog</div></title>
+  <entry><id>urn:1</id><title>One</title><updated>2024-01-15T00:00:00Z</updated></entry>
+  <entry><id>urn:2</id><title>Two</title></entry>
+  <entry><updated>2024-01-15T00:00:00Z</updated></entry>
+</feed>"#;
+        assert_eq!(
+            errors(xml),
+            [
+                "<feed> has no <updated>",
+                "entry \"Two\" has no <updated>",
+                "entry 3 has no <id>",
+                "entry 3 has no <title>",
+            ]
+        );
+    }
+}
@@ -1,29 +1,33 @@
 //! Site validation command.
 
+mod accessibility;
+mod external;
+mod feed;
 mod report;
 mod scan;
+mod sitemap;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use parking_lot::RwLock;
 use rayon::prelude::*;
 
 use super::common::collect_content_files;
-use crate::address::SiteIndex;
+use crate::address::{Resource, SiteIndex, redirect};
 use crate::compiler::page::typst::{MAX_METADATA_SCAN_ITERATIONS, scan_single_with_current};
 use crate::compiler::page::{CompiledPage, TypstHost};
-use crate::config::SiteConfig;
-use crate::core::{ContentKind, LinkKind, LinkOrigin, ResolveContext, ResolveResult};
+use crate::config::{SiteConfig, ValidateLevel};
+use crate::core::{ContentKind, LinkKind, LinkOrigin, ResolveContext, ResolveResult, UrlPath};
 use crate::log;
 use crate::package::build_visible_inputs;
 use crate::page::{HashStabilityTracker, PageKind, PageMeta, StabilityDecision, StoredPageMap};
 use crate::utils::path::route::{strip_path_prefix, strip_path_prefix_in_text};
 use crate::utils::{plural_count, plural_s};
 
-use report::ValidationReport;
+use report::{ValidationError, ValidationReport};
 use scan::scan_markdown;
 
 /// Result type for address space building: (pages, typst_links, compile_errors)
@@ -54,7 +58,7 @@ pub fn validate_site(config: &SiteConfig) -> Result<()> {
     let host = crate::compiler::page::TypstHost::for_config(config);
 
     let args = get_validate_args();
-    let files = collect_content_files(&args.paths, &config.build.content)?;
+    let files = collect_content_files(&args.paths, &config.build)?;
 
     if files.is_empty() {
         log!("validate"; "no content files found");
@@ -67,8 +71,26 @@ pub fn validate_site(config: &SiteConfig) -> Result<()> {
     // Check if any validation is enabled
     let check_pages = validate_config.pages.enable;
     let check_assets = validate_config.assets.enable;
-
-    if !check_pages && !check_assets {
+    // Inbound links are only complete when every file is scanned
+    let check_orphans = validate_config.orphans.enable && args.paths.is_empty();
+    let check_orphan_assets = validate_config.assets.orphans.enable && args.paths.is_empty();
+    let check_external = validate_config.external.enable;
+    let check_accessibility = validate_config.accessibility.enable;
+    // Sitemap URLs are only reachable once every page is known
+    let check_sitemap =
+        validate_config.sitemap.enable && config.site.seo.sitemap.enable && args.paths.is_empty();
+    let check_feed =
+        validate_config.feed.enable && config.site.seo.feed.enable && args.paths.is_empty();
+
+    if !check_pages
+        && !check_assets
+        && !check_orphans
+        && !check_orphan_assets
+        && !check_external
+        && !check_accessibility
+        && !check_sitemap
+        && !check_feed
+    {
         log!("validate"; "no checks enabled");
         return Ok(());
     }
@@ -82,7 +104,14 @@ pub fn validate_site(config: &SiteConfig) -> Result<()> {
     let report = Arc::new(RwLock::new(ValidationReport::default()));
 
     // Build AddressSpace for validation (unified scan: metadata + links + errors)
-    let (all_pages, typst_links) = if check_pages || check_assets {
+    let (all_pages, typst_links) = if check_pages
+        || check_assets
+        || check_orphans
+        || check_orphan_assets
+        || check_external
+        || check_accessibility
+        || check_sitemap
+    {
         let (pages, links, compile_errors) = build_address_space(config, &host, &state)?;
 
         // Add compile errors to report as asset errors
@@ -99,11 +128,17 @@ pub fn validate_site(config: &SiteConfig) -> Result<()> {
         (Vec::new(), HashMap::new())
     };
 
+    // Redirect loops also collide with a permalink, so check them before conflicts
+    if check_pages {
+        check_redirects(config, &root, &state, &mut report.write());
+    }
+
     // Check for permalink conflicts
     let url_sources = crate::address::conflict::collect_url_sources(&all_pages, config);
     let conflicts = crate::address::conflict::detect_conflicts(&url_sources, config.get_root());
     if !conflicts.is_empty() {
         let prefix = config.paths().prefix().to_string_lossy().into_owned();
+        report.read().print_redirects();
         crate::address::conflict::print_conflicts_with_prefix(&conflicts, &prefix);
         let total_sources: usize = conflicts.iter().map(|c| c.sources.len()).sum();
         anyhow::bail!(
@@ -148,43 +183,569 @@ pub fn validate_site(config: &SiteConfig) -> Result<()> {
     }
 
     // Get final report
-    let report = Arc::try_unwrap(report).unwrap().into_inner();
+    let mut report = Arc::try_unwrap(report).unwrap().into_inner();
+
+    // Orphans need the inbound links recorded during link validation
+    if check_orphans {
+        find_orphans(config, &root, &all_pages, &mut report);
+        let count = report.orphan_count();
+        if count > 0 {
+            log!("validate"; "found {} orphaned page{}", count, plural_s(count));
+        } else {
+            log!("validate"; "no orphaned pages");
+        }
+    }
+
+    // Social preview images are warnings only
+    if check_assets || check_orphan_assets {
+        let count = check_og_images(config, &root, &all_pages, &mut report);
+        if count > 0 {
+            log!("validate"; "found {} missing og_image{}", count, plural_s(count));
+        }
+    }
+
+    // Canonical mismatches are warnings only
+    if check_pages && validate_config.pages.canonical {
+        check_canonicals(config, &root, &all_pages, &mut report);
+        report.warning_sections.insert("canonical");
+        let count = report.canonical_file_count();
+        if count > 0 {
+            log!("validate"; "found {} with a mismatched canonical", plural_count(count, "page"));
+        }
+    }
+
+    // Unreferenced assets are warnings only: some files are served unlinked
+    if check_orphan_assets {
+        find_orphan_assets(config, &root, &mut report);
+        let count = report.orphan_asset_count();
+        if count > 0 {
+            log!(
+                "validate";
+                "found {} ({})",
+                plural_count(count, "unreferenced asset"),
+                crate::cli::clean::format_bytes(report.orphan_asset_bytes())
+            );
+        } else {
+            log!("validate"; "no unreferenced assets");
+        }
+    }
+
+    // External links go over the network, so they run after the internal pass
+    if check_external {
+        validate_external_links(config, &root, &mut report)?;
+    }
+
+    // Accessibility reads the built HTML, independent of link validation
+    if check_accessibility {
+        accessibility::audit_pages(
+            &validate_config.accessibility,
+            &root,
+            &all_pages,
+            &mut report,
+        );
+        let count = report.accessibility_error_count();
+        if count > 0 {
+            log!("validate"; "found {} accessibility issue{}", count, plural_s(count));
+        } else {
+            log!("validate"; "no accessibility issues");
+        }
+    }
 
-    // Print detailed report (pages -> assets)
+    // The sitemap is checked last, against the complete address space
+    if check_sitemap {
+        sitemap::validate_sitemap(config, &all_pages, &mut report);
+        let count = report.sitemap_error_count();
+        if count > 0 {
+            log!("validate"; "found {} in sitemap", plural_count(count, "issue"));
+        } else {
+            log!("validate"; "sitemap valid");
+        }
+    }
+
+    if check_feed {
+        feed::validate_feed(config, &mut report);
+        let count = report.feed_error_count();
+        if count > 0 {
+            log!("validate"; "found {} in feed", plural_count(count, "issue"));
+        } else {
+            log!("validate"; "feed valid");
+        }
+    }
+
+    report.files_checked = file_count;
+    for (name, level) in [
+        ("orphans", &validate_config.orphans.level),
+        ("external", &validate_config.external.level),
+        ("accessibility", &validate_config.accessibility.level),
+        ("sitemap", &validate_config.sitemap.level),
+        ("feed", &validate_config.feed.level),
+    ] {
+        if *level != ValidateLevel::Error {
+            report.warning_sections.insert(name);
+        }
+    }
+    if args.html_report.is_some() {
+        suggest_page_fixes(config, &state, &mut report);
+    }
+
+    // Print detailed report (pages -> assets -> externals -> accessibility -> sitemap -> feed)
     report.print();
+    write_reports(&report, &args)?;
 
-    // Final summary (pages -> assets)
-    print_summary(report.page_file_count(), report.asset_file_count())
+    // Final summary; sections at `level = "warn"` don't fail validation
+    print_summary(&report)
 }
 
-/// Validate all links using pre-scanned Typst links and scanning Markdown files
-fn validate_all_links(
-    files: &[PathBuf],
-    root: &std::path::Path,
+/// A page link that resolves to nothing
+pub struct BrokenLink {
+    /// Absolute path of the file containing the link.
+    pub file: PathBuf,
+    /// Link destination as written (with `path_prefix` stripped).
+    pub dest: String,
+}
+
+/// Scan all content into a fresh site index without compiling pages
+///
+/// Used when no build has persisted its URL map yet.
+pub fn scan_site_index(config: &SiteConfig) -> Result<SiteIndex> {
+    let state = SiteIndex::new();
+    let host = TypstHost::for_config(config);
+    build_address_space(config, &host, &state)?;
+    Ok(state)
+}
+
+/// Scan all content for page links that resolve to nothing
+///
+/// Shares the address space and link scanning of `tola validate`, regardless
+/// of the `[validate]` settings. The returned index holds every known page.
+pub fn find_broken_page_links(config: &SiteConfig) -> Result<(SiteIndex, Vec<BrokenLink>)> {
+    let mut config = config.clone();
+    config.validate.pages.enable = true;
+    config.validate.assets.enable = false;
+    config.validate.orphans.enable = false;
+    config.validate.assets.orphans.enable = false;
+    config.validate.external.enable = false;
+    config.validate.accessibility.enable = false;
+    config.validate.sitemap.enable = false;
+    config.validate.feed.enable = false;
+
+    let state = SiteIndex::new();
+    let host = TypstHost::for_config(&config);
+    let root = crate::utils::path::normalize_path(config.get_root());
+    let files = collect_content_files(&[], &config.build)?;
+
+    let (pages, typst_links, _) = build_address_space(&config, &host, &state)?;
+    let report = Arc::new(RwLock::new(ValidationReport::default()));
+    validate_all_links(
+        &files,
+        &root,
+        &config,
+        &host,
+        &state,
+        &pages,
+        &typst_links,
+        &report,
+    );
+
+    // Fragment and resolver errors have no replacement target to suggest
+    let report = Arc::try_unwrap(report).unwrap().into_inner();
+    let broken = report
+        .pages
+        .into_iter()
+        .flat_map(|(source, errors)| {
+            let file = root.join(source);
+            errors
+                .into_iter()
+                .filter(|error| error.reason == "not found")
+                .map(move |error| BrokenLink {
+                    file: file.clone(),
+                    dest: error.target,
+                })
+        })
+        .collect();
+
+    Ok((state, broken))
+}
+
+/// Report alias redirect loops, and warn on chains over `max_redirect_chain` hops
+fn check_redirects(
     config: &SiteConfig,
-    host: &TypstHost,
+    root: &std::path::Path,
     state: &SiteIndex,
+    report: &mut ValidationReport,
+) {
+    let prefix = config.paths().prefix().to_string_lossy().into_owned();
+    let max_chain = config.validate.pages.max_redirect_chain;
+    let display = |urls: &[UrlPath]| {
+        urls.iter()
+            .map(|url| strip_path_prefix(url.as_str(), &prefix))
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    };
+
+    state.read(|_, space| {
+        let redirects = space.redirects();
+        let analysis = redirect::analyze(redirects, max_chain);
+
+        for cycle in &analysis.cycles {
+            let source = &redirects[&cycle[0]].source;
+            let source = source.strip_prefix(root).unwrap_or(source);
+            report.add_redirect_loop(source.to_string_lossy().to_string(), display(cycle));
+        }
+
+        for chain in &analysis.long_chains {
+            log!(
+                "validate";
+                "redirect chain of {} hops: {}",
+                chain.len() - 1,
+                display(chain)
+            );
+        }
+    });
+
+    let count = report.redirect_loop_count();
+    if count > 0 {
+        log!("validate"; "found {} redirect loop{}", count, plural_s(count));
+    }
+}
+
+/// Report pages that no other page links to
+///
+/// Skips the site root, the configured 404 page, and pages whose metadata
+/// sets `no_validate: true`.
+fn find_orphans(
+    config: &SiteConfig,
+    root: &std::path::Path,
     all_pages: &[CompiledPage],
-    typst_links: &HashMap<PathBuf, Vec<scan::ScannedLink>>,
+    report: &mut ValidationReport,
+) {
+    let prefix = config.paths().prefix().to_string_lossy().into_owned();
+    let not_found = config.site.not_found.as_ref().map(|p| config.root_join(p));
+
+    for page in all_pages {
+        let permalink = page.route.permalink.as_str();
+        if report.linked.contains(permalink) {
+            continue;
+        }
+        if strip_path_prefix(permalink, &prefix) == "/" {
+            continue;
+        }
+        if not_found.as_deref() == Some(page.route.source.as_path()) {
+            continue;
+        }
+        if page.content_meta.as_ref().is_some_and(is_no_validate) {
+            continue;
+        }
+
+        let source = page
+            .route
+            .source
+            .strip_prefix(root)
+            .unwrap_or(&page.route.source)
+            .to_string_lossy()
+            .to_string();
+        report.add_orphan(source, strip_path_prefix(permalink, &prefix));
+    }
+}
+
+/// Check the `no_validate` metadata flag (`no-validate` is accepted too)
+fn is_no_validate(meta: &PageMeta) -> bool {
+    ["no_validate", "no-validate"]
+        .iter()
+        .filter_map(|key| meta.extra.get(*key))
+        .any(|value| value.as_bool() == Some(true))
+}
+
+/// Record the page a resolved link points to, ignoring self-links
+fn record_inbound(
+    result: &ResolveResult,
+    from: &CompiledPage,
     report: &Arc<RwLock<ValidationReport>>,
 ) {
-    // Collect all nested asset source directories with their output prefixes
-    let nested_assets: Vec<_> = config
+    let target = match result {
+        ResolveResult::Found(Resource::Page { route, .. }) => route.permalink.to_string(),
+        ResolveResult::FragmentNotFound { page, .. } => page.clone(),
+        _ => return,
+    };
+    if target != from.route.permalink.as_str() {
+        report.write().add_inbound(target);
+    }
+}
+
+/// Check collected external URLs and add broken ones to the report
+fn validate_external_links(
+    config: &SiteConfig,
+    root: &std::path::Path,
+    report: &mut ValidationReport,
+) -> Result<()> {
+    let refs = std::mem::take(&mut report.external_refs);
+    if refs.is_empty() {
+        log!("validate"; "no external links found");
+        return Ok(());
+    }
+
+    let urls: Vec<String> = refs.keys().cloned().collect();
+    let broken = external::check_external_links(&urls, &config.validate.external, root)?;
+
+    for (url, reason) in &broken {
+        for source in &refs[url] {
+            report.add_external(source.clone(), url.clone(), reason.clone());
+        }
+    }
+
+    if broken.is_empty() {
+        log!("validate"; "all external links reachable");
+    } else {
+        log!("validate"; "found {} broken external link{}", broken.len(), plural_s(broken.len()));
+    }
+
+    Ok(())
+}
+
+/// Nested asset source directories with their output prefixes
+fn nested_asset_sources(config: &SiteConfig, root: &std::path::Path) -> Vec<(String, PathBuf)> {
+    config
         .build
         .assets
         .nested
         .iter()
         .map(|e| (e.output_name().to_string(), root.join(e.source())))
-        .collect();
+        .collect()
+}
 
-    // Collect all flatten files (for asset link validation)
-    let flatten_outputs: Vec<_> = config
+/// Existing flatten asset files with their output names
+fn flatten_asset_sources(config: &SiteConfig, root: &std::path::Path) -> Vec<(String, PathBuf)> {
+    config
         .build
         .assets
         .flatten
         .iter()
         .filter(|e| e.source().exists())
-        .map(|e| e.output_name().to_string())
+        .map(|e| (e.output_name().to_string(), root.join(e.source())))
+        .collect()
+}
+
+/// Whether an output-relative asset path is provided by nested/flatten assets
+fn asset_exists(
+    trimmed: &str,
+    nested_assets: &[(String, PathBuf)],
+    flatten_outputs: &[String],
+) -> bool {
+    // Check nested assets: /images/xxx -> find entry with output_name "images"
+    let in_nested = nested_assets.iter().any(|(output_name, abs_source)| {
+        // Exact match: /images -> output_name "images"
+        if trimmed == output_name {
+            return abs_source.exists();
+        }
+        // Prefix with slash: /images/xxx -> output_name "images", rest "xxx"
+        if let Some(rest) = trimmed.strip_prefix(output_name)
+            && let Some(rest) = rest.strip_prefix('/')
+        {
+            return abs_source.join(rest).exists();
+        }
+        false
+    });
+
+    // Check flatten outputs (e.g., /favicon.ico -> "favicon.ico")
+    in_nested || flatten_outputs.iter().any(|name| trimmed == name)
+}
+
+/// Warn about `og_image` metadata and `site.info.og.default_image` that no
+/// asset provides. Absolute URLs are not checked.
+///
+/// Also records the images as referenced assets for the orphan check.
+fn check_og_images(
+    config: &SiteConfig,
+    root: &std::path::Path,
+    all_pages: &[CompiledPage],
+    report: &mut ValidationReport,
+) -> usize {
+    let nested_assets = nested_asset_sources(config, root);
+    let flatten_outputs: Vec<_> = flatten_asset_sources(config, root)
+        .into_iter()
+        .map(|(output_name, _)| output_name)
+        .collect();
+
+    let page_images = all_pages.iter().filter_map(|page| {
+        let image = page.content_meta.as_ref()?.og_image.as_deref()?;
+        let source = page
+            .route
+            .source
+            .strip_prefix(root)
+            .unwrap_or(&page.route.source);
+        Some((source.display().to_string(), image))
+    });
+    let default_image = config
+        .site
+        .info
+        .og
+        .default_image
+        .as_deref()
+        .map(|image| ("site.info.og.default_image".to_string(), image));
+
+    let mut missing = 0;
+    for (source, image) in page_images.chain(default_image) {
+        if image.starts_with("http://") || image.starts_with("https://") {
+            continue;
+        }
+        let trimmed = image.trim_start_matches('/');
+        report.add_asset_ref(trimmed.to_string());
+        if !asset_exists(trimmed, &nested_assets, &flatten_outputs) {
+            log!("warning"; "{}: og_image `{}` not found in assets", source, image);
+            missing += 1;
+        }
+    }
+    missing
+}
+
+/// Report `canonical` metadata that is not the page's own URL
+///
+/// A declared canonical must be the page's permalink or one of its aliases.
+/// Pages sharing a canonical URL that none of them is served at are also
+/// reported as a conflict. Absolute URLs on another host are left alone.
+fn check_canonicals(
+    config: &SiteConfig,
+    root: &std::path::Path,
+    all_pages: &[CompiledPage],
+    report: &mut ValidationReport,
+) {
+    let site_url = config.site.info.url.as_deref();
+    let mut declared: BTreeMap<UrlPath, Vec<String>> = BTreeMap::new();
+    let mut served: HashSet<UrlPath> = HashSet::new();
+
+    for page in all_pages {
+        served.insert(page.route.permalink.clone());
+        let Some(meta) = &page.content_meta else {
+            continue;
+        };
+        let aliases: Vec<UrlPath> = meta.aliases.iter().map(|a| UrlPath::from_page(a)).collect();
+        served.extend(aliases.iter().cloned());
+
+        let Some(canonical) = meta.extra.get("canonical").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        if is_no_validate(meta) {
+            continue;
+        }
+        let Some(target) = canonical_path(canonical, site_url) else {
+            continue;
+        };
+        if target == page.route.permalink || aliases.contains(&target) {
+            continue;
+        }
+
+        let source = page
+            .route
+            .source
+            .strip_prefix(root)
+            .unwrap_or(&page.route.source)
+            .display()
+            .to_string();
+        report.add_canonical(
+            source.clone(),
+            ValidationError::new(
+                format!("`{canonical}`"),
+                format!("does not match permalink `{}`", page.route.permalink),
+            ),
+        );
+        declared.entry(target).or_default().push(source);
+    }
+
+    for (target, sources) in declared {
+        if sources.len() > 1 && !served.contains(&target) {
+            let reason = format!(
+                "is declared by {} but served by none",
+                plural_count(sources.len(), "page")
+            );
+            for source in sources {
+                report.add_canonical(
+                    source,
+                    ValidationError::new(format!("`{target}`"), reason.clone()),
+                );
+            }
+        }
+    }
+}
+
+/// Site-relative path of a `canonical` value, `None` for other hosts
+fn canonical_path(canonical: &str, site_url: Option<&str>) -> Option<UrlPath> {
+    if !canonical.starts_with("http://") && !canonical.starts_with("https://") {
+        return Some(UrlPath::from_page(canonical));
+    }
+    let base = site_url?.trim_end_matches('/');
+    let path = canonical.strip_prefix(base)?;
+    (path.is_empty() || path.starts_with('/')).then(|| UrlPath::from_page(path))
+}
+
+/// Report asset files that no page links to
+///
+/// Cross-references the nested/flatten asset sources against the asset
+/// paths recorded during link validation. Files pulled in by
+/// `[site.header]` (icon, styles, scripts) count as referenced.
+fn find_orphan_assets(config: &SiteConfig, root: &std::path::Path, report: &mut ValidationReport) {
+    use crate::utils::path::normalize_path;
+
+    let orphans_config = &config.validate.assets.orphans;
+    let header = &config.site.header;
+    let header_sources: std::collections::HashSet<PathBuf> = header
+        .icon
+        .iter()
+        .cloned()
+        .chain(header.expanded_styles(root))
+        .chain(
+            header
+                .expanded_scripts(root)
+                .iter()
+                .map(|s| s.path().to_path_buf()),
+        )
+        .map(|p| normalize_path(&root.join(p)))
+        .collect();
+
+    let mut candidates: Vec<(String, PathBuf)> = Vec::new();
+    for (output_name, source) in nested_asset_sources(config, root) {
+        if !source.is_dir() {
+            continue;
+        }
+        for file in crate::compiler::collect_all_files(&source) {
+            let rel = file.strip_prefix(&source).unwrap_or(&file);
+            let output = format!(
+                "{}/{}",
+                output_name,
+                rel.to_string_lossy().replace('\\', "/")
+            );
+            candidates.push((output, file));
+        }
+    }
+    candidates.extend(flatten_asset_sources(config, root));
+
+    for (output, source) in candidates {
+        if report.referenced_assets.contains(&output)
+            || orphans_config.is_ignored(&output)
+            || header_sources.contains(&normalize_path(&source))
+        {
+            continue;
+        }
+        let size = std::fs::metadata(&source).map(|m| m.len()).unwrap_or(0);
+        report.add_orphan_asset(output, size);
+    }
+}
+
+/// Validate all links using pre-scanned Typst links and scanning Markdown files
+#[allow(clippy::too_many_arguments)]
+fn validate_all_links(
+    files: &[PathBuf],
+    root: &std::path::Path,
+    config: &SiteConfig,
+    host: &TypstHost,
+    state: &SiteIndex,
+    all_pages: &[CompiledPage],
+    typst_links: &HashMap<PathBuf, Vec<scan::ScannedLink>>,
+    report: &Arc<RwLock<ValidationReport>>,
+) {
+    let nested_assets = nested_asset_sources(config, root);
+    let flatten_outputs: Vec<_> = flatten_asset_sources(config, root)
+        .into_iter()
+        .map(|(output_name, _)| output_name)
         .collect();
 
     // Process Typst links (already scanned in build_address_space)
@@ -265,34 +826,30 @@ fn validate_links(
         let is_asset_attr = link.origin.is_asset_attr();
 
         match link.kind() {
-            // External links: skip (no HTTP validation)
-            LinkKind::External(_) => {}
+            // External links: collect http(s) URLs for the network pass
+            LinkKind::External(_) => {
+                if validate_config.external.enable && link.is_http() {
+                    report
+                        .write()
+                        .add_external_ref(source.to_string(), link.dest.clone());
+                }
+            }
 
             // Site-root links: could be page OR static asset
             LinkKind::SiteRoot(path) => {
+                if validate_config.assets.orphans.enable {
+                    let asset = strip_path_prefix(path, &prefix);
+                    let asset = asset.split(['?', '#']).next().unwrap_or_default();
+                    report
+                        .write()
+                        .add_asset_ref(asset.trim_start_matches('/').to_string());
+                }
+
                 // For asset attributes, check all static assets directories
                 if is_asset_attr {
                     let trimmed = path.trim_start_matches('/');
 
-                    // Check nested assets: /images/xxx -> find entry with output_name "images"
-                    let in_nested = nested_assets.iter().any(|(output_name, abs_source)| {
-                        // Exact match: /images -> output_name "images"
-                        if trimmed == output_name {
-                            return abs_source.exists();
-                        }
-                        // Prefix with slash: /images/xxx -> output_name "images", rest "xxx"
-                        if let Some(rest) = trimmed.strip_prefix(output_name)
-                            && let Some(rest) = rest.strip_prefix('/')
-                        {
-                            return abs_source.join(rest).exists();
-                        }
-                        false
-                    });
-
-                    // Check flatten outputs (e.g., /favicon.ico -> "favicon.ico")
-                    let in_flatten = flatten_outputs.iter().any(|name| trimmed == name);
-
-                    if in_nested || in_flatten {
+                    if asset_exists(trimmed, nested_assets, flatten_outputs) {
                         continue;
                     }
 
@@ -337,7 +894,7 @@ fn validate_links(
                 }
 
                 // Try AddressSpace for non-asset links
-                if !validate_config.pages.enable {
+                if !validate_config.pages.enable && !validate_config.orphans.enable {
                     continue;
                 }
 
@@ -365,6 +922,9 @@ fn validate_links(
                     }
                     result
                 });
+                if validate_config.orphans.enable {
+                    record_inbound(&result, page, report);
+                }
                 handle_resolve_result(
                     result,
                     source,
@@ -378,7 +938,10 @@ fn validate_links(
 
             // File-relative and fragment links: validate via AddressSpace
             LinkKind::FileRelative(_) | LinkKind::Fragment(_) => {
-                if !validate_config.pages.enable && !validate_config.assets.enable {
+                if !validate_config.pages.enable
+                    && !validate_config.assets.enable
+                    && !validate_config.orphans.enable
+                {
                     continue;
                 }
 
@@ -391,6 +954,9 @@ fn validate_links(
                 };
 
                 let result = state.read(|_, space| space.resolve(&link.dest, &ctx));
+                if validate_config.orphans.enable {
+                    record_inbound(&result, page, report);
+                }
                 handle_resolve_result(
                     result,
                     source,
@@ -501,7 +1067,11 @@ fn build_address_space(
 
     use crate::cli::common::scan_markdown_file;
 
-    let content_files = crate::compiler::collect_all_files(&config.build.content);
+    let content_files: Vec<_> = config
+        .build
+        .content_dirs()
+        .flat_map(crate::compiler::collect_all_files)
+        .collect();
     let label = &config.build.meta.label;
 
     // Separate Typst and Markdown files
@@ -777,27 +1347,136 @@ fn extract_asset_path(error: &str, root: &std::path::Path) -> String {
 }
 
 /// Print final summary and return error if validation failed
-fn print_summary(page_errors: usize, asset_errors: usize) -> Result<()> {
-    if page_errors > 0 || asset_errors > 0 {
-        let mut parts = Vec::new();
-        if page_errors > 0 {
-            parts.push(format!(
-                "{} with page link errors",
-                plural_count(page_errors, "file")
-            ));
-        }
-        if asset_errors > 0 {
-            parts.push(format!(
-                "{} with asset link errors",
-                plural_count(asset_errors, "file")
-            ));
-        }
+///
+/// Sections in `report.warning_sections` are left out.
+fn print_summary(report: &ValidationReport) -> Result<()> {
+    // (section, count, counted noun, detail)
+    let sections = [
+        (
+            "pages",
+            report.page_file_count(),
+            "file",
+            " with page link errors",
+        ),
+        (
+            "redirects",
+            report.redirect_loop_count(),
+            "redirect loop",
+            "",
+        ),
+        (
+            "assets",
+            report.asset_file_count(),
+            "file",
+            " with asset link errors",
+        ),
+        ("orphans", report.orphan_count(), "orphaned page", ""),
+        (
+            "canonical",
+            report.canonical_file_count(),
+            "page",
+            " with a mismatched canonical",
+        ),
+        (
+            "external",
+            report.external_file_count(),
+            "file",
+            " with external link errors",
+        ),
+        (
+            "accessibility",
+            report.accessibility_file_count(),
+            "file",
+            " with accessibility issues",
+        ),
+        (
+            "sitemap",
+            report.sitemap_file_count(),
+            "sitemap file",
+            " with issues",
+        ),
+        (
+            "feed",
+            report.feed_file_count(),
+            "feed file",
+            " with issues",
+        ),
+    ];
+
+    let parts: Vec<String> = sections
+        .into_iter()
+        .filter(|(name, count, ..)| *count > 0 && !report.warning_sections.contains(name))
+        .map(|(_, count, noun, detail)| format!("{}{detail}", plural_count(count, noun)))
+        .collect();
+    if !parts.is_empty() {
         anyhow::bail!("found {}", parts.join(", "));
     }
 
     Ok(())
 }
 
+/// Record the closest permalink for each page link that was not found
+fn suggest_page_fixes(config: &SiteConfig, state: &SiteIndex, report: &mut ValidationReport) {
+    let prefix = config.paths().prefix().to_string_lossy().into_owned();
+    let permalinks: Vec<String> = state.read(|_, space| {
+        space
+            .pages()
+            .filter_map(|resource| match resource {
+                Resource::Page { route, .. } => {
+                    Some(strip_path_prefix(route.permalink.as_str(), &prefix))
+                }
+                Resource::Asset { .. } => None,
+            })
+            .collect()
+    });
+
+    let suggestions: Vec<_> = report
+        .pages
+        .values()
+        .flatten()
+        .filter(|e| e.reason == "not found")
+        .filter_map(|e| {
+            Some((
+                e.target.clone(),
+                super::fix::suggest(&e.target, &permalinks)?,
+            ))
+        })
+        .collect();
+    report.suggestions.extend(suggestions);
+}
+
+/// Emit machine-readable reports: JUnit XML, HTML and GitHub Actions annotations.
+fn write_reports(report: &ValidationReport, args: &crate::cli::ValidateArgs) -> Result<()> {
+    if std::env::var_os("GITHUB_ACTIONS").is_some_and(|v| v == "true") {
+        print!("{}", report.to_github_annotations());
+    }
+
+    if args.reporter == crate::cli::ValidateReporter::Junit {
+        let xml = report.to_junit_xml();
+        match &args.junit_output {
+            Some(path) => {
+                if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(path, xml)
+                    .with_context(|| format!("failed to write {}", path.display()))?;
+                log!("validate"; "wrote junit report to {}", path.display());
+            }
+            None => print!("{xml}"),
+        }
+    }
+
+    if let Some(path) = &args.html_report {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, report.to_html())
+            .with_context(|| format!("failed to write {}", path.display()))?;
+        log!("validate"; "wrote html report to {}", path.display());
+    }
+    Ok(())
+}
+
 fn get_validate_args() -> crate::cli::ValidateArgs {
     use crate::cli::{Cli, Commands};
     use clap::Parser;
@@ -810,6 +1489,103 @@ fn get_validate_args() -> crate::cli::ValidateArgs {
             warn_only: false,
             pages: None,
             assets: None,
+            reporter: crate::cli::ValidateReporter::Human,
+            junit_output: None,
+            html_report: None,
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// Compile `content/<rel>` with the given JSON metadata
+    fn page(config: &SiteConfig, rel: &str, meta: &str) -> CompiledPage {
+        let source = config.build.content.join(rel);
+        fs::create_dir_all(source.parent().unwrap()).unwrap();
+        fs::write(&source, "= Page").unwrap();
+        let meta = serde_json::from_str(meta).unwrap();
+        CompiledPage::from_paths_with_meta(source, config, Some(meta)).unwrap()
+    }
+
+    fn site() -> (TempDir, SiteConfig) {
+        let dir = TempDir::new().unwrap();
+        let mut config = SiteConfig::default();
+        config.build.output = dir.path().join("public");
+        config.build.content = dir.path().join("content");
+        config.site.info.url = Some("https://example.com".to_string());
+        (dir, config)
+    }
+
+    #[test]
+    fn test_canonical_path() {
+        let site = Some("https://example.com/");
+        assert_eq!(
+            canonical_path("/a/b", site),
+            Some(UrlPath::from_page("/a/b/"))
+        );
+        assert_eq!(
+            canonical_path("https://example.com/a/", site),
+            Some(UrlPath::from_page("/a/"))
+        );
+        assert_eq!(
+            canonical_path("https://example.com", site),
+            Some(UrlPath::from_page("/"))
+        );
+        assert_eq!(canonical_path("https://example.community/a/", site), None);
+        assert_eq!(canonical_path("https://other.test/a/", site), None);
+        assert_eq!(canonical_path("https://example.com/a/", None), None);
+    }
+
+    #[test]
+    fn test_check_canonicals() {
+        let (dir, config) = site();
+        let pages = [
+            page(&config, "plain.typ", "{}"),
+            page(&config, "own.typ", r#"{"canonical": "/own/"}"#),
+            page(
+                &config,
+                "aliased.typ",
+                r#"{"canonical": "https://example.com/old/", "aliases": ["/old/"]}"#,
+            ),
+            page(
+                &config,
+                "syndicated.typ",
+                r#"{"canonical": "https://other.test/post/"}"#,
+            ),
+            page(&config, "wrong.typ", r#"{"canonical": "/own/"}"#),
+        ];
+
+        let mut report = ValidationReport::default();
+        check_canonicals(&config, dir.path(), &pages, &mut report);
+
+        assert_eq!(report.canonical_file_count(), 1);
+        let issues = &report.canonicals["content/wrong.typ"];
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].target, "`/own/`");
+        assert_eq!(issues[0].reason, "does not match permalink `/wrong/`");
+    }
+
+    #[test]
+    fn test_check_canonicals_unserved_conflict() {
+        let (dir, config) = site();
+        let pages = [
+            page(&config, "a.typ", r#"{"canonical": "/gone/"}"#),
+            page(&config, "b.typ", r#"{"canonical": "/gone/"}"#),
+        ];
+
+        let mut report = ValidationReport::default();
+        check_canonicals(&config, dir.path(), &pages, &mut report);
+
+        // Each page gets its mismatch plus the shared conflict
+        assert_eq!(report.canonical_file_count(), 2);
+        assert_eq!(report.canonical_error_count(), 4);
+        assert_eq!(
+            report.canonicals["content/a.typ"][1].reason,
+            "is declared by 2 pages but served by none"
+        );
+    }
+}
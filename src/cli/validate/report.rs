@@ -1,10 +1,12 @@
 //! Validation report types and formatting.
 
-use std::collections::BTreeMap;
-use std::fmt;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{self, Write as _};
 
 use owo_colors::OwoColorize;
 
+use crate::cli::clean::format_bytes;
+use crate::utils::html::{escape, escape_attr};
 use crate::utils::plural_s;
 
 /// A single validation error
@@ -16,6 +18,16 @@ pub struct ValidationError {
     pub reason: String,
 }
 
+impl ValidationError {
+    /// Error for `target` (link, path or element) with `reason`.
+    pub fn new(target: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
 /// Unified validation report for all error types
 #[derive(Debug, Default)]
 pub struct ValidationReport {
@@ -23,15 +35,43 @@ pub struct ValidationReport {
     pub pages: BTreeMap<String, Vec<ValidationError>>,
     /// Asset errors (missing files), grouped by source file.
     pub assets: BTreeMap<String, Vec<ValidationError>>,
+    /// Alias redirect loops, grouped by source file.
+    pub redirects: BTreeMap<String, Vec<ValidationError>>,
+    /// Orphaned pages (no inbound links), keyed by source file.
+    pub orphans: BTreeMap<String, Vec<ValidationError>>,
+    /// Canonical metadata that is not the page's own URL, grouped by source file.
+    pub canonicals: BTreeMap<String, Vec<ValidationError>>,
+    /// Permalinks referenced by at least one other page.
+    pub linked: BTreeSet<String>,
+    /// External link errors (unreachable URLs), grouped by source file.
+    pub externals: BTreeMap<String, Vec<ValidationError>>,
+    /// External URLs awaiting the network check, with their source files.
+    pub external_refs: BTreeMap<String, BTreeSet<String>>,
+    /// Accessibility issues in built pages, grouped by source file.
+    pub accessibility: BTreeMap<String, Vec<ValidationError>>,
+    /// Issues in the built sitemap, grouped by output-relative sitemap file.
+    pub sitemap: BTreeMap<String, Vec<ValidationError>>,
+    /// Issues in the built feeds, grouped by output-relative feed file.
+    pub feed: BTreeMap<String, Vec<ValidationError>>,
+    /// Output-relative asset paths linked from at least one page.
+    pub referenced_assets: BTreeSet<String>,
+    /// Unreferenced asset files (output path -> size in bytes).
+    pub orphan_assets: BTreeMap<String, u64>,
+    /// Number of content files validated.
+    pub files_checked: usize,
+    /// Sections (by report name) whose failures are warnings (`level = "warn"`).
+    pub warning_sections: BTreeSet<&'static str>,
+    /// Closest existing permalink for broken page links (link -> suggestion).
+    pub suggestions: BTreeMap<String, String>,
 }
 
 impl ValidationReport {
     /// Add a page link error.
     pub fn add_page(&mut self, source: String, link: String, reason: String) {
-        self.pages.entry(source).or_default().push(ValidationError {
-            target: link,
-            reason,
-        });
+        self.pages
+            .entry(source)
+            .or_default()
+            .push(ValidationError::new(link, reason));
     }
 
     /// Add an asset error.
@@ -39,10 +79,71 @@ impl ValidationReport {
         self.assets
             .entry(source)
             .or_default()
-            .push(ValidationError {
-                target: path,
-                reason,
-            });
+            .push(ValidationError::new(path, reason));
+    }
+
+    /// Add a redirect loop, with the full cycle path as target.
+    pub fn add_redirect_loop(&mut self, source: String, cycle: String) {
+        self.redirects
+            .entry(source)
+            .or_default()
+            .push(ValidationError::new(cycle, "redirect loop"));
+    }
+
+    /// Record that `permalink` has an inbound link.
+    pub fn add_inbound(&mut self, permalink: String) {
+        self.linked.insert(permalink);
+    }
+
+    /// Add an orphaned page.
+    pub fn add_orphan(&mut self, source: String, permalink: String) {
+        self.orphans
+            .entry(source)
+            .or_default()
+            .push(ValidationError::new(permalink, "no inbound links"));
+    }
+
+    /// Add a canonical metadata issue.
+    pub fn add_canonical(&mut self, source: String, issue: ValidationError) {
+        self.canonicals.entry(source).or_default().push(issue);
+    }
+
+    /// Record that asset `path` (output-relative) is linked from a page.
+    pub fn add_asset_ref(&mut self, path: String) {
+        self.referenced_assets.insert(path);
+    }
+
+    /// Add an unreferenced asset file.
+    pub fn add_orphan_asset(&mut self, path: String, size: u64) {
+        self.orphan_assets.insert(path, size);
+    }
+
+    /// Record an external URL to check after the internal pass.
+    pub fn add_external_ref(&mut self, source: String, url: String) {
+        self.external_refs.entry(url).or_default().insert(source);
+    }
+
+    /// Add an external link error.
+    pub fn add_external(&mut self, source: String, url: String, reason: String) {
+        self.externals
+            .entry(source)
+            .or_default()
+            .push(ValidationError::new(url, reason));
+    }
+
+    /// Add an accessibility issue.
+    pub fn add_accessibility(&mut self, source: String, issue: ValidationError) {
+        self.accessibility.entry(source).or_default().push(issue);
+    }
+
+    /// Add a sitemap issue.
+    pub fn add_sitemap(&mut self, file: String, issue: ValidationError) {
+        self.sitemap.entry(file).or_default().push(issue);
+    }
+
+    /// Add a feed issue.
+    pub fn add_feed(&mut self, file: String, issue: ValidationError) {
+        self.feed.entry(file).or_default().push(issue);
     }
 
     /// Count of files with page link errors.
@@ -55,6 +156,51 @@ impl ValidationReport {
         self.assets.len()
     }
 
+    /// Number of redirect loops.
+    pub fn redirect_loop_count(&self) -> usize {
+        self.redirects.values().map(|v| v.len()).sum()
+    }
+
+    /// Number of orphaned pages.
+    pub fn orphan_count(&self) -> usize {
+        self.orphans.len()
+    }
+
+    /// Count of files with canonical issues.
+    pub fn canonical_file_count(&self) -> usize {
+        self.canonicals.len()
+    }
+
+    /// Number of unreferenced asset files.
+    pub fn orphan_asset_count(&self) -> usize {
+        self.orphan_assets.len()
+    }
+
+    /// Total size of unreferenced asset files.
+    pub fn orphan_asset_bytes(&self) -> u64 {
+        self.orphan_assets.values().sum()
+    }
+
+    /// Count of files with external link errors.
+    pub fn external_file_count(&self) -> usize {
+        self.externals.len()
+    }
+
+    /// Count of files with accessibility issues.
+    pub fn accessibility_file_count(&self) -> usize {
+        self.accessibility.len()
+    }
+
+    /// Count of sitemap files with issues.
+    pub fn sitemap_file_count(&self) -> usize {
+        self.sitemap.len()
+    }
+
+    /// Count of feed files with issues.
+    pub fn feed_file_count(&self) -> usize {
+        self.feed.len()
+    }
+
     /// Total page link error count.
     pub fn page_error_count(&self) -> usize {
         self.pages.values().map(|v| v.len()).sum()
@@ -65,10 +211,273 @@ impl ValidationReport {
         self.assets.values().map(|v| v.len()).sum()
     }
 
-    /// Print the full report to stdout (pages -> assets).
+    /// Total canonical issue count.
+    pub fn canonical_error_count(&self) -> usize {
+        self.canonicals.values().map(|v| v.len()).sum()
+    }
+
+    /// Total external link error count.
+    pub fn external_error_count(&self) -> usize {
+        self.externals.values().map(|v| v.len()).sum()
+    }
+
+    /// Total accessibility issue count.
+    pub fn accessibility_error_count(&self) -> usize {
+        self.accessibility.values().map(|v| v.len()).sum()
+    }
+
+    /// Total sitemap issue count.
+    pub fn sitemap_error_count(&self) -> usize {
+        self.sitemap.values().map(|v| v.len()).sum()
+    }
+
+    /// Total feed issue count.
+    pub fn feed_error_count(&self) -> usize {
+        self.feed.values().map(|v| v.len()).sum()
+    }
+
+    /// Error sections in report order, with their JUnit/annotation names.
+    fn error_sections(&self) -> [(&'static str, &BTreeMap<String, Vec<ValidationError>>); 9] {
+        [
+            ("pages", &self.pages),
+            ("redirects", &self.redirects),
+            ("assets", &self.assets),
+            ("orphans", &self.orphans),
+            ("canonical", &self.canonicals),
+            ("external", &self.externals),
+            ("accessibility", &self.accessibility),
+            ("sitemap", &self.sitemap),
+            ("feed", &self.feed),
+        ]
+    }
+
+    /// Render the report as JUnit XML.
+    ///
+    /// Each section becomes a `<testsuite>`, each file a `<testcase>` with
+    /// one `<failure>` per error. Unreferenced assets are warnings and
+    /// are not included.
+    pub fn to_junit_xml(&self) -> String {
+        let mut suites = String::new();
+        let mut total_tests = 0;
+        let mut total_failures = 0;
+
+        for (name, errors) in self.error_sections() {
+            let tests = errors.len();
+            let failures: usize = errors.values().map(|v| v.len()).sum();
+            total_tests += tests;
+            total_failures += failures;
+
+            let _ = writeln!(
+                suites,
+                r#"  <testsuite name="{name}" tests="{tests}" failures="{failures}">"#
+            );
+            for (path, errs) in errors {
+                let path = escape_attr(path);
+                let _ = writeln!(
+                    suites,
+                    r#"    <testcase name="{path}" classname="{name}" file="{path}">"#
+                );
+                for e in errs {
+                    let message = error_message(e);
+                    let _ = writeln!(
+                        suites,
+                        r#"      <failure type="{name}" message="{}">{}</failure>"#,
+                        escape_attr(&message),
+                        escape(&message)
+                    );
+                }
+                suites.push_str("    </testcase>\n");
+            }
+            suites.push_str("  </testsuite>\n");
+        }
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites name=\"tola validate\" tests=\"{total_tests}\" failures=\"{total_failures}\">\n\
+             {suites}</testsuites>\n"
+        )
+    }
+
+    /// Render the report as a self-contained HTML document.
+    ///
+    /// A summary table (errors, warnings, files checked) is followed by one
+    /// collapsible `<details>` per file. Styles are inlined.
+    pub fn to_html(&self) -> String {
+        let mut errors = 0;
+        let mut warnings = self.orphan_asset_count();
+        let mut body = String::new();
+
+        for (name, section) in self.error_sections() {
+            if section.is_empty() {
+                continue;
+            }
+            let count: usize = section.values().map(|v| v.len()).sum();
+            let is_warning = self.warning_sections.contains(name);
+            if is_warning {
+                warnings += count;
+            } else {
+                errors += count;
+            }
+
+            let class = if is_warning { "warn" } else { "error" };
+            let _ = writeln!(
+                body,
+                r#"<h2 class="{class}">{name} <small>({} file{}, {count} issue{})</small></h2>"#,
+                section.len(),
+                plural_s(section.len()),
+                plural_s(count)
+            );
+            for (path, errs) in section {
+                let _ = writeln!(
+                    body,
+                    "<details open><summary><code>{}</code> <small>({})</small></summary><ul>",
+                    escape(path),
+                    errs.len()
+                );
+                for e in errs {
+                    let _ = write!(body, "<li><code>{}</code>", escape(&e.target));
+                    if !e.reason.is_empty() {
+                        let _ = write!(body, " {}", escape(&e.reason));
+                    }
+                    if let Some(fix) = self.suggestions.get(&e.target) {
+                        let _ = write!(
+                            body,
+                            r#" <span class="fix">did you mean <code>{}</code>?</span>"#,
+                            escape(fix)
+                        );
+                    }
+                    body.push_str("</li>\n");
+                }
+                body.push_str("</ul></details>\n");
+            }
+        }
+
+        if !self.orphan_assets.is_empty() {
+            let _ = writeln!(
+                body,
+                r#"<h2 class="warn">unreferenced assets <small>({} file{}, {})</small></h2><ul>"#,
+                self.orphan_asset_count(),
+                plural_s(self.orphan_asset_count()),
+                format_bytes(self.orphan_asset_bytes())
+            );
+            for (path, size) in &self.orphan_assets {
+                let _ = writeln!(
+                    body,
+                    "<li><code>{}</code> {}</li>",
+                    escape(path),
+                    format_bytes(*size)
+                );
+            }
+            body.push_str("</ul>\n");
+        }
+
+        if body.is_empty() {
+            body.push_str("<p class=\"ok\">All checks passed.</p>\n");
+        }
+
+        let generated = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| i64::try_from(d.as_secs()).unwrap_or(0));
+
+        format!(
+            r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>tola validate report</title>
+<style>{HTML_REPORT_CSS}</style>
+</head>
+<body>
+<h1>Validation report</h1>
+<p class="meta">Generated {generated} by tola {version}</p>
+<table>
+<tr><th>Errors</th><td class="{error_class}">{errors}</td></tr>
+<tr><th>Warnings</th><td class="{warn_class}">{warnings}</td></tr>
+<tr><th>Files checked</th><td>{files}</td></tr>
+</table>
+{body}</body>
+</html>
+"#,
+            generated = crate::page::format_rfc3339(generated),
+            version = env!("CARGO_PKG_VERSION"),
+            error_class = if errors > 0 { "error" } else { "ok" },
+            warn_class = if warnings > 0 { "warn" } else { "ok" },
+            files = self.files_checked,
+        )
+    }
+
+    /// Render errors as GitHub Actions workflow commands (`::error file=...::`).
+    ///
+    /// Sections in `warning_sections` are emitted as `::warning`.
+    pub fn to_github_annotations(&self) -> String {
+        let mut out = String::new();
+        for (name, errors) in self.error_sections() {
+            let command = if self.warning_sections.contains(name) {
+                "warning"
+            } else {
+                "error"
+            };
+            for (path, errs) in errors {
+                for e in errs {
+                    let _ = writeln!(
+                        out,
+                        "::{command} file={},title={}::{}",
+                        escape_annotation_property(path),
+                        escape_annotation_property(&format!("tola validate: {name}")),
+                        escape_annotation_data(&error_message(e))
+                    );
+                }
+            }
+        }
+        out
+    }
+
+    /// Print the full report to stdout
+    /// (pages -> redirects -> assets -> orphans -> unreferenced assets -> canonicals -> externals
+    /// -> accessibility -> sitemap -> feed).
     pub fn print(&self) {
         self.print_section("pages", &self.pages);
+        self.print_redirects();
         self.print_section("assets", &self.assets);
+        self.print_section("orphans", &self.orphans);
+        self.print_orphan_assets();
+        self.print_section("canonical", &self.canonicals);
+        self.print_section("external", &self.externals);
+        self.print_section("accessibility", &self.accessibility);
+        self.print_section("sitemap", &self.sitemap);
+        self.print_section("feed", &self.feed);
+    }
+
+    /// Print only the redirect loops section.
+    pub fn print_redirects(&self) {
+        self.print_section("redirects", &self.redirects);
+    }
+
+    /// Print unreferenced assets as warnings, largest first.
+    fn print_orphan_assets(&self) {
+        if self.orphan_assets.is_empty() {
+            return;
+        }
+        eprintln!();
+
+        let count = self.orphan_asset_count();
+        eprintln!(
+            "{} {}",
+            "unreferenced assets".yellow().bold(),
+            format!(
+                "({count} file{}, {})",
+                plural_s(count),
+                format_bytes(self.orphan_asset_bytes())
+            )
+            .dimmed()
+        );
+
+        let mut assets: Vec<_> = self.orphan_assets.iter().collect();
+        assets.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        for (path, size) in assets {
+            eprintln!("{} {} {}", "→".yellow(), path, format_bytes(*size).dimmed());
+        }
     }
 
     /// Print section with format (target + reason for non-empty reason).
@@ -107,11 +516,68 @@ impl ValidationReport {
     }
 }
 
+/// Inline styles of the HTML report
+const HTML_REPORT_CSS: &str = "
+body { font: 15px/1.5 system-ui, sans-serif; max-width: 960px; margin: 2rem auto; padding: 0 1rem; color: #1f2328; }
+h1 { margin-bottom: 0; }
+h2 { font-size: 1.1rem; margin-top: 2rem; border-bottom: 1px solid #d0d7de; padding-bottom: .25rem; }
+small, .meta { color: #656d76; font-weight: normal; }
+table { border-collapse: collapse; margin: 1rem 0; }
+th, td { border: 1px solid #d0d7de; padding: .35rem .9rem; text-align: left; }
+td { font-weight: 600; font-variant-numeric: tabular-nums; }
+details { margin: .5rem 0; border: 1px solid #d0d7de; border-radius: 6px; padding: .4rem .8rem; }
+summary { cursor: pointer; }
+ul { margin: .4rem 0; padding-left: 1.4rem; }
+code { font: 13px ui-monospace, monospace; background: #f6f8fa; padding: .1rem .3rem; border-radius: 4px; }
+.error { color: #cf222e; }
+.warn { color: #9a6700; }
+.ok { color: #1a7f37; }
+.fix { color: #1a7f37; }
+";
+
+/// Single-line message for an error (target + reason when present).
+fn error_message(e: &ValidationError) -> String {
+    if e.reason.is_empty() {
+        e.target.clone()
+    } else {
+        format!("{} {}", e.target, e.reason)
+    }
+}
+
+/// Escape the message of a GitHub Actions workflow command.
+fn escape_annotation_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a GitHub Actions workflow command.
+fn escape_annotation_property(s: &str) -> String {
+    escape_annotation_data(s)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
 impl fmt::Display for ValidationReport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let pages = self.page_error_count();
+        let redirects = self.redirect_loop_count();
         let assets = self.asset_error_count();
-        let total = pages + assets;
+        let orphans = self.orphan_count();
+        let canonicals = self.canonical_error_count();
+        let externals = self.external_error_count();
+        let accessibility = self.accessibility_error_count();
+        let sitemap = self.sitemap_error_count();
+        let feed = self.feed_error_count();
+        let total = pages
+            + redirects
+            + assets
+            + orphans
+            + canonicals
+            + externals
+            + accessibility
+            + sitemap
+            + feed;
 
         if total == 0 {
             write!(f, "{}", "all checks passed".green())
@@ -126,3 +592,127 @@ impl fmt::Display for ValidationReport {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_report() -> ValidationReport {
+        let mut report = ValidationReport::default();
+        report.add_page(
+            "content/a & b.typ".to_string(),
+            "`/missing`".to_string(),
+            "not found".to_string(),
+        );
+        report.add_page(
+            "content/a & b.typ".to_string(),
+            "`/gone`".to_string(),
+            String::new(),
+        );
+        report.add_asset(
+            "content/post.typ".to_string(),
+            "`<img.png>`".to_string(),
+            "not found".to_string(),
+        );
+        report.add_orphan_asset("assets/unused.png".to_string(), 10);
+        report
+    }
+
+    #[test]
+    fn test_junit_xml() {
+        let xml = sample_report().to_junit_xml();
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains(r#"<testsuites name="tola validate" tests="2" failures="3">"#));
+        assert!(xml.contains(r#"<testsuite name="pages" tests="1" failures="2">"#));
+        assert!(xml.contains(r#"<testsuite name="orphans" tests="0" failures="0">"#));
+        assert!(xml.contains(
+            r#"<testcase name="content/a &amp; b.typ" classname="pages" file="content/a &amp; b.typ">"#
+        ));
+        assert!(xml.contains(
+            r#"<failure type="pages" message="`/missing` not found">`/missing` not found</failure>"#
+        ));
+        assert!(xml.contains(r#"message="`&lt;img.png&gt;` not found""#));
+        assert!(!xml.contains("unused.png"));
+        assert!(xml.ends_with("</testsuites>\n"));
+    }
+
+    #[test]
+    fn test_junit_xml_empty_report() {
+        let xml = ValidationReport::default().to_junit_xml();
+        assert!(xml.contains(r#"tests="0" failures="0""#));
+        assert!(!xml.contains("<testcase"));
+    }
+
+    #[test]
+    fn test_html_report() {
+        let mut report = sample_report();
+        report.files_checked = 4;
+        report
+            .suggestions
+            .insert("`/missing`".to_string(), "/missed/".to_string());
+        report.add_orphan("content/lonely.typ".to_string(), "/lonely/".to_string());
+        report.warning_sections.insert("orphans");
+
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>"));
+        assert!(!html.contains("<link"));
+        assert!(html.contains(env!("CARGO_PKG_VERSION")));
+        assert!(html.contains(r#"<tr><th>Errors</th><td class="error">3</td></tr>"#));
+        // One orphaned page and one unreferenced asset
+        assert!(html.contains(r#"<tr><th>Warnings</th><td class="warn">2</td></tr>"#));
+        assert!(html.contains("<tr><th>Files checked</th><td>4</td></tr>"));
+        assert!(html.contains("<code>content/a &amp; b.typ</code>"));
+        assert!(html.contains("did you mean <code>/missed/</code>?"));
+        assert!(html.contains("<code>`&lt;img.png&gt;`</code> not found"));
+    }
+
+    #[test]
+    fn test_html_report_empty() {
+        let html = ValidationReport::default().to_html();
+        assert!(html.contains("All checks passed."));
+        assert!(html.contains(r#"<td class="ok">0</td>"#));
+    }
+
+    #[test]
+    fn test_github_annotations() {
+        let mut report = ValidationReport::default();
+        report.add_external(
+            "content/links.typ".to_string(),
+            "https://a.test/x,y".to_string(),
+            "100%\nfailed".to_string(),
+        );
+
+        assert_eq!(
+            report.to_github_annotations(),
+            "::error file=content/links.typ,title=tola validate%3A external::https://a.test/x,y 100%25%0Afailed\n"
+        );
+    }
+
+    #[test]
+    fn test_canonical_section() {
+        let mut report = ValidationReport::default();
+        report.add_canonical(
+            "content/post.typ".to_string(),
+            ValidationError::new("`/other/`", "does not match permalink `/post/`"),
+        );
+        report.warning_sections.insert("canonical");
+
+        assert_eq!(report.canonical_file_count(), 1);
+        assert!(
+            report
+                .to_junit_xml()
+                .contains(r#"<testsuite name="canonical" tests="1" failures="1">"#)
+        );
+        assert!(
+            report
+                .to_html()
+                .contains(r#"<tr><th>Warnings</th><td class="warn">1</td></tr>"#)
+        );
+        assert_eq!(
+            report.to_github_annotations(),
+            "::warning file=content/post.typ,title=tola validate%3A canonical::`/other/` does not match permalink `/post/`\n"
+        );
+    }
+}
@@ -29,7 +29,6 @@ impl ScannedLink {
 
     /// Check if this is an HTTP/HTTPS link.
     #[inline]
-    #[allow(unused)]
     pub fn is_http(&self) -> bool {
         LinkKind::is_http(&self.dest)
     }
@@ -0,0 +1,519 @@
+//! Sitemap check of the build output.
+//!
+//! Reads the sitemap written by the last `tola build` (`site.seo.sitemap.path`,
+//! or its `-index` file when split) and reports:
+//!
+//! - XML that is not well-formed, or a root other than `<urlset>` /
+//!   `<sitemapindex>`
+//! - `<loc>` URLs that are not pages of the site
+//! - `<lastmod>` values that are not W3C datetimes (ISO 8601 subset)
+//! - more than 50,000 entries in one file
+//! - index entries whose child sitemap is missing from the output
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+use crate::compiler::page::CompiledPage;
+use crate::config::SiteConfig;
+use crate::core::UrlPath;
+use crate::log;
+use crate::seo::absolute_url;
+use crate::utils::date::DateTimeUtc;
+use crate::utils::path::route::strip_path_prefix;
+
+use super::report::{ValidationError, ValidationReport};
+
+/// Most URLs (or child sitemaps) a single sitemap file may list
+const MAX_ENTRIES: usize = 50_000;
+
+/// Check the sitemap files in the output directory, adding issues to `report`
+pub(super) fn validate_sitemap(
+    config: &SiteConfig,
+    all_pages: &[CompiledPage],
+    report: &mut ValidationReport,
+) {
+    let paths = config.paths();
+    let output_dir = paths.output_dir();
+    let sitemap = &config.site.seo.sitemap;
+
+    let entries: Vec<_> = [sitemap.numbered_path("index"), sitemap.path.clone()]
+        .into_iter()
+        .filter(|path| output_dir.join(path).is_file())
+        .collect();
+    if entries.is_empty() {
+        log!(
+            "validate";
+            "sitemap: no {} in output (run `tola build` first)",
+            sitemap.path.display()
+        );
+        return;
+    }
+
+    let check = SitemapCheck {
+        origin: absolute_url(config, ""),
+        prefix: paths.prefix().to_string_lossy().into_owned(),
+        permalinks: all_pages
+            .iter()
+            .map(|page| page.route.permalink.clone())
+            .collect(),
+    };
+    for entry in entries {
+        check.check_file(&output_dir, &entry.to_string_lossy(), true, report);
+    }
+}
+
+/// Reachability context shared by all sitemap files of a build
+struct SitemapCheck {
+    /// Scheme and host every `<loc>` starts with (empty without `site.info.url`).
+    origin: String,
+    /// `build.path_prefix`, carried by `<loc>` paths.
+    prefix: String,
+    /// Permalinks of every page in the address space.
+    permalinks: HashSet<UrlPath>,
+}
+
+impl SitemapCheck {
+    /// Check one output-relative sitemap file, following index entries
+    fn check_file(
+        &self,
+        output_dir: &Path,
+        rel: &str,
+        allow_index: bool,
+        report: &mut ValidationReport,
+    ) {
+        let xml = match fs::read_to_string(output_dir.join(rel)) {
+            Ok(xml) => xml,
+            Err(e) => {
+                report.add_sitemap(
+                    rel.to_string(),
+                    ValidationError::new(rel, format!("unreadable: {e}")),
+                );
+                return;
+            }
+        };
+        let doc = match parse_sitemap(&xml) {
+            Ok(doc) => doc,
+            Err(e) => {
+                report.add_sitemap(rel.to_string(), ValidationError::new("malformed XML", e));
+                return;
+            }
+        };
+
+        for error in self.check_doc(&doc) {
+            report.add_sitemap(rel.to_string(), error);
+        }
+        if !doc.is_index {
+            return;
+        }
+
+        for entry in &doc.entries {
+            let Some(child) = self.child_path(&entry.loc) else {
+                continue;
+            };
+            if !allow_index {
+                report.add_sitemap(
+                    rel.to_string(),
+                    ValidationError::new(&entry.loc, "nested sitemap index"),
+                );
+            } else if output_dir.join(&child).is_file() {
+                self.check_file(output_dir, &child, false, report);
+            } else {
+                report.add_sitemap(
+                    rel.to_string(),
+                    ValidationError::new(&entry.loc, "not found"),
+                );
+            }
+        }
+    }
+
+    /// Entry count, `<loc>` and `<lastmod>` issues of one document
+    ///
+    /// Page URLs must be permalinks; index URLs are checked by the caller.
+    fn check_doc(&self, doc: &SitemapDoc) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        if doc.entries.len() > MAX_ENTRIES {
+            errors.push(ValidationError::new(
+                format!("{} entries", doc.entries.len()),
+                format!("exceeds the limit of {MAX_ENTRIES}"),
+            ));
+        }
+
+        for (i, entry) in doc.entries.iter().enumerate() {
+            if entry.loc.is_empty() {
+                errors.push(ValidationError::new(
+                    format!("entry {}", i + 1),
+                    "has no <loc>",
+                ));
+                continue;
+            }
+            if let Some(lastmod) = &entry.lastmod
+                && !is_w3c_datetime(lastmod)
+            {
+                errors.push(ValidationError::new(
+                    &entry.loc,
+                    format!("invalid lastmod `{lastmod}`"),
+                ));
+            }
+            if doc.is_index {
+                continue;
+            }
+            match self.page_path(&entry.loc) {
+                None => errors.push(ValidationError::new(&entry.loc, "not on this site")),
+                Some(path) if !self.permalinks.contains(&UrlPath::from_page(path)) => {
+                    errors.push(ValidationError::new(&entry.loc, "not found"));
+                }
+                Some(_) => {}
+            }
+        }
+        errors
+    }
+
+    /// Site path of a `<loc>` URL, `None` when it points elsewhere
+    fn page_path<'a>(&self, loc: &'a str) -> Option<&'a str> {
+        let path = loc.strip_prefix(self.origin.as_str())?;
+        path.starts_with('/').then_some(path)
+    }
+
+    /// Output-relative file of a child sitemap URL
+    fn child_path(&self, loc: &str) -> Option<String> {
+        let path = self.page_path(loc)?;
+        let rel = strip_path_prefix(path, &self.prefix);
+        Some(rel.trim_start_matches('/').to_string())
+    }
+}
+
+/// `<url>` or `<sitemap>` entry
+#[derive(Debug, Default, PartialEq, Eq)]
+struct SitemapEntry {
+    loc: String,
+    lastmod: Option<String>,
+}
+
+/// Parsed `<urlset>` or `<sitemapindex>` document
+#[derive(Debug, Default, PartialEq, Eq)]
+struct SitemapDoc {
+    is_index: bool,
+    entries: Vec<SitemapEntry>,
+}
+
+/// Parse a sitemap, failing on XML that is not well-formed
+fn parse_sitemap(xml: &str) -> Result<SitemapDoc, String> {
+    let mut reader = Reader::from_str(xml);
+    let mut doc = SitemapDoc::default();
+    let mut has_root = false;
+    let mut depth = 0usize;
+    let mut entry: Option<SitemapEntry> = None;
+    // Raw (still escaped) text of the open `<loc>` / `<lastmod>`
+    let mut field: Option<(bool, String)> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("{e} (at byte {})", reader.error_position()))?;
+        match event {
+            Event::Eof => break,
+            Event::Start(e) => {
+                match (depth, e.local_name().as_ref()) {
+                    (0, name) => doc.is_index = parse_root(name, &mut has_root)?,
+                    (1, b"url" | b"sitemap") => entry = Some(SitemapEntry::default()),
+                    (2, b"loc") if entry.is_some() => field = Some((true, String::new())),
+                    (2, b"lastmod") if entry.is_some() => field = Some((false, String::new())),
+                    _ => {}
+                }
+                depth += 1;
+            }
+            Event::End(_) => {
+                depth = depth.saturating_sub(1);
+                match depth {
+                    2 => {
+                        if let (Some((is_loc, raw)), Some(entry)) = (field.take(), entry.as_mut()) {
+                            let value = quick_xml::escape::unescape(&raw)
+                                .map_err(|e| e.to_string())?
+                                .trim()
+                                .to_string();
+                            if is_loc {
+                                entry.loc = value;
+                            } else {
+                                entry.lastmod = Some(value);
+                            }
+                        }
+                    }
+                    1 => doc.entries.extend(entry.take()),
+                    _ => {}
+                }
+            }
+            Event::Empty(e) => match (depth, e.local_name().as_ref()) {
+                (0, name) => doc.is_index = parse_root(name, &mut has_root)?,
+                (1, b"url" | b"sitemap") => doc.entries.push(SitemapEntry::default()),
+                _ => {}
+            },
+            Event::Text(e) => {
+                let text = std::str::from_utf8(&e).map_err(|e| e.to_string())?;
+                if let Some((_, raw)) = field.as_mut() {
+                    raw.push_str(text);
+                } else if depth == 0 && !text.trim().is_empty() {
+                    return Err("text outside the root element".to_string());
+                }
+            }
+            Event::GeneralRef(e) => {
+                if let Some((_, raw)) = field.as_mut() {
+                    let name = std::str::from_utf8(&e).map_err(|e| e.to_string())?;
+                    raw.push('&');
+                    raw.push_str(name);
+                    raw.push(';');
+                }
+            }
+            Event::CData(e) => {
+                if let Some((_, raw)) = field.as_mut() {
+                    let text = std::str::from_utf8(&e).map_err(|e| e.to_string())?;
+                    raw.push_str(&quick_xml::escape::escape(text));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if depth > 0 {
+        return Err("unclosed element at end of file".to_string());
+    }
+    if !has_root {
+        return Err("no root element".to_string());
+    }
+    Ok(doc)
+}
+
+/// Whether a root element is `<sitemapindex>`, failing on a second root
+/// or an element other than `<urlset>`
+fn parse_root(name: &[u8], has_root: &mut bool) -> Result<bool, String> {
+    if std::mem::replace(has_root, true) {
+        return Err("multiple root elements".to_string());
+    }
+    match name {
+        b"urlset" => Ok(false),
+        b"sitemapindex" => Ok(true),
+        other => Err(format!(
+            "unexpected root element <{}>",
+            String::from_utf8_lossy(other)
+        )),
+    }
+}
+
+/// W3C datetime: `YYYY`, `YYYY-MM`, `YYYY-MM-DD` or a date with
+/// `Thh:mm[:ss[.s]]` and a `Z` / `+hh:mm` offset
+fn is_w3c_datetime(s: &str) -> bool {
+    let digits = |s: &str, n: usize| s.len() == n && s.bytes().all(|b| b.is_ascii_digit());
+    let two = |s: &str, max: u8| digits(s, 2) && s.parse::<u8>().is_ok_and(|v| v <= max);
+    if !s.is_ascii() {
+        return false;
+    }
+
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (s, None),
+    };
+    match date.len() {
+        4 if time.is_none() => return digits(date, 4),
+        7 if time.is_none() => {
+            return digits(&date[..4], 4) && date.as_bytes()[4] == b'-' && {
+                let month = &date[5..];
+                two(month, 12) && month != "00"
+            };
+        }
+        10 if DateTimeUtc::parse(date).is_some() => {}
+        _ => return false,
+    }
+    let Some(time) = time else {
+        return true;
+    };
+
+    let (clock, offset) = if let Some(clock) = time.strip_suffix('Z') {
+        (clock, None)
+    } else if let Some(pos) = time.rfind(['+', '-']) {
+        (&time[..pos], Some(&time[pos + 1..]))
+    } else {
+        return false;
+    };
+    if let Some(offset) = offset {
+        let Some((hh, mm)) = offset.split_once(':') else {
+            return false;
+        };
+        if !two(hh, 23) || !two(mm, 59) {
+            return false;
+        }
+    }
+
+    let (clock, fraction) = match clock.split_once('.') {
+        Some((clock, fraction)) => (clock, Some(fraction)),
+        None => (clock, None),
+    };
+    let mut parts = clock.split(':');
+    let (Some(hh), Some(mm)) = (parts.next(), parts.next()) else {
+        return false;
+    };
+    let ss = parts.next();
+    if parts.next().is_some() {
+        return false;
+    }
+    // Fractions only follow seconds
+    if let Some(fraction) = fraction
+        && (ss.is_none() || fraction.is_empty() || !digits(fraction, fraction.len()))
+    {
+        return false;
+    }
+    two(hh, 23) && two(mm, 59) && ss.is_none_or(|ss| two(ss, 59))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(origin: &str, prefix: &str, permalinks: &[&str]) -> SitemapCheck {
+        SitemapCheck {
+            origin: origin.to_string(),
+            prefix: prefix.to_string(),
+            permalinks: permalinks.iter().map(|p| UrlPath::from_page(p)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_sitemap() {
+        let doc = parse_sitemap(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/search?q=a&amp;b=c</loc><lastmod>2025-01-01</lastmod></url>
+  <url><loc> https://example.com/ </loc></url>
+  <url/>
+</urlset>"#,
+        )
+        .unwrap();
+        assert!(!doc.is_index);
+        assert_eq!(
+            doc.entries,
+            [
+                SitemapEntry {
+                    loc: "https://example.com/search?q=a&b=c".to_string(),
+                    lastmod: Some("2025-01-01".to_string()),
+                },
+                SitemapEntry {
+                    loc: "https://example.com/".to_string(),
+                    lastmod: None,
+                },
+                SitemapEntry::default(),
+            ]
+        );
+
+        let index = parse_sitemap(
+            "<sitemapindex><sitemap><loc>https://example.com/sitemap-0.xml</loc></sitemap></sitemapindex>",
+        )
+        .unwrap();
+        assert!(index.is_index);
+        assert_eq!(index.entries.len(), 1);
+
+        // An empty site still has a valid sitemap
+        assert!(parse_sitemap("<urlset/>").unwrap().entries.is_empty());
+    }
+
+    #[test]
+    fn test_parse_sitemap_malformed() {
+        for xml in [
+            "",
+            "<urlset><url></urlset>",
+            "<urlset><url><loc>/a/</loc></url>",
+            "<urlset></urlset><urlset></urlset>",
+            "<rss></rss>",
+            "<urlset><url><loc>a &bogus; b</loc></url></urlset>",
+        ] {
+            assert!(parse_sitemap(xml).is_err(), "{xml:?}");
+        }
+    }
+
+    #[test]
+    fn test_check_doc() {
+        let check = check("https://example.com", "blog", &["/blog/", "/blog/post/"]);
+        let entry = |loc: &str, lastmod: Option<&str>| SitemapEntry {
+            loc: loc.to_string(),
+            lastmod: lastmod.map(str::to_string),
+        };
+        let doc = SitemapDoc {
+            is_index: false,
+            entries: vec![
+                entry("https://example.com/blog/", Some("2025-01-01")),
+                entry("https://example.com/blog/post/", Some("2025-02-30")),
+                entry("https://example.com/blog/gone/", None),
+                entry("https://other.com/blog/", None),
+                entry("", None),
+            ],
+        };
+
+        let errors: Vec<_> = check
+            .check_doc(&doc)
+            .into_iter()
+            .map(|e| format!("{} {}", e.target, e.reason))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                "https://example.com/blog/post/ invalid lastmod `2025-02-30`",
+                "https://example.com/blog/gone/ not found",
+                "https://other.com/blog/ not on this site",
+                "entry 5 has no <loc>",
+            ]
+        );
+
+        let doc = SitemapDoc {
+            is_index: false,
+            entries: (0..=MAX_ENTRIES)
+                .map(|_| entry("https://example.com/blog/", None))
+                .collect(),
+        };
+        let errors = check.check_doc(&doc);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].target, "50001 entries");
+    }
+
+    #[test]
+    fn test_child_path() {
+        let check = check("https://example.com", "blog", &[]);
+        assert_eq!(
+            check.child_path("https://example.com/blog/sitemap-0.xml"),
+            Some("sitemap-0.xml".to_string())
+        );
+        assert_eq!(check.child_path("https://other.com/sitemap-0.xml"), None);
+    }
+
+    #[test]
+    fn test_is_w3c_datetime() {
+        for valid in [
+            "2025",
+            "2025-06",
+            "2025-06-15",
+            "2024-02-29",
+            "2025-06-15T14:30Z",
+            "2025-06-15T14:30:45Z",
+            "2025-06-15T14:30:45.123+02:00",
+            "2025-06-15T14:30:45-05:30",
+        ] {
+            assert!(is_w3c_datetime(valid), "{valid:?}");
+        }
+        for invalid in [
+            "",
+            "25",
+            "2025-13",
+            "2025-6-15",
+            "2025-02-29",
+            "2025/06/15",
+            "2025-06-15T14:30:45",
+            "2025-06-15T24:00Z",
+            "2025-06-15T14:30.5Z",
+            "2025-06-15T14:30:45+0200",
+            "2025-06T14:30Z",
+            "June 15, 2025",
+        ] {
+            assert!(!is_w3c_datetime(invalid), "{invalid:?}");
+        }
+    }
+}
@@ -79,6 +79,25 @@ impl DependencyGraph {
         self.reverse.get(file)
     }
 
+    /// Get content files that depend on the given file, transitively.
+    ///
+    /// Follows reverse edges through content files that are themselves
+    /// imported (e.g. a page importing another page's exports).
+    pub fn dependents_of(&self, file: &Path) -> PathSet {
+        let mut found = PathSet::default();
+        let mut queue = vec![file.to_path_buf()];
+
+        while let Some(current) = queue.pop() {
+            for dependent in self.used_by(&current).into_iter().flatten() {
+                if dependent.as_path() != file && found.insert(dependent.clone()) {
+                    queue.push(dependent.clone());
+                }
+            }
+        }
+
+        found
+    }
+
     /// Get dependencies of a content file.
     #[inline]
     pub fn uses(&self, content_file: &Path) -> Option<&PathSet> {
@@ -277,11 +296,13 @@ pub use parallel::{
 /// Collect all pages that depend on any `@tola/*` virtual package.
 ///
 /// Used by both initial build (serve/build.rs) and hot-reload (CompilerActor)
-/// to recompile pages whose data may have changed.
+/// to recompile pages whose data may have changed. `@tola/build` is skipped:
+/// its constants are fixed for the whole session.
 pub fn collect_virtual_dependents() -> rustc_hash::FxHashSet<std::path::PathBuf> {
     use crate::package::TolaPackage;
     TolaPackage::all()
         .iter()
+        .filter(|pkg| **pkg != TolaPackage::Build)
         .flat_map(|pkg| get_dependents(&pkg.sentinel()))
         .collect()
 }
@@ -416,5 +437,39 @@ mod tests {
             let graph = DependencyGraph::new();
             assert!(graph.used_by(&path("/nonexistent.typ")).is_none());
         }
+
+        #[test]
+        fn dependents_of_is_transitive() {
+            let mut graph = DependencyGraph::new();
+
+            let template = path("/templates/tola.typ");
+            let shared = path("/content/shared.typ");
+            let post = path("/content/post.typ");
+            let other = path("/content/other.typ");
+
+            graph.record(&shared, std::slice::from_ref(&template));
+            graph.record(&post, std::slice::from_ref(&shared));
+            graph.record(&other, std::slice::from_ref(&path("/utils/date.typ")));
+
+            let dependents = graph.dependents_of(&template);
+            assert_eq!(dependents.len(), 2);
+            assert!(dependents.contains(&shared));
+            assert!(dependents.contains(&post));
+            assert!(graph.dependents_of(&post).is_empty());
+        }
+
+        #[test]
+        fn dependents_of_handles_cycles() {
+            let mut graph = DependencyGraph::new();
+
+            let a = path("/content/a.typ");
+            let b = path("/content/b.typ");
+            graph.record(&a, std::slice::from_ref(&b));
+            graph.record(&b, std::slice::from_ref(&a));
+
+            let dependents = graph.dependents_of(&a);
+            assert_eq!(dependents.len(), 1);
+            assert!(dependents.contains(&b));
+        }
     }
 }
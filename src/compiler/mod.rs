@@ -4,13 +4,16 @@ pub mod dependency;
 pub mod family;
 pub mod page;
 pub mod scheduler;
+pub mod timeout;
 
-use jwalk::WalkDir;
+use jwalk::{Parallelism, WalkDir};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use crate::config::SiteConfig;
 use crate::core::BuildMode;
-use crate::page::StoredPageMap;
+use crate::page::{PageMeta, StoredPageMap};
 use page::{PageRoute, TypstHost};
 
 /// Context for the compilation pipeline
@@ -25,6 +28,11 @@ pub struct CompileContext<'a> {
     /// Default: `true`. Set to `false` for pages like 404 that need
     /// self-contained styles to avoid relative path issues.
     pub global_header: bool,
+    /// Page metadata, used for `site.info.title_template` and JSON-LD.
+    pub page_meta: Option<&'a PageMeta>,
+    /// Extra `sys.inputs` keys for the page (`permalink`, `path` and scalar
+    /// `extra` metadata), see [`crate::package::page_variables`].
+    pub variables: HashMap<String, String>,
 }
 
 impl<'a> CompileContext<'a> {
@@ -42,14 +50,34 @@ impl<'a> CompileContext<'a> {
             route: None,
             current_context: None,
             global_header: true,
+            page_meta: None,
+            variables: HashMap::new(),
         }
     }
 
+    /// Set the route, and the page variables from the stored page metadata.
     pub fn with_route(mut self, route: &'a PageRoute) -> Self {
+        let permalink = self
+            .store
+            .get_permalink_by_source(&route.source)
+            .unwrap_or_else(|| route.permalink.clone());
+        let meta = self.store.get_page(&permalink).map(|page| page.meta);
+        self.variables = crate::package::page_variables(
+            self.config,
+            &permalink,
+            crate::package::source_path_rel(self.config, &route.source).as_deref(),
+            meta.as_ref(),
+        );
         self.route = Some(route);
         self
     }
 
+    /// Replace the page variables (e.g. with freshly scanned metadata).
+    pub fn with_variables(mut self, variables: HashMap<String, String>) -> Self {
+        self.variables = variables;
+        self
+    }
+
     pub fn with_current_context(mut self, current_context: &'a serde_json::Value) -> Self {
         self.current_context = Some(current_context);
         self
@@ -72,14 +100,30 @@ const IGNORED_FILES: &[&str] = &[".DS_Store"];
 
 /// Collect all files from a directory recursively
 pub fn collect_all_files(dir: &Path) -> Vec<PathBuf> {
-    WalkDir::new(dir)
-        .into_iter()
-        .filter_map(Result::ok)
-        .filter(|e| e.file_type().is_file())
-        .filter(|e| {
-            let name = e.file_name().to_str().unwrap_or_default();
-            !IGNORED_FILES.contains(&name)
-        })
-        .map(|e| e.path())
-        .collect()
+    // jwalk gives up when no rayon worker frees up in time (single core, or
+    // assets processed alongside), so fall back to a serial walk.
+    walk_files(
+        dir,
+        Parallelism::RayonDefaultPool {
+            busy_timeout: Duration::from_secs(1),
+        },
+    )
+    .unwrap_or_else(|| walk_files(dir, Parallelism::Serial).unwrap_or_default())
+}
+
+/// Walk `dir`, returning `None` if the thread pool was too busy
+fn walk_files(dir: &Path, parallelism: Parallelism) -> Option<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(dir).parallelism(parallelism) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) if e.is_busy() => return None,
+            Err(_) => continue,
+        };
+        let name = entry.file_name().to_str().unwrap_or_default();
+        if entry.file_type().is_file() && !IGNORED_FILES.contains(&name) {
+            files.push(entry.path());
+        }
+    }
+    Some(files)
 }
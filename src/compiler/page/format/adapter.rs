@@ -13,6 +13,9 @@ use super::super::output::{PageCompileOutput, PageScanOutput};
 /// Each format implements this trait to provide unified compilation and
 /// lightweight scanning capabilities.
 pub trait PageFormat {
+    /// Format name used in logs and error messages (e.g. `"typst"`).
+    fn name() -> &'static str;
+
     /// Compile content to HTML via VDOM pipeline.
     fn compile(path: &Path, ctx: &CompileContext<'_>) -> Result<PageCompileOutput>;
 
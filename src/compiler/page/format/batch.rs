@@ -44,6 +44,11 @@ impl<'a> PageScanResult<'a> {
             if total_errors > 1 {
                 crate::log!("error"; "... and {} more errors", total_errors - 1);
             }
+        } else if crate::logger::json::is_json_diagnostics() {
+            // Recorded for the JSON lines written once the build fails
+            for (_, error) in self.errors.iter().take(max_errors) {
+                let _ = super::super::format_compile_error(error, max_errors);
+            }
         } else {
             for (path, error) in self.errors.iter().take(max_errors) {
                 let display_path = path.strip_prefix(root).unwrap_or(path);
@@ -76,7 +81,8 @@ pub fn scan_pages<'a>(
 
     let typst_result =
         super::super::typst::filter_drafts(typst_files, root, typst_host, label, config);
-    let md_result = super::super::markdown::filter_markdown_drafts(markdown_files, root, label);
+    let md_result =
+        super::super::markdown::filter_markdown_drafts(markdown_files, root, label, config);
     let drafts_skipped = typst_result.draft_count + md_result.draft_count;
 
     PageScanResult {
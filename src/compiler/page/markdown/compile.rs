@@ -6,7 +6,7 @@ use anyhow::Result;
 use typst_batch::Diagnostics;
 
 use crate::compiler::CompileContext;
-use crate::compiler::page::PageCompileOutput;
+use crate::compiler::page::{PageCompileOutput, apply_reading_time};
 use crate::pipeline::compile as pipeline_compile;
 
 use super::{MarkdownMetaExtractor, MarkdownOptions, from_markdown};
@@ -18,7 +18,7 @@ pub fn compile(path: &Path, ctx: &CompileContext<'_>) -> Result<PageCompileOutpu
 
     // Extract metadata from frontmatter
     let extractor = MarkdownMetaExtractor;
-    let (meta, body) = match extractor.extract_frontmatter(&source)? {
+    let (mut meta, body) = match extractor.extract_frontmatter(&source)? {
         Some((meta, body)) => (Some(meta), body.to_string()),
         None => (None, source),
     };
@@ -29,10 +29,13 @@ pub fn compile(path: &Path, ctx: &CompileContext<'_>) -> Result<PageCompileOutpu
     // Convert markdown to Raw VDOM
     let options = MarkdownOptions::all();
     let raw_doc = from_markdown(&body, &options);
+    apply_reading_time(&mut meta, &raw_doc, ctx.config);
 
-    // Create compile context with global_header setting
+    // Create compile context with global_header and page metadata
     let compile_ctx = CompileContext {
         global_header,
+        page_meta: meta.as_ref(),
+        variables: ctx.variables.clone(),
         ..*ctx
     };
 
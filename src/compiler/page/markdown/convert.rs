@@ -504,6 +504,7 @@ impl MarkdownMetaExtractor {
                     "date" => meta.date = Some(value.to_string()),
                     "update" => meta.update = Some(value.to_string()),
                     "author" => meta.author = Some(value.to_string()),
+                    "schema_type" | "schema-type" => meta.schema_type = Some(value.to_string()),
                     "summary" => meta.summary = Some(serde_json::Value::String(value.to_string())),
                     "draft" => meta.draft = value.eq_ignore_ascii_case("true"),
                     "tags" => {
@@ -6,6 +6,7 @@ use rayon::prelude::*;
 
 use super::convert::MarkdownMetaExtractor;
 use crate::compiler::page::format::{ScannedHeading, ScannedPage, ScannedPageLink};
+use crate::config::SiteConfig;
 use crate::core::{LinkKind, LinkOrigin};
 use crate::page::{PageKind, PageMeta};
 
@@ -20,7 +21,12 @@ pub struct MarkdownFilterResult {
 /// Filter Markdown files, removing drafts
 ///
 /// Also collects metadata and extracts links for pre-scan optimization
-pub fn filter_drafts(files: &[&PathBuf], _root: &Path, _label: &str) -> MarkdownFilterResult {
+pub fn filter_drafts(
+    files: &[&PathBuf],
+    _root: &Path,
+    _label: &str,
+    config: &SiteConfig,
+) -> MarkdownFilterResult {
     let results: Vec<_> = files
         .par_iter()
         .filter_map(|path| {
@@ -36,7 +42,7 @@ pub fn filter_drafts(files: &[&PathBuf], _root: &Path, _label: &str) -> Markdown
     let mut draft_count = 0;
 
     for (path, meta, links, headings) in results {
-        if meta.as_ref().map(|m| m.draft).unwrap_or(false) {
+        if meta.as_ref().is_some_and(|m| m.is_hidden_draft(config)) {
             draft_count += 1;
         } else {
             scanned.push(ScannedPage {
@@ -31,6 +31,10 @@ pub use filter::{extract_headings, extract_links};
 pub struct Markdown;
 
 impl PageFormat for Markdown {
+    fn name() -> &'static str {
+        "markdown"
+    }
+
     fn compile(path: &Path, ctx: &CompileContext<'_>) -> Result<PageCompileOutput> {
         compile::compile(path, ctx)
     }
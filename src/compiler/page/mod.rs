@@ -9,6 +9,7 @@
 //! - [`format`] - Format adapter trait and pre-scan data
 //! - [`output`] - Compilation output types (PageCompileOutput, PageScanOutput)
 //! - [`cache`] - Build VDOM cache
+//! - [`timing`] - Per-page compile timings
 //! - [`warning`] - Compilation warnings collection
 //! - [`process`] - Build and compile processes (batch, single, conflict)
 //! - [`markdown`] - Markdown format implementation
@@ -19,6 +20,7 @@ mod format;
 pub mod markdown;
 mod output;
 mod process;
+mod timing;
 pub mod typst;
 mod warning;
 mod write;
@@ -37,16 +39,17 @@ pub use format::{
     scan_single_page,
 };
 pub use output::{PageCompileOutput, PageScanOutput};
-pub use process::collect_content_files;
+pub use process::collect_site_content_files;
 pub use process::{
     GlobalStateMode, build_address_space, build_static_pages, populate_pages,
     rebuild_iterative_pages,
 };
 pub use process::{PageStateEpoch, PageStateTicket};
 pub(crate) use process::{PreparedPage, commit_page_state_parts, prepare_page};
+pub use timing::PAGE_TIMINGS;
 pub use typst::TypstHost;
 pub use typst::process_result as process_typst_result;
-pub use warning::{WarningCollector, format_warning_with_prefix};
+pub use warning::{BUILD_ERRORS, WarningCollector, format_warning_with_prefix};
 pub use write::{write_page_html, write_redirects};
 
 // Re-export page domain types
@@ -64,8 +67,8 @@ pub fn compile(path: &Path, ctx: &CompileContext<'_>) -> Result<PageCompileOutpu
         .ok_or_else(|| anyhow::anyhow!("unsupported content type: {:?}", path))?;
 
     match kind {
-        ContentKind::Typst => typst::Typst::compile(path, ctx),
-        ContentKind::Markdown => markdown::Markdown::compile(path, ctx),
+        ContentKind::Typst => compile_with::<typst::Typst>(path, ctx),
+        ContentKind::Markdown => compile_with::<markdown::Markdown>(path, ctx),
     }
 }
 
@@ -77,11 +80,50 @@ pub fn scan(path: &Path, ctx: &CompileContext<'_>) -> Result<PageScanOutput> {
         .ok_or_else(|| anyhow::anyhow!("unsupported content type: {:?}", path))?;
 
     match kind {
-        ContentKind::Typst => typst::Typst::scan(path, ctx),
-        ContentKind::Markdown => markdown::Markdown::scan(path, ctx),
+        ContentKind::Typst => scan_with::<typst::Typst>(path, ctx),
+        ContentKind::Markdown => scan_with::<markdown::Markdown>(path, ctx),
     }
 }
 
+/// Scan any content file and return its body text
+///
+/// Shared by `tola stats` and `tola query --stats` so their word counts
+/// match `reading_time_minutes`.
+pub fn scan_text(path: &Path, ctx: &CompileContext<'_>) -> Result<String> {
+    scan(path, ctx).map(|output| crate::page::reading::body_text(&output.indexed_vdom))
+}
+
+/// Compile with a format adapter, naming the format in errors
+fn compile_with<F: PageFormat>(path: &Path, ctx: &CompileContext<'_>) -> Result<PageCompileOutput> {
+    F::compile(path, ctx).map_err(|e| anyhow::anyhow!("{} compile failed: {:#}", F::name(), e))
+}
+
+/// Scan with a format adapter, naming the format in errors
+fn scan_with<F: PageFormat>(path: &Path, ctx: &CompileContext<'_>) -> Result<PageScanOutput> {
+    F::scan(path, ctx).map_err(|e| anyhow::anyhow!("{} scan failed: {:#}", F::name(), e))
+}
+
+/// Fill `reading_time_minutes` from the document text when enabled
+///
+/// Counts text inside `<body>` (or the whole document if there is none).
+/// Pages without metadata are left untouched.
+pub(crate) fn apply_reading_time(
+    meta: &mut Option<crate::page::PageMeta>,
+    doc: &crate::compiler::family::RawDocument,
+    config: &crate::config::SiteConfig,
+) {
+    if !config.build.meta.reading_time {
+        return;
+    }
+    let Some(meta) = meta else { return };
+
+    let text = crate::page::reading::body_text(doc);
+    meta.reading_time_minutes = Some(crate::page::reading::reading_time_minutes(
+        &text,
+        config.site.info.reading_speed,
+    ));
+}
+
 // ============================================================================
 // Type Aliases
 // ============================================================================
@@ -110,12 +152,18 @@ pub type BatchCompileResult =
 /// from a single syntax error
 pub fn format_compile_error(error: &typst_batch::CompileError, max_errors: usize) -> anyhow::Error {
     match error.diagnostics() {
-        Some(diags) => anyhow::anyhow!("{}", diags.with_max_errors(max_errors)),
+        Some(diags) => {
+            if crate::logger::json::is_json() || crate::logger::json::is_json_diagnostics() {
+                BUILD_ERRORS.collect(diags);
+            }
+            anyhow::anyhow!("{}", diags.with_max_errors(max_errors))
+        }
         None => anyhow::anyhow!("{}", error),
     }
 }
 
-/// Compilation statistics: counts of direct, iterative, and skipped draft pages
+/// Compilation statistics: counts of direct, iterative, cached, and skipped draft pages,
+/// plus compiled pages per format
 #[derive(Debug, Clone, Copy, Default)]
 #[allow(dead_code)]
 pub struct CompileStats {
@@ -125,19 +173,40 @@ pub struct CompileStats {
     pub iterative_pages: usize,
     /// Number of draft pages skipped.
     pub drafts_skipped: usize,
+    /// Number of unchanged pages reused from the cache instead of compiled.
+    pub cache_hits: usize,
+    /// Number of Typst pages compiled.
+    pub typst_pages: usize,
+    /// Number of Markdown pages compiled.
+    pub markdown_pages: usize,
 }
 
 impl CompileStats {
     /// Create new stats with the given counts.
     #[inline]
-    pub fn new(direct_pages: usize, iterative_pages: usize, drafts_skipped: usize) -> Self {
+    pub fn new(
+        direct_pages: usize,
+        iterative_pages: usize,
+        drafts_skipped: usize,
+        cache_hits: usize,
+    ) -> Self {
         Self {
             direct_pages,
             iterative_pages,
             drafts_skipped,
+            cache_hits,
+            ..Self::default()
         }
     }
 
+    /// Set the number of compiled pages per format.
+    #[inline]
+    pub fn with_formats(mut self, typst_pages: usize, markdown_pages: usize) -> Self {
+        self.typst_pages = typst_pages;
+        self.markdown_pages = markdown_pages;
+        self
+    }
+
     /// Check if any drafts were skipped.
     #[inline]
     pub fn has_skipped_drafts(&self) -> bool {
@@ -7,14 +7,16 @@ use crate::asset::{scan_content_assets, scan_flatten_assets, scan_global_assets}
 use crate::compiler::dependency::{flush_thread_local_deps, record_dependencies_local};
 use crate::compiler::page::write::write_page;
 use crate::compiler::page::{
-    BatchCompileResult, CompileStats, FileSnapshot, MetadataResult, ScannedPage, TypstBatcher,
-    TypstHost, WarningCollector, cache_vdom, format_compile_error, scan_pages, write_redirects,
+    BUILD_CACHE, CompileStats, FileSnapshot, MetadataResult, PAGE_TIMINGS, ScannedPage,
+    TypstBatcher, TypstHost, WarningCollector, cache_vdom, format_compile_error, scan_pages,
+    write_redirects,
 };
 use crate::compiler::page::{PageCompileOutput, compile, process_typst_result};
+use crate::compiler::timeout::{BatchEvent, run_batch_with_timeout};
 use crate::compiler::{CompileContext, collect_all_files};
 use crate::config::SiteConfig;
 use crate::core::{BuildMode, ContentKind, UrlPath};
-use crate::freshness::ContentHash;
+use crate::freshness::{ContentHash, is_fresh};
 use crate::logger::ProgressLine;
 use crate::package::{
     build_visible_current_context_for_source, build_visible_inputs, package_sentinel,
@@ -24,10 +26,15 @@ use crate::page::{
     HashStabilityTracker, PageState, StabilityDecision, StaleLinkPolicy, StoredPageMap,
     resolve_page_link_target,
 };
+use crate::utils::path::normalize_path;
 use crate::utils::path::slug::slugify_fragment;
 use anyhow::Result;
 use rayon::prelude::*;
+use rustc_hash::FxHashSet;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
 
 struct BuildContext<'a> {
     mode: BuildMode,
@@ -41,6 +48,7 @@ struct BuildContext<'a> {
 }
 
 impl<'a> BuildContext<'a> {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         mode: BuildMode,
         config: &'a SiteConfig,
@@ -113,6 +121,7 @@ impl GlobalStateMode {
 ///
 /// `global_state` controls whether this build owns page storage/address-space
 /// rebuilding or reuses state that a separate scan phase already populated.
+#[allow(clippy::too_many_arguments)]
 pub fn build_static_pages(
     mode: BuildMode,
     config: &SiteConfig,
@@ -161,6 +170,7 @@ pub fn build_static_pages(
         .map(|build| build.metadata)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_static_pages_with_store(
     mode: BuildMode,
     config: &SiteConfig,
@@ -182,7 +192,7 @@ fn build_static_pages_with_store(
         global_state,
         warnings,
     );
-    let content_files = collect_content_files(&config.build.content);
+    let content_files = collect_site_content_files(config);
     let (typst_files, markdown_files) = ContentKind::partition_by_kind(&content_files);
 
     // Always pre-scan to collect metadata and identify iterative pages
@@ -194,16 +204,45 @@ fn build_static_pages_with_store(
 
     // Get paths and identify iterative pages from scan results
     let (scanned_typst, scanned_md) = ScannedPage::partition_by_kind(&scan_result.scanned);
-    let typst_paths: Vec<&PathBuf> = scanned_typst.iter().map(|s| &s.path).collect();
-    let markdown_paths: Vec<&PathBuf> = scanned_md.iter().map(|s| &s.path).collect();
+    // `--only` restricts compilation; unselected pages are still routed below
+    let selected = |path: &Path| config.build.is_selected(path);
+
+    // Unchanged direct pages keep their output and skip compilation
+    let cached = if clean {
+        Vec::new()
+    } else {
+        find_cached_pages(&scan_result.scanned, config, deps_hash, progress)?
+    };
+    let cached_paths: FxHashSet<&Path> = cached.iter().map(|(path, _)| *path).collect();
+    let compiled = |path: &Path| selected(path) && !cached_paths.contains(path);
+
+    let typst_paths: Vec<&PathBuf> = scanned_typst
+        .iter()
+        .map(|s| &s.path)
+        .filter(|p| compiled(p))
+        .collect();
+    let markdown_paths: Vec<&PathBuf> = scanned_md
+        .iter()
+        .map(|s| &s.path)
+        .filter(|p| compiled(p))
+        .collect();
 
     let iterative_paths: Vec<PathBuf> = scan_result
         .scanned
         .iter()
-        .filter(|s| s.kind.is_iterative())
+        .filter(|s| s.kind.is_iterative() && selected(&s.path))
         .map(|s| s.path.clone())
         .collect();
 
+    // Unselected pages keep their existing output but still need routes
+    // for conflict detection and the address space (link validation)
+    let unselected: Vec<CompiledPage> = scan_result
+        .scanned
+        .iter()
+        .filter(|s| !selected(&s.path))
+        .map(|s| CompiledPage::from_paths_with_meta(&s.path, config, s.meta.clone()))
+        .collect::<Result<_>>()?;
+
     // Populate page store from scan results BEFORE compilation.
     if ctx.rebuilds_global_state() {
         populate_pages(&scan_result.scanned, config, store);
@@ -217,24 +256,22 @@ fn build_static_pages_with_store(
     // Always compile with per-file @tola/current context to keep build
     // behavior aligned with serve and avoid scan-time under-detection when
     // current-dependent code only appears in page body.
-    let batch = create_batch_with_inputs(
-        config.get_root(),
-        typst_host,
-        &typst_paths,
-        snapshot,
-        inputs,
-    )?;
-    let typst_results =
-        compile_typst_batch_with_context(&batch, &typst_paths, config, store, progress)?;
+    let (typst_results, snapshot) =
+        compile_typst_batch_with_context(&ctx, &typst_paths, snapshot, inputs, progress)?;
 
     let typst_processed = process_typst_files(&ctx, &typst_paths, typst_results);
     let markdown_processed = process_markdown_files(&ctx, &markdown_paths, progress);
 
     // Collect results - iterative pages already compiled with complete data
-    let (pages, _) = collect_results(typst_processed, markdown_processed)?;
+    let (mut pages, _) = collect_results(typst_processed, markdown_processed)?;
 
     flush_thread_local_deps();
 
+    let compiled_count = pages.len();
+    let cache_hits = cached.len();
+    pages.extend(unselected);
+    pages.extend(cached.into_iter().map(|(_, page)| page));
+
     let url_sources = conflict::collect_url_sources(&pages, config);
 
     let conflicts = conflict::detect_conflicts(&url_sources, config.get_root());
@@ -253,27 +290,82 @@ fn build_static_pages_with_store(
 
     // Write non-iterative pages only (iterative pages will be written by rebuild_iterative_pages)
     write_static_pages(
-        &pages,
+        &pages[..compiled_count],
         &iterative_paths,
         clean,
         deps_hash,
         &config.build.output,
     )?;
 
-    let snapshot = batch.and_then(|b: TypstBatcher| b.snapshot());
     let iterative_count = iterative_paths.len();
-    let direct_count = pages.len() - iterative_count;
+    let direct_count = compiled_count - iterative_count;
 
     Ok(StaticBuild {
         metadata: MetadataResult {
             iterative_paths,
-            stats: CompileStats::new(direct_count, iterative_count, drafts_skipped),
+            stats: CompileStats::new(direct_count, iterative_count, drafts_skipped, cache_hits)
+                .with_formats(typst_paths.len(), markdown_paths.len()),
             snapshot,
         },
         pages,
     })
 }
 
+/// Find selected direct pages that can skip compilation
+///
+/// A page is reused when its source and dependency hashes match the cache
+/// index and its output still carries the current hash marker. Iterative
+/// pages always recompile, since they read metadata of other pages.
+///
+/// Cached VDOM and dependency entries are restored so the next cache persist
+/// keeps them.
+fn find_cached_pages<'a>(
+    scanned: &'a [ScannedPage],
+    config: &SiteConfig,
+    deps_hash: Option<ContentHash>,
+    progress: Option<&ProgressLine>,
+) -> Result<Vec<(&'a Path, CompiledPage)>> {
+    let root = config.get_root();
+    let unchanged = crate::cache::get_unchanged_files(root);
+    if unchanged.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut cached = Vec::new();
+    for entry in scanned {
+        if entry.kind.is_iterative()
+            || !config.build.is_selected(&entry.path)
+            || !unchanged.contains(&normalize_path(&entry.path))
+        {
+            continue;
+        }
+        let page = CompiledPage::from_paths_with_meta(&entry.path, config, entry.meta.clone())?;
+        if !is_fresh(&page.route.source, &page.route.output_file, deps_hash) {
+            continue;
+        }
+        if let Some(p) = progress {
+            match ContentKind::from_path(&entry.path) {
+                Some(ContentKind::Markdown) => p.inc("markdown"),
+                _ => p.inc("typst"),
+            }
+        }
+        PAGE_TIMINGS.mark_cached(&page.route.source);
+        cached.push((entry.path.as_path(), page));
+    }
+
+    if !cached.is_empty() {
+        if let Err(e) = crate::cache::restore_cache(&BUILD_CACHE, root) {
+            crate::debug!("build"; "failed to restore vdom cache: {}", e);
+        }
+        if let Err(e) = crate::cache::restore_dependency_graph(root) {
+            crate::debug!("build"; "failed to restore dependency graph: {}", e);
+        }
+        crate::debug!("build"; "{} unchanged page(s) reused from cache", cached.len());
+    }
+
+    Ok(cached)
+}
+
 /// Maximum iterations for metadata convergence
 const MAX_ITERATIONS: usize = 5;
 
@@ -283,6 +375,9 @@ const MAX_ITERATIONS: usize = 5;
 /// - Compile with current page-store data
 /// - Check if metadata changed (via hash)
 /// - Repeat until convergence or max iterations
+///
+/// `progress_fn` is called with `(completed, total)` after each page of a pass.
+#[allow(clippy::too_many_arguments)]
 pub fn rebuild_iterative_pages(
     mode: BuildMode,
     paths: &[PathBuf],
@@ -293,6 +388,7 @@ pub fn rebuild_iterative_pages(
     deps_hash: Option<ContentHash>,
     snapshot: Option<FileSnapshot>,
     warnings: &WarningCollector,
+    progress_fn: Option<&(dyn Fn(usize, usize) + Sync)>,
 ) -> Result<Vec<CompiledPage>> {
     if paths.is_empty() {
         return Ok(vec![]);
@@ -314,32 +410,37 @@ pub fn rebuild_iterative_pages(
     let mut stability = HashStabilityTracker::with_oscillation_detection(store.pages_hash());
     let mut pages: Vec<CompiledPage> = Vec::new();
 
+    let completed = AtomicUsize::new(0);
+    let report_progress = || {
+        let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+        if let Some(progress_fn) = progress_fn {
+            progress_fn(done, paths.len());
+        }
+    };
+
     for iteration in 0..MAX_ITERATIONS {
+        completed.store(0, Ordering::Relaxed);
         let inputs = build_site_inputs(config, store)?;
 
-        let batch = create_batch_compiler_with_inputs(
-            config.get_root(),
-            typst_host,
-            &typst_paths,
-            snapshot.clone(),
-            Some(inputs),
-        )?;
-        let typst_results =
-            compile_typst_batch_with_context(&batch, &typst_paths, config, store, None)?;
+        let (typst_results, _) =
+            compile_typst_batch_with_context(&ctx, &typst_paths, snapshot.clone(), inputs, None)?;
 
         // Process results and update page store.
-        let max_errors = ctx.max_errors();
         let typst_pages: Vec<Result<CompiledPage>> = typst_paths
             .par_iter()
             .zip(typst_results.into_par_iter())
             .map(|(path, result)| {
-                let result = result.map_err(|e| format_compile_error(&e, max_errors))?;
+                let start = Instant::now();
+                let result = result?;
                 let page = CompiledPage::from_paths(path, ctx.config)?;
                 let compile_ctx =
                     CompileContext::new(ctx.mode, ctx.config, ctx.typst_host, ctx.store)
                         .with_route(&page.route);
                 let content = process_typst_result(result, ctx.label(), &compile_ctx)?;
-                process_iterative_page(&ctx, page, content)
+                let page = process_iterative_page(&ctx, page, content)?;
+                PAGE_TIMINGS.record(&page.route.source, start.elapsed());
+                report_progress();
+                Ok(page)
             })
             .collect();
 
@@ -347,12 +448,16 @@ pub fn rebuild_iterative_pages(
         let markdown_pages: Vec<Result<CompiledPage>> = markdown_paths
             .par_iter()
             .map(|path| {
+                let start = Instant::now();
                 let page = CompiledPage::from_paths(path, ctx.config)?;
                 let compile_ctx =
                     CompileContext::new(ctx.mode, ctx.config, ctx.typst_host, ctx.store)
                         .with_route(&page.route);
                 let content = compile(path, &compile_ctx)?;
-                process_iterative_page(&ctx, page, content)
+                let page = process_iterative_page(&ctx, page, content)?;
+                PAGE_TIMINGS.record(&page.route.source, start.elapsed());
+                report_progress();
+                Ok(page)
             })
             .collect();
 
@@ -441,6 +546,15 @@ pub fn collect_content_files(content_dir: &Path) -> Vec<PathBuf> {
         .collect()
 }
 
+/// Content files from `build.content` and every `build.extra_content` directory.
+pub fn collect_site_content_files(config: &SiteConfig) -> Vec<PathBuf> {
+    config
+        .build
+        .content_dirs()
+        .flat_map(collect_content_files)
+        .collect()
+}
+
 // ============================================================================
 // Batch Compilation
 // ============================================================================
@@ -486,84 +600,147 @@ pub fn populate_pages(scanned: &[ScannedPage], config: &SiteConfig, store: &Stor
 fn create_batch_with_inputs<'a>(
     root: &'a Path,
     typst_host: &TypstHost,
-    paths: &[&'a PathBuf],
+    paths: &[&PathBuf],
     snapshot: Option<FileSnapshot>,
     inputs: typst_batch::Inputs,
-) -> Result<Option<TypstBatcher<'a>>> {
-    if paths.is_empty() {
-        return Ok(None);
-    }
-
+) -> Result<TypstBatcher<'a>> {
     let batch = typst_host.batcher(root).with_inputs_obj(inputs);
 
-    Ok(Some(if let Some(snap) = snapshot {
+    Ok(if let Some(snap) = snapshot {
         batch.with_snapshot(snap)
     } else {
         batch
             .with_snapshot_from(paths)
             .map_err(|e| anyhow::anyhow!("{}", e))?
-    }))
+    })
 }
 
 /// Compile with per-file context for @tola/current
-fn compile_typst_batch_with_context<'a>(
-    batch: &Option<TypstBatcher<'a>>,
+///
+/// Returns one result per file and the snapshot they were compiled from.
+/// With `build.compile_timeout_secs` set, the batch runs on a detached thread
+/// and a file that exceeds the timeout fails on its own while the rest of the
+/// batch completes.
+fn compile_typst_batch_with_context(
+    ctx: &BuildContext,
     files: &[&PathBuf],
-    config: &SiteConfig,
-    store: &StoredPageMap,
+    snapshot: Option<FileSnapshot>,
+    inputs: typst_batch::Inputs,
     progress: Option<&ProgressLine>,
-) -> Result<Vec<BatchCompileResult>> {
-    let Some(b) = batch else { return Ok(vec![]) };
-    let current_context_by_path: rustc_hash::FxHashMap<&Path, serde_json::Value> = files
+) -> Result<(Vec<Result<CompileResult>>, Option<FileSnapshot>)> {
+    if files.is_empty() {
+        return Ok((vec![], None));
+    }
+    let config = ctx.config;
+    let max_errors = ctx.max_errors();
+    let contexts: Vec<serde_json::Value> = files
         .iter()
-        .map(|p| {
-            let current = build_visible_current_context_for_source(config, store, p)?;
-            Ok((p.as_path(), current))
-        })
+        .map(|p| build_visible_current_context_for_source(config, ctx.store, p))
         .collect::<Result<_>>()?;
 
-    b.batch_compile_with_context(files, |path| {
-        if let Some(p) = progress {
-            p.inc("typst");
-        }
-        crate::debug!("typst"; "compiled {}", path.display());
-        current_context_by_path
-            .get(path)
-            .cloned()
-            .unwrap_or_else(|| {
-                panic!(
-                    "missing precomputed @tola/current context for {}",
-                    path.display()
-                )
-            })
-    })
-    .map_err(|e| anyhow::anyhow!("{}", e))
-}
+    let Some(timeout) = config.build.compile_timeout() else {
+        let batch =
+            create_batch_with_inputs(config.get_root(), ctx.typst_host, files, snapshot, inputs)?;
+        let current_context_by_path: rustc_hash::FxHashMap<&Path, &serde_json::Value> =
+            files.iter().map(|p| p.as_path()).zip(&contexts).collect();
+        let context_fn = |path: &Path| {
+            if let Some(p) = progress {
+                p.inc("typst");
+            }
+            crate::debug!("typst"; "compiled {}", path.display());
+            current_context_by_path
+                .get(path)
+                .map(|current| (*current).clone())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "missing precomputed @tola/current context for {}",
+                        path.display()
+                    )
+                })
+        };
+        let results = batch
+            .batch_compile_with_context(files, context_fn)
+            .map_err(|e| anyhow::anyhow!("{}", e))?;
+        let results = results
+            .into_iter()
+            .map(|result| result.map_err(|e| format_compile_error(&e, max_errors)))
+            .collect();
+        return Ok((results, batch.snapshot()));
+    };
 
-fn create_batch_compiler_with_inputs<'a>(
-    root: &'a Path,
-    typst_host: &TypstHost,
-    typst_paths: &[&'a PathBuf],
-    snapshot: Option<FileSnapshot>,
-    inputs: Option<typst_batch::Inputs>,
-) -> Result<Option<TypstBatcher<'a>>> {
-    if typst_paths.is_empty() {
-        return Ok(None);
-    }
-    let mut compiler = typst_host.batcher(root);
+    // Snapshot parsing does not hang, so its errors still fail the batch here
+    let snapshot = match snapshot {
+        Some(snapshot) => snapshot,
+        None => create_batch_with_inputs(
+            config.get_root(),
+            ctx.typst_host,
+            files,
+            None,
+            typst_batch::Inputs::empty(),
+        )?
+        .snapshot()
+        .ok_or_else(|| anyhow::anyhow!("no Typst snapshot for batch"))?,
+    };
+
+    // A stuck compile cannot be cancelled, so the batch owns everything it
+    // needs and may outlive this call.
+    let root = config.get_root().to_path_buf();
+    let typst_host = ctx.typst_host.clone();
+    let batch_snapshot = Arc::clone(&snapshot);
+    let owned_files: Vec<PathBuf> = files.iter().map(|p| (*p).clone()).collect();
+    let results = run_batch_with_timeout(
+        timeout,
+        files.len(),
+        |_| {
+            if let Some(p) = progress {
+                p.inc("typst");
+            }
+        },
+        move |tx| {
+            let batch = typst_host
+                .batcher(&root)
+                .with_inputs_obj(inputs)
+                .with_snapshot(batch_snapshot);
+            // One file per call so each result is reported as soon as it is
+            // ready. The batcher already holds the snapshot.
+            owned_files.par_iter().zip(contexts).enumerate().for_each(
+                |(index, (path, current))| {
+                    let _ = tx.send(BatchEvent::Started(index));
+                    let result = compile_one(&batch, path, current, max_errors);
+                    crate::debug!("typst"; "compiled {}", path.display());
+                    let _ = tx.send(BatchEvent::Finished(index, result));
+                },
+            );
+        },
+    );
 
-    // Inject inputs if provided
-    if let Some(inp) = inputs {
-        compiler = compiler.with_inputs_obj(inp);
-    }
+    let results = files
+        .iter()
+        .zip(results)
+        .map(|(path, result)| {
+            result.unwrap_or_else(|timed_out| {
+                Err(anyhow::anyhow!("{}: {}", path.display(), timed_out))
+            })
+        })
+        .collect();
+    Ok((results, Some(snapshot)))
+}
 
-    Ok(Some(if let Some(snap) = snapshot {
-        compiler.with_snapshot(snap)
-    } else {
-        compiler
-            .with_snapshot_from(typst_paths)
-            .map_err(|e| anyhow::anyhow!("{}", e))?
-    }))
+/// Compile a single file with the batcher's snapshot and inputs
+fn compile_one(
+    batch: &TypstBatcher<'_>,
+    path: &Path,
+    current: serde_json::Value,
+    max_errors: usize,
+) -> Result<CompileResult> {
+    let results = batch
+        .batch_compile_with_context(&[path], |_| current.clone())
+        .map_err(|e| format_compile_error(&e, max_errors))?;
+    results
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("no compile result for {}", path.display()))?
+        .map_err(|e| format_compile_error(&e, max_errors))
 }
 
 // ============================================================================
@@ -573,19 +750,22 @@ fn create_batch_compiler_with_inputs<'a>(
 fn process_typst_files(
     ctx: &BuildContext,
     files: &[&PathBuf],
-    results: Vec<BatchCompileResult>,
+    results: Vec<Result<CompileResult>>,
 ) -> Vec<Result<Option<BuildPageResult>>> {
-    let max_errors = ctx.max_errors();
     files
         .par_iter()
         .zip(results.into_par_iter())
         .map(|(path, result)| {
-            let result = result.map_err(|e| format_compile_error(&e, max_errors))?;
+            let start = Instant::now();
+            let result = result?;
             let page = CompiledPage::from_paths(path, ctx.config)?;
+            let source = page.route.source.clone();
             let compile_ctx = CompileContext::new(ctx.mode, ctx.config, ctx.typst_host, ctx.store)
                 .with_route(&page.route);
             let content = process_typst_result(result, ctx.label(), &compile_ctx)?;
-            finalize_static_page(ctx, page, content)
+            let finalized = finalize_static_page(ctx, page, content);
+            PAGE_TIMINGS.record(&source, start.elapsed());
+            finalized
         })
         .collect()
 }
@@ -598,14 +778,18 @@ fn process_markdown_files(
     files
         .par_iter()
         .map(|path| {
+            let start = Instant::now();
             let page = CompiledPage::from_paths(path, ctx.config)?;
+            let source = page.route.source.clone();
             let compile_ctx = CompileContext::new(ctx.mode, ctx.config, ctx.typst_host, ctx.store)
                 .with_route(&page.route);
             let content = compile(path, &compile_ctx)?;
             if let Some(p) = progress {
                 p.inc("markdown");
             }
-            finalize_static_page(ctx, page, content)
+            let finalized = finalize_static_page(ctx, page, content);
+            PAGE_TIMINGS.record(&source, start.elapsed());
+            finalized
         })
         .collect()
 }
@@ -637,7 +821,11 @@ fn finalize_static_page(
     ctx.warnings.collect(&result.warnings);
 
     // Skip drafts
-    if result.meta.as_ref().is_some_and(|m| m.draft) {
+    if result
+        .meta
+        .as_ref()
+        .is_some_and(|m| m.is_hidden_draft(ctx.config))
+    {
         return Ok(None);
     }
 
@@ -715,8 +903,6 @@ fn filter_direct_pages<'a>(
     pages: &'a [CompiledPage],
     iterative_paths: &[PathBuf],
 ) -> Vec<&'a CompiledPage> {
-    use rustc_hash::FxHashSet;
-
     let iterative_set: FxHashSet<&Path> = iterative_paths.iter().map(|p| p.as_path()).collect();
     pages
         .iter()
@@ -765,6 +951,18 @@ pub fn build_address_space(pages: &[CompiledPage], config: &SiteConfig, state: &
         for page in pages {
             let title = page.content_meta.as_ref().and_then(|m| m.title.clone());
             space.register_page(page.route.clone(), title);
+            if let Some(natural) = natural_permalink(page, config) {
+                space.register_natural_url(natural, page.route.permalink.clone());
+            }
+            if let Some(meta) = &page.content_meta {
+                for alias in &meta.aliases {
+                    space.register_redirect(
+                        UrlPath::from_page(alias),
+                        page.route.permalink.clone(),
+                        page.route.source.clone(),
+                    );
+                }
+            }
             let heading_ids = store
                 .get_headings(&page.route.permalink)
                 .into_iter()
@@ -789,6 +987,14 @@ pub fn build_address_space(pages: &[CompiledPage], config: &SiteConfig, state: &
     });
 }
 
+/// Path-derived permalink of a page whose `permalink` metadata overrides it.
+fn natural_permalink(page: &CompiledPage, config: &SiteConfig) -> Option<UrlPath> {
+    page.content_meta.as_ref()?.permalink.as_ref()?;
+    CompiledPage::from_paths(&page.route.source, config)
+        .ok()
+        .map(|natural| natural.route.permalink)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1089,4 +1295,43 @@ mod tests {
                 .all(|page| page.permalink != UrlPath::from_page("/compiled/"))
         );
     }
+
+    #[test]
+    fn test_build_static_pages_counts_compiled_pages_by_format() {
+        let state = GlobalStateGuard::new();
+        let site = state.state();
+
+        let dir = TempDir::new().unwrap();
+        let mut config = markdown_site(&dir);
+        fs::write(config.build.content.join("home.typ"), "= Home").unwrap();
+        fs::create_dir_all(config.build.content.join("notes")).unwrap();
+        write_markdown_page(&config, "notes/a.md", "A");
+        write_markdown_page(&config, "notes/b.md", "B");
+        let warnings = WarningCollector::new();
+        let host = typst_host(&config);
+
+        let build = |config: &SiteConfig| {
+            build_static_pages(
+                BuildMode::DEVELOPMENT,
+                config,
+                &host,
+                site,
+                true,
+                None,
+                GlobalStateMode::Rebuild,
+                &warnings,
+                None,
+            )
+            .unwrap()
+            .stats
+        };
+
+        let stats = build(&config);
+        assert_eq!((stats.typst_pages, stats.markdown_pages), (1, 2));
+
+        // Pages left out by `--only` are not counted for their format
+        config.build.only = Some("notes/a.md".to_string());
+        let stats = build(&config);
+        assert_eq!((stats.typst_pages, stats.markdown_pages), (0, 1));
+    }
 }
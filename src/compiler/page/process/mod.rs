@@ -6,7 +6,7 @@
 mod batch;
 mod single;
 
-pub use batch::collect_content_files;
+pub use batch::collect_site_content_files;
 pub use batch::{
     GlobalStateMode, build_address_space, build_static_pages, populate_pages,
     rebuild_iterative_pages,
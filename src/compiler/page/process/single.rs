@@ -12,7 +12,7 @@ use crate::compiler::page::{
 };
 use crate::config::SiteConfig;
 use crate::core::{BuildMode, UrlPath};
-use crate::package::{TolaPackage, package_sentinel};
+use crate::package::{TolaPackage, package_sentinel, page_variables};
 use crate::page::{
     CompiledPage, PageMeta, PageState, StaleLinkPolicy, StoredPage, StoredPageMap,
     resolve_page_link_target,
@@ -92,9 +92,15 @@ fn heading_ids<'a>(
 }
 
 fn relative_source_path(path: &Path, config: &SiteConfig) -> Option<String> {
-    normalize_path(path)
-        .strip_prefix(normalize_path(&config.build.content))
-        .ok()
+    let path = normalize_path(path);
+    config
+        .build
+        .content_dirs()
+        .find_map(|dir| {
+            path.strip_prefix(normalize_path(dir))
+                .ok()
+                .map(Path::to_path_buf)
+        })
         .map(|path| path.to_string_lossy().to_string())
 }
 
@@ -259,16 +265,26 @@ fn prepare_page_inner(
 
         // Compile with fresh @tola/current data ===
         let current_context = current_context_from_scan(store, path, &page, &scan_data, config);
+        let variables = page_variables(
+            config,
+            &page.route.permalink,
+            relative_source_path(path, config).as_deref(),
+            scan_data.meta.as_ref(),
+        );
         let ctx = CompileContext::new(mode, config, host, store)
             .with_route(&page.route)
-            .with_current_context(&current_context);
+            .with_current_context(&current_context)
+            .with_variables(variables);
         let result = compile(path, &ctx)?;
 
         // Extract metadata
         let content_meta: Option<PageMeta> = result.meta;
 
         // Skip drafts
-        if content_meta.as_ref().is_some_and(|m| m.draft) {
+        if content_meta
+            .as_ref()
+            .is_some_and(|m| m.is_hidden_draft(config))
+        {
             return Ok(None);
         }
 
@@ -414,6 +430,40 @@ mod tests {
         assert_eq!(meta.author, Some("Author".to_string()));
     }
 
+    #[test]
+    fn test_compile_meta_custom_label() {
+        let dir = TempDir::new().unwrap();
+        let file_path = dir.path().join("test.typ");
+
+        fs::write(
+            &file_path,
+            r#"#metadata((
+  title: "Custom",
+)) <frontmatter>
+
+= Content
+"#,
+        )
+        .unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(dir.path());
+        config.build.meta.label = "frontmatter".to_string();
+        let store = StoredPageMap::new();
+        let host = crate::compiler::page::TypstHost::for_config(&config);
+
+        let (_, meta, _indexed_vdom) =
+            compile_meta(BuildMode::DEVELOPMENT, &file_path, &config, &host, &store).unwrap();
+        assert_eq!(meta.and_then(|m| m.title), Some("Custom".to_string()));
+
+        // The default label is no longer recognized
+        config.build.meta.label = "tola-meta".to_string();
+        let host = crate::compiler::page::TypstHost::for_config(&config);
+        let (_, meta, _indexed_vdom) =
+            compile_meta(BuildMode::DEVELOPMENT, &file_path, &config, &host, &store).unwrap();
+        assert!(meta.is_none());
+    }
+
     #[test]
     fn test_compile_meta_draft_field() {
         let dir = TempDir::new().unwrap();
@@ -678,4 +728,46 @@ permalink = "/showcase/source-field-test/"
 
         reset_state(&state);
     }
+
+    #[test]
+    fn test_process_page_injects_page_variables() {
+        let dir = TempDir::new().unwrap();
+        let content_dir = dir.path().join("content");
+        fs::create_dir_all(&content_dir).unwrap();
+        let file_path = content_dir.join("post.typ");
+
+        fs::write(
+            &file_path,
+            r#"#metadata((
+  title: "Variables",
+  permalink: "/notes/vars/",
+  section: "essays",
+)) <tola-meta>
+
+permalink=#sys.inputs.at("permalink")
+path=#sys.inputs.at("path")
+section=#sys.inputs.at("section", default: "none")
+"#,
+        )
+        .unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(dir.path());
+        config.build.content = content_dir;
+        let state = SiteIndex::new();
+        let host = typst_host(&config);
+
+        reset_state(&state);
+
+        let result = process_page(BuildMode::DEVELOPMENT, &file_path, &config, &host, &state)
+            .expect("process_page should succeed")
+            .expect("page should not be filtered");
+        let html = String::from_utf8(result.page.compiled_html.unwrap()).unwrap();
+
+        assert!(html.contains("permalink=/notes/vars/"), "{html}");
+        assert!(html.contains("path=post.typ"), "{html}");
+        assert!(html.contains("section=essays"), "{html}");
+
+        reset_state(&state);
+    }
 }
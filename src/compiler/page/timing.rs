@@ -0,0 +1,76 @@
+//! Per-page compile timings for the build manifest.
+//!
+//! Typst pages are compiled in one shared batch, so their timing covers the
+//! per-page work after the batch: HTML/VDOM processing and metadata. Markdown
+//! pages are timed end to end. Iterative pages add up all passes.
+
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use dashmap::{DashMap, DashSet};
+
+/// Global timing record for the current build
+pub static PAGE_TIMINGS: LazyLock<PageTimings> = LazyLock::new(PageTimings::default);
+
+/// Compile durations and cache hits, keyed by source path
+#[derive(Default)]
+pub struct PageTimings {
+    compiled: DashMap<PathBuf, Duration>,
+    cached: DashSet<PathBuf>,
+}
+
+impl PageTimings {
+    /// Add compile time for a page.
+    pub fn record(&self, source: &Path, elapsed: Duration) {
+        *self.compiled.entry(source.to_path_buf()).or_default() += elapsed;
+    }
+
+    /// Mark a page as reused from the cache.
+    pub fn mark_cached(&self, source: &Path) {
+        self.cached.insert(source.to_path_buf());
+    }
+
+    /// Total compile time recorded for a page.
+    pub fn get(&self, source: &Path) -> Option<Duration> {
+        self.compiled.get(source).map(|d| *d)
+    }
+
+    /// Whether the page was reused from the cache.
+    pub fn is_cached(&self, source: &Path) -> bool {
+        self.cached.contains(source)
+    }
+
+    /// Number of pages compiled in this build.
+    pub fn compiled_count(&self) -> usize {
+        self.compiled.len()
+    }
+
+    pub fn clear(&self) {
+        self.compiled.clear();
+        self.cached.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_accumulates() {
+        let timings = PageTimings::default();
+        let path = Path::new("content/index.typ");
+
+        timings.record(path, Duration::from_millis(3));
+        timings.record(path, Duration::from_millis(4));
+        assert_eq!(timings.get(path), Some(Duration::from_millis(7)));
+        assert_eq!(timings.compiled_count(), 1);
+        assert!(!timings.is_cached(path));
+
+        timings.mark_cached(Path::new("content/about.typ"));
+        assert!(timings.is_cached(Path::new("content/about.typ")));
+
+        timings.clear();
+        assert_eq!(timings.get(path), None);
+    }
+}
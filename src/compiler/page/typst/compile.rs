@@ -6,7 +6,7 @@ use anyhow::Result;
 use typst_batch::prelude::*;
 
 use crate::compiler::CompileContext;
-use crate::compiler::page::{PageCompileOutput, format_compile_error};
+use crate::compiler::page::{PageCompileOutput, apply_reading_time, format_compile_error};
 use crate::package::{
     build_visible_inputs, build_visible_inputs_for_source,
     build_visible_inputs_with_current_context,
@@ -45,13 +45,19 @@ pub fn compile(path: &Path, ctx: &CompileContext<'_>) -> Result<PageCompileOutpu
     // Build inputs for virtual packages. Single-page watch compiles can pass a
     // scanned current context so templates see fresh @tola/current data without
     // publishing draft page state globally.
-    let inputs = if let Some(current_context) = ctx.current_context {
+    let mut inputs = if let Some(current_context) = ctx.current_context {
         build_visible_inputs_with_current_context(ctx.config, ctx.store, current_context)?
     } else if let Some(route) = ctx.route {
         build_visible_inputs_for_source(ctx.config, ctx.store, &route.source)?
     } else {
         build_visible_inputs(ctx.config, ctx.store)?
     };
+    // Per-page variables, e.g. `sys.inputs.at("permalink")`
+    if !ctx.variables.is_empty() {
+        inputs
+            .merge_json(&serde_json::json!(ctx.variables))
+            .map_err(|e| anyhow::anyhow!("failed to merge page variables: {}", e))?;
+    }
 
     // Compile Typst to HtmlDocument using Builder API with inputs
     let result = ctx
@@ -100,17 +106,20 @@ pub fn process_result(
     let (document, accessed, _) = result.into_parts();
 
     // Extract and convert metadata (JsonValue → PageMeta)
-    let meta: Option<PageMeta> = document.query_metadata(label).and_then(parse_page_meta);
+    let mut meta: Option<PageMeta> = document.query_metadata(label).and_then(parse_page_meta);
 
     // Get global_header from metadata (default: true)
     let global_header = meta.as_ref().is_none_or(|m| m.global_header);
 
     // Convert to Raw VDOM
     let raw_doc = from_typst_html(&document, ctx.config.build.svg.baseline_align);
+    apply_reading_time(&mut meta, &raw_doc, ctx.config);
 
-    // Create compile context with global_header setting
+    // Create compile context with global_header and page metadata
     let compile_ctx = CompileContext {
         global_header,
+        page_meta: meta.as_ref(),
+        variables: ctx.variables.clone(),
         ..*ctx
     };
 
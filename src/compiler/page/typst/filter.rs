@@ -210,7 +210,11 @@ fn filter_drafts_impl<'a>(
     let mut scanned = Vec::new();
     let mut draft_count = 0usize;
     for page in slots.into_iter().flatten() {
-        if page.meta.as_ref().is_some_and(|m| m.draft) {
+        if page
+            .meta
+            .as_ref()
+            .is_some_and(|m| m.is_hidden_draft(config))
+        {
             draft_count += 1;
             continue;
         }
@@ -360,4 +364,57 @@ mod tests {
         );
         assert_eq!(result.scanned.len(), 1);
     }
+
+    #[test]
+    fn test_filter_drafts_paginate_slices() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let content_dir = root.join("content");
+        fs::create_dir_all(&content_dir).unwrap();
+        let page = content_dir.join("archive.typ");
+
+        // Typst assertions surface as scan errors
+        fs::write(
+            &page,
+            r#"
+#import "@tola/pages:0.0.0": paginate
+#metadata((title: "Archive")) <tola-meta>
+
+#let p = paginate(range(7), 3)
+#assert.eq(p.total-pages, 3)
+#assert.eq(p.pages.map(s => s.len()), (3, 3, 1))
+#assert.eq(p.current-page, 1)
+#assert.eq(p.items, (0, 1, 2))
+#assert.eq((p.get-page)(3), (6,))
+#assert.eq((p.get-page)(0), ())
+#assert.eq((p.get-page)(4), ())
+
+#let even = paginate(range(6), 3, current: 2)
+#assert.eq(even.total-pages, 2)
+#assert.eq(even.items, (3, 4, 5))
+
+#let empty = paginate((), 10)
+#assert.eq(empty.total-pages, 1)
+#assert.eq(empty.items, ())
+= Archive
+"#,
+        )
+        .unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(root);
+        config.build.content = content_dir;
+        let host = super::super::TypstHost::for_config(&config);
+
+        let files = [page];
+        let refs = files.iter().collect::<Vec<_>>();
+        let result = filter_drafts(&refs, root, &host, "tola-meta", &config);
+
+        assert!(
+            result.errors.is_empty(),
+            "unexpected scan errors: {:?}",
+            result.errors
+        );
+        assert_eq!(result.scanned.len(), 1);
+    }
 }
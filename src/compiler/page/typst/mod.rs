@@ -36,6 +36,10 @@ pub use iterative::{MAX_METADATA_SCAN_ITERATIONS, scan_single_with_current};
 pub struct Typst;
 
 impl PageFormat for Typst {
+    fn name() -> &'static str {
+        "typst"
+    }
+
     fn compile(path: &Path, ctx: &CompileContext<'_>) -> Result<PageCompileOutput> {
         compile::compile(path, ctx)
     }
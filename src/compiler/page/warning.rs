@@ -1,11 +1,18 @@
 //! Compilation warnings collection.
 //!
 //! Warning collection is owned by the caller instead of hidden global state.
+//! The one exception is [`BUILD_ERRORS`], which keeps compile errors for the
+//! `--json` build report and JSON diagnostics after they have been turned
+//! into `anyhow` errors.
 
 use parking_lot::Mutex;
 use std::path::Path;
+use std::sync::LazyLock;
 use typst_batch::{DiagnosticInfo, Diagnostics};
 
+/// Compile errors of the current build, recorded only in JSON output modes
+pub static BUILD_ERRORS: LazyLock<WarningCollector> = LazyLock::new(WarningCollector::new);
+
 /// Compilation warning collector.
 ///
 /// Uses `Vec` to preserve all warnings; display-time code owns truncation.
@@ -26,12 +33,22 @@ impl WarningCollector {
         }
     }
 
+    /// Copy all collected warnings, keeping them for a later `drain`.
+    pub fn snapshot(&self) -> Diagnostics {
+        Diagnostics::from_vec(self.items.lock().clone())
+    }
+
     /// Drain all collected warnings.
     pub fn drain(&self) -> Diagnostics {
         let items = std::mem::take(&mut *self.items.lock());
         Diagnostics::from_vec(items)
     }
 
+    /// Drop all collected diagnostics.
+    pub fn clear(&self) {
+        self.items.lock().clear();
+    }
+
     #[cfg(test)]
     fn len(&self) -> usize {
         self.items.lock().len()
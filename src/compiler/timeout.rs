@@ -0,0 +1,249 @@
+//! Per-file compilation timeout (`build.compile_timeout_secs`).
+//!
+//! Typst compilation cannot be cancelled, so a timed-out compile keeps
+//! running on its own thread while the caller moves on:
+//!
+//! - [`run_with_timeout`]: watch mode, reports the page as failed and
+//!   continues with the queue
+//! - [`run_batch_with_timeout`]: batch builds, fails only the pages that
+//!   timed out and returns the rest
+
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use rustc_hash::FxHashMap;
+
+/// A compilation did not finish within the configured timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut(pub Duration);
+
+impl std::fmt::Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "compilation timed out after {:?}", self.0)
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Run `compile` on a dedicated thread, giving up after `timeout`.
+///
+/// Runs inline when `timeout` is `None`. Panics in `compile` are
+/// propagated to the caller.
+pub fn run_with_timeout<T, F>(timeout: Option<Duration>, compile: F) -> Result<T, TimedOut>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return Ok(compile());
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let _ = tx.send(compile());
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(value) => Ok(value),
+        Err(RecvTimeoutError::Timeout) => Err(TimedOut(timeout)),
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => std::panic::resume_unwind(payload),
+            Ok(()) => unreachable!("compile thread exited without sending a result"),
+        },
+    }
+}
+
+/// Progress of a batch running on a detached thread.
+pub enum BatchEvent<T> {
+    /// Item `index` started compiling.
+    Started(usize),
+    /// Item `index` finished with a value.
+    Finished(usize, T),
+}
+
+/// Run a batch of `len` items on a detached thread, giving up on items that
+/// take longer than `timeout`.
+///
+/// `batch` reports every item through the sender, in any order. Returns one
+/// result per item, in item order; items still running after `timeout` are
+/// [`TimedOut`] and keep running on the detached thread. Items still queued
+/// when nothing has started for `timeout` (every worker is stuck on a
+/// timed-out item) are [`TimedOut`] too. `on_start` is called on the
+/// caller's thread as each item starts. Panics in `batch` are propagated to
+/// the caller.
+pub fn run_batch_with_timeout<T, F>(
+    timeout: Duration,
+    len: usize,
+    mut on_start: impl FnMut(usize),
+    batch: F,
+) -> Vec<Result<T, TimedOut>>
+where
+    T: Send + 'static,
+    F: FnOnce(mpsc::Sender<BatchEvent<T>>) + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::spawn(move || batch(tx));
+
+    let mut results: Vec<Option<Result<T, TimedOut>>> = (0..len).map(|_| None).collect();
+    let mut running: FxHashMap<usize, Instant> = FxHashMap::default();
+    let mut pending = len;
+
+    while pending > 0 {
+        let idle = running.is_empty();
+        let now = Instant::now();
+        let wait = running
+            .values()
+            .map(|started| (*started + timeout).saturating_duration_since(now))
+            .min()
+            .unwrap_or(timeout);
+
+        match rx.recv_timeout(wait) {
+            Ok(BatchEvent::Started(index)) => {
+                running.insert(index, Instant::now());
+                on_start(index);
+            }
+            Ok(BatchEvent::Finished(index, value)) => {
+                running.remove(&index);
+                // Late results of items that already timed out are dropped
+                if results[index].is_none() {
+                    results[index] = Some(Ok(value));
+                    pending -= 1;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) if idle => {
+                // Workers still busy with timed-out items never pick up the rest
+                for result in results.iter_mut().filter(|result| result.is_none()) {
+                    *result = Some(Err(TimedOut(timeout)));
+                }
+                pending = 0;
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                let now = Instant::now();
+                running.retain(|&index, started| {
+                    if now.duration_since(*started) < timeout {
+                        return true;
+                    }
+                    results[index] = Some(Err(TimedOut(timeout)));
+                    pending -= 1;
+                    false
+                });
+            }
+            Err(RecvTimeoutError::Disconnected) => match handle.join() {
+                Err(payload) => std::panic::resume_unwind(payload),
+                Ok(()) => unreachable!("batch thread exited without reporting every item"),
+            },
+        }
+    }
+
+    results.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_without_timeout_runs_inline() {
+        let id = thread::current().id();
+        let result = run_with_timeout(None, move || thread::current().id() == id);
+        assert_eq!(result, Ok(true));
+    }
+
+    #[test]
+    fn test_run_with_timeout_returns_result() {
+        let result = run_with_timeout(Some(Duration::from_secs(5)), || 42);
+        assert_eq!(result, Ok(42));
+    }
+
+    #[test]
+    fn test_run_with_timeout_gives_up_on_slow_compile() {
+        let timeout = Duration::from_millis(20);
+        let started = Instant::now();
+        let result = run_with_timeout(Some(timeout), || thread::sleep(Duration::from_secs(2)));
+
+        assert_eq!(result, Err(TimedOut(timeout)));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(
+            TimedOut(Duration::from_secs(30)).to_string(),
+            "compilation timed out after 30s"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn test_run_with_timeout_propagates_panic() {
+        let _ = run_with_timeout(Some(Duration::from_secs(5)), || panic!("boom"));
+    }
+
+    #[test]
+    fn test_batch_with_timeout_returns_results_in_order() {
+        let mut started = Vec::new();
+        let results = run_batch_with_timeout(
+            Duration::from_secs(5),
+            3,
+            |index| started.push(index),
+            |tx| {
+                for index in (0..3).rev() {
+                    let _ = tx.send(BatchEvent::Started(index));
+                    let _ = tx.send(BatchEvent::Finished(index, index * 10));
+                }
+            },
+        );
+
+        assert_eq!(results, [Ok(0), Ok(10), Ok(20)]);
+        assert_eq!(started, [2, 1, 0]);
+    }
+
+    #[test]
+    fn test_batch_with_timeout_fails_only_the_slow_item() {
+        let timeout = Duration::from_millis(20);
+        let started = Instant::now();
+        let results = run_batch_with_timeout(
+            timeout,
+            2,
+            |_| {},
+            |tx| {
+                let _ = tx.send(BatchEvent::Started(0));
+                let _ = tx.send(BatchEvent::Started(1));
+                let _ = tx.send(BatchEvent::Finished(1, "fast"));
+                thread::sleep(Duration::from_secs(2));
+                let _ = tx.send(BatchEvent::Finished(0, "slow"));
+            },
+        );
+
+        assert_eq!(results, [Err(TimedOut(timeout)), Ok("fast")]);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_batch_with_timeout_fails_items_that_never_start() {
+        let timeout = Duration::from_millis(20);
+        let started = Instant::now();
+        let results = run_batch_with_timeout(
+            timeout,
+            3,
+            |_| {},
+            |tx| {
+                // One worker, stuck on the first item
+                let _ = tx.send(BatchEvent::Started(0));
+                thread::sleep(Duration::from_secs(2));
+                let _ = tx.send(BatchEvent::Finished(0, ()));
+            },
+        );
+
+        assert_eq!(results, [Err(TimedOut(timeout)); 3]);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "boom")]
+    fn test_batch_with_timeout_propagates_panic() {
+        let _ = run_batch_with_timeout(
+            Duration::from_secs(5),
+            1,
+            |_| {},
+            |_: mpsc::Sender<BatchEvent<()>>| panic!("boom"),
+        );
+    }
+}
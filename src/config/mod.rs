@@ -25,8 +25,13 @@
 //! | `[site.nav]`       | SPA navigation, transition, preload settings |
 //! | `[build]`          | Build paths, svg, css, feed, sitemap, etc.   |
 //! | `[serve]`          | Development server (port, interface, watch)  |
-//! | `[deploy]`         | Deployment targets (GitHub, Cloudflare)      |
+//! | `[deploy]`         | Deployment targets (GitHub, Netlify)         |
 //! | `[validate]`       | Link and asset validation settings           |
+//!
+//! # Local overrides
+//!
+//! A `tola.local.toml` next to `tola.toml` is deep-merged on top of it.
+//! Use it for machine-specific settings; `tola init` adds it to `.gitignore`.
 
 pub mod section;
 pub mod types;
@@ -36,8 +41,9 @@ use util::{extract_url_path, find_config_file};
 
 // Re-export from section/
 pub use section::{
-    AssetsConfig, BuildSectionConfig, DeployConfig, FeedFormat, SlugCase, SlugConfig, SlugMode,
-    SvgConverter, SvgFormat, ValidateConfig, ValidateLevel,
+    AccessibilityValidateConfig, AssetsConfig, BuildSectionConfig, CompressionConfig, DeployConfig,
+    DiagnosticsFormat, ExternalValidateConfig, FeedFormat, SitemapLastmod, SlugCase, SlugConfig,
+    SlugMode, SlugTransform, SvgConverter, SvgFormat, SvgPass, ValidateConfig, ValidateLevel,
 };
 
 // Re-export from types/
@@ -60,6 +66,9 @@ use std::{
     path::{Path, PathBuf},
 };
 
+/// Machine-specific override file, discovered next to the primary config.
+pub const LOCAL_CONFIG_FILE: &str = "tola.local.toml";
+
 // ============================================================================
 // root configuration
 // ============================================================================
@@ -150,8 +159,9 @@ impl SiteConfig {
             Self::default()
         };
 
-        // Validate raw paths before normalization
+        // Environment variables override tola.toml, validate raw paths before normalization
         if !cli.is_init() {
+            config.apply_env_overrides()?;
             config.validate_paths()?;
         }
 
@@ -227,6 +237,14 @@ impl SiteConfig {
         if matches!(cli.command, Commands::Serve { .. }) && !self.serve.respect_prefix {
             self.build.path_prefix = PathBuf::new();
         }
+
+        // GitHub user/org pages are served from the domain root
+        if matches!(cli.command, Commands::Deploy { .. })
+            && self.deploy.provider == "github"
+            && self.deploy.github.page_type.is_root()
+        {
+            self.build.path_prefix = PathBuf::new();
+        }
     }
 
     /// Derive path_prefix from site.info.url.
@@ -244,6 +262,33 @@ impl SiteConfig {
         }
     }
 
+    /// Apply `#[config(env = "...")]` overrides on top of the parsed config.
+    ///
+    /// Environment variables win over `tola.toml`, CLI flags still win over both.
+    fn apply_env_overrides(&mut self) -> Result<()> {
+        self.site.from_env()?;
+        self.theme.from_env()?;
+        self.serve.from_env()?;
+        self.deploy.from_env()?;
+        self.validate.from_env()?;
+        self.build.from_env()?;
+        Ok(())
+    }
+
+    /// Generate JSON Schema for `tola.toml`.
+    ///
+    /// Aggregates the `schema()` of every top-level section.
+    pub fn schema() -> serde_json::Value {
+        let mut properties = serde_json::Map::new();
+        properties.insert("site".into(), SiteSectionConfig::schema());
+        properties.insert("theme".into(), ThemeSectionConfig::schema());
+        properties.insert("build".into(), BuildSectionConfig::schema());
+        properties.insert("serve".into(), ServeConfig::schema());
+        properties.insert("deploy".into(), DeployConfig::schema());
+        properties.insert("validate".into(), ValidateConfig::schema());
+        types::schema::document(properties)
+    }
+
     /// Parse configuration from TOML string
     pub fn from_str(content: &str) -> Result<Self> {
         let mut config: Self = toml::from_str(content)?;
@@ -251,23 +296,84 @@ impl SiteConfig {
         Ok(config)
     }
 
+    /// Serialize to `tola.toml` format, omitting values equal to their defaults.
+    ///
+    /// Sections keep their `[site.info]`-style headers, as in
+    /// `template_with_header()`. Internal `#[serde(skip)]` fields (`cli`,
+    /// `root`, `build.clean`, ...) never appear. Paths are written as stored,
+    /// so a finalized config emits absolute paths.
+    pub fn to_toml(&self) -> Result<String> {
+        let mut table = toml::Table::try_from(self)?;
+        let default = toml::Table::try_from(Self::default())?;
+        prune_defaults(&mut table, &default);
+        Ok(toml::to_string_pretty(&table)?)
+    }
+
     /// Load configuration from file path with unknown field detection.
+    ///
+    /// If a `tola.local.toml` exists next to the config file, it is
+    /// deep-merged on top of the base config. Each file is parsed on its own
+    /// first, so unknown fields and parse errors point at the file (and the
+    /// text) they come from.
     fn from_path(path: &Path) -> Result<Self> {
         let content =
             fs::read_to_string(path).map_err(|err| ConfigError::Io(path.to_path_buf(), err))?;
 
-        let presence = ConfigPresence::from_toml(&content)?;
         let (mut config, ignored) = Self::parse_with_ignored(&content)?;
+        Self::confirm_unknown_fields(&ignored, path)?;
+        let mut presence = ConfigPresence::from_toml(&content)?;
+
+        let local_path = Self::local_path_for(path);
+        if local_path.is_file() {
+            let local = fs::read_to_string(&local_path)
+                .map_err(|err| ConfigError::Io(local_path.clone(), err))?;
+            let (_, ignored) = Self::parse_with_ignored(&local)?;
+            Self::confirm_unknown_fields(&ignored, &local_path)?;
+
+            // Unknown fields of both files were reported above
+            let merged = Self::merge_local_override(&content, &local)?;
+            presence = ConfigPresence::from_toml(&merged)?;
+            (config, _) = Self::parse_with_ignored(&merged)?;
+        }
+
         config.presence = presence;
+        Ok(config)
+    }
 
-        if !ignored.is_empty() {
-            Self::print_unknown_fields_warning(&ignored, path);
-            if !Self::prompt_continue()? {
-                bail!("Aborted due to unknown config fields");
+    /// Warn about unknown fields in `path` and ask whether to continue.
+    fn confirm_unknown_fields(ignored: &[String], path: &Path) -> Result<()> {
+        if ignored.is_empty() {
+            return Ok(());
+        }
+        Self::print_unknown_fields_warning(ignored, path);
+        if !Self::prompt_continue()? {
+            bail!("Aborted due to unknown config fields");
+        }
+        Ok(())
+    }
+
+    /// Deep-merge `local` TOML content on top of `base`.
+    ///
+    /// Tables are merged recursively; any other value in `local` replaces
+    /// the base value (arrays are replaced, not concatenated).
+    fn merge_local_override(base: &str, local: &str) -> Result<String> {
+        fn merge(base: &mut toml::Table, local: toml::Table) {
+            for (key, value) in local {
+                match (base.get_mut(&key), value) {
+                    (Some(toml::Value::Table(base_table)), toml::Value::Table(local_table)) => {
+                        merge(base_table, local_table);
+                    }
+                    (_, value) => {
+                        base.insert(key, value);
+                    }
+                }
             }
         }
 
-        Ok(config)
+        let mut merged: toml::Table = toml::from_str(base).map_err(ConfigError::Toml)?;
+        let local: toml::Table = toml::from_str(local).map_err(ConfigError::Toml)?;
+        merge(&mut merged, local);
+        toml::to_string(&merged).context("Failed to serialize merged config")
     }
 
     /// Parse TOML content, collecting any unknown fields.
@@ -311,6 +417,16 @@ impl SiteConfig {
         Ok(input == "y" || input == "yes")
     }
 
+    /// Path of the local override file (`tola.local.toml`) for a config file.
+    fn local_path_for(config_path: &Path) -> PathBuf {
+        config_path.with_file_name(LOCAL_CONFIG_FILE)
+    }
+
+    /// Path of the local override file next to the loaded config.
+    pub fn local_config_path(&self) -> PathBuf {
+        Self::local_path_for(&self.config_path)
+    }
+
     /// Get the root directory path
     pub fn get_root(&self) -> &Path {
         &self.root
@@ -372,8 +488,13 @@ impl SiteConfig {
 
     /// Apply command-specific configuration options.
     fn apply_command_options(&mut self, cli: &Cli) {
+        // Draft previews only exist in the dev server
+        if !cli.is_serve() {
+            self.serve.drafts.enable = false;
+        }
+
         match &cli.command {
-            Commands::Build { build_args } => {
+            Commands::Build { build_args, .. } => {
                 self.apply_build_args(build_args, false);
             }
             Commands::Serve {
@@ -381,23 +502,37 @@ impl SiteConfig {
                 interface,
                 port,
                 watch,
+                open,
                 ..
             } => {
                 self.apply_build_args(build_args, true);
-                self.apply_serve_options(*interface, *port, *watch);
+                self.apply_serve_options(*interface, *port, *watch, *open);
             }
-            Commands::Deploy { force } => {
+            Commands::Deploy { force, dry_run } => {
                 Self::update_option(&mut self.deploy.force, force.as_ref());
+                self.deploy.dry_run = *dry_run;
             }
             Commands::Init { .. } => {}
+            // Export command doesn't modify config
+            Commands::Export { .. } => {}
             // Query command doesn't modify config
             Commands::Query { .. } => {}
             // Validate command: CLI args override config
             Commands::Validate { args } => {
                 self.apply_validate_args(args);
             }
+            // Clean command doesn't modify config
+            Commands::Clean { .. } => {}
+            // New command doesn't modify config
+            Commands::New { .. } => {}
             // Fix command doesn't modify config
-            Commands::Fix => {}
+            Commands::Fix { .. } => {}
+            // Stats command doesn't modify config
+            Commands::Stats => {}
+            // Schema command doesn't read config
+            Commands::Schema => {}
+            // Completions command doesn't read config
+            Commands::Completions { .. } => {}
         }
     }
 
@@ -411,6 +546,9 @@ impl SiteConfig {
         if args.warn_only {
             self.validate.pages.level = ValidateLevel::Warn;
             self.validate.assets.level = ValidateLevel::Warn;
+            self.validate.orphans.level = ValidateLevel::Warn;
+            self.validate.external.level = ValidateLevel::Warn;
+            self.validate.accessibility.level = ValidateLevel::Warn;
         }
     }
 
@@ -428,6 +566,10 @@ impl SiteConfig {
         );
         self.build.clean = args.clean;
         self.build.skip_drafts = args.skip_drafts;
+        if !is_serve {
+            self.build.only = args.only.clone();
+            self.build.manifest = args.manifest.clone();
+        }
 
         // Override site URL if provided via CLI
         // path_prefix will be derived from it in sync_path_prefix_from_url()
@@ -452,10 +594,12 @@ impl SiteConfig {
         interface: Option<std::net::IpAddr>,
         port: Option<u16>,
         watch: Option<bool>,
+        open: Option<bool>,
     ) {
         Self::update_option(&mut self.serve.interface, interface.as_ref());
         Self::update_option(&mut self.serve.port, port.as_ref());
         Self::update_option(&mut self.serve.watch, watch.as_ref());
+        Self::update_option(&mut self.serve.open, open.as_ref());
 
         // Set base URL for local development (only if not overridden via CLI --base-url)
         if self.site.info.url.is_none() {
@@ -494,6 +638,12 @@ impl SiteConfig {
 
         // Normalize build directories
         self.build.content = crate::utils::path::normalize_path(&root.join(&self.build.content));
+        self.build.extra_content = self
+            .build
+            .extra_content
+            .iter()
+            .map(|p| crate::utils::path::normalize_path(&root.join(p)))
+            .collect();
         // Normalize assets paths
         self.build.assets.normalize(&root);
         self.build.output = crate::utils::path::normalize_path(&root.join(&self.build.output));
@@ -510,15 +660,25 @@ impl SiteConfig {
         self.normalize_optional_paths(&root);
     }
 
-    /// Normalize optional paths (CSS processor path, deploy token).
+    /// Normalize optional paths (CSS processor path, TLS files, deploy token).
     fn normalize_optional_paths(&mut self, root: &Path) {
         if let Some(path) = self.build.hooks.css.path.take() {
             self.build.hooks.css.path = Some(crate::utils::path::normalize_path(&root.join(path)));
         }
 
+        if let Some(path) = self.serve.tls.cert.take() {
+            self.serve.tls.cert = Some(crate::utils::path::normalize_path(&root.join(path)));
+        }
+        if let Some(path) = self.serve.tls.key.take() {
+            self.serve.tls.key = Some(crate::utils::path::normalize_path(&root.join(path)));
+        }
+
         if let Some(token_path) = self.deploy.github.token_path.take() {
             self.deploy.github.token_path = Some(Self::normalize_token_path(&token_path, root));
         }
+        if let Some(token_path) = self.deploy.netlify.token_path.take() {
+            self.deploy.netlify.token_path = Some(Self::normalize_token_path(&token_path, root));
+        }
     }
 
     /// Normalize token path with tilde expansion.
@@ -563,18 +723,20 @@ impl SiteConfig {
             bail!(ConfigError::Validation("config file not found".into()));
         }
 
-        // Validate field status (experimental, deprecated, not_implemented)
+        // Validate field status (experimental, deprecated, not_implemented, renamed)
         self.site.validate_field_status(&mut diag);
         self.theme.validate_field_status(&mut diag);
         self.serve.validate_field_status(&mut diag);
         self.validate.validate_field_status(&mut diag);
         self.deploy.validate_field_status(&mut diag);
-        self.build.svg.validate_field_status(&mut diag);
+        self.build.validate_field_status(&mut diag);
 
         // Validate each section
         self.site
             .info
             .validate(self.site.seo.feed.enable, &mut diag);
+        self.site.seo.feed.validate(&mut diag);
+        self.site.i18n.validate(&mut diag);
         self.build.validate(&mut diag);
         self.build.hooks.validate(&mut diag);
         self.build.svg.validate(&mut diag);
@@ -596,13 +758,40 @@ impl SiteConfig {
 
     /// Validate command-specific requirements.
     fn validate_command_specific(&self, diag: &mut ConfigDiagnostics) -> Result<()> {
-        if let Commands::Deploy { .. } = &self.get_cli().command {
-            self.deploy.validate(diag);
+        match &self.get_cli().command {
+            Commands::Deploy { .. } => self.deploy.validate(diag),
+            Commands::Serve { .. } => self.serve.validate(diag),
+            _ => {}
         }
         Ok(())
     }
 }
 
+/// Remove entries of `table` equal to `default`, dropping emptied sub-tables.
+///
+/// Arrays are compared as a whole; tables are pruned recursively.
+fn prune_defaults(table: &mut toml::Table, default: &toml::Table) {
+    let mut unchanged = Vec::new();
+    for (key, value) in table.iter_mut() {
+        let Some(default_value) = default.get(key) else {
+            continue;
+        };
+        let keep = match (value, default_value) {
+            (toml::Value::Table(sub), toml::Value::Table(default_sub)) => {
+                prune_defaults(sub, default_sub);
+                !sub.is_empty()
+            }
+            (value, default_value) => value != default_value,
+        };
+        if !keep {
+            unchanged.push(key.clone());
+        }
+    }
+    for key in unchanged {
+        table.remove(&key);
+    }
+}
+
 // ============================================================================
 // Test Helpers (available to all modules via `use crate::config::test_*`)
 // ============================================================================
@@ -642,12 +831,15 @@ mod tests {
             site_url: None,
             verbose: false,
             skip_drafts: false,
+            only: None,
+            manifest: None,
         }
     }
 
     fn test_cli(command: Commands) -> &'static Cli {
         Box::leak(Box::new(Cli {
             color: ColorChoice::Never,
+            json: false,
             package_path: None,
             package_cache_path: None,
             output: None,
@@ -708,12 +900,73 @@ mod tests {
         assert!(ignored.is_empty());
     }
 
+    #[test]
+    fn test_schema_covers_sections() {
+        let schema = SiteConfig::schema();
+        let props = &schema["properties"];
+
+        assert_eq!(schema["$schema"], types::schema::SCHEMA_DIALECT);
+        assert_eq!(
+            props["site"]["properties"]["info"]["properties"]["title"]["type"],
+            "string"
+        );
+        assert_eq!(props["serve"]["properties"]["port"]["type"], "integer");
+        assert_eq!(props["serve"]["properties"]["port"]["default"], 5277);
+        assert_eq!(props["build"]["properties"]["minify"]["type"], "boolean");
+
+        let feed = &props["site"]["properties"]["seo"]["properties"]["feed"];
+        let formats = &feed["properties"]["format"];
+        assert_eq!(formats["type"], "array");
+        assert_eq!(formats["default"], serde_json::json!(["rss"]));
+        assert!(
+            formats["items"]["enum"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|v| v == "atom")
+        );
+    }
+
+    #[test]
+    fn test_merge_local_override() {
+        let base = "[site.info]\ntitle = \"Test\"\ndescription = \"Test\"\n[serve]\nport = 5277\nwatch = true\n[build.assets]\nflatten = [\"CNAME\"]";
+        let local = "[serve]\nport = 8080\n[build]\nminify = false\n[build.assets]\nflatten = []";
+
+        let merged = SiteConfig::merge_local_override(base, local).unwrap();
+        let (config, ignored) = SiteConfig::parse_with_ignored(&merged).unwrap();
+
+        assert!(ignored.is_empty());
+        assert_eq!(config.site.info.title, "Test");
+        assert_eq!(config.serve.port, 8080);
+        assert!(config.serve.watch);
+        assert!(!config.build.minify);
+        assert!(config.build.assets.flatten.is_empty());
+    }
+
+    #[test]
+    fn test_from_path_applies_local_override() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("tola.toml");
+        fs::write(
+            &path,
+            "[site.info]\ntitle = \"Base\"\ndescription = \"Test\"\n[serve]\nport = 5277",
+        )
+        .unwrap();
+        fs::write(temp.path().join(LOCAL_CONFIG_FILE), "[serve]\nport = 9000").unwrap();
+
+        let config = SiteConfig::from_path(&path).unwrap();
+        assert_eq!(config.site.info.title, "Base");
+        assert_eq!(config.serve.port, 9000);
+        assert!(config.presence.contains("serve.port"));
+    }
+
     #[test]
     fn test_finalize_build_derives_path_prefix_from_site_info_url() {
         let config = finalize_test_config(
             "url = \"https://example.com/docs/blog\"",
             Commands::Build {
                 build_args: test_build_args(),
+                watch: false,
             },
         );
 
@@ -726,6 +979,7 @@ mod tests {
             "url = \"https://example.com/\"",
             Commands::Build {
                 build_args: test_build_args(),
+                watch: false,
             },
         );
 
@@ -741,12 +995,31 @@ mod tests {
                 interface: None,
                 port: None,
                 watch: None,
+                open: None,
             },
         );
 
         assert!(config.build.path_prefix.as_os_str().is_empty());
     }
 
+    #[test]
+    fn test_finalize_deploy_clears_path_prefix_for_github_user_pages() {
+        let deploy = || Commands::Deploy {
+            force: None,
+            dry_run: false,
+        };
+        let url = "url = \"https://user.github.io/blog\"\n";
+
+        let config = finalize_test_config(url, deploy());
+        assert_eq!(config.build.path_prefix, PathBuf::from("blog"));
+
+        let config = finalize_test_config(
+            &format!("{url}[deploy.github]\npage_type = \"user\""),
+            deploy(),
+        );
+        assert!(config.build.path_prefix.as_os_str().is_empty());
+    }
+
     #[test]
     fn test_finalize_serve_keeps_path_prefix_when_respect_prefix_enabled() {
         let config = finalize_test_config(
@@ -756,6 +1029,7 @@ mod tests {
                 interface: None,
                 port: None,
                 watch: None,
+                open: None,
             },
         );
 
@@ -766,6 +1040,7 @@ mod tests {
     fn test_package_paths_follow_cli() {
         let cli = Box::leak(Box::new(Cli {
             color: ColorChoice::Never,
+            json: false,
             package_path: Some(PathBuf::from("packages")),
             package_cache_path: Some(PathBuf::from("cache")),
             output: None,
@@ -773,13 +1048,86 @@ mod tests {
             config: PathBuf::from("tola.toml"),
             command: Commands::Build {
                 build_args: test_build_args(),
+                watch: false,
             },
         }));
 
-        let mut config = SiteConfig::default();
-        config.cli = Some(cli);
+        let config = SiteConfig {
+            cli: Some(cli),
+            ..Default::default()
+        };
 
         assert_eq!(config.package_path(), Some(Path::new("packages")));
         assert_eq!(config.package_cache_path(), Some(Path::new("cache")));
     }
+
+    #[test]
+    fn test_to_toml_round_trip() {
+        let config = test_parse_config(
+            r#"
+[build]
+minify = false
+
+[build.search]
+enable = true
+
+[serve]
+port = 8080
+
+[[serve.proxy]]
+prefix = "/api"
+target = "http://localhost:3000"
+"#,
+        );
+
+        let toml = config.to_toml().unwrap();
+        assert!(toml.contains("[site.info]"));
+        assert!(toml.contains("[build.search]"));
+        assert!(toml.contains("[[serve.proxy]]"));
+        // Defaults and internal fields are omitted
+        assert!(!toml.contains("reading_speed"));
+        assert!(!toml.contains("config_path"));
+        assert!(!toml.contains("[deploy"));
+
+        let reparsed = SiteConfig::from_str(&toml).unwrap();
+        assert_eq!(
+            toml::Table::try_from(&reparsed).unwrap(),
+            toml::Table::try_from(&config).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_to_toml_default_is_empty() {
+        assert_eq!(SiteConfig::default().to_toml().unwrap().trim(), "");
+    }
+
+    #[test]
+    fn test_config_rename_all_kebab_case() {
+        /// Kebab-case test section.
+        #[derive(Debug, Default, Serialize, Deserialize, macros::Config)]
+        #[serde(default, rename_all = "kebab-case")]
+        #[config(section = "test.kebab", rename_all = "kebab-case")]
+        struct KebabConfig {
+            /// Renamed by the struct rule.
+            my_field: bool,
+            /// Explicit name wins over the struct rule.
+            #[serde(rename = "other_name")]
+            #[config(name = "other_name")]
+            named_field: u32,
+        }
+
+        assert_eq!(KebabConfig::FIELDS.my_field.as_str(), "test.kebab.my-field");
+        assert_eq!(
+            KebabConfig::FIELDS.named_field.as_str(),
+            "test.kebab.other_name"
+        );
+
+        let template = KebabConfig::template();
+        assert!(template.contains("my-field = false"));
+        assert!(template.contains("other_name = 0"));
+
+        let parsed: KebabConfig = toml::from_str("my-field = true\nother_name = 3").unwrap();
+        assert!(parsed.my_field);
+        assert_eq!(parsed.named_field, 3);
+    }
 }
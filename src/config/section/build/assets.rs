@@ -16,6 +16,9 @@
 //!     "assets/CNAME",                        # -> output/CNAME
 //!     { file = "icons/fav.ico", as = "favicon.ico" },
 //! ]
+//!
+//! [build.assets.images]                      # Responsive variants (see `images`)
+//! enable = true
 //! ```
 
 use rustc_hash::FxHashMap;
@@ -24,6 +27,7 @@ use std::path::{Path, PathBuf};
 use macros::Config;
 use serde::{Deserialize, Serialize};
 
+use super::ImagesConfig;
 use crate::config::{ConfigDiagnostics, FieldPath};
 
 // ============================================================================
@@ -82,6 +86,8 @@ pub struct AssetsConfig {
     /// - `"assets"` -> `/assets/xxx`
     /// - `"assets/styles"` -> `/styles/xxx`
     /// - `{ dir = "vendor", as = "lib" }` -> `/lib/xxx`
+    #[serde(alias = "dirs")]
+    #[config(alias = "dirs")]
     pub nested: Vec<NestedEntry>,
 
     /// Flatten files (copy to output root).
@@ -89,7 +95,13 @@ pub struct AssetsConfig {
     /// Examples:
     /// - `"assets/CNAME"` -> `/CNAME`
     /// - `{ file = "icons/fav.ico", as = "favicon.ico" }` -> `/favicon.ico`
+    #[serde(alias = "files")]
+    #[config(alias = "files")]
     pub flatten: Vec<FlattenEntry>,
+
+    /// Responsive image variants (`srcset` / `<picture>`).
+    #[config(sub)]
+    pub images: ImagesConfig,
 }
 
 impl Default for AssetsConfig {
@@ -97,6 +109,7 @@ impl Default for AssetsConfig {
         Self {
             nested: vec![NestedEntry::Simple("assets".into())],
             flatten: vec![],
+            images: ImagesConfig::default(),
         }
     }
 }
@@ -461,4 +474,41 @@ flatten = [
         // Not a flatten file
         assert!(!config.is_flatten(Path::new("assets/other.txt")));
     }
+
+    #[test]
+    fn test_renamed_fields_accept_alias_and_warn() {
+        use crate::config::{ConfigPresence, test_parse_config};
+
+        let snippet = r#"
+[build.assets]
+dirs = ["static"]
+files = ["CNAME"]
+"#;
+        let config = test_parse_config(snippet);
+        assert_eq!(config.build.assets.nested.len(), 1);
+        assert_eq!(config.build.assets.nested[0].source(), Path::new("static"));
+        assert_eq!(config.build.assets.flatten.len(), 1);
+
+        let mut diag = ConfigDiagnostics::new();
+        let raw = format!("[site.info]\ntitle = \"Test\"\ndescription = \"Test\"\n{snippet}");
+        diag.set_presence(ConfigPresence::from_toml(&raw).unwrap());
+        config.build.validate_field_status(&mut diag);
+
+        let renames: Vec<_> = diag
+            .renames()
+            .iter()
+            .map(|(old, new)| (old.as_str(), new.as_str()))
+            .collect();
+        assert!(renames.contains(&("build.assets.dirs", "build.assets.nested")));
+        assert!(renames.contains(&("build.assets.files", "build.assets.flatten")));
+        assert!(!diag.has_errors());
+    }
+
+    #[test]
+    fn test_deprecated_aliases_const() {
+        assert!(
+            AssetsConfig::DEPRECATED_ALIASES
+                .contains(&("build.assets.dirs", "build.assets.nested"))
+        );
+    }
 }
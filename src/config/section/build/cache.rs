@@ -0,0 +1,92 @@
+//! `[build.cache]` section configuration.
+//!
+//! Controls how the VDOM cache in `.tola/cache/` is stored on disk.
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.cache]
+//! compress = true          # zstd-compress each cache entry
+//! compression_level = 3    # 1 (fastest) ..= 22 (smallest)
+//! ```
+
+use crate::config::ConfigDiagnostics;
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+/// Valid zstd compression levels
+const LEVELS: std::ops::RangeInclusive<i32> = 1..=22;
+
+/// VDOM cache persistence settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.cache")]
+pub struct CacheConfig {
+    /// Compress cache entries with zstd.
+    pub compress: bool,
+
+    /// zstd compression level (1-22).
+    pub compression_level: i32,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            compress: true,
+            compression_level: 3,
+        }
+    }
+}
+
+impl CacheConfig {
+    /// Compression level to write with, `None` when compression is off.
+    #[inline]
+    pub fn compression(&self) -> Option<i32> {
+        self.compress.then_some(self.compression_level)
+    }
+
+    /// Validate cache configuration.
+    pub fn validate(&self, diag: &mut ConfigDiagnostics) {
+        if self.compress && !LEVELS.contains(&self.compression_level) {
+            diag.error(
+                CacheConfig::FIELDS.compression_level,
+                format!(
+                    "compression_level must be between {} and {}, got {}",
+                    LEVELS.start(),
+                    LEVELS.end(),
+                    self.compression_level
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{ConfigDiagnostics, test_parse_config};
+
+    #[test]
+    fn test_cache_config() {
+        let config = test_parse_config("");
+        assert_eq!(config.build.cache.compression(), Some(3));
+
+        let config = test_parse_config("[build.cache]\ncompress = false");
+        assert_eq!(config.build.cache.compression(), None);
+
+        let config = test_parse_config("[build.cache]\ncompression_level = 19");
+        assert_eq!(config.build.cache.compression(), Some(19));
+    }
+
+    #[test]
+    fn test_cache_config_validation() {
+        let config = test_parse_config("[build.cache]\ncompression_level = 30");
+        let mut diag = ConfigDiagnostics::new();
+        config.build.cache.validate(&mut diag);
+        assert!(diag.has_errors());
+
+        let config = test_parse_config("[build.cache]\ncompress = false\ncompression_level = 30");
+        let mut diag = ConfigDiagnostics::new();
+        config.build.cache.validate(&mut diag);
+        assert!(!diag.has_errors());
+    }
+}
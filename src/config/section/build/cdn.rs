@@ -0,0 +1,48 @@
+//! `[build.cdn]` section configuration.
+//!
+//! Generates `_headers` and `_redirects` in the output root, read by
+//! Netlify and Cloudflare Pages.
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.cdn]
+//! headers_file = true     # Cache-Control rules for hashed assets and pages
+//! redirects_file = true   # 301 redirects for page aliases
+//! ```
+
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+/// CDN rule file generation settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.cdn")]
+pub struct CdnConfig {
+    /// Generate `_headers` with long-lived caching for content-hashed files
+    /// and revalidation for HTML pages.
+    pub headers_file: bool,
+
+    /// Generate `_redirects` with a 301 redirect for every page alias.
+    pub redirects_file: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_cdn_config() {
+        let config = test_parse_config(
+            r#"
+[build.cdn]
+headers_file = true
+"#,
+        );
+        assert!(config.build.cdn.headers_file);
+        assert!(!config.build.cdn.redirects_file);
+
+        let config = test_parse_config("");
+        assert!(!config.build.cdn.headers_file);
+    }
+}
@@ -0,0 +1,118 @@
+//! `[build.code]` section configuration.
+//!
+//! Syntax highlighting for fenced code blocks (`class="language-*"`).
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.code]
+//! highlighter = "syntect"          # syntect | none
+//! theme = "base16-ocean.dark"      # Any built-in syntect theme
+//! inline_css = true                # false = classes + generated stylesheet
+//! ```
+
+use crate::config::ConfigDiagnostics;
+use crate::pipeline::transform::code::{has_theme, theme_names};
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+/// Code highlighting backend
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Highlighter {
+    /// Highlight with syntect (Sublime Text grammars).
+    #[default]
+    Syntect,
+    /// Leave code blocks untouched.
+    None,
+}
+
+/// Code block highlighting settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.code")]
+pub struct CodeConfig {
+    /// Highlighting backend.
+    pub highlighter: Highlighter,
+
+    /// Color theme name.
+    pub theme: String,
+
+    /// Inline `style` colors; `false` emits classes plus a stylesheet.
+    pub inline_css: bool,
+}
+
+impl Default for CodeConfig {
+    fn default() -> Self {
+        Self {
+            highlighter: Highlighter::Syntect,
+            theme: "base16-ocean.dark".into(),
+            inline_css: true,
+        }
+    }
+}
+
+impl CodeConfig {
+    /// Whether code blocks are highlighted.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.highlighter != Highlighter::None
+    }
+
+    /// Whether the class-based stylesheet is generated and linked.
+    #[inline]
+    pub fn needs_stylesheet(&self) -> bool {
+        self.is_enabled() && !self.inline_css
+    }
+
+    /// Validate code highlighting configuration.
+    pub fn validate(&self, diag: &mut ConfigDiagnostics) {
+        if self.is_enabled() && !has_theme(&self.theme) {
+            diag.error(
+                CodeConfig::FIELDS.theme,
+                format!(
+                    "unknown theme '{}', available: {}",
+                    self.theme,
+                    theme_names().join(", ")
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_code_config() {
+        let config = test_parse_config(
+            r#"
+[build.code]
+theme = "InspiredGitHub"
+inline_css = false
+"#,
+        );
+        let code = &config.build.code;
+        assert_eq!(code.highlighter, Highlighter::Syntect);
+        assert_eq!(code.theme, "InspiredGitHub");
+        assert!(code.needs_stylesheet());
+
+        let config = test_parse_config("[build.code]\nhighlighter = \"none\"\ninline_css = false");
+        assert!(!config.build.code.is_enabled());
+        assert!(!config.build.code.needs_stylesheet());
+    }
+
+    #[test]
+    fn test_unknown_theme() {
+        let mut config = CodeConfig::default();
+        let mut diag = ConfigDiagnostics::new();
+        config.validate(&mut diag);
+        assert!(!diag.has_errors());
+
+        config.theme = "no-such-theme".into();
+        config.validate(&mut diag);
+        assert!(diag.has_errors());
+    }
+}
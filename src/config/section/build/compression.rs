@@ -0,0 +1,89 @@
+//! `[build.compression]` section configuration.
+//!
+//! Pre-compressed `.br` / `.gz` copies of text output files, for servers
+//! that serve them directly (Nginx `gzip_static`, Caddy `precompressed`).
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.compression]
+//! brotli = true               # Write `.br` files
+//! gzip = true                 # Write `.gz` files
+//! min_size_bytes = 1024       # Skip smaller files
+//! exclude = ["search.json"]   # Output-relative globs to leave uncompressed
+//! ```
+
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::glob;
+
+/// Pre-compression settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.compression")]
+pub struct CompressionConfig {
+    /// Write a `.br` file next to each compressible output file.
+    pub brotli: bool,
+
+    /// Write a `.gz` file next to each compressible output file.
+    pub gzip: bool,
+
+    /// Files smaller than this are not compressed.
+    pub min_size_bytes: u64,
+
+    /// Glob patterns (relative to the output directory) never compressed.
+    pub exclude: Vec<String>,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            brotli: false,
+            gzip: false,
+            min_size_bytes: 1024,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+impl CompressionConfig {
+    /// Whether any compressed variant is written.
+    pub const fn is_enabled(&self) -> bool {
+        self.brotli || self.gzip
+    }
+
+    /// Whether an output-relative path matches an `exclude` pattern.
+    pub fn is_excluded(&self, path: &str) -> bool {
+        self.exclude
+            .iter()
+            .any(|pattern| glob::matches(pattern, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_compression_config() {
+        let compression = test_parse_config("").build.compression;
+        assert!(!compression.is_enabled());
+        assert_eq!(compression.min_size_bytes, 1024);
+
+        let config = test_parse_config(
+            r#"
+[build.compression]
+brotli = true
+min_size_bytes = 0
+exclude = ["feed.xml", "docs/**"]
+"#,
+        );
+        let compression = &config.build.compression;
+        assert!(compression.is_enabled());
+        assert!(!compression.gzip);
+        assert!(compression.is_excluded("feed.xml"));
+        assert!(compression.is_excluded("docs/guide/index.html"));
+        assert!(!compression.is_excluded("blog/feed.xml"));
+    }
+}
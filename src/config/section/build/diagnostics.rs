@@ -8,19 +8,43 @@
 //! [build.diagnostics]
 //! max_errors = 3                   # Max errors to display (default: 3)
 //! max_warnings = 3                 # Max warnings to display (default: 3)
+//! format = "text"                  # "text" or "json" (default: "text")
+//! ```
+//!
+//! With `format = "json"` (or the `--json` flag), compile errors are written
+//! to stderr as JSON Lines instead of formatted text:
+//!
+//! ```json
+//! {"level":"error","file":"content/index.typ","line":3,"col":2,"message":"unknown variable: x","hint":null}
 //! ```
 
+use macros::Config;
 use serde::{Deserialize, Serialize};
 
 /// Diagnostics display configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
 #[serde(default)]
+#[config(section = "build.diagnostics")]
 pub struct DiagnosticsConfig {
     /// Maximum errors to display (None = unlimited).
     pub max_errors: Option<usize>,
 
     /// Maximum warnings to display (None = unlimited).
     pub max_warnings: Option<usize>,
+
+    /// Compile error output format.
+    pub format: DiagnosticsFormat,
+}
+
+/// Compile error output format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticsFormat {
+    /// Formatted text with source snippets (default).
+    #[default]
+    Text,
+    /// One JSON object per error on stderr.
+    Json,
 }
 
 impl Default for DiagnosticsConfig {
@@ -28,6 +52,7 @@ impl Default for DiagnosticsConfig {
         Self {
             max_errors: Some(3),
             max_warnings: Some(3),
+            format: DiagnosticsFormat::Text,
         }
     }
 }
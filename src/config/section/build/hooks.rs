@@ -15,6 +15,10 @@
 //! # Post hooks (run after build)
 //! [[build.hooks.post]]
 //! command = ["imagemin", "$TOLA_OUTPUT_DIR/images", "--out-dir", "$TOLA_OUTPUT_DIR/images"]
+//! env = { IMAGEMIN_QUALITY = "80" }
+//!
+//! # Every hook also receives TOLA_OUTPUT_DIR, TOLA_CONTENT_DIR, TOLA_ROOT,
+//! # TOLA_MODE (development | production), TOLA_VERSION and TOLA_MINIFY.
 //!
 //! # CSS processor (syntax sugar for pre hook)
 //! [build.hooks.css]
@@ -26,17 +30,20 @@
 use crate::config::ConfigDiagnostics;
 use macros::Config;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Hooks configuration containing pre and post build hooks
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
 #[serde(default)]
+#[config(section = "build.hooks")]
 pub struct HooksConfig {
     /// Pre-build hooks (run before content compilation).
     pub pre: Vec<HookConfig>,
     /// Post-build hooks (run after build completion).
     pub post: Vec<HookConfig>,
     /// CSS processor hook (syntax sugar for pre hook).
+    #[config(sub)]
     pub css: CssProcessorConfig,
 }
 
@@ -73,6 +80,11 @@ pub struct HookConfig {
     /// Suppress output (default: true).
     #[serde(default = "default_quiet")]
     pub quiet: bool,
+
+    /// Extra environment variables for the hook process.
+    /// Override the injected `TOLA_*` variables on conflict.
+    #[serde(default)]
+    pub env: HashMap<String, String>,
 }
 
 fn default_quiet() -> bool {
@@ -92,6 +104,7 @@ impl Default for HookConfig {
             watch: WatchMode::default(),
             build_args: Vec::new(),
             quiet: true,
+            env: HashMap::new(),
         }
     }
 }
@@ -0,0 +1,190 @@
+//! `[build.assets.images]` section configuration.
+//!
+//! Generates resized image variants and rewrites `<img>` into `<picture>`.
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.assets.images]
+//! enable = true
+//! glob = "assets/images/**"        # Relative to site root (default: all images)
+//! srcset_widths = [400, 800, 1200] # Widths for resized variants
+//! formats = ["avif", "webp"]       # Extra formats as <source>: avif | webp | png | jpeg
+//! strip_exif = true                # Remove EXIF/XMP from copied JPEG/PNG/WebP
+//! ```
+//!
+//! Browsers use the first `<source>` they support, so list AVIF before WebP.
+//! AVIF is only generated, never read: `.avif` sources are not resized.
+
+use std::path::Path;
+
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ConfigDiagnostics;
+use crate::utils::glob;
+
+/// Raster extensions eligible for responsive variants.
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "webp"];
+
+/// Output format for responsive image variants
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    Avif,
+    Webp,
+    Png,
+    #[serde(alias = "jpg")]
+    Jpeg,
+}
+
+impl ImageFormat {
+    /// Format matching a file extension.
+    pub fn from_extension(ext: &str) -> Option<Self> {
+        match ext.to_ascii_lowercase().as_str() {
+            "webp" => Some(Self::Webp),
+            "png" => Some(Self::Png),
+            "jpg" | "jpeg" => Some(Self::Jpeg),
+            _ => None,
+        }
+    }
+
+    /// File extension for generated variants.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Avif => "avif",
+            Self::Webp => "webp",
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+        }
+    }
+
+    /// MIME type for `<source type="...">`.
+    pub fn mime(self) -> &'static str {
+        match self {
+            Self::Avif => "image/avif",
+            Self::Webp => "image/webp",
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+        }
+    }
+}
+
+/// Responsive image settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.assets.images")]
+pub struct ImagesConfig {
+    /// Generate resized variants and emit `<picture>`/`srcset`.
+    pub enable: bool,
+
+    /// Glob (relative to site root) selecting images to process.
+    pub glob: String,
+
+    /// Widths of resized variants. Widths above the original are skipped.
+    pub srcset_widths: Vec<u32>,
+
+    /// Additional formats, each emitted as a `<source>` element.
+    pub formats: Vec<ImageFormat>,
+
+    /// Strip EXIF/XMP metadata (GPS, camera serial, author) from copied
+    /// JPEG, PNG and WebP files. Independent of `enable`. JPEG orientation
+    /// and color profiles are kept.
+    pub strip_exif: bool,
+}
+
+impl Default for ImagesConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            glob: "**".into(),
+            srcset_widths: vec![400, 800, 1200],
+            formats: vec![ImageFormat::Webp],
+            strip_exif: false,
+        }
+    }
+}
+
+impl ImagesConfig {
+    /// Check whether a source image should get responsive variants.
+    ///
+    /// `*` does not cross `/`; use `**` for that.
+    pub fn is_selected(&self, path: &Path, root: &Path) -> bool {
+        if !self.enable {
+            return false;
+        }
+        let is_image = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()));
+        if !is_image {
+            return false;
+        }
+
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        glob::matches_path(&self.glob, relative)
+    }
+
+    /// Validate responsive image configuration.
+    pub fn validate(&self, diag: &mut ConfigDiagnostics) {
+        if !self.enable {
+            return;
+        }
+        if self.srcset_widths.is_empty() || self.srcset_widths.contains(&0) {
+            diag.error(
+                ImagesConfig::FIELDS.srcset_widths,
+                format!(
+                    "{} must contain positive widths",
+                    ImagesConfig::FIELDS.srcset_widths
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_images_config() {
+        let config = test_parse_config(
+            r#"
+[build.assets.images]
+enable = true
+srcset_widths = [320, 640]
+formats = ["avif", "webp", "jpg"]
+strip_exif = true
+"#,
+        );
+        let images = &config.build.assets.images;
+        assert!(images.enable);
+        assert!(images.strip_exif);
+        assert_eq!(images.srcset_widths, vec![320, 640]);
+        assert_eq!(
+            images.formats,
+            vec![ImageFormat::Avif, ImageFormat::Webp, ImageFormat::Jpeg]
+        );
+    }
+
+    #[test]
+    fn test_images_is_selected() {
+        let root = Path::new("/site");
+        let mut images = ImagesConfig {
+            enable: true,
+            ..Default::default()
+        };
+
+        assert!(images.is_selected(Path::new("/site/assets/a.png"), root));
+        assert!(images.is_selected(Path::new("/site/content/b.JPG"), root));
+        assert!(!images.is_selected(Path::new("/site/assets/c.svg"), root));
+
+        images.glob = "content/**".into();
+        assert!(!images.is_selected(Path::new("/site/assets/a.png"), root));
+        assert!(images.is_selected(Path::new("/site/content/posts/a.png"), root));
+
+        images.enable = false;
+        assert!(!images.is_selected(Path::new("/site/content/posts/a.png"), root));
+    }
+}
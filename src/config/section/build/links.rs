@@ -0,0 +1,67 @@
+//! `[build.links]` section configuration.
+//!
+//! Attributes added to links pointing at other sites (`http://`, `https://`).
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.links.external]
+//! noopener = true         # Add rel="noopener noreferrer"
+//! target_blank = false    # Add target="_blank"
+//! ```
+
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+/// Link attribute settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.links")]
+pub struct LinksConfig {
+    /// Attributes for external links.
+    #[config(sub)]
+    pub external: ExternalLinksConfig,
+}
+
+/// Attributes for `<a>` elements linking to other sites
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.links.external")]
+pub struct ExternalLinksConfig {
+    /// Add `noopener noreferrer` to `rel`, keeping existing values.
+    pub noopener: bool,
+
+    /// Open external links in a new tab.
+    pub target_blank: bool,
+}
+
+impl Default for ExternalLinksConfig {
+    fn default() -> Self {
+        Self {
+            noopener: true,
+            target_blank: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_external_links_config() {
+        let config = test_parse_config("");
+        assert!(config.build.links.external.noopener);
+        assert!(!config.build.links.external.target_blank);
+
+        let config = test_parse_config(
+            r#"
+[build.links.external]
+noopener = false
+target_blank = true
+"#,
+        );
+        assert!(!config.build.links.external.noopener);
+        assert!(config.build.links.external.target_blank);
+    }
+}
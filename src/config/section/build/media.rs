@@ -0,0 +1,100 @@
+//! `[build.media]` section configuration.
+//!
+//! Links to audio files (`.mp3`, `.ogg`, `.wav`, `.flac`, `.aac`, `.opus`)
+//! become inline players with a download fallback.
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.media.audio]
+//! controls = true         # Show playback controls
+//! preload = "metadata"    # none | metadata | auto
+//! autoplay = false        # Start playing on page load
+//! ```
+
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+/// Media element settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.media")]
+pub struct MediaConfig {
+    /// Audio players for audio file links.
+    #[config(sub)]
+    pub audio: AudioConfig,
+}
+
+/// `<audio>` settings for audio file links
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.media.audio")]
+pub struct AudioConfig {
+    /// Show playback controls.
+    pub controls: bool,
+
+    /// How much audio to load before playback.
+    pub preload: AudioPreload,
+
+    /// Start playing on page load (most browsers require user interaction).
+    pub autoplay: bool,
+}
+
+impl Default for AudioConfig {
+    fn default() -> Self {
+        Self {
+            controls: true,
+            preload: AudioPreload::default(),
+            autoplay: false,
+        }
+    }
+}
+
+/// `preload` attribute of `<audio>`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioPreload {
+    /// Load nothing until playback starts.
+    None,
+    /// Load duration and other metadata (default).
+    #[default]
+    Metadata,
+    /// Load the whole file.
+    Auto,
+}
+
+impl AudioPreload {
+    /// Attribute value.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Metadata => "metadata",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_audio_config() {
+        let config = test_parse_config(
+            r#"
+[build.media.audio]
+preload = "none"
+autoplay = true
+"#,
+        );
+        let audio = &config.build.media.audio;
+        assert!(audio.controls);
+        assert!(audio.autoplay);
+        assert_eq!(audio.preload, AudioPreload::None);
+
+        let config = test_parse_config("");
+        assert_eq!(config.build.media.audio.preload, AudioPreload::Metadata);
+        assert!(!config.build.media.audio.autoplay);
+    }
+}
@@ -1,21 +1,30 @@
 //! Metadata extraction configuration.
 
+use macros::Config;
 use serde::{Deserialize, Serialize};
 
 /// Default metadata label for Typst files
 pub const TOLA_META_LABEL: &str = "tola-meta";
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Metadata extraction settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
 #[serde(default)]
+#[config(section = "build.meta")]
 pub struct MetaConfig {
-    /// Label name for metadata extraction in Typst files.
+    /// Label name for metadata extraction in Typst files (`#metadata(..) <label>`).
+    ///
+    /// Also used by the `templates/tola.typ` written by `tola init` and `tola fix`.
     pub label: String,
+
+    /// Estimate reading time from rendered text (`reading-time` in `@tola/current`).
+    pub reading_time: bool,
 }
 
 impl Default for MetaConfig {
     fn default() -> Self {
         Self {
             label: TOLA_META_LABEL.into(),
+            reading_time: false,
         }
     }
 }
@@ -7,6 +7,7 @@
 //! ```toml
 //! [build]
 //! content = "content"         # Source directory for .typ files (relative to site root)
+//! extra_content = ["docs"]    # More content directories, merged into one URL space
 //! output = "public"           # Output directory for generated HTML (relative to site root)
 //! assets = "assets"           # Static assets directory (relative to site root)
 //! deps = ["templates"]        # Dependency dirs (relative to site root)
@@ -21,43 +22,104 @@
 //! converter = "builtin"       # Conversion tool: builtin | magick | ffmpeg | none
 //! format = "svg"             # Output format: svg | png | jpg | webp
 //! dpi = 144.0                 # Rendering DPI (default: 96.0)
+//!
+//! [build.structured_data]
+//! enable = true               # Inject JSON-LD into pages
+//! schema = "Article"          # Default schema.org type
+//!
+//! [build.robots]
+//! enable = true               # Generate robots.txt
+//! disallow = ["/drafts/"]     # Paths crawlers must not fetch
+//!
+//! [build.code]
+//! theme = "base16-ocean.dark"  # Syntax highlighting theme
+//!
+//! [build.search]
+//! enable = true               # Generate a JSON search index
+//!
+//! [build.cdn]
+//! headers_file = true         # Generate `_headers` (Netlify/Cloudflare)
+//! redirects_file = true       # Generate `_redirects` for page aliases
+//!
+//! [build.media.audio]
+//! preload = "metadata"        # Audio players for audio file links
+//!
+//! [build.links.external]
+//! noopener = true             # Add rel="noopener noreferrer" to external links
+//!
+//! [build.compression]
+//! brotli = true               # Write pre-compressed `.br` files
+//! gzip = true                 # Write pre-compressed `.gz` files
 //! ```
 //!
-//! See submodules for detailed options: [`slug`], [`svg`], [`hooks`].
+//! See submodules for detailed options: [`slug`], [`svg`], [`hooks`], [`structured_data`],
+//! [`robots`], [`search`], [`code`], [`cdn`], [`media`], [`links`],
+//! [`compression`].
 
 pub mod assets;
+mod cache;
+mod cdn;
+mod code;
+mod compression;
 mod diagnostics;
 mod hooks;
+mod images;
+mod links;
+mod media;
 mod meta;
+mod robots;
+mod search;
 mod slug;
+mod structured_data;
 mod svg;
 
 pub use assets::AssetsConfig;
-pub use diagnostics::DiagnosticsConfig;
+pub use cache::CacheConfig;
+pub use cdn::CdnConfig;
+pub use code::CodeConfig;
+pub use compression::CompressionConfig;
+pub use diagnostics::{DiagnosticsConfig, DiagnosticsFormat};
 pub use hooks::{CssFormat, CssProcessorConfig, HookConfig, HooksConfig, WatchMode};
+pub use images::{ImageFormat, ImagesConfig};
+pub use links::LinksConfig;
+pub use media::MediaConfig;
 pub use meta::MetaConfig;
-pub use slug::{SlugCase, SlugConfig, SlugMode};
-pub use svg::{SvgConfig, SvgConverter, SvgFormat};
+pub use robots::RobotsConfig;
+pub use search::SearchConfig;
+pub use slug::{SlugCase, SlugConfig, SlugMode, SlugTransform};
+pub use structured_data::StructuredDataConfig;
+pub use svg::{SvgConfig, SvgConverter, SvgFormat, SvgPass};
 
 use crate::config::{ConfigDiagnostics, FieldPath};
+use crate::utils::glob;
+use macros::Config;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Build settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
 #[serde(default)]
+#[config(section = "build")]
 pub struct BuildSectionConfig {
     /// URL path prefix for subdirectory deployment.
     /// Automatically extracted from `[base].url` path component.
     #[serde(skip)]
+    #[config(skip)]
     pub path_prefix: PathBuf,
 
     /// Content source directory (Typst files).
     pub content: PathBuf,
 
+    /// Additional content directories (e.g. a docs submodule).
+    /// Each is a URL root like `content`: `docs/guide.typ` -> `/guide/`.
+    pub extra_content: Vec<PathBuf>,
+
     /// Build output directory.
     pub output: PathBuf,
 
     /// Static assets configuration.
+    #[config(sub)]
     pub assets: AssetsConfig,
 
     /// Dependency directories (templates/, utilities/, etc.).
@@ -69,29 +131,85 @@ pub struct BuildSectionConfig {
     /// Minify HTML output.
     pub minify: bool,
 
+    /// Per-file compilation timeout in seconds (0 = no timeout).
+    pub compile_timeout_secs: u64,
+
     /// Clean output directory before building (CLI only).
     #[serde(skip)]
+    #[config(skip)]
     pub clean: bool,
 
     /// Skip draft pages during build (CLI only).
     #[serde(skip)]
+    #[config(skip)]
     pub skip_drafts: bool,
 
+    /// Only compile content files matching this glob (CLI only).
+    #[serde(skip)]
+    #[config(skip)]
+    pub only: Option<String>,
+
+    /// Write a JSON build manifest to this path (CLI only).
+    #[serde(skip)]
+    #[config(skip)]
+    pub manifest: Option<PathBuf>,
+
     /// URL slugification settings.
+    #[config(sub)]
     pub slug: SlugConfig,
 
     /// SVG processing settings.
+    #[config(sub)]
     pub svg: SvgConfig,
 
     /// Build hooks (pre/post commands, tailwind).
+    #[config(sub)]
     pub hooks: HooksConfig,
 
     /// Metadata extraction settings.
+    #[config(sub)]
     pub meta: MetaConfig,
 
     /// Diagnostics display settings (warnings/errors).
+    #[config(sub)]
     pub diagnostics: DiagnosticsConfig,
 
+    /// JSON-LD structured data injection.
+    #[config(sub)]
+    pub structured_data: StructuredDataConfig,
+
+    /// robots.txt generation.
+    #[config(sub)]
+    pub robots: RobotsConfig,
+
+    /// Syntax highlighting for code blocks.
+    #[config(sub)]
+    pub code: CodeConfig,
+
+    /// Client-side search index generation.
+    #[config(sub)]
+    pub search: SearchConfig,
+
+    /// VDOM cache persistence.
+    #[config(sub)]
+    pub cache: CacheConfig,
+
+    /// CDN rule files (`_headers`, `_redirects`).
+    #[config(sub)]
+    pub cdn: CdnConfig,
+
+    /// Media elements (audio players).
+    #[config(sub)]
+    pub media: MediaConfig,
+
+    /// Link attributes (external links).
+    #[config(sub)]
+    pub links: LinksConfig,
+
+    /// Pre-compressed `.br` / `.gz` output files.
+    #[config(sub)]
+    pub compression: CompressionConfig,
+
     /// Allow experimental features without warnings.
     #[serde(default)]
     pub allow_experimental: bool,
@@ -102,18 +220,31 @@ impl Default for BuildSectionConfig {
         Self {
             path_prefix: PathBuf::new(),
             content: "content".into(),
+            extra_content: Vec::new(),
             output: "public".into(),
             assets: AssetsConfig::default(),
             deps: vec!["templates".into(), "utils".into()],
             data: "_data".into(),
             minify: true,
+            compile_timeout_secs: 0,
             clean: false,
             skip_drafts: false,
+            only: None,
+            manifest: None,
             slug: SlugConfig::default(),
             svg: SvgConfig::default(),
             hooks: HooksConfig::default(),
             meta: MetaConfig::default(),
             diagnostics: DiagnosticsConfig::default(),
+            structured_data: StructuredDataConfig::default(),
+            robots: RobotsConfig::default(),
+            code: CodeConfig::default(),
+            search: SearchConfig::default(),
+            cache: CacheConfig::default(),
+            cdn: CdnConfig::default(),
+            media: MediaConfig::default(),
+            links: LinksConfig::default(),
+            compression: CompressionConfig::default(),
             allow_experimental: false,
         }
     }
@@ -124,6 +255,32 @@ impl BuildSectionConfig {
     ///
     /// Checks deps paths exist and warns about missing ones.
     pub fn validate(&self, diag: &mut ConfigDiagnostics) {
+        self.assets.images.validate(diag);
+        self.structured_data.validate(diag);
+        self.code.validate(diag);
+        self.cache.validate(diag);
+
+        for dir in &self.extra_content {
+            if !dir.is_dir() {
+                diag.error(
+                    FieldPath::new("build.extra_content"),
+                    format!("content directory '{}' not found", dir.display()),
+                );
+            }
+            let overlaps = self
+                .content_dirs()
+                .any(|other| other != dir && (dir.starts_with(other) || other.starts_with(dir)));
+            if overlaps {
+                diag.error(
+                    FieldPath::new("build.extra_content"),
+                    format!(
+                        "content directory '{}' overlaps another content directory",
+                        dir.display()
+                    ),
+                );
+            }
+        }
+
         // Warn about missing deps directories
         for dep in &self.deps {
             if !dep.exists() {
@@ -145,6 +302,43 @@ impl BuildSectionConfig {
     pub fn filter_existing_deps(&mut self) {
         self.deps.retain(|p| p.exists());
     }
+
+    /// Per-file compilation timeout, or `None` when disabled.
+    pub fn compile_timeout(&self) -> Option<Duration> {
+        (self.compile_timeout_secs > 0).then(|| Duration::from_secs(self.compile_timeout_secs))
+    }
+
+    /// All content directories: `content` first, then `extra_content`.
+    pub fn content_dirs(&self) -> impl Iterator<Item = &Path> {
+        std::iter::once(self.content.as_path())
+            .chain(self.extra_content.iter().map(PathBuf::as_path))
+    }
+
+    /// The content directory containing `path`, if any.
+    pub fn content_root(&self, path: &Path) -> Option<&Path> {
+        self.content_dirs().find(|dir| path.starts_with(dir))
+    }
+
+    /// Check whether `path` lies in any content directory.
+    pub fn is_content_path(&self, path: &Path) -> bool {
+        self.content_root(path).is_some()
+    }
+
+    /// Check whether a content file is selected by `--only`.
+    ///
+    /// The glob is matched against the path relative to its content directory,
+    /// so `posts/**` selects everything under `content/posts/`. `*` does not
+    /// cross `/`; use `**` for that. Always true when no filter is set.
+    pub fn is_selected(&self, path: &Path) -> bool {
+        let Some(pattern) = &self.only else {
+            return true;
+        };
+        let relative = self
+            .content_root(path)
+            .and_then(|root| path.strip_prefix(root).ok())
+            .unwrap_or(path);
+        glob::matches_path(pattern, relative)
+    }
 }
 
 #[cfg(test)]
@@ -168,4 +362,76 @@ flatten = ["CNAME"]
         assert_eq!(config.build.assets.flatten.len(), 1);
         // minify defaults to true, only test assets config here
     }
+
+    #[test]
+    fn test_compile_timeout() {
+        let config = test_parse_config("");
+        assert_eq!(config.build.compile_timeout(), None);
+
+        let config = test_parse_config("[build]\ncompile_timeout_secs = 30");
+        assert_eq!(
+            config.build.compile_timeout(),
+            Some(std::time::Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn test_is_selected() {
+        let mut config = test_parse_config("");
+        let content = config.build.content.clone();
+        assert!(config.build.is_selected(&content.join("about.typ")));
+
+        config.build.only = Some("posts/**".to_string());
+        assert!(config.build.is_selected(&content.join("posts/hello.typ")));
+        assert!(
+            config
+                .build
+                .is_selected(&content.join("posts/2024/deep.md"))
+        );
+        assert!(!config.build.is_selected(&content.join("about.typ")));
+
+        config.build.only = Some("*.md".to_string());
+        assert!(config.build.is_selected(&content.join("notes.md")));
+        assert!(!config.build.is_selected(&content.join("posts/notes.md")));
+    }
+
+    #[test]
+    fn test_extra_content_dirs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("content")).unwrap();
+        std::fs::create_dir_all(temp.path().join("docs")).unwrap();
+
+        let mut build = crate::config::section::BuildSectionConfig {
+            content: temp.path().join("content"),
+            extra_content: vec![temp.path().join("docs")],
+            ..Default::default()
+        };
+        let doc = temp.path().join("docs/guide.typ");
+        assert_eq!(build.content_dirs().count(), 2);
+        assert_eq!(
+            build.content_root(&doc),
+            Some(temp.path().join("docs").as_path())
+        );
+        assert!(build.is_content_path(&temp.path().join("content/index.typ")));
+        assert!(!build.is_content_path(&temp.path().join("templates/base.typ")));
+
+        build.only = Some("guide.typ".into());
+        assert!(build.is_selected(&doc));
+
+        // Globs match relative to whichever content root holds the file
+        build.only = Some("api/**".into());
+        assert!(build.is_selected(&temp.path().join("docs/api/index.typ")));
+        assert!(build.is_selected(&temp.path().join("content/api/v1.md")));
+        assert!(!build.is_selected(&doc));
+        assert!(!build.is_selected(&temp.path().join("content/docs/api/index.typ")));
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        build.validate(&mut diag);
+        assert!(!diag.has_errors());
+
+        build.extra_content.push(temp.path().join("content/nested"));
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        build.validate(&mut diag);
+        assert!(diag.has_errors());
+    }
 }
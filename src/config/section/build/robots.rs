@@ -0,0 +1,77 @@
+//! `[build.robots]` section configuration.
+//!
+//! Generates `robots.txt` in the output root.
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.robots]
+//! enable = true
+//! disallow = ["/drafts/", "/private/"]
+//! allow = ["/private/public.html"]
+//! crawl_delay = 10                 # Seconds between requests
+//! sitemap = true                   # Add a `Sitemap:` line (needs site.info.url)
+//! ```
+
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+/// robots.txt generation settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.robots")]
+pub struct RobotsConfig {
+    /// Generate robots.txt.
+    pub enable: bool,
+
+    /// Paths crawlers may fetch (`Allow:`).
+    pub allow: Vec<String>,
+
+    /// Paths crawlers must not fetch (`Disallow:`).
+    pub disallow: Vec<String>,
+
+    /// Link the sitemap (`Sitemap:`) when sitemap generation is enabled.
+    pub sitemap: bool,
+
+    /// Seconds between requests (`Crawl-delay:`).
+    pub crawl_delay: Option<u32>,
+}
+
+impl Default for RobotsConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            allow: Vec::new(),
+            disallow: Vec::new(),
+            sitemap: true,
+            crawl_delay: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_robots_config() {
+        let config = test_parse_config(
+            r#"
+[build.robots]
+enable = true
+disallow = ["/drafts/"]
+crawl_delay = 5
+"#,
+        );
+        let robots = &config.build.robots;
+        assert!(robots.enable);
+        assert_eq!(robots.disallow, vec!["/drafts/"]);
+        assert!(robots.allow.is_empty());
+        assert!(robots.sitemap);
+        assert_eq!(robots.crawl_delay, Some(5));
+
+        let config = test_parse_config("");
+        assert!(!config.build.robots.enable);
+        assert_eq!(config.build.robots.crawl_delay, None);
+    }
+}
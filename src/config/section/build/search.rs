@@ -0,0 +1,72 @@
+//! `[build.search]` section configuration.
+//!
+//! Generates a JSON full-text search index for client-side search.
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.search]
+//! enable = true
+//! output_path = "_data/search-index.json"  # Relative to the output directory
+//! strip_html = true                        # Plain text instead of body HTML
+//! max_excerpt_chars = 2000                 # Truncate each page's text
+//! ```
+
+use macros::Config;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Search index generation settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.search")]
+pub struct SearchConfig {
+    /// Generate the search index.
+    pub enable: bool,
+
+    /// Index file, relative to the output directory.
+    pub output_path: PathBuf,
+
+    /// Index plain text; `false` keeps the page body HTML.
+    pub strip_html: bool,
+
+    /// Maximum characters of text per page (unlimited when unset).
+    pub max_excerpt_chars: Option<usize>,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            output_path: "_data/search-index.json".into(),
+            strip_html: true,
+            max_excerpt_chars: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_search_config() {
+        let config = test_parse_config(
+            r#"
+[build.search]
+enable = true
+strip_html = false
+max_excerpt_chars = 500
+"#,
+        );
+        let search = &config.build.search;
+        assert!(search.enable);
+        assert!(!search.strip_html);
+        assert_eq!(search.output_path.to_str(), Some("_data/search-index.json"));
+        assert_eq!(search.max_excerpt_chars, Some(500));
+
+        let config = test_parse_config("");
+        assert!(!config.build.search.enable);
+        assert!(config.build.search.strip_html);
+    }
+}
@@ -1,5 +1,6 @@
 //! URL slug configuration.
 
+use macros::Config;
 use serde::{Deserialize, Serialize};
 
 /// URL slug generation mode for paths and anchors
@@ -32,6 +33,18 @@ pub enum SlugCase {
     Preserve,
 }
 
+/// Text transformation applied before the slug mode
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SlugTransform {
+    /// No transformation (default).
+    #[default]
+    None,
+    /// NFKD normalization + Latin transliteration (`ä` -> `ae`, `é` -> `e`);
+    /// other scripts are preserved.
+    UnicodeLatin,
+}
+
 /// Separator character for slugs
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -53,8 +66,10 @@ impl SlugSeparator {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// URL slugification settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
 #[serde(default)]
+#[config(section = "build.slug")]
 pub struct SlugConfig {
     /// Slugify URL paths.
     pub path: SlugMode,
@@ -64,6 +79,10 @@ pub struct SlugConfig {
     pub separator: SlugSeparator,
     /// Case transformation.
     pub case: SlugCase,
+    /// Transformation applied before the slug mode.
+    pub transform: SlugTransform,
+    /// Maximum slug length in characters, truncated at word boundaries (`0` = unlimited).
+    pub max_length: usize,
 }
 
 impl Default for SlugConfig {
@@ -73,6 +92,8 @@ impl Default for SlugConfig {
             fragment: SlugMode::Full,
             separator: SlugSeparator::Dash,
             case: SlugCase::Lower,
+            transform: SlugTransform::None,
+            max_length: 0,
         }
     }
 }
@@ -0,0 +1,72 @@
+//! `[build.structured_data]` section configuration.
+//!
+//! Injects a JSON-LD `<script type="application/ld+json">` block into each page.
+//!
+//! # Example
+//!
+//! ```toml
+//! [build.structured_data]
+//! enable = true
+//! schema = "Article"               # Article | BlogPosting | WebSite | ...
+//! ```
+//!
+//! Pages can override the type with a `schema_type` metadata field.
+
+use crate::config::ConfigDiagnostics;
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+/// JSON-LD structured data settings
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "build.structured_data")]
+pub struct StructuredDataConfig {
+    /// Inject JSON-LD into every page.
+    pub enable: bool,
+
+    /// Default schema.org type (`@type`) for pages.
+    pub schema: String,
+}
+
+impl Default for StructuredDataConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            schema: "Article".into(),
+        }
+    }
+}
+
+impl StructuredDataConfig {
+    /// Validate structured data configuration.
+    pub fn validate(&self, diag: &mut ConfigDiagnostics) {
+        if self.enable && self.schema.trim().is_empty() {
+            diag.error(
+                StructuredDataConfig::FIELDS.schema,
+                format!("{} must not be empty", StructuredDataConfig::FIELDS.schema),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_structured_data_config() {
+        let config = test_parse_config(
+            r#"
+[build.structured_data]
+enable = true
+schema = "BlogPosting"
+"#,
+        );
+        assert!(config.build.structured_data.enable);
+        assert_eq!(config.build.structured_data.schema, "BlogPosting");
+
+        let config = test_parse_config("");
+        assert!(!config.build.structured_data.enable);
+        assert_eq!(config.build.structured_data.schema, "Article");
+    }
+}
@@ -13,6 +13,7 @@
 //! threshold = "10KB"      # SVGs smaller than this stay inline
 //! expand_viewbox = false  # Auto-expand viewBox to include stroke (default: false)
 //! baseline_align = false  # Apply vertical-align for inline SVG baseline (default: false)
+//! optimization_passes = ["remove-comments"]  # "all" (default) | "none" | pass names
 //! ```
 //!
 //! # Behavior
@@ -70,6 +71,34 @@ pub enum SvgConverter {
     Ffmpeg,
 }
 
+/// SVG optimization pass (`optimization_passes`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgPass {
+    /// Strip `<!-- ... -->` comments.
+    RemoveComments,
+    /// Rewrite through usvg, which flattens groups, resolves `use` and
+    /// converts shapes to paths. Scripts and animations are dropped.
+    CollapseGroups,
+}
+
+impl SvgPass {
+    /// Every pass, the default (`"all"`).
+    pub const ALL: [Self; 2] = [Self::RemoveComments, Self::CollapseGroups];
+
+    /// Name used in `optimization_passes`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::RemoveComments => "remove-comments",
+            Self::CollapseGroups => "collapse-groups",
+        }
+    }
+
+    /// Parse a pass name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|pass| pass.name() == name)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Config)]
 #[serde(default)]
 #[config(section = "build.svg", status = experimental)]
@@ -108,6 +137,13 @@ pub struct SvgConfig {
     /// Default: false (opt-in)
     #[config(default = "false", status = not_implemented)]
     pub baseline_align: bool,
+
+    /// Optimization passes applied to SVGs.
+    /// - `all`: Every pass (default)
+    /// - `none`: Keep SVGs as written, useful for animated or scripted SVGs
+    /// - `remove-comments`: Strip comments
+    /// - `collapse-groups`: Rewrite through usvg (flattens groups, drops scripts and animations)
+    pub optimization_passes: Vec<String>,
 }
 
 impl Default for SvgConfig {
@@ -120,6 +156,7 @@ impl Default for SvgConfig {
             threshold: "0B".to_string(),
             expand_viewbox: false,
             baseline_align: false,
+            optimization_passes: vec!["all".to_string()],
         }
     }
 }
@@ -145,12 +182,39 @@ impl SvgConfig {
         parse_size_string(&self.threshold)
     }
 
+    /// Resolve `optimization_passes`; unknown names are skipped.
+    pub fn passes(&self) -> Vec<SvgPass> {
+        let names = &self.optimization_passes;
+        if names.iter().any(|name| name == "none") {
+            return Vec::new();
+        }
+        if names.iter().any(|name| name == "all") {
+            return SvgPass::ALL.to_vec();
+        }
+        SvgPass::ALL
+            .into_iter()
+            .filter(|pass| names.iter().any(|name| name == pass.name()))
+            .collect()
+    }
+
     /// Validate SVG configuration.
     ///
     /// # Checks
+    /// - Every optimization pass must be known.
     /// - If rasterization is needed and converter is external (magick/ffmpeg),
     ///   the command must be installed.
     pub fn validate(&self, diag: &mut crate::config::ConfigDiagnostics) {
+        for name in &self.optimization_passes {
+            if name != "all" && name != "none" && SvgPass::from_name(name).is_none() {
+                let known: Vec<_> = SvgPass::ALL.iter().map(|pass| pass.name()).collect();
+                diag.error_with_hint(
+                    Self::FIELDS.optimization_passes,
+                    format!("unknown SVG optimization pass `{name}`"),
+                    format!("use \"all\", \"none\" or one of: {}", known.join(", ")),
+                );
+            }
+        }
+
         // Only check if external conversion is needed
         if !self.needs_rasterization() {
             return;
@@ -256,6 +320,23 @@ mod tests {
         assert_eq!(config.build.svg.threshold_bytes(), 10 * 1024);
     }
 
+    #[test]
+    fn test_optimization_passes() {
+        let config = test_parse_config("");
+        assert_eq!(config.build.svg.passes(), SvgPass::ALL);
+
+        let config = test_parse_config("[build.svg]\noptimization_passes = [\"none\"]");
+        assert!(config.build.svg.passes().is_empty());
+
+        let config = test_parse_config("[build.svg]\noptimization_passes = [\"remove-comments\"]");
+        assert_eq!(config.build.svg.passes(), [SvgPass::RemoveComments]);
+
+        let config = test_parse_config("[build.svg]\noptimization_passes = [\"minify\"]");
+        let mut diag = ConfigDiagnostics::new();
+        config.build.svg.validate(&mut diag);
+        assert!(diag.has_errors());
+    }
+
     #[test]
     fn test_baseline_align_is_not_implemented() {
         let snippet = "[build.svg]\nbaseline_align = true";
@@ -1,39 +1,58 @@
 //! `[deploy]` section configuration. (WIP, only github page supported now)
 //!
-//! Contains deployment settings for various providers (GitHub, Cloudflare, Vercel).
+//! Contains deployment settings for various providers (GitHub, Netlify, Cloudflare, Vercel).
 //!
 //! # Example
 //!
 //! ```toml
 //! [deploy]
-//! provider = "github"         # Deployment provider: github | cloudflare | vercel
+//! provider = "github"         # Deployment provider: github | netlify | cloudflare | vercel
 //! force = false               # Force push (overwrites remote history)
 //!
 //! [deploy.github]
 //! url = "https://github.com/user/user.github.io"  # Repository URL
-//! branch = "gh-pages"                              # Target branch
+//! branch = "main"                                  # Target branch
+//! page_type = "user"                               # project | user | org
 //! token_path = "~/.github-token"                   # Optional: PAT file path
+//!
+//! [deploy.netlify]
+//! site_id = "your-site-id"                         # Site API ID
+//! token_path = "~/.netlify-token"                  # Personal access token file
+//! production_branch = "main"                       # Other branches deploy as drafts
 //! ```
+//!
+//! User and organization pages (`page_type = "user"` / `"org"`) are served
+//! from the root of `<name>.github.io`, so `tola deploy` builds them without
+//! a `path_prefix`.
 
 use macros::Config;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Deploy configuration (not implemented)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Config)]
 #[serde(default)]
 #[config(section = "deploy", status = not_implemented)]
 pub struct DeployConfig {
-    /// Deployment provider: "github", "cloudflare", "vercel".
+    /// Deployment provider: "github", "netlify", "cloudflare", "vercel".
     pub provider: String,
 
     /// Force push (overwrites remote history).
     pub force: bool,
 
+    /// Report changes without deploying (CLI only).
+    #[serde(skip)]
+    #[config(skip)]
+    pub dry_run: bool,
+
     /// GitHub Pages deployment settings.
     #[config(sub)]
     pub github: GithubDeployConfig,
 
+    /// Netlify deployment settings.
+    #[config(sub)]
+    pub netlify: NetlifyDeployConfig,
+
     /// Cloudflare Pages settings (not yet implemented).
     #[config(sub)]
     pub cloudflare: CloudflareDeployConfig,
@@ -48,7 +67,9 @@ impl Default for DeployConfig {
         Self {
             provider: "github".to_string(),
             force: false,
+            dry_run: false,
             github: GithubDeployConfig::default(),
+            netlify: NetlifyDeployConfig::default(),
             cloudflare: CloudflareDeployConfig::default(),
             vercel: VercelDeployConfig::default(),
         }
@@ -59,30 +80,55 @@ impl DeployConfig {
     /// Validate deploy configuration.
     ///
     /// # Checks
-    /// - If `github.token_path` is set, it must exist and be a file.
+    /// - If `github.token_path` or `netlify.token_path` is set, it must exist and be a file.
+    /// - With `provider = "netlify"`, `netlify.site_id` and `netlify.token_path` are required.
     pub fn validate(&self, diag: &mut crate::config::ConfigDiagnostics) {
         if let Some(path) = &self.github.token_path {
-            if !path.exists() {
+            Self::validate_token_path(GithubDeployConfig::FIELDS.token_path, path, diag);
+        }
+        if let Some(path) = &self.netlify.token_path {
+            Self::validate_token_path(NetlifyDeployConfig::FIELDS.token_path, path, diag);
+        }
+
+        if self.provider == "netlify" {
+            if self.netlify.site_id.trim().is_empty() {
                 diag.error(
-                    GithubDeployConfig::FIELDS.token_path,
+                    NetlifyDeployConfig::FIELDS.site_id,
                     format!(
-                        "{} file not found: {}",
-                        GithubDeployConfig::FIELDS.token_path,
-                        path.display()
+                        "{} is required when deploy.provider = \"netlify\"",
+                        NetlifyDeployConfig::FIELDS.site_id
                     ),
                 );
-            } else if !path.is_file() {
+            }
+            if self.netlify.token_path.is_none() {
                 diag.error(
-                    GithubDeployConfig::FIELDS.token_path,
+                    NetlifyDeployConfig::FIELDS.token_path,
                     format!(
-                        "{} is not a file: {}",
-                        GithubDeployConfig::FIELDS.token_path,
-                        path.display()
+                        "{} is required when deploy.provider = \"netlify\"",
+                        NetlifyDeployConfig::FIELDS.token_path
                     ),
                 );
             }
         }
     }
+
+    fn validate_token_path(
+        field: crate::config::FieldPath,
+        path: &Path,
+        diag: &mut crate::config::ConfigDiagnostics,
+    ) {
+        if !path.exists() {
+            diag.error(
+                field,
+                format!("{} file not found: {}", field, path.display()),
+            );
+        } else if !path.is_file() {
+            diag.error(
+                field,
+                format!("{} is not a file: {}", field, path.display()),
+            );
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Config)]
@@ -95,11 +141,20 @@ pub struct GithubDeployConfig {
     /// Target branch for deployment (e.g., "main", "gh-pages").
     pub branch: String,
 
+    /// Site type: `project` pages live under `/<repo>/`, `user` and `org`
+    /// pages at the root of `<name>.github.io`.
+    pub page_type: GithubPageType,
+
     /// Path to file containing GitHub personal access token.
     ///
+    /// `TOLA_DEPLOY_TOKEN_PATH` overrides this path. `TOLA_DEPLOY_TOKEN`
+    /// holds the token itself (e.g. a CI secret) and is used instead of
+    /// any token file when set.
+    ///
     /// # Security
     /// - Store outside repository (e.g., `~/.github-token`)
     /// - Never commit tokens to version control!
+    #[config(env = "TOLA_DEPLOY_TOKEN_PATH")]
     pub token_path: Option<PathBuf>,
 }
 
@@ -108,7 +163,65 @@ impl Default for GithubDeployConfig {
         Self {
             url: String::new(),
             branch: "main".to_string(),
+            page_type: GithubPageType::default(),
+            token_path: None,
+        }
+    }
+}
+
+/// GitHub Pages site type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GithubPageType {
+    /// Project page, served under `/<repo>/` (default).
+    #[default]
+    Project,
+    /// User page, served from the root of `<user>.github.io`.
+    User,
+    /// Organization page, served from the root of `<org>.github.io`.
+    Org,
+}
+
+impl GithubPageType {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Project => "project",
+            Self::User => "user",
+            Self::Org => "org",
+        }
+    }
+
+    /// Whether the site is served from the domain root.
+    pub const fn is_root(self) -> bool {
+        matches!(self, Self::User | Self::Org)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "deploy.netlify")]
+pub struct NetlifyDeployConfig {
+    /// Netlify site API ID (Site configuration → Site details).
+    pub site_id: String,
+
+    /// Path to file containing a Netlify personal access token.
+    ///
+    /// # Security
+    /// - Store outside repository (e.g., `~/.netlify-token`)
+    /// - Never commit tokens to version control!
+    #[config(env = "TOLA_NETLIFY_TOKEN_PATH")]
+    pub token_path: Option<PathBuf>,
+
+    /// Branch published to production; other branches create draft deploys.
+    pub production_branch: String,
+}
+
+impl Default for NetlifyDeployConfig {
+    fn default() -> Self {
+        Self {
+            site_id: String::new(),
             token_path: None,
+            production_branch: "main".to_string(),
         }
     }
 }
@@ -147,6 +260,7 @@ impl Default for VercelDeployConfig {
 
 #[cfg(test)]
 mod tests {
+    use super::GithubPageType;
     use crate::config::{ConfigDiagnostics, ConfigPresence, SiteConfig, test_parse_config};
 
     #[test]
@@ -160,6 +274,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_github_page_type() {
+        let config = test_parse_config("");
+        assert_eq!(config.deploy.github.page_type, GithubPageType::Project);
+        assert!(!config.deploy.github.page_type.is_root());
+
+        let config = test_parse_config("[deploy.github]\npage_type = \"org\"");
+        assert_eq!(config.deploy.github.page_type, GithubPageType::Org);
+        assert!(config.deploy.github.page_type.is_root());
+    }
+
+    #[test]
+    fn test_netlify_config() {
+        let config = test_parse_config(
+            r#"
+[deploy]
+provider = "netlify"
+
+[deploy.netlify]
+site_id = "abc"
+production_branch = "release"
+"#,
+        );
+        assert_eq!(config.deploy.netlify.site_id, "abc");
+        assert_eq!(config.deploy.netlify.production_branch, "release");
+
+        // token_path is required for the netlify provider
+        let mut diag = ConfigDiagnostics::new();
+        config.deploy.validate(&mut diag);
+        assert!(diag.has_errors());
+
+        let config = test_parse_config("");
+        assert_eq!(config.deploy.netlify.production_branch, "main");
+        let mut diag = ConfigDiagnostics::new();
+        config.deploy.validate(&mut diag);
+        assert!(!diag.has_errors());
+    }
+
     #[test]
     fn test_not_implemented_section_triggers_on_explicit_presence_even_if_default() {
         let snippet = r#"
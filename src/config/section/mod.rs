@@ -20,10 +20,14 @@ mod validate;
 
 // Re-export section configs
 pub use build::{
-    AssetsConfig, BuildSectionConfig, SlugCase, SlugConfig, SlugMode, SvgConverter, SvgFormat,
+    AssetsConfig, BuildSectionConfig, CompressionConfig, DiagnosticsFormat, SlugCase, SlugConfig,
+    SlugMode, SlugTransform, SvgConverter, SvgFormat, SvgPass,
 };
-pub use deploy::DeployConfig;
-pub use serve::ServeConfig;
-pub use site::{FeedConfig, FeedFormat, SiteSectionConfig};
+pub use deploy::{DeployConfig, GithubPageType};
+pub use serve::{DiffGranularity, ProxyConfig, ServeConfig};
+pub use site::{FeedConfig, FeedFormat, SiteSectionConfig, SitemapLastmod};
 pub use theme::ThemeSectionConfig;
-pub use validate::{AssetsValidateConfig, PagesValidateConfig, ValidateConfig, ValidateLevel};
+pub use validate::{
+    AccessibilityValidateConfig, AssetsValidateConfig, ExternalValidateConfig,
+    OrphansValidateConfig, PagesValidateConfig, ValidateConfig, ValidateLevel,
+};
@@ -9,18 +9,96 @@
 //! interface = "127.0.0.1"     # Network interface (127.0.0.1 = localhost only)
 //! port = 5277                 # HTTP port number
 //! watch = true                # Auto-rebuild on file changes
+//! watch_ignore = ["**/.git/**", "**/node_modules/**"]  # Paths the watcher skips
+//! watch_debounce_ms = 300     # Quiet time after the last file event before rebuilding
+//! open = false                # Open the site in the default browser on startup
 //! respect_prefix = false      # Ignore path_prefix for local development
+//! shutdown_timeout_secs = 5   # Wait for in-flight requests on Ctrl+C
+//!
+//! [serve.drafts]
+//! enable = false              # Serve draft pages for preview
+//! prefix = "/_drafts"         # URL prefix for draft pages
+//!
+//! [serve.diff]
+//! granularity = "element"     # Hot reload patches: element | block | page
+//!
+//! [serve.tls]
+//! enable = false              # Serve over HTTPS (and hot reload over wss://)
+//! cert = "certs/dev.pem"      # PEM certificate (optional)
+//! key = "certs/dev-key.pem"   # PEM private key (optional)
+//!
+//! [serve.http2]
+//! enable = false              # Serve over HTTP/2 (requires serve.tls)
+//!
+//! [[serve.proxy]]
+//! prefix = "/api"             # Forward /api and /api/** ...
+//! target = "http://localhost:3000"  # ... to this backend
+//!
+//! [[serve.middleware]]
+//! path_pattern = "/**"        # Request path glob
+//! headers = { "Content-Security-Policy" = "default-src 'self'" }
+//!
+//! [[serve.headers]]
+//! path_pattern = "**/*.{css,js}"   # Request path glob
+//! cache_control = "no-cache"       # Cache-Control for matching responses
 //! ```
 //!
-//! Use `interface = "0.0.0.0"` to make the server accessible from LAN.
+//! Use `interface = "0.0.0.0"` to make the server accessible from LAN; the
+//! network URLs are then logged next to the localhost one.
 //!
 //! Set `respect_prefix = true` to test deployment paths (e.g., GitHub Pages subdirectory).
+//!
+//! Globs follow the shared rules in [`crate::utils::glob`]: `watch_ignore`
+//! matches paths relative to the site root, `path_pattern` matches request
+//! paths, and a leading `/` is optional in both.
+//!
+//! `watch_debounce_ms` also applies to `tola build --watch` and is read when
+//! the watcher starts, so changing it needs a restart. Lower it on fast disks;
+//! raise it on network file systems that report one save as several events.
+//!
+//! With `serve.drafts.enable`, draft pages are compiled during `tola serve` and
+//! served under `prefix` (e.g., `/_drafts/posts/hello/`). `tola build` never
+//! outputs drafts.
+//!
+//! With `serve.tls.enable` and no `cert`/`key`, a self-signed certificate for
+//! `localhost` is generated on first run and cached in `.tola/cache/tls/`.
+//! Browsers will ask to trust it once.
+//!
+//! `serve.http2.enable` multiplexes asset loading over one connection. Clients
+//! without HTTP/2 support fall back to HTTP/1.1 on the same port.
+//!
+//! `serve.diff.granularity` trades patch precision for speed: `block` patches
+//! whole block elements (`<p>`, `<pre>`, `<ul>`, ...) instead of the inline
+//! elements inside them, and `page` always reloads the full page.
+//!
+//! `[[serve.proxy]]` requests are forwarded before any file lookup, keeping
+//! the full request path (`/api/users` -> `http://localhost:3000/api/users`).
+//!
+//! `[[serve.middleware]]` headers are added to every response whose request
+//! path matches `path_pattern` (`/**` for all, `/assets/**` for one directory).
+//! `%{content_type}` in a value is replaced with the response content type.
+//!
+//! `[[serve.headers]]` sets `Cache-Control`; the first matching rule wins.
+//! Without a match, content-hashed tola assets (under `/.tola/`) are cached
+//! for a year and everything else gets `no-cache`. HTML pages are always sent
+//! uncached for hot reload.
 
+use std::collections::BTreeMap;
 use std::net::{IpAddr, Ipv4Addr};
+use std::path::{Path, PathBuf};
 
 use macros::Config;
 use serde::{Deserialize, Serialize};
 
+use crate::core::UrlPath;
+use crate::utils::glob;
+use crate::utils::path::normalize_path;
+
+/// `Cache-Control` for content-hashed tola assets (`/.tola/...`)
+const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+/// `Cache-Control` for everything else, revalidated on every request
+const DEFAULT_CACHE_CONTROL: &str = "no-cache";
+
 /// Development server settings
 #[derive(Debug, Clone, Serialize, Deserialize, Config)]
 #[serde(default)]
@@ -29,18 +107,62 @@ pub struct ServeConfig {
     /// Network interface to bind
     /// - `127.0.0.1` (default): localhost only
     /// - `0.0.0.0`: all interfaces (LAN accessible)
+    #[config(env = "TOLA_SERVE_INTERFACE")]
     pub interface: IpAddr,
 
-    #[config(inline_doc = "HTTP port number")]
+    #[config(
+        env = "TOLA_SERVE_PORT",
+        validate = "validate_port",
+        inline_doc = "HTTP port number"
+    )]
     pub port: u16,
 
     #[config(inline_doc = "Enable file watcher for live reload")]
     pub watch: bool,
 
+    #[config(inline_doc = "Glob patterns the file watcher skips")]
+    pub watch_ignore: Vec<String>,
+
+    #[config(inline_doc = "Milliseconds to wait after the last file event before rebuilding")]
+    pub watch_debounce_ms: u64,
+
+    #[config(inline_doc = "Open the site in the default browser on startup")]
+    pub open: bool,
+
     /// Respect path_prefix from site.url during local development
     /// - `false` (default): Ignore prefix, access pages at `/`
     /// - `true`: Keep prefix, access at `/my-project/`
     pub respect_prefix: bool,
+
+    #[config(inline_doc = "Seconds to wait for in-flight requests on shutdown")]
+    pub shutdown_timeout_secs: u64,
+
+    // Arrays stay ahead of the sub-tables so the generated template keeps
+    // them under `[serve]`
+    /// Reverse proxy rules for API backends
+    pub proxy: Vec<ProxyConfig>,
+
+    /// Extra response headers by request path
+    pub middleware: Vec<MiddlewareConfig>,
+
+    /// `Cache-Control` rules by request path
+    pub headers: Vec<CacheHeaderConfig>,
+
+    /// Draft preview settings
+    #[config(sub)]
+    pub drafts: DraftsServeConfig,
+
+    /// Hot reload diff settings
+    #[config(sub)]
+    pub diff: DiffServeConfig,
+
+    /// HTTPS settings
+    #[config(sub)]
+    pub tls: TlsServeConfig,
+
+    /// HTTP/2 settings
+    #[config(sub)]
+    pub http2: Http2ServeConfig,
 }
 
 impl Default for ServeConfig {
@@ -49,7 +171,615 @@ impl Default for ServeConfig {
             interface: IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
             port: 5277,
             watch: true,
+            watch_ignore: vec!["**/.git/**".into(), "**/node_modules/**".into()],
+            watch_debounce_ms: 300,
+            open: false,
             respect_prefix: false,
+            shutdown_timeout_secs: 5,
+            drafts: DraftsServeConfig::default(),
+            diff: DiffServeConfig::default(),
+            tls: TlsServeConfig::default(),
+            http2: Http2ServeConfig::default(),
+            proxy: Vec::new(),
+            middleware: Vec::new(),
+            headers: Vec::new(),
+        }
+    }
+}
+
+impl ServeConfig {
+    /// Validate serve configuration.
+    ///
+    /// # Checks
+    /// - `drafts.prefix` must not be the site root when draft preview is enabled.
+    /// - `tls.cert` and `tls.key` must be set together.
+    /// - `http2.enable` requires `tls.enable`.
+    /// - `proxy` prefixes must start with `/` and targets must be http(s) URLs.
+    /// - `middleware` header names and values must be valid.
+    /// - `headers` rules need a pattern and a single-line ASCII `cache_control`.
+    /// - `watch_debounce_ms` below 10 is warned about.
+    pub fn validate(&self, diag: &mut crate::config::ConfigDiagnostics) {
+        if self.watch && self.watch_debounce_ms < 10 {
+            diag.warn(
+                ServeConfig::FIELDS.watch_debounce_ms,
+                format!(
+                    "watch_debounce_ms = {} may cause spurious rebuilds on networked file systems",
+                    self.watch_debounce_ms
+                ),
+            );
+        }
+
+        for rule in &self.headers {
+            if rule.path_pattern.is_empty() {
+                diag.error(ServeConfig::FIELDS.headers, "headers path_pattern is empty");
+            }
+            let value = &rule.cache_control;
+            if value.is_empty() || value.contains(['\r', '\n']) || !value.is_ascii() {
+                diag.error(
+                    ServeConfig::FIELDS.headers,
+                    format!(
+                        "headers cache_control for '{}' must be single-line ASCII",
+                        rule.path_pattern
+                    ),
+                );
+            }
+        }
+
+        for middleware in &self.middleware {
+            for (name, value) in &middleware.headers {
+                let valid_name = !name.is_empty()
+                    && name
+                        .bytes()
+                        .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b));
+                if !valid_name {
+                    diag.error(
+                        ServeConfig::FIELDS.middleware,
+                        format!("middleware header name '{name}' is invalid"),
+                    );
+                }
+                if value.contains(['\r', '\n']) || !value.is_ascii() {
+                    diag.error(
+                        ServeConfig::FIELDS.middleware,
+                        format!("middleware header '{name}' value must be single-line ASCII"),
+                    );
+                }
+            }
+        }
+
+        for proxy in &self.proxy {
+            if !proxy.prefix.starts_with('/') {
+                diag.error(
+                    ServeConfig::FIELDS.proxy,
+                    format!("proxy prefix '{}' must start with '/'", proxy.prefix),
+                );
+            }
+            if !matches!(url::Url::parse(&proxy.target), Ok(url) if matches!(url.scheme(), "http" | "https"))
+            {
+                diag.error(
+                    ServeConfig::FIELDS.proxy,
+                    format!("proxy target '{}' must be an http(s) URL", proxy.target),
+                );
+            }
+        }
+
+        if self.tls.cert.is_some() != self.tls.key.is_some() {
+            diag.error(
+                TlsServeConfig::FIELDS.cert,
+                format!(
+                    "{} and {} must be set together",
+                    TlsServeConfig::FIELDS.cert,
+                    TlsServeConfig::FIELDS.key
+                ),
+            );
+        }
+
+        if self.http2.enable && !self.tls.enable {
+            diag.error_with_hint(
+                Http2ServeConfig::FIELDS.enable,
+                "HTTP/2 requires TLS",
+                format!("set {} = true", TlsServeConfig::FIELDS.enable),
+            );
+        }
+
+        if self.drafts.enable && UrlPath::from_page(&self.drafts.prefix) == "/" {
+            diag.error(
+                DraftsServeConfig::FIELDS.prefix,
+                format!(
+                    "{} must not be the site root",
+                    DraftsServeConfig::FIELDS.prefix
+                ),
+            );
+        }
+    }
+
+    /// Validate `port`: privileged ports (below 1024) are rejected.
+    fn validate_port(port: &u16, diag: &mut crate::config::ConfigDiagnostics) {
+        if *port < 1024 {
+            diag.error_with_hint(
+                ServeConfig::FIELDS.port,
+                format!("port {port} is privileged, must be 1024 or higher"),
+                "use an unprivileged port like 5277 or 8080",
+            );
+        }
+    }
+
+    /// Headers from all `middleware` rules matching `path`, in config order.
+    ///
+    /// `%{content_type}` in values is replaced with `content_type`.
+    pub fn middleware_headers(&self, path: &str, content_type: &str) -> Vec<(String, String)> {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        self.middleware
+            .iter()
+            .filter(|middleware| middleware.matches(path))
+            .flat_map(|middleware| &middleware.headers)
+            .map(|(name, value)| (name.clone(), value.replace("%{content_type}", content_type)))
+            .collect()
+    }
+
+    /// `Cache-Control` for request `path`.
+    ///
+    /// The first matching `headers` rule, else a year for tola assets under
+    /// `.tola/` (their names carry a content hash) and `no-cache` otherwise.
+    pub fn cache_control(&self, path: &str) -> &str {
+        let path = path.split(['?', '#']).next().unwrap_or_default();
+        if let Some(rule) = self.headers.iter().find(|rule| rule.matches(path)) {
+            return &rule.cache_control;
+        }
+        if path.split('/').any(|segment| segment == ".tola") {
+            IMMUTABLE_CACHE_CONTROL
+        } else {
+            DEFAULT_CACHE_CONTROL
+        }
+    }
+
+    /// Quiet time the file watcher waits for before rebuilding.
+    pub const fn watch_debounce(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.watch_debounce_ms)
+    }
+
+    /// Check whether the file watcher should skip `path`.
+    ///
+    /// A `dir/**` pattern also matches `dir` itself, so whole directories
+    /// can be skipped without looking at their contents.
+    pub fn is_watch_ignored(&self, path: &Path, root: &Path) -> bool {
+        let path = normalize_path(path);
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        self.watch_ignore
+            .iter()
+            .any(|pattern| glob::matches_path(pattern, relative))
+    }
+}
+
+/// One `[[serve.proxy]]` rule
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProxyConfig {
+    /// URL path prefix to forward (e.g. `/api`)
+    pub prefix: String,
+
+    /// Backend origin (e.g. `http://localhost:3000`)
+    pub target: String,
+}
+
+impl ProxyConfig {
+    /// Whether `path` (without query) is `prefix` or below it.
+    pub fn matches(&self, path: &str) -> bool {
+        let prefix = self.prefix.trim_end_matches('/');
+        match path.strip_prefix(prefix) {
+            Some(rest) => prefix.is_empty() || rest.is_empty() || rest.starts_with('/'),
+            None => false,
+        }
+    }
+}
+
+/// One `[[serve.middleware]]` rule
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MiddlewareConfig {
+    /// Request path glob (e.g. `/**`, `/assets/**`)
+    pub path_pattern: String,
+
+    /// Header name -> value added to matching responses
+    pub headers: BTreeMap<String, String>,
+}
+
+impl MiddlewareConfig {
+    /// Whether request `path` (without query) matches `path_pattern`.
+    pub fn matches(&self, path: &str) -> bool {
+        glob::matches(&self.path_pattern, path)
+    }
+}
+
+/// One `[[serve.headers]]` rule
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheHeaderConfig {
+    /// Request path glob (e.g. `**/*.{css,js}`, `/.tola/**`)
+    pub path_pattern: String,
+
+    /// `Cache-Control` value for matching responses
+    pub cache_control: String,
+}
+
+impl CacheHeaderConfig {
+    /// Whether request `path` (without query) matches `path_pattern`.
+    pub fn matches(&self, path: &str) -> bool {
+        glob::matches(&self.path_pattern, path)
+    }
+}
+
+/// Draft preview settings
+///
+/// Only takes effect for `tola serve`; other commands always drop drafts.
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "serve.drafts")]
+pub struct DraftsServeConfig {
+    #[config(inline_doc = "Serve draft pages for preview")]
+    pub enable: bool,
+
+    #[config(inline_doc = "URL prefix for draft pages")]
+    pub prefix: String,
+}
+
+impl Default for DraftsServeConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            prefix: "/_drafts".into(),
         }
     }
 }
+
+impl DraftsServeConfig {
+    /// Move a draft page's permalink under `prefix`.
+    ///
+    /// `/posts/hello/` -> `/_drafts/posts/hello/`
+    ///
+    /// Permalinks already under `prefix` are returned unchanged, so metadata
+    /// can be applied to a route more than once.
+    pub fn permalink(&self, permalink: &UrlPath) -> UrlPath {
+        let prefix = UrlPath::from_page(&self.prefix);
+        if permalink.as_str().starts_with(prefix.as_str()) {
+            return permalink.clone();
+        }
+        UrlPath::from_page(&format!(
+            "{}{}",
+            prefix.as_str().trim_end_matches('/'),
+            permalink
+        ))
+    }
+}
+
+/// Hot reload diff settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "serve.diff")]
+pub struct DiffServeConfig {
+    /// Smallest unit a hot reload patch replaces
+    pub granularity: DiffGranularity,
+}
+
+/// Smallest unit a hot reload patch replaces
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffGranularity {
+    /// Patch individual elements (default).
+    #[default]
+    Element,
+    /// Patch whole block elements (`<p>`, `<pre>`, `<ul>`, ...).
+    Block,
+    /// Skip diffing and reload the full page.
+    Page,
+}
+
+/// HTTPS settings
+///
+/// Useful for service workers, secure cookies and other browser APIs that
+/// require a secure context.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "serve.tls")]
+pub struct TlsServeConfig {
+    #[config(inline_doc = "Serve over HTTPS")]
+    pub enable: bool,
+
+    /// PEM certificate (chain); a self-signed one is generated when unset
+    pub cert: Option<PathBuf>,
+
+    /// PEM private key matching `cert`
+    pub key: Option<PathBuf>,
+}
+
+/// HTTP/2 settings
+///
+/// Browsers only speak HTTP/2 over TLS, so `[serve.tls]` must be enabled.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "serve.http2")]
+pub struct Http2ServeConfig {
+    #[config(inline_doc = "Serve over HTTP/2 (multiplexed asset loading)")]
+    pub enable: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_granularity() {
+        use crate::config::test_parse_config;
+
+        let config = test_parse_config(
+            r#"
+[serve.diff]
+granularity = "block"
+"#,
+        );
+        assert_eq!(config.serve.diff.granularity, DiffGranularity::Block);
+
+        let config = test_parse_config("");
+        assert_eq!(config.serve.diff.granularity, DiffGranularity::Element);
+    }
+
+    #[test]
+    fn test_drafts_permalink() {
+        let drafts = DraftsServeConfig::default();
+        assert_eq!(
+            drafts.permalink(&UrlPath::from_page("/posts/hello/")),
+            UrlPath::from_page("/_drafts/posts/hello/")
+        );
+        assert_eq!(
+            drafts.permalink(&UrlPath::from_page("/")),
+            UrlPath::from_page("/_drafts/")
+        );
+        // Idempotent
+        assert_eq!(
+            drafts.permalink(&UrlPath::from_page("/_drafts/posts/hello/")),
+            UrlPath::from_page("/_drafts/posts/hello/")
+        );
+
+        let drafts = DraftsServeConfig {
+            prefix: "preview/".into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            drafts.permalink(&UrlPath::from_page("/note/")),
+            UrlPath::from_page("/preview/note/")
+        );
+    }
+
+    #[test]
+    fn test_watch_debounce() {
+        use crate::config::test_parse_config;
+        use std::time::Duration;
+
+        let config = test_parse_config("");
+        assert_eq!(config.serve.watch_debounce(), Duration::from_millis(300));
+
+        let config = test_parse_config("[serve]\nwatch_debounce_ms = 50");
+        assert_eq!(config.serve.watch_debounce(), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_watch_ignore() {
+        let root = Path::new("/site");
+        let mut serve = ServeConfig::default();
+
+        assert!(serve.is_watch_ignored(Path::new("/site/node_modules/pkg/index.js"), root));
+        assert!(serve.is_watch_ignored(Path::new("/site/content/node_modules"), root));
+        assert!(serve.is_watch_ignored(Path::new("/site/.git/HEAD"), root));
+        assert!(!serve.is_watch_ignored(Path::new("/site/content/post.typ"), root));
+
+        serve.watch_ignore = vec!["content/generated/**".into(), "*.tmp".into()];
+        assert!(serve.is_watch_ignored(Path::new("/site/content/generated"), root));
+        assert!(serve.is_watch_ignored(Path::new("/site/content/generated/a/b.typ"), root));
+        assert!(serve.is_watch_ignored(Path::new("/site/draft.tmp"), root));
+        // `*` stays within one directory
+        assert!(!serve.is_watch_ignored(Path::new("/site/content/draft.tmp"), root));
+    }
+
+    #[test]
+    fn test_proxy_matches() {
+        let proxy = ProxyConfig {
+            prefix: "/api/".into(),
+            target: "http://localhost:3000".into(),
+        };
+        assert!(proxy.matches("/api"));
+        assert!(proxy.matches("/api/users"));
+        assert!(!proxy.matches("/apix"));
+        assert!(!proxy.matches("/"));
+    }
+
+    #[test]
+    fn test_proxy_validation() {
+        let config = crate::config::test_parse_config(
+            r#"
+[[serve.proxy]]
+prefix = "/api"
+target = "http://localhost:3000"
+
+[[serve.proxy]]
+prefix = "auth"
+target = "localhost:4000"
+"#,
+        );
+        let mut serve = config.serve;
+        assert_eq!(serve.proxy.len(), 2);
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(diag.has_errors());
+
+        serve.proxy.truncate(1);
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(!diag.has_errors());
+    }
+
+    #[test]
+    fn test_middleware_headers() {
+        let config = crate::config::test_parse_config(
+            r#"
+[[serve.middleware]]
+path_pattern = "/**"
+headers = { "X-Frame-Options" = "DENY" }
+
+[[serve.middleware]]
+path_pattern = "/assets/**"
+headers = { "Cache-Control" = "max-age=60", "X-Type" = "%{content_type}" }
+"#,
+        );
+        let serve = config.serve;
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(!diag.has_errors());
+
+        let names = |path: &str| -> Vec<String> {
+            serve
+                .middleware_headers(path, "text/css")
+                .into_iter()
+                .map(|(name, _)| name)
+                .collect()
+        };
+        assert_eq!(names("/"), ["X-Frame-Options"]);
+        assert_eq!(names("/posts/hello/?x=1"), ["X-Frame-Options"]);
+        assert_eq!(
+            names("/assets/css/site.css"),
+            ["X-Frame-Options", "Cache-Control", "X-Type"]
+        );
+        assert_eq!(
+            names("/assets"),
+            ["X-Frame-Options", "Cache-Control", "X-Type"]
+        );
+        assert!(names("/assetsx/a.css").len() == 1);
+
+        let headers = serve.middleware_headers("/assets/a.css", "text/css");
+        assert_eq!(headers[2], ("X-Type".to_string(), "text/css".to_string()));
+    }
+
+    #[test]
+    fn test_middleware_validation() {
+        let mut serve = ServeConfig::default();
+        serve.middleware.push(MiddlewareConfig {
+            path_pattern: "assets/**".into(),
+            headers: BTreeMap::from([("Bad Header".into(), "a\nb".into())]),
+        });
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(diag.has_errors());
+    }
+
+    #[test]
+    fn test_cache_control() {
+        let config = crate::config::test_parse_config(
+            r#"
+[[serve.headers]]
+path_pattern = "**/*.{css,js}"
+cache_control = "no-cache, max-age=0"
+
+[[serve.headers]]
+path_pattern = "/images/**"
+cache_control = "max-age=60"
+"#,
+        );
+        let serve = config.serve;
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(!diag.has_errors());
+
+        assert_eq!(serve.cache_control("/app.js"), "no-cache, max-age=0");
+        assert_eq!(
+            serve.cache_control("/a/b/site.css?v=1"),
+            "no-cache, max-age=0"
+        );
+        assert_eq!(serve.cache_control("/images/logo.png"), "max-age=60");
+        assert_eq!(serve.cache_control("/posts/hello/"), DEFAULT_CACHE_CONTROL);
+        // First rule wins over the `.tola/` default
+        assert_eq!(
+            serve.cache_control("/.tola/spa-1a2b.js"),
+            "no-cache, max-age=0"
+        );
+        assert_eq!(
+            serve.cache_control("/docs/.tola/recolor-1a2b.svg"),
+            IMMUTABLE_CACHE_CONTROL
+        );
+    }
+
+    #[test]
+    fn test_cache_header_validation() {
+        let mut serve = ServeConfig::default();
+        serve.headers.push(CacheHeaderConfig {
+            path_pattern: "/**".into(),
+            cache_control: "no-cache\nX: y".into(),
+        });
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(diag.has_errors());
+    }
+
+    #[test]
+    fn test_port_validator() {
+        let mut serve = ServeConfig::default();
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate_field_status(&mut diag);
+        assert!(!diag.has_errors());
+
+        serve.port = 80;
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate_field_status(&mut diag);
+        assert!(diag.has_errors());
+
+        serve.port = 8080;
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate_field_status(&mut diag);
+        assert!(!diag.has_errors());
+    }
+
+    #[test]
+    fn test_tls_cert_and_key_together() {
+        let mut serve = ServeConfig::default();
+        serve.tls.cert = Some("dev.pem".into());
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(diag.has_errors());
+
+        serve.tls.key = Some("dev-key.pem".into());
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(!diag.has_errors());
+    }
+
+    #[test]
+    fn test_http2_requires_tls() {
+        let mut serve = ServeConfig::default();
+        serve.http2.enable = true;
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(diag.has_errors());
+
+        serve.tls.enable = true;
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        serve.validate(&mut diag);
+        assert!(!diag.has_errors());
+    }
+
+    #[test]
+    fn test_http2_section_parses() {
+        let snippet = "[serve.tls]\nenable = true\n[serve.http2]\nenable = true\n";
+        let config = crate::config::test_parse_config(snippet);
+        assert!(config.serve.http2.enable);
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        let raw = format!("[site.info]\ntitle = \"Test\"\ndescription = \"Test\"\n{snippet}");
+        diag.set_presence(crate::config::ConfigPresence::from_toml(&raw).unwrap());
+        config.serve.validate_field_status(&mut diag);
+        assert!(!diag.has_errors());
+    }
+}
@@ -1,11 +1,18 @@
 //! Custom HTML header configuration.
+//!
+//! `styles` and `scripts` entries may be globs (`styles/*.css`), matched
+//! against files under the site root. The matches of one glob are included
+//! in alphabetical order.
 
+use jwalk::WalkDir;
 use macros::Config;
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 
 use crate::config::ConfigDiagnostics;
 use crate::config::section::build::AssetsConfig;
+use crate::utils::glob;
+use crate::utils::path::normalize_path;
 
 #[derive(Debug, Clone, Serialize, Deserialize, Config)]
 #[serde(default)]
@@ -16,12 +23,17 @@ pub struct HeaderConfig {
     pub no_fouc: bool,
     /// Favicon path (relative to site root).
     pub icon: Option<PathBuf>,
-    /// CSS stylesheet paths (relative to site root).
+    /// CSS stylesheet paths or globs (relative to site root).
+    /// Output as `name-<hash>.css` except under `tola serve`.
     pub styles: Vec<PathBuf>,
-    /// Script entries (relative to site root).
+    /// Script entries, paths or globs (relative to site root).
+    /// Output as `name-<hash>.js` except under `tola serve`.
     pub scripts: Vec<ScriptEntry>,
     /// Raw HTML elements to insert into head.
     pub elements: Vec<String>,
+    /// `<link rel="preload">` hints for critical assets.
+    #[config(sub)]
+    pub preload: PreloadConfig,
 }
 
 impl Default for HeaderConfig {
@@ -32,6 +44,7 @@ impl Default for HeaderConfig {
             styles: Vec::new(),
             scripts: Vec::new(),
             elements: Vec::new(),
+            preload: PreloadConfig::default(),
         }
     }
 }
@@ -46,11 +59,149 @@ impl HeaderConfig {
         }
 
         for style in &self.styles {
-            checker.validate(style, Self::FIELDS.styles, diag);
+            checker.validate_entry(style, Self::FIELDS.styles, diag);
         }
 
         for script in &self.scripts {
-            checker.validate(script.path(), Self::FIELDS.scripts, diag);
+            checker.validate_entry(script.path(), Self::FIELDS.scripts, diag);
+        }
+
+        // Missing images only lose their hint, so they are not fatal
+        for image in &self.preload.images {
+            let normalized = image.strip_prefix("./").unwrap_or(image);
+            if !root.join(normalized).is_file() {
+                diag.warn(
+                    PreloadConfig::FIELDS.images,
+                    format!("preload image '{}' not found, skipped", image.display()),
+                );
+            } else {
+                checker.validate(image, PreloadConfig::FIELDS.images, diag);
+            }
+        }
+    }
+
+    /// `styles` with globs expanded to the files they match.
+    pub fn expanded_styles(&self, root: &Path) -> Vec<PathBuf> {
+        self.styles
+            .iter()
+            .flat_map(|style| expand_entry(style, root))
+            .collect()
+    }
+
+    /// `scripts` with globs expanded, each match keeping its entry's options.
+    pub fn expanded_scripts(&self, root: &Path) -> Vec<ScriptEntry> {
+        self.scripts
+            .iter()
+            .flat_map(|script| {
+                expand_entry(script.path(), root)
+                    .into_iter()
+                    .map(|path| script.with_path(path))
+            })
+            .collect()
+    }
+
+    /// Whether `path` (normalized) is listed in `styles`, directly or by a glob.
+    pub fn lists_style(&self, path: &Path, root: &Path) -> bool {
+        self.styles
+            .iter()
+            .any(|style| entry_matches(style, path, root))
+    }
+
+    /// Whether `path` (normalized) is listed in `styles` or `scripts`.
+    pub fn lists_asset(&self, path: &Path, root: &Path) -> bool {
+        self.lists_style(path, root)
+            || self
+                .scripts
+                .iter()
+                .any(|script| entry_matches(script.path(), path, root))
+    }
+}
+
+// ============================================================================
+// Glob Entries
+// ============================================================================
+
+/// Whether a header path contains glob characters.
+fn is_glob(path: &Path) -> bool {
+    glob::is_glob(&path.to_string_lossy())
+}
+
+fn slash_path(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Split a glob into the directory to walk and the full pattern to match.
+///
+/// `styles/*.css` -> (`<root>/styles`, `<root>/styles/*.css`)
+fn glob_parts(entry: &Path, root: &Path) -> (PathBuf, String) {
+    let entry = entry.strip_prefix("./").unwrap_or(entry);
+    let base: PathBuf = entry
+        .components()
+        .take_while(|c| !is_glob(Path::new(c.as_os_str())))
+        .collect();
+    let rest = entry.strip_prefix(&base).unwrap_or(entry);
+    let base = normalize_path(&root.join(base));
+    let pattern = format!("{}/{}", slash_path(&base), slash_path(rest));
+    (base, pattern)
+}
+
+/// Files matched by a header entry, sorted; plain paths are returned as-is.
+fn expand_entry(entry: &Path, root: &Path) -> Vec<PathBuf> {
+    if !is_glob(entry) {
+        return vec![entry.to_path_buf()];
+    }
+
+    let (base, pattern) = glob_parts(entry, root);
+    let root = normalize_path(root);
+    let mut matches: Vec<PathBuf> = WalkDir::new(&base)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.path())
+        .filter(|path| glob::matches_path(&pattern, path))
+        .map(|path| match path.strip_prefix(&root) {
+            Ok(relative) => relative.to_path_buf(),
+            Err(_) => path,
+        })
+        .collect();
+    matches.sort();
+    matches
+}
+
+/// Whether a header entry (path or glob) refers to `path` (normalized).
+fn entry_matches(entry: &Path, path: &Path, root: &Path) -> bool {
+    if is_glob(entry) {
+        let (_, pattern) = glob_parts(entry, root);
+        glob::matches_path(&pattern, path)
+    } else {
+        let entry = entry.strip_prefix("./").unwrap_or(entry);
+        normalize_path(&root.join(entry)) == path
+    }
+}
+
+// ============================================================================
+// Preload Hints
+// ============================================================================
+
+/// `<link rel="preload">` hints emitted before the stylesheets.
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "site.header.preload")]
+pub struct PreloadConfig {
+    /// Preload `styles` (`as="style"`).
+    pub styles: bool,
+    /// Preload fonts from `@font-face` rules in `styles` (`as="font"`).
+    pub fonts: bool,
+    /// Image paths to preload (relative to site root, `as="image"`).
+    pub images: Vec<PathBuf>,
+}
+
+impl Default for PreloadConfig {
+    fn default() -> Self {
+        Self {
+            styles: true,
+            fonts: true,
+            images: Vec::new(),
         }
     }
 }
@@ -70,6 +221,27 @@ impl<'a> AssetPathChecker<'a> {
         Self { assets, root }
     }
 
+    /// Validate a path, or each file a glob matches (hinting at empty globs).
+    fn validate_entry(
+        &self,
+        path: &Path,
+        field: crate::config::FieldPath,
+        diag: &mut ConfigDiagnostics,
+    ) {
+        if !is_glob(path) {
+            self.validate(path, field, diag);
+            return;
+        }
+
+        let matches = expand_entry(path, self.root);
+        if matches.is_empty() {
+            diag.hint(field, format!("glob '{}' matches no files", path.display()));
+        }
+        for file in &matches {
+            self.validate(file, field, diag);
+        }
+    }
+
     /// Validate a path is within configured assets, report error if not.
     fn validate(&self, path: &Path, field: crate::config::FieldPath, diag: &mut ConfigDiagnostics) {
         if !self.is_in_assets(path) {
@@ -86,7 +258,7 @@ impl<'a> AssetPathChecker<'a> {
     /// Check if path is within any configured asset entry.
     fn is_in_assets(&self, path: &Path) -> bool {
         let normalized = path.strip_prefix("./").unwrap_or(path);
-        let abs_path = crate::utils::path::normalize_path(&self.root.join(normalized));
+        let abs_path = normalize_path(&self.root.join(normalized));
 
         // Check flatten (exact match) first, then nested (prefix match)
         self.assets.flatten.iter().any(|e| abs_path == e.source())
@@ -125,6 +297,18 @@ impl ScriptEntry {
         }
     }
 
+    /// The same entry pointing at `path` (a glob match).
+    fn with_path(&self, path: PathBuf) -> Self {
+        match self {
+            Self::Simple(_) => Self::Simple(path),
+            Self::WithOptions { defer, r#async, .. } => Self::WithOptions {
+                path,
+                defer: *defer,
+                r#async: *r#async,
+            },
+        }
+    }
+
     /// Check if defer attribute should be added.
     pub const fn is_defer(&self) -> bool {
         match self {
@@ -144,7 +328,96 @@ impl ScriptEntry {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::config::test_parse_config;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn site_with_styles() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for file in [
+            "assets/styles/b.css",
+            "assets/styles/a.css",
+            "assets/styles/readme.md",
+            "assets/styles/vendor/c.css",
+            "assets/js/app.js",
+        ] {
+            let path = dir.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_expanded_styles_and_scripts() {
+        let dir = site_with_styles();
+        let root = dir.path();
+        let header = HeaderConfig {
+            styles: vec![
+                "assets/styles/*.css".into(),
+                "./assets/styles/**/*.css".into(),
+                "extra.css".into(),
+            ],
+            scripts: vec![ScriptEntry::WithOptions {
+                path: "assets/js/*.js".into(),
+                defer: true,
+                r#async: false,
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            header.expanded_styles(root),
+            [
+                PathBuf::from("assets/styles/a.css"),
+                PathBuf::from("assets/styles/b.css"),
+                PathBuf::from("assets/styles/a.css"),
+                PathBuf::from("assets/styles/b.css"),
+                PathBuf::from("assets/styles/vendor/c.css"),
+                PathBuf::from("extra.css"),
+            ]
+        );
+
+        let scripts = header.expanded_scripts(root);
+        assert_eq!(scripts.len(), 1);
+        assert_eq!(scripts[0].path(), Path::new("assets/js/app.js"));
+        assert!(scripts[0].is_defer());
+
+        let file = |path: &str| normalize_path(&root.join(path));
+        assert!(header.lists_style(&file("assets/styles/vendor/c.css"), root));
+        assert!(!header.lists_style(&file("assets/styles/readme.md"), root));
+        assert!(header.lists_asset(&file("assets/js/app.js"), root));
+    }
+
+    #[test]
+    fn test_validate_glob_entries() {
+        let dir = site_with_styles();
+        let root = dir.path();
+        let assets = AssetsConfig {
+            nested: vec![crate::config::section::build::assets::NestedEntry::Simple(
+                normalize_path(&root.join("assets/styles")),
+            )],
+            ..Default::default()
+        };
+
+        let header = HeaderConfig {
+            styles: vec!["assets/styles/*.css".into(), "assets/none/*.css".into()],
+            ..Default::default()
+        };
+        let mut diag = ConfigDiagnostics::new();
+        header.validate(&assets, root, &mut diag);
+        assert!(!diag.has_errors());
+
+        // Each match is checked on its own
+        let header = HeaderConfig {
+            styles: vec!["assets/**/*.*".into()],
+            ..Default::default()
+        };
+        let mut diag = ConfigDiagnostics::new();
+        header.validate(&assets, root, &mut diag);
+        assert!(diag.has_errors());
+    }
 
     #[test]
     fn test_scripts_parsing_cases() {
@@ -170,4 +443,19 @@ scripts = [
         assert!(!config.site.header.scripts[2].is_defer());
         assert!(config.site.header.scripts[2].is_async());
     }
+
+    #[test]
+    fn test_preload_config() {
+        let preload = test_parse_config("").site.header.preload;
+        assert!(preload.styles && preload.fonts);
+        assert!(preload.images.is_empty());
+
+        let config = test_parse_config(
+            "[site.header.preload]\nfonts = false\nimages = [\"assets/hero.jpg\"]",
+        );
+        let preload = &config.site.header.preload;
+        assert!(preload.styles);
+        assert!(!preload.fonts);
+        assert_eq!(preload.images.len(), 1);
+    }
 }
@@ -0,0 +1,169 @@
+//! `[site.i18n]` configuration for multilingual sites.
+//!
+//! # Example
+//!
+//! ```toml
+//! [site.i18n]
+//! locales = ["en", "de"]
+//! default_locale = "en"
+//! ```
+//!
+//! Pages of the default locale live at the site root, every other locale
+//! under a directory of its name: `/posts/hello/` and `/de/posts/hello/` are
+//! the same page in English and German. With two or more locales, pages get
+//! `<link rel="alternate" hreflang>` tags and the sitemap lists the same
+//! alternates.
+
+use macros::Config;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ConfigDiagnostics;
+
+/// Multilingual site configuration
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "site.i18n")]
+pub struct I18nConfig {
+    #[config(inline_doc = "Locales of the site, e.g. [\"en\", \"de\"]")]
+    pub locales: Vec<String>,
+
+    #[config(inline_doc = "Locale served from the site root (default: first locale)")]
+    pub default_locale: String,
+}
+
+impl I18nConfig {
+    /// Whether `hreflang` alternates are generated (two or more locales).
+    pub fn is_enabled(&self) -> bool {
+        self.locales.len() > 1
+    }
+
+    /// Locale served from the site root.
+    pub fn default_locale(&self) -> &str {
+        if self.default_locale.is_empty() {
+            self.locales.first().map_or("", String::as_str)
+        } else {
+            &self.default_locale
+        }
+    }
+
+    /// Strip a non-default locale directory from a site path.
+    ///
+    /// `/de/posts/hello/` -> `/posts/hello/`; paths of the default locale are
+    /// returned unchanged.
+    pub fn strip_locale<'p>(&self, path: &'p str) -> &'p str {
+        let default = self.default_locale();
+        for locale in self.locales.iter().filter(|l| *l != default) {
+            let Some(rest) = path
+                .strip_prefix('/')
+                .and_then(|p| p.strip_prefix(locale.as_str()))
+            else {
+                continue;
+            };
+            if rest.is_empty() {
+                return "/";
+            }
+            if rest.starts_with('/') {
+                return rest;
+            }
+        }
+        path
+    }
+
+    /// Site path of `path` (without locale directory) in `locale`.
+    pub fn localized_path(&self, locale: &str, path: &str) -> String {
+        if locale == self.default_locale() {
+            path.to_string()
+        } else {
+            format!("/{locale}{path}")
+        }
+    }
+
+    /// Validate i18n configuration.
+    ///
+    /// - locales must be non-empty, unique and a single path segment
+    /// - `default_locale` must be one of `locales`
+    pub fn validate(&self, diag: &mut ConfigDiagnostics) {
+        for (i, locale) in self.locales.iter().enumerate() {
+            if locale.is_empty() || locale.contains(['/', '\\', ' ']) {
+                diag.error(
+                    Self::FIELDS.locales,
+                    format!("locale '{locale}' must be a single path segment"),
+                );
+            } else if self.locales[..i].contains(locale) {
+                diag.error(
+                    Self::FIELDS.locales,
+                    format!("locale '{locale}' is listed twice"),
+                );
+            }
+        }
+
+        if !self.default_locale.is_empty() && !self.locales.contains(&self.default_locale) {
+            diag.error_with_hint(
+                Self::FIELDS.default_locale,
+                format!("default_locale '{}' is not in locales", self.default_locale),
+                format!(
+                    "add \"{}\" to {}",
+                    self.default_locale,
+                    Self::FIELDS.locales
+                ),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_parse_config;
+
+    #[test]
+    fn test_i18n_config() {
+        let config = test_parse_config("");
+        assert!(!config.site.i18n.is_enabled());
+
+        let config = test_parse_config("[site.i18n]\nlocales = [\"en\", \"de\", \"zh-CN\"]");
+        let i18n = &config.site.i18n;
+        assert!(i18n.is_enabled());
+        assert_eq!(i18n.default_locale(), "en");
+
+        assert_eq!(i18n.strip_locale("/de/posts/hello/"), "/posts/hello/");
+        assert_eq!(i18n.strip_locale("/zh-CN/"), "/");
+        assert_eq!(i18n.strip_locale("/posts/hello/"), "/posts/hello/");
+        // Only whole segments are locale directories
+        assert_eq!(i18n.strip_locale("/design/"), "/design/");
+
+        assert_eq!(i18n.localized_path("en", "/posts/"), "/posts/");
+        assert_eq!(i18n.localized_path("de", "/posts/"), "/de/posts/");
+        assert_eq!(i18n.localized_path("de", "/"), "/de/");
+    }
+
+    #[test]
+    fn test_i18n_validate() {
+        let mut diag = ConfigDiagnostics::new();
+        let i18n = I18nConfig {
+            locales: vec!["en".into(), "de".into()],
+            default_locale: "de".into(),
+        };
+        i18n.validate(&mut diag);
+        assert!(!diag.has_errors());
+
+        for i18n in [
+            I18nConfig {
+                locales: vec!["en".into(), "de".into()],
+                default_locale: "fr".into(),
+            },
+            I18nConfig {
+                locales: vec!["en".into(), "en".into()],
+                default_locale: String::new(),
+            },
+            I18nConfig {
+                locales: vec!["en".into(), "de/at".into()],
+                default_locale: String::new(),
+            },
+        ] {
+            let mut diag = ConfigDiagnostics::new();
+            i18n.validate(&mut diag);
+            assert!(diag.has_errors(), "{i18n:?}");
+        }
+    }
+}
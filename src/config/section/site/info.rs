@@ -28,6 +28,8 @@ pub struct SiteInfoConfig {
     pub description: String,
 
     #[config(
+        env = "TOLA_SITE_URL",
+        validate = "validate_url",
         inline_doc = "Site URL, path used as prefix (e.g., \"https://example.com/blog/docs\")"
     )]
     pub url: Option<String>,
@@ -41,6 +43,18 @@ pub struct SiteInfoConfig {
     #[config(inline_doc = "Copyright notice")]
     pub copyright: String,
 
+    /// Page `<title>` template, e.g. `"{{ page.title }} | {{ site.title }}"`.
+    /// Variables: `site.title`, `site.author`, `page.title`, `site.extra.xxx`.
+    /// Pages without a title fall back to `title`.
+    pub title_template: Option<String>,
+
+    #[config(inline_doc = "Words per minute for reading time estimates")]
+    pub reading_speed: u32,
+
+    /// Open Graph / Twitter Card defaults
+    #[config(sub)]
+    pub og: OgConfig,
+
     /// Custom fields accessible via `sys.inputs.extra.xxx` in Typst
     #[serde(default)]
     #[config(skip)]
@@ -57,17 +71,107 @@ impl Default for SiteInfoConfig {
             url: None,
             language: "en".into(),
             copyright: String::new(),
+            title_template: None,
+            reading_speed: 200,
+            og: OgConfig::default(),
             extra: FxHashMap::default(),
         }
     }
 }
 
+/// Twitter Card type
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TwitterCard {
+    Summary,
+    /// Large preview image (default).
+    #[default]
+    SummaryLargeImage,
+    App,
+    Player,
+}
+
+impl TwitterCard {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Summary => "summary",
+            Self::SummaryLargeImage => "summary_large_image",
+            Self::App => "app",
+            Self::Player => "player",
+        }
+    }
+}
+
+/// Site-wide Open Graph defaults, overridden per page by `og_image` metadata
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "site.info.og")]
+pub struct OgConfig {
+    #[config(inline_doc = "Fallback og:image, a site-root asset path or absolute URL")]
+    pub default_image: Option<String>,
+
+    #[config(
+        default = "summary_large_image",
+        inline_doc = "twitter:card: summary | summary_large_image | app | player"
+    )]
+    pub twitter_card: TwitterCard,
+
+    #[config(inline_doc = "twitter:site handle (e.g., \"@example\")")]
+    pub twitter_site: Option<String>,
+}
+
 impl SiteInfoConfig {
+    /// Render the `<title>` text for a page.
+    ///
+    /// Uses `title_template` when both the template and `page_title` are set,
+    /// otherwise falls back to the plain site `title`. Unknown variables are
+    /// kept verbatim so typos stay visible in the output.
+    pub fn render_title(&self, page_title: Option<&str>) -> String {
+        let (Some(template), Some(page_title)) = (&self.title_template, page_title) else {
+            return self.title.clone();
+        };
+
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find("{{") {
+            let Some(len) = rest[start..].find("}}") else {
+                break;
+            };
+            out.push_str(&rest[..start]);
+            let var = rest[start + 2..start + len].trim();
+            match self.title_var(var, page_title) {
+                Some(value) => out.push_str(&value),
+                None => out.push_str(&rest[start..start + len + 2]),
+            }
+            rest = &rest[start + len + 2..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Resolve a single title template variable.
+    fn title_var(&self, var: &str, page_title: &str) -> Option<String> {
+        match var {
+            "page.title" => Some(page_title.to_string()),
+            "site.title" => Some(self.title.clone()),
+            "site.author" => Some(self.author.clone()),
+            _ => {
+                let key = var.strip_prefix("site.extra.")?;
+                self.extra.get(key).map(|value| match value {
+                    toml::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+            }
+        }
+    }
+
     /// Validate site configuration.
     ///
     /// # Checks
     /// - If `feed_enabled`, `url` must be set
-    /// - `url` must be a valid URL with scheme (e.g., `https://example.com`)
+    ///
+    /// The `url` format is checked by [`Self::validate_url`] via
+    /// `validate_field_status()`.
     pub fn validate(&self, feed_enabled: bool, diag: &mut crate::config::ConfigDiagnostics) {
         // Feed requires url
         if feed_enabled && self.url.is_none() {
@@ -81,39 +185,120 @@ impl SiteInfoConfig {
                 format!("set {}, e.g.: \"https://example.com\"", Self::FIELDS.url),
             );
         }
+    }
 
-        // URL format check using url crate for strict validation
-        if let Some(url_str) = &self.url {
-            match url::Url::parse(url_str) {
-                Ok(parsed) => {
-                    // Must be http or https
-                    if !matches!(parsed.scheme(), "http" | "https") {
-                        diag.error_with_hint(
-                            Self::FIELDS.url,
-                            format!(
-                                "scheme '{}' not supported, must be http or https",
-                                parsed.scheme()
-                            ),
-                            "use format like https://example.com",
-                        );
-                    }
-                    // Must have a valid host
-                    if parsed.host_str().is_none() {
-                        diag.error_with_hint(
-                            Self::FIELDS.url,
-                            "URL must have a valid host",
-                            "use format like https://example.com",
-                        );
-                    }
+    /// Validate `url` format (strict, via the `url` crate).
+    ///
+    /// Must parse, use http or https, and have a host.
+    fn validate_url(url: &Option<String>, diag: &mut crate::config::ConfigDiagnostics) {
+        let Some(url_str) = url else {
+            return;
+        };
+        match url::Url::parse(url_str) {
+            Ok(parsed) => {
+                // Must be http or https
+                if !matches!(parsed.scheme(), "http" | "https") {
+                    diag.error_with_hint(
+                        Self::FIELDS.url,
+                        format!(
+                            "scheme '{}' not supported, must be http or https",
+                            parsed.scheme()
+                        ),
+                        "use format like https://example.com",
+                    );
                 }
-                Err(e) => {
+                // Must have a valid host
+                if parsed.host_str().is_none() {
                     diag.error_with_hint(
                         Self::FIELDS.url,
-                        format!("invalid URL: {}", e),
+                        "URL must have a valid host",
                         "use format like https://example.com",
                     );
                 }
             }
+            Err(e) => {
+                diag.error_with_hint(
+                    Self::FIELDS.url,
+                    format!("invalid URL: {}", e),
+                    "use format like https://example.com",
+                );
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_og_config() {
+        let config = crate::config::test_parse_config(
+            "[site.info.og]\ndefault_image = \"/images/cover.png\"\ntwitter_card = \"summary\"\ntwitter_site = \"@tola\"",
+        );
+        let og = &config.site.info.og;
+        assert_eq!(og.default_image.as_deref(), Some("/images/cover.png"));
+        assert_eq!(og.twitter_card, TwitterCard::Summary);
+        assert_eq!(og.twitter_site.as_deref(), Some("@tola"));
+
+        let og = OgConfig::default();
+        assert_eq!(og.twitter_card.as_str(), "summary_large_image");
+    }
+
+    fn info_with_template(template: &str) -> SiteInfoConfig {
+        let mut info = SiteInfoConfig {
+            title: "My Blog".into(),
+            author: "Alice".into(),
+            title_template: Some(template.into()),
+            ..Default::default()
+        };
+        info.extra
+            .insert("tagline".into(), toml::Value::String("notes".into()));
+        info
+    }
+
+    #[test]
+    fn test_render_title_template() {
+        let info = info_with_template("{{ page.title }} | {{site.title}} by {{ site.author }}");
+        assert_eq!(info.render_title(Some("Hello")), "Hello | My Blog by Alice");
+
+        let info = info_with_template("{{ page.title }} - {{ site.extra.tagline }}");
+        assert_eq!(info.render_title(Some("Hello")), "Hello - notes");
+    }
+
+    #[test]
+    fn test_render_title_fallbacks() {
+        // No page title -> plain site title
+        let info = info_with_template("{{ page.title }} | {{ site.title }}");
+        assert_eq!(info.render_title(None), "My Blog");
+
+        // No template -> plain site title
+        let info = SiteInfoConfig {
+            title: "My Blog".into(),
+            ..Default::default()
+        };
+        assert_eq!(info.render_title(Some("Hello")), "My Blog");
+
+        // Unknown variables are kept verbatim
+        let info = info_with_template("{{ page.title }} {{ page.nope }}");
+        assert_eq!(info.render_title(Some("Hi")), "Hi {{ page.nope }}");
+    }
+
+    #[test]
+    fn test_url_validator() {
+        let check = |url: Option<&str>| {
+            let info = SiteInfoConfig {
+                url: url.map(Into::into),
+                ..Default::default()
+            };
+            let mut diag = crate::config::ConfigDiagnostics::new();
+            info.validate_field_status(&mut diag);
+            diag.has_errors()
+        };
+
+        assert!(!check(None));
+        assert!(!check(Some("https://example.com/blog")));
+        assert!(check(Some("ftp://example.com")));
+        assert!(check(Some("example.com")));
+    }
+}
@@ -24,29 +24,41 @@
 //! styles = ["styles/custom.css"]
 //! scripts = ["scripts/app.js"]
 //!
+//! [site.header.preload]
+//! styles = true             # <link rel="preload" as="style"> for styles
+//! fonts = true              # ... as="font" for @font-face URLs in styles
+//! images = ["assets/hero.jpg"]
+//!
 //! [site.seo]
 //! auto_og = true
 //!
 //! [site.seo.feed]
 //! enable = true
 //! path = "feed.xml"
+//! format = ["rss", "atom"]  # atom goes to atom_path = "atom.xml"
 //!
 //! [site.seo.sitemap]
 //! enable = true
 //!
+//! [site.i18n]
+//! locales = ["en", "de"]    # /de/... pages are the German versions
+//! default_locale = "en"
+//!
 //! [site]
 //! not_found = "404.html"
 //! ```
 
 mod header;
+mod i18n;
 mod info;
 mod nav;
 mod seo;
 
 pub use header::HeaderConfig;
+pub use i18n::I18nConfig;
 pub use info::SiteInfoConfig;
 pub use nav::{NavConfig, TransitionStyle};
-pub use seo::{FeedConfig, FeedFormat, SeoConfig};
+pub use seo::{FeedConfig, FeedFormat, SeoConfig, SitemapLastmod};
 
 use macros::Config;
 use serde::{Deserialize, Serialize};
@@ -73,6 +85,10 @@ pub struct SiteSectionConfig {
     #[config(sub)]
     pub seo: SeoConfig,
 
+    /// Multilingual settings (`hreflang` alternates).
+    #[config(sub)]
+    pub i18n: I18nConfig,
+
     /// Custom 404 page source file (relative to site root).
     pub not_found: Option<PathBuf>,
 }
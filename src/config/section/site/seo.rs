@@ -1,11 +1,13 @@
 //! SEO configuration (feed, sitemap, OG tags).
 
 use macros::Config;
-use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::utils::mime;
 
 /// Feed output format
-#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum FeedFormat {
     /// RSS 2.0 format (default).
@@ -15,6 +17,31 @@ pub enum FeedFormat {
     Atom,
 }
 
+impl FeedFormat {
+    /// MIME type for `<link rel="alternate">` discovery.
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Rss => mime::types::RSS,
+            Self::Atom => mime::types::ATOM,
+        }
+    }
+}
+
+/// Accept `format = "rss"` as well as `format = ["rss", "atom"]`.
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<FeedFormat>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(FeedFormat),
+        Many(Vec<FeedFormat>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(format) => vec![format],
+        OneOrMany::Many(formats) => formats,
+    })
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Config)]
 #[serde(default)]
 #[config(section = "site.seo.feed")]
@@ -23,8 +50,18 @@ pub struct FeedConfig {
     pub enable: bool,
     #[config(default = "feed.xml", inline_doc = "Output path for feed file")]
     pub path: PathBuf,
-    #[config(default = "rss", inline_doc = "Feed format: rss | atom")]
-    pub format: FeedFormat,
+    #[config(inline_doc = "Feed formats: rss | atom, or both as an array")]
+    #[serde(deserialize_with = "one_or_many")]
+    pub format: Vec<FeedFormat>,
+    #[config(
+        default = "atom.xml",
+        inline_doc = "Output path for the Atom feed when both formats are enabled"
+    )]
+    pub atom_path: PathBuf,
+
+    /// Podcast (iTunes) extension for the RSS feed
+    #[config(sub)]
+    pub podcast: PodcastConfig,
 }
 
 impl Default for FeedConfig {
@@ -32,9 +69,128 @@ impl Default for FeedConfig {
         Self {
             enable: false,
             path: "feed.xml".into(),
-            format: FeedFormat::Rss,
+            format: vec![FeedFormat::Rss],
+            atom_path: "atom.xml".into(),
+            podcast: PodcastConfig::default(),
+        }
+    }
+}
+
+impl FeedConfig {
+    /// Configured formats, deduplicated in declaration order.
+    pub fn formats(&self) -> Vec<FeedFormat> {
+        let mut formats = Vec::with_capacity(self.format.len());
+        for &format in &self.format {
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+        }
+        formats
+    }
+
+    /// Output path for `format`: `path` is the RSS path whenever RSS is
+    /// enabled, so Atom moves to `atom_path` alongside it.
+    pub fn output_path(&self, format: FeedFormat) -> &Path {
+        match format {
+            FeedFormat::Atom if self.format.contains(&FeedFormat::Rss) => &self.atom_path,
+            _ => &self.path,
         }
     }
+
+    /// Validate feed configuration.
+    ///
+    /// - `format` must list at least one format
+    /// - RSS and Atom must not be written to the same file
+    pub fn validate(&self, diag: &mut crate::config::ConfigDiagnostics) {
+        if !self.enable {
+            return;
+        }
+        if self.format.is_empty() {
+            diag.error_with_hint(
+                Self::FIELDS.format,
+                "no feed format configured",
+                "use \"rss\", \"atom\" or [\"rss\", \"atom\"]",
+            );
+        }
+        if self.output_path(FeedFormat::Rss) == self.output_path(FeedFormat::Atom)
+            && self.formats().len() > 1
+        {
+            diag.error(
+                Self::FIELDS.atom_path,
+                format!("RSS and Atom feeds both write to {}", self.path.display()),
+            );
+        }
+        if self.podcast.enable && !self.format.contains(&FeedFormat::Rss) {
+            diag.error_with_hint(
+                PodcastConfig::FIELDS.enable,
+                "podcast feeds require the RSS format",
+                "add \"rss\" to site.seo.feed.format",
+            );
+        }
+        self.podcast.validate(diag);
+    }
+}
+
+/// Podcast feed settings (`<itunes:*>` tags and episode `<enclosure>`)
+///
+/// Episodes read `enclosure_url`, `enclosure_length`, `enclosure_type` and
+/// `duration` from page metadata.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "site.seo.feed.podcast")]
+pub struct PodcastConfig {
+    #[config(inline_doc = "Add iTunes podcast tags to the RSS feed")]
+    pub enable: bool,
+    #[config(inline_doc = "<itunes:owner> name (defaults to site.info.author)")]
+    pub owner_name: String,
+    #[config(inline_doc = "<itunes:owner> email (defaults to site.info.email)")]
+    pub owner_email: String,
+    #[config(inline_doc = "Apple Podcasts category, e.g. \"Technology\" or \"Arts > Books\"")]
+    pub category: String,
+    #[config(inline_doc = "Mark the show as explicit")]
+    pub explicit: bool,
+    #[config(inline_doc = "Show artwork (1400-3000px square): asset path or absolute URL")]
+    pub image_url: String,
+}
+
+impl PodcastConfig {
+    /// Category and optional subcategory from `"Category > Subcategory"`.
+    pub fn category_path(&self) -> (&str, Option<&str>) {
+        match self.category.split_once('>') {
+            Some((category, sub)) => (category.trim(), Some(sub.trim())),
+            None => (self.category.trim(), None),
+        }
+    }
+
+    /// Validate podcast configuration.
+    ///
+    /// Podcast directories reject feeds without a category or artwork.
+    pub fn validate(&self, diag: &mut crate::config::ConfigDiagnostics) {
+        if !self.enable {
+            return;
+        }
+        if self.category.trim().is_empty() {
+            diag.error_with_hint(
+                Self::FIELDS.category,
+                "podcast feeds require a category",
+                "e.g. category = \"Technology\"",
+            );
+        }
+        if self.image_url.trim().is_empty() {
+            diag.error(Self::FIELDS.image_url, "podcast feeds require show artwork");
+        }
+    }
+}
+
+/// Source of sitemap `<lastmod>` dates
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SitemapLastmod {
+    /// Page `date` metadata (default).
+    #[default]
+    Date,
+    /// Last git commit touching the source file, falling back to file mtime.
+    Git,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Config)]
@@ -45,6 +201,10 @@ pub struct SitemapConfig {
     pub enable: bool,
     #[config(inline_doc = "Output path for sitemap file")]
     pub path: PathBuf,
+    #[config(default = "date", inline_doc = "Source of <lastmod>: date | git")]
+    pub lastmod: SitemapLastmod,
+    #[config(inline_doc = "Split into a sitemap index above this many URLs (0 = never)")]
+    pub split_at: usize,
 }
 
 impl Default for SitemapConfig {
@@ -52,10 +212,34 @@ impl Default for SitemapConfig {
         Self {
             enable: false,
             path: "sitemap.xml".into(),
+            lastmod: SitemapLastmod::Date,
+            split_at: 1000,
         }
     }
 }
 
+impl SitemapConfig {
+    /// Whether `url_count` URLs are split across numbered sitemaps.
+    pub fn is_split(&self, url_count: usize) -> bool {
+        self.split_at > 0 && url_count > self.split_at
+    }
+
+    /// File crawlers should be pointed at: the index when split, `path` otherwise.
+    pub fn entry_path(&self, url_count: usize) -> PathBuf {
+        if self.is_split(url_count) {
+            self.numbered_path("index")
+        } else {
+            self.path.clone()
+        }
+    }
+
+    /// `sitemap.xml` -> `sitemap-{suffix}.xml`, next to `path`.
+    pub fn numbered_path(&self, suffix: &str) -> PathBuf {
+        let stem = self.path.file_stem().unwrap_or_default().to_string_lossy();
+        self.path.with_file_name(format!("{stem}-{suffix}.xml"))
+    }
+}
+
 /// SEO configuration containing feed, sitemap, and OG tag settings
 #[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
 #[serde(default)]
@@ -72,3 +256,107 @@ pub struct SeoConfig {
     #[config(sub)]
     pub sitemap: SitemapConfig,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{SiteConfig, test_parse_config};
+
+    #[test]
+    fn test_feed_formats() {
+        let config = test_parse_config("[site.seo.feed]\nformat = \"atom\"");
+        let feed = &config.site.seo.feed;
+        assert_eq!(feed.formats(), [FeedFormat::Atom]);
+        assert_eq!(feed.output_path(FeedFormat::Atom), Path::new("feed.xml"));
+
+        let config = test_parse_config("[site.seo.feed]\nformat = [\"rss\", \"atom\", \"rss\"]");
+        let feed = &config.site.seo.feed;
+        assert_eq!(feed.formats(), [FeedFormat::Rss, FeedFormat::Atom]);
+        assert_eq!(feed.output_path(FeedFormat::Rss), Path::new("feed.xml"));
+        assert_eq!(feed.output_path(FeedFormat::Atom), Path::new("atom.xml"));
+
+        assert_eq!(
+            SiteConfig::default().site.seo.feed.formats(),
+            [FeedFormat::Rss]
+        );
+    }
+
+    #[test]
+    fn test_podcast_config() {
+        let config = test_parse_config(
+            r#"
+[site.seo.feed]
+enable = true
+
+[site.seo.feed.podcast]
+enable = true
+category = "Arts > Books"
+image_url = "/images/cover.jpg"
+"#,
+        );
+        let podcast = &config.site.seo.feed.podcast;
+        assert!(podcast.enable);
+        assert!(!podcast.explicit);
+        assert_eq!(podcast.category_path(), ("Arts", Some("Books")));
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        config.site.seo.feed.validate(&mut diag);
+        assert!(!diag.has_errors());
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        let feed = FeedConfig {
+            enable: true,
+            format: vec![FeedFormat::Atom],
+            podcast: PodcastConfig {
+                enable: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        feed.validate(&mut diag);
+        assert!(diag.has_errors());
+    }
+
+    #[test]
+    fn test_feed_validate() {
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        let feed = FeedConfig {
+            enable: true,
+            format: vec![FeedFormat::Rss, FeedFormat::Atom],
+            atom_path: "feed.xml".into(),
+            ..Default::default()
+        };
+        feed.validate(&mut diag);
+        assert!(diag.has_errors());
+
+        let mut diag = crate::config::ConfigDiagnostics::new();
+        let feed = FeedConfig {
+            enable: true,
+            format: vec![FeedFormat::Rss, FeedFormat::Atom],
+            ..Default::default()
+        };
+        feed.validate(&mut diag);
+        assert!(!diag.has_errors());
+    }
+
+    #[test]
+    fn test_sitemap_split_paths() {
+        let sitemap = SitemapConfig {
+            split_at: 2,
+            ..Default::default()
+        };
+        assert!(!sitemap.is_split(2));
+        assert!(sitemap.is_split(3));
+        assert_eq!(sitemap.entry_path(2), PathBuf::from("sitemap.xml"));
+        assert_eq!(sitemap.entry_path(3), PathBuf::from("sitemap-index.xml"));
+        assert_eq!(sitemap.numbered_path("0"), PathBuf::from("sitemap-0.xml"));
+
+        let nested = SitemapConfig {
+            path: "seo/map.xml".into(),
+            split_at: 0,
+            ..Default::default()
+        };
+        assert!(!nested.is_split(usize::MAX));
+        assert_eq!(nested.numbered_path("1"), PathBuf::from("seo/map-1.xml"));
+    }
+}
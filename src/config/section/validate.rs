@@ -8,15 +8,50 @@
 //! [validate.pages]
 //! enable = true               # Check internal page links
 //! level = "error"             # Failure level: error | warn
+//! max_redirect_chain = 3      # Warn when alias redirects chain more hops
+//! canonical = true            # Warn when `canonical` metadata is not the page's URL
 //!
 //! [validate.assets]
 //! enable = true               # Check referenced assets exist
 //! level = "error"             # Failure level: error | warn
+//!
+//! [validate.assets.orphans]
+//! enable = false              # Warn about assets no page references
+//! ignore = ["robots.txt", "CNAME", "_redirects", "_headers"]  # Output-relative globs
+//!
+//! [validate.orphans]
+//! enable = false              # Report pages with no inbound internal links
+//! level = "warn"              # Failure level: error | warn
+//!
+//! [validate.external]
+//! enable = false              # Check external http(s) links over the network
+//! level = "warn"              # Failure level: error | warn
+//! timeout_ms = 10000          # Per-request timeout
+//! concurrency = 8             # Parallel requests
+//! max_redirects = 5           # Redirects to follow before giving up
+//! cache_ttl = 86400           # Seconds to trust a cached result
+//!
+//! [validate.accessibility]
+//! enable = false              # Audit built HTML for common WCAG issues
+//! level = "warn"              # Failure level: error | warn
+//! images = true               # <img> needs meaningful alt text (alt="" marks decorative)
+//! heading_levels = true       # Heading levels must not skip (h1 -> h3)
+//! lang = true                 # <html> needs a lang attribute
+//!
+//! [validate.sitemap]
+//! enable = true               # Check the built sitemap.xml (when site.seo.sitemap is on)
+//! level = "error"             # Failure level: error | warn
+//!
+//! [validate.feed]
+//! enable = false              # Check the built RSS/Atom feed (when site.seo.feed is on)
+//! level = "error"             # Failure level: error | warn
 //! ```
 
 use macros::Config;
 use serde::{Deserialize, Serialize};
 
+use crate::utils::glob;
+
 // ============================================================================
 // Main ValidateConfig
 // ============================================================================
@@ -32,6 +67,26 @@ pub struct ValidateConfig {
     /// Asset validation settings.
     #[config(sub)]
     pub assets: AssetsValidateConfig,
+
+    /// Orphaned page detection settings.
+    #[config(sub)]
+    pub orphans: OrphansValidateConfig,
+
+    /// External link validation settings.
+    #[config(sub)]
+    pub external: ExternalValidateConfig,
+
+    /// Accessibility audit settings.
+    #[config(sub)]
+    pub accessibility: AccessibilityValidateConfig,
+
+    /// Built sitemap check settings.
+    #[config(sub)]
+    pub sitemap: SitemapValidateConfig,
+
+    /// Built feed check settings.
+    #[config(sub)]
+    pub feed: FeedValidateConfig,
 }
 
 // ============================================================================
@@ -47,6 +102,15 @@ pub struct PagesValidateConfig {
 
     #[config(default = "error", inline_doc = "Failure level: error | warn")]
     pub level: ValidateLevel,
+
+    #[config(inline_doc = "Warn when alias redirects chain more hops")]
+    pub max_redirect_chain: usize,
+
+    /// Check `canonical` page metadata against the permalink and aliases.
+    ///
+    /// Mismatches are always reported as warnings.
+    #[config(inline_doc = "Warn when `canonical` metadata is not the page's URL")]
+    pub canonical: bool,
 }
 
 impl Default for PagesValidateConfig {
@@ -54,6 +118,8 @@ impl Default for PagesValidateConfig {
         Self {
             enable: true,
             level: ValidateLevel::default(),
+            max_redirect_chain: 3,
+            canonical: true,
         }
     }
 }
@@ -71,9 +137,193 @@ pub struct AssetsValidateConfig {
 
     #[config(default = "error", inline_doc = "Failure level: error | warn")]
     pub level: ValidateLevel,
+
+    /// Unreferenced asset detection.
+    #[config(sub)]
+    pub orphans: OrphanAssetsValidateConfig,
 }
 
 impl Default for AssetsValidateConfig {
+    fn default() -> Self {
+        Self {
+            enable: true,
+            level: ValidateLevel::default(),
+            orphans: OrphanAssetsValidateConfig::default(),
+        }
+    }
+}
+
+/// Files in `build.assets` sources that no page links to.
+///
+/// Always reported as warnings: some files (`robots.txt`, `CNAME`) are
+/// meant to be served without being linked.
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "validate.assets.orphans")]
+pub struct OrphanAssetsValidateConfig {
+    #[config(inline_doc = "Warn about assets no page references")]
+    pub enable: bool,
+
+    /// Glob patterns (relative to the output directory) never reported.
+    pub ignore: Vec<String>,
+}
+
+impl Default for OrphanAssetsValidateConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            ignore: [
+                "robots.txt",
+                "CNAME",
+                "_redirects",
+                "_headers",
+                "favicon.ico",
+            ]
+            .map(String::from)
+            .to_vec(),
+        }
+    }
+}
+
+impl OrphanAssetsValidateConfig {
+    /// Whether an output-relative asset path matches an `ignore` pattern.
+    pub fn is_ignored(&self, path: &str) -> bool {
+        self.ignore
+            .iter()
+            .any(|pattern| glob::matches(pattern, path))
+    }
+}
+
+// ============================================================================
+// Orphaned Pages
+// ============================================================================
+
+/// Pages that no other page links to.
+///
+/// The site root and pages with `no_validate: true` in their metadata are
+/// never reported.
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "validate.orphans")]
+pub struct OrphansValidateConfig {
+    #[config(inline_doc = "Report pages with no inbound internal links")]
+    pub enable: bool,
+
+    #[config(default = "warn", inline_doc = "Failure level: error | warn")]
+    pub level: ValidateLevel,
+}
+
+impl Default for OrphansValidateConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            level: ValidateLevel::Warn,
+        }
+    }
+}
+
+// ============================================================================
+// External Link Validation
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "validate.external")]
+pub struct ExternalValidateConfig {
+    #[config(inline_doc = "Enable external http(s) link validation (network access)")]
+    pub enable: bool,
+
+    #[config(default = "warn", inline_doc = "Failure level: error | warn")]
+    pub level: ValidateLevel,
+
+    #[config(inline_doc = "Per-request timeout in milliseconds")]
+    pub timeout_ms: u64,
+
+    #[config(inline_doc = "Maximum number of parallel requests")]
+    pub concurrency: usize,
+
+    #[config(inline_doc = "Maximum redirects to follow")]
+    pub max_redirects: usize,
+
+    #[config(inline_doc = "Seconds to reuse cached results (0 = always re-check)")]
+    pub cache_ttl: u64,
+}
+
+impl Default for ExternalValidateConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            level: ValidateLevel::Warn,
+            timeout_ms: 10_000,
+            concurrency: 8,
+            max_redirects: 5,
+            cache_ttl: 86_400,
+        }
+    }
+}
+
+impl ExternalValidateConfig {
+    /// Request timeout as a [`Duration`](std::time::Duration).
+    pub const fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.timeout_ms)
+    }
+}
+
+// ============================================================================
+// Accessibility
+// ============================================================================
+
+/// Accessibility rules checked against the built HTML in the output directory.
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "validate.accessibility")]
+pub struct AccessibilityValidateConfig {
+    #[config(inline_doc = "Audit built pages for accessibility issues")]
+    pub enable: bool,
+
+    #[config(default = "warn", inline_doc = "Failure level: error | warn")]
+    pub level: ValidateLevel,
+
+    #[config(inline_doc = "Require meaningful alt text on non-decorative images")]
+    pub images: bool,
+
+    #[config(inline_doc = "Require sequential heading levels")]
+    pub heading_levels: bool,
+
+    #[config(inline_doc = "Require a lang attribute on <html>")]
+    pub lang: bool,
+}
+
+impl Default for AccessibilityValidateConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            level: ValidateLevel::Warn,
+            images: true,
+            heading_levels: true,
+            lang: true,
+        }
+    }
+}
+
+// ============================================================================
+// Sitemap
+// ============================================================================
+
+/// `sitemap.xml` (or its index) in the output directory, checked against
+/// the pages of the site. Skipped when `site.seo.sitemap` is disabled.
+#[derive(Debug, Clone, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "validate.sitemap")]
+pub struct SitemapValidateConfig {
+    #[config(inline_doc = "Check the built sitemap")]
+    pub enable: bool,
+
+    #[config(default = "error", inline_doc = "Failure level: error | warn")]
+    pub level: ValidateLevel,
+}
+
+impl Default for SitemapValidateConfig {
     fn default() -> Self {
         Self {
             enable: true,
@@ -82,6 +332,23 @@ impl Default for AssetsValidateConfig {
     }
 }
 
+// ============================================================================
+// Feed
+// ============================================================================
+
+/// RSS/Atom feeds in the output directory, checked for the elements their
+/// specifications require. Skipped when `site.seo.feed` is disabled.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Config)]
+#[serde(default)]
+#[config(section = "validate.feed")]
+pub struct FeedValidateConfig {
+    #[config(inline_doc = "Check the built feed against the RSS/Atom specifications")]
+    pub enable: bool,
+
+    #[config(default = "error", inline_doc = "Failure level: error | warn")]
+    pub level: ValidateLevel,
+}
+
 /// Validation error level
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
@@ -99,7 +366,7 @@ pub enum ValidateLevel {
 
 #[cfg(test)]
 mod tests {
-    use crate::config::{SiteConfig, test_parse_config};
+    use crate::config::{SiteConfig, ValidateLevel, test_parse_config};
 
     #[test]
     fn test_validate_config_custom() {
@@ -107,12 +374,111 @@ mod tests {
             r#"[validate.pages]
 enable = true
 level = "warn"
+max_redirect_chain = 5
 
 [validate.assets]
 enable = false
 level = "warn""#,
         );
         assert!(!config.validate.assets.enable);
+        assert_eq!(config.validate.pages.max_redirect_chain, 5);
+        assert_eq!(test_parse_config("").validate.pages.max_redirect_chain, 3);
+        assert!(config.validate.pages.canonical);
+
+        let config = test_parse_config("[validate.pages]\ncanonical = false");
+        assert!(!config.validate.pages.canonical);
+    }
+
+    #[test]
+    fn test_orphan_assets_config() {
+        let orphans = test_parse_config("").validate.assets.orphans;
+        assert!(!orphans.enable);
+        assert!(orphans.is_ignored("CNAME"));
+        assert!(!orphans.is_ignored("images/logo.png"));
+
+        let config = test_parse_config(
+            "[validate.assets.orphans]\nenable = true\nignore = [\"fonts/**\", \"*.txt\"]",
+        );
+        let orphans = &config.validate.assets.orphans;
+        assert!(orphans.enable);
+        assert!(orphans.is_ignored("fonts/inter/regular.woff2"));
+        assert!(orphans.is_ignored("robots.txt"));
+        assert!(!orphans.is_ignored("docs/notes.txt"));
+        assert!(!orphans.is_ignored("CNAME"));
+    }
+
+    #[test]
+    fn test_validate_orphans_config() {
+        let config = test_parse_config("");
+        assert!(!config.validate.orphans.enable);
+        assert_eq!(config.validate.orphans.level, ValidateLevel::Warn);
+
+        let config = test_parse_config("[validate.orphans]\nenable = true\nlevel = \"error\"");
+        assert!(config.validate.orphans.enable);
+        assert_eq!(config.validate.orphans.level, ValidateLevel::Error);
+    }
+
+    #[test]
+    fn test_validate_external_config() {
+        let config = test_parse_config("");
+        assert!(!config.validate.external.enable);
+        assert_eq!(config.validate.external.level, ValidateLevel::Warn);
+
+        let config = test_parse_config(
+            r#"[validate.external]
+enable = true
+level = "error"
+timeout_ms = 500
+concurrency = 2
+cache_ttl = 0"#,
+        );
+        let external = &config.validate.external;
+        assert!(external.enable);
+        assert_eq!(external.level, ValidateLevel::Error);
+        assert_eq!(external.timeout().as_millis(), 500);
+        assert_eq!(external.concurrency, 2);
+        assert_eq!(external.max_redirects, 5);
+        assert_eq!(external.cache_ttl, 0);
+    }
+
+    #[test]
+    fn test_validate_accessibility_config() {
+        let config = test_parse_config("");
+        let accessibility = &config.validate.accessibility;
+        assert!(!accessibility.enable);
+        assert_eq!(accessibility.level, ValidateLevel::Warn);
+        assert!(accessibility.images && accessibility.heading_levels && accessibility.lang);
+
+        let config = test_parse_config(
+            "[validate.accessibility]\nenable = true\nlevel = \"error\"\nheading_levels = false",
+        );
+        let accessibility = &config.validate.accessibility;
+        assert!(accessibility.enable);
+        assert_eq!(accessibility.level, ValidateLevel::Error);
+        assert!(!accessibility.heading_levels);
+        assert!(accessibility.images);
+    }
+
+    #[test]
+    fn test_validate_sitemap_config() {
+        let config = test_parse_config("");
+        assert!(config.validate.sitemap.enable);
+        assert_eq!(config.validate.sitemap.level, ValidateLevel::Error);
+
+        let config = test_parse_config("[validate.sitemap]\nenable = false\nlevel = \"warn\"");
+        assert!(!config.validate.sitemap.enable);
+        assert_eq!(config.validate.sitemap.level, ValidateLevel::Warn);
+    }
+
+    #[test]
+    fn test_validate_feed_config() {
+        let config = test_parse_config("");
+        assert!(!config.validate.feed.enable);
+        assert_eq!(config.validate.feed.level, ValidateLevel::Error);
+
+        let config = test_parse_config("[validate.feed]\nenable = true\nlevel = \"warn\"");
+        assert!(config.validate.feed.enable);
+        assert_eq!(config.validate.feed.level, ValidateLevel::Warn);
     }
 
     #[test]
@@ -0,0 +1,112 @@
+//! Environment variable overrides for config fields.
+//!
+//! Fields annotated with `#[config(env = "VAR")]` are overridden by the
+//! generated `from_env()` method when the variable is set. Env values are
+//! always strings, so they are coerced into the field type via serde.
+
+use super::ConfigError;
+use serde::de::DeserializeOwned;
+use std::env::VarError;
+
+/// Override `slot` with the value of `var` if the variable is set.
+///
+/// Parse failures are hard errors: a typo in CI should not silently
+/// fall back to the value from `tola.toml`.
+#[allow(clippy::result_large_err)]
+pub fn override_field<T: DeserializeOwned>(
+    slot: &mut T,
+    var: &'static str,
+    field: &'static str,
+) -> Result<(), ConfigError> {
+    match std::env::var(var) {
+        Ok(raw) => {
+            *slot = parse_value(var, field, &raw)?;
+            Ok(())
+        }
+        Err(VarError::NotPresent) => Ok(()),
+        Err(VarError::NotUnicode(_)) => Err(ConfigError::Env {
+            var,
+            field,
+            message: "value is not valid unicode".into(),
+        }),
+    }
+}
+
+/// Coerce a raw env string into the field type.
+///
+/// The raw string is tried first (`String`, `PathBuf`, enums, `IpAddr`),
+/// then it is parsed as a TOML value (`bool`, integers, floats, arrays).
+#[allow(clippy::result_large_err)]
+pub fn parse_value<T: DeserializeOwned>(
+    var: &'static str,
+    field: &'static str,
+    raw: &str,
+) -> Result<T, ConfigError> {
+    let as_string = T::deserialize(toml::Value::String(raw.to_string()));
+    let err = match as_string {
+        Ok(value) => return Ok(value),
+        Err(err) => err,
+    };
+
+    let err = match parse_toml_value(raw) {
+        Some(value) => match T::deserialize(value) {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        },
+        None => err,
+    };
+
+    Err(ConfigError::Env {
+        var,
+        field,
+        message: err.message().to_string(),
+    })
+}
+
+/// Parse a bare TOML value (e.g. `true`, `8080`, `["a", "b"]`).
+fn parse_toml_value(raw: &str) -> Option<toml::Value> {
+    let mut table: toml::Table = toml::from_str(&format!("value = {raw}")).ok()?;
+    table.remove("value")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::IpAddr;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_value_string_types() {
+        let s: String = parse_value("V", "f", "https://example.com").unwrap();
+        assert_eq!(s, "https://example.com");
+
+        let p: PathBuf = parse_value("V", "f", "~/.token").unwrap();
+        assert_eq!(p, PathBuf::from("~/.token"));
+
+        // Numeric-looking strings stay strings for String fields
+        let s: Option<String> = parse_value("V", "f", "8080").unwrap();
+        assert_eq!(s.as_deref(), Some("8080"));
+    }
+
+    #[test]
+    fn test_parse_value_coerces_scalars() {
+        let b: bool = parse_value("V", "f", "true").unwrap();
+        assert!(b);
+
+        let port: u16 = parse_value("V", "f", "8080").unwrap();
+        assert_eq!(port, 8080);
+
+        let ip: IpAddr = parse_value("V", "f", "0.0.0.0").unwrap();
+        assert!(ip.is_unspecified());
+    }
+
+    #[test]
+    fn test_parse_value_errors_are_reported() {
+        let err = parse_value::<u16>("TOLA_SERVE_PORT", "serve.port", "70000").unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("TOLA_SERVE_PORT"));
+        assert!(msg.contains("serve.port"));
+
+        assert!(parse_value::<bool>("V", "f", "yes").is_err());
+    }
+}
@@ -22,6 +22,13 @@ pub enum ConfigError {
     #[error("Config validation error: {0}")]
     Validation(String),
 
+    #[error("invalid value for environment variable `{var}` ({field}): {message}")]
+    Env {
+        var: &'static str,
+        field: &'static str,
+        message: String,
+    },
+
     // NOTE: No #[from] here - we don't want source() which causes duplicate output
     #[error("{0}")]
     Diagnostics(ConfigDiagnostics),
@@ -88,6 +95,8 @@ pub struct ConfigDiagnostics {
     hints: Vec<FieldPath>,
     /// Collected warnings (deprecated fields).
     warnings: Vec<(FieldPath, String)>,
+    /// Collected renames (old alias used, new name).
+    renames: Vec<(FieldPath, FieldPath)>,
     /// Suppress experimental feature hints.
     pub allow_experimental: bool,
     /// Explicitly present config paths collected from raw TOML.
@@ -105,6 +114,7 @@ impl ConfigDiagnostics {
             errors: Vec::new(),
             hints: Vec::new(),
             warnings: Vec::new(),
+            renames: Vec::new(),
             allow_experimental,
             presence: ConfigPresence::default(),
         }
@@ -147,6 +157,16 @@ impl ConfigDiagnostics {
         self.warnings.push((field, message.into()));
     }
 
+    /// Add a rename warning for a field set via its old alias (collected for batch display).
+    pub fn renamed(&mut self, old: FieldPath, new: FieldPath) {
+        self.renames.push((old, new));
+    }
+
+    /// Get collected renames as `(old, new)` field paths.
+    pub fn renames(&self) -> &[(FieldPath, FieldPath)] {
+        &self.renames
+    }
+
     /// Add a hint for experimental fields (collected for batch display).
     pub fn experimental_hint(&mut self, field: FieldPath) {
         self.hints.push(field);
@@ -161,10 +181,22 @@ impl ConfigDiagnostics {
     ///
     /// Call this after validation to display all hints/warnings at once.
     pub fn print_hints_and_warnings(&self) {
-        if self.warnings.is_empty() && self.hints.is_empty() {
+        if self.warnings.is_empty() && self.hints.is_empty() && self.renames.is_empty() {
             return;
         }
 
+        // Print renames (old alias still accepted)
+        if !self.renames.is_empty() {
+            crate::log!("warning"; "renamed fields, old names will stop working in a future version:");
+            for (old, new) in &self.renames {
+                eprintln!(
+                    "- rename `{}` to `{}` in tola.toml",
+                    old.as_str(),
+                    new.as_str()
+                );
+            }
+        }
+
         // Print warnings (deprecated fields/sections)
         if !self.warnings.is_empty() {
             crate::log!("warning"; "deprecated fields or sections, will be removed in a future version:");
@@ -199,6 +231,7 @@ impl ConfigDiagnostics {
     }
 
     /// Convert to Result (returns Err if there are errors).
+    #[allow(clippy::result_large_err)]
     pub fn into_result(self) -> Result<(), Self> {
         if self.errors.is_empty() {
             Ok(())
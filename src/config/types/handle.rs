@@ -38,7 +38,11 @@ impl ConfigHandle {
         let c = self.current();
         let cli = c.cli.expect("CLI should be set during initialization");
 
-        let content = fs::read_to_string(&c.config_path)?;
+        let mut content = fs::read_to_string(&c.config_path)?;
+        // Local overrides participate in change detection
+        if let Ok(local) = fs::read_to_string(c.local_config_path()) {
+            content.push_str(&local);
+        }
         let new_hash = crate::utils::hash::compute(content.as_bytes());
 
         let old_hash = CONFIG_HASH.load(std::sync::atomic::Ordering::Relaxed);
@@ -71,8 +75,11 @@ pub fn init_config(config: SiteConfig) -> Arc<SiteConfig> {
     use std::fs;
 
     if config.config_path.exists()
-        && let Ok(content) = fs::read_to_string(&config.config_path)
+        && let Ok(mut content) = fs::read_to_string(&config.config_path)
     {
+        if let Ok(local) = fs::read_to_string(config.local_config_path()) {
+            content.push_str(&local);
+        }
         let hash = crate::utils::hash::compute(content.as_bytes());
         CONFIG_HASH.store(hash, std::sync::atomic::Ordering::Relaxed);
     }
@@ -2,15 +2,19 @@
 //!
 //! | Module   | Purpose                                      |
 //! |----------|----------------------------------------------|
+//! | `env`    | Environment variable overrides               |
 //! | `error`  | Configuration error types                    |
 //! | `handle` | Global configuration handle (thread-safe)    |
 //! | `path`   | Path resolution utilities                    |
+//! | `schema` | JSON Schema generation helpers               |
 //! | `status` | Field status validation                      |
 
+pub(crate) mod env;
 mod error;
 mod field;
 pub mod handle;
 mod path;
+pub(crate) mod schema;
 pub(crate) mod status;
 
 pub use error::{ConfigDiagnostics, ConfigError};
@@ -55,8 +55,7 @@ impl<'a> PathResolver<'a> {
     /// - Git repository initialization
     /// - Top-level files like `.gitignore`, `.ignore`
     #[inline]
-    #[allow(dead_code)] // Reserved API
-    pub const fn output_root(&self) -> &Path {
+    pub const fn output_root(&self) -> &'a Path {
         self.output
     }
 
@@ -126,7 +125,6 @@ impl<'a> PathResolver<'a> {
     /// // Output root: /home/user/public
     /// // Result: /my-project/css/app.css
     /// ```
-    #[allow(dead_code)] // Reserved API
     pub fn url_for_path(&self, path: &Path) -> Option<String> {
         let rel = path.strip_prefix(self.output).ok()?;
         let path_str = rel.to_string_lossy().replace('\\', "/");
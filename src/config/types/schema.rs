@@ -0,0 +1,232 @@
+//! JSON Schema helpers for `tola.toml`.
+//!
+//! The `Config` derive macro emits a `schema()` method per section which
+//! calls into these helpers. Rust types are mapped by name, enum variants
+//! are discovered through serde so renamed variants match `tola.toml`.
+
+use super::FieldStatus;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde_json::{Map, Value, json};
+
+/// JSON Schema dialect used for the generated document.
+pub const SCHEMA_DIALECT: &str = "http://json-schema.org/draft-07/schema#";
+
+/// Build the root schema document for `tola.toml`.
+pub fn document(properties: Map<String, Value>) -> Value {
+    let mut schema = Map::new();
+    schema.insert("$schema".into(), json!(SCHEMA_DIALECT));
+    schema.insert("title".into(), json!("tola.toml"));
+    schema.insert(
+        "description".into(),
+        json!("Configuration file for the tola static site generator"),
+    );
+    schema.insert("type".into(), json!("object"));
+    schema.insert("properties".into(), Value::Object(properties));
+    Value::Object(schema)
+}
+
+/// Build an object schema from collected properties.
+pub fn object(description: &str, properties: Map<String, Value>) -> Value {
+    let mut schema = Map::new();
+    schema.insert("type".into(), json!("object"));
+    if !description.is_empty() {
+        schema.insert("description".into(), json!(description));
+    }
+    schema.insert("properties".into(), Value::Object(properties));
+    Value::Object(schema)
+}
+
+/// Build a field schema from its Rust type.
+///
+/// `ty` is the stringified type from the derive macro (e.g. `Option<PathBuf>`).
+pub fn field<T: DeserializeOwned>(
+    ty: &str,
+    description: Option<&str>,
+    default: Option<Value>,
+    status: Option<FieldStatus>,
+) -> Value {
+    let mut schema = match enum_variants::<T>() {
+        Some(variants) => enum_schema(ty, variants),
+        None => type_schema(ty),
+    };
+
+    let description = match (description, status) {
+        (Some(doc), Some(status)) => Some(format!("({}) {}", status.label(), doc)),
+        (Some(doc), None) => Some(doc.to_string()),
+        (None, Some(status)) => Some(format!("({})", status.label())),
+        (None, None) => None,
+    };
+    if let Some(description) = description {
+        schema.insert("description".into(), json!(description));
+    }
+    if let Some(default) = default.filter(|v| !v.is_null()) {
+        schema.insert("default".into(), default);
+    }
+    if status == Some(FieldStatus::Deprecated) {
+        schema.insert("deprecated".into(), json!(true));
+    }
+
+    Value::Object(schema)
+}
+
+/// Map a stringified Rust type to a JSON Schema fragment.
+fn type_schema(ty: &str) -> Map<String, Value> {
+    let mut map = Map::new();
+
+    if let Some(inner) = strip_generic(ty, "Option") {
+        return type_schema(inner);
+    }
+    if let Some(inner) = strip_generic(ty, "Vec") {
+        map.insert("type".into(), json!("array"));
+        map.insert("items".into(), Value::Object(type_schema(inner)));
+        return map;
+    }
+
+    let json_type = match ty {
+        "String" | "PathBuf" | "IpAddr" | "char" => "string",
+        "bool" => "boolean",
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" => {
+            "integer"
+        }
+        "f32" | "f64" => "number",
+        _ if ty.starts_with("FxHashMap<") || ty.starts_with("HashMap<") => "object",
+        // Untagged enums and custom types accept several shapes
+        _ => return map,
+    };
+    map.insert("type".into(), json!(json_type));
+    map
+}
+
+/// String enum schema, as array items for `Vec<Enum>` fields.
+fn enum_schema(ty: &str, variants: &[&str]) -> Map<String, Value> {
+    let mut map = Map::new();
+
+    if let Some(inner) = strip_generic(ty, "Option") {
+        return enum_schema(inner, variants);
+    }
+    if let Some(inner) = strip_generic(ty, "Vec") {
+        map.insert("type".into(), json!("array"));
+        map.insert("items".into(), Value::Object(enum_schema(inner, variants)));
+        return map;
+    }
+
+    map.insert("type".into(), json!("string"));
+    map.insert("enum".into(), json!(variants));
+    map
+}
+
+/// Strip `Wrapper<...>` and return the inner type.
+fn strip_generic<'a>(ty: &'a str, wrapper: &str) -> Option<&'a str> {
+    ty.strip_prefix(wrapper)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
+
+/// Discover serialized variant names of a unit-variant enum, or of the
+/// element type of a sequence (`Vec<Enum>`).
+///
+/// Returns `None` for non-enum types and for untagged enums, which
+/// deserialize through `deserialize_any` instead of `deserialize_enum`.
+fn enum_variants<T: DeserializeOwned>() -> Option<&'static [&'static str]> {
+    let mut variants = None;
+    let _ = T::deserialize(VariantCapture(&mut variants));
+    variants
+}
+
+/// Deserializer that records the variants passed to `deserialize_enum`.
+struct VariantCapture<'a>(&'a mut Option<&'static [&'static str]>);
+
+impl<'de> Deserializer<'de> for VariantCapture<'_> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not an enum"))
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_seq(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.0 = Some(variants);
+        Err(de::Error::custom("variants captured"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Yields a single element, deserialized with [`VariantCapture`].
+impl<'de> SeqAccess<'de> for VariantCapture<'_> {
+    type Error = de::value::Error;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, Self::Error> {
+        seed.deserialize(VariantCapture(self.0)).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{FeedFormat, SlugMode};
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_enum_variants_follow_serde_rename() {
+        let variants = enum_variants::<FeedFormat>().unwrap();
+        assert!(variants.contains(&"rss"));
+        assert!(variants.contains(&"atom"));
+
+        let variants = enum_variants::<Option<SlugMode>>().unwrap();
+        assert!(variants.contains(&"safe"));
+
+        let variants = enum_variants::<Vec<FeedFormat>>().unwrap();
+        assert!(variants.contains(&"atom"));
+    }
+
+    #[test]
+    fn test_enum_variants_none_for_plain_types() {
+        assert!(enum_variants::<String>().is_none());
+        assert!(enum_variants::<PathBuf>().is_none());
+        assert!(enum_variants::<bool>().is_none());
+        assert!(enum_variants::<Vec<String>>().is_none());
+    }
+
+    #[test]
+    fn test_field_schema() {
+        let schema = field::<Vec<PathBuf>>("Vec<PathBuf>", Some("Dirs."), None, None);
+        assert_eq!(schema["type"], "array");
+        assert_eq!(schema["items"]["type"], "string");
+        assert_eq!(schema["description"], "Dirs.");
+
+        let schema = field::<Vec<FeedFormat>>("Vec<FeedFormat>", None, None, None);
+        assert_eq!(schema["type"], "array");
+        assert_eq!(schema["items"]["type"], "string");
+        assert_eq!(schema["items"]["enum"], json!(["rss", "atom"]));
+
+        let schema = field::<u16>(
+            "u16",
+            None,
+            Some(json!(5277)),
+            Some(FieldStatus::Deprecated),
+        );
+        assert_eq!(schema["type"], "integer");
+        assert_eq!(schema["default"], 5277);
+        assert_eq!(schema["deprecated"], true);
+    }
+}
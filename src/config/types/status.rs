@@ -148,5 +148,13 @@ pub fn check_section_status(section: &str, status: FieldStatus, diag: &mut Confi
     }
 }
 
+/// Report usage of a renamed field's old name
+///
+/// Called by generated `validate_field_status` methods when the alias
+/// declared via `#[config(alias = "...")]` is present in TOML
+pub fn check_alias(old_path: &'static str, new_path: &'static str, diag: &mut ConfigDiagnostics) {
+    diag.renamed(FieldPath::new(old_path), FieldPath::new(new_path));
+}
+
 #[cfg(test)]
 mod tests {}
@@ -24,10 +24,26 @@ impl BuildMode {
         cache_vdom: true,
     };
 
+    /// Watch mode (`build --watch`): production output, with indexed VDOM
+    /// still cached so rebuilds run through the watch actors.
+    pub const WATCH: Self = Self {
+        emit_ids: false,
+        cache_vdom: true,
+    };
+
     /// Check if this is development mode.
     #[inline]
     #[allow(dead_code)]
     pub const fn is_dev(&self) -> bool {
         self.emit_ids
     }
+
+    /// Mode name: `"development"` or `"production"`.
+    pub const fn as_str(&self) -> &'static str {
+        if self.is_dev() {
+            "development"
+        } else {
+            "production"
+        }
+    }
 }
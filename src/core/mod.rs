@@ -14,7 +14,7 @@ pub use driver::BuildMode;
 pub use link::{LinkKind, LinkOrigin};
 pub use priority::Priority;
 pub use state::{
-    is_healthy, is_serving, is_shutdown, register_server, set_healthy, set_serving,
-    setup_shutdown_handler,
+    is_healthy, is_serving, is_shutdown, register_server, register_watcher, set_healthy,
+    set_serving, setup_shutdown_handler,
 };
 pub use url::{UrlChange, UrlPath};
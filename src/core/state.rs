@@ -5,10 +5,8 @@
 //! - `HEALTHY`: Is the build healthy? (can hot-reload vs needs full rebuild)
 //! - `SHUTDOWN`: Has shutdown been requested? (Ctrl+C received)
 
+use std::sync::OnceLock;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, OnceLock};
-
-use tiny_http::Server;
 
 /// Site is ready to serve requests (init phase complete)
 /// - `false`: Return 503
@@ -23,8 +21,8 @@ static HEALTHY: AtomicBool = AtomicBool::new(false);
 /// Shutdown has been requested (Ctrl+C received)
 static SHUTDOWN: AtomicBool = AtomicBool::new(false);
 
-/// HTTP server reference for graceful shutdown
-static SERVER: OnceLock<Arc<Server>> = OnceLock::new();
+/// Stops the HTTP server from accepting requests (graceful shutdown)
+static SERVER: OnceLock<Box<dyn Fn() + Send + Sync>> = OnceLock::new();
 
 /// Shutdown signal sender for actor system
 static SHUTDOWN_TX: OnceLock<crossbeam::channel::Sender<()>> = OnceLock::new();
@@ -63,9 +61,10 @@ pub fn set_healthy(healthy: bool) {
 
 /// Setup the global Ctrl+C handler. Call once at program start
 ///
-/// The handler behavior depends on whether a server has been registered:
-/// - Before `register_server()`: Sets SHUTDOWN flag, process exits naturally
+/// The handler behavior depends on what has been registered:
+/// - Nothing registered: Sets SHUTDOWN flag, process exits naturally
 /// - After `register_server()`: Graceful shutdown (unblock server, notify actors)
+/// - After `register_watcher()`: Graceful shutdown (notify actors)
 pub fn setup_shutdown_handler() -> anyhow::Result<()> {
     ctrlc::set_handler(|| {
         SHUTDOWN.store(true, Ordering::SeqCst);
@@ -76,9 +75,12 @@ pub fn setup_shutdown_handler() -> anyhow::Result<()> {
         }
 
         // Unblock HTTP server, or exit immediately if not yet serving
-        if let Some(server) = SERVER.get() {
+        if let Some(unblock) = SERVER.get() {
             crate::log!("serve"; "shutting down...");
-            server.unblock();
+            unblock();
+        } else if SHUTDOWN_TX.get().is_some() {
+            // Watcher without a server (`build --watch`)
+            crate::log!("watch"; "shutting down...");
         } else {
             // No server registered yet (e.g., during config prompt)
             // Exit immediately since there's nothing to gracefully shutdown
@@ -90,9 +92,20 @@ pub fn setup_shutdown_handler() -> anyhow::Result<()> {
 
 /// Register the HTTP server for graceful shutdown
 ///
-/// Call this after binding the server, before entering the request loop
-pub fn register_server(server: Arc<Server>, shutdown_tx: crossbeam::channel::Sender<()>) {
-    let _ = SERVER.set(server);
+/// `unblock` makes the request loop return. Call this after binding the
+/// server, before entering the request loop
+pub fn register_server(
+    unblock: impl Fn() + Send + Sync + 'static,
+    shutdown_tx: crossbeam::channel::Sender<()>,
+) {
+    let _ = SERVER.set(Box::new(unblock));
+    let _ = SHUTDOWN_TX.set(shutdown_tx);
+}
+
+/// Register a server-less watcher for graceful shutdown
+///
+/// Call this before starting the actors of `build --watch`
+pub fn register_watcher(shutdown_tx: crossbeam::channel::Sender<()>) {
     let _ = SHUTDOWN_TX.set(shutdown_tx);
 }
 
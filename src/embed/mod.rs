@@ -6,7 +6,8 @@
 //! - `asset` - Embedded asset types with content-hash filenames
 //! - `build` - Build-time templates (redirect.html)
 //! - `serve` - Dev server templates (welcome.html, hotreload.js)
-//! - `css` - Embedded stylesheets (enhance.css)
+//! - `css` - Embedded stylesheets (enhance.css, code.css)
+//! - `templates` - Starter sites for `tola init --template`
 //!
 //! Typst virtual packages (@tola/*) are in `src/package/embed/`.
 //!
@@ -21,7 +22,7 @@
 //! let html = REDIRECT_HTML.render(&RedirectVars { canonical_url: "/new-url/" });
 //!
 //! // Render hotreload JS with port
-//! let js = HOTRELOAD_JS.render(&HotreloadVars { ws_port: 35729 });
+//! let js = HOTRELOAD_JS.render(&HotreloadVars { ws_port: 35729, ws_scheme: "ws" });
 //! ```
 
 mod asset;
@@ -130,15 +131,19 @@ pub mod serve {
     /// Variables for hotreload.js.
     pub struct HotreloadVars {
         pub ws_port: u16,
+        /// `ws` or `wss` (when `serve.tls` is enabled).
+        pub ws_scheme: &'static str,
     }
 
     impl TemplateVars for HotreloadVars {
         fn apply(&self, content: &str) -> String {
-            content.replace("__TOLA_WS_PORT__", &self.ws_port.to_string())
+            content
+                .replace("__TOLA_WS_PORT__", &self.ws_port.to_string())
+                .replace("__TOLA_WS_SCHEME__", self.ws_scheme)
         }
 
         fn hash_input(&self) -> String {
-            self.ws_port.to_string()
+            format!("{}{}", self.ws_scheme, self.ws_port)
         }
     }
 
@@ -235,38 +240,167 @@ pub mod css {
     /// Enhanced CSS for Typst SVG theme adaptation and View Transitions.
     pub const ENHANCE_CSS: EmbeddedAsset<EnhanceVars> =
         EmbeddedAsset::new(AssetKind::Css, "enhance", include_str!("css/enhance.css"));
+
+    /// Variables for code.css template (from build.code config).
+    #[derive(Clone)]
+    pub struct CodeCssVars {
+        /// Syntax highlighting theme name.
+        pub theme: String,
+    }
+
+    impl TemplateVars for CodeCssVars {
+        fn apply(&self, content: &str) -> String {
+            content.replace(
+                "/*! CODE_CSS */",
+                &crate::pipeline::transform::code::theme_css(&self.theme),
+            )
+        }
+
+        fn hash_input(&self) -> String {
+            self.theme.clone()
+        }
+    }
+
+    /// Build CodeCssVars from SiteConfig.
+    pub fn code_vars(config: &crate::config::SiteConfig) -> CodeCssVars {
+        CodeCssVars {
+            theme: config.build.code.theme.clone(),
+        }
+    }
+
+    /// Class-based syntax highlighting CSS (`build.code.inline_css = false`).
+    pub const CODE_CSS: EmbeddedAsset<CodeCssVars> =
+        EmbeddedAsset::new(AssetKind::Css, "code", include_str!("css/code.css"));
 }
 
 pub mod typst {
     use super::{Template, TemplateVars};
+    use crate::config::SiteConfig;
 
     /// Variables for tola.typ templates.
-    pub struct TolaTypstVars {
+    pub struct TolaTypstVars<'a> {
         pub version: &'static str,
+        /// Label of the page metadata block (`build.meta.label`).
+        pub meta_label: &'a str,
     }
 
-    impl Default for TolaTypstVars {
-        fn default() -> Self {
+    impl<'a> TolaTypstVars<'a> {
+        /// Build template variables from site config.
+        pub fn from_config(config: &'a SiteConfig) -> Self {
             Self {
                 version: env!("CARGO_PKG_VERSION"),
+                meta_label: &config.build.meta.label,
             }
         }
     }
 
-    impl TemplateVars for TolaTypstVars {
+    impl TemplateVars for TolaTypstVars<'_> {
         fn apply(&self, content: &str) -> String {
-            content.replace("__VERSION__", self.version)
+            content
+                .replace("__VERSION__", self.version)
+                .replace("__META_LABEL__", self.meta_label)
         }
     }
 
     /// Tola template for tola init to generate templates/tola.typ.
-    pub const TOLA_TEMPLATE: Template<TolaTypstVars> =
+    pub const TOLA_TEMPLATE: Template<TolaTypstVars<'static>> =
         Template::new(include_str!("typst/templates/tola.typ"));
     /// Tola util for tola init to generate utils/tola.typ.
-    pub const TOLA_UTIL: Template<TolaTypstVars> =
+    pub const TOLA_UTIL: Template<TolaTypstVars<'static>> =
         Template::new(include_str!("typst/utils/tola.typ"));
 }
 
+pub mod templates {
+    /// A starter site that `tola init --template <name>` can expand.
+    #[derive(Debug, Clone, Copy)]
+    pub struct StarterTemplate {
+        pub name: &'static str,
+        pub description: &'static str,
+        /// Directories to create, relative to the site root.
+        pub dirs: &'static [&'static str],
+        /// Files to write, relative to the site root.
+        pub files: &'static [(&'static str, &'static [u8])],
+        /// Whether to write templates/tola.typ and utils/tola.typ.
+        pub tola_typst: bool,
+    }
+
+    /// Directory layout shared by the blog and docs starters.
+    const SITE_DIRS: &[&str] = &[
+        "content",
+        "assets/images",
+        "assets/iconfonts",
+        "assets/fonts",
+        "assets/scripts",
+        "assets/styles",
+        "templates",
+        "utils",
+    ];
+
+    /// Default starter: the standard site layout with no content.
+    pub const BLOG: StarterTemplate = StarterTemplate {
+        name: "blog",
+        description: "Standard site layout with templates/ and utils/ (default)",
+        dirs: SITE_DIRS,
+        files: &[],
+        tola_typst: true,
+    };
+
+    /// Documentation site with sidebar navigation.
+    pub const DOCS: StarterTemplate = StarterTemplate {
+        name: "docs",
+        description: "Documentation site with sidebar navigation",
+        dirs: SITE_DIRS,
+        files: &[
+            (
+                "templates/docs.typ",
+                include_bytes!("templates/docs/templates/docs.typ"),
+            ),
+            (
+                "content/index.typ",
+                include_bytes!("templates/docs/content/index.typ"),
+            ),
+            (
+                "content/guide/getting-started.typ",
+                include_bytes!("templates/docs/content/guide/getting-started.typ"),
+            ),
+            (
+                "assets/styles/docs.css",
+                include_bytes!("templates/docs/assets/styles/docs.css"),
+            ),
+        ],
+        tola_typst: true,
+    };
+
+    /// Bare-bones starter: tola.toml and an empty content directory.
+    pub const MINIMAL: StarterTemplate = StarterTemplate {
+        name: "minimal",
+        description: "Bare-bones tola.toml and an empty content/ directory",
+        dirs: &["content"],
+        files: &[],
+        tola_typst: false,
+    };
+
+    /// All starters, in the order `tola init --template list` prints them.
+    pub const ALL: &[StarterTemplate] = &[BLOG, DOCS, MINIMAL];
+
+    /// Look up a starter by name.
+    pub fn find(name: &str) -> Option<&'static StarterTemplate> {
+        ALL.iter().find(|t| t.name == name)
+    }
+
+    /// Editor settings written by every non-bare starter, relative to the site root.
+    pub const EDITOR_FILES: &[(&str, &str)] = &[
+        (
+            ".editorconfig",
+            include_str!("templates/common/editorconfig"),
+        ),
+        (
+            "typstfmt.toml",
+            include_str!("templates/common/typstfmt.toml"),
+        ),
+    ];
+}
+
 pub mod recolor {
     use super::{AssetKind, EmbeddedAsset, TemplateVars};
     use crate::config::section::theme::{RecolorConfig, RecolorSource};
@@ -392,6 +526,7 @@ use std::path::Path;
 ///
 /// This centralizes the logic for writing config-dependent embedded assets:
 /// - enhance.css (always)
+/// - code.css (if build.code uses classes instead of inline styles)
 /// - spa.js (if site.nav.spa)
 /// - recolor.css + recolor.js (if theme.recolor.enable)
 pub fn write_embedded_assets(config: &SiteConfig, output_dir: &Path) -> Result<()> {
@@ -406,6 +541,13 @@ pub fn write_embedded_assets(config: &SiteConfig, output_dir: &Path) -> Result<(
         ENHANCE_CSS.write_with_vars(output_dir, &vars)?;
     }
 
+    // code.css (class-based highlighting)
+    if config.build.code.needs_stylesheet() {
+        use css::{CODE_CSS, code_vars};
+        CODE_CSS.cleanup_old(output_dir)?;
+        CODE_CSS.write_with_vars(output_dir, &code_vars(config))?;
+    }
+
     // spa.js (if spa enabled)
     if config.site.nav.spa {
         use build::{SPA_JS, SpaVars};
@@ -448,4 +590,16 @@ mod tests {
         let vars = build::SpaVars::from_config(&config);
         assert_eq!(vars.path_prefix, "/docs/blog");
     }
+
+    #[test]
+    fn test_tola_template_uses_meta_label() {
+        let mut config = crate::config::SiteConfig::default();
+        let content = typst::TOLA_TEMPLATE.render(&typst::TolaTypstVars::from_config(&config));
+        assert!(content.contains(")) <tola-meta>]"));
+
+        config.build.meta.label = "frontmatter".to_string();
+        let content = typst::TOLA_TEMPLATE.render(&typst::TolaTypstVars::from_config(&config));
+        assert!(content.contains(")) <frontmatter>]"));
+        assert!(!content.contains("__META_LABEL__"));
+    }
 }
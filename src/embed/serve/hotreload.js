@@ -12,6 +12,25 @@
 
 (function() {
   const ERROR_OVERLAY_CSS = `__TOLA_ERROR_OVERLAY_CSS__`;
+  const RECONNECT_INDICATOR_CSS = `
+    :host {
+      all: initial;
+      position: fixed;
+      bottom: 12px;
+      right: 12px;
+      width: 10px;
+      height: 10px;
+      border-radius: 50%;
+      background: #f59e0b;
+      box-shadow: 0 0 0 2px rgba(0,0,0,0.25);
+      z-index: 99999;
+      pointer-events: none;
+      animation: tola-pulse 1.2s ease-in-out infinite;
+    }
+    @keyframes tola-pulse { 50% { opacity: 0.35; } }
+  `;
+  const MIN_RECONNECT_DELAY = 1000;
+  const MAX_RECONNECT_DELAY = 30000;
 
   const Tola = {
     // StableId -> Element mapping for O(1) lookups
@@ -21,11 +40,14 @@
     wsPort: null,
     reconnectTimer: null,
     reconnectRetries: 0,
-    maxReconnectRetries: 30,
+    // Build id from the last `connected` message, compared after reconnecting
+    lastBuild: null,
     pageActive: true,
     suppressNextClose: false,
     suppressReloadUntil: 0,
-    reconnectDelay: 1000,
+    reconnectDelay: MIN_RECONNECT_DELAY,
+    progressBar: null,
+    progressTimer: null,
 
     closeWsSilently() {
       if (!this.ws) return;
@@ -56,19 +78,20 @@
         return;
       }
 
-      const wsScheme = window.location.protocol === 'https:' ? 'wss' : 'ws';
+      const wsScheme = '__TOLA_WS_SCHEME__';
       const wsHost = window.location.hostname || 'localhost';
       const ws = new WebSocket(`${wsScheme}://${wsHost}:${this.wsPort}/`);
       this.ws = ws;
 
       ws.onopen = () => {
         console.log('[tola] hot reload connected');
-        this.reconnectDelay = 1000;
+        this.reconnectDelay = MIN_RECONNECT_DELAY;
         this.reconnectRetries = 0;
         if (this.reconnectTimer) {
           clearTimeout(this.reconnectTimer);
           this.reconnectTimer = null;
         }
+        this.hideReconnectIndicator();
         this.hydrate();
         // Report current page to the server so later hot-reload work can
         // prioritize the page the browser is currently showing.
@@ -104,8 +127,9 @@
       ws.onerror = () => {};
     },
 
-    // Attempt to reconnect with exponential backoff.
-    // Do not auto-reload the page on transient disconnects (e.g. laptop sleep).
+    // Attempt to reconnect with exponential backoff (1s, doubling up to 30s).
+    // Do not auto-reload the page on transient disconnects (e.g. laptop sleep);
+    // the `connected` message tells whether the build changed meanwhile.
     attemptReconnect() {
       if (!this.wsPort) return;
       if (this.reconnectTimer) return;
@@ -119,17 +143,12 @@
       if (navigator.onLine === false) {
         return;
       }
-      if (this.reconnectRetries >= this.maxReconnectRetries) {
-        console.log('[tola] giving up reconnect, please refresh manually');
-        return;
-      }
-
-      const delay = this.reconnectRetries === 0
-        ? 500
-        : Math.min(1000 * Math.pow(1.3, this.reconnectRetries - 1), 5000);
 
+      const delay = this.reconnectDelay;
+      this.reconnectDelay = Math.min(delay * 2, MAX_RECONNECT_DELAY);
       this.reconnectRetries += 1;
-      console.log(`[tola] reconnect attempt ${this.reconnectRetries}/${this.maxReconnectRetries}`);
+      console.log(`[tola] reconnect attempt ${this.reconnectRetries} in ${delay / 1000}s`);
+      this.showReconnectIndicator();
       this.reconnectTimer = setTimeout(() => {
         this.reconnectTimer = null;
         this.connect();
@@ -245,16 +264,25 @@
         case 'css':
           this.applyCssMessage(msg);
           break;
+        case 'css_reload':
+          this.reloadStylesheet(msg.href);
+          break;
+        case 'progress':
+          this.updateProgress(msg.percent, msg.message);
+          break;
         case 'ping':
           this.sendMessage({ type: 'pong', ts: msg.ts });
           break;
         case 'pong':
+          console.log('[tola] latency:', Date.now() - msg.ts, 'ms');
           break;
         case 'connected':
           console.log('[tola] server version:', msg.version);
+          this.handleConnected(msg.build);
           break;
         case 'error':
           console.error('[tola] compile error:', msg.path, msg.error);
+          this.updateProgress(100);
           this.errorState.set(msg.path, msg.error);
           this.renderErrorOverlay();
           break;
@@ -271,6 +299,19 @@
       }
     },
 
+    // Reload if the build changed while this page was disconnected
+    handleConnected(build) {
+      const previous = this.lastBuild;
+      this.lastBuild = build;
+      if (previous === null) return;
+
+      this.sendMessage({ type: 'ping', ts: Date.now() });
+      if (build !== previous && this.pageActive && Date.now() >= this.suppressReloadUntil) {
+        console.log('[tola] build changed while disconnected, reloading');
+        location.reload();
+      }
+    },
+
     sendMessage(message) {
       if (this.ws && this.ws.readyState === WebSocket.OPEN) {
         this.ws.send(JSON.stringify(message));
@@ -316,6 +357,78 @@
       }
     },
 
+    // Swap matching <link rel="stylesheet"> for a cache-busted copy.
+    // The old link is removed once the new one loads, so there is no flash.
+    // Slim fixed bar at the top of the viewport; hidden at 100%
+    updateProgress(percent, message) {
+      clearTimeout(this.progressTimer);
+      let bar = this.progressBar;
+      if (percent >= 100) {
+        if (!bar) return;
+        bar.style.transform = 'scaleX(1)';
+        bar.dataset.done = '';
+        this.progressTimer = setTimeout(() => {
+          bar.remove();
+          if (this.progressBar === bar) this.progressBar = null;
+        }, 300);
+        return;
+      }
+
+      if (!bar || !bar.isConnected) {
+        bar = document.createElement('div');
+        bar.className = 'tola-progress';
+        bar.setAttribute('role', 'progressbar');
+        document.body.appendChild(bar);
+        this.progressBar = bar;
+      }
+      delete bar.dataset.done;
+      bar.style.transform = `scaleX(${Math.max(percent, 2) / 100})`;
+      if (message) bar.title = message;
+    },
+
+    reloadStylesheet(href) {
+      if (typeof href !== 'string') return;
+
+      let target;
+      try {
+        target = new URL(href, window.location.href).pathname;
+      } catch (_) {
+        return;
+      }
+
+      let count = 0;
+      document.querySelectorAll('link[rel="stylesheet"]').forEach((link) => {
+        if (link.dataset.tolaReloading) return;
+        let url;
+        try {
+          url = new URL(link.getAttribute('href') || '', window.location.href);
+        } catch (_) {
+          return;
+        }
+        if (url.pathname !== target) return;
+
+        url.searchParams.set('v', Date.now().toString(36));
+        const next = link.cloneNode();
+        next.href = url.pathname + url.search;
+        link.dataset.tolaReloading = '1';
+        const done = () => link.remove();
+        next.addEventListener('load', done, { once: true });
+        next.addEventListener('error', done, { once: true });
+        link.after(next);
+        if (next.dataset.tolaId) {
+          this.idMap.set(next.dataset.tolaId, next);
+        }
+        count += 1;
+      });
+
+      if (count > 0) {
+        console.log('[tola] stylesheet reloaded:', target);
+        if (window.TolaSpa && typeof window.TolaSpa.clearCaches === 'function') {
+          window.TolaSpa.clearCaches();
+        }
+      }
+    },
+
     findCssTargets(target) {
       const targets = [];
       try {
@@ -396,21 +509,26 @@
       const extraCount = entries.length - 1;
       let overlay = document.getElementById('tola-error-overlay');
       if (!overlay) {
+        // Shadow root isolates the overlay from page styles (and vice versa)
         overlay = document.createElement('div');
         overlay.id = 'tola-error-overlay';
-        overlay.innerHTML = `
+        overlay.attachShadow({ mode: 'open' }).innerHTML = `
           <style>${ERROR_OVERLAY_CSS}</style>
           <div class="tola-error-header">
             <span class="tola-error-title">Compilation Error</span>
-            <button class="tola-error-close" onclick="Tola.hideErrorOverlay()">Dismiss</button>
+            <button class="tola-error-close">Dismiss</button>
           </div>
           <div class="tola-error-content">
             <div class="tola-error-path"></div>
             <div class="tola-error-message"></div>
           </div>
         `;
+        overlay.shadowRoot
+          .querySelector('.tola-error-close')
+          .addEventListener('click', () => this.hideErrorOverlay());
         document.body.appendChild(overlay);
       }
+      const root = overlay.shadowRoot;
 
       const title = extraCount > 0
         ? `Compilation Errors (${entries.length})`
@@ -419,10 +537,10 @@
         ? `${path} (+${extraCount} more)`
         : path;
 
-      overlay.querySelector('.tola-error-title').textContent = title;
-      overlay.querySelector('.tola-error-path').textContent = summary;
+      root.querySelector('.tola-error-title').textContent = title;
+      root.querySelector('.tola-error-path').textContent = summary;
       // Use innerHTML since error contains HTML spans for syntax highlighting
-      overlay.querySelector('.tola-error-message').innerHTML = error;
+      root.querySelector('.tola-error-message').innerHTML = error;
       overlay.style.display = 'flex';
     },
 
@@ -432,6 +550,25 @@
       if (overlay) overlay.style.display = 'none';
     },
 
+    // Small dot in the corner while reconnecting (shadow root, like the error overlay)
+    showReconnectIndicator() {
+      let dot = document.getElementById('tola-reconnect-indicator');
+      if (!dot) {
+        if (!document.body) return;
+        dot = document.createElement('div');
+        dot.id = 'tola-reconnect-indicator';
+        dot.title = 'tola: reconnecting...';
+        dot.attachShadow({ mode: 'open' }).innerHTML = `<style>${RECONNECT_INDICATOR_CSS}</style>`;
+        document.body.appendChild(dot);
+      }
+      dot.style.display = 'block';
+    },
+
+    hideReconnectIndicator() {
+      const dot = document.getElementById('tola-reconnect-indicator');
+      if (dot) dot.style.display = 'none';
+    },
+
     // Apply patch operations
     // Phase 1: apply stylesheet updates (replace/attrs) and wait for preload completion
     // Phase 2: apply all remaining DOM patches
@@ -0,0 +1,15 @@
+#import "/templates/docs.typ": docs-page
+
+#show: docs-page.with(
+  title: "Getting Started",
+  order: 1,
+)
+
+Start the development server:
+
+```sh
+tola serve
+```
+
+Then add a new page with `tola new guide/my-page` and edit it in
+`content/guide/my-page.typ`.
@@ -0,0 +1,13 @@
+#import "/templates/docs.typ": docs-page
+
+#show: docs-page.with(
+  title: "Introduction",
+  order: 0,
+)
+
+Welcome to your documentation site.
+
+Every page under `content/` shows up in the sidebar. Set `order` in a page's
+metadata to control where it appears.
+
+Continue with the #link("/guide/getting-started/")[Getting Started] guide.
@@ -0,0 +1,40 @@
+// Documentation layout with sidebar navigation.
+//
+// Pages are listed in the sidebar sorted by their `order` metadata
+// (pages without `order` come last, sorted by title).
+
+#import "/templates/tola.typ": tola-page
+#import "@tola/pages:0.0.0": pages
+
+#let _sidebar(current) = {
+  let entries = pages()
+    .filter(p => p.at("title", default: none) != none)
+    .sorted(key: p => (p.at("order", default: 9999), p.title))
+
+  html.elem("nav", attrs: (class: "docs-sidebar"))[
+    #html.elem("ul")[
+      #for p in entries {
+        let attrs = if p.permalink == current { (class: "active") } else { (:) }
+        html.elem("li", attrs: attrs)[#link(p.permalink)[#p.title]]
+      }
+    ]
+  ]
+}
+
+#let docs-page(title: none, permalink: none, ..args, body) = tola-page(
+  title: title,
+  permalink: permalink,
+  head: [
+    #html.elem("title")[#title]
+    #html.elem("link", attrs: (rel: "stylesheet", href: "/assets/styles/docs.css"))
+  ],
+  ..args,
+)[
+  #html.elem("div", attrs: (class: "docs-layout"))[
+    #_sidebar(permalink)
+    #html.elem("main", attrs: (class: "docs-content"))[
+      #if title != none [= #title]
+      #body
+    ]
+  ]
+]
@@ -182,7 +182,7 @@
     aliases: aliases,
     global-header: global-header,
     ..extra.named(),
-  )) <tola-meta>]
+  )) <__META_LABEL__>]
 
   show: tola-base
 
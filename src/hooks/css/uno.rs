@@ -32,5 +32,6 @@ pub fn build_hook(css: &CssProcessorConfig, output: &Path, minify: bool) -> Resu
         watch: WatchMode::Bool(true),
         build_args,
         quiet: css.quiet,
+        env: Default::default(),
     })
 }
@@ -21,17 +21,30 @@ pub fn build_tola_vars(config: &SiteConfig, mode: BuildMode) -> FxHashMap<String
         "TOLA_OUTPUT_DIR".into(),
         config.paths().output_dir().display().to_string(),
     );
+    vars.insert(
+        "TOLA_CONTENT_DIR".into(),
+        config.build.content.display().to_string(),
+    );
     vars.insert("TOLA_ROOT".into(), config.get_root().display().to_string());
 
     // Mode variables
-    let mode_str = if mode == BuildMode::PRODUCTION {
-        "build"
-    } else {
-        "serve"
-    };
-    vars.insert("TOLA_MODE".into(), mode_str.into());
+    vars.insert("TOLA_MODE".into(), mode.as_str().into());
     vars.insert("TOLA_MINIFY".into(), config.build.minify.to_string());
+    vars.insert("TOLA_VERSION".into(), env!("CARGO_PKG_VERSION").into());
+
+    vars
+}
 
+/// Environment for a hook process: `$TOLA_*` variables plus the hook's `env`
+///
+/// User-specified values win on conflict.
+pub fn build_hook_env(
+    hook: &HookConfig,
+    config: &SiteConfig,
+    mode: BuildMode,
+) -> FxHashMap<String, String> {
+    let mut vars = build_tola_vars(config, mode);
+    vars.extend(hook.env.iter().map(|(k, v)| (k.clone(), v.clone())));
     vars
 }
 
@@ -92,7 +105,7 @@ pub fn run_hook(
         return Ok(());
     }
 
-    let vars = build_tola_vars(config, mode);
+    let vars = build_hook_env(hook, config, mode);
     let mut resolved = resolve_args(&hook.command, &vars);
 
     // Append build_args if requested (typically during `tola build`)
@@ -113,11 +126,16 @@ pub fn run_hook(
         .run()?;
 
     // Print output directly without prefix (unless quiet)
+    // JSON mode keeps stdout parseable, so hook output goes through `log!`
     if !hook.quiet {
         let stdout = String::from_utf8_lossy(&output.stdout);
         let stdout = stdout.trim();
         if !stdout.is_empty() {
-            println!("{stdout}");
+            if crate::logger::json::is_json() {
+                crate::log!(phase.as_str(); "{}", stdout);
+            } else {
+                println!("{stdout}");
+            }
         }
     }
 
@@ -315,6 +333,32 @@ mod tests {
         assert_eq!(resolved[0], "cp /root/src /output/dest");
     }
 
+    #[test]
+    fn test_build_hook_env_user_wins() {
+        let mut config = SiteConfig::default();
+        config.set_root(std::path::Path::new("/site"));
+        let hook = HookConfig {
+            command: vec!["echo".into()],
+            env: [
+                ("TOLA_MODE".to_string(), "custom".to_string()),
+                ("API_KEY".to_string(), "secret".to_string()),
+            ]
+            .into_iter()
+            .collect(),
+            ..HookConfig::default()
+        };
+
+        let vars = build_hook_env(&hook, &config, BuildMode::PRODUCTION);
+        assert_eq!(vars["TOLA_MODE"], "custom");
+        assert_eq!(vars["API_KEY"], "secret");
+        assert_eq!(vars["TOLA_ROOT"], "/site");
+        assert_eq!(vars["TOLA_VERSION"], env!("CARGO_PKG_VERSION"));
+        assert!(vars.contains_key("TOLA_CONTENT_DIR"));
+
+        let vars = build_tola_vars(&config, BuildMode::DEVELOPMENT);
+        assert_eq!(vars["TOLA_MODE"], "development");
+    }
+
     #[test]
     fn test_has_watched_hooks_split_by_phase() {
         let mut config = SiteConfig::default();
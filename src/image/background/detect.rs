@@ -46,37 +46,77 @@ impl LabCluster {
     }
 }
 
-/// Detect background color by sampling corner pixels and choosing the dominant LAB cluster.
-pub(super) fn detect_background_color(img: &RgbaImage) -> Lab {
+/// Pixels sampled to detect the background color.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(dead_code)] // Only `Corners` is used by the pipeline so far
+pub enum SamplingRegion {
+    /// A 5×5 window in each of the four corners.
+    #[default]
+    Corners,
+    /// Every pixel on the outer border.
+    Edges,
+    /// Only the top-left N×N pixels.
+    TopLeft(usize),
+}
+
+/// Detect background color by sampling `region` and choosing the dominant LAB cluster.
+pub(super) fn detect_background_color(img: &RgbaImage, region: SamplingRegion) -> Lab {
     let (width, height) = img.dimensions();
     if width == 0 || height == 0 {
         return white_lab();
     }
 
-    let corners = [
-        (0, 0),
-        (width.saturating_sub(SAMPLE_WINDOW), 0),
-        (0, height.saturating_sub(SAMPLE_WINDOW)),
-        (
-            width.saturating_sub(SAMPLE_WINDOW),
-            height.saturating_sub(SAMPLE_WINDOW),
-        ),
-    ];
-
     let mut clusters: Vec<LabCluster> = Vec::with_capacity(8);
-    for (cx, cy) in corners {
-        for dy in 0..SAMPLE_WINDOW {
-            for dx in 0..SAMPLE_WINDOW {
-                let x = (cx + dx).min(width - 1);
-                let y = (cy + dy).min(height - 1);
-                let pixel = img.get_pixel(x, y);
-                if pixel[3] < MIN_SAMPLE_ALPHA {
-                    continue;
-                }
+    let mut sample = |x: u32, y: u32| {
+        let pixel = img.get_pixel(x, y);
+        if pixel[3] < MIN_SAMPLE_ALPHA {
+            return;
+        }
+
+        let lab = Lab::from_rgb(&[pixel[0], pixel[1], pixel[2]]);
+        let weight = (pixel[3] as f32 / 255.0).max(0.1);
+        add_to_cluster(&mut clusters, lab, weight);
+    };
 
-                let lab = Lab::from_rgb(&[pixel[0], pixel[1], pixel[2]]);
-                let weight = (pixel[3] as f32 / 255.0).max(0.1);
-                add_to_cluster(&mut clusters, lab, weight);
+    match region {
+        SamplingRegion::Corners => {
+            let corners = [
+                (0, 0),
+                (width.saturating_sub(SAMPLE_WINDOW), 0),
+                (0, height.saturating_sub(SAMPLE_WINDOW)),
+                (
+                    width.saturating_sub(SAMPLE_WINDOW),
+                    height.saturating_sub(SAMPLE_WINDOW),
+                ),
+            ];
+            for (cx, cy) in corners {
+                for dy in 0..SAMPLE_WINDOW {
+                    for dx in 0..SAMPLE_WINDOW {
+                        sample((cx + dx).min(width - 1), (cy + dy).min(height - 1));
+                    }
+                }
+            }
+        }
+        SamplingRegion::Edges => {
+            for x in 0..width {
+                sample(x, 0);
+                if height > 1 {
+                    sample(x, height - 1);
+                }
+            }
+            for y in 1..height.saturating_sub(1) {
+                sample(0, y);
+                if width > 1 {
+                    sample(width - 1, y);
+                }
+            }
+        }
+        SamplingRegion::TopLeft(size) => {
+            let size = u32::try_from(size).unwrap_or(u32::MAX);
+            for y in 0..size.min(height) {
+                for x in 0..size.min(width) {
+                    sample(x, y);
+                }
             }
         }
     }
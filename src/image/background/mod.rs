@@ -9,4 +9,10 @@ mod floodfill;
 mod mask;
 mod process;
 
-pub use process::remove_background;
+pub use process::remove_background_default;
+// Tunable API; the pipeline only uses the defaults so far
+#[allow(unused_imports)]
+pub use {
+    detect::SamplingRegion,
+    process::{RemoveBackgroundOptions, remove_background},
+};
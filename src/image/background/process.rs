@@ -5,7 +5,7 @@ use anyhow::Result;
 use image::{DynamicImage, ImageFormat};
 
 use crate::image::background::color::preconvert_to_lab;
-use crate::image::background::detect::detect_background_color;
+use crate::image::background::detect::{SamplingRegion, detect_background_color};
 use crate::image::background::floodfill::apply_edge_connected_mask;
 use crate::image::background::mask::build_background_mask;
 
@@ -18,10 +18,36 @@ const EDGE_THRESHOLD: f32 = 25.0;
 /// Use 1 so semi-transparent background can still be removed if it is edge-connected.
 const MIN_PROCESS_ALPHA: u8 = 1;
 
+/// Tuning for [`remove_background`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemoveBackgroundOptions {
+    /// Max color distance (ΔE) from the background for a pixel to be removed.
+    pub threshold: f32,
+    /// Max color distance (ΔE) for anti-aliased edge pixels, which become
+    /// partially transparent. Should not be below `threshold`.
+    pub edge_threshold: f32,
+    /// Pixels sampled to detect the background color.
+    pub sampling_region: SamplingRegion,
+}
+
+impl Default for RemoveBackgroundOptions {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_THRESHOLD,
+            edge_threshold: EDGE_THRESHOLD,
+            sampling_region: SamplingRegion::Corners,
+        }
+    }
+}
+
 /// Remove background from an image file and write PNG output.
-pub fn remove_background(input: &Path, output: &Path) -> Result<()> {
+pub fn remove_background(
+    input: &Path,
+    output: &Path,
+    options: &RemoveBackgroundOptions,
+) -> Result<()> {
     let img = image::open(input)?;
-    let processed = process_image(img);
+    let processed = process_image(img, options);
 
     if let Some(parent) = output.parent() {
         fs::create_dir_all(parent)?;
@@ -31,22 +57,27 @@ pub fn remove_background(input: &Path, output: &Path) -> Result<()> {
     Ok(())
 }
 
+/// [`remove_background`] with the default options.
+pub fn remove_background_default(input: &Path, output: &Path) -> Result<()> {
+    remove_background(input, output, &RemoveBackgroundOptions::default())
+}
+
 /// Process image to remove edge-connected background.
-fn process_image(img: DynamicImage) -> DynamicImage {
+fn process_image(img: DynamicImage, options: &RemoveBackgroundOptions) -> DynamicImage {
     let mut output = img.to_rgba8();
     let (width, height) = output.dimensions();
     if width == 0 || height == 0 {
         return DynamicImage::ImageRgba8(output);
     }
 
-    let bg_lab = detect_background_color(&output);
+    let bg_lab = detect_background_color(&output, options.sampling_region);
     let labs = preconvert_to_lab(&output);
     let mask = build_background_mask(
         &output,
         &labs,
         &bg_lab,
-        DEFAULT_THRESHOLD,
-        EDGE_THRESHOLD,
+        options.threshold,
+        options.edge_threshold,
         MIN_PROCESS_ALPHA,
     );
     apply_edge_connected_mask(&mut output, &mask);
@@ -58,14 +89,51 @@ fn process_image(img: DynamicImage) -> DynamicImage {
 mod tests {
     use image::{Rgba, RgbaImage};
 
-    use super::process_image;
+    use super::{RemoveBackgroundOptions, SamplingRegion, process_image};
+
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+    const RED: Rgba<u8> = Rgba([255, 0, 0, 255]);
+
+    fn process(img: RgbaImage, sampling_region: SamplingRegion) -> RgbaImage {
+        let options = RemoveBackgroundOptions {
+            sampling_region,
+            ..Default::default()
+        };
+        process_image(img.into(), &options).to_rgba8()
+    }
+
+    /// 40×40 white image with a red 6×6 square in each corner.
+    ///
+    /// The corner windows are all red, while most of the border is white.
+    fn corner_squares() -> RgbaImage {
+        let mut img = RgbaImage::from_pixel(40, 40, WHITE);
+        for (ox, oy) in [(0, 0), (34, 0), (0, 34), (34, 34)] {
+            for y in oy..oy + 6 {
+                for x in ox..ox + 6 {
+                    img.put_pixel(x, y, RED);
+                }
+            }
+        }
+        img
+    }
+
+    /// 20×20 white image with a red 8×8 square in the top-left corner.
+    fn top_left_square() -> RgbaImage {
+        let mut img = RgbaImage::from_pixel(20, 20, WHITE);
+        for y in 0..8 {
+            for x in 0..8 {
+                img.put_pixel(x, y, RED);
+            }
+        }
+        img
+    }
 
     #[test]
     fn removes_single_pixel_background() {
         let mut img = RgbaImage::new(1, 1);
         img.put_pixel(0, 0, Rgba([255, 255, 255, 255]));
 
-        let out = process_image(img.into()).to_rgba8();
+        let out = process_image(img.into(), &RemoveBackgroundOptions::default()).to_rgba8();
         assert_eq!(out.get_pixel(0, 0)[3], 0);
     }
 
@@ -74,7 +142,7 @@ mod tests {
         let mut img = RgbaImage::new(1, 1);
         img.put_pixel(0, 0, Rgba([12, 34, 56, 0]));
 
-        let out = process_image(img.into()).to_rgba8();
+        let out = process_image(img.into(), &RemoveBackgroundOptions::default()).to_rgba8();
         assert_eq!(out.get_pixel(0, 0)[3], 0);
     }
 
@@ -92,7 +160,7 @@ mod tests {
             img.put_pixel(5, y, fg);
         }
 
-        let out = process_image(img.into()).to_rgba8();
+        let out = process_image(img.into(), &RemoveBackgroundOptions::default()).to_rgba8();
 
         // Outer white background is edge-connected and should be removed.
         assert_eq!(out.get_pixel(0, 0)[3], 0);
@@ -109,7 +177,7 @@ mod tests {
         img.put_pixel(1, 0, Rgba([255, 255, 255, 255]));
         img.put_pixel(2, 0, Rgba([255, 255, 255, 255]));
 
-        let out = process_image(img.into()).to_rgba8();
+        let out = process_image(img.into(), &RemoveBackgroundOptions::default()).to_rgba8();
         assert_eq!(out.get_pixel(0, 0)[3], 0);
         assert_eq!(out.get_pixel(1, 0)[3], 0);
         assert_eq!(out.get_pixel(2, 0)[3], 0);
@@ -122,9 +190,66 @@ mod tests {
         img.put_pixel(0, 1, Rgba([255, 255, 255, 255]));
         img.put_pixel(0, 2, Rgba([255, 255, 255, 255]));
 
-        let out = process_image(img.into()).to_rgba8();
+        let out = process_image(img.into(), &RemoveBackgroundOptions::default()).to_rgba8();
         assert_eq!(out.get_pixel(0, 0)[3], 0);
         assert_eq!(out.get_pixel(0, 1)[3], 0);
         assert_eq!(out.get_pixel(0, 2)[3], 0);
     }
+
+    #[test]
+    fn samples_corners() {
+        let out = process(corner_squares(), SamplingRegion::Corners);
+        assert_eq!(out.get_pixel(0, 0)[3], 0);
+        assert_eq!(out.get_pixel(39, 39)[3], 0);
+        assert_eq!(out.get_pixel(20, 20)[3], 255);
+
+        let out = process(top_left_square(), SamplingRegion::Corners);
+        assert_eq!(out.get_pixel(0, 0)[3], 255);
+        assert_eq!(out.get_pixel(19, 19)[3], 0);
+    }
+
+    #[test]
+    fn samples_edges() {
+        let out = process(corner_squares(), SamplingRegion::Edges);
+        assert_eq!(out.get_pixel(0, 0)[3], 255);
+        assert_eq!(out.get_pixel(39, 39)[3], 255);
+        assert_eq!(out.get_pixel(20, 0)[3], 0);
+        assert_eq!(out.get_pixel(20, 20)[3], 0);
+    }
+
+    #[test]
+    fn samples_top_left() {
+        let out = process(top_left_square(), SamplingRegion::TopLeft(4));
+        assert_eq!(out.get_pixel(0, 0)[3], 0);
+        assert_eq!(out.get_pixel(7, 7)[3], 0);
+        assert_eq!(out.get_pixel(19, 19)[3], 255);
+
+        // Larger than the image samples the whole image, here mostly white
+        let out = process(top_left_square(), SamplingRegion::TopLeft(100));
+        assert_eq!(out.get_pixel(0, 0)[3], 255);
+        assert_eq!(out.get_pixel(19, 19)[3], 0);
+    }
+
+    #[test]
+    fn respects_custom_threshold() {
+        // Light gray touching the border, ~ΔE 9 from white
+        let mut img = RgbaImage::from_pixel(10, 10, WHITE);
+        for y in 3..7 {
+            for x in 0..4 {
+                img.put_pixel(x, y, Rgba([230, 230, 230, 255]));
+            }
+        }
+
+        let out = process_image(img.clone().into(), &RemoveBackgroundOptions::default()).to_rgba8();
+        assert_eq!(out.get_pixel(0, 5)[3], 0);
+
+        let strict = RemoveBackgroundOptions {
+            threshold: 2.0,
+            edge_threshold: 4.0,
+            ..Default::default()
+        };
+        let out = process_image(img.into(), &strict).to_rgba8();
+        assert_eq!(out.get_pixel(0, 5)[3], 255);
+        assert_eq!(out.get_pixel(9, 9)[3], 0);
+    }
 }
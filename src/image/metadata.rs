@@ -0,0 +1,622 @@
+//! Image metadata stripping (`build.assets.images.strip_exif`).
+//!
+//! Removes EXIF/XMP/text metadata without re-encoding pixel data:
+//!
+//! - JPEG: drops APP1 (EXIF, XMP), APP13 (IPTC) and COM segments
+//! - PNG: drops `eXIf`, `tEXt`, `zTXt`, `iTXt` and `tIME` chunks
+//! - WebP: drops `EXIF` and `XMP ` chunks and clears their `VP8X` flags
+//! - TIFF: drops EXIF/GPS sub-IFDs, XMP, IPTC and descriptive text tags
+//!   (camera, software, author, dates) from every IFD, zeroing their data
+//!   in place so no other offset moves
+//!
+//! Color profiles (JPEG APP2, PNG `iCCP`, WebP `ICCP`, TIFF ICC tag) are
+//! kept. A JPEG EXIF orientation other than upright is rewritten as a
+//! minimal EXIF segment holding only that tag, so photos are not displayed
+//! rotated. AVIF and BigTIFF are copied unchanged with a warning.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+
+/// Image container detected from magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageContainer {
+    Jpeg,
+    Png,
+    Webp,
+    Tiff,
+    Avif,
+}
+
+impl ImageContainer {
+    /// Detect the container from the file's leading bytes.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpeg),
+            [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Some(Self::Png),
+            [
+                b'R',
+                b'I',
+                b'F',
+                b'F',
+                _,
+                _,
+                _,
+                _,
+                b'W',
+                b'E',
+                b'B',
+                b'P',
+                ..,
+            ] => Some(Self::Webp),
+            [b'I', b'I', 0x2A, 0x00, ..] | [b'M', b'M', 0x00, 0x2A, ..] => Some(Self::Tiff),
+            [
+                _,
+                _,
+                _,
+                _,
+                b'f',
+                b't',
+                b'y',
+                b'p',
+                b'a',
+                b'v',
+                b'i',
+                b'f' | b's',
+                ..,
+            ] => Some(Self::Avif),
+            _ => None,
+        }
+    }
+}
+
+/// Extensions of images that may carry EXIF metadata.
+const METADATA_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp", "tif", "tiff", "avif"];
+
+/// Whether `path` has an image extension that may carry metadata.
+pub fn may_have_metadata(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| METADATA_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+/// Read an image and return its bytes without metadata.
+///
+/// Returns `None` when the file should be copied unchanged: not an image,
+/// an unsupported container, or a file that fails to parse. Images whose
+/// metadata could not be stripped are reported as warnings.
+pub fn strip_file(path: &Path) -> Result<Option<Vec<u8>>> {
+    if !may_have_metadata(path) {
+        return Ok(None);
+    }
+    let bytes = fs::read(path)?;
+    let Some(container) = ImageContainer::detect(&bytes) else {
+        return Ok(None);
+    };
+    let stripped = strip(container, &bytes);
+    if stripped.is_none() {
+        crate::log!(
+            "warning";
+            "{:?} metadata not stripped, copied unchanged: {}",
+            container,
+            path.display()
+        );
+    }
+    Ok(stripped)
+}
+
+/// Strip metadata from an in-memory image.
+///
+/// Returns `None` for AVIF, BigTIFF and malformed input.
+pub fn strip(container: ImageContainer, bytes: &[u8]) -> Option<Vec<u8>> {
+    match container {
+        ImageContainer::Jpeg => strip_jpeg(bytes),
+        ImageContainer::Png => strip_png(bytes),
+        ImageContainer::Webp => strip_webp(bytes),
+        ImageContainer::Tiff => strip_tiff(bytes),
+        ImageContainer::Avif => None,
+    }
+}
+
+fn strip_jpeg(bytes: &[u8]) -> Option<Vec<u8>> {
+    const SOS: u8 = 0xDA;
+    const APP1: u8 = 0xE1;
+    const APP13: u8 = 0xED;
+    const COM: u8 = 0xFE;
+
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(bytes.get(..2)?); // SOI
+    let mut pos = 2;
+
+    loop {
+        if *bytes.get(pos)? != 0xFF {
+            return None;
+        }
+        // Skip fill bytes
+        while bytes.get(pos + 1) == Some(&0xFF) {
+            pos += 1;
+        }
+        let marker = *bytes.get(pos + 1)?;
+
+        // Standalone markers carry no length
+        if matches!(marker, 0x01 | 0xD0..=0xD7) {
+            out.extend_from_slice(&bytes[pos..pos + 2]);
+            pos += 2;
+            continue;
+        }
+
+        let len = u16::from_be_bytes([*bytes.get(pos + 2)?, *bytes.get(pos + 3)?]) as usize;
+        let end = pos + 2 + len;
+        if len < 2 || end > bytes.len() {
+            return None;
+        }
+
+        if marker == SOS {
+            // Entropy-coded data follows; metadata segments precede the first scan.
+            out.extend_from_slice(&bytes[pos..]);
+            return Some(out);
+        }
+        if marker == APP1
+            && let Some(orientation) = exif_orientation(&bytes[pos + 4..end])
+        {
+            out.extend_from_slice(&orientation_segment(orientation));
+        } else if !matches!(marker, APP1 | APP13 | COM) {
+            out.extend_from_slice(&bytes[pos..end]);
+        }
+        pos = end;
+    }
+}
+
+/// Orientation tag in IFD0.
+const ORIENTATION_TAG: u16 = 0x0112;
+
+/// Orientation (2..=8) from an APP1 EXIF payload, `None` when upright or absent.
+fn exif_orientation(payload: &[u8]) -> Option<u16> {
+    let tiff = payload.strip_prefix(b"Exif\0\0")?;
+    let big_endian = match tiff.get(..2)? {
+        b"MM" => true,
+        b"II" => false,
+        _ => return None,
+    };
+    let u16_at = |at: usize| -> Option<u16> {
+        let raw = [*tiff.get(at)?, *tiff.get(at + 1)?];
+        Some(if big_endian {
+            u16::from_be_bytes(raw)
+        } else {
+            u16::from_le_bytes(raw)
+        })
+    };
+    let u32_at = |at: usize| -> Option<u32> {
+        let raw: [u8; 4] = tiff.get(at..at + 4)?.try_into().ok()?;
+        Some(if big_endian {
+            u32::from_be_bytes(raw)
+        } else {
+            u32::from_le_bytes(raw)
+        })
+    };
+
+    let ifd = u32_at(4)? as usize;
+    let count = u16_at(ifd)? as usize;
+    (0..count)
+        .map(|i| ifd + 2 + i * 12)
+        .find(|&entry| u16_at(entry) == Some(ORIENTATION_TAG))
+        .and_then(|entry| u16_at(entry + 8))
+        .filter(|orientation| (2..=8).contains(orientation))
+}
+
+/// APP1 segment with a big-endian EXIF holding only the orientation tag.
+fn orientation_segment(orientation: u16) -> Vec<u8> {
+    let mut exif = b"Exif\0\0MM\0\x2a".to_vec();
+    exif.extend_from_slice(&8u32.to_be_bytes()); // IFD0 offset
+    exif.extend_from_slice(&1u16.to_be_bytes()); // entry count
+    exif.extend_from_slice(&ORIENTATION_TAG.to_be_bytes());
+    exif.extend_from_slice(&3u16.to_be_bytes()); // SHORT
+    exif.extend_from_slice(&1u32.to_be_bytes()); // value count
+    exif.extend_from_slice(&orientation.to_be_bytes());
+    exif.extend_from_slice(&[0, 0]); // value padding
+    exif.extend_from_slice(&0u32.to_be_bytes()); // no next IFD
+
+    let mut segment = vec![0xFF, 0xE1];
+    segment.extend_from_slice(&(exif.len() as u16 + 2).to_be_bytes());
+    segment.extend(exif);
+    segment
+}
+
+fn strip_png(bytes: &[u8]) -> Option<Vec<u8>> {
+    const DROPPED: &[&[u8; 4]] = &[b"eXIf", b"tEXt", b"zTXt", b"iTXt", b"tIME"];
+
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(bytes.get(..8)?); // signature
+    let mut pos = 8;
+
+    while pos < bytes.len() {
+        let len = u32::from_be_bytes(bytes.get(pos..pos + 4)?.try_into().ok()?) as usize;
+        let kind = bytes.get(pos + 4..pos + 8)?;
+        // length + type + data + crc
+        let end = pos.checked_add(12)?.checked_add(len)?;
+        if end > bytes.len() {
+            return None;
+        }
+        if !DROPPED.iter().any(|d| d.as_slice() == kind) {
+            out.extend_from_slice(&bytes[pos..end]);
+        }
+        pos = end;
+        if kind == b"IEND" {
+            break;
+        }
+    }
+    Some(out)
+}
+
+fn strip_webp(bytes: &[u8]) -> Option<Vec<u8>> {
+    const VP8X_EXIF: u8 = 0x08;
+    const VP8X_XMP: u8 = 0x04;
+
+    let mut out = Vec::with_capacity(bytes.len());
+    out.extend_from_slice(bytes.get(..12)?); // RIFF header, size patched below
+    let mut pos = 12;
+
+    while pos < bytes.len() {
+        let kind = bytes.get(pos..pos + 4)?;
+        let len = u32::from_le_bytes(bytes.get(pos + 4..pos + 8)?.try_into().ok()?) as usize;
+        // Chunks are padded to an even size
+        let end = pos.checked_add(8)?.checked_add(len + (len & 1))?;
+        if end > bytes.len() {
+            return None;
+        }
+        match kind {
+            b"EXIF" | b"XMP " => {}
+            b"VP8X" => {
+                let start = out.len();
+                out.extend_from_slice(&bytes[pos..end]);
+                if let Some(flags) = out.get_mut(start + 8) {
+                    *flags &= !(VP8X_EXIF | VP8X_XMP);
+                }
+            }
+            _ => out.extend_from_slice(&bytes[pos..end]),
+        }
+        pos = end;
+    }
+
+    let riff_size = u32::try_from(out.len() - 8).ok()?;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Some(out)
+}
+
+/// TIFF tags removed from every IFD: EXIF/GPS/interop sub-IFD pointers,
+/// XMP, IPTC, Photoshop resources and descriptive text.
+#[rustfmt::skip]
+const TIFF_DROPPED_TAGS: &[u16] = &[
+    0x010D, // DocumentName
+    0x010E, // ImageDescription
+    0x010F, // Make
+    0x0110, // Model
+    0x0131, // Software
+    0x0132, // DateTime
+    0x013B, // Artist
+    0x013C, // HostComputer
+    0x02BC, // XMP
+    0x8298, // Copyright
+    0x83BB, // IPTC
+    0x8649, // Photoshop
+    0x8769, // ExifIFD
+    0x8825, // GPSIFD
+    0xA005, // InteropIFD
+];
+
+/// Sub-IFD pointer tags whose target IFD is zeroed along with its values.
+const TIFF_SUB_IFD_TAGS: &[u16] = &[0x8769, 0x8825, 0xA005];
+
+fn strip_tiff(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut tiff = Tiff {
+        big_endian: match bytes.get(..4)? {
+            b"MM\0\x2a" => true,
+            b"II\x2a\0" => false,
+            // BigTIFF (version 43) and anything else
+            _ => return None,
+        },
+        out: bytes.to_vec(),
+    };
+
+    let mut ifd = tiff.u32_at(4)? as usize;
+    let mut seen = Vec::new();
+    while ifd != 0 {
+        // Guard against IFD chains that loop
+        if seen.contains(&ifd) {
+            return None;
+        }
+        seen.push(ifd);
+        ifd = tiff.strip_ifd(ifd)?;
+    }
+    Some(tiff.out)
+}
+
+/// TIFF file being stripped in place.
+struct Tiff {
+    big_endian: bool,
+    out: Vec<u8>,
+}
+
+impl Tiff {
+    fn u16_at(&self, at: usize) -> Option<u16> {
+        let raw = [*self.out.get(at)?, *self.out.get(at + 1)?];
+        Some(if self.big_endian {
+            u16::from_be_bytes(raw)
+        } else {
+            u16::from_le_bytes(raw)
+        })
+    }
+
+    fn u32_at(&self, at: usize) -> Option<u32> {
+        let raw: [u8; 4] = self.out.get(at..at + 4)?.try_into().ok()?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(raw)
+        } else {
+            u32::from_le_bytes(raw)
+        })
+    }
+
+    fn zero(&mut self, start: usize, len: usize) -> Option<()> {
+        self.out.get_mut(start..start.checked_add(len)?)?.fill(0);
+        Some(())
+    }
+
+    /// Byte range of an entry's value when stored outside the entry.
+    ///
+    /// `Some(None)` when the value fits in the entry or its type is unknown.
+    fn external_value(&self, entry: usize) -> Option<Option<(usize, usize)>> {
+        let size = match self.u16_at(entry + 2)? {
+            1 | 2 | 6 | 7 => 1,
+            3 | 8 => 2,
+            4 | 9 | 11 | 13 => 4,
+            5 | 10 | 12 => 8,
+            _ => return Some(None),
+        };
+        let len = (self.u32_at(entry + 4)? as usize).checked_mul(size)?;
+        if len <= 4 {
+            return Some(None);
+        }
+        Some(Some((self.u32_at(entry + 8)? as usize, len)))
+    }
+
+    /// Drop metadata entries from the IFD at `ifd`, returning the next IFD offset.
+    fn strip_ifd(&mut self, ifd: usize) -> Option<usize> {
+        let count = self.u16_at(ifd)? as usize;
+        let entries_end = ifd + 2 + count * 12;
+        let next = self.u32_at(entries_end)? as usize;
+
+        let mut kept = Vec::with_capacity(count * 12);
+        let mut kept_count: u16 = 0;
+        for i in 0..count {
+            let entry = ifd + 2 + i * 12;
+            let tag = self.u16_at(entry)?;
+            if !TIFF_DROPPED_TAGS.contains(&tag) {
+                kept.extend_from_slice(&self.out[entry..entry + 12]);
+                kept_count += 1;
+                continue;
+            }
+            if TIFF_SUB_IFD_TAGS.contains(&tag) {
+                let sub_ifd = self.u32_at(entry + 8)? as usize;
+                self.zero_ifd(sub_ifd)?;
+            }
+            if let Some((offset, len)) = self.external_value(entry)? {
+                self.zero(offset, len)?;
+            }
+        }
+
+        // Rewrite the IFD shorter and zero the freed tail
+        let count_bytes = if self.big_endian {
+            kept_count.to_be_bytes()
+        } else {
+            kept_count.to_le_bytes()
+        };
+        let next_bytes: [u8; 4] = self.out[entries_end..entries_end + 4].try_into().ok()?;
+        let mut ifd_bytes = count_bytes.to_vec();
+        ifd_bytes.extend(kept);
+        ifd_bytes.extend_from_slice(&next_bytes);
+        self.zero(ifd, entries_end + 4 - ifd)?;
+        self.out[ifd..ifd + ifd_bytes.len()].copy_from_slice(&ifd_bytes);
+        Some(next)
+    }
+
+    /// Zero a sub-IFD and the values it points to.
+    fn zero_ifd(&mut self, ifd: usize) -> Option<()> {
+        let count = self.u16_at(ifd)? as usize;
+        for i in 0..count {
+            if let Some((offset, len)) = self.external_value(ifd + 2 + i * 12)? {
+                self.zero(offset, len)?;
+            }
+        }
+        self.zero(ifd, 2 + count * 12 + 4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, Rgb, RgbImage};
+    use std::io::Cursor;
+
+    fn encode(format: ImageFormat) -> Vec<u8> {
+        let mut buf = Cursor::new(Vec::new());
+        RgbImage::from_pixel(8, 8, Rgb([200, 10, 10]))
+            .write_to(&mut buf, format)
+            .unwrap();
+        buf.into_inner()
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[test]
+    fn test_detect_container() {
+        assert_eq!(
+            ImageContainer::detect(&encode(ImageFormat::Png)),
+            Some(ImageContainer::Png)
+        );
+        assert_eq!(
+            ImageContainer::detect(&encode(ImageFormat::Jpeg)),
+            Some(ImageContainer::Jpeg)
+        );
+        assert_eq!(
+            ImageContainer::detect(b"II\x2a\x00rest"),
+            Some(ImageContainer::Tiff)
+        );
+        assert_eq!(
+            ImageContainer::detect(b"\0\0\0\x1cftypavif"),
+            Some(ImageContainer::Avif)
+        );
+        assert_eq!(ImageContainer::detect(b"fake png"), None);
+    }
+
+    #[test]
+    fn test_strip_jpeg() {
+        let jpeg = encode(ImageFormat::Jpeg);
+        let exif = b"Exif\0\0GPS-SECRET";
+        let mut input = jpeg[..2].to_vec();
+        input.extend_from_slice(&[0xFF, 0xE1]);
+        input.extend_from_slice(&((exif.len() + 2) as u16).to_be_bytes());
+        input.extend_from_slice(exif);
+        input.extend_from_slice(&jpeg[2..]);
+
+        let stripped = strip(ImageContainer::Jpeg, &input).unwrap();
+        assert!(!contains(&stripped, b"GPS-SECRET"));
+        assert_eq!(stripped, jpeg);
+        image::load_from_memory(&stripped).unwrap();
+    }
+
+    #[test]
+    fn test_strip_jpeg_keeps_orientation() {
+        let jpeg = encode(ImageFormat::Jpeg);
+        // Little-endian EXIF: orientation 6 (rotate 90 CW), then GPS payload
+        let mut exif = b"Exif\0\0II\x2a\0".to_vec();
+        exif.extend_from_slice(&8u32.to_le_bytes());
+        exif.extend_from_slice(&1u16.to_le_bytes());
+        exif.extend_from_slice(&[0x12, 0x01, 3, 0, 1, 0, 0, 0, 6, 0, 0, 0]);
+        exif.extend_from_slice(&0u32.to_le_bytes());
+        exif.extend_from_slice(b"GPS-SECRET");
+        let mut input = jpeg[..2].to_vec();
+        input.extend_from_slice(&[0xFF, 0xE1]);
+        input.extend_from_slice(&((exif.len() + 2) as u16).to_be_bytes());
+        input.extend_from_slice(&exif);
+        input.extend_from_slice(&jpeg[2..]);
+
+        let stripped = strip(ImageContainer::Jpeg, &input).unwrap();
+        assert!(!contains(&stripped, b"GPS-SECRET"));
+        let segment = orientation_segment(6);
+        assert_eq!(&stripped[2..2 + segment.len()], segment.as_slice());
+        assert_eq!(exif_orientation(&segment[4..]), Some(6));
+
+        let decoded = image::load_from_memory(&stripped).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (8, 8));
+
+        // Upright photos keep no EXIF at all
+        assert_eq!(exif_orientation(&orientation_segment(1)[4..]), None);
+    }
+
+    #[test]
+    fn test_strip_png() {
+        let png = encode(ImageFormat::Png);
+        let text = b"Author\0Jane Doe";
+        let mut chunk = (text.len() as u32).to_be_bytes().to_vec();
+        chunk.extend_from_slice(b"tEXt");
+        chunk.extend_from_slice(text);
+        chunk.extend_from_slice(&[0; 4]); // CRC is not checked when stripping
+        // Insert after IHDR (signature + 25-byte chunk)
+        let mut input = png[..33].to_vec();
+        input.extend_from_slice(&chunk);
+        input.extend_from_slice(&png[33..]);
+
+        let stripped = strip(ImageContainer::Png, &input).unwrap();
+        assert!(!contains(&stripped, b"Jane Doe"));
+        assert_eq!(stripped, png);
+        image::load_from_memory(&stripped).unwrap();
+    }
+
+    #[test]
+    fn test_strip_webp() {
+        fn chunk(kind: &[u8; 4], data: &[u8]) -> Vec<u8> {
+            let mut out = kind.to_vec();
+            out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            out.extend_from_slice(data);
+            if data.len() % 2 == 1 {
+                out.push(0);
+            }
+            out
+        }
+
+        const VP8X_FLAGS: u8 = 0x08 | 0x04 | 0x10; // EXIF | XMP | alpha
+
+        let mut body = b"WEBP".to_vec();
+        body.extend(chunk(b"VP8X", &[VP8X_FLAGS, 0, 0, 0, 7, 0, 0, 7, 0, 0]));
+        body.extend(chunk(b"VP8L", b"pixels"));
+        body.extend(chunk(b"EXIF", b"GPS-SECRET"));
+        body.extend(chunk(b"XMP ", b"<x:xmpmeta/>"));
+        let mut input = b"RIFF".to_vec();
+        input.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        input.extend(body);
+
+        let stripped = strip(ImageContainer::Webp, &input).unwrap();
+        assert!(!contains(&stripped, b"GPS-SECRET"));
+        assert!(!contains(&stripped, b"xmpmeta"));
+        assert!(contains(&stripped, b"pixels"));
+        // Only the alpha flag remains
+        assert_eq!(stripped[20], 0x10);
+        let riff_size = u32::from_le_bytes(stripped[4..8].try_into().unwrap()) as usize;
+        assert_eq!(riff_size, stripped.len() - 8);
+    }
+
+    #[test]
+    fn test_strip_malformed_returns_none() {
+        let mut jpeg = encode(ImageFormat::Jpeg);
+        jpeg.truncate(6);
+        assert_eq!(strip(ImageContainer::Jpeg, &jpeg), None);
+    }
+
+    #[test]
+    fn test_strip_tiff() {
+        fn entry(tag: u16, kind: u16, count: u32, value: u32) -> Vec<u8> {
+            let mut out = tag.to_le_bytes().to_vec();
+            out.extend_from_slice(&kind.to_le_bytes());
+            out.extend_from_slice(&count.to_le_bytes());
+            out.extend_from_slice(&value.to_le_bytes());
+            out
+        }
+
+        // 1x1 grayscale image: header, pixel, metadata values, GPS IFD, IFD0
+        let mut input = b"II\x2a\0".to_vec();
+        input.extend_from_slice(&48u32.to_le_bytes()); // IFD0
+        input.push(0x7F); // pixel at 8
+        input.push(0);
+        input.extend_from_slice(b"GPS-SECRET"); // at 10
+        input.extend_from_slice(b"Jane Doe\0\0"); // at 20
+        input.extend_from_slice(&1u16.to_le_bytes()); // GPS IFD at 30
+        input.extend(entry(0x0002, 2, 10, 10));
+        input.extend_from_slice(&0u32.to_le_bytes());
+        let ifd0 = [
+            entry(0x0100, 3, 1, 1),  // ImageWidth
+            entry(0x0101, 3, 1, 1),  // ImageLength
+            entry(0x0111, 4, 1, 8),  // StripOffsets
+            entry(0x013B, 2, 9, 20), // Artist
+            entry(0x8825, 4, 1, 30), // GPSIFD
+        ];
+        input.extend_from_slice(&(ifd0.len() as u16).to_le_bytes());
+        ifd0.iter().for_each(|e| input.extend_from_slice(e));
+        input.extend_from_slice(&0u32.to_le_bytes());
+
+        let stripped = strip(ImageContainer::Tiff, &input).unwrap();
+        assert_eq!(stripped.len(), input.len());
+        assert!(!contains(&stripped, b"GPS-SECRET"));
+        assert!(!contains(&stripped, b"Jane Doe"));
+        assert_eq!(stripped[8], 0x7F);
+        // IFD0 keeps the image tags, in order, followed by no next IFD
+        assert_eq!(u16::from_le_bytes([stripped[48], stripped[49]]), 3);
+        assert_eq!(&stripped[50..86], &ifd0[..3].concat()[..]);
+        assert_eq!(&stripped[86..], &[0; 28]);
+
+        // BigTIFF is left alone
+        assert_eq!(strip(ImageContainer::Tiff, b"II\x2b\0\x08\0\0\0"), None);
+    }
+}
@@ -5,7 +5,11 @@
 //! - [`svg`]: SVG optimization, conversion, and extraction
 //! - [`background`]: Background removal (nobg)
 //! - [`recolor`]: Theme-based image recolor filter generation
+//! - [`responsive`]: Resized variants for `srcset`
+//! - [`metadata`]: EXIF/XMP stripping for copied images
 
 pub mod background;
+pub mod metadata;
 pub mod recolor;
+pub mod responsive;
 pub mod svg;
@@ -0,0 +1,279 @@
+//! Responsive image variants.
+//!
+//! Plans and generates resized copies of a source image for `srcset`.
+//! Variants sit next to the original output and use content-hash naming:
+//!
+//! ```text
+//! photo.png -> photo-400w.1a2b3c4d5e6f.png
+//!              photo-400w.1a2b3c4d5e6f.webp
+//!              photo-1600w.1a2b3c4d5e6f.webp   (original width, converted)
+//! ```
+//!
+//! Planning only reads the image header and hashes the file, so
+//! `MediaTransform` can compute the same names without decoding.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use image::DynamicImage;
+use image::imageops::FilterType;
+
+use crate::config::section::build::{ImageFormat, ImagesConfig};
+use crate::image::svg::filename_hash;
+
+/// A single resized image file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub width: u32,
+    pub height: u32,
+    /// Output file name (same directory as the original).
+    pub file_name: String,
+}
+
+/// Variants for one `<source>` (or the fallback `<img>`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariantSet {
+    pub format: ImageFormat,
+    pub variants: Vec<Variant>,
+}
+
+/// All variants planned for a source image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponsivePlan {
+    /// Original image width.
+    pub width: u32,
+    /// Resized copies in the original format (fallback `<img srcset>`).
+    pub fallback: VariantSet,
+    /// Additional formats, one `<source>` each.
+    pub sources: Vec<VariantSet>,
+}
+
+impl ResponsivePlan {
+    /// Iterate over every file the plan produces.
+    pub fn files(&self) -> impl Iterator<Item = (ImageFormat, &Variant)> {
+        std::iter::once(&self.fallback)
+            .chain(&self.sources)
+            .flat_map(|set| set.variants.iter().map(move |v| (set.format, v)))
+    }
+}
+
+/// Plan responsive variants for an image.
+///
+/// Returns `None` for unsupported formats or unreadable files.
+pub fn plan(source: &Path, config: &ImagesConfig) -> Option<ResponsivePlan> {
+    let ext = source.extension()?.to_str()?;
+    let original = ImageFormat::from_extension(ext)?;
+    let stem = source.file_stem()?.to_str()?;
+    let (width, height) = image::image_dimensions(source).ok()?;
+    if width == 0 || height == 0 {
+        return None;
+    }
+    let hash = filename_hash(&fs::read(source).ok()?);
+
+    let mut widths: Vec<u32> = config
+        .srcset_widths
+        .iter()
+        .copied()
+        .filter(|&w| w > 0 && w < width)
+        .collect();
+    widths.sort_unstable();
+    widths.dedup();
+
+    let variant = |w: u32, format: ImageFormat| Variant {
+        width: w,
+        height: scaled_height(width, height, w),
+        file_name: format!("{stem}-{w}w.{hash}.{}", format.extension()),
+    };
+
+    let fallback = VariantSet {
+        format: original,
+        variants: widths.iter().map(|&w| variant(w, original)).collect(),
+    };
+
+    // Other formats also get a full-size conversion
+    let mut sources = Vec::new();
+    for &format in &config.formats {
+        if format == original || sources.iter().any(|s: &VariantSet| s.format == format) {
+            continue;
+        }
+        let variants = widths
+            .iter()
+            .copied()
+            .chain(std::iter::once(width))
+            .map(|w| variant(w, format))
+            .collect();
+        sources.push(VariantSet { format, variants });
+    }
+
+    Some(ResponsivePlan {
+        width,
+        fallback,
+        sources,
+    })
+}
+
+/// Generate planned variants into `output_dir`.
+///
+/// Existing files are kept, since content-hash names change with the source.
+/// Returns the number of files written.
+pub fn generate(source: &Path, output_dir: &Path, plan: &ResponsivePlan) -> Result<usize> {
+    let missing: Vec<_> = plan
+        .files()
+        .filter(|(_, v)| !output_dir.join(&v.file_name).exists())
+        .collect();
+    if missing.is_empty() {
+        return Ok(0);
+    }
+
+    let img = image::open(source)?;
+    fs::create_dir_all(output_dir)?;
+
+    for (format, variant) in &missing {
+        let resized = if variant.width == img.width() {
+            img.clone()
+        } else {
+            img.resize_exact(variant.width, variant.height, FilterType::Lanczos3)
+        };
+        to_encodable(resized, *format)
+            .save_with_format(output_dir.join(&variant.file_name), codec(*format))?;
+    }
+
+    Ok(missing.len())
+}
+
+/// Height preserving aspect ratio (at least 1px).
+fn scaled_height(width: u32, height: u32, target: u32) -> u32 {
+    let scaled = (u64::from(height) * u64::from(target) + u64::from(width) / 2) / u64::from(width);
+    scaled.max(1) as u32
+}
+
+/// Convert pixel layout to what the encoder accepts (JPEG has no alpha).
+fn to_encodable(img: DynamicImage, format: ImageFormat) -> DynamicImage {
+    match format {
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(img.to_rgb8()),
+        ImageFormat::Avif | ImageFormat::Webp => DynamicImage::ImageRgba8(img.to_rgba8()),
+        ImageFormat::Png => img,
+    }
+}
+
+fn codec(format: ImageFormat) -> image::ImageFormat {
+    match format {
+        ImageFormat::Avif => image::ImageFormat::Avif,
+        ImageFormat::Webp => image::ImageFormat::WebP,
+        ImageFormat::Png => image::ImageFormat::Png,
+        ImageFormat::Jpeg => image::ImageFormat::Jpeg,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+    use tempfile::TempDir;
+
+    fn write_png(path: &Path, width: u32, height: u32) {
+        RgbaImage::from_pixel(width, height, Rgba([10, 20, 30, 255]))
+            .save(path)
+            .unwrap();
+    }
+
+    fn config(widths: &[u32], formats: &[ImageFormat]) -> ImagesConfig {
+        ImagesConfig {
+            enable: true,
+            srcset_widths: widths.to_vec(),
+            formats: formats.to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_plan_skips_upscaling() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("photo.png");
+        write_png(&source, 100, 50);
+
+        let plan = plan(&source, &config(&[40, 80, 200], &[ImageFormat::Webp])).unwrap();
+        assert_eq!(plan.width, 100);
+
+        let fallback: Vec<_> = plan.fallback.variants.iter().map(|v| v.width).collect();
+        assert_eq!(fallback, vec![40, 80]);
+        assert_eq!(plan.fallback.variants[0].height, 20);
+        assert!(
+            plan.fallback.variants[0]
+                .file_name
+                .starts_with("photo-40w.")
+        );
+        assert!(plan.fallback.variants[0].file_name.ends_with(".png"));
+
+        assert_eq!(plan.sources.len(), 1);
+        let webp: Vec<_> = plan.sources[0].variants.iter().map(|v| v.width).collect();
+        assert_eq!(webp, vec![40, 80, 100]);
+    }
+
+    #[test]
+    fn test_plan_skips_original_format() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("photo.png");
+        write_png(&source, 100, 50);
+
+        let plan = plan(&source, &config(&[40], &[ImageFormat::Png])).unwrap();
+        assert!(plan.sources.is_empty());
+    }
+
+    #[test]
+    fn test_plan_name_changes_with_content() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("photo.png");
+        let cfg = config(&[40], &[]);
+
+        write_png(&source, 100, 50);
+        let before = plan(&source, &cfg).unwrap();
+        write_png(&source, 100, 60);
+        let after = plan(&source, &cfg).unwrap();
+
+        assert_ne!(
+            before.fallback.variants[0].file_name,
+            after.fallback.variants[0].file_name
+        );
+    }
+
+    #[test]
+    fn test_generate_writes_variants() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("photo.png");
+        write_png(&source, 100, 50);
+        let out = dir.path().join("out");
+
+        let plan = plan(
+            &source,
+            &config(&[40], &[ImageFormat::Webp, ImageFormat::Jpeg]),
+        )
+        .unwrap();
+        assert_eq!(generate(&source, &out, &plan).unwrap(), 5);
+
+        for (_, variant) in plan.files() {
+            let (w, h) = image::image_dimensions(out.join(&variant.file_name)).unwrap();
+            assert_eq!((w, h), (variant.width, variant.height));
+        }
+
+        // Second run is a no-op
+        assert_eq!(generate(&source, &out, &plan).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_generate_writes_avif() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("photo.png");
+        write_png(&source, 64, 32);
+        let out = dir.path().join("out");
+
+        let plan = plan(&source, &config(&[32], &[ImageFormat::Avif])).unwrap();
+        assert_eq!(generate(&source, &out, &plan).unwrap(), 3);
+
+        let avif = &plan.sources[0].variants[0];
+        assert!(avif.file_name.ends_with(".avif"));
+        let bytes = fs::read(out.join(&avif.file_name)).unwrap();
+        assert_eq!(&bytes[4..12], b"ftypavif");
+    }
+}
@@ -11,7 +11,7 @@ use std::path::PathBuf;
 use super::convert::convert_svg;
 use super::filename_hash;
 use super::optimize::{OptimizeOptions, optimize_svg};
-use crate::config::{SvgConverter, SvgFormat};
+use crate::config::{SvgConverter, SvgFormat, SvgPass};
 
 /// Context for SVG extraction
 #[derive(Debug, Clone)]
@@ -30,6 +30,8 @@ pub struct ExtractContext {
     pub threshold: usize,
     /// Whether to expand viewBox to include stroke boundaries.
     pub expand_viewbox: bool,
+    /// Optimization passes (`build.svg.optimization_passes`).
+    pub passes: Vec<SvgPass>,
 }
 
 impl ExtractContext {
@@ -50,9 +52,16 @@ impl ExtractContext {
             quality: 90,
             threshold,
             expand_viewbox,
+            passes: SvgPass::ALL.to_vec(),
         }
     }
 
+    /// Set the optimization passes.
+    pub fn with_passes(mut self, passes: Vec<SvgPass>) -> Self {
+        self.passes = passes;
+        self
+    }
+
     /// Get the .tola subdirectory path.
     pub fn tola_dir(&self) -> PathBuf {
         self.output_dir.join(".tola")
@@ -93,6 +102,7 @@ pub fn extract_svg_to_file(svg_content: &[u8], ctx: &ExtractContext) -> Result<E
     let optimize_opts = OptimizeOptions {
         dpi: ctx.dpi,
         expand_viewbox: ctx.expand_viewbox,
+        passes: ctx.passes.clone(),
     };
     let optimized = optimize_svg(svg_content, &optimize_opts).context("Failed to optimize SVG")?;
 
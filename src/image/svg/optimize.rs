@@ -1,10 +1,13 @@
 //! SVG optimization using usvg.
 //!
 //! Handles SVG minification and viewBox expansion to include stroke boundaries.
+//!
+//! Minification runs the passes selected by `build.svg.optimization_passes`.
 
 use anyhow::{Context, Result};
 
 use super::bounds::{calculate_stroke_bounds, expand_viewbox_to_bounds};
+use crate::config::SvgPass;
 
 /// Options for SVG optimization
 #[derive(Debug, Clone)]
@@ -14,6 +17,8 @@ pub struct OptimizeOptions {
     /// Whether to expand viewBox to include stroke boundaries.
     /// This prevents content clipping when converting to external files.
     pub expand_viewbox: bool,
+    /// Optimization passes; empty keeps the SVG as written.
+    pub passes: Vec<SvgPass>,
 }
 
 impl Default for OptimizeOptions {
@@ -21,6 +26,7 @@ impl Default for OptimizeOptions {
         Self {
             dpi: 96.0,
             expand_viewbox: true,
+            passes: SvgPass::ALL.to_vec(),
         }
     }
 }
@@ -47,12 +53,19 @@ pub fn optimize_svg(content: &[u8], options: &OptimizeOptions) -> Result<Optimiz
 
     let tree = usvg::Tree::from_data(content, &usvg_options).context("Failed to parse SVG")?;
 
-    let write_options = usvg::WriteOptions {
-        indent: usvg::Indent::None,
-        ..Default::default()
+    let mut optimized = if options.passes.contains(&SvgPass::CollapseGroups) {
+        let write_options = usvg::WriteOptions {
+            indent: usvg::Indent::None,
+            ..Default::default()
+        };
+        tree.to_string(&write_options)
+    } else {
+        String::from_utf8_lossy(content).into_owned()
     };
 
-    let mut optimized = tree.to_string(&write_options);
+    if options.passes.contains(&SvgPass::RemoveComments) {
+        optimized = remove_comments(&optimized);
+    }
 
     // Expand viewBox to include stroke boundaries if enabled
     if options.expand_viewbox
@@ -61,7 +74,8 @@ pub fn optimize_svg(content: &[u8], options: &OptimizeOptions) -> Result<Optimiz
         optimized = expand_viewbox_to_bounds(&optimized, bounds);
     }
 
-    let size = parse_dimensions(&optimized).unwrap_or((0.0, 0.0));
+    let size =
+        parse_dimensions(&optimized).unwrap_or_else(|| (tree.size().width(), tree.size().height()));
 
     Ok(OptimizedSvg {
         data: optimized.into_bytes(),
@@ -69,6 +83,39 @@ pub fn optimize_svg(content: &[u8], options: &OptimizeOptions) -> Result<Optimiz
     })
 }
 
+/// Strip `<!-- ... -->` comments, leaving CDATA sections untouched
+fn remove_comments(svg: &str) -> String {
+    let mut out = String::with_capacity(svg.len());
+    let mut rest = svg;
+    loop {
+        let comment = rest.find("<!--");
+        let cdata = rest.find("<![CDATA[");
+        match (comment, cdata) {
+            (Some(start), cdata) if cdata.is_none_or(|cdata| start < cdata) => {
+                out.push_str(&rest[..start]);
+                let Some(end) = rest[start..].find("-->") else {
+                    // Unterminated comment: keep it rather than drop the tail
+                    out.push_str(&rest[start..]);
+                    break;
+                };
+                rest = &rest[start + end + 3..];
+            }
+            (_, Some(start)) => {
+                let end = rest[start..]
+                    .find("]]>")
+                    .map_or(rest.len(), |end| start + end + 3);
+                out.push_str(&rest[..end]);
+                rest = &rest[end..];
+            }
+            (_, None) => {
+                out.push_str(rest);
+                break;
+            }
+        }
+    }
+    out
+}
+
 /// Parse width and height from SVG string
 fn parse_dimensions(svg: &str) -> Option<(f32, f32)> {
     let width = extract_attr(svg, r#"width=""#)?.parse().ok()?;
@@ -101,6 +148,48 @@ mod tests {
         assert_eq!(parse_dimensions(r#"<svg height="50">"#), None);
     }
 
+    #[test]
+    fn test_remove_comments() {
+        assert_eq!(
+            remove_comments("<svg><!-- a --><g/><!--b--></svg>"),
+            "<svg><g/></svg>"
+        );
+        assert_eq!(
+            remove_comments("<script><![CDATA[ '<!--' ]]></script><!-- x -->"),
+            "<script><![CDATA[ '<!--' ]]></script>"
+        );
+        assert_eq!(remove_comments("<svg><!-- open"), "<svg><!-- open");
+    }
+
+    #[test]
+    fn test_optimize_passes() {
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"><!-- c --><g><rect width="5" height="5"/></g></svg>"#;
+
+        let none = OptimizeOptions {
+            expand_viewbox: false,
+            passes: Vec::new(),
+            ..Default::default()
+        };
+        let optimized = optimize_svg(svg, &none).unwrap();
+        assert_eq!(optimized.data, svg);
+        assert_eq!(optimized.size, (10.0, 10.0));
+
+        let comments = OptimizeOptions {
+            passes: vec![SvgPass::RemoveComments],
+            ..none.clone()
+        };
+        let optimized = String::from_utf8(optimize_svg(svg, &comments).unwrap().data).unwrap();
+        assert!(!optimized.contains("<!--"));
+        assert!(optimized.contains("<g><rect"));
+
+        let all = OptimizeOptions {
+            passes: SvgPass::ALL.to_vec(),
+            ..none
+        };
+        let optimized = String::from_utf8(optimize_svg(svg, &all).unwrap().data).unwrap();
+        assert!(!optimized.contains("<rect"));
+    }
+
     #[test]
     fn test_extract_attr() {
         let s = r#"<svg width="100" height="50" class="icon">"#;
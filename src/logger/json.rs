@@ -0,0 +1,133 @@
+//! JSON output mode (`--json`).
+//!
+//! When enabled, `log!` writes one JSON object per line to stdout instead of
+//! colored text, so CI can consume the output as JSON Lines:
+//!
+//! ```json
+//! {"type":"log","module":"build","message":"2 pages unchanged, reused from cache"}
+//! ```
+//!
+//! Commands append their own summary object (e.g. `{"type":"build", ...}`).
+//!
+//! Compile errors go to stderr as [`DiagnosticRecord`] lines, with `--json` or
+//! `[build.diagnostics] format = "json"`.
+
+use serde::Serialize;
+use std::{
+    io::{Write, stderr, stdout},
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// Global JSON flag (set by --json CLI argument)
+static JSON: AtomicBool = AtomicBool::new(false);
+
+/// Set JSON output mode globally
+pub fn set_json(v: bool) {
+    JSON.store(v, Ordering::SeqCst);
+}
+
+/// Check if JSON output mode is enabled
+pub fn is_json() -> bool {
+    JSON.load(Ordering::SeqCst)
+}
+
+/// Global JSON diagnostics flag (`--json` or `[build.diagnostics] format = "json"`)
+static JSON_DIAGNOSTICS: AtomicBool = AtomicBool::new(false);
+
+/// Set JSON diagnostics mode globally
+pub fn set_json_diagnostics(v: bool) {
+    JSON_DIAGNOSTICS.store(v, Ordering::SeqCst);
+}
+
+/// Check if compile errors are written as JSON lines on stderr
+pub fn is_json_diagnostics() -> bool {
+    JSON_DIAGNOSTICS.load(Ordering::SeqCst)
+}
+
+/// A compile error in JSON diagnostics mode
+#[derive(Debug, Serialize)]
+pub struct DiagnosticRecord {
+    pub level: &'static str,
+    /// Source file, relative to the site root.
+    pub file: Option<String>,
+    pub line: Option<usize>,
+    pub col: Option<usize>,
+    pub message: String,
+    pub hint: Option<String>,
+}
+
+/// A `log!` call in JSON mode
+#[derive(Serialize)]
+struct LogRecord<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    module: &'a str,
+    message: &'a str,
+}
+
+/// Write a `log!` message as a JSON line
+pub(super) fn log(module: &str, message: &str) {
+    emit(&LogRecord {
+        kind: "log",
+        module,
+        message,
+    });
+}
+
+/// Write a value as a single JSON line on stdout
+pub fn emit<T: Serialize>(value: &T) {
+    let Ok(line) = serde_json::to_string(value) else {
+        return;
+    };
+    let mut stdout = stdout().lock();
+    writeln!(stdout, "{line}").ok();
+    stdout.flush().ok();
+}
+
+/// Write a compile error as a single JSON line on stderr
+pub fn emit_diagnostic(record: &DiagnosticRecord) {
+    let Ok(line) = serde_json::to_string(record) else {
+        return;
+    };
+    let mut stderr = stderr().lock();
+    writeln!(stderr, "{line}").ok();
+    stderr.flush().ok();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_record_shape() {
+        let record = LogRecord {
+            kind: "log",
+            module: "build",
+            message: "say \"hi\"",
+        };
+        let json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+        assert_eq!(json["type"], "log");
+        assert_eq!(json["module"], "build");
+        assert_eq!(json["message"], "say \"hi\"");
+    }
+
+    #[test]
+    fn test_diagnostic_record_shape() {
+        let record = DiagnosticRecord {
+            level: "error",
+            file: Some("content/index.typ".to_string()),
+            line: Some(3),
+            col: Some(2),
+            message: "unknown variable: x".to_string(),
+            hint: None,
+        };
+        let json: serde_json::Value =
+            serde_json::from_str(&serde_json::to_string(&record).unwrap()).unwrap();
+        assert_eq!(json["level"], "error");
+        assert_eq!(json["file"], "content/index.typ");
+        assert_eq!(json["line"], 3);
+        assert_eq!(json["col"], 2);
+        assert!(json["hint"].is_null());
+    }
+}
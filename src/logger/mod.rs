@@ -4,6 +4,7 @@
 //! - `log!` macro for formatted terminal output with colored prefixes
 //! - `ProgressLine` for single-line progress display with multiple counters
 //! - `WatchStatus` for watch mode status messages
+//! - [`json`] for machine-readable output (`--json`)
 //!
 //! # Example
 //!
@@ -17,6 +18,8 @@
 //! progress.finish();
 //! ```
 
+pub mod json;
+
 use crossterm::{
     cursor, execute,
     terminal::{Clear, ClearType},
@@ -106,6 +109,11 @@ macro_rules! debug_do {
 #[inline]
 #[allow(clippy::cast_possible_truncation)] // Safe: bars count is always small
 pub fn log(module: &str, message: &str) {
+    if json::is_json() {
+        json::log(module, message);
+        return;
+    }
+
     let module_lower = module.to_ascii_lowercase();
     let prefix = colorize_prefix(module, &module_lower);
 
@@ -314,6 +322,7 @@ pub fn status_warning(detail: &str) {
 /// progress.finish(); // keeps the line, moves cursor down
 /// ```
 pub struct ProgressLine {
+    prefix: &'static str,
     counters: Vec<Counter>,
     lock: Mutex<()>,
 }
@@ -329,6 +338,11 @@ impl ProgressLine {
     ///
     /// Only includes counters with total > 0.
     pub fn new(items: &[(&'static str, usize)]) -> Self {
+        Self::with_prefix("build", items)
+    }
+
+    /// Create a progress display shown under `[prefix]` instead of `[build]`.
+    pub fn with_prefix(prefix: &'static str, items: &[(&'static str, usize)]) -> Self {
         let counters: Vec<_> = items
             .iter()
             .filter(|(_, total)| *total > 0)
@@ -342,6 +356,7 @@ impl ProgressLine {
         BAR_COUNT.store(1, Ordering::SeqCst);
 
         let progress = Self {
+            prefix,
             counters,
             lock: Mutex::new(()),
         };
@@ -366,6 +381,21 @@ impl ProgressLine {
         }
     }
 
+    /// Set the counter with the given name to an absolute value.
+    ///
+    /// Non-blocking like [`inc`](Self::inc).
+    pub fn set(&self, name: &str, current: usize) {
+        for counter in &self.counters {
+            if counter.name == name {
+                counter.current.store(current, Ordering::Relaxed);
+                if self.lock.try_lock().is_some() {
+                    self.display();
+                }
+                return;
+            }
+        }
+    }
+
     /// Display the current progress line (overwrites current line with \r).
     fn display(&self) {
         let mut parts = Vec::with_capacity(self.counters.len());
@@ -375,7 +405,7 @@ impl ProgressLine {
         }
 
         let line = parts.join(" ");
-        let prefix = colorize_prefix("build", "build");
+        let prefix = colorize_prefix(self.prefix, self.prefix);
 
         let mut stdout = stdout().lock();
         // Clear line and write progress (no newline - stays on same line)
@@ -403,7 +433,7 @@ impl ProgressLine {
                 parts.push(format!("{}({}/{})", counter.name, current, counter.total));
             }
             let line = parts.join(" ");
-            let prefix = colorize_prefix("build", "build");
+            let prefix = colorize_prefix(self.prefix, self.prefix);
 
             let mut stdout = stdout().lock();
             // Final line with newline to preserve it
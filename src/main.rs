@@ -22,13 +22,24 @@ mod reload;
 mod seo;
 mod utils;
 
+use std::sync::Arc;
+use std::time::Instant;
+
 use address::SiteIndex;
 use anyhow::Result;
 use clap::{ColorChoice, Parser};
-use cli::{Cli, Commands, build::build_site};
-use config::{SiteConfig, init_config};
+use cli::{
+    Cli, Commands,
+    build::{BuildOutput, build_site, manifest::BuildManifest, report::BuildReport},
+};
+use config::{DiagnosticsFormat, SiteConfig, init_config};
 use core::BuildMode;
-use seo::{feed::build_feed, sitemap::build_sitemap};
+use seo::{
+    feed::build_feed,
+    robots::build_robots,
+    search::build_search_index,
+    sitemap::{build_sitemap, open_source_repo},
+};
 
 fn main() -> Result<()> {
     // Setup global Ctrl+C handler (before any blocking operations)
@@ -37,41 +48,123 @@ fn main() -> Result<()> {
     let cli: &'static Cli = Box::leak(Box::new(Cli::parse()));
 
     // Set global color override based on CLI option
+    // JSON output must stay free of ANSI escapes, including inside `log!` messages
+    logger::json::set_json(cli.json);
     match cli.color {
+        _ if cli.json => owo_colors::set_override(false),
         ColorChoice::Always => owo_colors::set_override(true),
         ColorChoice::Never => owo_colors::set_override(false),
         ColorChoice::Auto => {} // owo-colors auto-detects TTY
     }
 
+    // Schema output is static and must work without a tola.toml
+    if cli.is_schema() {
+        return cli::schema::print_schema();
+    }
+    if let Commands::Completions { shell } = cli.command {
+        return cli::completions::print_completions(shell);
+    }
+    if let Commands::Init { template, .. } = &cli.command
+        && template == "list"
+    {
+        return cli::init::print_templates();
+    }
+
     let config = init_config(SiteConfig::load(cli)?);
 
-    match &cli.command {
-        Commands::Init { name, dry } => cli::init::new_site(&config, name.is_some(), *dry),
-        Commands::Build { .. } => build_all(&config, BuildMode::PRODUCTION),
+    // `--json` overrides `[build.diagnostics] format`; the dev server keeps text
+    logger::json::set_json_diagnostics(
+        !cli.is_serve() && (cli.json || config.build.diagnostics.format == DiagnosticsFormat::Json),
+    );
+
+    let result = match &cli.command {
+        Commands::Init {
+            name,
+            dry,
+            template,
+            bare,
+        } => cli::init::new_site(&config, name.is_some(), template, *dry, *bare),
+        Commands::Build { watch, .. } => {
+            let started = Instant::now();
+            let state = Arc::new(SiteIndex::new());
+            let result = build_into(&config, BuildMode::PRODUCTION, &state);
+            if cli.json {
+                let diagnostics = result.as_ref().map(|output| &output.warnings);
+                BuildReport::new(config.get_root(), diagnostics, started.elapsed()).print();
+            }
+            match result {
+                Ok(_) if *watch => cli::build::watch::watch_site(state),
+                result => result.map(drop),
+            }
+        }
         Commands::Deploy { .. } => {
             build_all(&config, BuildMode::PRODUCTION)?;
             cli::deploy::deploy_site(&config)
         }
+        Commands::Export { args } => {
+            if !cli::export::has_build_output(&config) {
+                build_all(&config, BuildMode::PRODUCTION)?;
+            }
+            cli::export::export_site(&config, args)
+        }
         Commands::Serve { .. } => cli::serve::serve_with_cache(&config),
         Commands::Query { args } => cli::query::run_query(args, &config),
         Commands::Validate { .. } => cli::validate::validate_site(&config),
-        Commands::Fix => cli::fix::run_fix(&config),
+        Commands::Clean { cache_only } => cli::clean::run_clean(&config, *cache_only),
+        Commands::New { args } => cli::new::new_page(args, &config),
+        Commands::Fix { args } => cli::fix::run_fix(&config, args),
+        Commands::Stats => cli::stats::run_stats(&config, cli.json),
+        Commands::Schema => cli::schema::print_schema(),
+        Commands::Completions { shell } => cli::completions::print_completions(*shell),
+    };
+
+    // Report failures as JSON lines instead of anyhow's text on stderr
+    if let Err(e) = &result
+        && logger::json::is_json_diagnostics()
+    {
+        cli::build::report::print_error_records(config.get_root(), e);
+        std::process::exit(1);
     }
+    result
+}
+
+/// Build site and optionally generate rss/sitemap/robots.txt
+fn build_all(config: &SiteConfig, mode: BuildMode) -> Result<BuildOutput> {
+    build_into(config, mode, &SiteIndex::new())
 }
 
-/// Build site and optionally generate rss/sitemap in parallel
-fn build_all(config: &SiteConfig, mode: BuildMode) -> Result<()> {
-    let state = SiteIndex::new();
-    let _pages = build_site(mode, config, &state, false)?;
+/// [`build_all`] into a caller-owned `state`, kept for `build --watch`
+fn build_into(config: &SiteConfig, mode: BuildMode, state: &SiteIndex) -> Result<BuildOutput> {
+    let started = Instant::now();
+
+    // Opened once here and shared with @tola/build and the sitemap builder (git lastmod)
+    let repo = open_source_repo(config);
+    package::init_build_info(mode, repo.as_ref(), config.get_root());
 
-    // Generate SEO files in parallel (feed, sitemap)
+    let output = build_site(mode, config, state, false)?;
+
+    // Generate SEO files in parallel (feed, sitemap, search index)
     // Note: OG tags are injected during VDOM pipeline (see HeaderInjector)
-    let (feed_result, sitemap_result) = rayon::join(
-        || state.with_pages(|pages| build_feed(config, pages)),
-        || state.with_pages(|pages| build_sitemap(config, pages)),
+    let ((feed_result, sitemap_result), search_result) = rayon::join(
+        || {
+            rayon::join(
+                || state.with_pages(|pages| build_feed(config, pages)),
+                || state.with_pages(|pages| build_sitemap(config, pages, repo.as_ref())),
+            )
+        },
+        || state.with_pages(|pages| build_search_index(config, pages)),
     );
 
     feed_result?;
     sitemap_result?;
-    Ok(())
+    search_result?;
+    state.with_pages(|pages| build_robots(config, pages))?;
+
+    // Last, so the feed and sitemap are compressed too
+    cli::build::compress::compress_output(config)?;
+
+    if let Some(path) = &config.build.manifest {
+        BuildManifest::collect(config, state, &output.warnings, started.elapsed()).write(path)?;
+    }
+    Ok(output)
 }
@@ -0,0 +1,123 @@
+//! Build metadata for `@tola/build`.
+//!
+//! Collected once when a build or serve session starts. The values never
+//! change afterwards, so pages importing `@tola/build` are not iterative and
+//! are not recompiled when other virtual packages change.
+
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+use gix::ThreadSafeRepository;
+
+use crate::core::BuildMode;
+use crate::page::format_rfc3339;
+use crate::utils::git;
+
+/// Length of the abbreviated commit hash
+const SHORT_HASH_LEN: usize = 7;
+
+static BUILD_INFO: OnceLock<BuildInfo> = OnceLock::new();
+
+/// Build-time constants exposed to Typst
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildInfo {
+    /// Build start time (RFC 3339, UTC).
+    pub build_time: String,
+    pub tola_version: &'static str,
+    /// Short `HEAD` hash, `None` outside a repository or before the first commit.
+    pub git_commit: Option<String>,
+    /// Working tree differs from `HEAD`.
+    pub git_dirty: bool,
+    /// `"development"` or `"production"`.
+    pub build_mode: &'static str,
+}
+
+impl BuildInfo {
+    /// Collect build metadata, discovering the repository from `root` if none is given
+    pub fn collect(mode: BuildMode, repo: Option<&ThreadSafeRepository>, root: &Path) -> Self {
+        let discovered;
+        let repo = match repo {
+            Some(repo) => Some(repo),
+            None => {
+                discovered = git::discover_repo(root).ok();
+                discovered.as_ref()
+            }
+        };
+        let (git_commit, git_dirty) = repo.map(git_state).unwrap_or_default();
+
+        let secs = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        Self {
+            build_time: format_rfc3339(i64::try_from(secs).unwrap_or(0)),
+            tola_version: env!("CARGO_PKG_VERSION"),
+            git_commit,
+            git_dirty,
+            build_mode: mode.as_str(),
+        }
+    }
+}
+
+/// Short `HEAD` hash and dirty flag
+fn git_state(repo: &ThreadSafeRepository) -> (Option<String>, bool) {
+    let local = repo.to_thread_local();
+    let Ok(head) = local.head_id() else {
+        return (None, false);
+    };
+    let commit = head.to_hex_with_len(SHORT_HASH_LEN).to_string();
+
+    let dirty = git::get_repo_root(&local)
+        .ok()
+        .and_then(|root| Some(git::head_blobs(repo).ok()? != git::worktree_blobs(root).ok()?))
+        .unwrap_or(false);
+
+    (Some(commit), dirty)
+}
+
+/// Record build metadata for this process
+///
+/// Only the first call takes effect; later builds in the same session (e.g.
+/// serve rebuilds) keep the startup values.
+pub fn init_build_info(mode: BuildMode, repo: Option<&ThreadSafeRepository>, root: &Path) {
+    BUILD_INFO.get_or_init(|| BuildInfo::collect(mode, repo, root));
+}
+
+/// Build metadata, falling back to development mode outside build/serve
+/// (e.g. `tola query`)
+pub(super) fn build_info() -> &'static BuildInfo {
+    BUILD_INFO.get_or_init(|| {
+        let cwd = std::env::current_dir().unwrap_or_default();
+        BuildInfo::collect(BuildMode::DEVELOPMENT, None, &cwd)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_collect_outside_repository() {
+        let dir = TempDir::new().unwrap();
+        let info = BuildInfo::collect(BuildMode::PRODUCTION, None, dir.path());
+
+        assert_eq!(info.build_mode, "production");
+        assert_eq!(info.tola_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.build_time.len(), "2025-06-15T14:30:45Z".len());
+        assert!(info.build_time.ends_with('Z'));
+    }
+
+    #[test]
+    fn test_collect_uncommitted_repository() {
+        let dir = TempDir::new().unwrap();
+        let repo = git::create_repo(dir.path()).unwrap();
+        let info = BuildInfo::collect(BuildMode::DEVELOPMENT, Some(&repo), dir.path());
+
+        assert_eq!(info.build_mode, "development");
+        assert_eq!(info.git_commit, None);
+        assert!(!info.git_dirty);
+    }
+}
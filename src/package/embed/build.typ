@@ -0,0 +1,15 @@
+// @tola/build:0.0.0 - Build metadata, fixed for the whole build
+
+/// Build metadata.
+/// Access via `build.build_time`, `build.git_commit`, etc.
+#let build = (
+  // Build start time, ISO 8601 in UTC (e.g. "2025-06-15T14:30:45Z")
+  build_time: __BUILD_TIME__,
+  tola_version: __TOLA_VERSION__,
+  // Short commit hash, `none` outside a git repository
+  git_commit: __GIT_COMMIT__,
+  // Whether the working tree has uncommitted changes
+  git_dirty: __GIT_DIRTY__,
+  // "development" (serve) or "production" (build)
+  build_mode: __BUILD_MODE__,
+)
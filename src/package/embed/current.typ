@@ -2,6 +2,9 @@
 
 #let _tola_current = sys.inputs.at("__CURRENT_KEY__", default: (:))
 
+// Tag -> pages (newest first, drafts excluded), same order as `by-tag(tag)`.
+#let _tag-index = sys.inputs.at("__TAG_INDEX_KEY__", default: (:))
+
 /// Current page's permalink (URL path).
 /// Example: "/blog/hello/"
 #let current-permalink = _tola_current.at("current-permalink", default: none)
@@ -30,6 +33,17 @@
 /// Returns an array of heading objects with `level` (1-6) and `text`.
 #let headings = _tola_current.at("headings", default: ())
 
+/// Estimated reading time in minutes.
+/// Requires `[build.meta] reading_time = true`; `none` otherwise.
+#let reading-time = _tola_current.at("reading-time", default: none)
+
+/// Page number of a paginated page (from `page-number` metadata).
+/// Defaults to 1; `paginate()` in `@tola/pages` uses it automatically.
+#let current-page = {
+  let n = _tola_current.at("current-page", default: none)
+  if n == none { 1 } else { n }
+}
+
 #let siblings(pages) = {
   if parent-permalink == none { return () }
   pages.filter(p => (
@@ -120,3 +134,11 @@
   let end = calc.min(sorted-pages.len(), idx + 1 + n)
   sorted-pages.slice(idx + 1, end)
 }
+
+/// Find previous page among pages tagged `tag`.
+/// Same as `prev(by-tag(tag), n: n)` from `@tola/pages`.
+#let find-prev-by-tag(tag, n: 1) = prev(_tag-index.at(tag, default: ()), n: n)
+
+/// Find next page among pages tagged `tag`.
+/// Same as `next(by-tag(tag), n: n)` from `@tola/pages`.
+#let find-next-by-tag(tag, n: 1) = next(_tag-index.at(tag, default: ()), n: n)
@@ -11,6 +11,13 @@
 // API:
 // - pages()                -> non-draft pages only
 // - pages-with-drafts()    -> all pages (raw)
+// - draft-pages()          -> draft pages only (with `serve.drafts` preview)
+// - categories()           -> category tree from `category: "a/b"` metadata
+// - by-category(path)      -> pages under a category, including descendants
+// - tag-reading-time(tag)  -> (count, total-minutes, average-minutes) of a tag
+// - reading-summary(pages) -> the same summary for any list of pages
+// - paginate(items, n)     -> split items into pages of n
+// - search-index           -> URL of the JSON search index (`[build.search]`), or none
 
 #let _phase = sys.inputs.at("__PHASE_KEY__", default: "__FILTER_PHASE__")
 
@@ -31,6 +38,10 @@
 
 #let pages() = pages-with-drafts().filter(p => not p.at("draft", default: false))
 
+// Drafts are only present during `tola serve` with `[serve.drafts] enable = true`,
+// so a private index built from this is empty in production builds.
+#let draft-pages() = pages-with-drafts().filter(p => p.at("draft", default: false))
+
 #let by-tag(tag) = pages().filter(p => tag in p.tags)
 
 #let by-tags(..tags) = pages().filter(p => tags.pos().all(t => t in p.tags))
@@ -40,3 +51,91 @@
   for p in pages() { for t in p.tags { if t not in result { result.push(t) } } }
   result.sorted()
 }
+
+/// Root of the category tree built from `category` metadata (`"tech/rust"`).
+///
+/// Each node is `(name, path, pages, count, children)`: `pages` are filed
+/// directly under the node, `count` includes descendants, and `children` is
+/// sorted by name. Recurse over `children` to render category menus.
+#let categories() = sys.inputs.at(
+  "__CATEGORIES_KEY__",
+  default: (name: "", path: "", pages: (), count: 0, children: ()),
+)
+
+// Normalized category paths of a page (string or array metadata).
+#let _categories-of(p) = {
+  let value = p.at("category", default: none)
+  let paths = if value == none { () } else if type(value) == array { value } else { (value,) }
+  paths.map(c => c.trim("/"))
+}
+
+/// Pages in category `path` or any of its descendants.
+#let by-category(path) = {
+  let path = path.trim("/")
+  pages().filter(p => _categories-of(p).any(c => c == path or c.starts-with(path + "/")))
+}
+
+// Tag -> reading time summary, precomputed from `pages()`.
+#let _tag-reading-time = sys.inputs.at("__TAG_READING_TIME_KEY__", default: (:))
+
+/// Reading time summary of `pages`.
+///
+/// Returns a dictionary with:
+/// - `count`: number of pages
+/// - `total-minutes`: sum of `reading_time_minutes`
+/// - `average-minutes`: rounded mean over pages with a reading time, or `none`
+///
+/// Reading times require `[build.meta] reading_time = true`.
+#let reading-summary(pages) = {
+  let times = pages.map(p => p.at("reading_time_minutes", default: none)).filter(m => m != none)
+  let total = times.sum(default: 0)
+  (
+    count: pages.len(),
+    total-minutes: total,
+    average-minutes: if times.len() == 0 { none } else { int(calc.round(total / times.len())) },
+  )
+}
+
+/// Reading time summary of the pages tagged `tag`, same shape as `reading-summary()`.
+///
+/// ```typst
+/// #let t = tag-reading-time("rust")
+/// #t.count posts · #t.total-minutes min read
+/// ```
+#let tag-reading-time(tag) = _tag-reading-time.at(
+  tag,
+  default: (count: 0, total-minutes: 0, average-minutes: none),
+)
+
+// Current page number for paginate(), from `page-number` metadata.
+#let _current-page = sys.inputs.at("__CURRENT_KEY__", default: (:)).at("current-page", default: none)
+
+/// Split `items` into pages of `per-page` items.
+///
+/// Returns a dictionary with:
+/// - `pages`: array of slices (the last one may be shorter)
+/// - `total-pages`: number of slices (at least 1)
+/// - `current-page`: 1-based page number (`page-number` metadata, default 1)
+/// - `items`: slice for `current-page`
+/// - `get-page(n)`: slice for 1-based page `n`, `()` if out of range
+#let paginate(items, per-page, current: auto) = {
+  assert(
+    type(per-page) == int and per-page > 0,
+    message: "paginate: per-page must be a positive integer",
+  )
+  let slices = items.chunks(per-page)
+  let get-page(n) = if n < 1 { () } else { slices.at(n - 1, default: ()) }
+  let current = if current != auto { current } else if _current-page != none { _current-page } else { 1 }
+  (
+    pages: slices,
+    total-pages: calc.max(1, slices.len()),
+    current-page: current,
+    items: get-page(current),
+    get-page: get-page,
+  )
+}
+
+// URL of the generated search index, `none` unless `[build.search]` is enabled.
+// The index itself ([(url, title, text)]) is written after all pages compile,
+// so pages fetch it client-side rather than reading it here.
+#let search-index = sys.inputs.at("__SEARCH_KEY__", default: none)
@@ -3,16 +3,19 @@
 //! This module centralizes `sys.inputs` construction for `@tola/*` packages
 //! to keep behavior consistent across build/query/serve/validate paths.
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use anyhow::{Result, anyhow};
 
 use crate::config::SiteConfig;
 use crate::core::UrlPath;
-use crate::page::{PageState, StoredPageMap};
+use crate::page::{PageMeta, PageState, StoredPageMap};
 use crate::utils::path::normalize_path;
 use crate::utils::path::route::strip_path_prefix_from_page_url;
 
+use super::taxonomy::{category_tree, tag_index, tag_reading_times};
+use super::tola::{CATEGORIES_KEY, SEARCH_INDEX_KEY, TAG_INDEX_KEY, TAG_READING_TIME_KEY};
 use super::{Phase, TolaPackage};
 
 /// Typed specification for base virtual-package injection.
@@ -178,7 +181,22 @@ fn build_base_inputs_impl(
     if spec.include_pages {
         let mut pages_payload = store.pages_to_json_value_with_drafts();
         strip_pages_permalinks(&mut pages_payload, &path_prefix(config));
+        combined.insert(CATEGORIES_KEY.to_string(), category_tree(&pages_payload));
+        combined.insert(TAG_INDEX_KEY.to_string(), tag_index(&pages_payload));
+        combined.insert(
+            TAG_READING_TIME_KEY.to_string(),
+            tag_reading_times(&pages_payload),
+        );
         combined.insert(TolaPackage::Pages.input_key(), pages_payload);
+
+        let search = &config.build.search;
+        if search.enable {
+            let url = format!(
+                "/{}",
+                search.output_path.to_string_lossy().replace('\\', "/")
+            );
+            combined.insert(SEARCH_INDEX_KEY.to_string(), serde_json::json!(url));
+        }
     }
 
     combined.insert(
@@ -243,13 +261,69 @@ fn resolve_source_context(
         page.route.permalink
     };
 
-    let content_dir = normalize_path(&config.build.content);
-    let path_rel = normalized
-        .strip_prefix(&content_dir)
-        .ok()
-        .map(|p| p.to_string_lossy().to_string());
+    Ok((permalink, source_path_rel(config, file_path)))
+}
+
+/// Source path relative to its content directory
+pub fn source_path_rel(config: &SiteConfig, file_path: &Path) -> Option<String> {
+    let normalized = normalize_path(file_path);
+    config
+        .build
+        .content_dirs()
+        .find_map(|dir| normalized.strip_prefix(normalize_path(dir)).ok())
+        .map(|p| p.to_string_lossy().to_string())
+}
+
+/// Per-page `sys.inputs` variables
+///
+/// Scalar `extra` metadata fields (strings, numbers, booleans), plus
+/// `permalink` (without `path_prefix`) and `path` (content-relative source),
+/// which take precedence. tola's own input keys are never overridden.
+pub fn page_variables(
+    config: &SiteConfig,
+    permalink: &UrlPath,
+    path_rel: Option<&str>,
+    meta: Option<&PageMeta>,
+) -> HashMap<String, String> {
+    let mut variables: HashMap<String, String> = meta
+        .into_iter()
+        .flat_map(|meta| &meta.extra)
+        .filter(|(key, _)| !is_reserved_input(key))
+        .filter_map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s.clone(),
+                serde_json::Value::Number(n) => n.to_string(),
+                serde_json::Value::Bool(b) => b.to_string(),
+                _ => return None,
+            };
+            Some((key.clone(), value))
+        })
+        .collect();
+
+    variables.insert(
+        "permalink".to_string(),
+        strip_path_prefix_from_page_url(permalink.as_str(), &path_prefix(config)),
+    );
+    if let Some(path) = path_rel {
+        variables.insert("path".to_string(), path.replace('\\', "/"));
+    }
+    variables
+}
+
+/// Keys of the inputs tola injects itself
+fn is_reserved_input(key: &str) -> bool {
+    key.starts_with("__tola") || key == "format"
+}
 
-    Ok((permalink, path_rel))
+/// Variables of the page at `permalink`, with its stored metadata
+fn stored_page_variables(
+    config: &SiteConfig,
+    store: &StoredPageMap,
+    permalink: &UrlPath,
+    path_rel: Option<&str>,
+) -> serde_json::Value {
+    let meta = store.get_page(permalink).map(|page| page.meta);
+    serde_json::json!(page_variables(config, permalink, path_rel, meta.as_ref()))
 }
 
 fn build_inputs_for_source_impl(
@@ -264,6 +338,10 @@ fn build_inputs_for_source_impl(
     let (permalink, path_rel) = resolve_source_context(config, store, file_path)?;
 
     merge_current_context(config, &mut inputs, store, &permalink, path_rel.as_deref())?;
+    let variables = stored_page_variables(config, store, &permalink, path_rel.as_deref());
+    inputs
+        .merge_json(&variables)
+        .map_err(|e| anyhow!("failed to merge page variables: {}", e))?;
     Ok(inputs)
 }
 
@@ -304,6 +382,9 @@ pub fn build_visible_inputs_for_source(
 }
 
 /// Build visible-phase `@tola/current` payload for a specific source.
+///
+/// Also carries the page variables (see [`page_variables`]), as batch
+/// compilation merges this payload into the shared inputs per file.
 pub fn build_visible_current_context_for_source(
     config: &SiteConfig,
     store: &StoredPageMap,
@@ -313,6 +394,12 @@ pub fn build_visible_current_context_for_source(
     let (permalink, path_rel) = resolve_source_context(config, store, file_path)?;
     let mut current = PageState::new(store).build_current_context(&permalink, path_rel.as_deref());
     strip_current_context_permalinks(&mut current, &path_prefix(config));
+    if let (Some(current), serde_json::Value::Object(variables)) = (
+        current.as_object_mut(),
+        stored_page_variables(config, store, &permalink, path_rel.as_deref()),
+    ) {
+        current.extend(variables);
+    }
     Ok(current)
 }
 
@@ -325,6 +412,36 @@ mod tests {
 
     use crate::page::StoredPageMap;
 
+    #[test]
+    fn test_page_variables() {
+        let config = SiteConfig::default();
+        let mut meta = PageMeta::default();
+        for (key, value) in [
+            ("series", serde_json::json!("rust")),
+            ("part", serde_json::json!(2)),
+            ("featured", serde_json::json!(true)),
+            ("tags", serde_json::json!(["a"])),
+            ("format", serde_json::json!("pdf")),
+            ("permalink", serde_json::json!("/other/")),
+        ] {
+            meta.extra.insert(key.to_string(), value);
+        }
+
+        let variables = page_variables(
+            &config,
+            &UrlPath::from_page("/posts/hello/"),
+            Some("posts/hello.typ"),
+            Some(&meta),
+        );
+        assert_eq!(variables["series"], "rust");
+        assert_eq!(variables["part"], "2");
+        assert_eq!(variables["featured"], "true");
+        assert_eq!(variables["permalink"], "/posts/hello/");
+        assert_eq!(variables["path"], "posts/hello.typ");
+        assert!(!variables.contains_key("tags"));
+        assert!(!variables.contains_key("format"));
+    }
+
     #[test]
     fn test_visible_spec_requires_site_and_pages() {
         let spec = InjectSpec::visible().with_site(false);
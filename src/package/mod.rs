@@ -1,25 +1,31 @@
 //! Virtual Package System for `@tola/*` packages.
 //!
-//! Provides three virtual packages:
+//! Provides four virtual packages:
 //! - `@tola/site` - Site configuration from `[site]` in tola.toml
 //! - `@tola/pages` - Page metadata and filtering utilities
 //! - `@tola/current` - Current page context and navigation
+//! - `@tola/build` - Build time, tola version, git commit and build mode
 //!
 //! # Usage in Typst
 //!
 //! ```typst
 //! #import "@tola/site:0.0.0": info, root
-//! #import "@tola/pages:0.0.0": pages, by-tag, all-tags
+//! #import "@tola/pages:0.0.0": pages, by-tag, all-tags, categories, by-category
 //! #import "@tola/current:0.0.0": permalink, siblings, prev
+//! #import "@tola/build:0.0.0": build
 //! ```
 
+mod build;
 mod inject;
 mod phase;
+mod taxonomy;
 mod tola;
 
+pub use build::init_build_info;
 pub use inject::{
     build_filter_inputs_with_site, build_visible_current_context_for_source, build_visible_inputs,
-    build_visible_inputs_for_source, build_visible_inputs_with_current_context,
+    build_visible_inputs_for_source, build_visible_inputs_with_current_context, page_variables,
+    source_path_rel,
 };
 pub use phase::Phase;
 pub use tola::{TolaPackage, generate_lsp_stubs, package_sentinel, read_package};
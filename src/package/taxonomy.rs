@@ -0,0 +1,282 @@
+//! Category tree for `categories()` in `@tola/pages`, and the tag index for
+//! `find-prev-by-tag()` / `find-next-by-tag()` in `@tola/current`.
+//!
+//! Pages declare `category: "tech/rust"` (or an array of such paths) in their
+//! metadata. Each `/`-separated segment becomes a node:
+//!
+//! ```text
+//! (name: "", path: "", pages: (), count: 3, children: (
+//!   (name: "tech", path: "tech", pages: (..), count: 3, children: (
+//!     (name: "rust", path: "tech/rust", pages: (..), count: 2, children: ()),
+//!   )),
+//! ))
+//! ```
+//!
+//! `pages` holds only pages filed directly under a node; `count` includes
+//! descendants.
+//!
+//! The tag index maps each tag to its pages in `pages()` order (newest
+//! first), so navigating within a tag matches `prev(by-tag(tag))`.
+//!
+//! The tag reading times for `tag-reading-time()` in `@tola/pages` sum the
+//! `reading_time_minutes` of each tag's pages:
+//!
+//! ```text
+//! (rust: (count: 12, total-minutes: 47, average-minutes: 4))
+//! ```
+//!
+//! Drafts are left out of all three, matching `pages()`.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+/// Metadata field holding a page's category path(s)
+const CATEGORY_FIELD: &str = "category";
+
+/// Page field holding the estimated reading time (`build.meta.reading_time`)
+const READING_TIME_FIELD: &str = "reading_time_minutes";
+
+/// A node in the category tree
+#[derive(Debug, Default, Serialize)]
+struct CategoryNode {
+    name: String,
+    path: String,
+    pages: Vec<JsonValue>,
+    count: usize,
+    /// Keyed by segment name for sorted, deduplicated children
+    #[serde(serialize_with = "serialize_children")]
+    children: BTreeMap<String, CategoryNode>,
+}
+
+fn serialize_children<S: serde::Serializer>(
+    children: &BTreeMap<String, CategoryNode>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(children.values())
+}
+
+impl CategoryNode {
+    /// Insert `page` under `segments`, creating intermediate nodes.
+    fn insert(&mut self, segments: &[&str], page: &JsonValue) {
+        self.count += 1;
+        let Some((first, rest)) = segments.split_first() else {
+            self.pages.push(page.clone());
+            return;
+        };
+
+        let child = self
+            .children
+            .entry((*first).to_string())
+            .or_insert_with(|| CategoryNode {
+                name: (*first).to_string(),
+                path: if self.path.is_empty() {
+                    (*first).to_string()
+                } else {
+                    format!("{}/{}", self.path, first)
+                },
+                ..Default::default()
+            });
+        child.insert(rest, page);
+    }
+}
+
+/// Build the category tree from the `@tola/pages` payload (array of pages).
+pub(super) fn category_tree(pages: &JsonValue) -> JsonValue {
+    let mut root = CategoryNode::default();
+
+    for page in pages.as_array().into_iter().flatten() {
+        if page.get("draft").and_then(JsonValue::as_bool) == Some(true) {
+            continue;
+        }
+        for category in page_categories(page) {
+            let segments: Vec<&str> = category.split('/').filter(|s| !s.is_empty()).collect();
+            if !segments.is_empty() {
+                root.insert(&segments, page);
+            }
+        }
+    }
+
+    serde_json::to_value(&root).unwrap_or(JsonValue::Null)
+}
+
+/// Build the tag -> pages index from the `@tola/pages` payload (array of pages).
+pub(super) fn tag_index(pages: &JsonValue) -> JsonValue {
+    let mut index: BTreeMap<&str, Vec<&JsonValue>> = BTreeMap::new();
+
+    for page in pages.as_array().into_iter().flatten() {
+        if page.get("draft").and_then(JsonValue::as_bool) == Some(true) {
+            continue;
+        }
+        let tags = page.get("tags").and_then(JsonValue::as_array);
+        for tag in tags.into_iter().flatten().filter_map(JsonValue::as_str) {
+            let tagged = index.entry(tag).or_default();
+            // A tag listed twice on one page must not duplicate it
+            if !tagged.last().is_some_and(|last| std::ptr::eq(*last, page)) {
+                tagged.push(page);
+            }
+        }
+    }
+
+    serde_json::to_value(&index).unwrap_or(JsonValue::Null)
+}
+
+/// Reading time summary of the pages sharing a tag
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct ReadingTime {
+    count: u64,
+    total_minutes: u64,
+    /// Rounded mean over pages with a reading time, `None` if there are none
+    average_minutes: Option<u64>,
+    #[serde(skip)]
+    timed: u64,
+}
+
+/// Build the tag -> reading time summary from the `@tola/pages` payload.
+pub(super) fn tag_reading_times(pages: &JsonValue) -> JsonValue {
+    let mut summaries: BTreeMap<&str, ReadingTime> = BTreeMap::new();
+
+    for page in pages.as_array().into_iter().flatten() {
+        if page.get("draft").and_then(JsonValue::as_bool) == Some(true) {
+            continue;
+        }
+        let minutes = page.get(READING_TIME_FIELD).and_then(JsonValue::as_u64);
+        let mut tags: Vec<&str> = page
+            .get("tags")
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(JsonValue::as_str)
+            .collect();
+        tags.sort_unstable();
+        tags.dedup();
+
+        for tag in tags {
+            let summary = summaries.entry(tag).or_default();
+            summary.count += 1;
+            if let Some(minutes) = minutes {
+                summary.total_minutes += minutes;
+                summary.timed += 1;
+            }
+        }
+    }
+
+    for summary in summaries.values_mut() {
+        summary.average_minutes = (summary.timed > 0)
+            .then(|| (summary.total_minutes + summary.timed / 2) / summary.timed);
+    }
+
+    serde_json::to_value(&summaries).unwrap_or(JsonValue::Null)
+}
+
+/// Category paths of a page: a single string or an array of strings.
+fn page_categories(page: &JsonValue) -> Vec<&str> {
+    match page.get(CATEGORY_FIELD) {
+        Some(JsonValue::String(s)) => vec![s.as_str()],
+        Some(JsonValue::Array(items)) => items.iter().filter_map(JsonValue::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn names(node: &JsonValue) -> Vec<&str> {
+        node["children"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["name"].as_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_category_tree_nesting() {
+        let pages = json!([
+            {"title": "A", "category": "tech/rust"},
+            {"title": "B", "category": "/tech/rust/"},
+            {"title": "C", "category": "tech/python"},
+            {"title": "D", "category": "tech"},
+            {"title": "E", "category": "life"},
+            {"title": "F"},
+        ]);
+
+        let tree = category_tree(&pages);
+        assert_eq!(tree["count"], 5);
+        assert_eq!(names(&tree), ["life", "tech"]);
+
+        let tech = &tree["children"][1];
+        assert_eq!(tech["path"], "tech");
+        assert_eq!(tech["count"], 4);
+        assert_eq!(tech["pages"].as_array().unwrap().len(), 1);
+        assert_eq!(names(tech), ["python", "rust"]);
+
+        let rust = &tech["children"][1];
+        assert_eq!(rust["path"], "tech/rust");
+        assert_eq!(rust["count"], 2);
+        assert!(rust["children"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_category_tree_multiple_and_drafts() {
+        let pages = json!([
+            {"title": "A", "category": ["tech/rust", "notes"]},
+            {"title": "B", "category": "tech/rust", "draft": true},
+        ]);
+
+        let tree = category_tree(&pages);
+        assert_eq!(names(&tree), ["notes", "tech"]);
+        assert_eq!(tree["children"][1]["children"][0]["count"], 1);
+    }
+
+    #[test]
+    fn test_tag_index() {
+        let pages = json!([
+            {"title": "A", "tags": ["rust", "web"]},
+            {"title": "B", "tags": ["rust", "rust"]},
+            {"title": "C", "tags": ["rust"], "draft": true},
+            {"title": "D"},
+        ]);
+
+        let index = tag_index(&pages);
+        let titles = |tag: &str| -> Vec<&str> {
+            index[tag]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|p| p["title"].as_str().unwrap())
+                .collect()
+        };
+        assert_eq!(titles("rust"), ["A", "B"]);
+        assert_eq!(titles("web"), ["A"]);
+        assert_eq!(index.as_object().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_tag_reading_times() {
+        let pages = json!([
+            {"title": "A", "tags": ["rust", "web"], "reading_time_minutes": 3},
+            {"title": "B", "tags": ["rust", "rust"], "reading_time_minutes": 6},
+            {"title": "C", "tags": ["rust"], "reading_time_minutes": 9, "draft": true},
+            {"title": "D", "tags": ["rust", "notes"]},
+        ]);
+
+        let times = tag_reading_times(&pages);
+        assert_eq!(
+            times["rust"],
+            json!({"count": 3, "total-minutes": 9, "average-minutes": 5})
+        );
+        assert_eq!(
+            times["web"],
+            json!({"count": 1, "total-minutes": 3, "average-minutes": 3})
+        );
+        assert_eq!(
+            times["notes"],
+            json!({"count": 1, "total-minutes": 0, "average-minutes": null})
+        );
+    }
+}
@@ -7,6 +7,7 @@ use typst_batch::prelude::*;
 use crate::embed::{Template, TemplateVars};
 
 use super::Phase;
+use super::build::{BuildInfo, build_info};
 
 // =============================================================================
 // Constants
@@ -15,6 +16,18 @@ use super::Phase;
 const TOLA_NAMESPACE: &str = "tola";
 const TOLA_VERSION: PackageVersion = PackageVersion::new(0, 0, 0);
 
+/// sys.inputs key for the search index URL (`search-index` in `@tola/pages`)
+pub(super) const SEARCH_INDEX_KEY: &str = "__tola_search_index";
+
+/// sys.inputs key for the category tree (`categories()` in `@tola/pages`)
+pub(super) const CATEGORIES_KEY: &str = "__tola_categories";
+
+/// sys.inputs key for the tag index (`find-prev-by-tag()` in `@tola/current`)
+pub(super) const TAG_INDEX_KEY: &str = "__tola_tag_index";
+
+/// sys.inputs key for per-tag reading times (`tag-reading-time()` in `@tola/pages`)
+pub(super) const TAG_READING_TIME_KEY: &str = "__tola_tag_reading_time";
+
 // =============================================================================
 // Template Constants
 // =============================================================================
@@ -24,6 +37,7 @@ const SITE_INFO_TYP: Template<SiteInfoTypVars<'static>> =
 const PAGES_TYP: Template<PagesTypVars<'static>> = Template::new(include_str!("embed/pages.typ"));
 const CURRENT_TYP: Template<CurrentTypVars<'static>> =
     Template::new(include_str!("embed/current.typ"));
+const BUILD_TYP: Template<BuildTypVars<'static>> = Template::new(include_str!("embed/build.typ"));
 
 // =============================================================================
 // Template Variables
@@ -36,11 +50,20 @@ struct SiteInfoTypVars<'a> {
 struct PagesTypVars<'a> {
     phase_key: &'a str,
     pages_key: &'a str,
+    current_key: &'a str,
+    search_key: &'a str,
+    categories_key: &'a str,
+    tag_reading_time_key: &'a str,
     filter_phase: &'a str,
 }
 
 struct CurrentTypVars<'a> {
     current_key: &'a str,
+    tag_index_key: &'a str,
+}
+
+struct BuildTypVars<'a> {
+    info: &'a BuildInfo,
 }
 
 impl TemplateVars for SiteInfoTypVars<'_> {
@@ -54,16 +77,43 @@ impl TemplateVars for PagesTypVars<'_> {
         content
             .replace("__PHASE_KEY__", self.phase_key)
             .replace("__PAGES_KEY__", self.pages_key)
+            .replace("__CURRENT_KEY__", self.current_key)
+            .replace("__SEARCH_KEY__", self.search_key)
+            .replace("__CATEGORIES_KEY__", self.categories_key)
+            .replace("__TAG_READING_TIME_KEY__", self.tag_reading_time_key)
             .replace("__FILTER_PHASE__", self.filter_phase)
     }
 }
 
 impl TemplateVars for CurrentTypVars<'_> {
     fn apply(&self, content: &str) -> String {
-        content.replace("__CURRENT_KEY__", self.current_key)
+        content
+            .replace("__CURRENT_KEY__", self.current_key)
+            .replace("__TAG_INDEX_KEY__", self.tag_index_key)
     }
 }
 
+impl TemplateVars for BuildTypVars<'_> {
+    fn apply(&self, content: &str) -> String {
+        let info = self.info;
+        let git_commit = info
+            .git_commit
+            .as_deref()
+            .map_or_else(|| "none".to_string(), typst_str);
+        content
+            .replace("__BUILD_TIME__", &typst_str(&info.build_time))
+            .replace("__TOLA_VERSION__", &typst_str(info.tola_version))
+            .replace("__GIT_COMMIT__", &git_commit)
+            .replace("__GIT_DIRTY__", &info.git_dirty.to_string())
+            .replace("__BUILD_MODE__", &typst_str(info.build_mode))
+    }
+}
+
+/// Typst string literal
+fn typst_str(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
 // =============================================================================
 // TolaPackage Enum
 // =============================================================================
@@ -74,11 +124,15 @@ impl TemplateVars for CurrentTypVars<'_> {
 /// - `Site`: Static config from `tola.toml`, always available
 /// - `Pages`: All page metadata, available after scan phase
 /// - `Current`: Current page context, available at compile time
+///
+/// `Build` is not injected: its build-time constants are rendered directly
+/// into `lib.typ`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TolaPackage {
     Site,
     Pages,
     Current,
+    Build,
 }
 
 impl TolaPackage {
@@ -88,6 +142,7 @@ impl TolaPackage {
             Self::Site => "site",
             Self::Pages => "pages",
             Self::Current => "current",
+            Self::Build => "build",
         }
     }
 
@@ -117,11 +172,17 @@ impl TolaPackage {
             Self::Pages => PAGES_TYP.render(&PagesTypVars {
                 phase_key: Phase::input_key(),
                 pages_key: &Self::Pages.input_key(),
+                current_key: &Self::Current.input_key(),
+                search_key: SEARCH_INDEX_KEY,
+                categories_key: CATEGORIES_KEY,
+                tag_reading_time_key: TAG_READING_TIME_KEY,
                 filter_phase: Phase::Filter.as_str(),
             }),
             Self::Current => CURRENT_TYP.render(&CurrentTypVars {
                 current_key: &Self::Current.input_key(),
+                tag_index_key: TAG_INDEX_KEY,
             }),
+            Self::Build => BUILD_TYP.render(&BuildTypVars { info: build_info() }),
         }
     }
 
@@ -147,6 +208,7 @@ entrypoint = "lib.typ"
             "site" => Some(Self::Site),
             "pages" => Some(Self::Pages),
             "current" => Some(Self::Current),
+            "build" => Some(Self::Build),
             _ => None,
         }
     }
@@ -159,13 +221,14 @@ entrypoint = "lib.typ"
             "site" => Some(Self::Site),
             "pages" => Some(Self::Pages),
             "current" => Some(Self::Current),
+            "build" => Some(Self::Build),
             _ => None,
         }
     }
 
     /// All tola packages.
     pub const fn all() -> &'static [Self] {
-        &[Self::Site, Self::Pages, Self::Current]
+        &[Self::Site, Self::Pages, Self::Current, Self::Build]
     }
 
     /// Sentinel path for dependency tracking.
@@ -39,6 +39,24 @@ fn days_to_ymd(days: i64) -> (i64, u32, u32) {
     (y, m, d)
 }
 
+/// Format seconds since Unix epoch as `YYYY-MM-DD`
+pub(crate) fn format_ymd(secs: i64) -> String {
+    let (year, month, day) = days_to_ymd(secs.div_euclid(86400));
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Format seconds since Unix epoch as RFC 3339 (`YYYY-MM-DDTHH:MM:SSZ`)
+pub(crate) fn format_rfc3339(secs: i64) -> String {
+    let time = secs.rem_euclid(86400);
+    format!(
+        "{}T{:02}:{:02}:{:02}Z",
+        format_ymd(secs),
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
 /// Primary metadata structure for a compiled content page
 ///
 /// Contains all path and URL information needed by build, rss and sitemap
@@ -90,8 +108,13 @@ impl CompiledPage {
         ContentKind::from_path(&source)
             .ok_or_else(|| anyhow!("Unsupported content type: {}", source.display()))?;
 
-        // Also normalize content_dir for consistent comparison
-        let content_dir = crate::utils::path::normalize_path(&config.build.content);
+        // Strip whichever content directory (primary or `extra_content`) holds the file
+        let content_dir = config
+            .build
+            .content_dirs()
+            .map(crate::utils::path::normalize_path)
+            .find(|dir| source.starts_with(dir))
+            .unwrap_or_else(|| crate::utils::path::normalize_path(&config.build.content));
         let paths = config.paths();
         let output_root = paths.output_dir();
 
@@ -189,6 +212,7 @@ impl CompiledPage {
     pub fn apply_meta(&mut self, meta: Option<PageMeta>, config: &SiteConfig) {
         self.content_meta = meta;
         self.apply_custom_permalink(config);
+        self.apply_draft_prefix(config);
     }
 
     /// Apply custom permalink from PageMeta if present.
@@ -208,7 +232,22 @@ impl CompiledPage {
         // Create UrlPath (handles normalization: leading/trailing slashes)
         let permalink = UrlPath::from_page(custom);
 
-        // Update output paths based on new permalink
+        self.set_permalink(permalink, config);
+    }
+
+    /// Move previewed drafts under `serve.drafts.prefix`.
+    fn apply_draft_prefix(&mut self, config: &SiteConfig) {
+        let is_draft = self.content_meta.as_ref().is_some_and(|m| m.draft);
+        if !is_draft || !config.serve.drafts.enable {
+            return;
+        }
+
+        let permalink = config.serve.drafts.permalink(&self.route.permalink);
+        self.set_permalink(permalink, config);
+    }
+
+    /// Update route.permalink and the output paths derived from it.
+    fn set_permalink(&mut self, permalink: UrlPath, config: &SiteConfig) {
         let paths = config.paths();
         let output_root = paths.output_dir();
 
@@ -228,10 +267,8 @@ impl CompiledPage {
     pub fn lastmod_ymd(&self) -> Option<String> {
         let modified = self.lastmod?;
         let duration = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
-        #[allow(clippy::cast_possible_wrap)] // Safe: seconds/86400 fits in i64
-        let days = duration.as_secs() as i64 / 86400;
-        let (year, month, day) = days_to_ymd(days);
-        Some(format!("{year:04}-{month:02}-{day:02}"))
+        #[allow(clippy::cast_possible_wrap)] // Safe: seconds fit in i64
+        Some(format_ymd(duration.as_secs() as i64))
     }
 }
 
@@ -424,6 +461,21 @@ mod tests {
         assert!(page.route.output_file.ends_with("public/index.html"));
     }
 
+    #[test]
+    fn test_compiled_page_extra_content_route() {
+        let (dir, _source, mut config) = temp_source_page("index.typ", "= Home");
+        let docs = dir.path().join("docs");
+        fs::create_dir_all(docs.join("guide")).unwrap();
+        fs::write(docs.join("guide/setup.typ"), "= Setup").unwrap();
+        config.build.extra_content = vec![docs.clone()];
+
+        let page = CompiledPage::from_paths(docs.join("guide/setup.typ"), &config).unwrap();
+        assert_eq!(page.route.permalink, "/guide/setup/");
+
+        let outside = dir.path().join("templates/base.typ");
+        assert!(CompiledPage::from_paths(outside, &config).is_err());
+    }
+
     #[test]
     fn test_pages_empty() {
         let pages = Pages::default();
@@ -673,6 +725,34 @@ mod tests {
         assert_eq!(page.route.permalink, "/custom-slug/");
     }
 
+    #[test]
+    fn test_apply_meta_prefixes_previewed_drafts() {
+        let (_dir, source, mut config) = temp_source_page("posts/hello.typ", "= Hello");
+        let draft = PageMeta {
+            draft: true,
+            ..Default::default()
+        };
+
+        // Preview disabled: route unchanged
+        let mut page = CompiledPage::from_paths(&source, &config).unwrap();
+        page.apply_meta(Some(draft.clone()), &config);
+        assert_eq!(page.route.permalink, "/posts/hello/");
+
+        config.serve.drafts.enable = true;
+        let mut page = CompiledPage::from_paths(&source, &config).unwrap();
+        page.apply_meta(Some(draft.clone()), &config);
+        assert_eq!(page.route.permalink, "/_drafts/posts/hello/");
+        assert!(
+            page.route
+                .output_file
+                .ends_with("public/_drafts/posts/hello/index.html")
+        );
+
+        // Applying metadata again (scan, then compile) must not double the prefix
+        page.apply_meta(Some(draft), &config);
+        assert_eq!(page.route.permalink, "/_drafts/posts/hello/");
+    }
+
     #[test]
     fn test_apply_meta_without_permalink_keeps_route() {
         let (_dir, source, config) = temp_source_page("hello.typ", "= Hello");
@@ -706,4 +786,14 @@ mod tests {
         let (y, m, d) = days_to_ymd(19782);
         assert_eq!((y, m, d), (2024, 2, 29));
     }
+
+    #[test]
+    fn test_format_rfc3339() {
+        // 2025-06-15 14:30:45 UTC
+        assert_eq!(
+            format_rfc3339(20254 * 86400 + 52245),
+            "2025-06-15T14:30:45Z"
+        );
+        assert_eq!(format_rfc3339(0), "1970-01-01T00:00:00Z");
+    }
 }
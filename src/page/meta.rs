@@ -3,6 +3,7 @@
 use serde::Deserialize;
 
 use super::JsonMap;
+use crate::config::SiteConfig;
 use crate::utils::date::parse_typst_datetime;
 
 /// Deserialize tags, treating `null` as empty vec
@@ -58,6 +59,11 @@ where
 /// | `tags`      | `Vec<String>`  | Categorization tags            |
 /// | `permalink` | `String`       | Custom URL path (overrides default) |
 /// | `aliases`   | `Vec<String>`  | Redirect URLs to this page     |
+/// | `page-number` | `u32`        | Page number of a paginated archive page |
+/// | `schema_type` | `String`     | JSON-LD `@type` override       |
+/// | `og_image`  | `String`       | Social preview image (`og:image`) |
+///
+/// `reading_time_minutes` is computed, not read from metadata.
 ///
 /// # Custom Fields (`extra`)
 ///
@@ -74,7 +80,6 @@ pub struct PageMeta {
     #[serde(default, deserialize_with = "deserialize_date")]
     pub date: Option<String>,
     #[serde(default, deserialize_with = "deserialize_date")]
-    #[allow(dead_code)] // Reserved for future use
     pub update: Option<String>,
     pub author: Option<String>,
     #[serde(default)]
@@ -104,6 +109,41 @@ pub struct PageMeta {
     /// self-contained styles to avoid relative path issues.
     #[serde(default = "default_true")]
     pub global_header: bool,
+    /// Estimated reading time in minutes.
+    ///
+    /// Computed from the rendered text when `build.meta.reading_time` is enabled.
+    #[serde(
+        default,
+        rename = "reading_time_minutes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub reading_time_minutes: Option<u32>,
+    /// 1-based page number for paginated archive pages.
+    ///
+    /// Exposed as `current-page` in `@tola/current` and used by `paginate()`.
+    #[serde(
+        default,
+        rename(serialize = "page_number"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub page_number: Option<u32>,
+    /// schema.org type for the page's JSON-LD (overrides `build.structured_data.schema`).
+    #[serde(
+        default,
+        rename = "schema_type",
+        alias = "schema-type",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub schema_type: Option<String>,
+    /// Social preview image: a site-root asset path (`/images/cover.png`)
+    /// or absolute URL. Overrides `site.info.og.default_image`.
+    #[serde(
+        default,
+        rename = "og_image",
+        alias = "og-image",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub og_image: Option<String>,
     /// Additional user-defined fields (raw JSON, Content preserved).
     #[serde(flatten, default)]
     pub extra: JsonMap,
@@ -113,6 +153,15 @@ fn default_true() -> bool {
     true
 }
 
+impl PageMeta {
+    /// Whether this page is a draft that should be left out of the site.
+    ///
+    /// Drafts are kept when `serve.drafts` preview is enabled.
+    pub fn is_hidden_draft(&self, config: &SiteConfig) -> bool {
+        self.draft && !config.serve.drafts.enable
+    }
+}
+
 impl Default for PageMeta {
     fn default() -> Self {
         Self {
@@ -126,6 +175,10 @@ impl Default for PageMeta {
             permalink: None,
             aliases: Vec::new(),
             global_header: true, // Default to true
+            reading_time_minutes: None,
+            page_number: None,
+            schema_type: None,
+            og_image: None,
             extra: JsonMap::new(),
         }
     }
@@ -162,6 +215,43 @@ mod tests {
         assert!(meta.tags.is_empty());
     }
 
+    #[test]
+    fn test_page_meta_page_number() {
+        let json = r#"{"title": "Archive", "page-number": 2}"#;
+        let meta: PageMeta = serde_json::from_str(json).unwrap();
+        assert_eq!(meta.page_number, Some(2));
+        assert!(meta.extra.is_empty());
+
+        let json: serde_json::Value = serde_json::to_value(&meta).unwrap();
+        assert_eq!(json["page_number"], 2);
+    }
+
+    #[test]
+    fn test_page_meta_schema_type() {
+        let json = r#"{"title": "Home", "schema_type": "WebSite"}"#;
+        let meta: PageMeta = serde_json::from_str(json).unwrap();
+        assert_eq!(meta.schema_type.as_deref(), Some("WebSite"));
+        assert!(meta.extra.is_empty());
+
+        let json = r#"{"schema-type": "BlogPosting"}"#;
+        let meta: PageMeta = serde_json::from_str(json).unwrap();
+        assert_eq!(meta.schema_type.as_deref(), Some("BlogPosting"));
+    }
+
+    #[test]
+    fn test_is_hidden_draft() {
+        let mut config = SiteConfig::default();
+        let draft = PageMeta {
+            draft: true,
+            ..Default::default()
+        };
+        assert!(draft.is_hidden_draft(&config));
+        assert!(!PageMeta::default().is_hidden_draft(&config));
+
+        config.serve.drafts.enable = true;
+        assert!(!draft.is_hidden_draft(&config));
+    }
+
     #[test]
     fn test_page_meta_permalink_not_serialized() {
         let meta = PageMeta {
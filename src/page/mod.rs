@@ -5,12 +5,14 @@ mod iteration;
 mod kind;
 mod links;
 mod meta;
+pub mod reading;
 mod resolve;
 mod route;
 mod state;
 mod store;
 
 pub use compiled::{CompiledPage, Pages};
+pub(crate) use compiled::{format_rfc3339, format_ymd};
 pub use iteration::{HashStabilityTracker, StabilityDecision};
 pub use kind::PageKind;
 pub use meta::PageMeta;
@@ -0,0 +1,77 @@
+//! Reading time estimation.
+//!
+//! Words are whitespace-separated runs of text. CJK characters carry meaning
+//! without spaces, so each one counts as a word on its own.
+
+use tola_vdom::prelude::*;
+
+/// Text inside `<body>`, or of the whole document if there is none.
+pub fn body_text<P: PhaseExt>(doc: &Document<P>) -> String {
+    let body = doc.root.children.iter().find_map(|n| match n {
+        Node::Element(e) if e.tag == "body" => Some(e.as_ref()),
+        _ => None,
+    });
+    body.unwrap_or(&doc.root).text_content()
+}
+
+/// Count words in plain text.
+pub fn count_words(text: &str) -> usize {
+    let mut words = 0;
+    let mut in_word = false;
+
+    for ch in text.chars() {
+        if is_cjk(ch) {
+            words += 1;
+            in_word = false;
+        } else if ch.is_whitespace() {
+            in_word = false;
+        } else if !in_word {
+            words += 1;
+            in_word = true;
+        }
+    }
+
+    words
+}
+
+/// Estimated reading time in whole minutes, rounded up.
+///
+/// Non-empty text always takes at least one minute. `wpm` of zero is
+/// treated as one word per minute to avoid dividing by zero.
+pub fn reading_time_minutes(text: &str, wpm: u32) -> u32 {
+    let words = u32::try_from(count_words(text)).unwrap_or(u32::MAX);
+    words.div_ceil(wpm.max(1))
+}
+
+/// CJK ideographs, kana and hangul syllables
+const fn is_cjk(ch: char) -> bool {
+    matches!(ch,
+        '\u{3040}'..='\u{30FF}'   // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}' // Hangul Syllables
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_words() {
+        assert_eq!(count_words(""), 0);
+        assert_eq!(count_words("  hello   world\n"), 2);
+        assert_eq!(count_words("你好世界"), 4);
+        assert_eq!(count_words("Rust 很好"), 3);
+    }
+
+    #[test]
+    fn test_reading_time_rounds_up() {
+        let text = "word ".repeat(201);
+        assert_eq!(reading_time_minutes(&text, 200), 2);
+        assert_eq!(reading_time_minutes("one", 200), 1);
+        assert_eq!(reading_time_minutes("", 200), 0);
+        assert_eq!(reading_time_minutes("a b", 0), 2);
+    }
+}
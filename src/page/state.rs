@@ -50,6 +50,9 @@ impl<'a> PageState<'a> {
         let links_to = self.pages_for_urls(&self.links_to(url));
         let linked_by = self.pages_for_urls(&self.linked_by(url));
         let headings = self.pages.get_headings(url);
+        let page = self.pages.get_page(url);
+        let reading_time = page.as_ref().and_then(|p| p.meta.reading_time_minutes);
+        let current_page = page.as_ref().and_then(|p| p.meta.page_number);
 
         serde_json::json!({
             TolaPackage::Current.input_key(): {
@@ -60,6 +63,8 @@ impl<'a> PageState<'a> {
                 "links_to": links_to,
                 "linked_by": linked_by,
                 "headings": headings,
+                "reading-time": reading_time,
+                "current-page": current_page,
             }
         })
     }
@@ -174,4 +179,48 @@ mod tests {
         assert_eq!(payload["links_to"][0]["permalink"], "/target/");
         assert_eq!(payload["linked_by"][0]["permalink"], "/source/");
     }
+
+    #[test]
+    fn current_context_exposes_reading_time() {
+        let pages = StoredPageMap::new();
+        let state = PageState::new(&pages);
+
+        let current = UrlPath::from_page("/current/");
+        let other = UrlPath::from_page("/other/");
+        pages.insert_page(
+            current.clone(),
+            PageMeta {
+                reading_time_minutes: Some(3),
+                ..Default::default()
+            },
+        );
+        pages.insert_page(other.clone(), PageMeta::default());
+
+        let context = state.build_current_context(&current, None);
+        let payload = &context[TolaPackage::Current.input_key()];
+        assert_eq!(payload["reading-time"], 3);
+
+        let context = state.build_current_context(&other, None);
+        let payload = &context[TolaPackage::Current.input_key()];
+        assert!(payload["reading-time"].is_null());
+    }
+
+    #[test]
+    fn current_context_exposes_page_number() {
+        let pages = StoredPageMap::new();
+        let state = PageState::new(&pages);
+
+        let archive = UrlPath::from_page("/blog/page/2/");
+        pages.insert_page(
+            archive.clone(),
+            PageMeta {
+                page_number: Some(2),
+                ..Default::default()
+            },
+        );
+
+        let context = state.build_current_context(&archive, None);
+        let payload = &context[TolaPackage::Current.input_key()];
+        assert_eq!(payload["current-page"], 2);
+    }
 }
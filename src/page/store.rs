@@ -154,6 +154,15 @@ impl StoredPageMap {
             .or_else(|| source_to_url.get(source).cloned())
     }
 
+    /// Permalink to source file path mapping (reverse of `get_permalink_by_source`).
+    pub fn sources_by_permalink(&self) -> FxHashMap<UrlPath, PathBuf> {
+        self.source_to_url
+            .read()
+            .iter()
+            .map(|(source, url)| (url.clone(), source.clone()))
+            .collect()
+    }
+
     /// Keep source->permalink mapping consistent.
     ///
     /// If an existing mapping points to a different permalink, the old stored page is removed.
@@ -190,6 +199,11 @@ impl StoredPageMap {
         Some(permalink)
     }
 
+    /// Get a single page by permalink.
+    pub fn get_page(&self, url: &UrlPath) -> Option<StoredPage> {
+        self.pages.read().get(url).cloned()
+    }
+
     /// Get headings for a page.
     pub fn get_headings(&self, url: &UrlPath) -> Vec<ScannedHeading> {
         self.headings.read().get(url).cloned().unwrap_or_default()
@@ -30,7 +30,9 @@ use crate::compiler::CompileContext;
 use crate::compiler::family::{IndexedDocument, Raw, TolaSite};
 use crate::compiler::page::PageRoute;
 
-pub use transform::{BodyInjector, HeaderInjector, LinkTransform, MediaTransform, SvgTransform};
+pub use transform::{
+    BodyInjector, CodeTransform, HeaderInjector, LinkTransform, MediaTransform, SvgTransform,
+};
 
 // =============================================================================
 // Types
@@ -76,10 +78,17 @@ pub fn compile(doc: Document<Raw>, ctx: &CompileContext<'_>) -> CompileOutput {
 
     // Build pipeline (sync transforms only, no validation)
     let indexed = Pipeline::new(doc)
-        .pipe(HeaderInjector::new(ctx.config).with_global_header(ctx.global_header))
+        .pipe(
+            HeaderInjector::new(ctx.config)
+                .with_global_header(ctx.global_header)
+                .with_page_meta(ctx.page_meta)
+                .with_route(ctx.route)
+                .with_store(ctx.store),
+        )
         .pipe(indexer)
         .pipe(LinkTransform::new(ctx.config, route))
         .pipe(MediaTransform::new(ctx.config, route))
+        .pipe(CodeTransform::new(ctx.config))
         // Transforms that affect diff/hotreload must be placed BEFORE this line
         .inspect_if(ctx.mode.cache_vdom, |doc| {
             indexed_cache = Some(doc.clone());
@@ -109,7 +118,7 @@ pub fn compile(doc: Document<Raw>, ctx: &CompileContext<'_>) -> CompileOutput {
 /// Compile a Raw VDOM document to Indexed phase only
 ///
 /// This is a **lightweight** pipeline that stops at the Indexed phase,
-/// skipping LinkTransform, MediaTransform, Processor, and HTML rendering
+/// skipping LinkTransform, MediaTransform, CodeTransform, Processor, and HTML rendering
 ///
 /// Use this for:
 /// - **Validation**: Extract links/assets without full rendering
@@ -126,7 +135,13 @@ pub fn compile_for_scan(doc: Document<Raw>, ctx: &CompileContext<'_>) -> Indexed
     };
 
     Pipeline::new(doc)
-        .pipe(HeaderInjector::new(ctx.config).with_global_header(ctx.global_header))
+        .pipe(
+            HeaderInjector::new(ctx.config)
+                .with_global_header(ctx.global_header)
+                .with_page_meta(ctx.page_meta)
+                .with_route(ctx.route)
+                .with_store(ctx.store),
+        )
         .pipe(indexer)
         .into_inner()
 }
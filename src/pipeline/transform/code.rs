@@ -0,0 +1,252 @@
+//! Code block highlighter (Indexed -> Indexed).
+//!
+//! Highlights `<pre>`/`<code>` elements with a `language-*` class using
+//! syntect (`build.code`):
+//! - `inline_css = true`: colors as inline `style` attributes
+//! - `inline_css = false`: `hl-*` classes, styled by the generated
+//!   `code.css` linked from `HeaderInjector`
+//!
+//! Typst raw blocks (`language-typst`) are left alone, Typst already
+//! highlights them. So are blocks whose language is unknown or that already
+//! contain markup.
+
+use std::sync::LazyLock;
+
+use syntect::highlighting::{Color, Theme, ThemeSet};
+use syntect::html::{
+    ClassStyle, ClassedHTMLGenerator, IncludeBackground, css_for_theme_with_class_style,
+    styled_line_to_highlighted_html,
+};
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+use syntect::util::LinesWithEndings;
+use tola_vdom::prelude::*;
+
+use crate::compiler::family::Indexed;
+use crate::config::SiteConfig;
+
+/// Built-in syntax definitions
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+/// Built-in color themes
+static THEMES: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+/// Class prefix for class-based output (`hl-keyword`, ...)
+const CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "hl-" };
+
+/// Container class matching the theme's base colors in the stylesheet
+const CLASS_CODE: &str = "hl-code";
+
+const LANGUAGE_PREFIX: &str = "language-";
+
+/// Languages already highlighted by Typst
+const TYPST_LANGUAGES: &[&str] = &["typst", "typ", "typc"];
+
+/// Whether `name` is a built-in theme.
+pub fn has_theme(name: &str) -> bool {
+    THEMES.themes.contains_key(name)
+}
+
+/// Built-in theme names, sorted.
+pub fn theme_names() -> Vec<&'static str> {
+    THEMES.themes.keys().map(String::as_str).collect()
+}
+
+/// Stylesheet for class-based output, empty for unknown themes.
+pub fn theme_css(name: &str) -> String {
+    THEMES
+        .themes
+        .get(name)
+        .and_then(|theme| css_for_theme_with_class_style(theme, CLASS_STYLE).ok())
+        .unwrap_or_default()
+}
+
+/// Highlights fenced code blocks in Indexed VDOM
+pub struct CodeTransform<'a> {
+    config: &'a SiteConfig,
+}
+
+impl<'a> CodeTransform<'a> {
+    pub fn new(config: &'a SiteConfig) -> Self {
+        Self { config }
+    }
+
+    /// Highlight one code element in place.
+    fn highlight(&self, elem: &mut Element<Indexed>, theme: &Theme) {
+        let Some(syntax) = code_language(elem).and_then(|lang| SYNTAXES.find_syntax_by_token(lang))
+        else {
+            return;
+        };
+
+        let code = elem.text_content();
+        let inline_css = self.config.build.code.inline_css;
+        let html = if inline_css {
+            highlight_inline(&code, syntax, theme)
+        } else {
+            highlight_classed(&code, syntax)
+        };
+        let Some(html) = html else { return };
+
+        elem.children.clear();
+        elem.push(Node::Text(Text::raw(html)));
+
+        if inline_css {
+            append_style(elem, &base_style(theme));
+        } else {
+            elem.add_class(CLASS_CODE);
+        }
+    }
+}
+
+impl Transform<Indexed> for CodeTransform<'_> {
+    type To = Indexed;
+
+    fn transform(self, mut doc: Document<Indexed>) -> Document<Indexed> {
+        let code = &self.config.build.code;
+        if !code.is_enabled() {
+            return doc;
+        }
+        let Some(theme) = THEMES.themes.get(&code.theme) else {
+            return doc;
+        };
+
+        process_code_blocks(&mut doc.root, &self, theme);
+        doc
+    }
+}
+
+/// Recursively highlight `<pre>`/`<code>` elements with a language class
+fn process_code_blocks(elem: &mut Element<Indexed>, transform: &CodeTransform<'_>, theme: &Theme) {
+    if (elem.is_tag("pre") || elem.is_tag("code")) && code_language(elem).is_some() {
+        transform.highlight(elem, theme);
+        return;
+    }
+
+    for child in &mut elem.children {
+        if let Node::Element(child_elem) = child {
+            process_code_blocks(child_elem, transform, theme);
+        }
+    }
+}
+
+/// Language of a plain-text code element, `None` for Typst or existing markup
+fn code_language(elem: &Element<Indexed>) -> Option<&str> {
+    if elem.children.iter().any(|n| matches!(n, Node::Element(_))) {
+        return None;
+    }
+    let lang = elem
+        .get_attr("class")?
+        .split_whitespace()
+        .find_map(|class| class.strip_prefix(LANGUAGE_PREFIX))?;
+    (!TYPST_LANGUAGES.contains(&lang.to_ascii_lowercase().as_str())).then_some(lang)
+}
+
+fn highlight_inline(code: &str, syntax: &SyntaxReference, theme: &Theme) -> Option<String> {
+    let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
+    let mut html = String::with_capacity(code.len() * 2);
+    for line in LinesWithEndings::from(code) {
+        let regions = highlighter.highlight_line(line, &SYNTAXES).ok()?;
+        html.push_str(&styled_line_to_highlighted_html(&regions, IncludeBackground::No).ok()?);
+    }
+    Some(html)
+}
+
+fn highlight_classed(code: &str, syntax: &SyntaxReference) -> Option<String> {
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(syntax, &SYNTAXES, CLASS_STYLE);
+    for line in LinesWithEndings::from(code) {
+        generator
+            .parse_html_for_line_which_includes_newline(line)
+            .ok()?;
+    }
+    Some(generator.finalize())
+}
+
+/// Theme background and foreground as inline CSS
+fn base_style(theme: &Theme) -> String {
+    let mut style = String::new();
+    if let Some(color) = theme.settings.background {
+        style.push_str(&format!("background-color:{};", hex(color)));
+    }
+    if let Some(color) = theme.settings.foreground {
+        style.push_str(&format!("color:{};", hex(color)));
+    }
+    style
+}
+
+fn hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+fn append_style(elem: &mut Element<Indexed>, style: &str) {
+    if style.is_empty() {
+        return;
+    }
+    let merged = match elem.get_attr("style") {
+        Some(existing) if !existing.trim().is_empty() => {
+            format!("{};{style}", existing.trim_end().trim_end_matches(';'))
+        }
+        _ => style.to_string(),
+    };
+    elem.set_attr("style", merged);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compiler::family::TolaSite;
+
+    fn code_block(class: &str, code: &str) -> Document<Indexed> {
+        let mut pre = TolaSite::element("pre", Attrs::from([("class", class)]));
+        pre.push_text(code);
+        let mut body = TolaSite::element("body", Attrs::new());
+        body.push_elem(pre);
+        TolaSite::indexer().transform(Document::new(body))
+    }
+
+    fn first_child(doc: &Document<Indexed>) -> &Element<Indexed> {
+        match &doc.root.children[0] {
+            Node::Element(elem) => elem,
+            _ => panic!("expected element"),
+        }
+    }
+
+    #[test]
+    fn test_inline_highlight() {
+        let config = SiteConfig::default();
+        let doc =
+            CodeTransform::new(&config).transform(code_block("language-rust", "fn main() {}\n"));
+        let pre = first_child(&doc);
+
+        assert!(
+            pre.get_attr("style")
+                .unwrap()
+                .contains("background-color:#")
+        );
+        let html = pre.text_content();
+        assert!(html.contains("<span style=\"color:"));
+        assert!(html.contains("main"));
+    }
+
+    #[test]
+    fn test_classed_highlight() {
+        let mut config = SiteConfig::default();
+        config.build.code.inline_css = false;
+        let doc =
+            CodeTransform::new(&config).transform(code_block("language-rust", "let x = 1;\n"));
+        let pre = first_child(&doc);
+
+        assert!(pre.has_class(CLASS_CODE));
+        assert!(pre.text_content().contains("class=\"hl-"));
+        assert!(theme_css(&config.build.code.theme).contains(".hl-"));
+    }
+
+    #[test]
+    fn test_skipped_blocks() {
+        let config = SiteConfig::default();
+        for class in ["language-typst", "language-no-such-lang", "plain"] {
+            let doc = CodeTransform::new(&config).transform(code_block(class, "#let x = 1"));
+            let pre = first_child(&doc);
+            assert_eq!(pre.text_content(), "#let x = 1");
+            assert_eq!(pre.get_attr("style"), None);
+        }
+    }
+}
@@ -3,16 +3,19 @@
 //! Injects site-wide `<head>` content from config into Raw VDOM before indexing.
 //! Also sets `lang` attribute on `<html>` root if not present.
 //!
-//! Injected elements: title, description meta, icon link, stylesheets, scripts,
-//! CSS processor output, auto-enhance CSS, and raw HTML elements.
+//! Injected elements: title, description meta, feed alternate links, `hreflang` alternate links,
+//! icon link, preload hints, stylesheets, scripts, CSS processor output, auto-enhance CSS, syntax highlighting CSS, raw HTML
+//! elements, and JSON-LD structured data (when `build.structured_data` is enabled).
 
 use std::path::Path;
 
 use tola_vdom::prelude::*;
 
-use crate::asset::{compute_asset_href, version};
+use crate::asset::{compute_asset_href, hashed, version};
 use crate::compiler::family::{Raw, TolaSite};
+use crate::compiler::page::PageRoute;
 use crate::config::SiteConfig;
+use crate::page::{PageMeta, StoredPageMap};
 use crate::utils::mime;
 
 /// Injects site-wide `<head>` content into Raw VDOM
@@ -22,6 +25,15 @@ pub struct HeaderInjector<'a> {
     /// Default: `true`. Set to `false` for pages like 404 that need
     /// self-contained styles to avoid relative path issues.
     global_header: bool,
+    /// Page title from metadata for `site.info.title_template`.
+    /// Falls back to the first `<h1>` when not set.
+    page_title: Option<&'a str>,
+    /// Page metadata for JSON-LD structured data.
+    page_meta: Option<&'a PageMeta>,
+    /// Page route, providing the JSON-LD `url`.
+    route: Option<&'a PageRoute>,
+    /// Page store, for the `hreflang` counterparts of the page.
+    store: Option<&'a StoredPageMap>,
 }
 
 /// Compute versioned href for an asset (with ?v=hash for cache busting)
@@ -31,11 +43,83 @@ fn versioned_href(path: &Path, config: &SiteConfig) -> Option<String> {
     Some(version::versioned_url(&href, &abs_path))
 }
 
+/// Compute href for a header style or script
+///
+/// Uses the content-hash filename written by asset processing, falling back
+/// to a versioned href under `tola serve`.
+fn header_asset_href(path: &Path, config: &SiteConfig) -> Option<String> {
+    if !hashed::uses_hashed_names(config) {
+        return versioned_href(path, config);
+    }
+    let href = compute_asset_href(path, config).ok()?;
+    let abs_path = config.get_root().join(path);
+    hashed::hashed_href(&href, &abs_path)
+}
+
+/// First `src` URL of every `@font-face` rule in a stylesheet
+///
+/// Browsers use the first supported source, which is the one worth preloading.
+fn font_face_urls(css: &str) -> Vec<String> {
+    let mut urls = Vec::new();
+    let mut rest = css;
+    while let Some(start) = rest.find("@font-face") {
+        let rule = &rest[start..];
+        let (Some(open), Some(close)) = (rule.find('{'), rule.find('}')) else {
+            break;
+        };
+        if close > open
+            && let Some(url) = first_src_url(&rule[open + 1..close])
+        {
+            urls.push(url);
+        }
+        rest = &rule[close.max(open) + 1..];
+    }
+    urls
+}
+
+/// First `url(...)` of the `src` declaration in a `@font-face` body
+fn first_src_url(body: &str) -> Option<String> {
+    let src = body.split(';').find_map(|decl| {
+        let (name, value) = decl.split_once(':')?;
+        (name.trim() == "src").then_some(value)
+    })?;
+    let start = src.find("url(")? + "url(".len();
+    let end = start + src[start..].find(')')?;
+    let url = src[start..end].trim().trim_matches(['"', '\'']);
+    (!url.is_empty() && !url.starts_with("data:")).then(|| url.to_string())
+}
+
+/// Resolve a stylesheet-relative URL against the stylesheet's href
+fn resolve_css_url(base_href: &str, url: &str) -> String {
+    if url.starts_with('/') || url.contains("://") {
+        return url.to_string();
+    }
+    let base = base_href.split(['?', '#']).next().unwrap_or(base_href);
+    let dir = base.rsplit_once('/').map_or("", |(dir, _)| dir);
+    let mut segments: Vec<&str> = dir.split('/').collect();
+    for part in url.split('/') {
+        match part {
+            "." => {}
+            ".." => {
+                if segments.len() > 1 {
+                    segments.pop();
+                }
+            }
+            _ => segments.push(part),
+        }
+    }
+    segments.join("/")
+}
+
 impl<'a> HeaderInjector<'a> {
     pub fn new(config: &'a SiteConfig) -> Self {
         Self {
             config,
             global_header: true,
+            page_title: None,
+            page_meta: None,
+            route: None,
+            store: None,
         }
     }
 
@@ -45,22 +129,65 @@ impl<'a> HeaderInjector<'a> {
         self
     }
 
+    /// Set page metadata (title for `site.info.title_template`, plus JSON-LD fields).
+    pub fn with_page_meta(mut self, page_meta: Option<&'a PageMeta>) -> Self {
+        self.page_title = page_meta.and_then(|m| m.title.as_deref());
+        self.page_meta = page_meta;
+        self
+    }
+
+    /// Set page route (used for the JSON-LD `url` and `hreflang` alternates).
+    pub fn with_route(mut self, route: Option<&'a PageRoute>) -> Self {
+        self.route = route;
+        self
+    }
+
+    /// Set the page store (used to find `hreflang` counterparts).
+    pub fn with_store(mut self, store: &'a StoredPageMap) -> Self {
+        self.store = Some(store);
+        self
+    }
+
     /// Recursively find and populate `<head>` element.
-    fn inject_head(&self, element: &mut Element<Raw>) {
+    fn inject_head(
+        &self,
+        element: &mut Element<Raw>,
+        page_title: Option<&str>,
+        headline: Option<&str>,
+    ) {
         if element.tag == "head" {
-            self.populate_head(element);
+            self.populate_head(element, page_title, headline);
             return;
         }
 
         for child in &mut element.children {
             if let Node::Element(elem) = child {
-                self.inject_head(elem);
+                self.inject_head(elem, page_title, headline);
             }
         }
     }
 
+    /// Find text of the first `<h1>` in document order.
+    fn first_h1_text(element: &Element<Raw>) -> Option<String> {
+        if element.tag == "h1" {
+            let text = element.text_content();
+            let text = text.trim();
+            return (!text.is_empty()).then(|| text.to_string());
+        }
+
+        element.children.iter().find_map(|child| match child {
+            Node::Element(elem) => Self::first_h1_text(elem),
+            _ => None,
+        })
+    }
+
     /// Populate `<head>` with site configuration content.
-    fn populate_head(&self, head: &mut Element<Raw>) {
+    fn populate_head(
+        &self,
+        head: &mut Element<Raw>,
+        page_title: Option<&str>,
+        headline: Option<&str>,
+    ) {
         let config = self.config;
         let head_config = &config.site.header;
         let existing_len = head.children.len();
@@ -73,10 +200,13 @@ impl<'a> HeaderInjector<'a> {
         }
 
         // Title (skip if user already defined one)
-        if !config.site.info.title.is_empty() && !Self::has_tag(head, "title") {
-            let mut title = TolaSite::element("title", Attrs::new());
-            title.push_text(&config.site.info.title);
-            head.push_elem(title);
+        if !Self::has_tag(head, "title") {
+            let text = config.site.info.render_title(page_title);
+            if !text.is_empty() {
+                let mut title = TolaSite::element("title", Attrs::new());
+                title.push_text(&text);
+                head.push_elem(title);
+            }
         }
 
         // Description meta (skip if user already defined one)
@@ -87,6 +217,30 @@ impl<'a> HeaderInjector<'a> {
             head.push_elem(TolaSite::element("meta", attrs));
         }
 
+        // Feed discovery links, one per configured format
+        if config.site.seo.feed.enable {
+            let feed = &config.site.seo.feed;
+            for format in feed.formats() {
+                if Self::has_alternate(head, format.mime_type()) {
+                    continue;
+                }
+                let mut attrs = Attrs::new();
+                attrs.set("rel", "alternate");
+                attrs.set("type", format.mime_type());
+                attrs.set(
+                    "href",
+                    config.paths().url_for_rel_path(feed.output_path(format)),
+                );
+                if !config.site.info.title.is_empty() {
+                    attrs.set("title", &config.site.info.title);
+                }
+                head.push_elem(TolaSite::element("link", attrs));
+            }
+        }
+
+        // Language versions of the page (`[site.i18n]`)
+        self.inject_hreflang(head);
+
         // Icon
         if let Some(icon) = &head_config.icon
             && let Some(href) = versioned_href(icon, config)
@@ -98,9 +252,12 @@ impl<'a> HeaderInjector<'a> {
             head.push_elem(TolaSite::element("link", attrs));
         }
 
+        // Preload hints ahead of the stylesheets that need them
+        self.inject_preloads(head);
+
         // User-defined stylesheets
-        for style in &head_config.styles {
-            if let Some(href) = versioned_href(style, config) {
+        for style in head_config.expanded_styles(config.get_root()) {
+            if let Some(href) = header_asset_href(&style, config) {
                 let mut attrs = Attrs::new();
                 attrs.set("rel", "stylesheet");
                 attrs.set("href", href);
@@ -133,6 +290,16 @@ impl<'a> HeaderInjector<'a> {
             head.push_elem(TolaSite::element("link", attrs));
         }
 
+        // Syntax highlighting CSS (class-based output only)
+        if config.build.code.needs_stylesheet() {
+            use crate::embed::css::{CODE_CSS, code_vars};
+            let href = CODE_CSS.url_path_with_vars(&config.build.path_prefix, &code_vars(config));
+            let mut attrs = Attrs::new();
+            attrs.set("rel", "stylesheet");
+            attrs.set("href", href);
+            head.push_elem(TolaSite::element("link", attrs));
+        }
+
         // Recolor CSS + JS (if enabled)
         if config.theme.recolor.enable {
             use crate::config::section::theme::RecolorSource;
@@ -160,8 +327,8 @@ impl<'a> HeaderInjector<'a> {
         }
 
         // Scripts
-        for script in &head_config.scripts {
-            if let Some(src) = versioned_href(script.path(), config) {
+        for script in head_config.expanded_scripts(config.get_root()) {
+            if let Some(src) = header_asset_href(script.path(), config) {
                 let mut attrs = Attrs::new();
                 attrs.set("src", src);
                 if script.is_defer() {
@@ -186,6 +353,11 @@ impl<'a> HeaderInjector<'a> {
             self.inject_og_defaults(head);
         }
 
+        // JSON-LD structured data (if enabled and not user-defined)
+        if config.build.structured_data.enable && !Self::has_json_ld(head) {
+            self.inject_json_ld(head, headline);
+        }
+
         // Keep all injected nodes ahead of user-defined head nodes.
         let injected_len = head.children.len().saturating_sub(existing_len);
         if existing_len > 0 && injected_len > 0 {
@@ -193,6 +365,84 @@ impl<'a> HeaderInjector<'a> {
         }
     }
 
+    /// Inject `<link rel="preload">` hints from `site.header.preload`.
+    ///
+    /// Images that do not exist are skipped (config validation warns).
+    fn inject_preloads(&self, head: &mut Element<Raw>) {
+        let config = self.config;
+        let head_config = &config.site.header;
+        let preload = &head_config.preload;
+
+        let mut fonts: Vec<String> = Vec::new();
+        for style in head_config.expanded_styles(config.get_root()) {
+            let Some(href) = header_asset_href(&style, config) else {
+                continue;
+            };
+            if preload.fonts
+                && let Ok(css) = std::fs::read_to_string(config.root_join(&style))
+            {
+                for url in font_face_urls(&css) {
+                    let url = resolve_css_url(&href, &url);
+                    if !fonts.contains(&url) {
+                        fonts.push(url);
+                    }
+                }
+            }
+            if preload.styles {
+                head.push_elem(Self::preload_link(href, "style"));
+            }
+        }
+
+        for font in fonts {
+            let mut link = Self::preload_link(font, "font");
+            link.set_attr("crossorigin", "anonymous");
+            head.push_elem(link);
+        }
+
+        for image in &preload.images {
+            if !config.root_join(image).is_file() {
+                continue;
+            }
+            if let Some(href) = versioned_href(image, config) {
+                head.push_elem(Self::preload_link(href, "image"));
+            }
+        }
+    }
+
+    /// Inject `<link rel="alternate" hreflang>` for each language version.
+    ///
+    /// Skipped when the page already declares any `hreflang` link.
+    fn inject_hreflang(&self, head: &mut Element<Raw>) {
+        use crate::seo::{absolute_url, hreflang};
+
+        let (Some(route), Some(store)) = (self.route, self.store) else {
+            return;
+        };
+        if Self::has_hreflang(head) {
+            return;
+        }
+
+        for alternate in hreflang::page_alternates(self.config, store, &route.permalink) {
+            let mut attrs = Attrs::new();
+            attrs.set("rel", "alternate");
+            attrs.set("hreflang", alternate.hreflang);
+            attrs.set(
+                "href",
+                absolute_url(self.config, alternate.permalink.as_str()),
+            );
+            head.push_elem(TolaSite::element("link", attrs));
+        }
+    }
+
+    /// Create a `<link rel="preload">` element.
+    fn preload_link(href: impl Into<String>, kind: &str) -> Element<Raw> {
+        let mut attrs = Attrs::new();
+        attrs.set("rel", "preload");
+        attrs.set("href", href.into());
+        attrs.set("as", kind);
+        TolaSite::element("link", attrs)
+    }
+
     /// Check if head already contains a specific tag.
     fn has_tag(head: &Element<Raw>, tag: &str) -> bool {
         head.children
@@ -207,6 +457,20 @@ impl<'a> HeaderInjector<'a> {
         })
     }
 
+    /// Check if head already contains an alternate link of the given type.
+    fn has_alternate(head: &Element<Raw>, mime_type: &str) -> bool {
+        head.children.iter().any(|n| {
+            matches!(n, Node::Element(e) if e.tag == "link" && e.get_attr("rel").is_some_and(|v| v == "alternate") && e.get_attr("type").is_some_and(|v| v == mime_type))
+        })
+    }
+
+    /// Check if head already contains a `<link hreflang>`.
+    fn has_hreflang(head: &Element<Raw>) -> bool {
+        head.children
+            .iter()
+            .any(|n| matches!(n, Node::Element(e) if e.tag == "link" && e.has_attr("hreflang")))
+    }
+
     /// Check if head already contains OG tags (user-defined via Typst head parameter).
     fn has_og_tags(head: &Element<Raw>) -> bool {
         head.children.iter().any(|n| {
@@ -214,11 +478,38 @@ impl<'a> HeaderInjector<'a> {
         })
     }
 
+    /// Check if head already contains a JSON-LD script.
+    fn has_json_ld(head: &Element<Raw>) -> bool {
+        head.children.iter().any(|n| {
+            matches!(n, Node::Element(e) if e.tag == "script" && e.get_attr("type").is_some_and(|v| v == "application/ld+json"))
+        })
+    }
+
+    /// Inject a JSON-LD `<script>` composed from page metadata.
+    fn inject_json_ld(&self, head: &mut Element<Raw>, headline: Option<&str>) {
+        use crate::seo::jsonld::StructuredData;
+
+        let url = self.route.map(|route| {
+            if route.full_url.is_empty() {
+                route.permalink.as_str()
+            } else {
+                route.full_url.as_str()
+            }
+        });
+        let data = StructuredData::new(self.config, self.page_meta, headline, url);
+
+        let mut attrs = Attrs::new();
+        attrs.set("type", "application/ld+json");
+        let mut script = TolaSite::element("script", attrs);
+        script.push(Node::Text(Text::raw(data.to_script_json())));
+        head.push_elem(script);
+    }
+
     /// Inject default Open Graph and Twitter Card meta tags.
     fn inject_og_defaults(&self, head: &mut Element<Raw>) {
         use crate::seo::og::OgDefaults;
 
-        let og = OgDefaults::from_config(self.config);
+        let og = OgDefaults::from_config(self.config, self.page_meta);
 
         // og:type
         head.push_elem(Self::meta_property("og:type", og.og_type));
@@ -238,8 +529,23 @@ impl<'a> HeaderInjector<'a> {
             head.push_elem(Self::meta_property("og:description", og.description));
         }
 
+        // og:image (page `og_image`, then `site.info.og.default_image`)
+        if let Some(image) = &og.image {
+            head.push_elem(Self::meta_property("og:image", image));
+        }
+
         // twitter:card
         head.push_elem(Self::meta_name("twitter:card", og.twitter_card));
+
+        // twitter:image
+        if let Some(image) = &og.image {
+            head.push_elem(Self::meta_name("twitter:image", image));
+        }
+
+        // twitter:site
+        if let Some(site) = og.twitter_site {
+            head.push_elem(Self::meta_name("twitter:site", site));
+        }
     }
 
     /// Create a meta element with property attribute.
@@ -272,7 +578,21 @@ impl<'a> Transform<Raw> for HeaderInjector<'a> {
         // Skip head injection if global_header is false
         // (e.g., 404 pages that need self-contained styles)
         if self.global_header {
-            self.inject_head(&mut doc.root);
+            // Only resolve page title when a template will use it
+            let page_title = match self.config.site.info.title_template {
+                Some(_) => self
+                    .page_title
+                    .map(str::to_string)
+                    .or_else(|| Self::first_h1_text(&doc.root)),
+                None => None,
+            };
+            // Headline for JSON-LD: first <h1>, then metadata title
+            let headline = if self.config.build.structured_data.enable {
+                Self::first_h1_text(&doc.root).or_else(|| self.page_title.map(str::to_string))
+            } else {
+                None
+            };
+            self.inject_head(&mut doc.root, page_title.as_deref(), headline.as_deref());
         }
 
         doc
@@ -323,6 +643,350 @@ mod tests {
         assert!(has_title, "should have title element");
     }
 
+    fn head_title(doc: &Document<Raw>) -> Option<String> {
+        let head = doc.root.children.iter().find_map(|n| match n {
+            Node::Element(e) if e.tag == "head" => Some(e.as_ref()),
+            _ => None,
+        })?;
+        head.children.iter().find_map(|n| match n {
+            Node::Element(e) if e.tag == "title" => Some(e.text_content()),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_title_template_uses_page_title() {
+        let mut config = SiteConfig::default();
+        config.site.info.title = "Test Site".to_string();
+        config.site.info.title_template = Some("{{ page.title }} | {{ site.title }}".into());
+
+        let meta = PageMeta {
+            title: Some("Hello".into()),
+            ..Default::default()
+        };
+        let doc = HeaderInjector::new(&config)
+            .with_page_meta(Some(&meta))
+            .transform(make_html_doc());
+        assert_eq!(head_title(&doc).as_deref(), Some("Hello | Test Site"));
+
+        // No page title and no <h1>: plain site title
+        let doc = HeaderInjector::new(&config).transform(make_html_doc());
+        assert_eq!(head_title(&doc).as_deref(), Some("Test Site"));
+    }
+
+    #[test]
+    fn test_title_template_falls_back_to_first_h1() {
+        let mut config = SiteConfig::default();
+        config.site.info.title = "Test Site".to_string();
+        config.site.info.title_template = Some("{{ page.title }} | {{ site.title }}".into());
+
+        let mut doc = make_html_doc();
+        if let Some(Node::Element(body)) = doc.root.children.get_mut(1) {
+            let mut h1 = TolaSite::element("h1", Attrs::new());
+            h1.push_text("From Heading");
+            body.push_elem(h1);
+        }
+
+        let doc = HeaderInjector::new(&config).transform(doc);
+        assert_eq!(
+            head_title(&doc).as_deref(),
+            Some("From Heading | Test Site")
+        );
+    }
+
+    fn head_json_ld(doc: &Document<Raw>) -> Option<serde_json::Value> {
+        let head = doc.root.children.iter().find_map(|n| match n {
+            Node::Element(e) if e.tag == "head" => Some(e.as_ref()),
+            _ => None,
+        })?;
+        head.children.iter().find_map(|n| match n {
+            Node::Element(e)
+                if e.tag == "script" && e.get_attr("type") == Some("application/ld+json") =>
+            {
+                serde_json::from_str(&e.text_content()).ok()
+            }
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_json_ld_injection() {
+        let mut config = SiteConfig::default();
+        config.site.info.author = "Alice".to_string();
+
+        let mut doc = make_html_doc();
+        if let Some(Node::Element(body)) = doc.root.children.get_mut(1) {
+            let mut h1 = TolaSite::element("h1", Attrs::new());
+            h1.push_text("First Post");
+            body.push_elem(h1);
+        }
+        let meta = PageMeta {
+            date: Some("2024-05-01".into()),
+            schema_type: Some("BlogPosting".into()),
+            ..Default::default()
+        };
+        let route = PageRoute {
+            full_url: "https://example.com/posts/first/".into(),
+            ..Default::default()
+        };
+
+        // Disabled by default
+        let disabled = HeaderInjector::new(&config)
+            .with_page_meta(Some(&meta))
+            .transform(doc.clone());
+        assert!(head_json_ld(&disabled).is_none());
+
+        config.build.structured_data.enable = true;
+        let doc = HeaderInjector::new(&config)
+            .with_page_meta(Some(&meta))
+            .with_route(Some(&route))
+            .transform(doc);
+        let json = head_json_ld(&doc).expect("should inject JSON-LD");
+
+        assert_eq!(json["@type"], "BlogPosting");
+        assert_eq!(json["headline"], "First Post");
+        assert_eq!(json["author"]["name"], "Alice");
+        assert_eq!(json["datePublished"], "2024-05-01");
+        assert_eq!(json["url"], "https://example.com/posts/first/");
+    }
+
+    #[test]
+    fn test_inject_hreflang_alternates() {
+        use crate::core::UrlPath;
+
+        let mut config = SiteConfig::default();
+        config.site.info.url = Some("https://example.com".into());
+        config.site.i18n.locales = vec!["en".into(), "de".into()];
+
+        let store = StoredPageMap::new();
+        for permalink in ["/posts/hello/", "/de/posts/hello/", "/posts/solo/"] {
+            store.insert_page(UrlPath::from_page(permalink), PageMeta::default());
+        }
+        let hreflang_links = |config: &SiteConfig, permalink: &str| {
+            let route = PageRoute {
+                permalink: UrlPath::from_page(permalink),
+                ..Default::default()
+            };
+            let doc = HeaderInjector::new(config)
+                .with_route(Some(&route))
+                .with_store(&store)
+                .transform(make_html_doc());
+            let Some(Node::Element(head)) = doc.root.children.first() else {
+                panic!("should have head");
+            };
+            head.children
+                .iter()
+                .filter_map(|n| match n {
+                    Node::Element(e) if e.has_attr("hreflang") => Some(format!(
+                        "{} {}",
+                        e.get_attr("hreflang").unwrap_or_default(),
+                        e.get_attr("href").unwrap_or_default()
+                    )),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            hreflang_links(&config, "/de/posts/hello/"),
+            [
+                "en https://example.com/posts/hello/",
+                "de https://example.com/de/posts/hello/",
+                "x-default https://example.com/posts/hello/",
+            ]
+        );
+        assert_eq!(
+            hreflang_links(&config, "/posts/solo/"),
+            ["x-default https://example.com/posts/solo/"]
+        );
+
+        // Single-language sites get no alternates
+        config.site.i18n.locales.truncate(1);
+        assert!(hreflang_links(&config, "/posts/hello/").is_empty());
+    }
+
+    fn head_meta<'d>(doc: &'d Document<Raw>, attr: &str, key: &str) -> Option<&'d str> {
+        let head = doc.root.children.iter().find_map(|n| match n {
+            Node::Element(e) if e.tag == "head" => Some(e.as_ref()),
+            _ => None,
+        })?;
+        head.children.iter().find_map(|n| match n {
+            Node::Element(e) if e.tag == "meta" && e.get_attr(attr) == Some(key) => {
+                e.get_attr("content")
+            }
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_og_image_prefers_page_meta() {
+        let mut config = SiteConfig::default();
+        config.site.info.url = Some("https://example.com".into());
+        config.site.info.og.default_image = Some("/images/default.png".into());
+        config.site.info.og.twitter_site = Some("@tola".into());
+
+        let doc = HeaderInjector::new(&config).transform(make_html_doc());
+        assert_eq!(
+            head_meta(&doc, "property", "og:image"),
+            Some("https://example.com/images/default.png")
+        );
+        assert_eq!(head_meta(&doc, "name", "twitter:site"), Some("@tola"));
+
+        let meta = PageMeta {
+            og_image: Some("/images/post.png".into()),
+            ..Default::default()
+        };
+        let doc = HeaderInjector::new(&config)
+            .with_page_meta(Some(&meta))
+            .transform(make_html_doc());
+        assert_eq!(
+            head_meta(&doc, "property", "og:image"),
+            Some("https://example.com/images/post.png")
+        );
+        assert_eq!(
+            head_meta(&doc, "name", "twitter:image"),
+            Some("https://example.com/images/post.png")
+        );
+    }
+
+    #[test]
+    fn test_feed_alternate_links() {
+        use crate::config::FeedFormat;
+
+        let mut config = SiteConfig::default();
+        config.site.seo.feed.enable = true;
+        config.site.seo.feed.format = vec![FeedFormat::Rss, FeedFormat::Atom];
+
+        let doc = HeaderInjector::new(&config).transform(make_html_doc());
+        let head = doc
+            .root
+            .children
+            .iter()
+            .find_map(|n| match n {
+                Node::Element(e) if e.tag == "head" => Some(e.as_ref()),
+                _ => None,
+            })
+            .expect("should have head");
+        let alternates: Vec<_> = head
+            .children
+            .iter()
+            .filter_map(|n| match n {
+                Node::Element(e) if e.get_attr("rel") == Some("alternate") => {
+                    Some((e.get_attr("type")?, e.get_attr("href")?))
+                }
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(
+            alternates,
+            [
+                (mime::types::RSS, "/feed.xml"),
+                (mime::types::ATOM, "/atom.xml")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_font_face_urls() {
+        let css = r#"
+@font-face { font-family: "Inter"; src: url("../fonts/inter.woff2") format("woff2"), url(../fonts/inter.woff); }
+body { font-family: Inter; }
+@font-face{font-family:Mono;src:url('/fonts/mono.woff2')}
+@font-face { font-family: Inline; src: url(data:font/woff2;base64,AAAA); }
+"#;
+        assert_eq!(
+            font_face_urls(css),
+            ["../fonts/inter.woff2", "/fonts/mono.woff2"]
+        );
+
+        assert_eq!(
+            resolve_css_url("/blog/assets/css/site-1a2b.css?v=3", "../fonts/inter.woff2"),
+            "/blog/assets/fonts/inter.woff2"
+        );
+        assert_eq!(
+            resolve_css_url("/assets/site.css", "./inter.woff2"),
+            "/assets/inter.woff2"
+        );
+        assert_eq!(
+            resolve_css_url("/assets/site.css", "/fonts/mono.woff2"),
+            "/fonts/mono.woff2"
+        );
+    }
+
+    #[test]
+    fn test_preload_hints() {
+        let dir = TempDir::new().unwrap();
+        let assets_dir = dir.path().join("assets");
+        fs::create_dir_all(&assets_dir).unwrap();
+        let style_path = assets_dir.join("site.css");
+        fs::write(
+            &style_path,
+            "@font-face { font-family: Inter; src: url(fonts/inter.woff2); }",
+        )
+        .unwrap();
+        fs::write(assets_dir.join("hero.jpg"), "jpg").unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(dir.path());
+        config.build.assets.nested = vec![NestedEntry::Simple(assets_dir.clone())];
+        config.site.header.styles = vec![style_path];
+        config.site.header.preload.images =
+            vec![assets_dir.join("hero.jpg"), assets_dir.join("missing.jpg")];
+
+        let doc = HeaderInjector::new(&config).transform(make_html_doc());
+        let head = doc
+            .root
+            .children
+            .iter()
+            .find_map(|n| match n {
+                Node::Element(e) if e.tag == "head" => Some(e.as_ref()),
+                _ => None,
+            })
+            .expect("should have head");
+        let preloads: Vec<_> = head
+            .children
+            .iter()
+            .filter_map(|n| match n {
+                Node::Element(e) if e.get_attr("rel") == Some("preload") => Some(e.as_ref()),
+                _ => None,
+            })
+            .collect();
+
+        let kinds: Vec<_> = preloads.iter().filter_map(|e| e.get_attr("as")).collect();
+        assert_eq!(kinds, ["style", "font", "image"]);
+        assert_eq!(
+            preloads[1].get_attr("href"),
+            Some("/assets/fonts/inter.woff2")
+        );
+        assert_eq!(preloads[1].get_attr("crossorigin"), Some("anonymous"));
+        assert!(
+            preloads[2]
+                .get_attr("href")
+                .is_some_and(|href| href.starts_with("/assets/hero.jpg"))
+        );
+
+        config.site.header.preload.styles = false;
+        config.site.header.preload.fonts = false;
+        config.site.header.preload.images.clear();
+        let doc = HeaderInjector::new(&config).transform(make_html_doc());
+        let head = doc
+            .root
+            .children
+            .iter()
+            .find_map(|n| match n {
+                Node::Element(e) if e.tag == "head" => Some(e.as_ref()),
+                _ => None,
+            })
+            .expect("should have head");
+        assert!(
+            !head
+                .children
+                .iter()
+                .any(|n| matches!(n, Node::Element(e) if e.get_attr("rel") == Some("preload")))
+        );
+    }
+
     #[test]
     fn injected_href_links_have_link_family_payloads() {
         let dir = TempDir::new().unwrap();
@@ -347,4 +1011,32 @@ mod tests {
             assert_eq!(data.href.as_deref(), link.get_attr("href"));
         }
     }
+
+    #[test]
+    fn header_styles_use_hashed_filenames() {
+        let dir = TempDir::new().unwrap();
+        let assets_dir = dir.path().join("assets");
+        fs::create_dir_all(&assets_dir).unwrap();
+        let style_path = assets_dir.join("site.css");
+        fs::write(&style_path, "body{}").unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(dir.path());
+        config.build.assets.nested = vec![NestedEntry::Simple(assets_dir)];
+        config.site.header.styles = vec![style_path];
+
+        let doc = HeaderInjector::new(&config).transform(make_html_doc());
+        let indexed = TolaSite::indexer().transform(doc);
+        let links = indexed.find_all(|elem| elem.is_tag("link") && elem.has_attr("href"));
+
+        let expected = format!(
+            "/assets/site-{}.css",
+            crate::image::svg::filename_hash(b"body{}")
+        );
+        assert!(
+            links
+                .iter()
+                .any(|link| link.get_attr("href") == Some(expected.as_str()))
+        );
+    }
 }
@@ -14,10 +14,17 @@
 //! | `Fragment` | `#section` | Slugified anchor |
 //! | `SiteRoot` | `/about` | Prefixed and slugified |
 //! | `FileRelative` | `./img.png` | Adjusted for output structure |
+//!
+//! Audio file links are left to `MediaTransform`, which resolves them as
+//! `<audio>` sources.
+//!
+//! `<a>` elements linking to other sites get `rel="noopener noreferrer"`
+//! (and optionally `target="_blank"`), see `[build.links.external]`.
 
 use anyhow::Result;
 use tola_vdom::prelude::*;
 
+use super::media::is_audio_link;
 use crate::compiler::family::{Indexed, TolaSite::FamilyKind};
 use crate::compiler::page::PageRoute;
 use crate::config::SiteConfig;
@@ -45,6 +52,9 @@ impl<'a> LinkTransform<'a> {
         let Some(value) = elem.get_attr("href").map(str::to_string) else {
             return;
         };
+        if elem.is_tag("a") && is_audio_link(&value) {
+            return;
+        }
 
         if let Ok(processed) = process_link_value(&value, self.config, self.route) {
             elem.set_attr("href", processed.clone());
@@ -52,6 +62,22 @@ impl<'a> LinkTransform<'a> {
                 data.set_href(Some(processed));
             }
         }
+
+        if elem.is_tag("a") && is_web_link(&value) {
+            self.process_external(elem);
+        }
+    }
+
+    /// Add `rel` and `target` attributes to an external `<a>`.
+    fn process_external(&self, elem: &mut Element<Indexed>) {
+        let external = &self.config.build.links.external;
+        if external.noopener {
+            let rel = merge_rel(elem.get_attr("rel"), &["noopener", "noreferrer"]);
+            elem.set_attr("rel", rel);
+        }
+        if external.target_blank && elem.get_attr("target").is_none() {
+            elem.set_attr("target", "_blank");
+        }
     }
 
     /// Slugify a heading id and keep indexed family data in sync.
@@ -121,6 +147,28 @@ pub fn resolve_link(value: &str, config: &SiteConfig, route: &PageRoute) -> Resu
     Ok(url)
 }
 
+/// Whether a link points at another site (`http://` or `https://`)
+///
+/// Other external schemes (`mailto:`, `tel:`) don't open pages, so they
+/// get no `rel`/`target` attributes.
+fn is_web_link(value: &str) -> bool {
+    matches!(LinkKind::parse(value), LinkKind::External(_))
+        && value.split_once(':').is_some_and(|(scheme, _)| {
+            scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https")
+        })
+}
+
+/// Append `add` to a space-separated `rel` value, skipping duplicates
+fn merge_rel(existing: Option<&str>, add: &[&str]) -> String {
+    let mut tokens: Vec<&str> = existing.unwrap_or_default().split_whitespace().collect();
+    for token in add {
+        if !tokens.iter().any(|t| t.eq_ignore_ascii_case(token)) {
+            tokens.push(token);
+        }
+    }
+    tokens.join(" ")
+}
+
 /// Process a link value (href or src attribute)
 ///
 /// Alias for [`resolve_link`] for clarity at call sites
@@ -373,6 +421,83 @@ mod tests {
         assert_eq!(heading_data.id.as_deref(), Some("my-section"));
     }
 
+    #[test]
+    fn test_merge_rel() {
+        let add = ["noopener", "noreferrer"];
+        assert_eq!(merge_rel(None, &add), "noopener noreferrer");
+        assert_eq!(merge_rel(Some(""), &add), "noopener noreferrer");
+        assert_eq!(
+            merge_rel(Some("external"), &add),
+            "external noopener noreferrer"
+        );
+        assert_eq!(
+            merge_rel(Some("  noopener  me "), &add),
+            "noopener me noreferrer"
+        );
+        assert_eq!(
+            merge_rel(Some("NoReferrer noopener"), &add),
+            "NoReferrer noopener"
+        );
+    }
+
+    #[test]
+    fn test_is_web_link() {
+        assert!(is_web_link("https://example.com"));
+        assert!(is_web_link("HTTP://example.com/page"));
+        assert!(!is_web_link("mailto:user@example.com"));
+        assert!(!is_web_link("/about"));
+        assert!(!is_web_link("#section"));
+        assert!(!is_web_link("./https.html"));
+    }
+
+    #[test]
+    fn transform_adds_rel_to_external_links() {
+        use crate::compiler::family::TolaSite;
+
+        let links = || {
+            let root = TolaSite::element("main", Attrs::new())
+                .child(TolaSite::element(
+                    "a",
+                    Attrs::from([("href", "https://example.com"), ("rel", "external")]),
+                ))
+                .child(TolaSite::element("a", Attrs::from([("href", "/about")])))
+                .child(TolaSite::element(
+                    "a",
+                    Attrs::from([("href", "mailto:user@example.com")]),
+                ));
+            TolaSite::indexer().transform(Document::new(root))
+        };
+        let mut config = SiteConfig::default();
+        let route = test_route(true);
+
+        let transformed = LinkTransform::new(&config, &route).transform(links());
+        let external = transformed
+            .find(|elem| elem.get_attr("href") == Some("https://example.com"))
+            .unwrap();
+        assert_eq!(
+            external.get_attr("rel"),
+            Some("external noopener noreferrer")
+        );
+        assert_eq!(external.get_attr("target"), None);
+        let internal = transformed
+            .find(|elem| elem.get_attr("href").is_some_and(|h| h.starts_with('/')))
+            .unwrap();
+        assert_eq!(internal.get_attr("rel"), None);
+        let mail = transformed
+            .find(|elem| elem.get_attr("href") == Some("mailto:user@example.com"))
+            .unwrap();
+        assert_eq!(mail.get_attr("rel"), None);
+
+        config.build.links.external.noopener = false;
+        config.build.links.external.target_blank = true;
+        let transformed = LinkTransform::new(&config, &route).transform(links());
+        let external = transformed
+            .find(|elem| elem.get_attr("href") == Some("https://example.com"))
+            .unwrap();
+        assert_eq!(external.get_attr("rel"), Some("external"));
+        assert_eq!(external.get_attr("target"), Some("_blank"));
+    }
+
     // =========================================================================
     // Path Prefix Tests
     // =========================================================================
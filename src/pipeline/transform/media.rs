@@ -4,6 +4,8 @@
 //! - URL processing for `src` attribute
 //! - Auto-inject `.tola-recolor` class based on inheritance and config
 //! - Remove background from images with `.tola-nobg` class
+//! - Rewrite `<img>` into `<picture>` with responsive variants (`build.assets.images`)
+//! - Rewrite audio file links into `<audio>` players (`build.media.audio`)
 
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
@@ -18,7 +20,8 @@ use crate::compiler::page::PageRoute;
 use crate::config::SiteConfig;
 use crate::config::section::theme::RecolorTarget;
 use crate::core::LinkKind;
-use crate::image::background;
+use crate::image::{background, responsive};
+use crate::utils::html::escape_attr;
 
 // =============================================================================
 // nobg reference tracking (minify mode only)
@@ -49,6 +52,16 @@ const CLASS_NO_RECOLOR: &str = "tola-no-recolor";
 const CLASS_NOBG: &str = "tola-nobg";
 const RECOLOR_TARGETS: &[&str] = &["img"];
 const NOBG_FORMATS: &[&str] = &["png", "jpg", "jpeg", "webp"];
+const AUDIO_EXTENSIONS: &[&str] = &["mp3", "ogg", "wav", "flac", "aac", "opus"];
+
+/// Check if an `<a>` href points at an audio file (`./talk.mp3`, `/audio/song.ogg?t=30`)
+pub fn is_audio_link(href: &str) -> bool {
+    let path = href.split(['?', '#']).next().unwrap_or(href);
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|ext| AUDIO_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
 
 /// Processes media element src attributes in Indexed VDOM
 pub struct MediaTransform<'a> {
@@ -212,7 +225,134 @@ impl<'a> MediaTransform<'a> {
             std::fs::create_dir_all(parent)?;
         }
 
-        background::remove_background(source, output)
+        background::remove_background_default(source, output)
+    }
+
+    /// Rewrite an `<img>` into `<picture>` with responsive variants.
+    ///
+    /// The element keeps its stable id and media payload (pointing at the
+    /// original image); `<source>` tags and the fallback `<img>` become raw
+    /// children, with URLs resolved like `src`. Variant files are generated
+    /// during asset processing under the same content-hash names.
+    fn process_responsive(&self, elem: &mut Element<Indexed>) {
+        let images = &self.config.build.assets.images;
+        if !images.enable || elem.has_attr("srcset") {
+            return;
+        }
+        let Some(src) = elem.get_attr("src").map(str::to_string) else {
+            return;
+        };
+        let Some(source_path) = self.resolve_source_path(&src) else {
+            return;
+        };
+        if !images.is_selected(&source_path, self.config.get_root()) {
+            return;
+        }
+        let Some(plan) = responsive::plan(&source_path, images) else {
+            return;
+        };
+        if plan.fallback.variants.is_empty() && plan.sources.is_empty() {
+            return;
+        }
+        let Ok(resolved) = process_link_value(&src, self.config, self.route) else {
+            return;
+        };
+
+        // Variants live next to the original
+        let variant_url = |name: &str| match resolved.rsplit_once('/') {
+            Some((dir, _)) => format!("{dir}/{name}"),
+            None => name.to_string(),
+        };
+        let srcset = |set: &responsive::VariantSet| {
+            set.variants
+                .iter()
+                .map(|v| format!("{} {}w", variant_url(&v.file_name), v.width))
+                .collect::<Vec<_>>()
+        };
+
+        let mut html = String::new();
+        for source in &plan.sources {
+            html.push_str(&format!(
+                "<source type=\"{}\" srcset=\"{}\">",
+                source.format.mime(),
+                escape_attr(&srcset(source).join(", "))
+            ));
+        }
+
+        let mut fallback = srcset(&plan.fallback);
+        fallback.push(format!("{} {}w", resolved, plan.width));
+        html.push_str("<img");
+        for (key, value) in elem.attrs.iter() {
+            if key != "src" {
+                html.push_str(&format!(" {}=\"{}\"", key, escape_attr(value)));
+            }
+        }
+        html.push_str(&format!(
+            " src=\"{}\" srcset=\"{}\">",
+            escape_attr(&resolved),
+            escape_attr(&fallback.join(", "))
+        ));
+
+        elem.tag = "picture".into();
+        elem.attrs = Attrs::new();
+        elem.children.clear();
+        elem.children.push(Node::Text(Text::raw(html)));
+        if let Some(data) = ExtractFamily::<MediaFamily>::get_mut(&mut elem.ext) {
+            data.set_src(Some(resolved));
+        }
+    }
+
+    /// Rewrite an audio file link into a `<figure>` with an `<audio>` player.
+    ///
+    /// `LinkTransform` leaves these hrefs untouched, so the src is resolved
+    /// here like image `src`s. Local files missing from the content and asset
+    /// directories are reported; the player is still emitted.
+    fn process_audio(&self, elem: &mut Element<Indexed>) {
+        if !elem.is_tag("a") {
+            return;
+        }
+        let Some(href) = elem.get_attr("href").map(str::to_string) else {
+            return;
+        };
+        if !is_audio_link(&href) {
+            return;
+        }
+
+        if !matches!(LinkKind::parse(&href), LinkKind::External(_))
+            && self.resolve_source_path(&href).is_none()
+        {
+            crate::log!(
+                "warning";
+                "{}: audio file not found: {}",
+                self.route.source.display(),
+                href
+            );
+        }
+        let Ok(src) = process_link_value(&href, self.config, self.route) else {
+            return;
+        };
+
+        let audio = &self.config.build.media.audio;
+        let mut html = String::from("<audio");
+        if audio.controls {
+            html.push_str(" controls");
+        }
+        if audio.autoplay {
+            html.push_str(" autoplay");
+        }
+        let src = escape_attr(&src);
+        html.push_str(&format!(
+            " preload=\"{}\" src=\"{src}\"><a href=\"{src}\">Download audio</a></audio>",
+            audio.preload.as_str()
+        ));
+
+        elem.tag = "figure".into();
+        elem.attrs = Attrs::new();
+        elem.children.clear();
+        elem.children.push(Node::Text(Text::raw(html)));
+        if let Some(data) = ExtractFamily::<LinkFamily>::get_mut(&mut elem.ext) {
+            data.set_href(None);
+        }
     }
 
     /// Compute output path for an image src.
@@ -247,6 +387,11 @@ impl Transform<Indexed> for MediaTransform<'_> {
             && self.config.theme.recolor.target == RecolorTarget::Auto;
         process_classes(&mut doc.root, &self, ClassState::default(), auto_inject);
 
+        // Audio file links (before src processing, the player src is final)
+        doc.modify_by::<FamilyKind::Link, _>(|elem| {
+            self.process_audio(elem);
+        });
+
         // Process src attributes (URL resolution)
         doc.modify_by::<FamilyKind::Media, _>(|elem| {
             if let Some(src) = elem.get_attr("src").map(|s| s.to_string())
@@ -312,6 +457,9 @@ fn process_classes(
             auto_inject,
         );
         apply_nobg_processing(elem, has_nobg, inherited.nobg, transform);
+        if elem.is_tag("img") && !current.nobg {
+            transform.process_responsive(elem);
+        }
     }
 
     // Recurse into children
@@ -401,4 +549,78 @@ mod tests {
         assert_eq!(image.get_attr("src"), Some(".././photo.png"));
         assert_eq!(media.src.as_deref(), Some(".././photo.png"));
     }
+
+    #[test]
+    fn transform_rewrites_img_into_picture_with_variants() {
+        use image::{Rgba, RgbaImage};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let content = dir.path().join("content");
+        std::fs::create_dir_all(&content).unwrap();
+        RgbaImage::from_pixel(100, 50, Rgba([0, 0, 0, 255]))
+            .save(content.join("photo.png"))
+            .unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(dir.path());
+        config.build.assets.images.enable = true;
+        config.build.assets.images.srcset_widths = vec![40];
+        let route = PageRoute {
+            source: content.join("post.typ"),
+            permalink: crate::core::UrlPath::from_page("/post/"),
+            output_dir: dir.path().join("public/post"),
+            ..Default::default()
+        };
+        let root = TolaSite::element("main", Attrs::new()).child(TolaSite::element(
+            "img",
+            Attrs::from([("src", "./photo.png"), ("alt", "A \"photo\"")]),
+        ));
+        let indexed = TolaSite::indexer().transform(Document::new(root));
+
+        let transformed = MediaTransform::new(&config, &route).transform(indexed);
+
+        let picture = transformed.find(|elem| elem.is_tag("picture")).unwrap();
+        let html = picture.text_content();
+        assert!(html.contains(r#"<source type="image/webp" srcset=".././photo-40w."#));
+        assert!(html.contains(r#"alt="A &quot;photo&quot;""#));
+        assert!(html.contains(r#"src=".././photo.png""#));
+        assert!(html.contains(".././photo.png 100w"));
+        let media = ExtractFamily::<MediaFamily>::get(&picture.ext).unwrap();
+        assert_eq!(media.src.as_deref(), Some(".././photo.png"));
+    }
+
+    #[test]
+    fn test_is_audio_link() {
+        assert!(is_audio_link("./talk.mp3"));
+        assert!(is_audio_link("/audio/song.OGG?t=30"));
+        assert!(is_audio_link("https://example.com/a.opus#x"));
+        assert!(!is_audio_link("./notes.pdf"));
+        assert!(!is_audio_link("/blog/mp3/"));
+    }
+
+    #[test]
+    fn transform_rewrites_audio_link_into_player() {
+        let mut config = SiteConfig::default();
+        config.build.media.audio.autoplay = true;
+        let route = PageRoute {
+            source: PathBuf::from("content/post.typ"),
+            permalink: crate::core::UrlPath::from_page("/post/"),
+            output_dir: PathBuf::from("public/post"),
+            ..Default::default()
+        };
+        let root = TolaSite::element("main", Attrs::new()).child(TolaSite::element(
+            "a",
+            Attrs::from([("href", "./talk.mp3")]),
+        ));
+        let indexed = TolaSite::indexer().transform(Document::new(root));
+
+        let transformed = MediaTransform::new(&config, &route).transform(indexed);
+
+        assert!(transformed.find(|elem| elem.is_tag("a")).is_none());
+        let figure = transformed.find(|elem| elem.is_tag("figure")).unwrap();
+        assert_eq!(
+            figure.text_content(),
+            r#"<audio controls autoplay preload="metadata" src=".././talk.mp3"><a href=".././talk.mp3">Download audio</a></audio>"#
+        );
+    }
 }
@@ -4,19 +4,22 @@
 //!
 //! # Modules
 //!
+//! - `code`: Highlights fenced code blocks with syntect (Indexed -> Indexed)
 //! - `header`: Injects `<head>` content and sets `lang` attribute (Raw -> Raw)
 //! - `link`: Processes href and heading id with slugification (Indexed -> Indexed)
-//! - `media`: Processes media elements with auto-enhance (Indexed -> Indexed)
+//! - `media`: Processes media elements and audio links with auto-enhance (Indexed -> Indexed)
 //! - `svg`: Processes SVG elements (optimize/extract) (Indexed -> Indexed)
 //! - `body`: Injects body scripts (SPA navigation) (Indexed -> Indexed)
 
 mod body;
+pub mod code;
 mod header;
 mod link;
 mod media;
 mod svg;
 
 pub use body::BodyInjector;
+pub use code::CodeTransform;
 pub use header::HeaderInjector;
 pub use link::{LinkTransform, normalize_site_root_page_url, resolve_link};
 pub use media::{MediaTransform, cleanup_nobg_originals};
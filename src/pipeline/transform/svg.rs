@@ -71,6 +71,7 @@ impl<'a> SvgTransform<'a> {
             self.config.build.svg.threshold_bytes(),
             self.config.build.svg.expand_viewbox,
         )
+        .with_passes(self.config.build.svg.passes())
     }
 
     /// Reconstruct full SVG string from element.
@@ -124,6 +125,7 @@ impl<'a> SvgTransform<'a> {
         let options = OptimizeOptions {
             dpi: self.config.build.svg.dpi,
             expand_viewbox: self.config.build.svg.expand_viewbox,
+            passes: self.config.build.svg.passes(),
         };
 
         let optimized = optimize_svg(svg_content.as_bytes(), &options)?;
@@ -30,7 +30,7 @@ pub fn categorize_path(path: &Path, config: &SiteConfig) -> FileCategory {
     if path.starts_with(config.paths().output_dir()) {
         return FileCategory::Output;
     }
-    if path == config.config_path {
+    if path == config.config_path || path == config.local_config_path() {
         FileCategory::Config
     } else if config.build.deps.iter().any(|dep| path.starts_with(dep)) {
         FileCategory::Deps
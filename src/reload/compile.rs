@@ -39,11 +39,23 @@ pub enum CompileOutcome {
     },
 }
 
+/// Build mode for watch rebuilds
+///
+/// `serve` emits `data-tola-id` for hot reload; `build --watch` keeps
+/// writing production HTML.
+pub fn watch_mode(config: &SiteConfig) -> BuildMode {
+    if config.cli.is_some_and(|cli| cli.is_build()) {
+        BuildMode::WATCH
+    } else {
+        BuildMode::DEVELOPMENT
+    }
+}
+
 /// Compile a single file to VDOM
 ///
 /// This function:
 /// - Routes by file extension
-/// - Prepares page output with the Development driver
+/// - Prepares page output with the [`watch_mode`] driver
 /// - Returns a unified outcome type
 /// - Applies draft-transition cleanup when a page becomes non-visible
 pub fn compile_page(
@@ -124,7 +136,7 @@ fn compile_content_file(
     state: &SiteIndex,
     ticket: Option<&PageStateTicket>,
 ) -> CompileOutcome {
-    let result = prepare_page(BuildMode::DEVELOPMENT, path, config, host, state);
+    let result = prepare_page(watch_mode(config), path, config, host, state);
 
     match result {
         Ok(Some(prepared)) => finish_prepared_page(path, config, state, ticket, prepared),
@@ -303,9 +315,7 @@ pub fn cleanup_removed_source_state(
         crate::compiler::scheduler::SCHEDULER.invalidate(&normalized);
     }
 
-    let Some(old_url) = old_url else {
-        return None;
-    };
+    let old_url = old_url?;
 
     // Remove cached VDOM and link-graph edges for this page.
     crate::compiler::page::BUILD_CACHE.remove(&tola_vdom::CacheKey::new(old_url.as_str()));
@@ -586,6 +596,56 @@ mod tests {
         reset_state(&state);
     }
 
+    #[test]
+    fn draft_preview_compiles_draft_under_prefix() {
+        let dir = TempDir::new().unwrap();
+        let content_dir = dir.path().join("content");
+        let output_dir = dir.path().join("public");
+        fs::create_dir_all(&content_dir).unwrap();
+
+        let page = content_dir.join("post.md");
+        fs::write(&page, "---\ntitle: Post\ndraft: true\n---\n\n# Post\n").unwrap();
+
+        let mut config = SiteConfig::default();
+        config.set_root(dir.path());
+        config.build.content = content_dir.clone();
+        config.build.output = output_dir.clone();
+        config.serve.drafts.enable = true;
+        let state = SiteIndex::new();
+
+        reset_state(&state);
+        let host = typst_host(&config);
+
+        let draft_url = UrlPath::from_page("/_drafts/post/");
+        match compile_page(&page, &config, &host, &state) {
+            CompileOutcome::Vdom { url_path, .. } => assert_eq!(url_path, draft_url),
+            other => panic!("expected draft preview, got: {:?}", other),
+        }
+        assert!(draft_url.output_html_path(&output_dir).exists());
+        assert_eq!(
+            state.read(|_, address| address.url_for_source(&page).cloned()),
+            Some(draft_url.clone())
+        );
+        // Drafts stay out of the public listing
+        assert!(state.with_pages(|store| store.get_pages().is_empty()));
+
+        // Publishing moves the page out of the draft prefix
+        fs::write(&page, "---\ntitle: Post\n---\n\n# Post\n").unwrap();
+        match compile_page(&page, &config, &host, &state) {
+            CompileOutcome::Vdom {
+                url_path,
+                permalink_change,
+                ..
+            } => {
+                assert_eq!(url_path, UrlPath::from_page("/post/"));
+                assert!(permalink_change.is_some());
+            }
+            other => panic!("expected published page, got: {:?}", other),
+        }
+
+        reset_state(&state);
+    }
+
     #[test]
     fn cleanup_removed_source_state_clears_page_links() {
         let dir = TempDir::new().unwrap();
@@ -5,14 +5,23 @@
 //! # Function Types
 //!
 //! - `diff_vdom()` - Pure function, computes diff without side effects
+//! - `diff_vdom_blocks()` - Pure, compares block elements as a whole
 //! - `compute_diff()` - Effectful, updates cache (used by VdomActor)
-//! - `compute_diff_shared()` - Thread-safe version using `SharedCache`
+//! - `compute_diff_shared()` - Thread-safe version using `SharedCache`,
+//!   honoring `serve.diff.granularity`
+
+use std::hash::{Hash, Hasher};
+
+use rustc_hash::{FxHashMap, FxHasher};
 
 use crate::compiler::family::{
     Cache, CacheEntry, DiffEdit, Indexed, SharedCache, StructuralDocument,
 };
-use tola_vdom::diff::{Outcome, diff};
-use tola_vdom::{CacheKey, Document};
+use crate::config::section::DiffGranularity;
+use tola_vdom::core::{HasStableId, PhaseExt};
+use tola_vdom::diff::{Outcome, TreeEdit, diff};
+use tola_vdom::prelude::*;
+use tola_vdom::{CacheKey, Document, Element};
 
 /// Outcome of diff computation
 #[derive(Debug)]
@@ -98,6 +107,9 @@ pub fn compute_diff(cache: &mut Cache, key: CacheKey, new_vdom: Document<Indexed
 /// This version is suitable for concurrent access from multiple threads
 /// Uses `RwLock` internally for better read performance
 ///
+/// `granularity` picks the diff: `Element` diffs the whole tree, `Block`
+/// stops at block elements, and `Page` skips diffing and reloads.
+///
 /// # Cache Update Strategy
 /// Same as `compute_diff`:
 /// - Initial/NeedsReload/Unchanged: Update cache immediately
@@ -106,10 +118,17 @@ pub fn compute_diff_shared(
     cache: &SharedCache,
     key: CacheKey,
     new_vdom: Document<Indexed>,
+    granularity: DiffGranularity,
 ) -> DiffOutcome {
     // Try to get old VDOM (read lock)
     if let Some(old_entry) = cache.get(&key) {
-        let outcome = diff_vdom(&old_entry.doc, &new_vdom);
+        let outcome = match granularity {
+            DiffGranularity::Element => diff_vdom(&old_entry.doc, &new_vdom),
+            DiffGranularity::Block => diff_vdom_blocks(&old_entry.doc, &new_vdom),
+            DiffGranularity::Page => DiffOutcome::NeedsReload {
+                reason: "serve.diff.granularity = \"page\"".to_string(),
+            },
+        };
 
         match &outcome {
             DiffOutcome::NeedsReload { .. } | DiffOutcome::Unchanged => {
@@ -130,6 +149,192 @@ pub fn compute_diff_shared(
     }
 }
 
+// =============================================================================
+// Block Granularity
+// =============================================================================
+
+/// Tags compared as a whole under `serve.diff.granularity = "block"`
+#[rustfmt::skip]
+const BLOCK_TAGS: &[&str] = &[
+    "address", "article", "aside", "blockquote", "dd", "details", "div", "dl", "dt", "figcaption",
+    "figure", "footer", "form", "h1", "h2", "h3", "h4", "h5", "h6", "header", "hr", "li", "main",
+    "nav", "ol", "p", "pre", "section", "table", "ul",
+];
+
+/// Tag of a collapsed block, so a block never diffs against a regular element
+const COLLAPSED_TAG: &str = "tola-block";
+
+/// Attribute holding the content hash of a collapsed block
+const BLOCK_HASH_ATTR: &str = "hash";
+
+/// Diff two documents, comparing innermost block elements as a whole
+///
+/// Block elements without nested blocks (`<p>`, `<pre>`, `<li>`, ...) are
+/// collapsed to a content hash before diffing, so the diff never descends
+/// into them. A changed block becomes one `Replace` carrying the new element.
+pub fn diff_vdom_blocks(
+    old_vdom: &StructuralDocument,
+    new_vdom: &Document<Indexed>,
+) -> DiffOutcome {
+    let mut old = old_vdom.clone();
+    collapse_blocks(&mut old.root, &mut |_, _| {});
+
+    let mut blocks = FxHashMap::default();
+    let mut new = new_vdom.clone();
+    collapse_blocks(&mut new.root, &mut |hash, block| {
+        blocks.insert(hash, block.clone());
+    });
+
+    match diff(&old, &new) {
+        Outcome::Unchanged { .. } => DiffOutcome::Unchanged,
+        Outcome::Changed(changes) => {
+            let edits = changes
+                .edits
+                .into_iter()
+                .map(|edit| expand_edit(edit, &blocks))
+                .collect();
+            DiffOutcome::Edits(edits, Box::new(new_vdom.clone()))
+        }
+        Outcome::Reload(reload) => DiffOutcome::NeedsReload {
+            reason: reload.reason.to_string(),
+        },
+    }
+}
+
+/// Collapse innermost blocks under `elem`, reporting each one before it is
+/// collapsed. Returns whether `elem` is or contains a block.
+fn collapse_blocks<P>(elem: &mut Element<P>, on_block: &mut impl FnMut(String, &Element<P>)) -> bool
+where
+    P: PhaseExt,
+    P::Ext: HasStableId,
+{
+    let mut nested = false;
+    for child in elem.children.iter_mut() {
+        if let Node::Element(child) = child {
+            nested |= collapse_blocks(child, on_block);
+        }
+    }
+
+    let is_block = BLOCK_TAGS.iter().any(|tag| elem.tag == *tag);
+    if is_block && !nested {
+        let hash = block_hash(elem);
+        on_block(hash.clone(), elem);
+        elem.tag = COLLAPSED_TAG.into();
+        elem.attrs = Default::default();
+        elem.set_attr(BLOCK_HASH_ATTR, hash);
+        elem.children.clear();
+    }
+    is_block || nested
+}
+
+/// Hash of an element's tag, attributes, identity and content
+fn block_hash<P>(elem: &Element<P>) -> String
+where
+    P: PhaseExt,
+    P::Ext: HasStableId,
+{
+    fn feed<P>(elem: &Element<P>, hasher: &mut FxHasher)
+    where
+        P: PhaseExt,
+        P::Ext: HasStableId,
+    {
+        elem.tag.hash(hasher);
+        elem.ext.stable_id().hash(hasher);
+        for (name, value) in &elem.attrs {
+            name.hash(hasher);
+            value.hash(hasher);
+        }
+        for child in &elem.children {
+            match child {
+                Node::Element(child) => feed(child, hasher),
+                Node::Text(text) => {
+                    text.content.hash(hasher);
+                    text.kind.hash(hasher);
+                }
+            }
+        }
+        // Close the element so siblings and children hash differently
+        hasher.write_u8(0);
+    }
+
+    let mut hasher = FxHasher::default();
+    feed(elem, &mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// The full block a collapsed element stands for
+fn collapsed_block<'a, P: PhaseExt>(
+    elem: &Element<P>,
+    blocks: &'a FxHashMap<String, Element<Indexed>>,
+) -> Option<&'a Element<Indexed>> {
+    if elem.tag != COLLAPSED_TAG {
+        return None;
+    }
+    blocks.get(elem.get_attr(BLOCK_HASH_ATTR)?)
+}
+
+/// Put the full blocks back into an edit computed on collapsed documents
+fn expand_edit(edit: DiffEdit, blocks: &FxHashMap<String, Element<Indexed>>) -> DiffEdit {
+    match edit {
+        // Only the hash of a collapsed block changes
+        TreeEdit::UpdateAttrs { target, changes } => {
+            let block = changes
+                .iter()
+                .find(|(name, _)| name == BLOCK_HASH_ATTR)
+                .and_then(|(_, hash)| blocks.get(hash.as_deref()?));
+            match block {
+                Some(block) => TreeEdit::Replace {
+                    target,
+                    element: Box::new(block.clone()),
+                },
+                None => TreeEdit::UpdateAttrs { target, changes },
+            }
+        }
+        TreeEdit::Replace {
+            target,
+            mut element,
+        } => {
+            expand_element(&mut element, blocks);
+            TreeEdit::Replace { target, element }
+        }
+        TreeEdit::ReplaceChildren {
+            target,
+            mut children,
+            is_svg,
+        } => {
+            children
+                .iter_mut()
+                .for_each(|child| expand_node(child, blocks));
+            TreeEdit::ReplaceChildren {
+                target,
+                children,
+                is_svg,
+            }
+        }
+        TreeEdit::Insert { anchor, mut node } => {
+            expand_node(&mut node, blocks);
+            TreeEdit::Insert { anchor, node }
+        }
+        edit => edit,
+    }
+}
+
+fn expand_node(node: &mut Node<Indexed>, blocks: &FxHashMap<String, Element<Indexed>>) {
+    if let Node::Element(elem) = node {
+        expand_element(elem, blocks);
+    }
+}
+
+fn expand_element(elem: &mut Element<Indexed>, blocks: &FxHashMap<String, Element<Indexed>>) {
+    if let Some(block) = collapsed_block(elem, blocks) {
+        *elem = block.clone();
+        return;
+    }
+    for child in elem.children.iter_mut() {
+        expand_node(child, blocks);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,4 +375,59 @@ mod tests {
         let outcome = compute_diff(&mut cache, key, doc);
         assert!(matches!(outcome, DiffOutcome::Initial));
     }
+
+    /// `<body><p>a <em>{em}</em></p><ul><li>one</li></ul></body>`
+    fn block_doc(em: &str) -> IndexedDocument {
+        let mut em_elem: Element<Raw> = Element::new("em");
+        em_elem.push_text(em);
+        let mut p: Element<Raw> = Element::new("p");
+        p.push_text("a ");
+        p.push_elem(em_elem);
+        let mut li: Element<Raw> = Element::new("li");
+        li.push_text("one");
+        let mut ul: Element<Raw> = Element::new("ul");
+        ul.push_elem(li);
+        let mut body: Element<Raw> = Element::new("body");
+        body.push_elem(p);
+        body.push_elem(ul);
+        TolaSite::indexer().transform(Document::new(body))
+    }
+
+    #[test]
+    fn test_diff_vdom_blocks_replaces_changed_block() {
+        let old = tola_vdom::snapshot::project(&block_doc("old"));
+        let new = block_doc("new");
+
+        let DiffOutcome::Edits(edits, _) = diff_vdom_blocks(&old, &new) else {
+            panic!("expected edits");
+        };
+        assert_eq!(edits.len(), 1);
+        let TreeEdit::Replace { target, element } = &edits[0] else {
+            panic!("expected the paragraph to be replaced");
+        };
+        let p = new.find_all(|elem| elem.is_tag("p"))[0];
+        assert_eq!(target.to_attr_value(), p.stable_id().to_attr_value());
+        assert!(element.is_tag("p"));
+        assert_eq!(element.text_content(), "a new");
+    }
+
+    #[test]
+    fn test_diff_vdom_blocks_unchanged() {
+        let old = tola_vdom::snapshot::project(&block_doc("same"));
+        let outcome = diff_vdom_blocks(&old, &block_doc("same"));
+        assert!(matches!(outcome, DiffOutcome::Unchanged));
+    }
+
+    #[test]
+    fn test_compute_diff_shared_page_granularity_skips_diff() {
+        let cache = SharedCache::default();
+        let key = CacheKey::new("/test");
+        let doc = make_indexed_doc("html");
+
+        let outcome = compute_diff_shared(&cache, key.clone(), doc.clone(), DiffGranularity::Page);
+        assert!(matches!(outcome, DiffOutcome::Initial));
+
+        let outcome = compute_diff_shared(&cache, key, doc, DiffGranularity::Page);
+        assert!(matches!(outcome, DiffOutcome::NeedsReload { .. }));
+    }
 }
@@ -8,6 +8,8 @@
 //! - `reload`: Trigger full page reload
 //! - `patch`: Apply incremental DOM patches (with optional URL change)
 //! - `css`: Inject updated CSS (no layout recalc)
+//! - `css_reload`: Re-fetch a changed stylesheet by href (no navigation)
+//! - `progress`: Rebuild progress for the loading bar
 //! - `ping`/`pong`: Keep connection alive
 
 // Many methods are not yet used but will be for incremental hot reload
@@ -51,13 +53,28 @@ pub enum HotReloadMessage {
         content: String,
     },
 
-    /// Keep-alive ping (server -> client)
+    /// Stylesheet changed on disk: swap matching `<link>` hrefs (cache-busted)
+    #[serde(rename = "css_reload")]
+    CssReload {
+        /// Stylesheet URL path (without version query)
+        href: String,
+    },
+
+    /// Rebuild progress (100 = finished, hides the loading bar)
+    Progress {
+        /// Compiled share of the batch, 0-100
+        percent: u8,
+        /// Short status text
+        message: String,
+    },
+
+    /// Keep-alive ping (either direction, answered with a pong)
     Ping {
         /// Timestamp for latency measurement
         ts: u64,
     },
 
-    /// Keep-alive pong (client -> server)
+    /// Keep-alive pong (reply to a ping)
     Pong {
         /// Echo back the timestamp
         ts: u64,
@@ -67,6 +84,10 @@ pub enum HotReloadMessage {
     Connected {
         /// Server version for compatibility check
         version: String,
+        /// Build identifier, changes with every pushed update (and restart)
+        ///
+        /// A reconnecting client reloads if it differs from the last one seen.
+        build: String,
     },
 
     /// Compilation error (display overlay, no reload)
@@ -134,10 +155,24 @@ impl HotReloadMessage {
         }
     }
 
+    /// Create a stylesheet reload message
+    pub fn css_reload(href: impl Into<String>) -> Self {
+        Self::CssReload { href: href.into() }
+    }
+
+    /// Create a rebuild progress message
+    pub fn progress(percent: u8, message: impl Into<String>) -> Self {
+        Self::Progress {
+            percent: percent.min(100),
+            message: message.into(),
+        }
+    }
+
     /// Create a connected message
-    pub fn connected() -> Self {
+    pub fn connected(build: impl Into<String>) -> Self {
         Self::Connected {
             version: env!("CARGO_PKG_VERSION").to_string(),
+            build: build.into(),
         }
     }
 
@@ -246,6 +281,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_css_reload_message() {
+        let msg = HotReloadMessage::css_reload("/assets/app.css");
+        assert_eq!(
+            msg.to_json(),
+            r#"{"type":"css_reload","href":"/assets/app.css"}"#
+        );
+
+        match HotReloadMessage::from_json(&msg.to_json()).unwrap() {
+            HotReloadMessage::CssReload { href } => assert_eq!(href, "/assets/app.css"),
+            _ => panic!("Expected CssReload message"),
+        }
+    }
+
+    #[test]
+    fn test_progress_message() {
+        let msg = HotReloadMessage::progress(42, "compiled 21/50 pages");
+        assert_eq!(
+            msg.to_json(),
+            r#"{"type":"progress","percent":42,"message":"compiled 21/50 pages"}"#
+        );
+
+        match HotReloadMessage::progress(150, "") {
+            HotReloadMessage::Progress { percent, .. } => assert_eq!(percent, 100),
+            _ => panic!("Expected Progress message"),
+        }
+    }
+
     #[test]
     fn test_clear_all_errors_message_omits_path() {
         let msg = HotReloadMessage::clear_all_errors();
@@ -277,9 +340,11 @@ mod tests {
                 target: "style[data-tola-css-target=\"main\"]".to_string(),
                 content: "body { color: red; }".to_string(),
             },
+            HotReloadMessage::css_reload("/assets/app.css"),
+            HotReloadMessage::progress(50, "compiled 1/2 pages"),
             HotReloadMessage::Ping { ts: 1 },
             HotReloadMessage::Pong { ts: 1 },
-            HotReloadMessage::connected(),
+            HotReloadMessage::connected("1"),
             HotReloadMessage::error("content/index.typ", "compile error"),
             HotReloadMessage::clear_all_errors(),
         ];
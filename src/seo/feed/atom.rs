@@ -4,7 +4,11 @@
 
 use super::common::{FeedPage, get_feed_pages};
 use crate::{
-    config::SiteConfig, core::UrlPath, log, page::StoredPageMap, seo::minify_xml,
+    config::{FeedFormat, SiteConfig},
+    core::UrlPath,
+    log,
+    page::StoredPageMap,
+    seo::minify_xml,
     utils::date::DateTimeUtc,
 };
 use anyhow::{Ok, Result};
@@ -42,7 +46,7 @@ impl AtomFeed {
                 .site
                 .seo
                 .feed
-                .path
+                .output_path(FeedFormat::Atom)
                 .to_string_lossy()
                 .replace('\\', "/")
         );
@@ -109,7 +113,13 @@ impl AtomFeed {
     fn write(self) -> Result<()> {
         let minify = self.config.build.minify;
         let output_dir = self.config.paths().output_dir();
-        let feed_path = self.config.site.seo.feed.path.clone();
+        let feed_path = self
+            .config
+            .site
+            .seo
+            .feed
+            .output_path(FeedFormat::Atom)
+            .to_path_buf();
         let xml = self.into_xml()?;
         let xml = minify_xml(xml.as_bytes(), minify);
         // Resolve feed path relative to output_dir (with path_prefix)
@@ -181,6 +191,7 @@ mod tests {
             permalink: "/test/".to_string(),
             summary: Some("A test summary".to_string()),
             author: Some("Post Author".to_string()),
+            episode: None,
         };
 
         let entry = page_to_atom_entry(&page, &config).expect("should create entry");
@@ -198,6 +209,7 @@ mod tests {
             permalink: "/test/".to_string(),
             summary: None,
             author: None,
+            episode: None,
         };
 
         // Invalid date should return None
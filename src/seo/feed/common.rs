@@ -2,7 +2,7 @@
 
 use crate::{
     log,
-    page::{StoredPage, StoredPageMap},
+    page::{JsonMap, StoredPage, StoredPageMap},
     seo::extract::extract,
 };
 
@@ -14,6 +14,8 @@ pub struct FeedPage {
     pub permalink: String,
     pub summary: Option<String>,
     pub author: Option<String>,
+    /// Podcast episode media (pages with `enclosure_url`)
+    pub episode: Option<FeedEpisode>,
 }
 
 impl FeedPage {
@@ -24,6 +26,46 @@ impl FeedPage {
             permalink: page.permalink.to_string(),
             summary: page.meta.summary.as_ref().map(extract),
             author: page.meta.author.clone(),
+            episode: FeedEpisode::from_extra(&page.meta.extra),
+        })
+    }
+}
+
+/// Podcast episode fields from page metadata
+///
+/// | Field              | Description                              |
+/// |--------------------|------------------------------------------|
+/// | `enclosure_url`    | Media file: asset path or absolute URL   |
+/// | `enclosure_length` | File size in bytes                       |
+/// | `enclosure_type`   | MIME type (guessed from the URL if unset) |
+/// | `duration`         | `HH:MM:SS`, `MM:SS` or seconds           |
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FeedEpisode {
+    pub url: String,
+    pub length: Option<u64>,
+    pub mime_type: Option<String>,
+    pub duration: Option<String>,
+}
+
+impl FeedEpisode {
+    fn from_extra(extra: &JsonMap) -> Option<Self> {
+        // Accept numbers as well as strings (e.g. `duration: 1830`)
+        let field = |key: &str| -> Option<String> {
+            let value = extra
+                .get(key)
+                .or_else(|| extra.get(&key.replace('_', "-")))?;
+            match value {
+                serde_json::Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+                serde_json::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            }
+        };
+
+        Some(Self {
+            url: field("enclosure_url")?,
+            length: field("enclosure_length").and_then(|s| s.parse().ok()),
+            mime_type: field("enclosure_type"),
+            duration: field("duration"),
         })
     }
 }
@@ -43,3 +85,26 @@ pub fn get_feed_pages(store: &StoredPageMap) -> Vec<FeedPage> {
 
     feed_pages
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_feed_episode_from_extra() {
+        let extra: JsonMap = serde_json::from_value(json!({
+            "enclosure_url": "/audio/ep1.mp3",
+            "enclosure-length": 123456,
+            "duration": "00:42:10",
+        }))
+        .unwrap();
+        let episode = FeedEpisode::from_extra(&extra).unwrap();
+        assert_eq!(episode.url, "/audio/ep1.mp3");
+        assert_eq!(episode.length, Some(123456));
+        assert_eq!(episode.mime_type, None);
+        assert_eq!(episode.duration.as_deref(), Some("00:42:10"));
+
+        assert!(FeedEpisode::from_extra(&JsonMap::new()).is_none());
+    }
+}
@@ -3,7 +3,9 @@
 //! Generates syndication feeds from compiled page metadata:
 //!
 //! - **RSS 2.0**: Standard feed format (`rss.xml`)
-//! - **Atom 1.0**: Modern feed format (`atom.xml`)
+//! - **Atom 1.0**: Modern feed format (`atom.xml` alongside RSS)
+//!
+//! Both can be generated at once with `format = ["rss", "atom"]`.
 
 use crate::config::{FeedFormat, SiteConfig};
 use crate::page::StoredPageMap;
@@ -13,12 +15,14 @@ pub mod atom;
 mod common;
 pub mod rss;
 
-/// Build feed if enabled in config (RSS or Atom based on format setting)
+/// Build feeds if enabled in config (one per configured format)
 pub fn build_feed(config: &SiteConfig, store: &StoredPageMap) -> Result<()> {
     if config.site.seo.feed.enable {
-        match config.site.seo.feed.format {
-            FeedFormat::Rss => rss::build_rss(config, store)?,
-            FeedFormat::Atom => atom::build_atom(config, store)?,
+        for format in config.site.seo.feed.formats() {
+            match format {
+                FeedFormat::Rss => rss::build_rss(config, store)?,
+                FeedFormat::Atom => atom::build_atom(config, store)?,
+            }
         }
     }
     Ok(())
@@ -1,16 +1,26 @@
 //! RSS 2.0 feed generation.
 //!
-//! Generates RSS feeds from page metadata.
+//! Generates RSS feeds from page metadata. Pages with `enclosure_url`
+//! get an `<enclosure>`; with `[site.seo.feed.podcast]` enabled the feed
+//! also carries the iTunes podcast tags.
 
-use super::common::{FeedPage, get_feed_pages};
+use super::common::{FeedEpisode, FeedPage, get_feed_pages};
 use crate::{
-    config::SiteConfig, core::UrlPath, log, page::StoredPageMap, seo::minify_xml,
-    utils::date::DateTimeUtc,
+    config::{FeedFormat, SiteConfig},
+    core::UrlPath,
+    log,
+    page::StoredPageMap,
+    seo::{asset_url, minify_xml},
+    utils::{date::DateTimeUtc, mime},
 };
 use anyhow::{Ok, Result, anyhow};
 use regex::Regex;
-use rss::{ChannelBuilder, GuidBuilder, ItemBuilder, validation::Validate};
-use std::{fs, sync::LazyLock};
+use rss::{
+    ChannelBuilder, Enclosure, GuidBuilder, ItemBuilder,
+    extension::itunes::{ITunesCategory, ITunesChannelExtension, ITunesItemExtension, ITunesOwner},
+    validation::Validate,
+};
+use std::{fs, path::Path, sync::LazyLock};
 
 /// Build RSS 2.0 feed
 pub fn build_rss(config: &SiteConfig, store: &StoredPageMap) -> Result<()> {
@@ -38,7 +48,7 @@ impl RssFeed {
             .filter_map(|page| page_to_rss_item(page, &self.config))
             .collect();
 
-        let channel = ChannelBuilder::default()
+        let mut channel = ChannelBuilder::default()
             .title(&self.config.site.info.title)
             .link(self.config.site.info.url.as_deref().unwrap_or_default())
             .description(&self.config.site.info.description)
@@ -46,6 +56,9 @@ impl RssFeed {
             .generator("tola-ssg".to_string())
             .items(items)
             .build();
+        if self.config.site.seo.feed.podcast.enable {
+            channel.set_itunes_ext(podcast_channel_ext(&self.config));
+        }
 
         channel
             .validate()
@@ -56,7 +69,13 @@ impl RssFeed {
     fn write(self) -> Result<()> {
         let minify = self.config.build.minify;
         let output_dir = self.config.paths().output_dir();
-        let feed_path = self.config.site.seo.feed.path.clone();
+        let feed_path = self
+            .config
+            .site
+            .seo
+            .feed
+            .output_path(FeedFormat::Rss)
+            .to_path_buf();
         let xml = self.into_xml()?;
         let xml = minify_xml(xml.as_bytes(), minify);
         // Resolve feed path relative to output_dir (with path_prefix)
@@ -83,16 +102,90 @@ fn page_to_rss_item(page: &FeedPage, config: &SiteConfig) -> Option<rss::Item> {
     // Convert summary JSON to HTML string using shared extractor
     let description = page.summary.clone();
 
-    Some(
-        ItemBuilder::default()
-            .title(page.title.clone())
-            .link(Some(link.clone()))
-            .guid(GuidBuilder::default().permalink(true).value(link).build())
-            .description(description)
-            .pub_date(pub_date)
-            .author(author)
-            .build(),
-    )
+    let mut item = ItemBuilder::default()
+        .title(page.title.clone())
+        .link(Some(link.clone()))
+        .guid(GuidBuilder::default().permalink(true).value(link).build())
+        .description(description)
+        .pub_date(pub_date)
+        .author(author)
+        .build();
+
+    if let Some(episode) = &page.episode {
+        item.set_enclosure(episode_enclosure(episode, config));
+        if config.site.seo.feed.podcast.enable {
+            item.set_itunes_ext(ITunesItemExtension {
+                duration: episode.duration.clone(),
+                ..Default::default()
+            });
+        }
+    }
+
+    Some(item)
+}
+
+/// Channel-level `<itunes:*>` tags from `[site.seo.feed.podcast]`
+fn podcast_channel_ext(config: &SiteConfig) -> ITunesChannelExtension {
+    let podcast = &config.site.seo.feed.podcast;
+    let info = &config.site.info;
+    let or_site = |value: &str, fallback: &str| {
+        let value = if value.is_empty() { fallback } else { value };
+        (!value.is_empty()).then(|| value.to_string())
+    };
+
+    let (category, subcategory) = podcast.category_path();
+    let category = ITunesCategory {
+        text: category.to_string(),
+        subcategory: subcategory.map(|text| {
+            Box::new(ITunesCategory {
+                text: text.to_string(),
+                subcategory: None,
+            })
+        }),
+    };
+
+    ITunesChannelExtension {
+        author: or_site("", &info.author),
+        categories: vec![category],
+        image: Some(asset_url(config, &podcast.image_url)),
+        explicit: Some(podcast.explicit.to_string()),
+        owner: Some(ITunesOwner {
+            name: or_site(&podcast.owner_name, &info.author),
+            email: or_site(&podcast.owner_email, &info.email),
+        }),
+        summary: or_site("", &info.description),
+        ..Default::default()
+    }
+}
+
+/// `<enclosure>` for an episode
+///
+/// A missing length is read from the media file in the output directory,
+/// and a missing type is guessed from the file extension.
+fn episode_enclosure(episode: &FeedEpisode, config: &SiteConfig) -> Enclosure {
+    let is_remote = episode.url.starts_with("http://") || episode.url.starts_with("https://");
+    let length = episode.length.or_else(|| {
+        if is_remote {
+            return None;
+        }
+        let local = config
+            .paths()
+            .output_dir()
+            .join(episode.url.trim_start_matches('/'));
+        fs::metadata(local).ok().map(|m| m.len())
+    });
+    if length.is_none() {
+        log!("warning"; "feed: enclosure_length unknown for {}", episode.url);
+    }
+
+    Enclosure {
+        url: asset_url(config, &episode.url),
+        length: length.unwrap_or_default().to_string(),
+        mime_type: episode
+            .mime_type
+            .clone()
+            .unwrap_or_else(|| mime::from_path(Path::new(&episode.url)).to_string()),
+    }
 }
 
 /// Normalize author field to RSS format: "email (Name)"
@@ -164,6 +257,7 @@ mod tests {
             permalink: "/test/".to_string(),
             summary: Some("A test summary".to_string()),
             author: None,
+            episode: None,
         };
 
         let item = page_to_rss_item(&page, &config).expect("should create item");
@@ -172,6 +266,49 @@ mod tests {
         assert_eq!(item.description(), Some("A test summary"));
     }
 
+    #[test]
+    fn test_page_to_rss_item_podcast_episode() {
+        let mut config = make_config("Host", "host@example.com");
+        config.site.info.title = "My Podcast".to_string();
+        config.site.info.description = "Weekly episodes".to_string();
+        config.site.seo.feed.podcast.enable = true;
+        config.site.seo.feed.podcast.category = "Technology > Podcasting".to_string();
+        config.site.seo.feed.podcast.image_url = "/images/cover.jpg".to_string();
+        let page = FeedPage {
+            title: "Episode 1".to_string(),
+            date: "2024-01-15".to_string(),
+            permalink: "/episodes/1/".to_string(),
+            summary: None,
+            author: None,
+            episode: Some(FeedEpisode {
+                url: "/audio/ep1.mp3".to_string(),
+                length: Some(1024),
+                mime_type: None,
+                duration: Some("00:42:10".to_string()),
+            }),
+        };
+
+        let item = page_to_rss_item(&page, &config).unwrap();
+        let enclosure = item.enclosure().unwrap();
+        assert_eq!(enclosure.url(), "https://example.com/audio/ep1.mp3");
+        assert_eq!(enclosure.length(), "1024");
+        assert_eq!(enclosure.mime_type(), "audio/mpeg");
+        assert_eq!(item.itunes_ext().unwrap().duration(), Some("00:42:10"));
+
+        let xml = RssFeed {
+            config,
+            pages: vec![page],
+        }
+        .into_xml()
+        .unwrap();
+        assert!(xml.contains("xmlns:itunes="));
+        assert!(xml.contains("<itunes:category text=\"Technology\">"));
+        assert!(xml.contains("<itunes:email>host@example.com</itunes:email>"));
+        assert!(xml.contains("https://example.com/images/cover.jpg"));
+        assert!(xml.contains("<itunes:explicit>false</itunes:explicit>"));
+        assert!(xml.contains("<itunes:duration>00:42:10</itunes:duration>"));
+    }
+
     #[test]
     fn test_page_to_rss_item_invalid_date() {
         let config = make_config("Test Author", "test@example.com");
@@ -181,6 +318,7 @@ mod tests {
             permalink: "/test/".to_string(),
             summary: None,
             author: None,
+            episode: None,
         };
 
         // Invalid date format should return None
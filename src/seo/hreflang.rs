@@ -0,0 +1,161 @@
+//! `hreflang` alternates for multilingual sites (`[site.i18n]`).
+//!
+//! A page's counterparts are found by swapping its locale directory:
+//! `/de/posts/hello/` pairs with `/posts/hello/` (default locale) and
+//! `/fr/posts/hello/` when those pages exist. Used for the
+//! `<link rel="alternate" hreflang>` tags in `<head>` and the `<xhtml:link>`
+//! entries of the sitemap.
+
+use crate::config::SiteConfig;
+use crate::core::UrlPath;
+use crate::page::StoredPageMap;
+use crate::utils::path::route::strip_path_prefix;
+
+/// `hreflang` value of the fallback alternate
+pub const X_DEFAULT: &str = "x-default";
+
+/// One language version of a page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Alternate {
+    /// Locale (`de`) or [`X_DEFAULT`].
+    pub hreflang: String,
+    /// Permalink of the version, with `path_prefix`.
+    pub permalink: UrlPath,
+}
+
+/// Language versions of `permalink`, empty unless `[site.i18n]` is enabled
+///
+/// `exists` tells whether a permalink is a page of the site. Pages with
+/// counterparts list every version in `locales` order plus `x-default` (the
+/// default-locale version); pages without one only get `x-default` pointing
+/// to themselves.
+pub fn alternates(
+    config: &SiteConfig,
+    permalink: &UrlPath,
+    exists: impl Fn(&UrlPath) -> bool,
+) -> Vec<Alternate> {
+    let i18n = &config.site.i18n;
+    if !i18n.is_enabled() {
+        return Vec::new();
+    }
+
+    let paths = config.paths();
+    let site_path = strip_path_prefix(permalink.as_str(), &paths.prefix().to_string_lossy());
+    let path = i18n.strip_locale(&site_path);
+
+    let mut versions: Vec<Alternate> = i18n
+        .locales
+        .iter()
+        .filter_map(|locale| {
+            let localized = i18n.localized_path(locale, path);
+            let candidate =
+                UrlPath::from_page(&paths.url_for_rel_path(localized.trim_start_matches('/')));
+            (candidate == *permalink || exists(&candidate)).then(|| Alternate {
+                hreflang: locale.clone(),
+                permalink: candidate,
+            })
+        })
+        .collect();
+
+    if versions.len() < 2 {
+        return vec![Alternate {
+            hreflang: X_DEFAULT.to_string(),
+            permalink: permalink.clone(),
+        }];
+    }
+
+    if let Some(default) = versions
+        .iter()
+        .find(|v| v.hreflang == i18n.default_locale())
+    {
+        let fallback = Alternate {
+            hreflang: X_DEFAULT.to_string(),
+            permalink: default.permalink.clone(),
+        };
+        versions.push(fallback);
+    }
+    versions
+}
+
+/// [`alternates`] of a page among the published pages in `store`
+pub fn page_alternates(
+    config: &SiteConfig,
+    store: &StoredPageMap,
+    permalink: &UrlPath,
+) -> Vec<Alternate> {
+    alternates(config, permalink, |url| {
+        store.get_page(url).is_some_and(|page| !page.is_draft())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_parse_config;
+
+    fn config() -> SiteConfig {
+        test_parse_config("[site.i18n]\nlocales = [\"en\", \"de\", \"fr\"]")
+    }
+
+    fn pairs(alternates: Vec<Alternate>) -> Vec<(String, String)> {
+        alternates
+            .into_iter()
+            .map(|a| (a.hreflang, a.permalink.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_alternates() {
+        let config = config();
+        let pages = ["/posts/hello/", "/de/posts/hello/", "/posts/solo/"];
+        let exists = |url: &UrlPath| pages.contains(&url.as_str());
+
+        let expected = [
+            ("en", "/posts/hello/"),
+            ("de", "/de/posts/hello/"),
+            ("x-default", "/posts/hello/"),
+        ]
+        .map(|(a, b)| (a.to_string(), b.to_string()));
+        for page in ["/posts/hello/", "/de/posts/hello/"] {
+            assert_eq!(
+                pairs(alternates(&config, &UrlPath::from_page(page), exists)),
+                expected
+            );
+        }
+
+        assert_eq!(
+            pairs(alternates(
+                &config,
+                &UrlPath::from_page("/posts/solo/"),
+                exists
+            )),
+            [("x-default".to_string(), "/posts/solo/".to_string())]
+        );
+
+        // A single locale is not multilingual
+        let config = test_parse_config("[site.i18n]\nlocales = [\"en\"]");
+        assert!(alternates(&config, &UrlPath::from_page("/posts/hello/"), exists).is_empty());
+    }
+
+    #[test]
+    fn test_alternates_with_path_prefix() {
+        let mut config = config();
+        config.build.path_prefix = "blog".into();
+        let pages = ["/blog/", "/blog/fr/"];
+        let exists = |url: &UrlPath| pages.contains(&url.as_str());
+
+        assert_eq!(
+            pairs(alternates(
+                &config,
+                &UrlPath::from_page("/blog/fr/"),
+                exists
+            )),
+            [
+                ("en", "/blog/"),
+                ("fr", "/blog/fr/"),
+                ("x-default", "/blog/")
+            ]
+            .map(|(a, b)| (a.to_string(), b.to_string()))
+        );
+    }
+}
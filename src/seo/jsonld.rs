@@ -0,0 +1,141 @@
+//! JSON-LD structured data.
+//!
+//! Composes a schema.org object from page metadata.
+//! VDOM injection is handled by `pipeline/transform/header.rs`.
+
+use serde::Serialize;
+
+use crate::config::SiteConfig;
+use crate::page::PageMeta;
+
+/// schema.org object for a `<script type="application/ld+json">` block.
+///
+/// Empty fields are omitted from the output.
+#[derive(Debug, Serialize)]
+pub struct StructuredData<'a> {
+    #[serde(rename = "@context")]
+    pub context: &'static str,
+    #[serde(rename = "@type")]
+    pub schema_type: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headline: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<Person<'a>>,
+    #[serde(rename = "datePublished", skip_serializing_if = "Option::is_none")]
+    pub date_published: Option<&'a str>,
+    #[serde(rename = "dateModified", skip_serializing_if = "Option::is_none")]
+    pub date_modified: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+/// schema.org `Person`.
+#[derive(Debug, Serialize)]
+pub struct Person<'a> {
+    #[serde(rename = "@type")]
+    pub person_type: &'static str,
+    pub name: &'a str,
+}
+
+impl<'a> StructuredData<'a> {
+    /// Compose structured data for a page.
+    ///
+    /// The page's `schema_type` overrides `build.structured_data.schema`, and
+    /// its `author` overrides `site.info.author`.
+    pub fn new(
+        config: &'a SiteConfig,
+        meta: Option<&'a PageMeta>,
+        headline: Option<&'a str>,
+        url: Option<&'a str>,
+    ) -> Self {
+        let schema_type = meta
+            .and_then(|m| m.schema_type.as_deref())
+            .unwrap_or(&config.build.structured_data.schema);
+        let author = meta
+            .and_then(|m| m.author.as_deref())
+            .filter(|name| !name.is_empty())
+            .unwrap_or(&config.site.info.author);
+        let author = (!author.is_empty()).then_some(Person {
+            person_type: "Person",
+            name: author,
+        });
+
+        Self {
+            context: "https://schema.org",
+            schema_type,
+            headline: headline.filter(|s| !s.is_empty()),
+            author,
+            date_published: meta.and_then(|m| m.date.as_deref()),
+            date_modified: meta.and_then(|m| m.update.as_deref()),
+            url: url.filter(|s| !s.is_empty()),
+        }
+    }
+
+    /// Serialize for embedding in a `<script>` element.
+    ///
+    /// `</` is escaped so the content cannot close the script early.
+    pub fn to_script_json(&self) -> String {
+        serde_json::to_string(self)
+            .unwrap_or_default()
+            .replace("</", "<\\/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_structured_data_from_meta() {
+        let mut config = SiteConfig::default();
+        config.site.info.author = "Site Author".into();
+        let meta = PageMeta {
+            date: Some("2024-01-02".into()),
+            update: Some("2024-03-04".into()),
+            ..Default::default()
+        };
+
+        let data = StructuredData::new(
+            &config,
+            Some(&meta),
+            Some("Hello"),
+            Some("https://example.com/hello/"),
+        );
+        let json: serde_json::Value = serde_json::from_str(&data.to_script_json()).unwrap();
+
+        assert_eq!(json["@context"], "https://schema.org");
+        assert_eq!(json["@type"], "Article");
+        assert_eq!(json["headline"], "Hello");
+        assert_eq!(json["author"]["name"], "Site Author");
+        assert_eq!(json["datePublished"], "2024-01-02");
+        assert_eq!(json["dateModified"], "2024-03-04");
+        assert_eq!(json["url"], "https://example.com/hello/");
+    }
+
+    #[test]
+    fn test_structured_data_schema_type_override() {
+        let config = SiteConfig::default();
+        let meta = PageMeta {
+            schema_type: Some("WebSite".into()),
+            ..Default::default()
+        };
+
+        let json: serde_json::Value = serde_json::from_str(
+            &StructuredData::new(&config, Some(&meta), None, None).to_script_json(),
+        )
+        .unwrap();
+
+        assert_eq!(json["@type"], "WebSite");
+        // Missing values are omitted, not null
+        assert!(json.get("headline").is_none());
+        assert!(json.get("author").is_none());
+        assert!(json.get("datePublished").is_none());
+    }
+
+    #[test]
+    fn test_structured_data_escapes_script_close() {
+        let config = SiteConfig::default();
+        let data = StructuredData::new(&config, None, Some("</script><b>"), None);
+        assert!(!data.to_script_json().contains("</script>"));
+    }
+}
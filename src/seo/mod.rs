@@ -4,18 +4,58 @@
 //!
 //! - **Feed**: RSS/Atom feeds for blog readers (`rss.xml`, `atom.xml`)
 //! - **Sitemap**: Search engine indexing (`sitemap.xml`)
+//! - **Robots**: Crawler rules (`robots.txt`)
+//! - **Search**: Client-side full-text index (`search-index.json`)
 //! - **OG Tags**: Open Graph meta tags for social media sharing
+//! - **JSON-LD**: schema.org structured data for rich search results
+//! - **hreflang**: Language alternates of multilingual pages (`[site.i18n]`)
 //!
 //! All generators use pre-collected `PageMeta` from the build pipeline,
 //! avoiding redundant filesystem scans or re-compilation.
 
 pub mod extract;
 pub mod feed;
+pub mod hreflang;
+pub mod jsonld;
 pub mod og;
+pub mod robots;
+pub mod search;
 pub mod sitemap;
 
 use std::borrow::Cow;
 
+use crate::config::SiteConfig;
+
+/// Absolute URL for an output URL path that already includes `path_prefix`
+///
+/// Only the origin of `site.info.url` is used: its path *is* the prefix, so
+/// appending a prefixed path to the full URL would repeat it.
+pub fn absolute_url(config: &SiteConfig, path: &str) -> String {
+    let origin = config
+        .site
+        .info
+        .url
+        .as_deref()
+        .and_then(|url| url::Url::parse(url).ok())
+        .map(|url| url.origin().ascii_serialization())
+        .unwrap_or_default();
+    format!("{origin}{path}")
+}
+
+/// Absolute URL for an asset referenced from metadata or config
+///
+/// Absolute URLs pass through; site-root asset paths (`/images/cover.png`)
+/// get `build.path_prefix` and the `site.info.url` origin.
+pub fn asset_url(config: &SiteConfig, path: &str) -> String {
+    if path.starts_with("http://") || path.starts_with("https://") {
+        return path.to_string();
+    }
+    let url_path = config
+        .paths()
+        .url_for_rel_path(path.trim_start_matches('/'));
+    absolute_url(config, &url_path)
+}
+
 /// Minify XML content if enabled
 pub fn minify_xml(content: &[u8], enabled: bool) -> Cow<'_, [u8]> {
     if enabled {
@@ -36,6 +76,18 @@ pub fn minify_xml(content: &[u8], enabled: bool) -> Cow<'_, [u8]> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_absolute_url() {
+        let mut config = SiteConfig::default();
+        assert_eq!(absolute_url(&config, "/posts/"), "/posts/");
+
+        config.site.info.url = Some("https://example.com/blog/".into());
+        assert_eq!(
+            absolute_url(&config, "/blog/posts/"),
+            "https://example.com/blog/posts/"
+        );
+    }
+
     #[test]
     fn test_minify_xml_basic() {
         let xml = br#"<?xml version="1.0"?>
@@ -4,28 +4,88 @@
 //! VDOM injection is handled by `pipeline/transform/header.rs`.
 
 use crate::config::SiteConfig;
+use crate::page::PageMeta;
+
+use super::asset_url;
 
 /// Default Open Graph tags from site config.
 ///
-/// Only contains site-level defaults. Page-specific tags (og:title, og:url)
-/// should be set via Typst `og-tags()` function.
+/// Site-level defaults plus the page's `og_image`. Other page-specific tags
+/// (og:title, og:url) should be set via Typst `og-tags()` function.
 pub struct OgDefaults<'a> {
     pub og_type: &'static str,
     pub site_name: &'a str,
     pub locale: &'a str,
     pub description: &'a str,
+    /// Absolute `og:image` URL (page `og_image`, then `site.info.og.default_image`).
+    pub image: Option<String>,
     pub twitter_card: &'static str,
+    pub twitter_site: Option<&'a str>,
 }
 
 impl<'a> OgDefaults<'a> {
-    /// Create default OG tags from site config.
-    pub fn from_config(config: &'a SiteConfig) -> Self {
+    /// Create default OG tags from site config and optional page metadata.
+    pub fn from_config(config: &'a SiteConfig, page_meta: Option<&PageMeta>) -> Self {
+        let og = &config.site.info.og;
+        let image = page_meta
+            .and_then(|meta| meta.og_image.as_deref())
+            .or(og.default_image.as_deref())
+            .map(|path| image_url(config, path));
+
         Self {
             og_type: "website",
             site_name: &config.site.info.title,
             locale: &config.site.info.language,
             description: &config.site.info.description,
-            twitter_card: "summary_large_image",
+            image,
+            twitter_card: og.twitter_card.as_str(),
+            twitter_site: og.twitter_site.as_deref(),
         }
     }
 }
+
+/// Absolute URL for an `og:image` value.
+pub fn image_url(config: &SiteConfig, path: &str) -> String {
+    asset_url(config, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_og_image_fallback() {
+        let mut config = SiteConfig::default();
+        config.site.info.url = Some("https://example.com/blog".into());
+        config.build.path_prefix = "blog".into();
+        assert!(OgDefaults::from_config(&config, None).image.is_none());
+
+        config.site.info.og.default_image = Some("/images/default.png".into());
+        assert_eq!(
+            OgDefaults::from_config(&config, None).image.as_deref(),
+            Some("https://example.com/blog/images/default.png")
+        );
+
+        let meta = PageMeta {
+            og_image: Some("images/post.png".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            OgDefaults::from_config(&config, Some(&meta))
+                .image
+                .as_deref(),
+            Some("https://example.com/blog/images/post.png")
+        );
+
+        let meta = PageMeta {
+            og_image: Some("https://cdn.example.com/post.png".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            OgDefaults::from_config(&config, Some(&meta))
+                .image
+                .as_deref(),
+            Some("https://cdn.example.com/post.png")
+        );
+    }
+}
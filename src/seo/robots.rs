@@ -0,0 +1,123 @@
+//! robots.txt generation.
+//!
+//! Writes a single `User-agent: *` group to the output root:
+//!
+//! ```text
+//! User-agent: *
+//! Disallow: /drafts/
+//! Allow: /drafts/preview/
+//! Crawl-delay: 10
+//!
+//! Sitemap: https://example.com/sitemap.xml
+//! ```
+//!
+//! The `Sitemap:` line points to `sitemap-index.xml` when the sitemap is split.
+
+use crate::config::SiteConfig;
+use crate::log;
+use crate::page::StoredPageMap;
+use crate::seo::absolute_url;
+use anyhow::{Context, Result};
+use std::fs;
+
+/// Build robots.txt if enabled
+///
+/// `store` decides whether the sitemap was split into an index.
+pub fn build_robots(config: &SiteConfig, store: &StoredPageMap) -> Result<()> {
+    if !config.build.robots.enable {
+        return Ok(());
+    }
+
+    let robots_path = config.paths().output_root().join("robots.txt");
+    let url_count = store.get_pages().len();
+    fs::write(&robots_path, render(config, url_count))
+        .with_context(|| format!("Failed to write robots.txt to {}", robots_path.display()))?;
+
+    log!("robots"; "robots.txt");
+    Ok(())
+}
+
+fn render(config: &SiteConfig, url_count: usize) -> String {
+    let robots = &config.build.robots;
+    let mut txt = String::from("User-agent: *\n");
+
+    for path in &robots.disallow {
+        txt.push_str(&format!("Disallow: {path}\n"));
+    }
+    for path in &robots.allow {
+        txt.push_str(&format!("Allow: {path}\n"));
+    }
+    // A group needs at least one rule; an empty `Disallow:` allows everything
+    if robots.disallow.is_empty() && robots.allow.is_empty() {
+        txt.push_str("Disallow:\n");
+    }
+    if let Some(delay) = robots.crawl_delay {
+        txt.push_str(&format!("Crawl-delay: {delay}\n"));
+    }
+    if robots.sitemap
+        && let Some(url) = sitemap_url(config, url_count)
+    {
+        txt.push_str(&format!("\nSitemap: {url}\n"));
+    }
+
+    txt
+}
+
+/// Absolute sitemap (or sitemap index) URL; `Sitemap:` must not be relative
+fn sitemap_url(config: &SiteConfig, url_count: usize) -> Option<String> {
+    let sitemap = &config.site.seo.sitemap;
+    if !sitemap.enable {
+        return None;
+    }
+    if config.site.info.url.is_none() {
+        log!("warning"; "robots.txt: site.info.url is not set, omitting Sitemap line");
+        return None;
+    }
+
+    let path = config
+        .paths()
+        .url_for_rel_path(sitemap.entry_path(url_count));
+    Some(absolute_url(config, &path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SiteConfig {
+        let mut config = SiteConfig::default();
+        config.build.robots.enable = true;
+        config
+    }
+
+    #[test]
+    fn test_render_defaults() {
+        assert_eq!(render(&config(), 1), "User-agent: *\nDisallow:\n");
+    }
+
+    #[test]
+    fn test_render_full() {
+        let mut config = config();
+        config.build.robots.disallow = vec!["/drafts/".into()];
+        config.build.robots.allow = vec!["/drafts/preview/".into()];
+        config.build.robots.crawl_delay = Some(10);
+        config.site.seo.sitemap.enable = true;
+        config.site.info.url = Some("https://example.com".into());
+
+        assert_eq!(
+            render(&config, 1),
+            "User-agent: *\n\
+             Disallow: /drafts/\n\
+             Allow: /drafts/preview/\n\
+             Crawl-delay: 10\n\
+             \n\
+             Sitemap: https://example.com/sitemap.xml\n"
+        );
+
+        config.site.seo.sitemap.split_at = 1;
+        assert!(render(&config, 2).ends_with("Sitemap: https://example.com/sitemap-index.xml\n"));
+
+        config.build.robots.sitemap = false;
+        assert!(!render(&config, 1).contains("Sitemap:"));
+    }
+}
@@ -0,0 +1,127 @@
+//! Client-side search index generation.
+//!
+//! Writes one compact JSON array to `build.search.output_path` (relative to
+//! the output directory):
+//!
+//! ```json
+//! [{"url":"/posts/hello/","title":"Hello","text":"First paragraph ..."}]
+//! ```
+//!
+//! `text` is the page body: plain text from the VDOM text nodes with
+//! whitespace collapsed, or the body HTML of the written page when
+//! `strip_html = false`. It is cut at `max_excerpt_chars` when set.
+//!
+//! Typst pages reach the index URL through `search-index` in `@tola/pages`.
+
+use crate::compiler::page::BUILD_CACHE;
+use crate::config::SiteConfig;
+use crate::log;
+use crate::page::{StoredPage, StoredPageMap, reading};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use tola_vdom::CacheKey;
+
+/// One page in the search index
+#[derive(Debug, Serialize)]
+struct SearchEntry<'a> {
+    url: &'a str,
+    title: &'a str,
+    text: String,
+}
+
+/// Build the search index if enabled
+pub fn build_search_index(config: &SiteConfig, store: &StoredPageMap) -> Result<()> {
+    let search = &config.build.search;
+    if !search.enable {
+        return Ok(());
+    }
+
+    let pages = store.get_pages();
+    let entries: Vec<_> = pages
+        .iter()
+        .map(|page| SearchEntry {
+            url: page.permalink.as_str(),
+            title: page.title(),
+            text: truncate(&page_text(config, page), search.max_excerpt_chars),
+        })
+        .collect();
+
+    let path = config.paths().output_dir().join(&search.output_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let json = serde_json::to_string(&entries)?;
+    fs::write(&path, json)
+        .with_context(|| format!("Failed to write search index to {}", path.display()))?;
+
+    log!("search"; "{}", search.output_path.display());
+    Ok(())
+}
+
+/// Indexed text of one page, empty when its VDOM or output is missing.
+fn page_text(config: &SiteConfig, page: &StoredPage) -> String {
+    if !config.build.search.strip_html {
+        let html_path = page
+            .permalink
+            .output_html_path(config.paths().output_root());
+        return fs::read_to_string(html_path)
+            .map(|html| body_html(&html).trim().to_string())
+            .unwrap_or_default();
+    }
+
+    let key = CacheKey::new(page.permalink.as_str());
+    BUILD_CACHE
+        .with_read(|cache| {
+            cache
+                .get(&key)
+                .map(|entry| collapse_whitespace(&reading::body_text(&entry.doc)))
+        })
+        .unwrap_or_default()
+}
+
+/// Contents of `<body>`, or the whole document without one.
+fn body_html(html: &str) -> &str {
+    let Some(start) = html.find("<body") else {
+        return html;
+    };
+    let Some(open_end) = html[start..].find('>') else {
+        return html;
+    };
+    let inner = &html[start + open_end + 1..];
+    inner.rfind("</body>").map_or(inner, |end| &inner[..end])
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Cut `text` to at most `max` chars.
+fn truncate(text: &str, max: Option<usize>) -> String {
+    match max.and_then(|max| text.char_indices().nth(max)) {
+        Some((end, _)) => text[..end].trim_end().to_string(),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_body_html() {
+        let html = r#"<html><head><title>x</title></head><body class="a"><p>Hi</p></body></html>"#;
+        assert_eq!(body_html(html), "<p>Hi</p>");
+        assert_eq!(body_html("<p>Hi</p>"), "<p>Hi</p>");
+    }
+
+    #[test]
+    fn test_collapse_and_truncate() {
+        assert_eq!(collapse_whitespace("  Hello\n\n  world \t"), "Hello world");
+        assert_eq!(truncate("héllo world", Some(5)), "héllo");
+        assert_eq!(truncate("hello world", Some(6)), "hello");
+        assert_eq!(truncate("hello", Some(10)), "hello");
+        assert_eq!(truncate("hello", None), "hello");
+    }
+}
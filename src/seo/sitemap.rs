@@ -13,23 +13,75 @@
 //!   </url>
 //! </urlset>
 //! ```
+//!
+//! Above `split_at` URLs (default 1000) the sitemap is split into numbered
+//! files (`sitemap-0.xml`, `sitemap-1.xml`, ...) referenced from
+//! `sitemap-index.xml`:
+//!
+//! ```xml
+//! <sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+//!   <sitemap><loc>https://example.com/sitemap-0.xml</loc></sitemap>
+//! </sitemapindex>
+//! ```
+//!
+//! With `[site.i18n]` enabled, each `<url>` lists its language versions as
+//! `<xhtml:link rel="alternate" hreflang="..." href="..."/>` (see
+//! [`crate::seo::hreflang`]).
+//!
+//! `<lastmod>` comes from page `date` metadata by default. With
+//! `site.seo.sitemap.lastmod = "git"` it is the date of the last commit that
+//! touched the source file, or the file mtime for uncommitted files.
 
-use crate::{config::SiteConfig, log, page::StoredPageMap, seo::minify_xml};
+use crate::{
+    config::{SiteConfig, SitemapLastmod},
+    core::UrlPath,
+    log,
+    page::{StoredPageMap, format_ymd},
+    seo::{absolute_url, hreflang, minify_xml},
+    utils::git,
+};
 use anyhow::{Context, Result};
+use gix::ThreadSafeRepository;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::borrow::Cow;
 use std::fs;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
 
 const SITEMAP_NS: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+const XHTML_NS: &str = "http://www.w3.org/1999/xhtml";
 
 /// Build sitemap if enabled
-pub fn build_sitemap(config: &SiteConfig, store: &StoredPageMap) -> Result<()> {
+///
+/// `repo` is the source repository, only used for `lastmod = "git"`.
+pub fn build_sitemap(
+    config: &SiteConfig,
+    store: &StoredPageMap,
+    repo: Option<&ThreadSafeRepository>,
+) -> Result<()> {
     if config.site.seo.sitemap.enable {
-        let sitemap = Sitemap::build(config, store);
+        let sitemap = Sitemap::build(config, store, repo);
         sitemap.write(config)?;
     }
     Ok(())
 }
 
+/// Open the source repository when the sitemap needs git history
+pub fn open_source_repo(config: &SiteConfig) -> Option<ThreadSafeRepository> {
+    let sitemap = &config.site.seo.sitemap;
+    if !sitemap.enable || sitemap.lastmod != SitemapLastmod::Git {
+        return None;
+    }
+
+    match git::discover_repo(config.get_root()) {
+        Ok(repo) => Some(repo),
+        Err(e) => {
+            log!("warning"; "sitemap lastmod = \"git\" but no repository found, using file mtime: {}", e);
+            None
+        }
+    }
+}
+
 struct Sitemap {
     urls: Vec<UrlEntry>,
 }
@@ -37,21 +89,42 @@ struct Sitemap {
 struct UrlEntry {
     loc: String,
     lastmod: Option<String>,
+    /// `(hreflang, absolute URL)` language versions.
+    alternates: Vec<(String, String)>,
 }
 
 impl Sitemap {
-    fn build(config: &SiteConfig, store: &StoredPageMap) -> Self {
+    fn build(
+        config: &SiteConfig,
+        store: &StoredPageMap,
+        repo: Option<&ThreadSafeRepository>,
+    ) -> Self {
         let pages = store.get_pages();
+        let git_lastmods = match config.site.seo.sitemap.lastmod {
+            SitemapLastmod::Date => None,
+            SitemapLastmod::Git => Some(git_lastmods(store, repo)),
+        };
+
+        let permalinks: FxHashSet<&UrlPath> = pages.iter().map(|page| &page.permalink).collect();
 
         let urls: Vec<UrlEntry> = pages
             .iter()
             .map(|page| {
-                let full_url = page
-                    .permalink
-                    .canonical_url(config.site.info.url.as_deref());
+                // Permalinks already carry `path_prefix`
+                let full_url = absolute_url(config, page.permalink.as_str());
+                let lastmod = match &git_lastmods {
+                    Some(lastmods) => lastmods.get(&page.permalink).cloned(),
+                    None => page.meta.date.clone(),
+                };
+                let alternates =
+                    hreflang::alternates(config, &page.permalink, |url| permalinks.contains(url))
+                        .into_iter()
+                        .map(|a| (a.hreflang, absolute_url(config, a.permalink.as_str())))
+                        .collect();
                 UrlEntry {
                     loc: full_url,
-                    lastmod: page.meta.date.clone(),
+                    lastmod,
+                    alternates,
                 }
             })
             .collect();
@@ -60,44 +133,128 @@ impl Sitemap {
     }
 
     fn into_xml(self) -> String {
-        let mut xml = String::with_capacity(4096);
-
-        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
-        xml.push_str("<urlset xmlns=\"");
-        xml.push_str(SITEMAP_NS);
-        xml.push_str("\">\n");
-
-        for entry in self.urls {
-            xml.push_str("  <url>\n    <loc>");
-            xml.push_str(&escape_xml(&entry.loc));
-            xml.push_str("</loc>\n");
-            if let Some(lastmod) = entry.lastmod {
-                xml.push_str("    <lastmod>");
-                xml.push_str(&lastmod);
-                xml.push_str("</lastmod>\n");
-            }
-            xml.push_str("  </url>\n");
+        urlset_xml(&self.urls)
+    }
+
+    fn write(self, config: &SiteConfig) -> Result<()> {
+        // Resolve sitemap paths relative to output_dir (with path_prefix)
+        let paths = config.paths();
+        let output_dir = paths.output_dir();
+        let sitemap = &config.site.seo.sitemap;
+
+        if !sitemap.is_split(self.urls.len()) {
+            return write_xml(config, &output_dir.join(&sitemap.path), self.into_xml());
         }
 
-        xml.push_str("</urlset>\n");
-        xml
+        let mut locs = Vec::new();
+        for (i, urls) in self.urls.chunks(sitemap.split_at).enumerate() {
+            let path = sitemap.numbered_path(&i.to_string());
+            write_xml(config, &output_dir.join(&path), urlset_xml(urls))?;
+            locs.push(absolute_url(config, &paths.url_for_rel_path(&path)));
+        }
+        let index_path = output_dir.join(sitemap.entry_path(self.urls.len()));
+        write_xml(config, &index_path, index_xml(&locs))
     }
+}
 
-    fn write(self, config: &SiteConfig) -> Result<()> {
-        // Resolve sitemap path relative to output_dir (with path_prefix)
-        let sitemap_path = config
-            .paths()
-            .output_dir()
-            .join(&config.site.seo.sitemap.path);
-        let xml = self.into_xml();
-        let xml = minify_xml(xml.as_bytes(), config.build.minify);
+/// `<urlset>` document listing `urls`
+fn urlset_xml(urls: &[UrlEntry]) -> String {
+    let mut xml = String::with_capacity(4096);
+
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<urlset xmlns=\"");
+    xml.push_str(SITEMAP_NS);
+    if urls.iter().any(|entry| !entry.alternates.is_empty()) {
+        xml.push_str("\" xmlns:xhtml=\"");
+        xml.push_str(XHTML_NS);
+    }
+    xml.push_str("\">\n");
+
+    for entry in urls {
+        xml.push_str("  <url>\n    <loc>");
+        xml.push_str(&escape_xml(&entry.loc));
+        xml.push_str("</loc>\n");
+        if let Some(lastmod) = &entry.lastmod {
+            xml.push_str("    <lastmod>");
+            xml.push_str(lastmod);
+            xml.push_str("</lastmod>\n");
+        }
+        for (lang, href) in &entry.alternates {
+            xml.push_str("    <xhtml:link rel=\"alternate\" hreflang=\"");
+            xml.push_str(&escape_xml(lang));
+            xml.push_str("\" href=\"");
+            xml.push_str(&escape_xml(href));
+            xml.push_str("\"/>\n");
+        }
+        xml.push_str("  </url>\n");
+    }
+
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// `<sitemapindex>` document referencing the numbered sitemaps
+fn index_xml(locs: &[String]) -> String {
+    let mut xml = String::with_capacity(256 + locs.len() * 64);
 
-        fs::write(&sitemap_path, &*xml)
-            .with_context(|| format!("Failed to write sitemap to {}", sitemap_path.display()))?;
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<sitemapindex xmlns=\"");
+    xml.push_str(SITEMAP_NS);
+    xml.push_str("\">\n");
 
-        log!("sitemap"; "{}", sitemap_path.file_name().unwrap_or_default().to_string_lossy());
-        Ok(())
+    for loc in locs {
+        xml.push_str("  <sitemap>\n    <loc>");
+        xml.push_str(&escape_xml(loc));
+        xml.push_str("</loc>\n  </sitemap>\n");
     }
+
+    xml.push_str("</sitemapindex>\n");
+    xml
+}
+
+fn write_xml(config: &SiteConfig, path: &Path, xml: String) -> Result<()> {
+    let xml = minify_xml(xml.as_bytes(), config.build.minify);
+    fs::write(path, &*xml)
+        .with_context(|| format!("Failed to write sitemap to {}", path.display()))?;
+
+    log!("sitemap"; "{}", path.file_name().unwrap_or_default().to_string_lossy());
+    Ok(())
+}
+
+/// `YYYY-MM-DD` of the last commit per page, falling back to file mtime
+fn git_lastmods(
+    store: &StoredPageMap,
+    repo: Option<&ThreadSafeRepository>,
+) -> FxHashMap<UrlPath, String> {
+    let sources = store.sources_by_permalink();
+    let paths: Vec<_> = sources.values().cloned().collect();
+
+    let commit_times = repo
+        .map(|repo| {
+            git::last_commit_times(repo, &paths).unwrap_or_else(|e| {
+                log!("warning"; "failed to read git history for sitemap: {}", e);
+                FxHashMap::default()
+            })
+        })
+        .unwrap_or_default();
+
+    sources
+        .into_iter()
+        .filter_map(|(url, source)| {
+            let secs = commit_times
+                .get(&source)
+                .copied()
+                .or_else(|| mtime_secs(&source))?;
+            Some((url, format_ymd(secs)))
+        })
+        .collect()
+}
+
+/// File modification time in seconds since Unix epoch
+fn mtime_secs(path: &Path) -> Option<i64> {
+    let modified = fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    let secs = modified.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    i64::try_from(secs).ok()
 }
 
 /// Escape special XML characters
@@ -158,6 +315,7 @@ mod tests {
             urls: vec![UrlEntry {
                 loc: "https://example.com/search?q=a&b=c".to_string(),
                 lastmod: None,
+                alternates: Vec::new(),
             }],
         };
         let xml = sitemap.into_xml();
@@ -175,12 +333,125 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sitemap_git_lastmod() {
+        use crate::page::PageMeta;
+        use tempfile::TempDir;
+
+        let dir = TempDir::new().unwrap();
+        let committed = dir.path().join("post.typ");
+        let uncommitted = dir.path().join("draft.typ");
+        fs::write(&committed, "= Post").unwrap();
+        let repo = git::create_repo(dir.path()).unwrap();
+        git::commit_all(&repo, "initial").unwrap();
+        fs::write(&uncommitted, "= New").unwrap();
+
+        let store = StoredPageMap::new();
+        for (source, url) in [(&committed, "/post/"), (&uncommitted, "/new/")] {
+            let url = UrlPath::from_page(url);
+            store.insert_source_mapping(source.clone(), url.clone());
+            store.insert_page(
+                url,
+                PageMeta {
+                    date: Some("2000-01-01".to_string()),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let mut config = SiteConfig::default();
+        config.site.seo.sitemap.lastmod = SitemapLastmod::Git;
+        let sitemap = Sitemap::build(&config, &store, Some(&repo));
+
+        let today = format_ymd(mtime_secs(&uncommitted).unwrap());
+        assert_eq!(sitemap.urls.len(), 2);
+        for entry in &sitemap.urls {
+            // Commit time for committed files, mtime otherwise; never the `date` field
+            assert_eq!(entry.lastmod.as_deref(), Some(today.as_str()));
+        }
+
+        config.site.seo.sitemap.lastmod = SitemapLastmod::Date;
+        let sitemap = Sitemap::build(&config, &store, Some(&repo));
+        assert!(
+            sitemap
+                .urls
+                .iter()
+                .all(|entry| entry.lastmod.as_deref() == Some("2000-01-01"))
+        );
+    }
+
+    #[test]
+    fn test_sitemap_hreflang_alternates() {
+        use crate::page::PageMeta;
+
+        let store = StoredPageMap::new();
+        for url in ["/posts/hello/", "/de/posts/hello/"] {
+            store.insert_page(UrlPath::from_page(url), PageMeta::default());
+        }
+
+        let mut config = SiteConfig::default();
+        config.site.info.url = Some("https://example.com".into());
+        let xml = Sitemap::build(&config, &store, None).into_xml();
+        assert!(!xml.contains("xhtml"));
+
+        config.site.i18n.locales = vec!["en".into(), "de".into()];
+        let xml = Sitemap::build(&config, &store, None).into_xml();
+        assert!(xml.contains(&format!(
+            r#"<urlset xmlns="{SITEMAP_NS}" xmlns:xhtml="{XHTML_NS}">"#
+        )));
+        let link = r#"<xhtml:link rel="alternate" hreflang="de" href="https://example.com/de/posts/hello/"/>"#;
+        // Listed under both versions of the page
+        assert_eq!(xml.matches(link).count(), 2);
+        assert_eq!(
+            xml.matches(r#"hreflang="x-default" href="https://example.com/posts/hello/""#)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_sitemap_split_writes_index() {
+        use tempfile::TempDir;
+
+        let dir = TempDir::new().unwrap();
+        let mut config = SiteConfig::default();
+        config.build.output = dir.path().to_path_buf();
+        config.build.path_prefix = "blog".into();
+        config.site.info.url = Some("https://example.com/blog".into());
+        config.site.seo.sitemap.split_at = 2;
+        fs::create_dir_all(config.paths().output_dir()).unwrap();
+
+        let urls = ["/blog/", "/blog/a/", "/blog/b/"]
+            .iter()
+            .map(|path| UrlEntry {
+                loc: absolute_url(&config, path),
+                lastmod: None,
+                alternates: Vec::new(),
+            })
+            .collect();
+        Sitemap { urls }.write(&config).unwrap();
+
+        let output_dir = config.paths().output_dir();
+        assert!(!output_dir.join("sitemap.xml").exists());
+        let first = fs::read_to_string(output_dir.join("sitemap-0.xml")).unwrap();
+        assert_eq!(first.matches("<url>").count(), 2);
+        assert!(first.contains("<loc>https://example.com/blog/a/</loc>"));
+        let second = fs::read_to_string(output_dir.join("sitemap-1.xml")).unwrap();
+        assert_eq!(second.matches("<url>").count(), 1);
+
+        let index = fs::read_to_string(output_dir.join("sitemap-index.xml")).unwrap();
+        assert!(index.contains("<sitemapindex"));
+        assert!(index.contains("<loc>https://example.com/blog/sitemap-0.xml</loc>"));
+        assert!(index.contains("<loc>https://example.com/blog/sitemap-1.xml</loc>"));
+    }
+
     #[test]
     fn test_sitemap_xml_structure() {
         let sitemap = Sitemap {
             urls: vec![UrlEntry {
                 loc: "https://example.com/".to_string(),
                 lastmod: Some("2025-01-01".to_string()),
+                alternates: Vec::new(),
             }],
         };
         let xml = sitemap.into_xml();
@@ -23,6 +23,12 @@
 
 use anyhow::{Result, bail};
 
+/// Weekday names indexed by [`DateTimeUtc::weekday_index`] (Zeller, Saturday first)
+const WEEKDAYS: [&str; 7] = ["Sat", "Sun", "Mon", "Tue", "Wed", "Thu", "Fri"];
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
 /// UTC datetime without timezone complexity
 #[derive(Debug, Clone, Copy)]
 pub struct DateTimeUtc {
@@ -153,11 +159,6 @@ impl DateTimeUtc {
     }
 
     pub fn to_rfc2822(self) -> String {
-        const WEEKDAYS: [&str; 7] = ["Sat", "Sun", "Mon", "Tue", "Wed", "Thu", "Fri"];
-        const MONTHS: [&str; 12] = [
-            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
-        ];
-
         // Zeller's congruence for weekday calculation
         let weekday = self.weekday_index();
 
@@ -218,6 +219,92 @@ fn parse_u16(bytes: &[u8]) -> Option<u16> {
     Some(result)
 }
 
+/// Check an RFC 2822 date-time (RSS `pubDate`).
+///
+/// Accepts `[Day, ]D Mon YYYY hh:mm[:ss] zone`, where the zone is `+hhmm`,
+/// `-hhmm`, `UT`, `GMT` or a US zone (`EST`, `PDT`, ...). Two-digit years
+/// (RFC 822) are accepted; a weekday must match the date.
+pub fn is_rfc2822(s: &str) -> bool {
+    let s = s.trim();
+    let (weekday, rest) = match s.split_once(',') {
+        Some((weekday, rest)) => (Some(weekday.trim()), rest),
+        None => (None, s),
+    };
+
+    let parts: Vec<&str> = rest.split_whitespace().collect();
+    let [day, month, year, time, zone] = parts.as_slice() else {
+        return false;
+    };
+
+    let Some(month) = (1u8..)
+        .zip(MONTHS)
+        .find(|(_, m)| m == month)
+        .map(|(n, _)| n)
+    else {
+        return false;
+    };
+    let day = match day.as_bytes() {
+        [d] => parse_u8(&[b'0', *d]),
+        bytes => parse_u8(bytes),
+    };
+    let Some(day) = day else {
+        return false;
+    };
+    let year = match year.len() {
+        4 => parse_u16(year.as_bytes()),
+        // RFC 822: 00-49 -> 20xx, 50-99 -> 19xx
+        2 => parse_u8(year.as_bytes()).map(|y| {
+            let y = u16::from(y);
+            if y < 50 { 2000 + y } else { 1900 + y }
+        }),
+        _ => None,
+    };
+    let Some(year) = year else {
+        return false;
+    };
+    if day == 0 || day > DateTimeUtc::days_in_month(year, month) {
+        return false;
+    }
+
+    let mut clock = time.split(':');
+    let (Some(hour), Some(minute)) = (clock.next(), clock.next()) else {
+        return false;
+    };
+    let second = clock.next().unwrap_or("00");
+    if clock.next().is_some() {
+        return false;
+    }
+    let (Some(hour), Some(minute), Some(second)) = (
+        parse_u8(hour.as_bytes()),
+        parse_u8(minute.as_bytes()),
+        parse_u8(second.as_bytes()),
+    ) else {
+        return false;
+    };
+    // 60 allows a leap second
+    if hour > 23 || minute > 59 || second > 60 {
+        return false;
+    }
+
+    let zone_ok = match zone.strip_prefix(['+', '-']).map(str::as_bytes) {
+        Some([h1, h2, m1, m2]) => {
+            parse_u8(&[*h1, *h2]).is_some_and(|h| h <= 23)
+                && parse_u8(&[*m1, *m2]).is_some_and(|m| m <= 59)
+        }
+        Some(_) => false,
+        None => matches!(
+            *zone,
+            "UT" | "GMT" | "Z" | "EST" | "EDT" | "CST" | "CDT" | "MST" | "MDT" | "PST" | "PDT"
+        ),
+    };
+    if !zone_ok {
+        return false;
+    }
+
+    let date = DateTimeUtc::from_ymd(year, month, day);
+    weekday.is_none_or(|weekday| WEEKDAYS[date.weekday_index()] == weekday)
+}
+
 /// Parse Typst datetime repr format.
 ///
 /// Handles both single-line and multi-line formats:
@@ -359,4 +446,30 @@ mod tests {
         let dt = DateTimeUtc::new(2024, 1, 15, 10, 30, 45);
         assert_eq!(dt.to_rfc2822(), "Mon, 15 Jan 2024 10:30:45 GMT");
     }
+
+    #[test]
+    fn test_is_rfc2822() {
+        for valid in [
+            "Mon, 15 Jan 2024 10:30:45 GMT",
+            DateTimeUtc::new(2024, 2, 29, 0, 0, 0).to_rfc2822().as_str(),
+            "15 Jan 2024 10:30 +0200",
+            "Thu, 1 Feb 2024 23:59:60 -0530",
+            "Sat, 15 Jun 24 14:30:45 EST",
+        ] {
+            assert!(is_rfc2822(valid), "{valid:?}");
+        }
+        for invalid in [
+            "",
+            "2024-01-15T10:30:45Z",
+            "Tue, 15 Jan 2024 10:30:45 GMT",
+            "Mon, 15 January 2024 10:30:45 GMT",
+            "Thu, 30 Feb 2024 10:30:45 GMT",
+            "Mon, 15 Jan 2024 24:00:00 GMT",
+            "Mon, 15 Jan 2024 10:30:45",
+            "Mon, 15 Jan 2024 10:30:45 +02:00",
+            "Mon, 15 Jan 2024 10:30:45 CET",
+        ] {
+            assert!(!is_rfc2822(invalid), "{invalid:?}");
+        }
+    }
 }
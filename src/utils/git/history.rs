@@ -0,0 +1,142 @@
+//! Commit history lookups.
+
+use anyhow::Result;
+use gix::{ObjectId, ThreadSafeRepository};
+use rustc_hash::FxHashMap;
+use std::path::{Path, PathBuf};
+
+use super::repo::get_repo_root;
+use crate::utils::path::normalize_path;
+
+/// A path still being traced back through history
+struct Tracked {
+    path: PathBuf,
+    rel: PathBuf,
+    blob: ObjectId,
+    /// Commit time of the oldest commit seen so far with the same blob.
+    time: i64,
+}
+
+/// Unix timestamp of the last commit that changed each path
+///
+/// Walks first-parent history from `HEAD` once, comparing each path's blob
+/// between consecutive commits. Paths outside the repository or not present
+/// in `HEAD` are absent from the result.
+pub fn last_commit_times(
+    repo: &ThreadSafeRepository,
+    paths: &[PathBuf],
+) -> Result<FxHashMap<PathBuf, i64>> {
+    let repo = repo.to_thread_local();
+    let root = normalize_path(get_repo_root(&repo)?);
+    let mut found = FxHashMap::default();
+
+    // Unborn HEAD: nothing committed yet
+    let Ok(head) = repo.head_id() else {
+        return Ok(found);
+    };
+    let mut walk = repo.rev_walk([head]).first_parent_only().all()?;
+
+    // Seed with blob ids at HEAD
+    let Some(info) = walk.next() else {
+        return Ok(found);
+    };
+    let commit = info?.object()?;
+    let time = commit.time()?.seconds;
+    let tree = commit.tree()?;
+    let mut pending: Vec<Tracked> = paths
+        .iter()
+        .filter_map(|path| {
+            let rel = normalize_path(path).strip_prefix(&root).ok()?.to_path_buf();
+            let blob = blob_at(&tree, &rel)?;
+            Some(Tracked {
+                path: path.clone(),
+                rel,
+                blob,
+                time,
+            })
+        })
+        .collect();
+
+    for info in walk {
+        if pending.is_empty() {
+            break;
+        }
+        let commit = info?.object()?;
+        let time = commit.time()?.seconds;
+        let tree = commit.tree()?;
+
+        pending.retain_mut(|tracked| {
+            if blob_at(&tree, &tracked.rel) == Some(tracked.blob) {
+                tracked.time = time;
+                return true;
+            }
+            // Changed (or added) by the newer commit
+            found.insert(tracked.path.clone(), tracked.time);
+            false
+        });
+    }
+
+    // Unchanged since the root commit
+    for tracked in pending {
+        found.insert(tracked.path, tracked.time);
+    }
+
+    Ok(found)
+}
+
+/// Blob id of `rel` in `tree`, if present
+fn blob_at(tree: &gix::Tree<'_>, rel: &Path) -> Option<ObjectId> {
+    tree.lookup_entry_by_path(rel)
+        .ok()
+        .flatten()
+        .map(|entry| entry.object_id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::git::{commit_all, create_repo};
+    use std::fs;
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_last_commit_times() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("content")).unwrap();
+        let committed = root.join("content/post.typ");
+        fs::write(&committed, "= Post").unwrap();
+
+        let repo = create_repo(root).unwrap();
+        commit_all(&repo, "initial").unwrap();
+
+        let untracked = root.join("content/new.typ");
+        fs::write(&untracked, "= New").unwrap();
+        let outside = TempDir::new().unwrap().path().join("other.typ");
+
+        let times =
+            last_commit_times(&repo, &[committed.clone(), untracked.clone(), outside]).unwrap();
+
+        let now = i64::try_from(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        )
+        .unwrap();
+        let time = times[&committed];
+        assert!((now - 60..=now + 60).contains(&time));
+        assert_eq!(times.len(), 1);
+    }
+
+    #[test]
+    fn test_last_commit_times_unborn_head() {
+        let dir = TempDir::new().unwrap();
+        let repo = create_repo(dir.path()).unwrap();
+
+        let times = last_commit_times(&repo, &[dir.path().join("a.typ")]).unwrap();
+
+        assert!(times.is_empty());
+    }
+}
@@ -1,11 +1,17 @@
 //! Git operations for the static site generator.
 //!
-//! Handles repository initialization, commits, and remote pushing.
+//! Handles repository initialization, commits, remote pushing, history lookups,
+//! and read-only snapshots for deploy dry runs.
 
+mod history;
 mod ignore;
 mod remote;
 mod repo;
+mod snapshot;
 mod tree;
 
+pub use history::last_commit_times;
+pub use ignore::IgnoreMatcher;
 pub use remote::push;
-pub use repo::{commit_all, create_repo, open_repo};
+pub use repo::{commit_all, create_repo, current_branch, discover_repo, get_repo_root, open_repo};
+pub use snapshot::{head_blobs, worktree_blobs};
@@ -79,16 +79,16 @@ fn push_to_remote(root: &Path, branch: &str, force: bool) -> Result<()> {
     Ok(())
 }
 
+/// Env var holding the deploy token itself, preferred over `token_path`
+const TOKEN_ENV: &str = "TOLA_DEPLOY_TOKEN";
+
 /// Build authenticated HTTPS URL with optional token
 fn build_authenticated_url(url: &str, token_path: Option<&std::path::PathBuf>) -> Result<String> {
     let base_url = url
         .strip_prefix("https://")
         .context("Remote URL must start with https://")?;
 
-    let token = token_path
-        .and_then(|p| fs::read_to_string(p).ok())
-        .map(|s| s.trim().to_owned())
-        .filter(|s| !s.is_empty());
+    let token = resolve_token(std::env::var(TOKEN_ENV).ok(), token_path);
 
     Ok(token.map_or_else(
         || format!("https://{base_url}"),
@@ -96,6 +96,17 @@ fn build_authenticated_url(url: &str, token_path: Option<&std::path::PathBuf>) -
     ))
 }
 
+/// Token from `TOLA_DEPLOY_TOKEN` if set, else read from `token_path`
+fn resolve_token(
+    env_token: Option<String>,
+    token_path: Option<&std::path::PathBuf>,
+) -> Option<String> {
+    env_token
+        .or_else(|| token_path.and_then(|p| fs::read_to_string(p).ok()))
+        .map(|s| s.trim().to_owned())
+        .filter(|s| !s.is_empty())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -155,6 +166,20 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_resolve_token_prefers_env() {
+        with_temp_dir(|dir| {
+            let token_path = dir.join("token");
+            fs::write(&token_path, "ghp_file\n").unwrap();
+
+            let token = resolve_token(Some("ghp_env\n".into()), Some(&token_path));
+            assert_eq!(token.as_deref(), Some("ghp_env"));
+            let token = resolve_token(None, Some(&token_path));
+            assert_eq!(token.as_deref(), Some("ghp_file"));
+            assert_eq!(resolve_token(Some(String::new()), None), None);
+        });
+    }
+
     #[test]
     fn test_build_authenticated_url_invalid_scheme() {
         let url = "http://github.com/user/repo.git";
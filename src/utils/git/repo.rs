@@ -16,6 +16,12 @@ pub fn open_repo(root: &Path) -> Result<ThreadSafeRepository> {
     Ok(repo.into_sync())
 }
 
+/// Find the repository containing `path`, searching parent directories
+pub fn discover_repo(path: &Path) -> Result<ThreadSafeRepository> {
+    let repo = gix::discover(path)?;
+    Ok(repo.into_sync())
+}
+
 /// Commit all changes in the repository
 pub fn commit_all(repo: &ThreadSafeRepository, message: &str) -> Result<gix::ObjectId> {
     if message.trim().is_empty() {
@@ -50,8 +56,14 @@ pub fn get_repo_root(repo: &Repository) -> Result<&Path> {
         .ok_or_else(|| anyhow!("Invalid repository path"))
 }
 
+/// Short name of the checked-out branch (`None` when HEAD is detached)
+pub fn current_branch(repo: &ThreadSafeRepository) -> Result<Option<String>> {
+    let repo = repo.to_thread_local();
+    Ok(repo.head_name()?.map(|name| name.shorten().to_string()))
+}
+
 /// Read .gitignore file if it exists
-fn read_gitignore(root: &Path) -> Result<Vec<u8>> {
+pub(super) fn read_gitignore(root: &Path) -> Result<Vec<u8>> {
     let path = root.join(".gitignore");
     if path.exists() {
         Ok(fs::read(path)?)
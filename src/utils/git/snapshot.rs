@@ -0,0 +1,115 @@
+//! Read-only file snapshots.
+//!
+//! Maps repo-relative paths to blob ids, so a working directory can be
+//! compared against `HEAD` without writing objects or touching the index.
+
+use anyhow::Result;
+use gix::ThreadSafeRepository;
+use std::collections::BTreeMap;
+use std::{fs, path::Path};
+
+use super::ignore::IgnoreMatcher;
+use super::repo::read_gitignore;
+
+/// Blob id (hex) of every file committed at `HEAD`
+///
+/// Empty for a repository without commits.
+pub fn head_blobs(repo: &ThreadSafeRepository) -> Result<BTreeMap<String, String>> {
+    let repo = repo.to_thread_local();
+    let Ok(commit) = repo.head_commit() else {
+        return Ok(BTreeMap::new());
+    };
+
+    let mut recorder = gix::traverse::tree::Recorder::default();
+    commit.tree()?.traverse().breadthfirst(&mut recorder)?;
+
+    Ok(recorder
+        .records
+        .into_iter()
+        .filter(|entry| !entry.mode.is_tree())
+        .map(|entry| (entry.filepath.to_string(), entry.oid.to_hex().to_string()))
+        .collect())
+}
+
+/// Blob id (hex) each file under `root` would get when committed
+///
+/// Skips `.git` and paths ignored by `root/.gitignore`, like `commit_all`.
+pub fn worktree_blobs(root: &Path) -> Result<BTreeMap<String, String>> {
+    let matcher = IgnoreMatcher::new(&read_gitignore(root)?);
+    let mut blobs = BTreeMap::new();
+    collect_blobs(root, root, &matcher, &mut blobs)?;
+    Ok(blobs)
+}
+
+fn collect_blobs(
+    root: &Path,
+    dir: &Path,
+    matcher: &IgnoreMatcher,
+    blobs: &mut BTreeMap<String, String>,
+) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let rel_path = path
+            .strip_prefix(root)?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let is_dir = path.is_dir();
+
+        if path.file_name().is_some_and(|name| name == ".git") || matcher.matches(&rel_path, is_dir)
+        {
+            continue;
+        }
+
+        if is_dir {
+            collect_blobs(root, &path, matcher, blobs)?;
+        } else if path.is_file() {
+            blobs.insert(rel_path, blob_id(&fs::read(&path)?)?);
+        }
+    }
+    Ok(())
+}
+
+/// Git blob id: SHA1 over `blob <len>\0<content>`
+fn blob_id(content: &[u8]) -> Result<String> {
+    let mut hasher = gix::hash::hasher(gix::hash::Kind::Sha1);
+    hasher.update(format!("blob {}\0", content.len()).as_bytes());
+    hasher.update(content);
+    Ok(hasher.try_finalize()?.to_hex().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::git::commit_all;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_blob_id_matches_git() {
+        // `echo -n hello | git hash-object --stdin`
+        assert_eq!(
+            blob_id(b"hello").unwrap(),
+            "b6fc4c620b67d95f953a5c1c1230aaab5db5a1b0"
+        );
+    }
+
+    #[test]
+    fn test_worktree_matches_head_after_commit() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let repo = gix::init(root).unwrap().into_sync();
+
+        fs::create_dir(root.join("posts")).unwrap();
+        fs::write(root.join("index.html"), "home").unwrap();
+        fs::write(root.join("posts/a.html"), "a").unwrap();
+        fs::write(root.join("skip.log"), "log").unwrap();
+        fs::write(root.join(".gitignore"), "*.log\n").unwrap();
+
+        assert!(head_blobs(&repo).unwrap().is_empty());
+
+        commit_all(&repo, "init").unwrap();
+        let worktree = worktree_blobs(root).unwrap();
+        assert!(worktree.contains_key("posts/a.html"));
+        assert!(!worktree.contains_key("skip.log"));
+        assert_eq!(head_blobs(&repo).unwrap(), worktree);
+    }
+}
@@ -0,0 +1,106 @@
+//! Glob matching for path patterns in `tola.toml`.
+//!
+//! Every config glob (`build.only`, `build.assets.images.glob`,
+//! `build.compression.exclude`, `site.header` entries, `serve.watch_ignore`,
+//! `[[serve.middleware]]`, `[[serve.headers]]`,
+//! `validate.assets.orphans.ignore`) follows the same rules:
+//!
+//! - Patterns match `/`-separated paths relative to the setting's base (site
+//!   root, content directory, output directory, or URL root). A leading `/`
+//!   is optional: `/assets/**` and `assets/**` are the same pattern.
+//! - `*` and `?` stay within one path segment; `**` crosses `/`.
+//! - `{a,b}` expands to alternatives (`*.{css,js}`).
+//! - `dir/**` also matches `dir` itself, so whole directories can be skipped.
+
+use std::path::Path;
+
+use gix::{bstr::ByteSlice, glob::wildmatch};
+
+/// Whether `path` matches `pattern`.
+pub fn matches(pattern: &str, path: &str) -> bool {
+    let path = path.trim_start_matches('/');
+    expand_braces(pattern).iter().any(|pattern| {
+        let pattern = pattern.trim_start_matches('/');
+        wildmatch_one(pattern, path)
+            || pattern
+                .strip_suffix("/**")
+                .is_some_and(|dir| wildmatch_one(dir, path))
+    })
+}
+
+/// Whether a filesystem `path` matches `pattern` (`\` is treated as `/`).
+pub fn matches_path(pattern: &str, path: &Path) -> bool {
+    matches(pattern, &path.to_string_lossy().replace('\\', "/"))
+}
+
+/// Whether `s` contains glob syntax rather than naming a single path.
+pub fn is_glob(s: &str) -> bool {
+    s.contains(['*', '?', '[', '{'])
+}
+
+fn wildmatch_one(pattern: &str, path: &str) -> bool {
+    wildmatch(
+        pattern.as_bytes().as_bstr(),
+        path.as_bytes().as_bstr(),
+        wildmatch::Mode::NO_MATCH_SLASH_LITERAL,
+    )
+}
+
+/// Expand `{a,b}` alternatives (`*.{css,js}` -> `*.css`, `*.js`).
+fn expand_braces(pattern: &str) -> Vec<String> {
+    let Some(open) = pattern.find('{') else {
+        return vec![pattern.to_string()];
+    };
+    let Some(close) = pattern[open..].find('}').map(|i| open + i) else {
+        return vec![pattern.to_string()];
+    };
+    let (head, tail) = (&pattern[..open], &pattern[close + 1..]);
+    pattern[open + 1..close]
+        .split(',')
+        .flat_map(|alt| expand_braces(&format!("{head}{alt}{tail}")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_braces() {
+        assert_eq!(expand_braces("/**"), ["/**"]);
+        assert_eq!(expand_braces("**/*.{css,js}"), ["**/*.css", "**/*.js"]);
+        assert_eq!(
+            expand_braces("/{a,b}/*.{png,jpg}"),
+            ["/a/*.png", "/a/*.jpg", "/b/*.png", "/b/*.jpg"]
+        );
+        assert_eq!(expand_braces("/a{b"), ["/a{b"]);
+    }
+
+    #[test]
+    fn test_matches() {
+        // Leading `/` is optional on both sides
+        assert!(matches("/assets/**", "assets/a.css"));
+        assert!(matches("assets/**", "/assets/a.css"));
+        assert!(matches("/**", "/"));
+
+        // `*` stays in one segment, `**` crosses
+        assert!(matches("*.txt", "robots.txt"));
+        assert!(!matches("*.txt", "docs/notes.txt"));
+        assert!(matches("**/*.txt", "docs/notes.txt"));
+
+        // Braces and `dir/**` matching `dir`
+        assert!(matches("**/*.{css,js}", "/a/b/site.css"));
+        assert!(matches("**/*.{css,js}", "app.js"));
+        assert!(matches("content/generated/**", "content/generated"));
+        assert!(!matches("/assets/**", "/assetsx/a.css"));
+    }
+
+    #[test]
+    fn test_matches_path_and_is_glob() {
+        assert!(matches_path("content/**", Path::new("content/posts/a.png")));
+        assert!(!matches_path("*.png", Path::new("content/a.png")));
+        assert!(is_glob("styles/*.css"));
+        assert!(is_glob("styles/{a,b}.css"));
+        assert!(!is_glob("styles/main.css"));
+    }
+}
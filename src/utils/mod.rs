@@ -8,6 +8,7 @@
 //! - [`date`]: Date/time formatting (RFC 2822, etc.)
 //! - [`exec`]: External command execution
 //! - [`git`]: Git repository operations
+//! - [`glob`]: Glob matching for config path patterns
 //! - [`hash`]: Content hashing (BLAKE3)
 //! - [`path`]: Path and URL utilities
 //! - [`platform`]: Platform-specific helpers
@@ -16,6 +17,7 @@
 pub mod date;
 pub mod exec;
 pub mod git;
+pub mod glob;
 pub mod hash;
 pub mod html;
 pub mod mime;
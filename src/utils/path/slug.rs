@@ -12,6 +12,13 @@
 //! | `Ascii` | -> ASCII | -> separator | configurable | `"Café World"` -> `"Cafe-World"` |
 //! | `No` | preserved | preserved | preserved | `"Café World"` -> `"Café World"` |
 //!
+//! # Transforms and Length
+//!
+//! `transform = "unicode-latin"` runs before the mode: NFKD normalization plus
+//! Latin transliteration (`"Grüße"` -> `"Gruesse"`, `"Đà Nẵng"` -> `"Da Nang"`).
+//! Other scripts are left to the mode. `max_length` truncates each slug at the
+//! last separator that fits.
+//!
 //! # Forbidden Characters
 //!
 //! The following characters are replaced with the separator:
@@ -30,9 +37,11 @@
 //! slugify_on("München", '-')       // -> "munchen"
 //! ```
 
-use crate::config::{SlugCase, SlugConfig, SlugMode};
+use crate::config::{SlugCase, SlugConfig, SlugMode, SlugTransform};
 use std::borrow::Cow;
 use std::path::{Path, PathBuf};
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
 
 /// Characters that are unsafe for URLs and file paths
 ///
@@ -60,15 +69,18 @@ pub const FORBIDDEN_CHARS: &[char] = &[
 /// ```
 pub fn slugify_fragment(text: &str, slug: &SlugConfig) -> String {
     let sep = slug.separator.as_char();
+    if slug.fragment == SlugMode::No {
+        return text.to_owned();
+    }
 
+    let text = apply_transform(text, &slug.transform);
     let result = match slug.fragment {
-        SlugMode::No => return text.to_owned(),
-        SlugMode::Full => slugify_full(text, sep),
-        SlugMode::Safe => sanitize(text, sep),
-        SlugMode::Ascii => sanitize(&deunicode::deunicode(text), sep),
+        SlugMode::Full => slugify_full(&text, sep),
+        SlugMode::Safe | SlugMode::No => sanitize(&text, sep),
+        SlugMode::Ascii => sanitize(&deunicode::deunicode(&text), sep),
     };
 
-    apply_case(&result, &slug.case).into_owned()
+    truncate_at_word(&apply_case(&result, &slug.case), sep, slug.max_length)
 }
 
 /// Converts a file path to URL-safe format
@@ -87,14 +99,21 @@ pub fn slugify_fragment(text: &str, slug: &SlugConfig) -> String {
 /// ```
 pub fn slugify_path(path: impl AsRef<Path>, slug: &SlugConfig) -> PathBuf {
     let sep = slug.separator.as_char();
+    if slug.path == SlugMode::No {
+        return path.as_ref().to_path_buf();
+    }
 
-    match slug.path {
-        SlugMode::No => path.as_ref().to_path_buf(),
+    let original = path.as_ref().to_string_lossy();
+    let text = apply_transform(&original, &slug.transform);
+    let path = Path::new(&*text);
+    let result = match slug.path {
         // Full mode: process each component with full slugification (ASCII + lowercase)
-        SlugMode::Full => transform_path_components_full(path.as_ref(), sep),
-        SlugMode::Safe => transform_path_components(path.as_ref(), sep, &slug.case, false),
-        SlugMode::Ascii => transform_path_components(path.as_ref(), sep, &slug.case, true),
-    }
+        SlugMode::Full => transform_path_components_full(path, sep),
+        SlugMode::Safe | SlugMode::No => transform_path_components(path, sep, &slug.case, false),
+        SlugMode::Ascii => transform_path_components(path, sep, &slug.case, true),
+    };
+
+    truncate_path_components(&result, sep, slug.max_length)
 }
 
 // ============================================================================
@@ -179,10 +198,145 @@ fn transform_path_components(path: &Path, sep: char, case: &SlugCase, to_ascii:
         .collect()
 }
 
+/// Applies the configured pre-mode transformation
+fn apply_transform<'a>(text: &'a str, transform: &SlugTransform) -> Cow<'a, str> {
+    match transform {
+        SlugTransform::None => Cow::Borrowed(text),
+        SlugTransform::UnicodeLatin => Cow::Owned(transliterate_latin(text)),
+    }
+}
+
+/// NFKD normalization + Latin transliteration
+///
+/// Letters with a conventional multi-letter spelling are expanded first
+/// (`ä` -> `ae`, `ß` -> `ss`), then diacritics are stripped from Latin base
+/// letters. Combining marks on other scripts (kana voicing marks, Arabic
+/// harakat) are kept and the result is recomposed to NFC, so CJK, Hangul,
+/// Arabic and emoji come out unchanged.
+///
+/// # Examples
+/// ```ignore
+/// transliterate_latin("Grüße aus Köln") // -> "Gruesse aus Koeln"
+/// transliterate_latin("Crème brûlée")   // -> "Creme brulee"
+/// transliterate_latin("ﬁle Ｎo.①")       // -> "file No.1"
+/// transliterate_latin("東京 café")       // -> "東京 cafe"
+/// ```
+fn transliterate_latin(text: &str) -> String {
+    // Compose first so decomposed input (e.g. macOS file names) matches the table
+    let mut expanded = String::with_capacity(text.len());
+    for c in text.nfc() {
+        match latin_expansion(c) {
+            Some(s) => expanded.push_str(s),
+            None => expanded.push(c),
+        }
+    }
+
+    let mut stripped = String::with_capacity(expanded.len());
+    let mut latin_base = false;
+    for c in expanded.nfkd() {
+        if is_combining_mark(c) {
+            if latin_base {
+                continue;
+            }
+        } else {
+            latin_base = c.is_ascii_alphabetic();
+        }
+        stripped.push(c);
+    }
+
+    stripped.nfc().collect()
+}
+
+/// Latin letters that NFKD does not reduce to ASCII, or that have a
+/// conventional multi-letter spelling
+const fn latin_expansion(c: char) -> Option<&'static str> {
+    Some(match c {
+        'ä' => "ae",
+        'Ä' => "Ae",
+        'ö' => "oe",
+        'Ö' => "Oe",
+        'ü' => "ue",
+        'Ü' => "Ue",
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'Æ' => "Ae",
+        'œ' => "oe",
+        'Œ' => "Oe",
+        'ø' => "o",
+        'Ø' => "O",
+        'ł' => "l",
+        'Ł' => "L",
+        'đ' | 'ð' => "d",
+        'Đ' | 'Ð' => "D",
+        'ħ' => "h",
+        'Ħ' => "H",
+        'ŧ' => "t",
+        'Ŧ' => "T",
+        'ı' => "i",
+        'þ' => "th",
+        'Þ' => "Th",
+        _ => return None,
+    })
+}
+
+/// Truncates each path component to `max_length` characters
+///
+/// A trailing extension is kept and not counted (`very-long-name.png`).
+fn truncate_path_components(path: &Path, sep: char, max_length: usize) -> PathBuf {
+    if max_length == 0 {
+        return path.to_path_buf();
+    }
+
+    path.components()
+        .map(|component| {
+            let text = component.as_os_str().to_string_lossy();
+            match text.rsplit_once('.') {
+                Some((stem, ext)) if !stem.is_empty() && !ext.is_empty() && !ext.contains(sep) => {
+                    format!("{}.{ext}", truncate_at_word(stem, sep, max_length))
+                }
+                _ => truncate_at_word(&text, sep, max_length),
+            }
+        })
+        .collect()
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Truncates text to at most `max_length` characters at a separator
+///
+/// Falls back to a hard cut when the first word alone is too long.
+/// `max_length = 0` means unlimited.
+///
+/// # Examples
+/// ```ignore
+/// truncate_at_word("hello-big-world", '-', 12) // -> "hello-big"
+/// truncate_at_word("hello-big-world", '-', 9)  // -> "hello-big"
+/// truncate_at_word("supercalifragilistic", '-', 5) // -> "super"
+/// ```
+fn truncate_at_word(text: &str, sep: char, max_length: usize) -> String {
+    if max_length == 0 {
+        return text.to_owned();
+    }
+    let Some((end, _)) = text.char_indices().nth(max_length) else {
+        return text.to_owned();
+    };
+
+    let (head, rest) = text.split_at(end);
+    let cut = if rest.starts_with(sep) {
+        head
+    } else {
+        match head.rfind(sep) {
+            Some(i) if i > 0 => &head[..i],
+            _ => head,
+        }
+    };
+    // Never end on a separator or a dangling zero-width joiner
+    cut.trim_end_matches([sep, '\u{200d}']).to_owned()
+}
+
 /// Replaces forbidden characters and whitespace with the separator
 #[inline]
 fn replace_special_chars(text: &str, sep: char) -> String {
@@ -556,6 +710,122 @@ mod tests {
     // Integration tests with SlugConfig
     // ========================================================================
 
+    // ========================================================================
+    // transliterate_latin() tests (SlugTransform::UnicodeLatin)
+    // ========================================================================
+
+    #[test]
+    fn test_transliterate_latin_cases() {
+        let cases = [
+            // German, incl. capitals and sharp s
+            ("Grüße aus Köln", "Gruesse aus Koeln"),
+            ("ÄRGER Über Öl", "AeRGER Ueber Oel"),
+            ("Straße ẞ", "Strasse SS"),
+            // Decomposed input (NFD file names) matches the same table
+            ("Gru\u{308}n", "Gruen"),
+            ("Cafe\u{301}", "Cafe"),
+            // Diacritics stripped
+            ("Crème brûlée à la française", "Creme brulee a la francaise"),
+            ("señor niño", "senor nino"),
+            ("Đà Nẵng Việt Nam", "Da Nang Viet Nam"),
+            ("Łódź Kraków", "Lodz Krakow"),
+            ("Ærøskøbing Åland", "Aeroskobing Aland"),
+            ("Œuvre þing", "Oeuvre thing"),
+            ("naïve Noël", "naive Noel"),
+            // NFKD compatibility forms
+            ("ﬁle", "file"),
+            ("Ｆｕｌｌ", "Full"),
+            ("①x²", "1x2"),
+            // Other scripts unchanged
+            ("日本語 テスト", "日本語 テスト"),
+            ("がぎぐ パピプ", "がぎぐ パピプ"),
+            ("한글 테스트", "한글 테스트"),
+            ("Привет", "Привет"),
+            ("مَرْحَبًا بالعالم", "مَرْحَبًا بالعالم"),
+            ("Ελληνικά", "Ελληνικά"),
+            // Emoji, incl. ZWJ sequences, modifiers and variation selectors
+            ("Hello 🎉", "Hello 🎉"),
+            ("👨‍👩‍👧 👍🏽 ❤️", "👨‍👩‍👧 👍🏽 ❤️"),
+            // Mixed scripts
+            ("Café 北京 Ünïcode", "Cafe 北京 Uenicode"),
+            ("München-東京-Dubai-دبي", "Muenchen-東京-Dubai-دبي"),
+            // ASCII untouched
+            ("", ""),
+            ("plain-ascii_123", "plain-ascii_123"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(transliterate_latin(input), expected, "{input:?}");
+        }
+    }
+
+    // ========================================================================
+    // truncate_at_word() tests
+    // ========================================================================
+
+    #[test]
+    fn test_truncate_at_word_cases() {
+        let cases = [
+            ("hello-big-world", 0, "hello-big-world"),
+            ("hello-big-world", 15, "hello-big-world"),
+            ("hello-big-world", 100, "hello-big-world"),
+            // Cut right before a separator keeps the whole word
+            ("hello-big-world", 9, "hello-big"),
+            ("hello-big-world", 10, "hello-big"),
+            ("hello-big-world", 12, "hello-big"),
+            // Single long word falls back to a hard cut
+            ("supercalifragilistic", 5, "super"),
+            ("supercalifragilistic-word", 8, "supercal"),
+            ("a-supercalifragilistic", 8, "a"),
+            // Counted in characters, not bytes
+            ("日本語-テスト-です", 7, "日本語-テスト"),
+            ("日本語テスト", 3, "日本語"),
+            ("مرحبا-بالعالم", 8, "مرحبا"),
+            // Emoji ZWJ sequences never end on the joiner
+            ("👨‍👩‍👧", 2, "👨"),
+            ("🎉-🚀-✨", 3, "🎉-🚀"),
+        ];
+        for (input, max, expected) in cases {
+            assert_eq!(
+                truncate_at_word(input, SEP_DASH, max),
+                expected,
+                "{input:?}"
+            );
+        }
+        assert_eq!(
+            truncate_at_word("hello_big_world", SEP_UNDERSCORE, 12),
+            "hello_big"
+        );
+        // Only the configured separator is a word boundary
+        assert_eq!(
+            truncate_at_word("hello-big_world", SEP_UNDERSCORE, 12),
+            "hello-big"
+        );
+        assert_eq!(
+            truncate_at_word("hello_big-world", SEP_UNDERSCORE, 12),
+            "hello"
+        );
+    }
+
+    #[test]
+    fn test_truncate_path_components() {
+        assert_eq!(
+            truncate_path_components(
+                Path::new("my-long-section/my-very-long-post-title"),
+                SEP_DASH,
+                10
+            ),
+            PathBuf::from("my-long/my-very")
+        );
+        assert_eq!(
+            truncate_path_components(Path::new("/images/holiday-photo-2024.png"), SEP_DASH, 13),
+            PathBuf::from("/images/holiday-photo.png")
+        );
+        assert_eq!(
+            truncate_path_components(Path::new("a/b-c"), SEP_DASH, 0),
+            PathBuf::from("a/b-c")
+        );
+    }
+
     fn make_slug_config(path_mode: &str, fragment_mode: &str, case: &str, sep: char) -> SlugConfig {
         let sep_str = if sep == '-' { "dash" } else { "underscore" };
         let toml = format!(
@@ -657,4 +927,86 @@ mod tests {
             PathBuf::from("hello-world")
         );
     }
+
+    #[test]
+    fn test_slug_config_defaults_unchanged() {
+        let config: SlugConfig = toml::from_str("").unwrap();
+        assert_eq!(config.transform, SlugTransform::None);
+        assert_eq!(config.max_length, 0);
+
+        // Existing modes ignore the new options at their defaults
+        let config = make_slug_config("ascii", "safe", "lower", SEP_DASH);
+        assert_eq!(
+            slugify_path("Blog/Grüße aus Köln", &config),
+            PathBuf::from("blog/grusse-aus-koln")
+        );
+        assert_eq!(slugify_fragment("Grüße 日本", &config), "grüße-日本");
+    }
+
+    #[test]
+    fn test_unicode_latin_transform_modes() {
+        let mut config = make_slug_config("safe", "safe", "lower", SEP_DASH);
+        config.transform = SlugTransform::UnicodeLatin;
+
+        // Safe mode: Latin transliterated, other scripts preserved
+        assert_eq!(
+            slugify_path("Blog/Grüße aus Köln", &config),
+            PathBuf::from("blog/gruesse-aus-koeln")
+        );
+        assert_eq!(
+            slugify_path("posts/東京 Café/مرحبا 🎉", &config),
+            PathBuf::from("posts/東京-cafe/مرحبا-🎉")
+        );
+        assert_eq!(slugify_fragment("Über uns", &config), "ueber-uns");
+        assert_eq!(
+            slugify_fragment("한글 Überblick", &config),
+            "한글-ueberblick"
+        );
+
+        // Ascii mode: umlauts keep their German spelling before deunicode
+        config.path = SlugMode::Ascii;
+        config.fragment = SlugMode::Ascii;
+        assert_eq!(
+            slugify_path("Blog/Grüße 北京", &config),
+            PathBuf::from("blog/gruesse-bei-jing")
+        );
+
+        // Full mode
+        config.path = SlugMode::Full;
+        config.fragment = SlugMode::Full;
+        assert_eq!(
+            slugify_fragment("Ærøskøbing Ünïcode", &config),
+            "aeroskobing-uenicode"
+        );
+
+        // No mode is never transformed
+        config.path = SlugMode::No;
+        config.fragment = SlugMode::No;
+        assert_eq!(slugify_fragment("Grüße", &config), "Grüße");
+        assert_eq!(slugify_path("Grüße", &config), PathBuf::from("Grüße"));
+    }
+
+    #[test]
+    fn test_max_length_modes() {
+        let mut config = make_slug_config("safe", "full", "lower", SEP_DASH);
+        config.max_length = 20;
+
+        assert_eq!(
+            slugify_path("blog/A Very Long Post Title About Slugs", &config),
+            PathBuf::from("blog/a-very-long-post")
+        );
+        assert_eq!(
+            slugify_fragment("Installing the Toolchain on Linux", &config),
+            "installing-the"
+        );
+        // CJK without separators is cut by characters
+        assert_eq!(
+            slugify_path("文章/这是一个非常非常非常非常非常非常非常长的标题", &config),
+            PathBuf::from("文章/这是一个非常非常非常非常非常非常非常长的")
+        );
+        // Truncation runs after transliteration
+        config.transform = SlugTransform::UnicodeLatin;
+        config.max_length = 8;
+        assert_eq!(slugify_fragment("Grüße aus Köln", &config), "gruesse");
+    }
 }
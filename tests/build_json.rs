@@ -0,0 +1,71 @@
+//! `tola build --json` keeps stdout parseable on success and failure.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use serde_json::Value;
+use tempfile::TempDir;
+
+fn write_site(root: &Path, index: &str) {
+    fs::create_dir_all(root.join("content")).unwrap();
+    fs::write(root.join("tola.toml"), "[site.info]\ntitle = \"Test\"\n").unwrap();
+    fs::write(root.join("content/index.typ"), index).unwrap();
+}
+
+fn build_json(root: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_tola"))
+        .current_dir(root)
+        .args(["build", "--json"])
+        .output()
+        .unwrap()
+}
+
+/// Parse every stdout line and return the final build summary
+fn summary(output: &Output) -> Value {
+    let stdout = String::from_utf8(output.stdout.clone()).unwrap();
+    let records: Vec<Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|e| panic!("{e}: {line}")))
+        .collect();
+    assert!(!stdout.contains('\u{1b}'), "ANSI escape in JSON output");
+
+    let last = records.last().expect("no JSON output").clone();
+    assert_eq!(last["type"], "build");
+    last
+}
+
+#[test]
+fn build_json_success() {
+    let dir = TempDir::new().unwrap();
+    write_site(
+        dir.path(),
+        "#metadata((title: \"Home\",)) <tola-meta>\n\n= Welcome\n",
+    );
+
+    let output = build_json(dir.path());
+    assert!(output.status.success());
+
+    let report = summary(&output);
+    assert_eq!(report["exit_code"], 0);
+    assert_eq!(report["errors"].as_array().unwrap().len(), 0);
+    assert!(report["pages_compiled"].as_u64().unwrap() >= 1);
+    assert!(report["time_ms"].is_u64());
+}
+
+#[test]
+fn build_json_failure() {
+    let dir = TempDir::new().unwrap();
+    write_site(dir.path(), "= Welcome\n\n#undefined_variable\n");
+
+    let output = build_json(dir.path());
+    assert!(!output.status.success());
+
+    let report = summary(&output);
+    assert_eq!(report["exit_code"], 1);
+    let errors = report["errors"].as_array().unwrap();
+    assert!(!errors.is_empty());
+    assert_eq!(errors[0]["severity"], "error");
+    assert!(errors[0]["path"].as_str().unwrap().ends_with("index.typ"));
+    assert!(errors[0]["line"].is_u64());
+}
@@ -0,0 +1,52 @@
+//! `[build.diagnostics] format = "json"` writes compile errors to stderr as JSON Lines.
+
+use std::fs;
+use std::process::Command;
+
+use serde_json::Value;
+use tempfile::TempDir;
+
+#[test]
+fn compile_error_as_json_on_stderr() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    fs::create_dir_all(root.join("content")).unwrap();
+    fs::write(
+        root.join("tola.toml"),
+        "[site.info]\ntitle = \"Test\"\n\n[build.diagnostics]\nformat = \"json\"\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("content/index.typ"),
+        "= Welcome\n\n#undefined_variable\n",
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_tola"))
+        .current_dir(root)
+        .arg("build")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let records: Vec<Value> = stderr
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|e| panic!("{e}: {line}")))
+        .collect();
+    assert!(!records.is_empty());
+    assert_eq!(records[0]["level"], "error");
+    assert!(records[0]["file"].as_str().unwrap().ends_with("index.typ"));
+    assert!(records[0]["line"].is_u64());
+    assert!(records[0]["col"].is_u64());
+    assert!(records[0]["message"].is_string());
+    assert!(records[0].get("hint").is_some());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout
+            .lines()
+            .all(|line| serde_json::from_str::<Value>(line).is_err()),
+        "JSON on stdout: {stdout}"
+    );
+}